@@ -3,6 +3,8 @@
 //! Supports CSV, TOML, RON, and raw byte loading.
 //! All parse functions are pure (no I/O) for cross-platform use.
 
+use std::collections::HashMap;
+
 use serde::de::DeserializeOwned;
 
 /// Supported asset types.
@@ -38,16 +40,21 @@ pub fn load_asset_bytes(path: &std::path::Path) -> Result<(AssetType, Vec<u8>),
     Ok((asset_type, bytes))
 }
 
-/// Parse a CSV file into a Vec of deserialized records.
-/// Skips comment lines (starting with #). Flexible: handles headers automatically.
-pub fn parse_csv<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<T>, String> {
-    // Filter out comment lines before parsing
+/// The CSV text with `#` comment lines removed.
+fn strip_csv_comments(data: &[u8]) -> Result<String, String> {
     let text = std::str::from_utf8(data).map_err(|e| format!("UTF-8 error: {e}"))?;
-    let filtered: String = text
+    Ok(text
         .lines()
         .filter(|line| !line.trim_start().starts_with('#'))
         .collect::<Vec<_>>()
-        .join("\n");
+        .join("\n"))
+}
+
+/// Parse a CSV file into a Vec of deserialized records.
+/// Skips comment lines (starting with #). Flexible: handles headers automatically.
+pub fn parse_csv<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<T>, String> {
+    // Filter out comment lines before parsing
+    let filtered = strip_csv_comments(data)?;
 
     let mut reader = csv::ReaderBuilder::new()
         .has_headers(true)
@@ -80,6 +87,58 @@ pub fn parse_csv<T: DeserializeOwned>(data: &[u8]) -> Result<Vec<T>, String> {
     Ok(records)
 }
 
+/// Collect every `<prefix><name>` column of a CSV as numbers, keyed by the
+/// first column's value then by `<name>` - the open-ended companion to
+/// `parse_csv` for tables whose column set is data (equipment.csv's
+/// `armor_<channel>` resistances). Blank or zero cells are left out; a
+/// non-numeric cell is skipped with a warning.
+pub fn parse_csv_prefixed(
+    data: &[u8],
+    prefix: &str,
+) -> Result<HashMap<String, HashMap<String, f32>>, String> {
+    let filtered = strip_csv_comments(data)?;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(filtered.as_bytes());
+    let headers = reader.headers().map_err(|e| format!("CSV header error: {e}"))?.clone();
+    let columns: Vec<(usize, &str)> = headers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, h)| h.strip_prefix(prefix).map(|name| (i, name)))
+        .collect();
+
+    let mut out = HashMap::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(r) => r,
+            Err(e) => {
+                log::warn!("CSV parse warning (skipping row): {e}");
+                continue;
+            }
+        };
+        let Some(key) = record.get(0).filter(|k| !k.is_empty()) else { continue };
+        let mut values = HashMap::new();
+        for &(i, name) in &columns {
+            let cell = record.get(i).unwrap_or("");
+            if cell.is_empty() {
+                continue;
+            }
+            match cell.parse::<f32>() {
+                Ok(v) if v != 0.0 => {
+                    values.insert(name.to_string(), v);
+                }
+                Ok(_) => {}
+                Err(_) => log::warn!("CSV parse warning: {key}.{prefix}{name} = '{cell}' is not a number"),
+            }
+        }
+        out.insert(key.to_string(), values);
+    }
+    Ok(out)
+}
+
 /// Parse a TOML string into a deserialized struct.
 pub fn parse_toml<T: DeserializeOwned>(data: &[u8]) -> Result<T, String> {
     let text = std::str::from_utf8(data).map_err(|e| format!("UTF-8 error: {e}"))?;
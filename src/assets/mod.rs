@@ -13,6 +13,10 @@ pub mod loader;
 /// Comment-preserving single-field RON value rewriter (Planet Tuner enabler,
 /// docs/design/artificial-planet.md). Pure std, ungated on purpose.
 pub mod ron_edit;
+/// Name-preserving RON syntax tree (mod patch merging, schema checks).
+pub mod ron_tree;
+/// `schemas/*.toml` validation of data files and mod overrides.
+pub mod schema;
 
 use std::collections::HashMap;
 use std::any::Any;
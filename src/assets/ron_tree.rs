@@ -0,0 +1,447 @@
+//! Lossless-enough RON syntax tree for mod patching.
+//!
+//! `ron::Value` drops struct and enum names (`Talk(npc_id: "x")` comes back
+//! as a bare map), so a file merged through it would no longer parse into
+//! the game's types. This tree keeps names and field order, treats every
+//! scalar (number, string, char, bool, unit variant) as opaque source text,
+//! and prints back valid RON. Comments are dropped; `#![enable(..)]`
+//! attributes are kept.
+
+/// One RON value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    /// Scalar, verbatim: `1.5`, `"text"`, `'c'`, `true`, `None`, `Passive`.
+    Atom(String),
+    /// `[a, b]`
+    Seq(Vec<Node>),
+    /// `{k: v}`
+    Map(Vec<(Node, Node)>),
+    /// `Name(field: v)` or `(field: v)`
+    Struct { name: Option<String>, fields: Vec<(String, Node)> },
+    /// `Name(a, b)`, `(a, b)`, `Name()`
+    Tuple { name: Option<String>, items: Vec<Node> },
+}
+
+impl Node {
+    /// A struct field by name.
+    pub fn field(&self, key: &str) -> Option<&Node> {
+        match self {
+            Node::Struct { fields, .. } => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// The unquoted text of a string atom (`"wheat"` -> `wheat`); other
+    /// atoms as written.
+    pub fn atom_text(&self) -> Option<&str> {
+        match self {
+            Node::Atom(a) => Some(a.strip_prefix('"').and_then(|s| s.strip_suffix('"')).unwrap_or(a)),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed file: leading `#![...]` attributes plus the root value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub attributes: Vec<String>,
+    pub root: Node,
+}
+
+impl Document {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut p = Parser { src: text.as_bytes(), pos: 0 };
+        let mut attributes = Vec::new();
+        p.skip_trivia();
+        while p.rest().starts_with(b"#!") {
+            let start = p.pos;
+            let end = p.rest().iter().position(|&b| b == b']').ok_or("unterminated #![ attribute")?;
+            p.pos += end + 1;
+            attributes.push(text[start..p.pos].to_string());
+            p.skip_trivia();
+        }
+        let root = p.value()?;
+        p.skip_trivia();
+        if p.pos < p.src.len() {
+            return Err(format!("trailing input at byte {}", p.pos));
+        }
+        Ok(Self { attributes, root })
+    }
+
+    pub fn to_ron(&self) -> String {
+        let mut out = String::new();
+        for a in &self.attributes {
+            out.push_str(a);
+            out.push('\n');
+        }
+        write_node(&mut out, &self.root, 0);
+        out.push('\n');
+        out
+    }
+}
+
+struct Parser<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &[u8] {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn err<T>(&self, what: &str) -> Result<T, String> {
+        let line = self.src[..self.pos.min(self.src.len())].iter().filter(|&&b| b == b'\n').count() + 1;
+        Err(format!("{what} (line {line})"))
+    }
+
+    /// Whitespace and `//` / nested `/* */` comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+                self.pos += 1;
+            }
+            if self.rest().starts_with(b"//") {
+                while self.peek().is_some_and(|b| b != b'\n') {
+                    self.pos += 1;
+                }
+            } else if self.rest().starts_with(b"/*") {
+                let mut depth = 0;
+                while self.pos < self.src.len() {
+                    if self.rest().starts_with(b"/*") {
+                        depth += 1;
+                        self.pos += 2;
+                    } else if self.rest().starts_with(b"*/") {
+                        depth -= 1;
+                        self.pos += 2;
+                        if depth == 0 {
+                            break;
+                        }
+                    } else {
+                        self.pos += 1;
+                    }
+                }
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), String> {
+        self.skip_trivia();
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.err(&format!("expected '{}'", b as char))
+        }
+    }
+
+    /// Consume `close` or a `,` before the next element; true when closed.
+    fn list_end(&mut self, close: u8) -> Result<bool, String> {
+        self.skip_trivia();
+        match self.peek() {
+            Some(b) if b == close => {
+                self.pos += 1;
+                Ok(true)
+            }
+            Some(b',') => {
+                self.pos += 1;
+                self.skip_trivia();
+                if self.peek() == Some(close) {
+                    self.pos += 1;
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+            _ => self.err(&format!("expected ',' or '{}'", close as char)),
+        }
+    }
+
+    fn value(&mut self) -> Result<Node, String> {
+        self.skip_trivia();
+        match self.peek() {
+            None => self.err("unexpected end of input"),
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                self.skip_trivia();
+                if self.peek() == Some(b']') {
+                    self.pos += 1;
+                    return Ok(Node::Seq(items));
+                }
+                loop {
+                    items.push(self.value()?);
+                    if self.list_end(b']')? {
+                        return Ok(Node::Seq(items));
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut entries = Vec::new();
+                self.skip_trivia();
+                if self.peek() == Some(b'}') {
+                    self.pos += 1;
+                    return Ok(Node::Map(entries));
+                }
+                loop {
+                    let k = self.value()?;
+                    self.expect(b':')?;
+                    entries.push((k, self.value()?));
+                    if self.list_end(b'}')? {
+                        return Ok(Node::Map(entries));
+                    }
+                }
+            }
+            Some(b'(') => self.parens(None),
+            Some(b'"') => self.string(),
+            Some(b'\'') => self.char_lit(),
+            Some(b'r') if matches!(self.src.get(self.pos + 1), Some(b'"' | b'#')) => self.raw_string(),
+            Some(_) => {
+                let atom = self.bare()?;
+                self.skip_trivia();
+                let is_ident = atom.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_');
+                if is_ident && self.peek() == Some(b'(') {
+                    self.parens(Some(atom))
+                } else {
+                    Ok(Node::Atom(atom))
+                }
+            }
+        }
+    }
+
+    /// `( ... )` after an optional name: a struct when the first element is
+    /// `ident:`, otherwise a tuple.
+    fn parens(&mut self, name: Option<String>) -> Result<Node, String> {
+        self.expect(b'(')?;
+        self.skip_trivia();
+        if self.peek() == Some(b')') {
+            self.pos += 1;
+            return Ok(Node::Tuple { name, items: Vec::new() });
+        }
+        let save = self.pos;
+        let named = match self.bare() {
+            Ok(_) => {
+                self.skip_trivia();
+                self.peek() == Some(b':')
+            }
+            Err(_) => false,
+        };
+        self.pos = save;
+        if named {
+            let mut fields = Vec::new();
+            loop {
+                self.skip_trivia();
+                let key = self.bare()?;
+                self.expect(b':')?;
+                fields.push((key, self.value()?));
+                if self.list_end(b')')? {
+                    return Ok(Node::Struct { name, fields });
+                }
+            }
+        }
+        let mut items = Vec::new();
+        loop {
+            items.push(self.value()?);
+            if self.list_end(b')')? {
+                return Ok(Node::Tuple { name, items });
+            }
+        }
+    }
+
+    /// Identifier, number, bool or unit variant: everything up to a delimiter.
+    fn bare(&mut self) -> Result<String, String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|b| {
+            !b.is_ascii_whitespace() && !b"[](){}:,\"'/".contains(&b)
+        }) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return self.err("expected a value");
+        }
+        Ok(String::from_utf8_lossy(&self.src[start..self.pos]).into_owned())
+    }
+
+    fn string(&mut self) -> Result<Node, String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return self.err("unterminated string"),
+                Some(b'\\') => self.pos += 2,
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+        Ok(Node::Atom(String::from_utf8_lossy(&self.src[start..self.pos]).into_owned()))
+    }
+
+    fn char_lit(&mut self) -> Result<Node, String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek() {
+                None => return self.err("unterminated char"),
+                Some(b'\\') => self.pos += 2,
+                Some(b'\'') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+        Ok(Node::Atom(String::from_utf8_lossy(&self.src[start..self.pos]).into_owned()))
+    }
+
+    /// `r"..."` / `r#"..."#`
+    fn raw_string(&mut self) -> Result<Node, String> {
+        let start = self.pos;
+        self.pos += 1;
+        let hashes = self.rest().iter().take_while(|&&b| b == b'#').count();
+        self.pos += hashes;
+        if self.peek() != Some(b'"') {
+            return self.err("malformed raw string");
+        }
+        self.pos += 1;
+        let mut close = vec![b'"'];
+        close.extend(std::iter::repeat_n(b'#', hashes));
+        match self.rest().windows(close.len()).position(|w| w == close.as_slice()) {
+            Some(i) => self.pos += i + close.len(),
+            None => return self.err("unterminated raw string"),
+        }
+        Ok(Node::Atom(String::from_utf8_lossy(&self.src[start..self.pos]).into_owned()))
+    }
+}
+
+fn indent(out: &mut String, depth: usize) {
+    for _ in 0..depth {
+        out.push_str("    ");
+    }
+}
+
+/// Scalars-only collections print on one line; anything nested breaks
+/// one element per line.
+fn is_flat(node: &Node) -> bool {
+    match node {
+        Node::Atom(_) => true,
+        Node::Seq(items) | Node::Tuple { items, .. } => items.iter().all(|n| matches!(n, Node::Atom(_))),
+        Node::Map(entries) => entries.is_empty(),
+        Node::Struct { fields, .. } => fields.is_empty(),
+    }
+}
+
+fn write_node(out: &mut String, node: &Node, depth: usize) {
+    match node {
+        Node::Atom(a) => out.push_str(a),
+        Node::Seq(items) => write_list(out, "[", "]", items.iter().map(|n| (None, n)), depth, is_flat(node)),
+        Node::Tuple { name, items } => {
+            out.push_str(name.as_deref().unwrap_or(""));
+            write_list(out, "(", ")", items.iter().map(|n| (None, n)), depth, is_flat(node));
+        }
+        Node::Struct { name, fields } => {
+            out.push_str(name.as_deref().unwrap_or(""));
+            write_list(out, "(", ")", fields.iter().map(|(k, v)| (Some(k.as_str()), v)), depth, false);
+        }
+        Node::Map(entries) => {
+            if entries.is_empty() {
+                out.push_str("{}");
+                return;
+            }
+            out.push_str("{\n");
+            for (k, v) in entries {
+                indent(out, depth + 1);
+                write_node(out, k, depth + 1);
+                out.push_str(": ");
+                write_node(out, v, depth + 1);
+                out.push_str(",\n");
+            }
+            indent(out, depth);
+            out.push('}');
+        }
+    }
+}
+
+fn write_list<'a>(
+    out: &mut String,
+    open: &str,
+    close: &str,
+    items: impl ExactSizeIterator<Item = (Option<&'a str>, &'a Node)>,
+    depth: usize,
+    flat: bool,
+) {
+    out.push_str(open);
+    if items.len() == 0 {
+        out.push_str(close);
+        return;
+    }
+    if flat {
+        let parts: Vec<String> = items
+            .map(|(_, n)| {
+                let mut s = String::new();
+                write_node(&mut s, n, depth);
+                s
+            })
+            .collect();
+        out.push_str(&parts.join(", "));
+        out.push_str(close);
+        return;
+    }
+    out.push('\n');
+    for (key, n) in items {
+        indent(out, depth + 1);
+        if let Some(k) = key {
+            out.push_str(k);
+            out.push_str(": ");
+        }
+        write_node(out, n, depth + 1);
+        out.push_str(",\n");
+    }
+    indent(out, depth);
+    out.push_str(close);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_names_attributes_and_strings() {
+        let src = r##"#![enable(implicit_some)]
+// a comment
+(
+    trees: {
+        "passive": Selector([Condition("health_low"), Action("flee")]),
+    },
+    kind: Talk(npc_id: "mara", line: r#"she said "hi""#),
+    tint: (0.9, -1.5e2, 'x'),
+    empty: [], unit: Passive, none: None,
+)"##;
+        let doc = Document::parse(src).unwrap();
+        assert_eq!(doc.attributes, vec!["#![enable(implicit_some)]"]);
+        let kind = doc.root.field("kind").unwrap();
+        assert!(matches!(kind, Node::Struct { name: Some(n), .. } if n == "Talk"));
+        assert_eq!(kind.field("npc_id").unwrap().atom_text(), Some("mara"));
+        // Printing and re-parsing is stable.
+        let again = Document::parse(&doc.to_ron()).unwrap();
+        assert_eq!(again, doc);
+    }
+
+    #[test]
+    fn printed_tree_still_deserializes_into_game_types() {
+        let text = crate::embedded_data::BEHAVIORS_RON;
+        let doc = Document::parse(text).unwrap();
+        let printed = doc.to_ron();
+        let a = crate::systems::ai::behavior::BehaviorTreeRegistry::from_ron(text.as_bytes()).unwrap();
+        let b = crate::systems::ai::behavior::BehaviorTreeRegistry::from_ron(printed.as_bytes()).unwrap();
+        assert_eq!(a.len(), b.len());
+    }
+}
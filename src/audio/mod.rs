@@ -1,6 +1,7 @@
 //! Audio manager powered by kira.
 //!
-//! Handles music, sound effects, ambient layers, and spatial audio.
+//! Handles music, sound effects, ambient layers, and spatial audio (the
+//! listener-relative mix itself lives in `spatial`, free of kira).
 //! All audio code is gated behind the `native` feature (kira doesn't support WASM).
 
 pub mod spatial;
@@ -144,33 +145,23 @@ impl AudioManager {
         self.ui_volume = vol.clamp(0.0, 1.0);
     }
 
-    /// Play a sound with distance-based volume falloff (simple spatial audio).
+    /// Play a positioned one-shot mixed by `audio::spatial::mix`: the mix's
+    /// gain (rolloff, occlusion, catalog volume) scales the bus volume, its
+    /// pan places it in the stereo field, and its playback rate carries the
+    /// doppler shift.
     pub fn play_spatial(
         &mut self,
         path: &str,
-        source_pos: [f32; 3],
-        listener_pos: [f32; 3],
+        bus: &str,
+        mix: &spatial::SpatialMix,
     ) -> Result<(), String> {
-        let dx = source_pos[0] - listener_pos[0];
-        let dy = source_pos[1] - listener_pos[1];
-        let dz = source_pos[2] - listener_pos[2];
-        let distance = (dx * dx + dy * dy + dz * dz).sqrt();
-
-        // Inverse distance falloff, clamped
-        let max_distance = 50.0_f32;
-        if distance > max_distance {
-            return Ok(()); // Too far, don't play
-        }
-        let falloff = (1.0 - distance / max_distance).max(0.0) as f64;
-        let vol = self.master_volume * self.sfx_volume * falloff;
-
-        // Simple stereo panning from X offset
-        let pan = (dx / max_distance).clamp(-1.0, 1.0) as f64;
-
+        let bus_gain = if bus == "ui" { self.ui_volume } else { self.sfx_volume };
+        let vol = self.master_volume * bus_gain * (mix.gain as f64).clamp(0.0, 2.0);
         let data = self.load_sound(path)?;
         let settings = kira::sound::static_sound::StaticSoundSettings::default()
             .volume(kira::Volume::Amplitude(vol))
-            .panning(0.5 + pan * 0.5); // kira panning: 0.0=left, 0.5=center, 1.0=right
+            .panning(mix.panning as f64) // kira panning: 0.0=left, 0.5=center, 1.0=right
+            .playback_rate(mix.playback_rate as f64);
         let data_with_settings = data.with_settings(settings);
         self.manager
             .play(data_with_settings)
@@ -147,6 +147,16 @@ impl SoundCatalog {
         self.entries.get(id).map(|e| e.volume as f64).unwrap_or(0.5)
     }
 
+    /// A positioned source for a sound the catalog marks `spatial`, with its
+    /// authored volume and falloff distances. None for non-spatial or
+    /// unknown ids (they play flat).
+    pub fn spatial_source(&self, id: &str, position: glam::Vec3) -> Option<super::spatial::SpatialSource> {
+        let e = self.entries.get(id).filter(|e| e.spatial)?;
+        let mut source = super::spatial::SpatialSource::new(position).with_falloff(e.falloff_min, e.falloff_max);
+        source.volume = e.volume;
+        Some(source)
+    }
+
     /// Get all sound IDs in this catalog.
     pub fn ids(&self) -> impl Iterator<Item = &String> {
         self.entries.keys()
@@ -251,4 +261,21 @@ mod tests {
             "expected the shipped CC0 files to decode, got {decoded}"
         );
     }
+
+    /// Spatial-ness is authored in sounds.toml: a door is positioned with its
+    /// falloff, a button click is not, and an unknown id never is.
+    #[test]
+    fn catalog_declares_which_sounds_are_spatial() {
+        let repo = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let cat = SoundCatalog::load(&repo.join("data"));
+        let at = glam::Vec3::new(3.0, 0.0, 0.0);
+        let door = cat.spatial_source("sfx.door_open", at).expect("doors are spatial");
+        assert_eq!(door.position, at);
+        assert_eq!(door.radius, cat.get("sfx.door_open").unwrap().falloff_max);
+        let arrive = cat.get("sfx.vehicle_arrive").expect("vehicle arrivals have a cue");
+        assert!(repo.join("assets").join(&arrive.path).exists(), "{} is shipped", arrive.path);
+        assert!(cat.spatial_source("sfx.vehicle_arrive", at).is_some());
+        assert!(cat.spatial_source("sfx.button_click", at).is_none());
+        assert!(cat.spatial_source("sfx.no_such_sound", at).is_none());
+    }
 }
@@ -1,12 +1,45 @@
-//! Spatial audio — 3D sound positioning (stub for Steam Audio integration).
+//! Spatial audio — listener-relative mixing for positioned sounds.
+//!
+//! Pure math, no kira: `mix` turns a source + listener (+ the home's walls)
+//! into a gain, a stereo pan and a doppler playback rate, and the native
+//! `AudioManager::play_spatial` applies them. Compiles on every target so the
+//! ECS side (`systems::SfxEvent`) can carry positions on relay builds too.
+//!
+//! - Rolloff: inverse distance from `min_distance` (full volume inside it),
+//!   windowed to reach silence at `radius`; past `radius` a sound is culled.
+//! - Panning: the source direction projected on the listener's right axis,
+//!   narrowed near the head so a sound at your feet doesn't snap hard left.
+//! - Doppler: classic moving-source / moving-listener ratio along the line
+//!   between them, clamped to an octave either way.
+//! - Occlusion: each wall segment crossing the straight path (XZ) multiplies
+//!   the gain by `OCCLUSION_PER_WALL` — cheap, but a generator behind two
+//!   walls is clearly muffled.
 
-use glam::Vec3;
+use glam::{Vec3, Vec3Swizzles};
+
+use crate::ship::wall_collision::WallSegment;
+
+/// Speed of sound in air at ~20 °C, m/s.
+pub const SPEED_OF_SOUND: f32 = 343.0;
+/// Gain kept per wall between source and listener.
+pub const OCCLUSION_PER_WALL: f32 = 0.4;
+/// Occlusion never drops a sound below this fraction (sound leaks).
+pub const OCCLUSION_FLOOR: f32 = 0.1;
+/// Doppler playback-rate bounds (one octave down / up).
+const DOPPLER_MIN: f32 = 0.5;
+const DOPPLER_MAX: f32 = 2.0;
 
 /// Spatial audio source with 3D position.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SpatialSource {
     pub position: Vec3,
     pub volume: f32,
+    /// Distance at which the sound fades to silence (catalog `falloff_max`).
     pub radius: f32,
+    /// Full volume inside this distance (catalog `falloff_min`).
+    pub min_distance: f32,
+    /// World velocity, m/s (doppler). Zero for static sources.
+    pub velocity: Vec3,
 }
 
 impl SpatialSource {
@@ -15,6 +48,171 @@ impl SpatialSource {
             position,
             volume: 1.0,
             radius: 50.0,
+            min_distance: 1.0,
+            velocity: Vec3::ZERO,
         }
     }
+
+    /// Falloff distances from a catalog entry. A zero `falloff_min` (the
+    /// TOML default) means 1 m.
+    pub fn with_falloff(mut self, min: f32, max: f32) -> Self {
+        self.min_distance = if min > 0.0 { min } else { 1.0 };
+        self.radius = max.max(self.min_distance + 0.01);
+        self
+    }
+
+    pub fn with_velocity(mut self, velocity: Vec3) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// Distance gain alone (no occlusion): 1 inside `min_distance`,
+    /// `min_distance / d` beyond it, windowed to 0 at `radius`.
+    pub fn rolloff(&self, distance: f32) -> f32 {
+        if distance <= self.min_distance {
+            return 1.0;
+        }
+        if distance >= self.radius {
+            return 0.0;
+        }
+        let t = (distance - self.min_distance) / (self.radius - self.min_distance);
+        self.min_distance / distance * (1.0 - t * t)
+    }
+}
+
+/// The ear: position, orientation (from the camera) and velocity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Listener {
+    pub position: Vec3,
+    pub forward: Vec3,
+    pub up: Vec3,
+    pub velocity: Vec3,
+}
+
+impl Listener {
+    pub fn new(position: Vec3, forward: Vec3, up: Vec3) -> Self {
+        Self { position, forward, up, velocity: Vec3::ZERO }
+    }
+
+    /// The listener's right ear axis.
+    pub fn right(&self) -> Vec3 {
+        self.forward.cross(self.up).normalize_or_zero()
+    }
+}
+
+/// How to play one positioned sound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialMix {
+    /// Amplitude multiplier (rolloff × occlusion × source volume).
+    pub gain: f32,
+    /// kira panning: 0.0 = left, 0.5 = centre, 1.0 = right.
+    pub panning: f32,
+    /// Playback-rate factor from doppler (1.0 = unshifted).
+    pub playback_rate: f32,
+}
+
+/// Mix `source` for `listener`; None when it is out of earshot.
+pub fn mix(source: &SpatialSource, listener: &Listener, walls: &[WallSegment]) -> Option<SpatialMix> {
+    let offset = source.position - listener.position;
+    let distance = offset.length();
+    let rolloff = source.rolloff(distance);
+    if rolloff <= 0.0 {
+        return None;
+    }
+    let dir = offset.normalize_or_zero();
+    // Narrow the image inside min_distance: a sound on top of you is centred.
+    let width = (distance / source.min_distance).min(1.0);
+    let side = dir.dot(listener.right()) * width;
+    Some(SpatialMix {
+        gain: source.volume * rolloff * occlusion(listener.position, source.position, walls),
+        panning: 0.5 + 0.5 * side.clamp(-1.0, 1.0),
+        playback_rate: doppler(dir, listener.velocity, source.velocity),
+    })
+}
+
+/// Doppler rate for a source in unit direction `dir` from the listener:
+/// listener closing in raises pitch, source receding lowers it.
+pub fn doppler(dir: Vec3, listener_velocity: Vec3, source_velocity: Vec3) -> f32 {
+    if dir == Vec3::ZERO {
+        return 1.0;
+    }
+    let toward = listener_velocity.dot(dir).min(SPEED_OF_SOUND * 0.5);
+    let away = source_velocity.dot(dir).max(-SPEED_OF_SOUND * 0.5);
+    ((SPEED_OF_SOUND + toward) / (SPEED_OF_SOUND + away)).clamp(DOPPLER_MIN, DOPPLER_MAX)
+}
+
+/// Gain kept after the walls crossing the path from `from` to `to` (XZ).
+pub fn occlusion(from: Vec3, to: Vec3, walls: &[WallSegment]) -> f32 {
+    let (p, q) = (from.xz(), to.xz());
+    let hits = walls
+        .iter()
+        .filter(|w| segments_cross(p, q, w.a.into(), w.b.into()))
+        .count();
+    OCCLUSION_PER_WALL.powi(hits as i32).max(OCCLUSION_FLOOR)
+}
+
+/// Proper intersection of segments p-q and a-b (touching ends don't count,
+/// so a sound sitting exactly on a wall line isn't occluded by it).
+fn segments_cross(p: glam::Vec2, q: glam::Vec2, a: glam::Vec2, b: glam::Vec2) -> bool {
+    let d1 = (q - p).perp_dot(a - p);
+    let d2 = (q - p).perp_dot(b - p);
+    let d3 = (b - a).perp_dot(p - a);
+    let d4 = (b - a).perp_dot(q - a);
+    d1 * d2 < 0.0 && d3 * d4 < 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ear() -> Listener {
+        Listener::new(Vec3::ZERO, Vec3::NEG_Z, Vec3::Y)
+    }
+
+    #[test]
+    fn rolloff_is_inverse_distance_and_culls_past_radius() {
+        let s = SpatialSource::new(Vec3::ZERO).with_falloff(2.0, 40.0);
+        assert_eq!(s.rolloff(1.0), 1.0);
+        assert!(s.rolloff(4.0) < 0.5 && s.rolloff(4.0) > 0.45, "≈ 2/4");
+        assert!(s.rolloff(20.0) < s.rolloff(10.0));
+        assert_eq!(s.rolloff(40.0), 0.0);
+        let far = SpatialSource::new(Vec3::new(0.0, 0.0, -200.0)).with_falloff(5.0, 60.0);
+        assert!(mix(&far, &ear(), &[]).is_none(), "a tractor 200 m away is silent");
+    }
+
+    #[test]
+    fn pans_from_camera_orientation() {
+        let right = SpatialSource::new(Vec3::new(5.0, 0.0, 0.0));
+        let m = mix(&right, &ear(), &[]).unwrap();
+        assert!(m.panning > 0.9, "looking down -Z, +X is the right ear: {m:?}");
+        // Turn around: the same source is now on the left.
+        let turned = Listener::new(Vec3::ZERO, Vec3::Z, Vec3::Y);
+        assert!(mix(&right, &turned, &[]).unwrap().panning < 0.1);
+        let ahead = SpatialSource::new(Vec3::new(0.0, 0.0, -5.0));
+        assert!((mix(&ahead, &ear(), &[]).unwrap().panning - 0.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn doppler_rises_approaching_and_falls_receding() {
+        let pos = Vec3::new(0.0, 0.0, -20.0);
+        let coming = SpatialSource::new(pos).with_velocity(Vec3::new(0.0, 0.0, 20.0));
+        let going = SpatialSource::new(pos).with_velocity(Vec3::new(0.0, 0.0, -20.0));
+        assert!(mix(&coming, &ear(), &[]).unwrap().playback_rate > 1.05);
+        assert!(mix(&going, &ear(), &[]).unwrap().playback_rate < 0.95);
+        assert_eq!(mix(&SpatialSource::new(pos), &ear(), &[]).unwrap().playback_rate, 1.0);
+    }
+
+    #[test]
+    fn walls_between_muffle_the_sound() {
+        let s = SpatialSource::new(Vec3::new(0.0, 0.0, -6.0));
+        let wall = |z: f32| WallSegment { a: (-3.0, z), b: (3.0, z), half_thickness: 0.1 };
+        let open = mix(&s, &ear(), &[]).unwrap().gain;
+        let one = mix(&s, &ear(), &[wall(-2.0)]).unwrap().gain;
+        let two = mix(&s, &ear(), &[wall(-2.0), wall(-4.0)]).unwrap().gain;
+        assert!((one / open - OCCLUSION_PER_WALL).abs() < 1e-4);
+        assert!(two < one);
+        // A wall off to the side doesn't occlude.
+        let side = WallSegment { a: (5.0, -1.0), b: (5.0, -8.0), half_thickness: 0.1 };
+        assert_eq!(mix(&s, &ear(), &[side]).unwrap().gain, open);
+    }
 }
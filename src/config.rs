@@ -615,6 +615,10 @@ pub struct AppConfig {
     /// 0 = paused). Settings > Gameplay slider (v0.791).
     #[serde(default = "default_vitals_drain")]
     pub vitals_drain: f32,
+    /// Write the home and its autosaves as binary `.hsav` (deflated CBOR)
+    /// instead of JSON. Off by default: JSON stays readable and diffable.
+    #[serde(default)]
+    pub binary_saves: bool,
     /// Play mode (task #50): Normal | Creative | Dev -- the ladder every
     /// cheat/scope gate hangs off (see the `PlayMode` docs above). Absent in
     /// old configs => Dev via `#[serde(default)]` (the pre-launch default;
@@ -1260,6 +1264,7 @@ impl AppConfig {
             home_variant: state.settings.home_variant.clone(),
             hostile_wildlife: state.settings.hostile_wildlife,
             vitals_drain: state.settings.vitals_drain,
+            binary_saves: state.settings.binary_saves,
             play_mode: state.settings.play_mode,
             // v0.488 voice input prefs (top-level GuiState, not SettingsState).
             voice_input_device: state.audio_input_device.clone(),
@@ -1471,6 +1476,7 @@ impl AppConfig {
         state.settings.home_variant = self.home_variant.clone();
         state.settings.hostile_wildlife = self.hostile_wildlife;
         state.settings.vitals_drain = self.vitals_drain.clamp(0.0, 5.0);
+        state.settings.binary_saves = self.binary_saves;
         // Play mode (task #50): restore the persisted mode, then PRESET the
         // creative (free resources) flag from it -- GuiState defaults that
         // flag to true (early-dev posture), so a Normal-mode player must get
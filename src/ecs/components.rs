@@ -243,6 +243,43 @@ pub struct AIBehavior {
     pub target: Option<u64>,
 }
 
+/// Per-entity behavior-tree memory. `AISystem` attaches one to every
+/// `AIBehavior` entity on its first tick; leaves read and write the shared
+/// slots (target, move_to, named timers) and decorators keep their counters
+/// in `nodes`, keyed by the node's pre-order index in the entity's tree.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Blackboard {
+    /// Entity the current branch is acting on (chase, flee, attack).
+    pub target: Option<u64>,
+    /// Point the current branch is walking to (wander destination).
+    pub move_to: Option<Vec3>,
+    /// Named countdowns in seconds ("wander", "rest"), ticked by the leaves.
+    #[serde(default)]
+    pub timers: std::collections::HashMap<String, f32>,
+    /// Seconds this tree has been ticked (decorator clock).
+    #[serde(default)]
+    pub clock: f32,
+    /// Ticks so far; lets a decorator tell "still running" from "re-entered".
+    #[serde(default)]
+    pub tick: u64,
+    /// Decorator state by node index.
+    #[serde(default)]
+    pub nodes: std::collections::HashMap<u32, NodeMemory>,
+}
+
+/// One decorator's memory inside a `Blackboard`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct NodeMemory {
+    /// Blackboard tick this node last ran on (0 = not running).
+    pub last_tick: u64,
+    /// Clock when the current run started (Timeout).
+    pub started: f32,
+    /// Successes so far (Repeat).
+    pub count: u32,
+    /// Clock before which the node refuses to run (Cooldown).
+    pub ready_at: f32,
+}
+
 // ── Interaction ──────────────────────────────────────────────
 
 /// Makes an entity interactable by the player (click/hover).
@@ -290,6 +327,24 @@ pub struct Creature {
     pub body_side: f32,
 }
 
+/// A herd animal's husbandry state: a Creature raised at the homestead or
+/// tamed from the wild. `systems::husbandry` drives hunger, mood and
+/// breeding; health rides the ordinary Health component and age the Age one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Husbandry {
+    /// 0 = sated, 1 = starving (health drains while starving).
+    pub hunger: f32,
+    /// 0 = miserable, 1 = content. Eases toward how fed and healthy it is.
+    pub mood: f32,
+    pub female: bool,
+    /// Animal traits from genetics.ron; None when genetics is not loaded.
+    #[serde(default)]
+    pub genome: Option<Genome>,
+    /// Seconds until it can breed again.
+    #[serde(default)]
+    pub breed_cooldown: f32,
+}
+
 // ── Farming ──────────────────────────────────────────────────
 
 /// Default growth stages used when a plant definition doesn't specify its own.
@@ -325,6 +380,15 @@ pub struct CropInstance {
     /// crops on every replant (v0.410). Seed-planted crops are None.
     #[serde(default)]
     pub tower_slot: Option<u32>,
+    /// This plant's genome (yield, growth speed, drought resistance, colour),
+    /// rolled at sowing from its seed lot. None for crops from older saves,
+    /// which grow and harvest as the plain species.
+    #[serde(default)]
+    pub genome: Option<Genome>,
+    /// The cultivar seed item this crop was sown from (`seed_<plant>_cv<n>`);
+    /// None = founder stock from the plain `seed_<plant>_0`.
+    #[serde(default)]
+    pub cultivar: Option<String>,
 }
 
 // ── Vehicles & Mechs ─────────────────────────────────────────
@@ -340,6 +404,24 @@ pub struct Vehicle {
     pub item_id: String,
 }
 
+/// Live dynamics of a deployed vehicle: what DrivingSystem integrates
+/// between frames. Attached on the first tick a vehicle is simulated with a
+/// full tank; `fuel_l` round-trips through WorldSave.deployed_vehicles, the
+/// motion state does not (a reloaded vehicle is parked).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct VehicleDynamics {
+    /// Signed speed along the body's long (+X) axis, m/s; negative reverses.
+    pub speed_mps: f32,
+    /// Vertical speed of the body, m/s (+ = up).
+    pub climb_mps: f32,
+    /// Litres in the tank (unused by kits without one).
+    pub fuel_l: f32,
+    /// Wheels (or hull corners) touching the ground this tick, 0-4.
+    pub wheels_down: u8,
+    /// A hull is floating this tick (boats only).
+    pub afloat: bool,
+}
+
 /// A vehicle in TRANSIT (economy Phase 2 Stage 3, v0.680): drives itself in a
 /// straight line toward `dest` at `speed_mps` on game time, yawing to face its
 /// travel direction; VehicleSystem removes this on arrival (within
@@ -434,9 +516,12 @@ pub struct WasteAccumulator {
 /// A production facility producing one recipe at a time.
 /// `ManufacturingSystem::tick` advances `progress` toward 1.0; when it
 /// reaches 1.0, `output_count` increments and progress resets to 0.0.
+/// A recipe listed in `data/manufacturing.ron` `facility_recipes` also
+/// consumes inputs pulled from nearby containers and upstream facilities, and
+/// hands its outputs on downstream.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProductionFacility {
-    /// Recipe id from `data/recipes.csv`.
+    /// Recipe id from `data/recipes.csv`, or a `facility_recipes` id in `data/manufacturing.ron`.
     pub recipe_id: String,
     /// Progress toward completing one unit (0.0 to 1.0).
     pub progress: f32,
@@ -444,8 +529,30 @@ pub struct ProductionFacility {
     pub days_per_unit: f32,
     /// Total units produced since the facility started.
     pub output_count: u32,
-    /// Whether the facility is currently running (off if missing inputs/power).
+    /// Whether the facility is currently running (switched off by its owner).
     pub running: bool,
+    /// Inputs pulled in and waiting for the next unit, by item id.
+    #[serde(default)]
+    pub input_buffer: std::collections::HashMap<String, u32>,
+    /// Finished goods waiting to be handed on, by item id.
+    #[serde(default)]
+    pub output_buffer: std::collections::HashMap<String, u32>,
+    /// True once the current unit's inputs are consumed (it is in the works).
+    #[serde(default)]
+    pub loaded: bool,
+    /// Why the facility is not making progress ("no power", "waiting for 2x iron_ore_0",
+    /// "output full"). None while it works.
+    #[serde(default)]
+    pub stall: Option<String>,
+    /// Units finished per quality tier id (`quality_levels`), scrapped ones included.
+    #[serde(default)]
+    pub quality_counts: std::collections::HashMap<String, u32>,
+    /// Quality tier of the last finished unit.
+    #[serde(default)]
+    pub last_quality: Option<String>,
+    /// Units scrapped as defective (their material went to waste).
+    #[serde(default)]
+    pub scrapped: u32,
 }
 
 impl Default for ProductionFacility {
@@ -456,7 +563,28 @@ impl Default for ProductionFacility {
             days_per_unit: 1.0,
             output_count: 0,
             running: true,
+            input_buffer: Default::default(),
+            output_buffer: Default::default(),
+            loaded: false,
+            stall: None,
+            quality_counts: Default::default(),
+            last_quality: None,
+            scrapped: 0,
+        }
+    }
+}
+
+impl ProductionFacility {
+    /// One-line state for a machine card: (text, "ok" | "warn" | "off").
+    pub fn status_line(&self) -> (String, &'static str) {
+        if !self.running {
+            return ("off".to_string(), "off");
+        }
+        if let Some(why) = &self.stall {
+            return (format!("stalled: {why}"), "warn");
         }
+        let last = self.last_quality.as_ref().map(|q| format!(", last {q}")).unwrap_or_default();
+        (format!("{:.0}%{last}", self.progress * 100.0), "ok")
     }
 }
 
@@ -777,6 +905,11 @@ impl Default for Appearance {
 pub struct Outfit {
     #[serde(default)]
     pub equipped: std::collections::HashMap<String, String>,
+    /// Durability points used per worn item id (weapons wear one per swing
+    /// or shot; `WeaponSystem` breaks the item at its items.csv durability).
+    /// Keyed by item id, so taking a weapon off and on keeps its wear.
+    #[serde(default)]
+    pub wear: std::collections::HashMap<String, u32>,
 }
 
 // ── Geology / Mining ────────────────────────────────────────
@@ -1034,7 +1167,8 @@ impl Default for AirlockChamber {
 
 /// A cargo vehicle traveling along a route. `TransportationSystem` advances
 /// `progress` from 0.0 (origin) to 1.0 (destination) at `speed_per_day`.
-/// On arrival, the vehicle is marked `arrived` and game code unloads cargo.
+/// On arrival, the vehicle is marked `arrived` and `TransportationSystem`
+/// unloads the payload into the destination node's hold.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CargoVehicle {
     /// Route id from `data/transportation.ron::roads[].id` or similar.
@@ -1048,6 +1182,45 @@ pub struct CargoVehicle {
     /// Whether this vehicle has reached its destination.
     #[serde(default)]
     pub arrived: bool,
+    /// `LogisticsNode` ids it left from and is bound for.
+    #[serde(default)]
+    pub origin: String,
+    #[serde(default)]
+    pub destination: String,
+    /// The standing order that sent it, if any.
+    #[serde(default)]
+    pub order: Option<String>,
+}
+
+/// A place that holds fleet cargo: a ship's hold or a settlement's
+/// depot. The `Inventory` on the same entity IS the hold; `TransportationSystem`
+/// ships stacks between nodes along `data/logistics.ron` routes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogisticsNode {
+    pub id: String,
+    pub name: String,
+    /// "ship" or "settlement". Fleet pools total the ships.
+    pub kind: String,
+    /// Most cargo the hold takes, by mass.
+    pub capacity_kg: f32,
+}
+
+/// A standing delivery order: keep `keep` of `item` at node `to`,
+/// topping it up from `from` in lots of up to `lot` every `every_days`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandingOrder {
+    pub id: String,
+    pub from: String,
+    pub to: String,
+    pub item: String,
+    pub keep: u32,
+    pub lot: u32,
+    pub every_days: f32,
+    /// Game days since the order last checked.
+    #[serde(default)]
+    pub since_days: f32,
+    #[serde(default)]
+    pub paused: bool,
 }
 
 // ── Offline / Autonomous Agents ─────────────────────────────
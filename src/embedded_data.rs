@@ -138,14 +138,27 @@ pub const PLACES_SEED_JSON: &str = include_str!("../data/places/seed.json");
 pub const HOME_OUTLINE_JSON: &str = include_str!("../data/home_outline.json");
 pub const FIBONACCI_HOMESTEAD_RON: &str = include_str!("../data/blueprints/fibonacci_homestead.ron");
 pub const TRADE_GOODS_RON: &str = include_str!("../data/trade_goods.ron");
+pub const MARKETS_RON: &str = include_str!("../data/markets.ron");
+pub const LOGISTICS_RON: &str = include_str!("../data/logistics.ron");
 pub const ECONOMY_RON: &str = include_str!("../data/economy.ron");
+pub const GENETICS_RON: &str = include_str!("../data/genetics.ron");
+pub const CATCH_UP_RON: &str = include_str!("../data/catch_up.ron");
 pub const EQUIPMENT_CSV: &str = include_str!("../data/equipment.csv");
+pub const DAMAGE_TYPES_CSV: &str = include_str!("../data/damage_types.csv");
 pub const CREATURES_CSV: &str = include_str!("../data/creatures.csv");
 pub const LIVESTOCK_RON: &str = include_str!("../data/entities/livestock.ron");
 pub const WILD_SPAWNS_RON: &str = include_str!("../data/entities/wild_spawns.ron");
+pub const HUSBANDRY_RON: &str = include_str!("../data/entities/husbandry.ron");
 pub const WEATHER_EVENTS_RON: &str = include_str!("../data/weather/events.ron");
 pub const ABILITIES_CSV: &str = include_str!("../data/abilities.csv");
+pub const SOIL_TYPES_CSV: &str = include_str!("../data/soil_types.csv");
+pub const SOIL_AMENDMENTS_CSV: &str = include_str!("../data/soil_amendments.csv");
 pub const PROPOSAL_TYPES_RON: &str = include_str!("../data/governance/proposal_types.ron");
+pub const BEHAVIORS_RON: &str = include_str!("../data/behaviors.ron");
+pub const AI_FLOW_FIELD_TOML: &str = include_str!("../data/ai/flow_field.toml");
+pub const NPCS_RON: &str = include_str!("../data/npcs.ron");
+pub const NPC_CREW_RON: &str = include_str!("../data/npc/crew.ron");
+pub const FACTIONS_RON: &str = include_str!("../data/factions.ron");
 
 // ── Lookup helper ───────────────────────────────────────────────────
 
@@ -174,9 +187,17 @@ pub fn get_embedded(path: &str) -> Option<&'static str> {
         "creatures.csv" => Some(CREATURES_CSV),
         "entities/livestock.ron" => Some(LIVESTOCK_RON),
         "entities/wild_spawns.ron" => Some(WILD_SPAWNS_RON),
+        "entities/husbandry.ron" => Some(HUSBANDRY_RON),
         "weather/events.ron" => Some(WEATHER_EVENTS_RON),
         "abilities.csv" => Some(ABILITIES_CSV),
+        "soil_types.csv" => Some(SOIL_TYPES_CSV),
+        "soil_amendments.csv" => Some(SOIL_AMENDMENTS_CSV),
         "governance/proposal_types.ron" => Some(PROPOSAL_TYPES_RON),
+        "behaviors.ron" => Some(BEHAVIORS_RON),
+        "ai/flow_field.toml" => Some(AI_FLOW_FIELD_TOML),
+        "npcs.ron" => Some(NPCS_RON),
+        "npc/crew.ron" => Some(NPC_CREW_RON),
+        "factions.ron" => Some(FACTIONS_RON),
 
         // JSON
         "glossary.json" => Some(GLOSSARY_JSON),
@@ -283,8 +304,13 @@ pub fn get_embedded(path: &str) -> Option<&'static str> {
         "home_outline.json" => Some(HOME_OUTLINE_JSON),
         "blueprints/fibonacci_homestead.ron" => Some(FIBONACCI_HOMESTEAD_RON),
         "trade_goods.ron" => Some(TRADE_GOODS_RON),
+        "markets.ron" => Some(MARKETS_RON),
+        "logistics.ron" => Some(LOGISTICS_RON),
         "economy.ron" => Some(ECONOMY_RON),
+        "genetics.ron" => Some(GENETICS_RON),
+        "catch_up.ron" => Some(CATCH_UP_RON),
         "equipment.csv" => Some(EQUIPMENT_CSV),
+        "damage_types.csv" => Some(DAMAGE_TYPES_CSV),
 
         _ => None,
     }
@@ -400,14 +426,27 @@ pub const EMBEDDED_KEYS: &[&str] = &[
     "home_outline.json",
     "blueprints/fibonacci_homestead.ron",
     "trade_goods.ron",
+    "markets.ron",
+    "logistics.ron",
     "economy.ron",
+    "genetics.ron",
+    "catch_up.ron",
     "equipment.csv",
+    "damage_types.csv",
     "creatures.csv",
     "entities/livestock.ron",
     "entities/wild_spawns.ron",
+    "entities/husbandry.ron",
     "weather/events.ron",
     "abilities.csv",
+    "soil_types.csv",
+    "soil_amendments.csv",
     "governance/proposal_types.ron",
+    "behaviors.ron",
+    "ai/flow_field.toml",
+    "npcs.ron",
+    "npc/crew.ron",
+    "factions.ron",
 ];
 
 #[cfg(test)]
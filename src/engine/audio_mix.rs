@@ -0,0 +1,77 @@
+//! One-shot SFX playback for the audio frame sync: drains the engine's
+//! `pending_sfx` queue and the ECS `"sfx_events"` channel, and mixes every
+//! catalog-`spatial` sound with a position relative to the camera
+//! (`audio::spatial`): distance rolloff, stereo pan, doppler, and occlusion
+//! by the home's walls. Everything else plays flat as before.
+
+use glam::Vec3;
+
+use crate::audio::sounds::SoundCatalog;
+use crate::audio::spatial::{self, Listener};
+use crate::audio::AudioManager;
+use crate::hot_reload::data_store::DataStore;
+use crate::renderer::camera::Camera;
+use crate::ship::wall_collision::WallSegment;
+use crate::systems::SfxEvent;
+
+/// The camera as a listener, with a velocity from its movement since the
+/// last frame (`prev`, updated here). Teleports and band handoffs (>50 m/s)
+/// count as standing still so they don't bend pitch.
+pub(crate) fn listener(camera: &Camera, prev: &mut Option<Vec3>, dt: f32) -> Listener {
+    let pos = camera.position;
+    let mut ear = Listener::new(pos, camera.forward(), camera.up);
+    if let (Some(last), true) = (*prev, dt > 0.0) {
+        let v = (pos - last) / dt;
+        if v.length() < 50.0 {
+            ear.velocity = v;
+        }
+    }
+    *prev = Some(pos);
+    ear
+}
+
+/// Play this frame's queued one-shots: the engine queue (`pending_sfx`)
+/// plus the ECS `"sfx_events"` channel, occluded by `walls`.
+pub(crate) fn play_queued_sfx(
+    audio: &mut AudioManager,
+    ear: &Listener,
+    pending: &mut Vec<(&'static str, &'static str, Option<Vec3>)>,
+    data: &DataStore,
+    catalog: &SoundCatalog,
+    walls: &[WallSegment],
+) {
+    let mut queued: Vec<SfxEvent> = pending
+        .drain(..)
+        .map(|(id, fallback, position)| SfxEvent {
+            id: id.to_string(),
+            fallback: fallback.to_string(),
+            position,
+            velocity: Vec3::ZERO,
+        })
+        .collect();
+    // ECS-system SFX channel (v0.985): construction and crafting completions
+    // arrive here (systems have no engine state - see push_sfx_event).
+    if let Some(m) = data.get::<std::sync::Mutex<Vec<SfxEvent>>>("sfx_events") {
+        if let Ok(mut events) = m.lock() {
+            queued.append(&mut events);
+        }
+    }
+    for ev in queued {
+        let path = format!("assets/{}", catalog.path_or(&ev.id, &ev.fallback));
+        let source = ev
+            .position
+            .and_then(|p| catalog.spatial_source(&ev.id, p))
+            .map(|s| s.with_velocity(ev.velocity));
+        let played = match source {
+            Some(source) => match spatial::mix(&source, ear, walls) {
+                Some(mix) => audio.play_spatial(&path, "sfx", &mix),
+                None => Ok(()), // out of earshot
+            },
+            None => audio.play_sound_vol(&path, catalog.volume_or_default(&ev.id)),
+        };
+        if let Err(e) = played {
+            static WARNED: std::sync::Once = std::sync::Once::new();
+            WARNED.call_once(|| log::warn!("[Audio] sfx {}: {e}", ev.id));
+        }
+    }
+}
@@ -511,7 +511,7 @@ fn handle_bg_message(state: &mut EngineState, ci: usize, raw: &str) {
             if !is_from_me && state.gui_state.notif_dm_enabled {
                 state
                     .pending_sfx
-                    .push(("sfx.chat_message", "audio/ui/chat_message.ogg"));
+                    .push(("sfx.chat_message", "audio/ui/chat_message.ogg", None));
             }
             let preview = if is_from_me {
                 format!("You: {}", content)
@@ -216,6 +216,27 @@ pub(crate) fn rebuild_homestead(state: &mut EngineState) {
             }
         }
     }
+    // Structural integrity: frame + solve every zone as built. In survival (creative off)
+    // whatever fails comes down before the meshes are regenerated, so the rubble never renders.
+    if !state.gui_state.creative_mode {
+        if let Some(ship) = state.gui_state.ship_structure.as_mut() {
+            let fallen = crate::systems::construction::framing::settle_ship(ship);
+            if !fallen.is_empty() {
+                log::warn!("Structural collapse: {}", fallen.join(", "));
+                state.gui_state.pending_toasts.push((
+                    format!("Collapsed: {}", fallen.join(", ")),
+                    crate::gui::ToastKind::Error,
+                ));
+                state.gui_state.construction_wall_selected = None;
+                state.gui_state.construction_structure_selected = None;
+            }
+        }
+    }
+    state.gui_state.construction_framing = state
+        .gui_state
+        .ship_structure
+        .as_ref()
+        .map_or_else(Vec::new, crate::systems::construction::framing::analyze_ship);
     // Dev tool (v0.576): write a machine-readable snapshot of the live home so an AI can READ what
     // the operator is building (the act surface -- a text-command console -- is the next stage).
     // Still the ACTIVE ZONE's body (the zone being edited -- what the operator is building right
@@ -245,10 +266,16 @@ pub(crate) fn rebuild_homestead(state: &mut EngineState) {
         Some(ship) => crate::ship::wall_collision::ship_sight_segments(ship),
         None => Vec::new(),
     };
+    let walls = state.wall_colliders.clone();
+    publish_nav_obstacles(state, |o| o.set_walls(walls));
     apply_homestead_meshes(state, homestead);
     // The hull wrap follows the structure (increment D): a zone add/move/resize or a roof
     // material change regrows the exterior shell in the same rebuild.
     rebuild_hull(state);
+    // The home's rooms as a thermal network: walls, doors and roof as built right now.
+    if let Some(ship) = &state.gui_state.ship_structure {
+        crate::engine::home_spawn::spawn_home_thermal(&mut state.game_world.world, ship);
+    }
     // Refresh lights + sealed bounds from the new room_info (height edits move them).
     let auto_lights = room_info.iter().map(|r| {
         let light_pos = Vec3::new(r.center.x, r.center.y + r.dimensions.y * 0.5 - 0.1, r.center.z);
@@ -361,6 +388,35 @@ pub(crate) fn port_gizmo_pos(p: &crate::machines::PlacedMachine, i: usize, n: us
     Vec3::new(p.pos.0 + r * a.cos(), top, p.pos.2 + r * a.sin())
 }
 
+/// Update the `"nav_obstacles"` AISystem paths creatures around (inserted on
+/// first use). NavObstacles only bumps its version on a real change, so the
+/// per-frame calls during an editor drag re-bake nothing until a wall or a
+/// machine actually moves.
+pub(crate) fn publish_nav_obstacles(
+    state: &mut EngineState,
+    edit: impl FnOnce(&mut crate::systems::ai::flow_field::NavObstacles),
+) {
+    use crate::systems::ai::flow_field::NavObstacles;
+    if !state.data_store.contains("nav_obstacles") {
+        state.data_store.insert("nav_obstacles", std::sync::Mutex::new(NavObstacles::default()));
+    }
+    if let Some(m) = state.data_store.get::<std::sync::Mutex<NavObstacles>>("nav_obstacles") {
+        if let Ok(mut obstacles) = m.lock() {
+            edit(&mut obstacles);
+        }
+    }
+}
+
+/// XZ footprint box of a placed machine; a rotated box is widened to its
+/// axis-aligned bounds.
+pub(crate) fn machine_footprint(x: f32, z: f32, size: (f32, f32, f32), yaw_deg: f32) -> (glam::Vec2, glam::Vec2) {
+    let (s, c) = yaw_deg.to_radians().sin_cos();
+    let (hx, hz) = (size.0 * 0.5, size.2 * 0.5);
+    let half = glam::Vec2::new(c.abs() * hx + s.abs() * hz, s.abs() * hx + c.abs() * hz);
+    let centre = glam::Vec2::new(x, z);
+    (centre - half, centre + half)
+}
+
 /// Keep the live machine ECS in sync with the editor placements (v0.730).
 /// Spawns entities for newly-placed machines (with their power/water/
 /// AutoRefine/Container roles), despawns removed ones, and updates the
@@ -418,6 +474,7 @@ pub(crate) fn sync_machine_entities(state: &mut EngineState, placements: &[crate
             }
         }
     }
+    crate::engine::home_spawn::spawn_home_grid(&mut state.game_world.world, home, &all);
 }
 
 pub(crate) fn rebuild_machine_objects(state: &mut EngineState) {
@@ -487,6 +544,12 @@ pub(crate) fn rebuild_machine_objects(state: &mut EngineState) {
     // recomputed here (a new connection re-islands on the next world entry,
     // same as before).
     sync_machine_entities(state, &placements);
+    // Machines are solid to creature pathfinding too.
+    let footprints = placements
+        .iter()
+        .map(|p| machine_footprint(p.pos.0, p.pos.2, p.size, p.rotation))
+        .collect();
+    publish_nav_obstacles(state, |o| o.set_solids(footprints));
 
     // Fast path: the machine COUNT is unchanged (an offset drag / room move, not add/remove).
     // Reuse the existing meshes + materials and only update positions, so a per-frame drag does
@@ -1451,8 +1514,7 @@ pub(crate) fn render_door_panels(
     let manual = state.door_manual_open.clone();
     // Local SFX buffer: the panel loop holds &mut door_panels, so edge sounds
     // collect here and land on state.pending_sfx after the borrow ends.
-    let mut sfx: Vec<(&'static str, &'static str)> = Vec::new();
-    let state_cam = state.camera.position;
+    let mut sfx: Vec<(&'static str, &'static str, Option<Vec3>)> = Vec::new();
     let locks_live = state.door_locks.clone();
     for (di, (p, open)) in state.door_panels.iter_mut().enumerate() {
         // An operable DOOR opens on approach; a window or a "fixed"-styled opening stays shut
@@ -1532,17 +1594,14 @@ pub(crate) fn render_door_panels(
         // Door SFX (v0.983): fire on the swing's START edges - rising off
         // fully-closed plays the open sound, dropping off fully-open plays
         // the close. Mid-swing reversals stay quiet (no re-trigger spam when
-        // a player hovers at an auto-door's radius), and doors beyond
-        // earshot stay silent (a REMOTE actor can open doors far from you;
-        // play_sound is non-spatial, so gate by distance until the spatial
-        // path is wired).
-        let within_earshot = state.aboard_station
-            && (p.center - state_cam).length_squared() < 25.0 * 25.0;
-        if within_earshot {
+        // a player hovers at an auto-door's radius). Door sounds are
+        // catalog-spatial: a REMOTE actor's door far from you falls off
+        // with distance and walls instead of playing at your ear.
+        if state.aboard_station {
             if open_before <= 0.02 && *open > 0.02 {
-                sfx.push(("sfx.door_open", "audio/sfx/door_open.ogg"));
+                sfx.push(("sfx.door_open", "audio/sfx/door_open.ogg", Some(p.center)));
             } else if open_before >= 0.98 && *open < 0.98 {
-                sfx.push(("sfx.door_close", "audio/sfx/door_close.ogg"));
+                sfx.push(("sfx.door_close", "audio/sfx/door_close.ogg", Some(p.center)));
             }
         }
         let m = crate::systems::door_anim::panel_motion(&p.style, *open, p.size.x, p.size.y);
@@ -1576,4 +1635,11 @@ pub(crate) fn render_door_panels(
         }
     }
     state.pending_sfx.extend(sfx);
+    // Live door openings for the thermal network: an open door mixes the rooms' air.
+    if let Some(slot) = state.data_store.get::<std::sync::Mutex<Vec<((f32, f32), f32)>>>("door_open") {
+        if let Ok(mut v) = slot.lock() {
+            v.clear();
+            v.extend(state.door_panels.iter().filter(|(p, _)| !p.is_window).map(|(p, open)| ((p.center.x, p.center.z), *open)));
+        }
+    }
 }
@@ -20,9 +20,70 @@ pub(crate) fn spawn_home_power_entities(world: &mut hecs::World, data_dir: &std:
         };
         spawn_home_machine_entity(world, inst, def, &power_islands, &water_islands, None, None);
     }
+    spawn_home_grid(world, &home, &all);
     spawn_home_air_space(world);
 }
 
+/// (Re)spawn the home's power runs as `GridCable` entities so the ElectricalSystem flows
+/// current through real cables. Replaces any existing set; a run that is still laid the same way
+/// keeps its breaker state, so an editor sync doesn't quietly reset a tripped circuit.
+pub(crate) fn spawn_home_grid(
+    world: &mut hecs::World,
+    home: &crate::machines::MachineHome,
+    all: &[crate::machines::MachineInstance],
+) {
+    use crate::ecs::components::HomeMachine;
+    use crate::systems::power_grid::{cables_for_home, GridCable};
+    let old: Vec<(hecs::Entity, GridCable)> =
+        world.query::<&GridCable>().iter().map(|(e, c)| (e, c.clone())).collect();
+    for (e, _) in &old {
+        let _ = world.despawn(*e);
+    }
+    for mut cable in cables_for_home(home, all) {
+        if let Some((_, prev)) = old.iter().find(|(_, p)| {
+            p.from == cable.from && p.to == cable.to && p.conduit == cable.conduit && p.protection == cable.protection
+        }) {
+            cable.heat = prev.heat;
+            cable.tripped = prev.tripped;
+        }
+        world.spawn((HomeMachine, cable));
+    }
+}
+
+/// (Re)spawn the home zone's thermal network: one `RoomEnvironment` + `ThermalRoom` entity per
+/// detected room and a `ThermalLink` per heat path. A room that still exists by id keeps its
+/// temperature and air, so a wall edit doesn't reset the house to 20 °C.
+pub(crate) fn spawn_home_thermal(world: &mut hecs::World, ship: &crate::ship::ship_structure::ShipStructure) {
+    use crate::ecs::components::{RoomEnvironment, Transform};
+    use crate::systems::thermal::{build_network, ThermalLink, ThermalRoom};
+    let old: Vec<(hecs::Entity, String, RoomEnvironment)> = world
+        .query::<(&ThermalRoom, &RoomEnvironment)>()
+        .iter()
+        .map(|(e, (r, env))| (e, r.id.clone(), env.clone()))
+        .collect();
+    let links: Vec<hecs::Entity> = world.query::<&ThermalLink>().iter().map(|(e, _)| e).collect();
+    for e in old.iter().map(|(e, _, _)| *e).chain(links) {
+        let _ = world.despawn(e);
+    }
+    let Some(zone) = ship.zones.get(ship.home_zone_index()) else {
+        return;
+    };
+    let origin = zone.origin_vec();
+    let (rooms, links) = build_network(&zone.body, origin);
+    for room in rooms {
+        let env = old
+            .iter()
+            .find(|(_, id, _)| *id == room.id)
+            .map(|(_, _, env)| env.clone())
+            .unwrap_or_default();
+        let center = Vec3::new((room.min.0 + room.max.0) * 0.5, origin.y, (room.min.1 + room.max.1) * 0.5);
+        world.spawn((Transform { position: center, rotation: Quat::IDENTITY, scale: Vec3::ONE }, env, room));
+    }
+    for link in links {
+        world.spawn((link,));
+    }
+}
+
 /// Spawn the home's sealed AIR space (v0.617) if one doesn't already exist: a HomeMachine + HomeAir
 /// tagged `EnclosedSpace` with an Earth-like atmosphere. The AtmosphereSystem ticks it + publishes the
 /// live AirStatus. Sealed (a habitat/ship hull), so it doesn't equalize with the outside (space). The
@@ -77,6 +138,8 @@ pub(crate) fn spawn_home_machine_entity(
         && def.rf_emission <= 0.0
         && def.auto_recipe.is_none()
         && def.container_type.is_none()
+        && def.hvac.is_none()
+        && def.facility.is_none()
     {
         return;
     }
@@ -140,6 +203,28 @@ pub(crate) fn spawn_home_machine_entity(
             }
         }
     }
+    // Climate role: a thermostat unit rated at the machine's Consumer draw.
+    if let Some(h) = &def.hvac {
+        let rated_w = match &def.power {
+            Some(MachinePower::Consumer { watts, .. }) => *watts,
+            _ => 0.0,
+        };
+        let _ = world.insert_one(
+            e,
+            crate::ecs::components::HvacUnit { mode: h.mode.clone(), target_temp: h.target_temp, power_kw: rated_w / 1000.0 },
+        );
+    }
+    // Production role: ManufacturingSystem works the chain recipe.
+    if let Some(f) = &def.facility {
+        let _ = world.insert_one(
+            e,
+            crate::ecs::components::ProductionFacility {
+                recipe_id: f.recipe.clone(),
+                days_per_unit: f.days_per_unit,
+                ..Default::default()
+            },
+        );
+    }
     if is_water {
         let _ = world.insert_one(e, PlumbingCircuit { island: water_islands.get(&inst.id).copied().unwrap_or(0) });
         // Does this machine need power to move/produce water? Only true when it has a power-CONSUMER
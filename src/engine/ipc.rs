@@ -98,6 +98,8 @@ pub(crate) fn auto_seed_showcase(state: &mut EngineState) {
             health: 100.0,
             tower_id: Some(grow_id.to_string()),
             tower_slot: Some(slot),
+            genome: None,
+            cultivar: None,
         });
     };
     for g in &state.grow_positions {
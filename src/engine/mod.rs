@@ -6,6 +6,8 @@
 //! pollers, frame-lock math, the editor cluster) land beside them as they
 //! extract.
 
+/// One-shot SFX drain + listener-relative spatial mixing (audio frame sync).
+pub mod audio_mix;
 pub mod color;
 pub mod dm;
 pub mod editor;
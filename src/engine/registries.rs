@@ -11,94 +11,110 @@ use crate::systems::quests::QuestRegistry;
 /// skills / quests) otherwise run against empty registries: raw item ids, no
 /// recipes to craft, no skill names, the quest shown by its raw id. (The heavy 3D
 /// mesh generation stays lazy in `load_world`.) Idempotent — safe to call twice.
-pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::Path) {
+/// Returns the mod resolution (which mods loaded, which were disabled and why).
+pub(crate) fn load_data_registries(
+    store: &mut DataStore,
+    data_dir: &std::path::Path,
+) -> crate::mods::ModResolution {
+    // Every file goes through the enabled mods (data/mods/*, dependency
+    // resolved): each mod's mirrored copy is merged in as a patch, in load order.
+    let mut modded = crate::mods::ModData::scan(data_dir);
     fn load_csv_registry<T: Send + Sync + 'static>(
         store: &mut DataStore,
-        path: std::path::PathBuf,
+        modded: &mut crate::mods::ModData,
+        rel: &str,
         key: &str,
         build: impl Fn(&[u8]) -> Result<T, String>,
     ) {
         // Disk-first (modding), embedded fallback (v0.744: a zero-file
-        // fresh install still gets every registry). The embedded key is
-        // the path relative to any `data` dir component.
-        let bytes: Option<Vec<u8>> = match std::fs::read(&path) {
-            Ok(b) => Some(b),
-            Err(_) => {
-                // Relative key = everything after the LAST `data` path
-                // component (the data dir itself always ends in `data`).
-                let comps: Vec<_> = path.iter().collect();
-                let rel = comps
-                    .iter()
-                    .rposition(|c| *c == std::ffi::OsStr::new("data"))
-                    .map(|i| {
-                        comps[i + 1..]
-                            .iter()
-                            .map(|c| c.to_string_lossy())
-                            .collect::<Vec<_>>()
-                            .join("/")
-                    })
-                    .unwrap_or_default();
-                crate::embedded_data::get_embedded(&rel).map(|s| {
-                    log::info!("{key}: disk file absent, using embedded {rel}");
-                    s.as_bytes().to_vec()
-                })
-            }
-        };
-        match bytes {
-            Some(bytes) => match build(&bytes) {
+        // fresh install still gets every registry).
+        match modded.read(rel) {
+            Some(text) => match build(text.as_bytes()) {
                 Ok(reg) => {
                     store.insert(key, reg);
-                    log::info!("Loaded {key} from {}", path.display());
+                    log::info!("Loaded {key} from {rel}");
                 }
-                Err(e) => log::warn!("Failed to build {key} from {}: {e}", path.display()),
+                Err(e) => log::warn!("Failed to build {key} from {rel}: {e}"),
             },
             None => log::warn!(
-                "Data file {} not found (no embedded copy); {key} unavailable (system on defaults)",
-                path.display()
+                "Data file {rel} not found (no embedded copy); {key} unavailable (system on defaults)"
             ),
         }
     }
     load_csv_registry(
         store,
-        data_dir.join("items.csv"),
+        &mut modded,
+        "items.csv",
         "item_registry",
         crate::systems::inventory::ItemRegistry::from_csv,
     );
     load_csv_registry(
         store,
-        data_dir.join("recipes.csv"),
+        &mut modded,
+        "recipes.csv",
         "recipe_registry",
         crate::systems::crafting::RecipeRegistry::from_csv,
     );
     load_csv_registry(
         store,
-        data_dir.join("plants.csv"),
+        &mut modded,
+        "plants.csv",
         "plant_registry",
         crate::systems::farming::PlantRegistry::from_csv,
     );
+    // Soil chemistry: the starting soil of each bed / tray / field unit and
+    // the items that amend it. Read by FarmingSystem::tick.
     load_csv_registry(
         store,
-        data_dir.join("status_effects.csv"),
+        &mut modded,
+        "soil_types.csv",
+        "soil_registry",
+        crate::systems::farming::soil::SoilRegistry::from_csv,
+    );
+    load_csv_registry(
+        store,
+        &mut modded,
+        "soil_amendments.csv",
+        "soil_amendment_registry",
+        crate::systems::farming::soil::AmendmentRegistry::from_csv,
+    );
+    load_csv_registry(
+        store,
+        &mut modded,
+        "status_effects.csv",
         "status_effect_registry",
         crate::systems::status_effects::StatusEffectRegistry::from_csv,
     );
     load_csv_registry(
         store,
-        data_dir.join("skills").join("skills.csv"),
+        &mut modded,
+        "skills/skills.csv",
         "skill_registry",
         SkillRegistry::from_csv,
     );
     load_csv_registry(
         store,
-        data_dir.join("equipment.csv"),
+        &mut modded,
+        "equipment.csv",
         "equipment_registry",
         crate::systems::economy::EquipmentRegistry::from_csv,
     );
+    // DamageTypeRegistry: damage types as rows. Read by
+    // CombatSystem (armour channel, status effect, skill) and every system
+    // that names a damage source (abilities, weather, disasters).
+    load_csv_registry(
+        store,
+        &mut modded,
+        "damage_types.csv",
+        "damage_type_registry",
+        crate::systems::combat::damage::DamageTypeRegistry::from_csv,
+    );
     // CreatureRegistry (v0.751, ladder rung 7): creatures.csv finally gets
     // its loader. Read by the livestock spawn + walk-up collect bridges.
     load_csv_registry(
         store,
-        data_dir.join("creatures.csv"),
+        &mut modded,
+        "creatures.csv",
         "creature_registry",
         crate::systems::livestock::CreatureRegistry::from_csv,
     );
@@ -107,17 +123,14 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
     // page's Abilities panel.
     load_csv_registry(
         store,
-        data_dir.join("abilities.csv"),
+        &mut modded,
+        "abilities.csv",
         "ability_registry",
         crate::systems::abilities::AbilityRegistry::from_csv,
     );
-    store.insert(
-        "quest_registry",
-        QuestRegistry::from_ron_dir(&data_dir.join("quests")),
-    );
     // Travel destinations (v0.979): named world places whose arrival radius
     // fires "travel_<id>" quest events. Read by QuestSystem::tick.
-    match crate::embedded_data::read_data_or_embedded(data_dir, "entities/destinations.ron") {
+    match modded.read("entities/destinations.ron") {
         Some(text) => match crate::systems::quests::DestinationList::from_ron(text.as_bytes()) {
             Ok(list) => {
                 log::info!(
@@ -137,7 +150,7 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
     // foundation/wall/door/window/roof/furniture/machine catalog that had nothing
     // loading it into the DataStore before this, so every queue_build() call would
     // have silently missed the registry lookup forever.
-    match crate::embedded_data::read_data_or_embedded(data_dir, "blueprints/basic.ron") {
+    match modded.read("blueprints/basic.ron") {
         Some(text) => match crate::systems::construction::BlueprintRegistry::from_ron(text.as_bytes()) {
             Ok(reg) => {
                 log::info!("Loaded {} blueprints from blueprints/basic.ron", reg.blueprints.len());
@@ -151,7 +164,7 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
     }
     // LivestockSpawnList (v0.751, ladder rung 7): which starter animals the
     // homestead gets and near which field. Read by load_world's spawn pass.
-    match crate::embedded_data::read_data_or_embedded(data_dir, "entities/livestock.ron") {
+    match modded.read("entities/livestock.ron") {
         Some(text) => match crate::systems::livestock::LivestockSpawnList::from_ron(text.as_bytes()) {
             Ok(list) => {
                 log::info!("Loaded {} livestock placements from entities/livestock.ron", list.animals.len());
@@ -161,9 +174,21 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
         },
         None => log::warn!("entities/livestock.ron not found (no embedded copy); no starter animals"),
     }
+    // HusbandryConfig: hunger / breeding / taming tuning and the feed each
+    // diet eats, for the herd step in LivestockSystem.
+    match modded.read("entities/husbandry.ron") {
+        Some(text) => match crate::systems::husbandry::HusbandryConfig::from_ron(text.as_bytes()) {
+            Ok(cfg) => {
+                log::info!("Loaded {} feed rows from entities/husbandry.ron", cfg.feed.len());
+                store.insert("husbandry_config", cfg);
+            }
+            Err(e) => log::warn!("Failed to parse entities/husbandry.ron: {e}"),
+        },
+        None => log::warn!("entities/husbandry.ron not found (no embedded copy); herd animals have no needs"),
+    }
     // WildSpawnList (v0.761, combat arc): hostile creatures placed away
     // from the homestead. Read by load_world's wild spawn pass.
-    match crate::embedded_data::read_data_or_embedded(data_dir, "entities/wild_spawns.ron") {
+    match modded.read("entities/wild_spawns.ron") {
         Some(text) => match crate::systems::livestock::WildSpawnList::from_ron(text.as_bytes()) {
             Ok(list) => {
                 log::info!("Loaded {} wild spawn placements from entities/wild_spawns.ron", list.spawns.len());
@@ -173,11 +198,40 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
         },
         None => log::warn!("entities/wild_spawns.ron not found (no embedded copy); no wild creatures"),
     }
+    // BehaviorTreeRegistry: one behavior tree per AIBehavior::behavior_type.
+    // Read by AISystem::tick (which falls back to its embedded copy when this
+    // key is absent). Trees naming leaves AISystem does not provide are
+    // logged here; those leaves fail at runtime.
+    match modded.read("behaviors.ron") {
+        Some(text) => match crate::systems::ai::behavior::BehaviorTreeRegistry::from_ron(text.as_bytes()) {
+            Ok(reg) => {
+                for missing in reg.unknown_leaves(&crate::systems::ai::creature_leaves()) {
+                    log::warn!("behaviors.ron names an unknown leaf: {missing}");
+                }
+                log::info!("Loaded {} behavior trees from behaviors.ron", reg.len());
+                store.insert("behavior_tree_registry", reg);
+            }
+            Err(e) => log::warn!("Failed to parse behaviors.ron: {e}"),
+        },
+        None => log::warn!("behaviors.ron not found (no embedded copy); AISystem uses its built-in trees"),
+    }
+    // FlowFieldConfig: creature pathfinding grid (cell size, clearance,
+    // water cost). Read by AISystem; its embedded copy stands in when absent.
+    match modded.read("ai/flow_field.toml") {
+        Some(text) => match crate::systems::ai::flow_field::FlowFieldConfig::from_toml(&text) {
+            Ok(cfg) => {
+                log::info!("Loaded flow field config from ai/flow_field.toml (cell {} m)", cfg.cell_size);
+                store.insert("flow_field_config", cfg);
+            }
+            Err(e) => log::warn!("Failed to parse ai/flow_field.toml: {e}"),
+        },
+        None => log::warn!("ai/flow_field.toml not found (no embedded copy); AISystem uses defaults"),
+    }
     // WeatherEventRegistry (v0.1034, extreme-weather schema increment):
     // tornado/blizzard/meteor-shower definitions. Loaded + validated now;
     // WeatherSystem starts CONSUMING it on the next rung (trigger rolls,
     // wind profiles, hazards) - until then it is inert data.
-    match crate::embedded_data::read_data_or_embedded(data_dir, "weather/events.ron") {
+    match modded.read("weather/events.ron") {
         Some(text) => {
             match crate::systems::weather_events::WeatherEventRegistry::from_ron(text.as_bytes()) {
                 Ok(reg) => {
@@ -194,7 +248,7 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
     // deployed-vehicle render pass.
     // TradeGoodsRegistry (v0.747, ladder rung 3): base credit values for the
     // vendor economy. Read by the vendor bridge + the vendor modal.
-    match crate::embedded_data::read_data_or_embedded(data_dir, "trade_goods.ron") {
+    match modded.read("trade_goods.ron") {
         Some(text) => match crate::systems::economy::TradeGoodsRegistry::from_ron(text.as_bytes()) {
             Ok(reg) => {
                 log::info!("Loaded {} trade goods from trade_goods.ron", reg.len());
@@ -204,7 +258,38 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
         },
         None => log::warn!("trade_goods.ron not found (no embedded copy); vendors disabled"),
     }
-    match crate::embedded_data::read_data_or_embedded(data_dir, "vehicles/kits.ron") {
+    // Vendor markets: stock-driven prices per trading post. Restocked
+    // by EconomySystem, settled by the vendor bridge, saved with the home.
+    match modded.read("markets.ron") {
+        Some(text) => match crate::systems::economy::market::MarketConfig::from_ron(text.as_bytes()) {
+            Ok(config) => {
+                log::info!("Loaded {} market regions from markets.ron", config.regions.len());
+                store.insert(
+                    "vendor_markets",
+                    std::sync::Mutex::new(crate::systems::economy::market::VendorMarkets::new(config)),
+                );
+            }
+            Err(e) => log::warn!("Failed to parse markets.ron: {e}"),
+        },
+        None => log::warn!("markets.ron not found (no embedded copy); vendors trade at fixed prices"),
+    }
+    // Trait definitions for seed saving (crop genomes, cultivars).
+    match modded.read("genetics.ron") {
+        Some(text) => match crate::systems::genetics::GeneticsSystem::from_ron(&text) {
+            Ok(genetics) => store.insert("genetics", genetics),
+            Err(e) => log::warn!("Failed to parse genetics.ron: {e}"),
+        },
+        None => log::warn!("genetics.ron not found (no embedded copy); crops carry no genomes"),
+    }
+    // Offline catch-up limits, read by save_load::catch_up_active_home.
+    match modded.read("catch_up.ron") {
+        Some(text) => match crate::systems::catch_up::CatchUpConfig::from_ron(text.as_bytes()) {
+            Ok(config) => store.insert("catch_up_config", config),
+            Err(e) => log::warn!("Failed to parse catch_up.ron: {e}"),
+        },
+        None => log::warn!("catch_up.ron not found (no embedded copy); catch-up on defaults"),
+    }
+    match modded.read("vehicles/kits.ron") {
         Some(text) => match crate::systems::vehicles::VehicleKitRegistry::from_ron(text.as_bytes()) {
             Ok(reg) => {
                 log::info!("Loaded {} vehicle kits from vehicles/kits.ron", reg.len());
@@ -219,9 +304,8 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
     // Container types + content-class compatibility (disk-first, embedded fallback).
     {
         use crate::systems::inventory::containers::ContainerRegistry;
-        let types = crate::embedded_data::read_data_or_embedded(data_dir, "containers/types.csv");
-        let classes =
-            crate::embedded_data::read_data_or_embedded(data_dir, "containers/content_classes.ron");
+        let types = modded.read("containers/types.csv");
+        let classes = modded.read("containers/content_classes.ron");
         match (types, classes) {
             (Some(types_text), Some(classes_text)) => {
                 match ContainerRegistry::from_bytes(types_text.as_bytes(), classes_text.as_bytes()) {
@@ -241,4 +325,45 @@ pub(crate) fn load_data_registries(store: &mut DataStore, data_dir: &std::path::
             ),
         }
     }
+    // Quests load LAST so every file can be validated against the registries
+    // above plus the NPC roster (data/npcs.ron ids + names, the starter crew's
+    // names): a quest naming an unknown item, recipe, crop, blueprint,
+    // destination, creature, skill, NPC or faction (or a dangling quest-graph
+    // edge) is logged and rejected.
+    let mut npcs: Option<std::collections::HashSet<String>> = None;
+    for rel in ["npcs.ron", "npc/crew.ron"] {
+        match modded.read(rel) {
+            Some(text) => match crate::systems::quests::objectives::npc_keys_from_ron(&text) {
+                Ok(keys) => npcs.get_or_insert_with(Default::default).extend(keys),
+                Err(e) => log::warn!("Failed to parse {rel} for quest NPC checks: {e}"),
+            },
+            None => log::warn!("{rel} not found (no embedded copy); its NPCs are unknown to quests"),
+        }
+    }
+    let factions = match modded.read("factions.ron") {
+        Some(text) => crate::systems::quests::graph::ids_from_ron(&text)
+            .map_err(|e| log::warn!("Failed to parse factions.ron for quest standing checks: {e}"))
+            .ok(),
+        None => None,
+    };
+    let refs = crate::systems::quests::QuestRefs::from_data(store, npcs, factions);
+    let quest_files = modded.files_in("quests", "ron");
+    let mut quests = QuestRegistry::from_ron_files(
+        quest_files.into_iter().filter_map(|rel| Some((rel.clone(), modded.read(&rel)?))),
+    );
+    let rejected = quests.reject_invalid(&refs);
+    if !rejected.is_empty() {
+        log::warn!("{} quest(s) rejected by validation: {}", rejected.len(), rejected.join(", "));
+    }
+    store.insert("quest_registry", quests);
+    // Schema check (schemas/*.toml with a [validate] table): every covered
+    // base file and mod override, problems logged with file and line. The
+    // registries above already loaded; this names what they skipped.
+    let schema_errors = modded.log_schema_errors();
+    if schema_errors > 0 {
+        log::warn!("{schema_errors} schema problem(s) in data files (see above)");
+    }
+    modded.log_report();
+    store.insert("mod_merge_report", modded.report);
+    modded.resolution
 }
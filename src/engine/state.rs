@@ -448,10 +448,15 @@ pub(crate) struct EngineState {
     /// from the home_structure on every structural edit + on world load. Doors collide live.
     pub(crate) wall_colliders: Vec<crate::ship::wall_collision::WallSegment>,
     /// One-shot SFX queue (v0.983): gameplay sites push (catalog id, fallback
-    /// path) pairs; the audio frame-sync block drains and plays them through
-    /// the kira sfx bus. Keeps action code free of audio borrows and gives
-    /// every sound one catalog-driven play path.
-    pub(crate) pending_sfx: Vec<(&'static str, &'static str)>,
+    /// path, world position) triples; the audio frame-sync block drains and
+    /// plays them through the kira sfx bus (`engine::audio_mix`), spatially
+    /// when the catalog marks the sound spatial and a position is given.
+    /// Keeps action code free of audio borrows and gives every sound one
+    /// catalog-driven play path.
+    pub(crate) pending_sfx: Vec<(&'static str, &'static str, Option<glam::Vec3>)>,
+    /// Camera position at the last SFX drain, for the listener's doppler
+    /// velocity (None until the first frame).
+    pub(crate) audio_listener_prev: Option<glam::Vec3>,
     /// Static SIGHT-blocking segments (v0.975): the collision spans with window apertures cut
     /// open, so nameplates/machine cards hide behind solid walls but stay visible through glass
     /// and doorways. Rebuilt alongside `wall_colliders`; live closed doors are appended per
@@ -504,6 +509,9 @@ pub(crate) struct EngineState {
     /// Controllable-transfer path -- moving Controllable off the player would
     /// make extract_world_save find no player and wipe the periodic save.
     pub(crate) driving_vehicle: Option<hecs::Entity>,
+    /// The driven body's heading last frame: its change turns the
+    /// camera with the vehicle. None when not driving.
+    pub(crate) driving_heading: Option<f32>,
     /// Chase-cam target while a vehicle self-drives (Stage 3 follow mode).
     pub(crate) follow_vehicle: Option<hecs::Entity>,
     /// The vehicle the crosshair currently targets (within reach + look cone).
@@ -892,12 +900,6 @@ pub(crate) struct EngineState {
     /// The celestial pass only READS these (it holds planet borrows).
     pub(crate) cloud_event_boost: f32,
     pub(crate) cloud_event_tint: [f32; 3],
-    /// Active Vortex event's core, PLANET-FRAME metres (v0.1038); None
-    /// when no vortex event runs. Placed 200-600 m from the player when
-    /// the event fires; the HUD warns inside 3x the hazard radius.
-    pub(crate) event_core: Option<glam::DVec3>,
-    /// Edge-detect flag for the enter/leave hazard-zone logs.
-    pub(crate) event_hazard_near: bool,
     // egui integration
     pub(crate) egui_ctx: egui::Context,
     pub(crate) egui_state: egui_winit::State,
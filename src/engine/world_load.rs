@@ -1,7 +1,7 @@
 use glam::Vec3;
 use std::time::Instant;
 use crate::ecs::components::{Controllable, Health, Name, Transform, Velocity};
-use crate::engine::home_meshes::{apply_homestead_meshes, home_lights, machine_mesh, rebuild_connection_objects, rebuild_door_panels, rebuild_hull};
+use crate::engine::home_meshes::{apply_homestead_meshes, home_lights, machine_mesh, rebuild_connection_objects, rebuild_door_panels, rebuild_hull, machine_footprint, publish_nav_obstacles};
 use crate::engine::home_spawn::{spawn_home_air_space, spawn_home_machine_entity};
 use crate::engine::net_route::reload_planet_defs;
 use crate::engine::registries::load_data_registries;
@@ -116,11 +116,18 @@ pub(crate) fn load_world(state: &mut EngineState) {
         Some(ship) => crate::ship::wall_collision::ship_sight_segments(ship),
         None => Vec::new(),
     };
+    // The same walls, as obstacles creature pathfinding routes around.
+    let walls = state.wall_colliders.clone();
+    publish_nav_obstacles(state, |o| o.set_walls(walls));
     apply_homestead_meshes(state, homestead);
     // The hull wrap (ship-superstructure increment D): the exterior shell around the zone
     // cluster, generated from data/blueprints/hull_profile.ron. Ships with the world load;
     // rebuild_homestead regrows it on every structure edit.
     rebuild_hull(state);
+    // The home's rooms as a thermal network: walls, doors and roof as built right now.
+    if let Some(ship) = &state.gui_state.ship_structure {
+        crate::engine::home_spawn::spawn_home_thermal(&mut state.game_world.world, ship);
+    }
 
     // Room ceiling lights
     let auto_lights = room_info.iter().map(|r| {
@@ -177,6 +184,7 @@ pub(crate) fn load_world(state: &mut EngineState) {
     state.machine_objects.clear();
     state.grow_positions.clear();
 
+    let mut machine_state = Vec::new();
     // ── Machine layout (data-driven, v0.427) ──
     // Rudimentary primitives for the homestead machines + pipes/tubes for the
     // connections between them (data/machines/home.ron). Falls back silently if the
@@ -202,11 +210,15 @@ pub(crate) fn load_world(state: &mut EngineState) {
             state.gui_state.machine_labels.clear();
             // Despawn any previously-spawned home machine entities so re-entering the
             // world never duplicates the live power entities (load_world can re-run).
+            // Their live battery charge + tank fill carry over to the new set; the grid
+            // cables are replaced by spawn_home_grid below, which keeps their breaker state.
             {
+                machine_state = crate::save_load::machine_states(&state.game_world.world);
                 let old: Vec<hecs::Entity> = state
                     .game_world
                     .world
                     .query::<&crate::ecs::components::HomeMachine>()
+                    .without::<&crate::systems::power_grid::GridCable>()
                     .iter()
                     .map(|(e, _)| e)
                     .collect();
@@ -345,25 +357,47 @@ pub(crate) fn load_world(state: &mut EngineState) {
                 placed += 1;
             }
             log::info!("Machines: placed {placed} machines");
+            crate::engine::home_spawn::spawn_home_grid(&mut state.game_world.world, &home, &all_instances);
+            // Placed machines are solid to creature pathfinding.
+            let footprints = state
+                .grow_positions
+                .iter()
+                .map(|g| machine_footprint(g.pos.x, g.pos.z, g.size, g.yaw))
+                .collect();
+            publish_nav_obstacles(state, |o| o.set_solids(footprints));
 
             // ── Starter livestock (v0.751, ladder rung 7) ── farm animals
             // near the outdoor fields, from data/entities/livestock.ron rows
             // against creatures.csv species. Spawned READY to collect so a
-            // fresh homestead demonstrates the loop immediately. Idempotent
-            // across world reloads (previous herd despawns first).
+            // fresh homestead demonstrates the loop immediately. The herd
+            // (Husbandry animals) is the save's and survives reloads; the
+            // starter herd only spawns when there is none, and every other
+            // creature despawns first.
             {
                 let old: Vec<hecs::Entity> = state
                     .game_world
                     .world
                     .query::<&crate::ecs::components::Creature>()
+                    .without::<&crate::ecs::components::Husbandry>()
                     .iter()
                     .map(|(e, _)| e)
                     .collect();
                 for e in old {
                     let _ = state.game_world.world.despawn(e);
                 }
+                let has_herd = state
+                    .game_world
+                    .world
+                    .query::<&crate::ecs::components::Husbandry>()
+                    .iter()
+                    .next()
+                    .is_some();
+                let genetics = state
+                    .data_store
+                    .get::<crate::systems::genetics::GeneticsSystem>("genetics");
                 let mut bundles = Vec::new();
-                if let (Some(reg), Some(list)) = (
+                if let (false, Some(reg), Some(list)) = (
+                    has_herd,
                     state
                         .data_store
                         .get::<crate::systems::livestock::CreatureRegistry>("creature_registry"),
@@ -390,26 +424,47 @@ pub(crate) fn load_world(state: &mut EngineState) {
                             let a = i as f32 * 2.399_963 + pi as f32 * 1.7;
                             let r = p.spread * (0.45 + 0.55 * (i as f32 + 1.0) / p.count as f32);
                             let pos = anchor + Vec3::new(a.cos() * r, 0.0, a.sin() * r);
+                            let mut creature = crate::ecs::components::Creature {
+                                def_id: def.id.clone(),
+                                anchor,
+                                range: p.spread,
+                                phase: pi as f32 * 0.9 + i as f32 * 1.7,
+                                speed: (def.movement_speed * 0.35).max(0.2),
+                                tint: [p.tint.0, p.tint.1, p.tint.2],
+                                body_side: def.body_side(),
+                            };
+                            let mut harvestable = crate::ecs::components::Harvestable {
+                                resource: product.item.clone(),
+                                amount: product.amount as f32,
+                                regrow_time: product.regrow_s,
+                                time_since_harvest: product.regrow_s, // ready on arrival
+                            };
+                            // Founder stock, adult, dams and sires alternating.
+                            let husbandry = crate::ecs::components::Husbandry {
+                                hunger: 0.2,
+                                mood: 0.8,
+                                female: i % 2 == 0,
+                                genome: crate::systems::husbandry::founder(genetics),
+                                breed_cooldown: 0.0,
+                            };
+                            crate::systems::husbandry::apply_traits(
+                                genetics,
+                                husbandry.genome.as_ref(),
+                                &mut creature,
+                                Some(&mut harvestable),
+                            );
                             bundles.push((
-                                crate::ecs::components::Creature {
-                                    def_id: def.id.clone(),
-                                    anchor,
-                                    range: p.spread,
-                                    phase: pi as f32 * 0.9 + i as f32 * 1.7,
-                                    speed: (def.movement_speed * 0.35).max(0.2),
-                                    tint: [p.tint.0, p.tint.1, p.tint.2],
-                                    body_side: def.body_side(),
-                                },
+                                creature,
                                 Transform {
                                     position: pos,
                                     ..Default::default()
                                 },
                                 Name(def.name.clone()),
-                                crate::ecs::components::Harvestable {
-                                    resource: product.item.clone(),
-                                    amount: product.amount as f32,
-                                    regrow_time: product.regrow_s,
-                                    time_since_harvest: product.regrow_s, // ready on arrival
+                                harvestable,
+                                husbandry,
+                                crate::ecs::components::Age {
+                                    years: 0.1,
+                                    life_stage: crate::systems::husbandry::BREEDING_STAGE.into(),
                                 },
                                 // Combat arc (v0.760): animals are living
                                 // entities - real health from the species
@@ -675,6 +730,7 @@ pub(crate) fn load_world(state: &mut EngineState) {
 
     // The home's sealed air space for the live AtmosphereSystem readout (v0.617).
     spawn_home_air_space(&mut state.game_world.world);
+    crate::save_load::apply_machine_state(&mut state.game_world.world, &machine_state);
     // Build the live connection cylinders (replaces the old static routed pipes). (v0.530)
     rebuild_connection_objects(state);
     // Build the door/window panels from the home structure's openings. (v0.537)
@@ -1005,7 +1061,8 @@ pub(crate) fn load_world(state: &mut EngineState) {
     // The registries are loaded EAGERLY at startup (load_data_registries, called
     // from resumed) — see that fn for why. This call re-loads them when the 3D
     // world opens (idempotent), so editing a data file + re-entering picks it up.
-    load_data_registries(&mut state.data_store, state.asset_manager.data_dir());
+    state.gui_state.mod_resolution =
+        load_data_registries(&mut state.data_store, state.asset_manager.data_dir());
 
     // ── Player avatar + character-select showroom (v0.440/441) ──
     // Place a blockman avatar on a podium in the respawner (where you wake) and OPEN the
@@ -766,6 +766,8 @@ pub struct GuiWeather {
     /// Hazard proximity warning ("" = none, v0.1038): set while the
     /// player is within 3x a Vortex event's hazard radius.
     pub warning: String,
+    /// The next forecast extreme event ("Tornado in 4 min"; "" = none).
+    pub forecast: String,
 }
 
 /// Task priority levels for the task board.
@@ -1407,6 +1409,10 @@ pub struct GuiTradeGood {
     pub buy_price: i64,
     /// What the PLAYER RECEIVES selling one (vendor buy price, 0.5x base).
     pub sell_price: i64,
+    /// Units on this vendor's shelf (market stock).
+    pub stock: u32,
+    /// Daily mid-price samples at this vendor, oldest first (the chart).
+    pub history: Vec<f32>,
 }
 
 /// A buildable structure blueprint for GUI display (v0.746, ladder rung 2):
@@ -1472,6 +1478,12 @@ pub struct GuiCrop {
     pub water_per_day: f32,
     pub temp_min: f32,
     pub temp_max: f32,
+    /// The cultivar seed item this crop was sown from and its name; None /
+    /// empty for founder stock.
+    pub cultivar_seed: Option<String>,
+    pub cultivar: String,
+    /// Expressed genome (`breeding::Phenotype::describe`); empty without one.
+    pub genes: String,
 }
 
 /// An asteroid (with remaining ore) for GUI display.
@@ -1501,6 +1513,8 @@ pub struct GuiVehicle {
     pub distance: f32,
     /// True while a VehicleRoute is attached (driving itself somewhere).
     pub in_transit: bool,
+    /// (litres, tank) for vehicles with a fuel tank.
+    pub fuel: Option<(f32, f32)>,
 }
 
 /// An active mining drone for GUI display.
@@ -2939,8 +2953,16 @@ pub struct GuiState {
     /// True when the pinned machine is a trading post (v0.747, ladder rung 3):
     /// the card shows a Trade button that opens the vendor modal.
     pub machine_card_vendor: bool,
+    /// The pinned trading post: (machine instance id, zone). The zone is the
+    /// market region its prices follow.
+    pub machine_card_vendor_post: (String, String),
     /// Vendor modal open (v0.747). Opened from the trading post's card.
     pub vendor_open: bool,
+    /// The trading post the open modal trades with (copied from the card on
+    /// Trade, so walking off the card doesn't switch vendors mid-trade).
+    pub vendor_post: (String, String),
+    /// Good whose price history the modal charts.
+    pub vendor_selected: Option<String>,
     /// The vendor's catalog: goods that exist in BOTH trade_goods.ron and
     /// items.csv, with the player-facing prices (pay / receive).
     pub vendor_goods: Vec<GuiTradeGood>,
@@ -3105,10 +3127,9 @@ pub struct GuiState {
     /// game_time when ability_status was set; lib.rs fades it after a few
     /// seconds so the HUD line does not linger forever. (v0.754)
     pub ability_status_at: f64,
-    /// F pressed: swing the held tool (or bare hands) at the faced creature.
+    /// F pressed: swing or fire the held weapon (or bare hands); lib.rs
+    /// bridges it onto WeaponSystem's attack_request channel, which paces it.
     pub pending_swing: bool,
-    /// game_time when the next swing is allowed (0.8s between swings). (v0.765)
-    pub swing_ready_at: f64,
 
     pub listings: Vec<GuiListing>,
     /// Set once listing_browse has been sent this connection; cleared on
@@ -3303,6 +3324,14 @@ pub struct GuiState {
     /// (machine_id, plant_id, unit count) — one CropInstance per unit, tagged
     /// with the machine id as its grow-area; drained into "plant_bed_request".
     pub pending_plant_bed: Option<(String, String, u32)>,
+    /// GUI -> ECS: work a soil amendment into a bed/tray/field (machine id,
+    /// item id, unit count); drained into "amend_soil_request".
+    pub pending_amend_soil: Option<(String, String, u32)>,
+    /// Crop entity bits the player clicked "Save seed" on; drained into
+    /// "save_seed_request" (harvests it and starts a new cultivar).
+    pub pending_save_seed: Option<u64>,
+    /// (cultivar seed item id, new name); drained into "name_cultivar_request".
+    pub pending_name_cultivar: Option<(String, String)>,
     /// Seed item ids to grant the player (the "Dev: stock seeds" starter set);
     /// drained into "stock_seeds_request" for FarmingSystem.
     pub pending_stock_seeds: Option<Vec<String>>,
@@ -3317,6 +3346,9 @@ pub struct GuiState {
     pub dev_grow_crops: bool,
     /// Growing crops, synced from the ECS each frame for the Garden panel.
     pub crops: Vec<GuiCrop>,
+    /// Mean soil chemistry per grow area (machine id), synced from the ECS
+    /// soil plots each frame for the garden edit modal's readout.
+    pub soil: std::collections::HashMap<String, crate::systems::farming::soil::Soil>,
 
     // ── Mining / drones state ──
     /// Set the frame the player clicks "Launch drone" → bridged to DroneSystem's
@@ -3346,6 +3378,9 @@ pub struct GuiState {
     /// GUI -> ECS: summon this vehicle (entity bits) to drive itself to the
     /// player; bridged into the "summon_vehicle" channel.
     pub pending_summon_vehicle: Option<u64>,
+    /// GUI -> ECS: top this vehicle's tank up from the backpack;
+    /// bridged into the "refuel_vehicle" channel.
+    pub pending_refuel_vehicle: Option<u64>,
     /// Cosmos -> Inventory jump (unified map slice 2): an asteroid id whose
     /// mining modal should open when the Inventory page next draws.
     pub pending_open_mining_modal: Option<String>,
@@ -3372,6 +3407,12 @@ pub struct GuiState {
     /// Quest id the player clicked Accept on; the frame bridge applies it to
     /// the ECS QuestTracker.
     pub pending_accept_quest: Option<String>,
+    /// Active quests waiting at a branch fork; the NPC talk card offers
+    /// their options when talking to the quest's `choice_npc`.
+    pub quest_choices: Vec<crate::systems::quests::PendingChoice>,
+    /// (quest id, branch id) picked on the talk card; the frame bridge
+    /// pushes it as a `QuestEvent::Chose`.
+    pub pending_quest_choice: Option<(String, String)>,
 
     // ── Guilds state (live from the relay's REST guild API, v0.757) ──
     pub guilds: Vec<GuiGuild>,
@@ -3418,6 +3459,16 @@ pub struct GuiState {
     pub power_battery_wh: f32,
     pub power_battery_capacity_wh: f32,
     pub power_autonomy_hours: f32,
+    /// Live cable grid: line loss, sag and open / overloaded runs, mirrored each frame.
+    pub power_grid: crate::systems::power_grid::GridReport,
+    /// The Home page's "Reset breakers" click, forwarded to the ElectricalSystem by the frame loop.
+    pub grid_reset_requested: bool,
+    /// Fleet logistics: holds, shipments in transit and standing orders, mirrored from
+    /// TransportationSystem each frame.
+    pub logistics: crate::systems::transportation::LogisticsReport,
+    /// Home page fleet-card clicks (ship now / order pause / cancel), drained into the
+    /// `logistics_request` channel by the frame loop.
+    pub logistics_requests: Vec<crate::systems::transportation::LogisticsRequest>,
     /// Live home WATER readout (v0.608), mirrored from PlumbingSystem each frame: production + demand
     /// (L/min), stored + capacity (litres), and days of autonomy at the current demand.
     pub water_production_lpm: f32,
@@ -3573,6 +3624,11 @@ pub struct GuiState {
     pub diag_light_count: usize,
     pub diag_mem_mb: f32,
     pub diag_uptime_secs: u64,
+    /// Installed mods as resolved at startup (load order, disabled mods and
+    /// why), shown in the F4 System overlay.
+    pub mod_resolution: crate::mods::ModResolution,
+    /// What the offline catch-up did at startup; the Home page shows it until dismissed.
+    pub away_report: Option<crate::systems::catch_up::AwayReport>,
     /// Index into construction_rooms of the room selected/grabbed in the 3D astral editor, for
     /// the highlight tint. None = no selection. (v0.466)
     pub construction_selected_room: Option<usize>,
@@ -3764,6 +3820,9 @@ pub struct GuiState {
     /// Index of the interior wall currently selected in the editor (for remove / opening edits), or
     /// None. (v0.534)
     pub construction_wall_selected: Option<usize>,
+    /// Every zone framed + solved on the last rebuild (indexed like `ship_structure.zones`):
+    /// the editor outlines strained/broken members from it and the left panel shows the verdict.
+    pub construction_framing: Vec<crate::systems::construction::framing::FramingReport>,
     /// Id of the machine currently selected in the editor (clicked in the viewport or the list), or
     /// None. Mutually exclusive with construction_wall_selected -- the right panel shows whichever is
     /// set. (v0.553)
@@ -5022,7 +5081,10 @@ impl Default for GuiState {
             machine_card_storable: Vec::new(),
             machine_card_store_pending: None,
             machine_card_vendor: false,
+            machine_card_vendor_post: (String::new(), String::new()),
             vendor_open: false,
+            vendor_post: (String::new(), String::new()),
+            vendor_selected: None,
             vendor_goods: Vec::new(),
             vendor_status: String::new(),
             wallet_credits: 0,
@@ -5089,7 +5151,6 @@ impl Default for GuiState {
             ability_status: String::new(),
             ability_status_at: 0.0,
             pending_swing: false,
-            swing_ready_at: 0.0,
             listings: Vec::new(),
             listings_synced: false,
             listing_status: String::new(),
@@ -5191,12 +5252,16 @@ impl Default for GuiState {
             pending_plant_seed: None,
             pending_plant_tower: None,
             pending_plant_bed: None,
+            pending_amend_soil: None,
+            pending_save_seed: None,
+            pending_name_cultivar: None,
             pending_stock_seeds: None,
             pending_water_crop: None,
             pending_harvest_crop: None,
             pending_harvest_many: Vec::new(),
             dev_grow_crops: false,
             crops: Vec::new(),
+            soil: std::collections::HashMap::new(),
             pending_drone_manifest: None,
             auto_mine_enabled: false,
             last_drone_order: None,
@@ -5206,6 +5271,7 @@ impl Default for GuiState {
             drones: Vec::new(),
             vehicles: Vec::new(),
             pending_summon_vehicle: None,
+            pending_refuel_vehicle: None,
             pending_open_mining_modal: None,
             pending_follow_vehicle: None,
             factory_status: Vec::new(),
@@ -5214,6 +5280,8 @@ impl Default for GuiState {
             quests: Vec::new(),
             quests_available: Vec::new(),
             pending_accept_quest: None,
+            quest_choices: Vec::new(),
+            pending_quest_choice: None,
 
             // Guilds defaults
             guilds: Vec::new(),
@@ -5241,6 +5309,10 @@ impl Default for GuiState {
             power_battery_wh: 0.0,
             power_battery_capacity_wh: 0.0,
             power_autonomy_hours: 0.0,
+            power_grid: Default::default(),
+            logistics: Default::default(),
+            logistics_requests: Vec::new(),
+            grid_reset_requested: false,
             power_consumption: 0.0,
             power_balance: 0.0,
             water_production_lpm: 0.0,
@@ -5310,6 +5382,8 @@ impl Default for GuiState {
             diag_light_count: 0,
             diag_mem_mb: 0.0,
             diag_uptime_secs: 0,
+            mod_resolution: Default::default(),
+            away_report: None,
             keymaps: Vec::new(),
             construction_selected_room: None,
             construction_height: 3.0,
@@ -5348,6 +5422,7 @@ impl Default for GuiState {
             construction_wall_start: None,
             construction_cursor_world: None,
             construction_wall_selected: None,
+            construction_framing: Vec::new(),
             construction_machine_selected: None,
             construction_light_selected: None,
             construction_light_ring_hover: None,
@@ -6312,6 +6387,8 @@ pub enum GrowControl {
     Crop { label: String, hint: String },
     /// A checkbox stored under `key`.
     Toggle { key: String, label: String },
+    /// A button that works one `item` (soil_amendments.csv) into every unit.
+    Amend { item: String, label: String },
 }
 
 /// A grow MEDIUM: a way crops are grown (aeroponic, soil bed, field, ...), matched to a
@@ -7279,6 +7356,9 @@ pub struct SettingsState {
     /// Survival-needs speed: scales hunger/thirst/energy decay in the food
     /// system (1.0 = normal, 0 = paused). v0.791, with slowed base rates.
     pub vitals_drain: f32,
+    /// Save the home and its autosaves as binary `.hsav` instead of JSON
+    /// (Settings > Gameplay). See `save_format`.
+    pub binary_saves: bool,
     /// Play mode (task #50): Normal | Creative | Dev -- one ladder for every
     /// cheat/scope gate (see `crate::config::PlayMode` + `Capability` for the
     /// tested truth table). Persisted in AppConfig; edited as radios in
@@ -7373,6 +7453,7 @@ impl Default for SettingsState {
             home_variant: "home".to_string(),
             hostile_wildlife: false,
             vitals_drain: 1.0,
+            binary_saves: false,
             play_mode: crate::config::PlayMode::default(),
             profile_visible: true,
             online_status_visible: true,
@@ -7384,6 +7465,18 @@ impl Default for SettingsState {
     }
 }
 
+#[cfg(feature = "native")]
+impl SettingsState {
+    /// The on-disk format the home save and autosaves are written in.
+    pub fn save_format(&self) -> crate::persistence::SaveFormat {
+        if self.binary_saves {
+            crate::persistence::SaveFormat::Binary
+        } else {
+            crate::persistence::SaveFormat::Json
+        }
+    }
+}
+
 #[cfg(all(test, feature = "native"))]
 mod ui_click_sound_tests {
     use super::ui_click_should_sound;
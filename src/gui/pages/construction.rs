@@ -699,6 +699,7 @@ fn draw_wall_editor(ctx: &Context, theme: &Theme, state: &mut GuiState) {
                     ui.label(RichText::new(format!("Fixed box  {:.0} x {:.0} x {:.0} m", hs.width, hs.depth, hs.height))
                         .size(theme.font_size_small).color(theme.text_muted()));
                 }
+                draw_structural_status(ui, theme, state);
                 ui.add_space(theme.spacing_sm);
 
                 // Wall drawing + all structural pieces moved to the footer "Structure" palette
@@ -953,7 +954,8 @@ fn draw_wall_editor(ctx: &Context, theme: &Theme, state: &mut GuiState) {
                         });
                 });
                 if let Some(m) = crate::ship::home_structure::wall_material(wall.material) {
-                    ui.label(RichText::new(format!("Density {:.0} kg/m3   Tensile {:.0} MPa", m.density_kg_m3, m.tensile_mpa))
+                    ui.label(RichText::new(format!("Density {:.0} kg/m3   Tensile {:.0} MPa   k {:.3} W/mK",
+                            m.density_kg_m3, m.tensile_mpa, m.conductivity_w_mk))
                         .size(theme.font_size_small).color(theme.text_muted()));
                     ui.label(RichText::new(format!("Cost {:.2}/kg   {}", m.cost_per_kg, if m.renewable { "renewable" } else { "non-renewable" }))
                         .size(theme.font_size_small).color(theme.text_muted()));
@@ -983,8 +985,9 @@ fn draw_wall_editor(ctx: &Context, theme: &Theme, state: &mut GuiState) {
                 // Layer 1 is the EXPOSED face -- it drives the rendered colour. Add/remove/reorder.
                 ui.add_space(theme.spacing_sm);
                 ui.label(RichText::new(format!("Surface layers ({})", wall.layers.len())).strong().color(theme.text_secondary()));
-                ui.label(RichText::new(format!("Total {:.0} cm with layers; exposed = {}",
+                ui.label(RichText::new(format!("Total {:.0} cm with layers, R {:.2} m2K/W; exposed = {}",
                         wall.total_thickness() * 100.0,
+                        wall.r_value(),
                         crate::ship::home_structure::wall_material(wall.exposed_material()).map(|m| m.name.clone()).unwrap_or_default()))
                     .size(theme.font_size_small).color(theme.text_muted()));
                 let mut rm_layer: Option<usize> = None;
@@ -1614,6 +1617,35 @@ const CONSOLE_VERBS: &[ConsoleVerb] = &[
     ConsoleVerb { usage: "rm_road <n>", desc: "Remove road edge #n (1-based)." },
 ];
 
+/// The active zone's structural verdict from the last rebuild: "Structure sound", or which
+/// walls and pieces are strained or failing, plus any design faults (a door wider than its wall).
+/// The viewport outlines the same members amber / red. In survival the failures have already come
+/// down by the time this draws; in creative they stand, flagged, until fixed.
+fn draw_structural_status(ui: &mut egui::Ui, theme: &Theme, state: &GuiState) {
+    use crate::systems::construction::solver::StructuralVerdict;
+    let (Some(report), Some(hs)) = (
+        state.construction_framing.get(state.construction_zone),
+        zone_body(&state.ship_structure, state.construction_zone),
+    ) else {
+        return;
+    };
+    let names = |parts: Vec<crate::systems::construction::framing::FramingPart>| {
+        parts.iter().map(|p| p.label(hs)).collect::<Vec<_>>().join(", ")
+    };
+    let (text, color) = match report.verdict() {
+        StructuralVerdict::Stable => ("Structure sound".to_string(), theme.success()),
+        StructuralVerdict::Unstable if report.failed_parts().is_empty() => {
+            (format!("Strained: {}", names(report.strained_parts())), theme.warning())
+        }
+        StructuralVerdict::Unstable => (format!("Overloaded: {}", names(report.failed_parts())), theme.danger()),
+        StructuralVerdict::Collapsed => (format!("Will collapse: {}", names(report.failed_parts())), theme.danger()),
+    };
+    ui.label(RichText::new(text).size(theme.font_size_small).color(color));
+    for issue in &report.issues {
+        ui.label(RichText::new(&issue.message).size(theme.font_size_small).color(theme.danger()));
+    }
+}
+
 /// SHIP ZONE selector (v0.754, ship-superstructure increment A), at the top of the Home structure
 /// panel: a combo of the ship's zones (which one the editor is EDITING -- every tool operates on
 /// it), an "Add zone" button (a modest default 10 x 10 x 3 m box placed clear of existing zones),
@@ -4020,6 +4052,8 @@ mod multi_select_tests {
             auto_recipe: None,
             container_type: None,
             model: None,
+            hvac: None,
+            facility: None,
         }
     }
 
@@ -4031,7 +4065,7 @@ mod multi_select_tests {
             catalog,
             instances: vec![inst("a"), inst("b"), inst("c")],
             arrays: Vec::new(),
-            connections: vec![MachineConnection { from: "a".into(), to: "b".into(), kind: "power".into(), spec: None }],
+            connections: vec![MachineConnection { from: "a".into(), to: "b".into(), kind: "power".into(), spec: None, protection: None }],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
             conduit_edges: Vec::new(),
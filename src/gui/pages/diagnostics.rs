@@ -2,7 +2,7 @@
 //! toggled by F-keys and stacked in the top-right corner:
 //!   F2 = Performance (FPS, frame time, a frame-time sparkline, entity count)
 //!   F3 = Network     (connection, server, members online, messages received)
-//!   F4 = System      (process RAM, uptime, build version)
+//!   F4 = System      (process RAM, uptime, build version, mods)
 //! All read from GuiState fields sampled in lib.rs (see show_*_overlay). The F1
 //! keymap lists the toggles so they are discoverable. Display-only.
 
@@ -182,6 +182,15 @@ fn draw_system(ui: &mut egui::Ui, theme: &Theme, state: &GuiState) {
     row(ui, theme, "RAM", format!("{:.0} MB", state.diag_mem_mb));
     row(ui, theme, "Uptime", fmt_uptime(state.diag_uptime_secs));
     row(ui, theme, "Version", format!("v{}", env!("CARGO_PKG_VERSION")));
+    let mods = &state.mod_resolution;
+    if mods.order.is_empty() && mods.disabled.is_empty() {
+        return;
+    }
+    row(ui, theme, "Mods", format!("{} on, {} off", mods.order.len(), mods.disabled.len()));
+    for problem in &mods.problems {
+        let color = if problem.is_fatal() { theme.danger() } else { theme.warning() };
+        ui.label(RichText::new(problem.to_string()).size(theme.font_size_small).color(color));
+    }
 }
 
 /// Seconds -> "1h 02m", "5m 03s", or "12s".
@@ -224,6 +224,19 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
                 );
                 return;
             };
+            if let Some(report) = &state.away_report {
+                if draw_away_report(ui, theme, report) {
+                    state.away_report = None;
+                }
+            }
+            if !state.power_grid.faults.is_empty() && draw_grid_faults(ui, theme, &state.power_grid) {
+                state.grid_reset_requested = true;
+            }
+            if !state.logistics.nodes.is_empty() {
+                if let Some(req) = draw_fleet_logistics(ui, theme, &state.logistics) {
+                    state.logistics_requests.push(req);
+                }
+            }
             let power = LivePower {
                 gen: state.power_generation,
                 usage: state.power_consumption,
@@ -231,6 +244,8 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
                 battery_wh: state.power_battery_wh,
                 capacity_wh: state.power_battery_capacity_wh,
                 autonomy: state.power_autonomy_hours,
+                line_loss: state.power_grid.line_loss_w,
+                worst_sag: state.power_grid.worst_sag_pct,
             };
             let water = LiveWater {
                 production: state.water_production_lpm,
@@ -250,6 +265,221 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
         });
 }
 
+/// "While you were away": what the offline catch-up did to the home at startup
+/// (`systems::catch_up`). Returns true when the player dismisses it.
+fn draw_away_report(ui: &mut egui::Ui, theme: &Theme, report: &crate::systems::catch_up::AwayReport) -> bool {
+    let mut dismissed = false;
+    widgets::card(ui, theme, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("While you were away").size(theme.font_size_body).strong().color(theme.accent()));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                dismissed = widgets::Button::secondary("Dismiss").show(ui, theme);
+            });
+        });
+        ui.add_space(theme.spacing_xs);
+        for line in report.lines() {
+            ui.label(RichText::new(line).size(theme.font_size_small).color(theme.text_secondary()));
+        }
+    });
+    ui.add_space(theme.spacing_sm);
+    dismissed
+}
+
+/// Wiring faults from the live cable grid (`systems::power_grid`): open runs first, then runs that
+/// are overloaded or sagging. Returns true when the player resets the breakers / replaces the fuses.
+fn draw_grid_faults(ui: &mut egui::Ui, theme: &Theme, report: &crate::systems::power_grid::GridReport) -> bool {
+    let mut reset = false;
+    widgets::card(ui, theme, |ui| {
+        ui.horizontal(|ui| {
+            let title = if report.open_runs > 0 { "Wiring faults" } else { "Wiring warnings" };
+            ui.label(RichText::new(title).size(theme.font_size_body).strong().color(theme.danger()));
+            if report.open_runs > 0 {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    reset = widgets::Button::secondary("Reset breakers / replace fuses").show(ui, theme);
+                });
+            }
+        });
+        ui.add_space(theme.spacing_xs);
+        let mut faults: Vec<&crate::systems::power_grid::GridFault> = report.faults.iter().collect();
+        faults.sort_by_key(|f| !f.open);
+        for f in faults {
+            let color = if f.open { theme.danger() } else { theme.warning() };
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(&f.run).size(theme.font_size_small).strong().color(color));
+                ui.label(RichText::new(&f.detail).size(theme.font_size_small).color(theme.text_secondary()));
+            });
+        }
+    });
+    ui.add_space(theme.spacing_sm);
+    reset
+}
+
+/// The fleet card's "Ship cargo" form (page-local, like the build scale).
+#[derive(Default)]
+struct ShipForm {
+    from: String,
+    to: String,
+    item: String,
+    qty: u32,
+}
+
+fn with_ship_form<R>(f: impl FnOnce(&mut ShipForm) -> R) -> R {
+    thread_local! {
+        static FORM: RefCell<ShipForm> = RefCell::new(ShipForm { qty: 10, ..Default::default() });
+    }
+    FORM.with(|s| f(&mut s.borrow_mut()))
+}
+
+/// Fleet logistics (`systems::transportation`): the fleet-wide pool totalled from the ships' holds,
+/// every hold against its capacity, cargo in transit, standing orders and a form to ship by hand.
+/// Returns the player's click as a request for the TransportationSystem.
+fn draw_fleet_logistics(
+    ui: &mut egui::Ui,
+    theme: &Theme,
+    report: &crate::systems::transportation::LogisticsReport,
+) -> Option<crate::systems::transportation::LogisticsRequest> {
+    use crate::systems::transportation::LogisticsRequest;
+    let mut request = None;
+    let name_of = |id: &str| -> String {
+        report.nodes.iter().find(|n| n.id == id).map(|n| n.name.clone()).unwrap_or_else(|| id.to_string())
+    };
+    widgets::card(ui, theme, |ui| {
+        egui::CollapsingHeader::new(
+            RichText::new(format!("Fleet logistics -- {} in transit", report.shipments.len()))
+                .size(theme.font_size_body)
+                .strong()
+                .color(theme.text_primary()),
+        )
+        .id_salt("fleet_logistics")
+        .show(ui, |ui| {
+            // Fleet pool: the ships' holds added up, biggest stacks first.
+            let mut pool: Vec<(&String, &f64)> = report.fleet.resources.iter().filter(|(_, q)| **q > 0.0).collect();
+            pool.sort_by(|a, b| b.1.total_cmp(a.1).then(a.0.cmp(b.0)));
+            let pool_line = pool.iter().take(8).map(|(id, q)| format!("{} {}", humanize(id), **q as u32)).collect::<Vec<_>>().join(", ");
+            ui.label(RichText::new("Fleet pool").size(theme.font_size_small).strong().color(theme.text_secondary()));
+            ui.label(RichText::new(if pool_line.is_empty() { "Empty".to_string() } else { pool_line }).size(theme.font_size_small).color(theme.text_muted()));
+            ui.add_space(theme.spacing_xs);
+
+            for node in &report.nodes {
+                let fill = if node.capacity_kg > 0.0 { node.mass_kg / node.capacity_kg } else { 0.0 };
+                ui.horizontal(|ui| {
+                    let icon = if node.kind == "ship" { "Ship" } else { "Depot" };
+                    ui.label(RichText::new(format!("{icon}: {}", node.name)).size(theme.font_size_small).strong().color(theme.text_primary()));
+                    let color = if fill > 0.95 { theme.warning() } else { theme.text_muted() };
+                    ui.label(RichText::new(format!("{:.0} / {:.0} kg", node.mass_kg, node.capacity_kg)).size(theme.font_size_small).color(color));
+                });
+                let top = node.cargo.iter().take(6).map(|(id, q)| format!("{} {q}", humanize(id))).collect::<Vec<_>>().join(", ");
+                if !top.is_empty() {
+                    ui.label(RichText::new(top).size(theme.font_size_small).color(theme.text_muted()));
+                }
+            }
+
+            if !report.shipments.is_empty() {
+                ui.add_space(theme.spacing_xs);
+                ui.label(RichText::new("In transit").size(theme.font_size_small).strong().color(theme.text_secondary()));
+                for s in &report.shipments {
+                    ui.horizontal(|ui| {
+                        let label = if s.arrived { "waiting for room" } else { "en route" };
+                        ui.label(RichText::new(format!("{} -> {} ({label})", name_of(&s.from), name_of(&s.to))).size(theme.font_size_small).color(theme.text_primary()));
+                        ui.add(egui::ProgressBar::new(s.progress.clamp(0.0, 1.0)).desired_width(90.0));
+                    });
+                    let cargo = s.cargo.iter().map(|(id, q)| format!("{} {q}", humanize(id))).collect::<Vec<_>>().join(", ");
+                    ui.label(RichText::new(cargo).size(theme.font_size_small).color(theme.text_muted()));
+                }
+            }
+
+            if !report.orders.is_empty() {
+                ui.add_space(theme.spacing_xs);
+                ui.label(RichText::new("Standing orders").size(theme.font_size_small).strong().color(theme.text_secondary()));
+                for o in &report.orders {
+                    ui.horizontal(|ui| {
+                        let color = if o.paused { theme.text_muted() } else { theme.text_primary() };
+                        ui.label(
+                            RichText::new(format!(
+                                "Keep {} {} at {} from {} (lots of {}, every {:.1} d)",
+                                o.keep, humanize(&o.item), name_of(&o.to), name_of(&o.from), o.lot, o.every_days
+                            ))
+                            .size(theme.font_size_small)
+                            .color(color),
+                        );
+                        if widgets::Button::secondary(if o.paused { "Resume" } else { "Pause" }).show(ui, theme) {
+                            request = Some(LogisticsRequest::ToggleOrder(o.id.clone()));
+                        }
+                        if widgets::Button::secondary("Cancel").show(ui, theme) {
+                            request = Some(LogisticsRequest::CancelOrder(o.id.clone()));
+                        }
+                    });
+                }
+            }
+
+            // Ship by hand: origin, a destination the routes reach, then a stack from the origin's hold.
+            ui.add_space(theme.spacing_xs);
+            ui.label(RichText::new("Ship cargo").size(theme.font_size_small).strong().color(theme.text_secondary()));
+            with_ship_form(|form| {
+                let mut origins: Vec<&String> = report.routes.iter().flat_map(|(a, b)| [a, b]).collect();
+                origins.sort();
+                origins.dedup();
+                let dests: Vec<&String> = report
+                    .routes
+                    .iter()
+                    .filter_map(|(a, b)| if *a == form.from { Some(b) } else if *b == form.from { Some(a) } else { None })
+                    .collect();
+                if !dests.iter().any(|d| **d == form.to) {
+                    form.to = dests.first().map(|d| d.to_string()).unwrap_or_default();
+                }
+                let held: &[(String, u32)] = report.nodes.iter().find(|n| n.id == form.from).map(|n| n.cargo.as_slice()).unwrap_or(&[]);
+                if !held.iter().any(|(id, _)| *id == form.item) {
+                    form.item = held.first().map(|(id, _)| id.clone()).unwrap_or_default();
+                }
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("fleet_ship_from")
+                        .selected_text(if form.from.is_empty() { "From...".to_string() } else { name_of(&form.from) })
+                        .show_ui(ui, |ui| {
+                            for id in &origins {
+                                ui.selectable_value(&mut form.from, id.to_string(), name_of(id));
+                            }
+                        });
+                    egui::ComboBox::from_id_salt("fleet_ship_to")
+                        .selected_text(if form.to.is_empty() { "To...".to_string() } else { name_of(&form.to) })
+                        .show_ui(ui, |ui| {
+                            for id in &dests {
+                                ui.selectable_value(&mut form.to, id.to_string(), name_of(id));
+                            }
+                        });
+                });
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("fleet_ship_item")
+                        .selected_text(if form.item.is_empty() { "Item...".to_string() } else { humanize(&form.item) })
+                        .show_ui(ui, |ui| {
+                            for (id, q) in held {
+                                ui.selectable_value(&mut form.item, id.clone(), format!("{} ({q})", humanize(id)));
+                            }
+                        });
+                    let have = held.iter().find(|(id, _)| *id == form.item).map(|(_, q)| *q).unwrap_or(0);
+                    ui.add(egui::DragValue::new(&mut form.qty).range(1..=have.max(1)));
+                    let ready = !form.to.is_empty() && have > 0;
+                    if ui.add_enabled_ui(ready, |ui| widgets::Button::primary("Send").show(ui, theme)).inner {
+                        request = Some(LogisticsRequest::Ship {
+                            from: form.from.clone(),
+                            to: form.to.clone(),
+                            cargo: vec![(form.item.clone(), form.qty.min(have))],
+                        });
+                    }
+                });
+            });
+
+            if !report.log.is_empty() {
+                ui.add_space(theme.spacing_xs);
+                for line in report.log.iter().rev().take(5) {
+                    ui.label(RichText::new(line).size(theme.font_size_small).color(theme.text_muted()));
+                }
+            }
+        });
+    });
+    ui.add_space(theme.spacing_sm);
+    request
+}
+
 /// Live power readout from the running sim (SolarSystem + ElectricalSystem -> PowerStatus
 /// -> GuiState), passed into `draw_design` (which has no `&GuiState`). Zero in a build
 /// with no home.ron -> the Live power card hides itself.
@@ -261,6 +491,9 @@ struct LivePower {
     battery_wh: f32,
     capacity_wh: f32,
     autonomy: f32,
+    /// Cable loss (W) and worst load-end sag (%) from the grid solve.
+    line_loss: f32,
+    worst_sag: f32,
 }
 
 /// Live WATER readout from the running sim (PlumbingSystem -> WaterStatus -> GuiState), passed into
@@ -408,6 +641,14 @@ fn draw_design(
                         &format!("{:.0}%  ({:.1} kWh)  ~{:.1} h autonomy", pct, live_battery_wh / 1000.0, live_autonomy),
                     );
                 }
+                if power.line_loss > 0.0 {
+                    widgets::detail_row(
+                        ui,
+                        theme,
+                        "Cable loss",
+                        &format!("{:.0} W  (worst sag {:.1}%)", power.line_loss, power.worst_sag),
+                    );
+                }
             });
             ui.add_space(theme.spacing_sm);
         }
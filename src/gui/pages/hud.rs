@@ -241,6 +241,19 @@ pub fn draw(
                         theme.danger(),
                     );
                 }
+                // Forecast: the next extreme event and when it
+                // lands, so there is time to get indoors.
+                if !w.forecast.is_empty() {
+                    let y = if w.warning.is_empty() { 60.0 } else { 74.0 };
+                    text_shadowed(
+                        painter,
+                        Pos2::new(screen.right() - 16.0, y),
+                        Align2::RIGHT_TOP,
+                        &format!("Forecast: {}", w.forecast),
+                        11.0,
+                        theme.warning(),
+                    );
+                }
             }
 
             // ── Power balance (live home electrical sim, below weather) ──
@@ -1195,13 +1208,14 @@ pub fn draw_machine_recipe_selector(ctx: &egui::Context, theme: &Theme, state: &
                     });
                 }
                 // Trade (v0.747, ladder rung 3): the trading post's card opens
-                // the vendor modal (buy at 125% of base, sell at 50%).
+                // the vendor modal; prices follow this post's stock.
                 if state.machine_card_vendor {
                     if crate::gui::widgets::Button::primary("Trade")
                         .tooltip("Buy and sell goods for credits.")
                         .show(ui, theme)
                     {
                         state.vendor_open = true;
+                        state.vendor_post = state.machine_card_vendor_post.clone();
                         state.vendor_status.clear();
                     }
                 }
@@ -1224,6 +1238,7 @@ pub fn draw_npc_talk_card(ctx: &egui::Context, theme: &Theme, state: &mut GuiSta
     }
     let mut close = false;
     let mut more = false;
+    let mut picked: Option<(String, String)> = None;
     let screen = ctx.screen_rect();
     let area = Area::new(egui::Id::new("npc_talk_card"))
         .fixed_pos(Pos2::new(
@@ -1258,6 +1273,21 @@ pub fn draw_npc_talk_card(ctx: &egui::Context, theme: &Theme, state: &mut GuiSta
                             .size(theme.font_size_body)
                             .color(theme.text_primary()),
                     );
+                    // Quest forks this NPC offers: one button per branch.
+                    // Picking one is final (the branches are exclusive).
+                    let npc_key = crate::systems::quests::npc_talk_key(&state.npc_talk_name);
+                    for choice in state
+                        .quest_choices
+                        .iter()
+                        .filter(|c| c.npc_id.is_empty() || c.npc_id == npc_key)
+                    {
+                        ui.add_space(theme.spacing_xs);
+                        for (branch_id, text) in &choice.options {
+                            if crate::gui::widgets::Button::secondary(text).show(ui, theme) {
+                                picked = Some((choice.quest_id.clone(), branch_id.clone()));
+                            }
+                        }
+                    }
                     ui.add_space(theme.spacing_xs);
                     ui.separator();
                     ui.horizontal(|ui| {
@@ -1292,6 +1322,10 @@ pub fn draw_npc_talk_card(ctx: &egui::Context, theme: &Theme, state: &mut GuiSta
     if more {
         state.npc_talk_advance();
     }
+    if let Some(choice) = picked {
+        state.quest_choices.retain(|c| c.quest_id != choice.0);
+        state.pending_quest_choice = Some(choice);
+    }
     if close || clicked_outside {
         state.npc_talk_target = None;
     }
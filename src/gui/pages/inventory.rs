@@ -193,6 +193,9 @@ struct GardenEditState {
     /// machine id of the grow area whose edit modal is open.
     open: Option<String>,
     configs: std::collections::HashMap<String, GardenEditConfig>,
+    /// Amend button clicked in the modal: (machine id, item id, units),
+    /// moved into `GuiState.pending_amend_soil` after the modal draws.
+    amend: Option<(String, String, u32)>,
 }
 
 fn with_garden_edit<R>(f: impl FnOnce(&mut GardenEditState) -> R) -> R {
@@ -295,7 +298,7 @@ fn garden_edit_modal(ctx: &egui::Context, theme: &Theme, state: &GuiState) {
                 );
             }
             ui.separator();
-            garden_medium_editor(ui, theme, &machine_id, medium.as_ref(), state);
+            garden_medium_editor(ui, theme, &machine_id, area.count, medium.as_ref(), state);
             ui.add_space(theme.spacing_sm);
             if widgets::primary_button(ui, theme, "Done") {
                 with_garden_edit(|s| s.open = None);
@@ -312,6 +315,7 @@ fn garden_medium_editor(
     ui: &mut egui::Ui,
     theme: &Theme,
     machine_id: &str,
+    units: u32,
     medium: Option<&crate::gui::GrowMedium>,
     state: &GuiState,
 ) {
@@ -348,6 +352,22 @@ fn garden_medium_editor(
             ui.add_space(theme.spacing_xs);
         }
     }
+    // Soil-grown areas show their plots' mean chemistry (planted or amended units).
+    if let Some(soil) = state.soil.get(machine_id) {
+        ui.label(
+            RichText::new(format!(
+                "Soil: pH {:.1} · N {:.0}% · P {:.0}% · K {:.0}% · organic {:.0}%",
+                soil.ph,
+                soil.nitrogen * 100.0,
+                soil.phosphorus * 100.0,
+                soil.potassium * 100.0,
+                soil.organic_matter * 100.0
+            ))
+            .color(theme.text_primary())
+            .size(theme.font_size_small),
+        );
+        ui.add_space(theme.spacing_xs);
+    }
     // The data-driven controls (sliders/crop field/toggles, keyed by the registry).
     for control in &medium.controls {
         match control {
@@ -365,6 +385,11 @@ fn garden_medium_editor(
                 let t = cfg.toggles.entry(key.clone()).or_insert(true);
                 widgets::toggle(ui, theme, label, t);
             }
+            crate::gui::GrowControl::Amend { item, label } => {
+                if widgets::secondary_button(ui, theme, label) {
+                    with_garden_edit(|s| s.amend = Some((machine_id.to_string(), item.clone(), units)));
+                }
+            }
         }
     }
     with_garden_edit(|s| {
@@ -1273,6 +1298,10 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
     // Crop actions come from the Garden section in the central panel; applied after.
     let mut action_water_crop: Option<u64> = None;
     let mut action_harvest_crop: Option<u64> = None;
+    // Seed saving from the slot card: harvest into a new cultivar, or rename
+    // the cultivar a crop was sown from (seed item id, name).
+    let mut action_save_seed: Option<u64> = None;
+    let mut action_name_cultivar: Option<(String, String)> = None;
     let mut action_dev_grow = false;
     // "Dev: stock seeds" — the seed item ids of the starter set to grant.
     let mut action_stock_seeds: Option<Vec<String>> = None;
@@ -1289,6 +1318,7 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
     // set by the Vehicles section's Summon button; applied after the panel.
     let mut action_summon_vehicle: Option<u64> = None;
     let mut action_follow_vehicle: Option<u64> = None;
+    let mut action_refuel_vehicle: Option<u64> = None;
     let mut action_rest = false;
     let mut action_compost = false;
     let mut action_fertilize_crop: Option<u64> = None;
@@ -2249,6 +2279,12 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
                                                             stat(ui, "Temp window", format!("{:.0}-{:.0} °C", c.temp_min, c.temp_max));
                                                             stat(ui, "Reservoir", format!("{:.0}%", c.water * 100.0));
                                                             stat(ui, "Health", format!("{:.0}%", c.health));
+                                                            if !c.cultivar.is_empty() {
+                                                                stat(ui, "Cultivar", c.cultivar.clone());
+                                                            }
+                                                            if !c.genes.is_empty() {
+                                                                stat(ui, "Genetics", c.genes.clone());
+                                                            }
                                                         });
                                                     ui.add_space(theme.spacing_xs);
                                                     ui.horizontal(|ui| {
@@ -2256,6 +2292,13 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
                                                         if c.mature && widgets::compact_button(ui, theme, "Harvest", widgets::ButtonVariant::Primary) {
                                                             action_harvest_crop = Some(c.entity_bits);
                                                         }
+                                                        // Keep this plant's seed as a new cultivar (selection).
+                                                        if c.mature
+                                                            && !c.genes.is_empty()
+                                                            && widgets::compact_button(ui, theme, "Save seed", widgets::ButtonVariant::Secondary)
+                                                        {
+                                                            action_save_seed = Some(c.entity_bits);
+                                                        }
                                                         if !c.dead && widgets::compact_button(ui, theme, "Water", widgets::ButtonVariant::Secondary) {
                                                             action_water_crop = Some(c.entity_bits);
                                                         }
@@ -2263,6 +2306,20 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
                                                             action_fertilize_crop = Some(c.entity_bits);
                                                         }
                                                     });
+                                                    // Name the cultivar this crop was sown from.
+                                                    if let Some(seed_id) = &c.cultivar_seed {
+                                                        let name_id = egui::Id::new(("cultivar_name", seed_id));
+                                                        let mut name: String = ui
+                                                            .data_mut(|d| d.get_temp(name_id))
+                                                            .unwrap_or_else(|| c.cultivar.clone());
+                                                        ui.horizontal(|ui| {
+                                                            ui.add(egui::TextEdit::singleline(&mut name).desired_width(160.0));
+                                                            if widgets::compact_button(ui, theme, "Name cultivar", widgets::ButtonVariant::Secondary) {
+                                                                action_name_cultivar = Some((seed_id.clone(), name.clone()));
+                                                            }
+                                                        });
+                                                        ui.data_mut(|d| d.insert_temp(name_id, name));
+                                                    }
                                                 }
                                                 None => {
                                                     ui.label(
@@ -2424,6 +2481,26 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
                                             .color(theme.text_secondary()),
                                     );
                                 });
+                                // Fuel gauge + Refuel: one Refined Fuel
+                                // per litre from the backpack.
+                                if let Some((litres, tank)) = v.fuel {
+                                    widgets::row_cell(ui, theme.cell_short_width, |ui| {
+                                        ui.label(
+                                            RichText::new(format!("{litres:.0}/{tank:.0} L"))
+                                                .size(theme.font_size_small)
+                                                .color(if litres <= 0.0 {
+                                                    theme.danger()
+                                                } else {
+                                                    theme.text_secondary()
+                                                }),
+                                        );
+                                    });
+                                    if litres < tank - 0.5
+                                        && widgets::compact_button(ui, theme, "Refuel", widgets::ButtonVariant::Secondary)
+                                    {
+                                        action_refuel_vehicle = Some(v.bits);
+                                    }
+                                }
                                 if v.in_transit {
                                     ui.label(
                                         RichText::new("En route to you...")
@@ -2494,6 +2571,9 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
     if let Some(bits) = action_follow_vehicle {
         state.pending_follow_vehicle = Some(bits);
     }
+    if let Some(bits) = action_refuel_vehicle {
+        state.pending_refuel_vehicle = Some(bits);
+    }
 
     // Apply the Garden actions (set inside the central panel) to GuiState; the main
     // loop bridges these into FarmingSystem's command channels before the next tick.
@@ -2512,6 +2592,12 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
     if let Some(bits) = action_harvest_crop {
         state.pending_harvest_crop = Some(bits);
     }
+    if let Some(bits) = action_save_seed {
+        state.pending_save_seed = Some(bits);
+    }
+    if let Some(naming) = action_name_cultivar {
+        state.pending_name_cultivar = Some(naming);
+    }
     if let Some(bits) = action_harvest_many {
         state.pending_harvest_many = bits;
     }
@@ -2535,6 +2621,9 @@ pub fn draw(ctx: &egui::Context, theme: &Theme, state: &mut GuiState) {
     // Per-medium grow-area edit modal — shown at ctx level (a floating window) when
     // a Garden tile was clicked. Rendered after the panel so it overlays everything.
     garden_edit_modal(ctx, theme, state);
+    if let Some(amend) = with_garden_edit(|s| s.amend.take()) {
+        state.pending_amend_soil = Some(amend);
+    }
     // Publish this frame's water + nutrient sliders to the sim (after the modal edits).
     snapshot_garden_sim(state);
     // Per-asteroid mining modal (clicked a Mining card) — also at ctx level.
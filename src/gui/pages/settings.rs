@@ -2906,6 +2906,20 @@ pub(crate) fn draw_gameplay_content(ui: &mut egui::Ui, theme: &Theme, state: &mu
              half an hour from full to empty), 0 = survival needs paused.",
         );
 
+        ui.add_space(theme.spacing_lg);
+        ui.label(RichText::new("Saves").color(theme.text_secondary()).strong());
+        ui.add_space(theme.spacing_xs);
+        if widgets::toggle(ui, theme, "Binary saves", &mut state.settings.binary_saves) {
+            state.settings_dirty = true;
+        }
+        widgets::setting_hint(
+            ui,
+            theme,
+            hint,
+            "Write your home and its rolling autosaves as compact .hsav files \
+             instead of JSON. Both load either way; the next save switches format.",
+        );
+
         ui.add_space(theme.spacing_lg);
         // Household size (2026-07-01, moved here from Data in v0.791): which home design
         // data/machines/*.ron loads. Two real, fully-authored designs exist -- the default
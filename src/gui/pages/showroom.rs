@@ -159,19 +159,19 @@ fn draw_picker(ctx: &Context, theme: &Theme, state: &mut GuiState) {
 /// which returns only (world name, timestamp): the WHO column needs the
 /// character name and the home summary needs the design, and both already sit
 /// in the very file list_saves parses and throws away. Rows are deduped by
-/// world name (the saves dir holds `offline_home.json` AND `auto_save.json`,
-/// which carry the SAME `WorldSave.name`), newest kept, so a row name is a
-/// usable id.
+/// world name (the saves dir holds `offline_home.json` AND the `auto_save_<n>`
+/// slots, which carry the SAME `WorldSave.name`), newest kept, so a row name
+/// is a usable id.
 fn load_homes(state: &mut GuiState) {
     let dir = crate::persistence::saves_dir();
     let mut homes: Vec<crate::gui::LauncherHome> = Vec::new();
     if let Ok(entries) = std::fs::read_dir(&dir) {
         for entry in entries.filter_map(|e| e.ok()) {
             let path = entry.path();
-            if path.extension().and_then(|x| x.to_str()) != Some("json") {
+            if !crate::persistence::is_save_file(&path) {
                 continue;
             }
-            let Ok(save) = crate::persistence::load_world(&path) else {
+            let Ok(save) = crate::persistence::read_world(&path) else {
                 continue;
             };
             if save.name.trim().is_empty() {
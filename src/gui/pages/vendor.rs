@@ -1,11 +1,14 @@
 //! Vendor modal (v0.747, closure ladder rung 3): the trading post's buy/sell
 //! window. Opened from the trading post machine's walk-up card; prices come
 //! from data/trade_goods.ron (player pays 1.25x base, receives 0.5x base).
+//! With data/markets.ron loaded, prices follow this post's stock and
+//! region instead, each good shows what's on the shelf, and clicking a good
+//! charts its daily price at this vendor.
 //! Transactions settle in lib.rs's frame bridge via economy::vendor_buy/sell
 //! against the ECS inventory + Wallet, so this page only reads GuiState and
 //! records intents (the same pattern as every other page).
 
-use egui::{Align2, RichText, ScrollArea};
+use egui::{Align2, RichText, ScrollArea, Sense, Stroke};
 use std::cell::RefCell;
 
 use crate::gui::theme::Theme;
@@ -38,11 +41,13 @@ pub fn draw_vendor_modal(ctx: &egui::Context, theme: &Theme, state: &mut GuiStat
                         .strong()
                         .color(theme.accent()),
                 );
-                ui.label(
-                    RichText::new("buy 125% of base · sell 50% of base")
-                        .size(theme.font_size_small)
-                        .color(theme.text_muted()),
-                );
+                let region = &state.vendor_post.1;
+                let terms = if region.is_empty() {
+                    "buy 125% of base · sell 50% of base".to_string()
+                } else {
+                    format!("{region} market · prices follow stock")
+                };
+                ui.label(RichText::new(terms).size(theme.font_size_small).color(theme.text_muted()));
             });
             if !state.vendor_status.is_empty() {
                 ui.label(
@@ -61,6 +66,7 @@ pub fn draw_vendor_modal(ctx: &egui::Context, theme: &Theme, state: &mut GuiStat
                 }
             });
             ui.separator();
+            draw_price_chart(ui, theme, state);
 
             if sell_tab {
                 // SELL: backpack items the vendor trades, at the receive price.
@@ -85,11 +91,18 @@ pub fn draw_vendor_modal(ctx: &egui::Context, theme: &Theme, state: &mut GuiStat
                     }
                     for (id, name, qty, price) in &sellable {
                         ui.horizontal(|ui| {
-                            ui.label(
-                                RichText::new(format!("{name} x{qty}"))
-                                    .size(theme.font_size_body)
-                                    .color(theme.text_primary()),
-                            );
+                            let picked = state.vendor_selected.as_deref() == Some(id.as_str());
+                            if ui
+                                .selectable_label(
+                                    picked,
+                                    RichText::new(format!("{name} x{qty}"))
+                                        .size(theme.font_size_body)
+                                        .color(theme.text_primary()),
+                                )
+                                .clicked()
+                            {
+                                state.vendor_selected = Some(id.clone());
+                            }
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                 if widgets::compact_button(ui, theme, "Sell all", widgets::ButtonVariant::Secondary) {
                                     state.pending_vendor_sell = Some((id.clone(), *qty));
@@ -129,13 +142,22 @@ pub fn draw_vendor_modal(ctx: &egui::Context, theme: &Theme, state: &mut GuiStat
                             );
                         }
                         ui.horizontal(|ui| {
-                            ui.label(
-                                RichText::new(&g.name)
-                                    .size(theme.font_size_body)
-                                    .color(theme.text_primary()),
-                            );
+                            let picked = state.vendor_selected.as_deref() == Some(g.id.as_str());
+                            if ui
+                                .selectable_label(
+                                    picked,
+                                    RichText::new(&g.name)
+                                        .size(theme.font_size_body)
+                                        .color(theme.text_primary()),
+                                )
+                                .clicked()
+                            {
+                                state.vendor_selected = Some(g.id.clone());
+                            }
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                if widgets::compact_button(ui, theme, "Buy", widgets::ButtonVariant::Primary) {
+                                if g.stock > 0
+                                    && widgets::compact_button(ui, theme, "Buy", widgets::ButtonVariant::Primary)
+                                {
                                     state.pending_vendor_buy = Some((g.id.clone(), 1));
                                 }
                                 ui.label(
@@ -143,6 +165,12 @@ pub fn draw_vendor_modal(ctx: &egui::Context, theme: &Theme, state: &mut GuiStat
                                         .size(theme.font_size_small)
                                         .color(theme.text_secondary()),
                                 );
+                                let (shelf, color) = if g.stock == 0 {
+                                    ("sold out".to_string(), theme.warning())
+                                } else {
+                                    (format!("{} on shelf", g.stock), theme.text_muted())
+                                };
+                                ui.label(RichText::new(shelf).size(theme.font_size_small).color(color));
                             });
                         });
                     }
@@ -153,5 +181,54 @@ pub fn draw_vendor_modal(ctx: &egui::Context, theme: &Theme, state: &mut GuiStat
     if !open_flag {
         state.vendor_open = false;
         state.vendor_status.clear();
+        state.vendor_selected = None;
+    }
+}
+
+/// The selected good's daily mid price at this vendor as a line, with today's
+/// pay / receive prices alongside. Nothing until a good is clicked; "no history
+/// yet" until the post has sampled it on two game days.
+fn draw_price_chart(ui: &mut egui::Ui, theme: &Theme, state: &GuiState) {
+    let Some(good) = state
+        .vendor_selected
+        .as_ref()
+        .and_then(|id| state.vendor_goods.iter().find(|g| &g.id == id))
+    else {
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(&good.name).size(theme.font_size_small).strong().color(theme.text_primary()));
+        ui.label(
+            RichText::new(format!("pay {} CR · receive {} CR", good.buy_price, good.sell_price))
+                .size(theme.font_size_small)
+                .color(theme.text_secondary()),
+        );
+    });
+    if good.history.len() < 2 {
+        ui.label(RichText::new("No price history yet.").size(theme.font_size_small).color(theme.text_muted()));
+        ui.separator();
+        return;
     }
+    let (rect, _) = ui.allocate_exact_size(egui::Vec2::new(ui.available_width(), 56.0), Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 2.0, theme.bg_panel());
+    let lo = good.history.iter().copied().fold(f32::INFINITY, f32::min);
+    let hi = good.history.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let span = (hi - lo).max(0.01);
+    let step = rect.width() / (good.history.len() - 1) as f32;
+    let points: Vec<egui::Pos2> = good
+        .history
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            egui::pos2(rect.left() + i as f32 * step, rect.bottom() - 4.0 - (p - lo) / span * (rect.height() - 8.0))
+        })
+        .collect();
+    painter.add(egui::Shape::line(points, Stroke::new(1.5, theme.accent())));
+    ui.label(
+        RichText::new(format!("{} days · {:.0}–{:.0} CR mid", good.history.len(), lo, hi))
+            .size(theme.font_size_small)
+            .color(theme.text_muted()),
+    );
+    ui.separator();
 }
@@ -373,6 +373,13 @@ pub fn draw(ctx: &Context, theme: &Theme, state: &mut GuiState) -> bool {
                             .color(theme.warning()),
                     );
                 }
+                if !w.forecast.is_empty() {
+                    ui.label(
+                        RichText::new(format!("forecast: {}", w.forecast))
+                            .size(theme.font_size_small)
+                            .color(theme.text_secondary()),
+                    );
+                }
             }
             ui.label(
                 RichText::new(
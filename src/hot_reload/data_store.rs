@@ -29,6 +29,11 @@ impl DataStore {
         self.entries.get(key).and_then(|v| v.downcast_ref::<T>())
     }
 
+    /// Retrieve a typed mutable reference by key.
+    pub fn get_mut<T: 'static>(&mut self, key: &str) -> Option<&mut T> {
+        self.entries.get_mut(key).and_then(|v| v.downcast_mut::<T>())
+    }
+
     /// Remove an entry (used on hot-reload to force re-load).
     pub fn remove(&mut self, key: &str) {
         self.entries.remove(key);
@@ -2,6 +2,7 @@
 //!
 //! On native: polls FileWatcher each frame for changed files,
 //! invalidates AssetManager cache entries, systems reload on next access.
+//! An edit that fails its `schemas/` check is logged and not applied.
 //! On WASM: hot-reload is not supported (data fetched from server).
 
 pub mod data_store;
@@ -9,11 +10,18 @@ pub mod data_store;
 #[cfg(feature = "native")]
 use crate::assets::watcher::FileWatcher;
 use crate::assets::AssetManager;
+#[cfg(feature = "native")]
+use crate::assets::schema::SchemaSet;
 
 /// Coordinates hot-reload between file watcher and asset manager.
 pub struct HotReloadCoordinator {
     #[cfg(feature = "native")]
     watcher: Option<FileWatcher>,
+    /// Gate for edits to schema-covered files (None: schemas unreadable).
+    #[cfg(feature = "native")]
+    schemas: Option<SchemaSet>,
+    #[cfg(feature = "native")]
+    data_dir: std::path::PathBuf,
 }
 
 impl HotReloadCoordinator {
@@ -27,7 +35,10 @@ impl HotReloadCoordinator {
                 None
             }
         };
-        Self { watcher }
+        let schemas = SchemaSet::for_data_dir(data_dir)
+            .map_err(|e| log::warn!("Hot-reload schema gate disabled: {e}"))
+            .ok();
+        Self { watcher, schemas, data_dir: data_dir.to_path_buf() }
     }
 
     /// Create a no-op coordinator (WASM).
@@ -38,11 +49,16 @@ impl HotReloadCoordinator {
 
     /// Poll for file changes and invalidate affected cache entries.
     /// Call once per frame. Returns the list of changed file paths (for logging).
+    /// A change failing schema validation is left out: the cached (last
+    /// good) data stays and no regeneration gate fires for it.
     #[cfg(feature = "native")]
     pub fn poll(&self, asset_manager: &mut AssetManager) -> Vec<String> {
         let mut changed = Vec::new();
         if let Some(ref watcher) = self.watcher {
             for path in watcher.poll_changes() {
+                if !self.passes_schema(&path) {
+                    continue;
+                }
                 asset_manager.invalidate(&path);
                 changed.push(path);
             }
@@ -50,6 +66,29 @@ impl HotReloadCoordinator {
         changed
     }
 
+    /// Validate the new contents of `rel` (a data file or a mod override)
+    /// against its schema; logs every problem. Unreadable files and files
+    /// no schema covers pass.
+    #[cfg(feature = "native")]
+    fn passes_schema(&self, rel: &str) -> bool {
+        let Some(schemas) = &self.schemas else {
+            return true;
+        };
+        let Ok(text) = std::fs::read_to_string(self.data_dir.join(rel)) else {
+            return true;
+        };
+        let mut modded = crate::mods::ModData::scan(&self.data_dir);
+        let errors = schemas.validate_file(rel, &text, &mut modded);
+        if errors.is_empty() {
+            return true;
+        }
+        for e in &errors {
+            log::warn!("Hot-reload: {e}");
+        }
+        log::warn!("Hot-reload: {rel} not applied ({} schema problem(s))", errors.len());
+        false
+    }
+
     /// No-op poll for WASM.
     #[cfg(feature = "wasm")]
     pub fn poll(&self, _asset_manager: &mut AssetManager) -> Vec<String> {
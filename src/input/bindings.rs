@@ -52,6 +52,10 @@ pub enum GameAction {
     Interact,
     /// F: swing the held tool (or bare hands) at the faced creature.
     AttackSwing,
+    /// X: feed the faced herd animal, or offer a wild one feed to tame it.
+    TendAnimal,
+    /// K: slaughter the faced herd animal.
+    Slaughter,
     Inventory,
     /// Tab (hold): peek hidden labels through walls.
     RevealPeek,
@@ -109,6 +113,8 @@ pub const ACTIONS: &[ActionInfo] = &[
     ActionInfo { action: GameAction::Sprint, id: "sprint", label: "Sprint / fly down", category: "Movement", default_primary: "ShiftLeft", default_secondary: "ShiftRight" },
     ActionInfo { action: GameAction::Interact, id: "interact", label: "Interact / talk", category: "Interaction", default_primary: "KeyE", default_secondary: "" },
     ActionInfo { action: GameAction::AttackSwing, id: "attack_swing", label: "Swing tool / attack", category: "Interaction", default_primary: "KeyF", default_secondary: "" },
+    ActionInfo { action: GameAction::TendAnimal, id: "tend_animal", label: "Feed / tame animal", category: "Interaction", default_primary: "KeyX", default_secondary: "" },
+    ActionInfo { action: GameAction::Slaughter, id: "slaughter", label: "Slaughter herd animal", category: "Interaction", default_primary: "KeyK", default_secondary: "" },
     ActionInfo { action: GameAction::Inventory, id: "inventory", label: "Inventory", category: "Interaction", default_primary: "KeyI", default_secondary: "" },
     ActionInfo { action: GameAction::RevealPeek, id: "reveal_peek", label: "Reveal labels (hold)", category: "Interaction", default_primary: "Tab", default_secondary: "" },
     ActionInfo { action: GameAction::OpenChat, id: "open_chat", label: "Open chat panel", category: "Interaction", default_primary: "Enter", default_secondary: "" },
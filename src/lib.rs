@@ -187,7 +187,7 @@ mod native_app {
     use crate::systems::food::FoodSystem;
     use crate::systems::mining::DroneSystem;
     use crate::systems::skills::{PlayerSkills, SkillRegistry, SkillSystem, SkillXPEvent};
-    use crate::systems::quests::{QuestRegistry, QuestSystem, QuestTracker};
+    use crate::systems::quests::{QuestEvent, QuestRegistry, QuestSystem, QuestTracker};
     use crate::systems::interaction::InteractionSystem;
     use crate::systems::inventory::{Inventory, InventorySystem, ItemRegistry};
     use crate::systems::inventory::containers::ContainerCompatibilitySystem;
@@ -1011,6 +1011,9 @@ mod native_app {
                 "power_status",
                 std::sync::Mutex::new(crate::systems::electrical::PowerStatus::default()),
             );
+            // Cable grid report + the Home page's breaker reset.
+            data_store.insert("grid_report", std::sync::Mutex::new(crate::systems::power_grid::GridReport::default()));
+            data_store.insert("grid_reset_request", std::sync::Mutex::new(false));
             // Live home WATER readout (production/demand/stored L): PlumbingSystem writes it each tick.
             data_store.insert(
                 "water_status",
@@ -1021,6 +1024,9 @@ mod native_app {
                 "air_status",
                 std::sync::Mutex::new(crate::systems::atmosphere::AirStatus::default()),
             );
+            // Room temperatures from the thermal network (HvacSystem) + the live door openings it mixes through.
+            data_store.insert("thermal_status", std::sync::Mutex::new(crate::systems::thermal::ThermalStatus::default()));
+            data_store.insert("door_open", std::sync::Mutex::new(Vec::<((f32, f32), f32)>::new()));
             system_runner.register(TimeSystem::new());
             // WeatherSystem ticks after TimeSystem (reads the exported season) and
             // exports Weather; the exposed-environment temperature consumes it.
@@ -1033,6 +1039,9 @@ mod native_app {
             // PlumbingSystem ticks AFTER electrical so it sees this frame's shed state -- a pump/purifier
             // shed in a power deficit stops producing water THIS tick (the power -> water chain). (v0.608)
             system_runner.register(crate::systems::plumbing::PlumbingSystem::new());
+            // HvacSystem: the home's rooms as a thermal network, after electrical so a shed
+            // furnace stops heating this tick, before atmosphere so the home air reads this tick's rooms.
+            system_runner.register(crate::systems::hvac::HvacSystem::new(&data_dir));
             // AtmosphereSystem (v0.617): ticks the home's sealed air space + publishes AirStatus. Earth-
             // like for now (Stage 1); occupancy + powered scrubbers + the power -> air -> Vitals
             // consequence are Stage 2.
@@ -1087,6 +1096,10 @@ mod native_app {
                 "time_set_hour_request",
                 std::sync::Mutex::new(Option::<f32>::None),
             );
+            // Absolute clock restore + the spoilage backlog, both filled once
+            // at boot by the offline catch-up (save_load::catch_up_active_home).
+            data_store.insert("time_set_elapsed_request", std::sync::Mutex::new(Option::<f64>::None));
+            data_store.insert("spoilage_catch_up", std::sync::Mutex::new(0.0f32));
             // Plant a bed/tray/field grow area (v0.738 grain loop): (machine id,
             // plant id, unit count). One CropInstance per unit, tagged with the
             // machine id as its grow-area so the Garden GUI groups them.
@@ -1094,8 +1107,19 @@ mod native_app {
                 "plant_bed_request",
                 std::sync::Mutex::new(Option::<(String, String, u32)>::None),
             );
+            // Soil amendment (garden edit modal): (machine id, item id, unit count).
+            data_store.insert(
+                "amend_soil_request",
+                std::sync::Mutex::new(Option::<(String, String, u32)>::None),
+            );
             data_store.insert("water_request", std::sync::Mutex::new(Option::<u64>::None));
             data_store.insert("harvest_request", std::sync::Mutex::new(Option::<u64>::None));
+            // Seed saving: harvest a crop into a new cultivar; rename a cultivar.
+            data_store.insert("save_seed_request", std::sync::Mutex::new(Option::<u64>::None));
+            data_store.insert(
+                "name_cultivar_request",
+                std::sync::Mutex::new(Option::<(String, String)>::None),
+            );
             // Bulk harvest (v0.739): the Garden group "Harvest N ready" button.
             data_store.insert("harvest_many_request", std::sync::Mutex::new(Vec::<u64>::new()));
             data_store.insert("dev_grow_crops", std::sync::Mutex::new(false));
@@ -1155,6 +1179,23 @@ mod native_app {
                 "summon_vehicle",
                 std::sync::Mutex::new(Option::<u64>::None),
             );
+            // Driving: the driver's pedals + wheel each frame (None when
+            // nobody is at the wheel), DrivingSystem's suspension probe points,
+            // the engine's ground/sea answers to them, and the Vehicles section's
+            // one-shot Refuel.
+            data_store.insert(
+                "drive_input",
+                Option::<crate::systems::vehicles::driving::DriveInput>::None,
+            );
+            data_store.insert("vehicle_probes", std::sync::Mutex::new(Vec::<Vec3>::new()));
+            data_store.insert(
+                "vehicle_ground",
+                std::sync::Mutex::new(Vec::<crate::systems::vehicles::driving::GroundSample>::new()),
+            );
+            data_store.insert(
+                "refuel_vehicle",
+                std::sync::Mutex::new(Option::<u64>::None),
+            );
             // Live production status (v0.681): CraftingSystem writes one honest
             // line per auto machine each tick ("Assemble Rover — 42%", "waiting
             // for Steel Ingot x6", "pad full, line paused"); the GUI shows them.
@@ -1192,16 +1233,18 @@ mod native_app {
             // Dev: max all skills (testing affordance — keeps every recipe craftable
             // under the #8b skill-gate, like "Dev: stock materials" for inventory).
             data_store.insert("dev_max_skills", std::sync::Mutex::new(false));
-            // Quest progress events: action systems push "craft_<recipe>" /
-            // "harvest_<crop>" keys; QuestSystem (registered after them) drains them
-            // each frame to advance count-based Craft/Harvest objectives.
-            data_store.insert("quest_events", std::sync::Mutex::new(Vec::<String>::new()));
+            // One-off waste (factory byproducts, scrap): emit_waste pushes, WasteSystem drains.
+            data_store.insert("waste_emissions", std::sync::Mutex::new(Vec::<crate::systems::waste::WasteEmission>::new()));
+            // Quest progress events: action systems push typed QuestEvents (Crafted,
+            // Harvested, Killed, ...); QuestSystem (registered after them) drains them
+            // each frame to advance count-based Craft/Harvest/Kill objectives.
+            data_store.insert("quest_events", std::sync::Mutex::new(Vec::<QuestEvent>::new()));
             // One-shot SFX channel for ECS systems (v0.985): construction /
             // crafting completions ding through here; see audio::sounds::
             // push_sfx_event + the drain in the audio frame-sync block.
             data_store.insert(
                 "sfx_events",
-                std::sync::Mutex::new(Vec::<(String, String)>::new()),
+                std::sync::Mutex::new(Vec::<crate::systems::SfxEvent>::new()),
             );
             system_runner.register(InteractionSystem::new());
             system_runner.register(FarmingSystem::new());
@@ -1223,6 +1266,8 @@ mod native_app {
             // never turned on" story as ConstructionSystem) -- safe no-op until something
             // spawns a ProductionFacility entity.
             system_runner.register(crate::systems::manufacturing::ManufacturingSystem::new(&data_dir));
+            // WasteSystem: waste sources + the factories' byproducts into the nearest bin.
+            system_runner.register(crate::systems::waste::WasteSystem::new(&data_dir));
             // FoodSystem: nutrition (eat -> Vitals + buffs), hunger/thirst decay,
             // and spoilage. Reads consume_request + status_effect_registry from the
             // DataStore. Loads food_system.ron from the data dir at construction.
@@ -1235,15 +1280,33 @@ mod native_app {
             // world). The enter/exit/mech arms it also carries are dormant until
             // Stage 3 wires an interaction that publishes their commands.
             system_runner.register(crate::systems::vehicles::VehicleSystem::new());
+            // DrivingSystem: suspension, throttle/brake/steer, fuel and
+            // buoyancy for every vehicle near the player. Reads drive_input and
+            // the engine's vehicle_ground answers; publishes vehicle_probes.
+            system_runner.register(crate::systems::vehicles::driving::DrivingSystem::new());
             // LivestockSystem (v0.751, ladder rung 7): ages every Harvestable
-            // toward ready and ambles the farm animals around their anchors.
+            // toward ready and ambles the farm animals around their anchors;
+            // steps the herd's needs, breeding and X/K verbs (husbandry).
+            data_store.insert(
+                "husbandry_request",
+                std::sync::Mutex::new(Vec::<(u64, crate::systems::husbandry::HusbandryAction)>::new()),
+            );
+            data_store.insert("husbandry_status", std::sync::Mutex::new(String::new()));
             system_runner.register(crate::systems::livestock::LivestockSystem::new());
+            // AgingSystem: herd animals grow up on its animal ladder.
+            system_runner.register(crate::systems::aging::AgingSystem::new(&data_dir));
             // AbilitySystem (v0.753, ladder rung 8): drains ability_request
             // casts (validate skill gate + energy cost + cooldown, apply the
             // self-scoped effect) and publishes live cooldowns for the GUI.
+            // Offensive casts carry the crosshair ray and fly / linger; what
+            // is in the air or on the ground is published as markers.
             data_store.insert(
                 "ability_request",
-                std::sync::Mutex::new(Vec::<(String, Option<u64>)>::new()),
+                std::sync::Mutex::new(Vec::<crate::systems::abilities::CastRequest>::new()),
+            );
+            data_store.insert(
+                "ability_markers",
+                std::sync::Mutex::new(Vec::<(glam::Vec3, f32)>::new()),
             );
             data_store.insert("ability_status", std::sync::Mutex::new(String::new()));
             data_store.insert(
@@ -1264,11 +1327,48 @@ mod native_app {
                 "loot_drops",
                 std::sync::Mutex::new(Vec::<(String, u32)>::new()),
             );
+            // DisasterSystem: strikes thrown by extreme weather
+            // (lightning, meteor impacts, blizzard squalls) arrive on
+            // disaster_requests; chains follow, hits ride damage_events.
+            // Ticks before CombatSystem so a strike lands the same frame.
+            data_store.insert(
+                "disaster_requests",
+                std::sync::Mutex::new(
+                    Vec::<(crate::systems::disasters::DisasterType, glam::Vec3, f32)>::new(),
+                ),
+            );
+            system_runner.register(crate::systems::disasters::DisasterSystem::new());
+            // WeaponSystem: F swings or fires the hands-slot weapon
+            // (reach, rate, ammo from the pack, durability); its hits ride
+            // damage_events and land in CombatSystem the same frame.
+            data_store.insert(
+                "attack_request",
+                std::sync::Mutex::new(Vec::<crate::systems::combat::weapons::AttackRequest>::new()),
+            );
+            data_store.insert("weapon_status", std::sync::Mutex::new(String::new()));
+            data_store.insert(
+                "weapon_markers",
+                std::sync::Mutex::new(Vec::<(glam::Vec3, f32)>::new()),
+            );
+            system_runner.register(crate::systems::combat::weapons::WeaponSystem::new());
             system_runner.register(crate::systems::combat::CombatSystem::new());
             // AISystem (v0.761, combat arc): the behavior state machine for
             // AI creatures - predators hunt prey INCLUDING the player and
             // their bites ride the damage_events channel above.
             system_runner.register(crate::systems::ai::AISystem::new());
+            // TransportationSystem: fleet logistics -- ships and depots
+            // with real holds, cargo runs between them, standing orders. Was coded
+            // but never registered. The Home page pushes logistics_request and
+            // reads logistics_report.
+            data_store.insert(
+                "logistics_request",
+                std::sync::Mutex::new(Vec::<crate::systems::transportation::LogisticsRequest>::new()),
+            );
+            data_store.insert(
+                "logistics_report",
+                std::sync::Mutex::new(crate::systems::transportation::LogisticsReport::default()),
+            );
+            system_runner.register(crate::systems::transportation::TransportationSystem::new(&data_dir));
             // EconomySystem (v0.747, ladder rung 3, FIRST registration): pays the
             // passive income (1 CR per game day) into every Wallet — economy.ron's
             // "nobody is ever stuck at zero". Vendor buy/sell runs in the frame
@@ -1446,8 +1546,12 @@ mod native_app {
             // ids, no recipes, empty skills + the quest shown by id. (load_world
             // re-loads them; idempotent.)
             let t_boot = std::time::Instant::now();
-            load_data_registries(&mut data_store, &data_dir);
+            gui_state.mod_resolution = load_data_registries(&mut data_store, &data_dir);
             boot_timer.since("eager_data_registries", t_boot);
+            // Offline catch-up: restore the saved clock + machine state, then
+            // advance the home by the time the app was closed.
+            gui_state.away_report =
+                crate::save_load::catch_up_active_home(&mut game_world.world, &data_store, &data_dir);
             // ── Background star-sky prebuild (v0.865 boot speed) ──
             // star_catalog_and_glow measured 4100 ms ON the world-entry path:
             // catalog read + vertex packing + glow PNG decode + GPU upload.
@@ -1748,6 +1852,7 @@ mod native_app {
                 port_pick: Vec::new(),
                 wall_colliders: Vec::new(),
                 pending_sfx: Vec::new(),
+                audio_listener_prev: None,
                 sight_colliders: Vec::new(),
                 connection_objects: Vec::new(),
                 connection_flow_paths: Vec::new(),
@@ -1760,6 +1865,7 @@ mod native_app {
                 structure_mesh: None,
                 structure_mats: None,
                 driving_vehicle: None,
+                driving_heading: None,
                 follow_vehicle: None,
                 targeted_vehicle: None,
                 vehicle_mats: None,
@@ -1894,8 +2000,6 @@ mod native_app {
                 cloud_advect_decaying: 0.0,
                 cloud_event_boost: 0.0,
                 cloud_event_tint: [1.0; 3],
-                event_core: None,
-                event_hazard_near: false,
                 egui_ctx,
                 egui_state,
                 egui_renderer,
@@ -1969,7 +2073,12 @@ mod native_app {
                     // Persist the active offline home before quitting (v0.381). The
                     // player entity exists from startup, so this captures the loaded
                     // or modified inventory + skills, round-tripping the save.
-                    crate::save_load::save_active_home(&state.game_world.world, &state.gui_state.placed_items);
+                    crate::save_load::save_active_home(
+                        &state.game_world.world,
+                        &state.data_store,
+                        &state.gui_state.placed_items,
+                        state.gui_state.settings.save_format(),
+                    );
                     // Flush unsaved build edits too (v0.791): quitting without the
                     // explicit Save button used to silently drop every wall/light/
                     // strip/corridor edit since the last click.
@@ -2360,8 +2469,16 @@ mod native_app {
                                     seat.occupant_key = None;
                                 }
                                 let side = rot * Vec3::new(0.0, 0.0, 2.2);
+                                let before = state.camera.position;
                                 state.camera.position =
                                     Vec3::new(pos.x + side.x, pos.y + 1.7, pos.z + side.z);
+                                // On a planet the step out moves the anchor too
+                                // (same carry as the DRIVING block).
+                                if state.surface_owns_translation {
+                                    let moved = (state.camera.position - before).as_dvec3();
+                                    state.frame_lock_anchor +=
+                                        glam::DQuat::from_rotation_y(-state.frame_lock_last_spin) * moved;
+                                }
                             } else if let Some(veh) = state.targeted_vehicle {
                                 // ENTER: seat the player, cancel self-drive.
                                 if state.game_world.world.contains(veh) {
@@ -2433,12 +2550,11 @@ mod native_app {
                                     // the next chat.
                                     crate::systems::quests::push_quest_event(
                                         &state.data_store,
-                                        format!(
-                                            "talk_{}",
-                                            crate::systems::quests::npc_talk_key(
-                                                &state.gui_state.npc_talk_name
-                                            )
-                                        ),
+                                        crate::systems::quests::QuestEvent::Talked {
+                                            npc_id: crate::systems::quests::npc_talk_key(
+                                                &state.gui_state.npc_talk_name,
+                                            ),
+                                        },
                                     );
                                     // No greetings authored -> lead with the first
                                     // dialog line instead of an empty card.
@@ -2540,9 +2656,9 @@ mod native_app {
                             }
                         }
 
-                        // F swings the held tool (or bare hands) at the faced
-                        // creature (v0.765). The frame bridge settles it with
-                        // the worn hands-slot weapon's damage + reach.
+                        // F swings or fires the held weapon (or bare hands)
+                        // at the faced creature or the crosshair (v0.765;
+                        // now through WeaponSystem).
                         if state.gui_state.keybinds.is(GA::AttackSwing, &key_name)
                             && pressed
                             && state.gui_state.active_page == GuiPage::None
@@ -2551,6 +2667,26 @@ mod native_app {
                             state.gui_state.pending_swing = true;
                         }
 
+                        // X tends (feeds / tames), K slaughters the faced
+                        // animal; LivestockSystem settles both next tick.
+                        let tend = state.gui_state.keybinds.is(GA::TendAnimal, &key_name);
+                        if (tend || state.gui_state.keybinds.is(GA::Slaughter, &key_name))
+                            && pressed
+                            && state.gui_state.active_page == GuiPage::None
+                            && !state.gui_state.showroom_active
+                        {
+                            use crate::systems::husbandry::HusbandryAction;
+                            let action = if tend { HusbandryAction::Tend } else { HusbandryAction::Slaughter };
+                            if let (Some(ent), Some(chan)) = (
+                                state.targeted_livestock,
+                                state.data_store.get::<std::sync::Mutex<Vec<(u64, HusbandryAction)>>>("husbandry_request"),
+                            ) {
+                                if let Ok(mut q) = chan.lock() {
+                                    q.push((u64::from(ent.to_bits()), action));
+                                }
+                            }
+                        }
+
                         // G opens the walk-up creature EDITOR for the creature
                         // you're facing (dev tool, v0.778; Dev play mode +
                         // cheats switch since task #50). The actual snapshot of
@@ -4358,8 +4494,11 @@ mod native_app {
                             // - kilometres per frame at high gear, and the
                             // root of every "I move parallel to the Earth
                             // on takeoff" report. Same deliberate-input
-                            // dead zone as the radial axis.
-                            if tangential.length() > crate::surface_move::RADIAL_WISH_EPS {
+                            // dead zone as the radial axis. At the wheel the
+                            // vehicle carries the anchor instead (DRIVING below).
+                            if state.driving_vehicle.is_none()
+                                && tangential.length() > crate::surface_move::RADIAL_WISH_EPS
+                            {
                                 anchor += tangential.normalize() * step;
                             }
                             // Ground radius at the (possibly moved) surface point.
@@ -4565,19 +4704,26 @@ mod native_app {
                                 .unwrap_or(9.81)
                                 .max(0.01);
                             state.gui_state.surface_gravity_now = Some(g_now as f32);
-                            let vs = crate::surface_move::radial_step(
-                                move_mode,
-                                in_walk_band,
-                                submerged,
-                                r0,
-                                state.surface_vr,
-                                rest,
-                                g_now,
-                                SURFACE_SETTLE_RATE,
-                                dt as f64,
-                                radial_wish,
-                                step,
-                            );
+                            // Driving: the vehicle's suspension owns
+                            // the height, so the anchor holds its radius (and
+                            // the driver's seat makes no footsteps).
+                            let vs = if state.driving_vehicle.is_some() {
+                                crate::surface_move::RadialStep { r: r0, v_r: 0.0, grounded: false }
+                            } else {
+                                crate::surface_move::radial_step(
+                                    move_mode,
+                                    in_walk_band,
+                                    submerged,
+                                    r0,
+                                    state.surface_vr,
+                                    rest,
+                                    g_now,
+                                    SURFACE_SETTLE_RATE,
+                                    dt as f64,
+                                    radial_wish,
+                                    step,
+                                )
+                            };
                             let r = vs.r;
                             state.surface_vr = vs.v_r;
                             anchor = dir1 * r;
@@ -4642,6 +4788,83 @@ mod native_app {
                                 anchor,
                                 cam_local,
                             );
+                            // ── Vehicle suspension probes ── Answer
+                            // DrivingSystem's last-tick probe points with the
+                            // ground under each and, at sea, the wave surface
+                            // (the same samplers the walker stands and floats
+                            // on), as heights in entity space: the radial gap
+                            // to the surface is taken along entity Y. Off the
+                            // planet (aboard the station) nothing answers and
+                            // vehicles keep to the deck.
+                            if in_walk_band && !state.aboard_station {
+                                let probes = state
+                                    .data_store
+                                    .get::<std::sync::Mutex<Vec<Vec3>>>("vehicle_probes")
+                                    .and_then(|m| m.lock().ok().map(|p| p.clone()))
+                                    .unwrap_or_default();
+                                if !probes.is_empty() {
+                                    let t = state.start_time.elapsed().as_secs_f32();
+                                    let unspin = glam::DQuat::from_rotation_y(-spin);
+                                    let fft = state
+                                        .ocean_fft
+                                        .as_ref()
+                                        .filter(|_| state.gui_state.settings.water_fft);
+                                    let answers: Vec<crate::systems::vehicles::driving::GroundSample> =
+                                        probes
+                                            .iter()
+                                            .map(|p| {
+                                                let local = (*p + state.station_off).as_dvec3();
+                                                let q = unspin
+                                                    * (state.ship_world_pos + local - body_center);
+                                                let r = q.length();
+                                                let dir = q / r.max(1.0);
+                                                let g = ground_radius_m(
+                                                    def,
+                                                    hm,
+                                                    detail.as_ref(),
+                                                    tiles_for_clamp,
+                                                    dir,
+                                                );
+                                                // Same ocean test as the walk clamp,
+                                                // deep-water backstop included.
+                                                let sea = def.filter(|d| {
+                                                    lock_body == "earth"
+                                                        && (ocean_for_clamp
+                                                            .is_some_and(|om| om.is_ocean(dir.as_vec3()))
+                                                            || g < d.radius - 60.0)
+                                                });
+                                                let water_y = sea.map(|d| {
+                                                    let at = dir * d.radius;
+                                                    let depth_m = (d.radius - g).max(0.0) as f32;
+                                                    let wave = match fft {
+                                                        Some(fft) => crate::terrain::ocean_fft::wave_height_shoaled_fft_m(
+                                                            at, t, depth_m, fft,
+                                                        ),
+                                                        None => crate::terrain::ocean_waves::wave_height_shoaled_m(
+                                                            at, t, depth_m,
+                                                        ),
+                                                    } as f64;
+                                                    let sea_r = d.radius
+                                                        + crate::terrain::ocean_waves::SURFACE_LIFT_M as f64
+                                                        + wave;
+                                                    p.y + (sea_r - r) as f32
+                                                });
+                                                crate::systems::vehicles::driving::GroundSample {
+                                                    ground_y: p.y + (g - r) as f32,
+                                                    water_y,
+                                                }
+                                            })
+                                            .collect();
+                                    if let Some(slot) = state.data_store.get::<std::sync::Mutex<
+                                        Vec<crate::systems::vehicles::driving::GroundSample>,
+                                    >>("vehicle_ground")
+                                    {
+                                        if let Ok(mut s) = slot.lock() {
+                                            *s = answers;
+                                        }
+                                    }
+                                }
+                            }
                             // No view yaw co-rotation in surface mode: the tangent
                             // basis is derived from surface_up (which co-rotates
                             // with the surface) + world Y, so a FIXED yaw already
@@ -4802,41 +5025,45 @@ mod native_app {
                         state.camera.position = resolved;
                     }
                     // ── DRIVING (Stage 3 take-over, v0.690) ──
-                    // While driving: WASD throttles/steers the VEHICLE (arcade: it
-                    // goes where you look), the camera rides the cab, and the ECS
-                    // player Transform travels along so saves + systems see the
-                    // player where the vehicle is. Runs AFTER the walk/collision
-                    // resolve above and simply overrides the camera -- walking
-                    // input becomes throttle input for free via InputState.
+                    // While driving: the bound move keys are the pedals and the
+                    // wheel (forward = throttle, back = brake then reverse, left /
+                    // right = steer, jump = handbrake), published as drive_input
+                    // for DrivingSystem, which moves the VEHICLE. The camera rides
+                    // the cab and turns with the body (the mouse still looks
+                    // around), and the ECS player Transform travels along so
+                    // saves + systems see the player where the vehicle is. Runs
+                    // AFTER the walk/collision resolve above and simply overrides
+                    // the camera.
+                    let mut drive_input = None;
                     if let Some(veh) = state.driving_vehicle {
                         if !state.game_world.world.contains(veh) {
                             state.driving_vehicle = None; // despawned under us
+                            state.driving_heading = None;
                         } else {
                             // Driver wins over any self-drive route re-attached mid-drive.
                             let _ = state
                                 .game_world
                                 .world
                                 .remove_one::<crate::ecs::components::VehicleRoute>(veh);
-                            let (mut vpos, item_id) = {
+                            let (vpos, vrot, item_id) = {
                                 let t = state
                                     .game_world
                                     .world
                                     .get::<&crate::ecs::components::Transform>(veh)
-                                    .map(|t| t.position)
-                                    .unwrap_or(state.camera.position);
+                                    .map(|t| (t.position, t.rotation))
+                                    .unwrap_or((state.camera.position, Quat::IDENTITY));
                                 let id = state
                                     .game_world
                                     .world
                                     .get::<&crate::ecs::components::Vehicle>(veh)
                                     .map(|v| v.item_id.clone())
                                     .unwrap_or_default();
-                                (t, id)
+                                (t.0, t.1, id)
                             };
                             let def = state
                                 .data_store
                                 .get::<crate::systems::vehicles::VehicleKitRegistry>("vehicle_kit_registry")
                                 .and_then(|r| r.get_vehicle(&item_id).cloned());
-                            let speed = def.as_ref().map(|d| d.speed_mps).unwrap_or(6.0);
                             let cab_h = def
                                 .as_ref()
                                 .map(|d| d.wheel_radius_m + d.body_m.1 + d.cabin_m.1 + 0.35)
@@ -4846,45 +5073,37 @@ mod native_app {
                                 .get::<InputState>("input_state")
                                 .cloned()
                                 .unwrap_or_default();
-                            let throttle = if input.forward {
-                                1.0
-                            } else if input.backward {
-                                -0.45
-                            } else {
-                                0.0
-                            };
-                            // A/D steer the wheel (v0.697, operator request):
-                            // they turn the shared yaw, so keyboard and mouse
-                            // steering work simultaneously -- the mouse looks,
-                            // A/D turn, both move the same heading. D = right =
-                            // +yaw (matches mouse-right). A dedicated steering-
-                            // mode setting (mouse-only / keys-only) is queued.
-                            let steer = (input.right as i32 - input.left as i32) as f32;
-                            if steer != 0.0 {
-                                state.camera.yaw += steer * 1.6 * dt;
-                            }
-                            // v0.697 fix: use the camera's OWN forward -- the old
-                            // recomputed (-sin, -cos) was X-mirrored, so driving
-                            // east/west went the wrong way while north/south was
-                            // fine (operator field report).
-                            let fwd = state.camera.forward_xz();
-                            if throttle != 0.0 {
-                                vpos += fwd * speed * throttle * dt;
-                            }
-                            // Body long axis is +X: derive the body yaw from the
-                            // actual forward vector (same formula the transit
-                            // route tick uses), never from yaw-plus-offset math.
-                            let vrot = Quat::from_rotation_y((-fwd.z).atan2(fwd.x));
-                            if let Ok(mut t) = state
-                                .game_world
-                                .world
-                                .get::<&mut crate::ecs::components::Transform>(veh)
-                            {
-                                t.position = vpos;
-                                t.rotation = vrot;
-                            }
+                            drive_input = Some(crate::systems::vehicles::driving::DriveInput {
+                                vehicle: veh.to_bits().get(),
+                                throttle: input.forward as i32 as f32 - input.backward as i32 as f32,
+                                steer: (input.right as i32 - input.left as i32) as f32,
+                                handbrake: input.jump,
+                            });
+                            // The view turns with the body: feed the vehicle's yaw
+                            // change into the shared yaw. Body heading h and camera
+                            // yaw run opposite ways (forward_xz = (sin yaw, 0,
+                            // -cos yaw), body +X = (cos h, 0, -sin h)).
+                            let nose = vrot * Vec3::X;
+                            let heading = (-nose.z).atan2(nose.x);
+                            if let Some(prev) = state.driving_heading {
+                                let turn = (heading - prev + std::f32::consts::PI)
+                                    .rem_euclid(std::f32::consts::TAU)
+                                    - std::f32::consts::PI;
+                                state.camera.yaw -= turn;
+                            }
+                            state.driving_heading = Some(heading);
                             // Camera rides the cab; player entity travels along.
+                            let before = state.camera.position;
                             state.camera.position = vpos + Vec3::Y * cab_h;
+                            // On a planet the frame-lock anchor IS the camera's
+                            // ground point: carry it with the vehicle so the
+                            // world stays put under the wheels (the walk above
+                            // stood still while driving).
+                            if state.surface_owns_translation {
+                                let moved = (state.camera.position - before).as_dvec3();
+                                state.frame_lock_anchor +=
+                                    glam::DQuat::from_rotation_y(-state.frame_lock_last_spin) * moved;
+                            }
                             for (_e, (tf, _c)) in state.game_world.world.query_mut::<(
                                 &mut crate::ecs::components::Transform,
                                 &crate::ecs::components::Controllable,
@@ -4893,7 +5112,10 @@ mod native_app {
                                 break;
                             }
                         }
+                    } else {
+                        state.driving_heading = None;
                     }
+                    state.data_store.insert("drive_input", drive_input);
                     // ── FOLLOW CAM (Stage 3, v0.690): chase a self-driving vehicle.
                     // Engaged from the Vehicles section; broken by any WASD input
                     // or by the vehicle arriving (route removed). The camera hangs
@@ -4982,11 +5204,40 @@ mod native_app {
                     // Walk-up to a VEHICLE (Stage 3 take-over, v0.690): the nearest
                     // Vehicle entity within reach inside the look cone. Drives the
                     // "[E] drive X" prompt; E enters, E again exits. While driving,
-                    // the prompt is a constant "[E] exit vehicle".
+                    // the prompt is "[E] exit vehicle" plus the speed and, for a
+                    // tanked vehicle, the fuel gauge.
                     {
-                        if state.driving_vehicle.is_some() {
+                        if let Some(veh) = state.driving_vehicle {
                             state.targeted_vehicle = None;
-                            state.gui_state.vehicle_prompt = "[E] exit vehicle".to_string();
+                            let world = &state.game_world.world;
+                            let dynamics = world
+                                .get::<&crate::ecs::components::VehicleDynamics>(veh)
+                                .map(|d| *d)
+                                .unwrap_or_default();
+                            let tank = world
+                                .get::<&crate::ecs::components::Vehicle>(veh)
+                                .ok()
+                                .and_then(|v| {
+                                    state
+                                        .data_store
+                                        .get::<crate::systems::vehicles::VehicleKitRegistry>(
+                                            "vehicle_kit_registry",
+                                        )
+                                        .and_then(|r| r.get_vehicle(&v.item_id))
+                                        .map(|k| k.fuel_tank_l)
+                                })
+                                .unwrap_or(0.0);
+                            let mut prompt = format!(
+                                "[E] exit vehicle  ·  {:.0} km/h",
+                                dynamics.speed_mps.abs() * 3.6
+                            );
+                            if tank > 0.0 {
+                                prompt += &format!("  ·  fuel {:.0}/{tank:.0} L", dynamics.fuel_l);
+                                if dynamics.fuel_l <= 0.0 {
+                                    prompt += " (empty)";
+                                }
+                            }
+                            state.gui_state.vehicle_prompt = prompt;
                         } else {
                             let cp = state.camera.position;
                             let cf = state.camera.forward();
@@ -5064,7 +5315,7 @@ mod native_app {
                                     .get::<&Name>(e)
                                     .map(|n| n.0.clone())
                                     .unwrap_or_else(|_| "animal".to_string());
-                                match world.get::<&crate::ecs::components::Harvestable>(e) {
+                                let base = match world.get::<&crate::ecs::components::Harvestable>(e) {
                                     Ok(h) => {
                                         let item_name = state
                                             .data_store
@@ -5082,6 +5333,12 @@ mod native_app {
                                     // No renewable product = a wild creature:
                                     // abilities or a swing attack it. (v0.765)
                                     Err(_) => format!("{name} - 1-9 casts, F swings"),
+                                };
+                                // Herd state and the X/K verbs (husbandry).
+                                let registry = state.data_store.get("creature_registry");
+                                match crate::systems::husbandry::prompt(world, e, registry) {
+                                    Some(p) => format!("{base} · {p}"),
+                                    None => base,
                                 }
                             }
                             None => String::new(),
@@ -5276,8 +5533,14 @@ mod native_app {
                                     .and_then(|m| m.lock().ok())
                                     .map(|a| a.breathable)
                                     .unwrap_or(true);
+                                // The room you stand in sets how warm you are (thermal network).
+                                let room_c = state
+                                    .data_store
+                                    .get::<std::sync::Mutex<crate::systems::thermal::ThermalStatus>>("thermal_status")
+                                    .and_then(|m| m.lock().ok().and_then(|t| t.temp_at(pos.x, pos.z)));
                                 crate::ecs::components::EnvironmentContext {
                                     oxygenated: breathable,
+                                    ambient_temp_c: room_c.unwrap_or(21.0),
                                     ..Default::default()
                                 }
                             }
@@ -5916,6 +6179,18 @@ mod native_app {
                             }
                         }
                     }
+                    // ...and its Refuel action: DrivingSystem tops the tank
+                    // up from the fuel in the backpack.
+                    if let Some(bits) = state.gui_state.pending_refuel_vehicle.take() {
+                        if let Some(slot) = state
+                            .data_store
+                            .get::<std::sync::Mutex<Option<u64>>>("refuel_vehicle")
+                        {
+                            if let Ok(mut s) = slot.lock() {
+                                *s = Some(bits);
+                            }
+                        }
+                    }
                     // Plant a whole tower (v0.386): the GUI sends (tower id, plant ids).
                     if let Some(tower_planting) = state.gui_state.pending_plant_tower.take() {
                         if let Some(slot) = state
@@ -5938,6 +6213,36 @@ mod native_app {
                             }
                         }
                     }
+                    if let Some(amend) = state.gui_state.pending_amend_soil.take() {
+                        if let Some(slot) = state
+                            .data_store
+                            .get::<std::sync::Mutex<Option<(String, String, u32)>>>("amend_soil_request")
+                        {
+                            if let Ok(mut s) = slot.lock() {
+                                *s = Some(amend);
+                            }
+                        }
+                    }
+                    if let Some(bits) = state.gui_state.pending_save_seed.take() {
+                        if let Some(slot) = state
+                            .data_store
+                            .get::<std::sync::Mutex<Option<u64>>>("save_seed_request")
+                        {
+                            if let Ok(mut s) = slot.lock() {
+                                *s = Some(bits);
+                            }
+                        }
+                    }
+                    if let Some(naming) = state.gui_state.pending_name_cultivar.take() {
+                        if let Some(slot) = state
+                            .data_store
+                            .get::<std::sync::Mutex<Option<(String, String)>>>("name_cultivar_request")
+                        {
+                            if let Ok(mut s) = slot.lock() {
+                                *s = Some(naming);
+                            }
+                        }
+                    }
                     if let Some(bits) = state.gui_state.pending_water_crop.take() {
                         if let Some(slot) = state
                             .data_store
@@ -6368,9 +6673,20 @@ mod native_app {
                     // where the graceful close-save would not fire.
                     crate::save_load::maybe_periodic_save(
                         &state.game_world.world,
+                        &state.data_store,
                         &state.gui_state.placed_items,
+                        state.gui_state.settings.save_format(),
                         120,
                     );
+                    // Rolling autosave slots every 10 minutes: a fallback if
+                    // the home file itself is ever lost or corrupted.
+                    crate::save_load::maybe_auto_save(
+                        &state.game_world.world,
+                        &state.data_store,
+                        &state.gui_state.placed_items,
+                        state.gui_state.settings.save_format(),
+                        600,
+                    );
                     // Ship-structure autosave (v0.791): build edits used to persist
                     // ONLY through the explicit Save button; this + the close flush
                     // make walls/lights/strips/corridors as durable as inventory.
@@ -6814,7 +7130,12 @@ mod native_app {
                             *o = outfit.clone();
                             break;
                         }
-                        crate::save_load::save_active_home(&state.game_world.world, &state.gui_state.placed_items);
+                        crate::save_load::save_active_home(
+                            &state.game_world.world,
+                            &state.data_store,
+                            &state.gui_state.placed_items,
+                            state.gui_state.settings.save_format(),
+                        );
                         state.controller.showroom_lock = false;
                         state
                             .camera
@@ -8098,6 +8419,36 @@ mod native_app {
                         }
                     }
 
+                    // STRUCTURAL OVERLAY: every strained (amber) or broken (red) framing member from
+                    // the last solve, across all zones, plus a ring on each node left hanging. Not gated by the
+                    // helper toggle -- it is a warning, not a helper.
+                    if state.gui_state.construction_active {
+                        const STRAINED: [f32; 4] = [1.0, 0.72, 0.15, 0.95];
+                        const BROKEN: [f32; 4] = [1.0, 0.18, 0.12, 1.0];
+                        for report in &state.gui_state.construction_framing {
+                            for (a, b, s) in report.flagged_members() {
+                                let col = if s == crate::systems::construction::solver::MemberState::Broken { BROKEN } else { STRAINED };
+                                crate::renderer::line::push_polyline(&mut ring_lines, &[a.into(), b.into()], col);
+                            }
+                            for p in report.island_points() {
+                                crate::renderer::line::push_circle(&mut ring_lines, p.into(), 0.25, BROKEN, 16);
+                            }
+                        }
+                    }
+                    // ABILITY + WEAPON MARKERS: casts and rounds in flight (small rings) and
+                    // lingering areas (their radius) from AbilitySystem / WeaponSystem, so a fireball's
+                    // path, an arrow's arc and a ground zone are readable.
+                    for (key, col) in [("ability_markers", [1.0, 0.45, 0.9, 0.9]), ("weapon_markers", [1.0, 0.9, 0.6, 0.9])] {
+                        let markers = state
+                            .data_store
+                            .get::<std::sync::Mutex<Vec<(Vec3, f32)>>>(key)
+                            .and_then(|m| m.lock().ok().map(|g| g.clone()))
+                            .unwrap_or_default();
+                        for (c, r) in markers {
+                            let segments = if r < 1.0 { 10 } else { 32 };
+                            crate::renderer::line::push_circle(&mut ring_lines, c.into(), r, col, segments);
+                        }
+                    }
                     // HELPER GIZMOS (v0.583/586/587): bounds boxes on placed structures + machines, the
                     // road graph (node rings + edge centerlines), and conduit-node markers -- the helper
                     // widgets the operator asked for "on everything." All drawn with the reusable line
@@ -12613,6 +12964,7 @@ mod native_app {
                                     state.pending_sfx.push((
                                         "sfx.inventory_pickup",
                                         "audio/ui/inventory_pickup.ogg",
+                                        None,
                                     ));
                                     let mut player: Option<hecs::Entity> = None;
                                     for (e, _c) in
@@ -12650,14 +13002,12 @@ mod native_app {
                                             "farming",
                                             5,
                                         );
-                                        if let Some(events) = state
-                                            .data_store
-                                            .get::<std::sync::Mutex<Vec<String>>>("quest_events")
-                                        {
-                                            if let Ok(mut ev) = events.lock() {
-                                                ev.push(format!("harvest_{creature_id}"));
-                                            }
-                                        }
+                                        crate::systems::quests::push_quest_event(
+                                            &state.data_store,
+                                            crate::systems::quests::QuestEvent::Harvested {
+                                                crop_id: creature_id.to_string(),
+                                            },
+                                        );
                                         notice = format!("+{n} {item_name} from {animal_name}");
                                     } else {
                                         // Refused: put the yield back on the animal.
@@ -12679,6 +13029,16 @@ mod native_app {
                         state.gui_state.livestock_notice = notice;
                         state.gui_state.livestock_notice_at = now_s;
                     }
+                    // Feed / tame / slaughter outcomes share the notice line.
+                    if let Some(status) = state
+                        .data_store
+                        .get::<std::sync::Mutex<String>>("husbandry_status")
+                        .and_then(|m| m.lock().ok().map(|mut s| std::mem::take(&mut *s)))
+                        .filter(|s| !s.is_empty())
+                    {
+                        state.gui_state.livestock_notice = status;
+                        state.gui_state.livestock_notice_at = now_s;
+                    }
                     // The collect notice fades after 3 game-seconds (same clock
                     // as the attack pulse above).
                     if !state.gui_state.livestock_notice.is_empty()
@@ -12687,57 +13047,42 @@ mod native_app {
                         state.gui_state.livestock_notice.clear();
                     }
 
-                    // ── Melee swing settle (v0.765) ── F swings the worn
-                    // hands-slot weapon (its equipment.csv damage + reach) or
-                    // bare hands at the faced creature; the hit rides the one
-                    // damage pipeline, so armor/death/loot/kill-XP all apply.
+                    // ── Attack settle (v0.765) ── F queues an
+                    // attack for WeaponSystem with the faced creature and the
+                    // crosshair ray; it swings or fires whatever is in the
+                    // hands (pacing, ammo and wear are its business) and the
+                    // hit rides the one damage pipeline, so armor/death/loot/
+                    // kill-XP all apply. Its line shares the ability status.
                     if std::mem::take(&mut state.gui_state.pending_swing) {
-                        if now_s >= state.gui_state.swing_ready_at {
-                            state.gui_state.swing_ready_at = now_s + 0.8;
-                            // What is in the hands? A tool row wins; bare
-                            // hands are the honest fallback.
-                            let hands_item: Option<String> = state
-                                .game_world
-                                .world
-                                .query::<(&crate::ecs::components::Outfit, &Controllable)>()
-                                .iter()
-                                .next()
-                                .and_then(|(_, (o, _))| o.equipped.get("hands").cloned());
-                            let (weapon_name, damage, range) = hands_item
-                                .as_deref()
-                                .and_then(|id| {
-                                    let def = state
-                                        .data_store
-                                        .get::<crate::systems::economy::EquipmentRegistry>(
-                                            "equipment_registry",
-                                        )?
-                                        .get(id)?;
-                                    if def.damage <= 0.0 {
-                                        return None; // gloves are not weapons
-                                    }
-                                    let name = state
-                                        .data_store
-                                        .get::<ItemRegistry>("item_registry")
-                                        .and_then(|r| r.items.get(id))
-                                        .map(|d| d.name.clone())
-                                        .unwrap_or_else(|| id.to_string());
-                                    Some((name, def.damage, def.range_m.max(1.5)))
-                                })
-                                .unwrap_or(("Bare hands".to_string(), 3.0, 1.5));
-                            let status = match state.targeted_livestock {
-                                Some(target) => crate::systems::combat::player_swing(
-                                    &mut state.game_world.world,
-                                    &state.data_store,
-                                    target,
-                                    state.camera.position,
-                                    &weapon_name,
-                                    damage,
-                                    range,
-                                ),
-                                None => "Nothing in reach".to_string(),
-                            };
-                            state.gui_state.ability_status = status;
-                            state.gui_state.ability_status_at = now_s;
+                        let aim = (state.camera.position - state.station_off, state.camera.forward());
+                        if let Some(req) = state.data_store.get::<std::sync::Mutex<
+                            Vec<crate::systems::combat::weapons::AttackRequest>,
+                        >>("attack_request")
+                        {
+                            if let Ok(mut v) = req.lock() {
+                                v.push(crate::systems::combat::weapons::AttackRequest {
+                                    target: state.targeted_livestock.map(|e| e.to_bits().into()),
+                                    aim: Some(aim),
+                                });
+                            }
+                        }
+                    }
+                    if let Some(status) = state
+                        .data_store
+                        .get::<std::sync::Mutex<String>>("weapon_status")
+                        .and_then(|m| m.lock().ok().map(|mut s| std::mem::take(&mut *s)))
+                        .filter(|s| !s.is_empty())
+                    {
+                        state.gui_state.ability_status = status;
+                        state.gui_state.ability_status_at = now_s;
+                        // A weapon that broke left the hands: refresh the mirror.
+                        for (_e, (outfit, _c)) in state
+                            .game_world
+                            .world
+                            .query::<(&crate::ecs::components::Outfit, &Controllable)>()
+                            .iter()
+                        {
+                            state.gui_state.outfit = outfit.clone();
                         }
                     }
 
@@ -12881,10 +13226,23 @@ mod native_app {
                         state.gui_state.wallet_credits = wallet.credits;
                         break;
                     }
-                    // Vendor catalog, built once: goods that exist in BOTH
-                    // trade_goods.ron and items.csv (never sell an id the game
-                    // cannot hold), sorted by category then name.
-                    if state.gui_state.vendor_goods.is_empty() {
+                    // Bred seed is an item and a trade good like the plain
+                    // seed it came from; cultivars appear as seed is saved.
+                    if let Some(items) = state.data_store.get_mut::<ItemRegistry>("item_registry") {
+                        crate::systems::farming::breeding::register_seed_items(&state.game_world.world, items);
+                    }
+                    if let Some(goods) = state
+                        .data_store
+                        .get_mut::<crate::systems::economy::TradeGoodsRegistry>("trade_goods_registry")
+                    {
+                        crate::systems::farming::breeding::register_seed_goods(&state.game_world.world, goods);
+                    }
+                    // Vendor catalog: goods that exist in BOTH trade_goods.ron
+                    // and items.csv (never sell an id the game cannot hold),
+                    // sorted by category then name. Rebuilt every frame the
+                    // modal is open: prices, stock and history follow the
+                    // open post's market (fixed prices without one).
+                    if state.gui_state.vendor_open || state.gui_state.vendor_goods.is_empty() {
                         if let (Some(goods), Some(items)) = (
                             state
                                 .data_store
@@ -12893,16 +13251,41 @@ mod native_app {
                                 ),
                             state.data_store.get::<ItemRegistry>("item_registry"),
                         ) {
+                            let markets = state
+                                .data_store
+                                .get::<std::sync::Mutex<crate::systems::economy::market::VendorMarkets>>(
+                                    "vendor_markets",
+                                )
+                                .and_then(|m| m.lock().ok());
+                            let (vendor, region) = &state.gui_state.vendor_post;
                             let mut list: Vec<crate::gui::GuiTradeGood> = goods
                                 .goods
                                 .values()
                                 .filter(|g| items.items.contains_key(&g.id))
-                                .map(|g| crate::gui::GuiTradeGood {
-                                    id: g.id.clone(),
-                                    name: g.name.clone(),
-                                    category: g.category.clone(),
-                                    buy_price: goods.vendor_sell_price(&g.id).unwrap_or(1),
-                                    sell_price: goods.vendor_buy_price(&g.id).unwrap_or(0),
+                                .map(|g| match markets.as_deref() {
+                                    Some(m) => crate::gui::GuiTradeGood {
+                                        id: g.id.clone(),
+                                        name: g.name.clone(),
+                                        category: g.category.clone(),
+                                        buy_price: m.sell_price(vendor, region, g),
+                                        sell_price: m.buy_price(vendor, region, g),
+                                        stock: m.stock(vendor, region, g).floor() as u32,
+                                        history: m
+                                            .vendors
+                                            .get(vendor)
+                                            .and_then(|v| v.history.get(&g.id))
+                                            .cloned()
+                                            .unwrap_or_default(),
+                                    },
+                                    None => crate::gui::GuiTradeGood {
+                                        id: g.id.clone(),
+                                        name: g.name.clone(),
+                                        category: g.category.clone(),
+                                        buy_price: goods.vendor_sell_price(&g.id).unwrap_or(1),
+                                        sell_price: goods.vendor_buy_price(&g.id).unwrap_or(0),
+                                        stock: 99,
+                                        history: Vec::new(),
+                                    },
                                 })
                                 .collect();
                             list.sort_by(|a, b| {
@@ -12932,6 +13315,16 @@ mod native_app {
                                 ),
                         ) {
                             let items = state.data_store.get::<ItemRegistry>("item_registry");
+                            let mut markets = state
+                                .data_store
+                                .get::<std::sync::Mutex<crate::systems::economy::market::VendorMarkets>>(
+                                    "vendor_markets",
+                                )
+                                .and_then(|m| m.lock().ok());
+                            let (vendor, region) = &state.gui_state.vendor_post;
+                            let desk = markets.as_deref_mut().map(|markets| {
+                                crate::systems::economy::VendorDesk { markets, vendor, region }
+                            });
                             let result = (|| -> Result<String, String> {
                                 let mut inv = state
                                     .game_world
@@ -12951,6 +13344,7 @@ mod native_app {
                                         items,
                                         &id,
                                         qty,
+                                        desk,
                                     )
                                 } else if let Some((id, qty)) = vendor_ops.1 {
                                     crate::systems::economy::vendor_sell(
@@ -12959,6 +13353,7 @@ mod native_app {
                                         goods,
                                         &id,
                                         qty,
+                                        desk,
                                     )
                                 } else {
                                     Ok(String::new())
@@ -12974,14 +13369,22 @@ mod native_app {
                     // ── Abilities bridge (v0.753, ladder rung 8) ──
                     // Cast click -> the ability_request channel AbilitySystem
                     // drains at its tick; the status line rides back the same
-                    // frame it lands.
+                    // frame it lands. The crosshair ray (entity space) aims
+                    // offensive rows with no faced target.
                     if let Some((id, target)) = state.gui_state.pending_cast.take() {
+                        let aim = (state.camera.position - state.station_off, state.camera.forward());
                         if let Some(req) = state
                             .data_store
-                            .get::<std::sync::Mutex<Vec<(String, Option<u64>)>>>("ability_request")
+                            .get::<std::sync::Mutex<Vec<crate::systems::abilities::CastRequest>>>(
+                                "ability_request",
+                            )
                         {
                             if let Ok(mut v) = req.lock() {
-                                v.push((id, target));
+                                v.push(crate::systems::abilities::CastRequest::new(
+                                    &id,
+                                    target,
+                                    Some(aim),
+                                ));
                             }
                         }
                     }
@@ -13030,9 +13433,9 @@ mod native_app {
                                     for def in reg.defs.values() {
                                         let gate_ok = def.skill_gate_met(skills);
                                         // Healing rows self-cast; damage rows
-                                        // cast at the faced creature (v0.760).
-                                        let has_effect =
-                                            def.self_castable() || def.damage_base > 0.0;
+                                        // go where their delivery sends them:
+                                        // target, area or projectile.
+                                        let has_effect = def.delivery().is_some();
                                         let castable = has_effect && gate_ok;
                                         let locked_reason = if !has_effect {
                                             "Does nothing yet - a later arc wires it".to_string()
@@ -13260,6 +13663,8 @@ mod native_app {
                     }
                     // Bridge inventory from the player entity
                     let item_registry = state.data_store.get::<ItemRegistry>("item_registry");
+                    // Cultivar seeds are bred in play, so not in items.csv.
+                    let cultivars = crate::systems::farming::breeding::cultivar_names(&state.game_world.world);
                     for (_entity, (inv, _ctrl)) in state.game_world.world.query::<(&Inventory, &Controllable)>().iter() {
                         state.gui_state.inventory_max_slots = inv.max_slots;
                         state.gui_state.inventory_items = inv.slots.iter().map(|slot| {
@@ -13267,6 +13672,7 @@ mod native_app {
                                 let name = item_registry
                                     .and_then(|reg| reg.items.get(&stack.item_id))
                                     .map(|def| def.name.clone())
+                                    .or_else(|| cultivars.get(&stack.item_id).map(|n| format!("{n} Seeds")))
                                     .unwrap_or_else(|| stack.item_id.clone());
                                 GuiItemSlot {
                                     item_id: stack.item_id.clone(),
@@ -13365,11 +13771,12 @@ mod native_app {
                     {
                         let quest_reg =
                             state.data_store.get::<QuestRegistry>("quest_registry");
+                        let quest_day = crate::systems::quests::current_day(&state.data_store);
                         state.gui_state.quests.clear();
-                        for (_e, (tracker, _ctrl)) in state
+                        for (_e, (tracker, _ctrl, skills)) in state
                             .game_world
                             .world
-                            .query::<(&QuestTracker, &Controllable)>()
+                            .query::<(&QuestTracker, &Controllable, Option<&crate::systems::skills::PlayerSkills>)>()
                             .iter()
                         {
                             for active in &tracker.active_quests {
@@ -13377,9 +13784,10 @@ mod native_app {
                                 let name = def
                                     .map(|d| d.name.clone())
                                     .unwrap_or_else(|| active.quest_id.clone());
-                                let step_total = def.map(|d| d.steps.len()).unwrap_or(0);
+                                let branch = active.branch.as_deref();
+                                let step_total = def.map(|d| d.step_count(branch)).unwrap_or(0);
                                 let step_desc = def
-                                    .and_then(|d| d.steps.get(active.current_step))
+                                    .and_then(|d| d.step_at(branch, active.current_step))
                                     .map(|s| s.description.clone())
                                     .unwrap_or_default();
                                 state.gui_state.quests.push(crate::gui::GuiQuest {
@@ -13403,51 +13811,53 @@ mod native_app {
                                     completed: true,
                                 });
                             }
-                            // Available quests (v0.748, ladder rung 4): accept-able
-                            // content the chaining alone never reached — quests with
-                            // no prerequisite (or a completed one) that aren't active
-                            // or done. Sorted for a stable list.
+                            // Available quests (v0.748, ladder rung 4): every quest whose
+                            // graph gates are open, plus forks awaiting a dialogue choice.
                             state.gui_state.quests_available.clear();
                             if let Some(reg) = quest_reg {
-                                let mut avail: Vec<crate::gui::GuiAvailableQuest> = reg
-                                    .quests
-                                    .values()
-                                    .filter(|d| {
-                                        !tracker.is_active(&d.id)
-                                            && !tracker.is_completed(&d.id)
-                                            && d.prerequisite
-                                                .as_deref()
-                                                .map_or(true, |p| tracker.is_completed(p))
-                                    })
+                                state.gui_state.quests_available = reg
+                                    .available(tracker, skills, quest_day)
+                                    .into_iter()
                                     .map(|d| crate::gui::GuiAvailableQuest {
                                         id: d.id.clone(),
                                         name: d.name.clone(),
                                         description: d.description.clone(),
                                     })
                                     .collect();
-                                avail.sort_by(|a, b| a.name.cmp(&b.name));
-                                state.gui_state.quests_available = avail;
+                                state.gui_state.quest_choices = crate::systems::quests::pending_choices(reg, tracker);
                             }
                             break;
                         }
-                        // Accept clicked: apply to the live tracker.
-                        if let Some(qid) = state.gui_state.pending_accept_quest.take() {
-                            for (_e, (tracker, _c)) in state
-                                .game_world
-                                .world
-                                .query_mut::<(&mut QuestTracker, &Controllable)>()
-                            {
-                                tracker.accept_quest(&qid);
+                        // Accept clicked: apply to the live tracker (re-checking its gates).
+                        if let (Some(qid), Some(reg)) = (state.gui_state.pending_accept_quest.take(), quest_reg) {
+                            for (_e, (tracker, _c, skills)) in state.game_world.world.query_mut::<(
+                                &mut QuestTracker,
+                                &Controllable,
+                                Option<&crate::systems::skills::PlayerSkills>,
+                            )>() {
+                                reg.accept(tracker, &qid, skills, quest_day);
                                 break;
                             }
                         }
+                        // Branch picked on the NPC talk card: QuestSystem applies it.
+                        if let Some((quest_id, branch_id)) = state.gui_state.pending_quest_choice.take() {
+                            crate::systems::quests::push_quest_event(
+                                &state.data_store,
+                                QuestEvent::Chose { quest_id, branch_id },
+                            );
+                        }
                     }
                     // Bridge growing crops from ECS for the gardening (Garden) panel.
                     {
                         let plant_reg = state
                             .data_store
                             .get::<crate::systems::farming::PlantRegistry>("plant_registry");
+                        let genetics = state
+                            .data_store
+                            .get::<crate::systems::genetics::GeneticsSystem>("genetics");
+                        let cultivars = crate::systems::farming::breeding::cultivar_names(&state.game_world.world);
                         state.gui_state.crops.clear();
+                        state.gui_state.soil = crate::systems::farming::soil::area_averages(&state.game_world.world);
                         for (entity, crop) in state
                             .game_world
                             .world
@@ -13494,6 +13904,20 @@ mod native_app {
                                 water_per_day: def.map(|d| d.water_per_day).unwrap_or(0.0),
                                 temp_min: def.map(|d| d.temp_min_c).unwrap_or(0.0),
                                 temp_max: def.map(|d| d.temp_max_c).unwrap_or(0.0),
+                                cultivar_seed: crop.cultivar.clone(),
+                                cultivar: crop
+                                    .cultivar
+                                    .as_ref()
+                                    .and_then(|id| cultivars.get(id).cloned())
+                                    .unwrap_or_default(),
+                                genes: crop
+                                    .genome
+                                    .as_ref()
+                                    .map(|g| {
+                                        crate::systems::farming::breeding::Phenotype::of(genetics, Some(g))
+                                            .describe(def.map_or(0.0, |d| d.growth_days))
+                                    })
+                                    .unwrap_or_default(),
                             });
                         }
                         // One-time tower compatibility (operator: "make sure they
@@ -13559,10 +13983,14 @@ mod native_app {
                         // disables Launch while one is in flight.
                         state.gui_state.drone_active = !state.gui_state.drones.is_empty();
                         // World vehicles (Stage 3, v0.680): name + distance from the
-                        // player + transit state, for the Inventory Vehicles section.
+                        // player + transit state + fuel gauge, for the
+                        // Inventory Vehicles section.
                         state.gui_state.vehicles.clear();
                         let cam_pos = state.camera.position;
-                        for (e, (_v, tf, name, route)) in state
+                        let kits = state
+                            .data_store
+                            .get::<crate::systems::vehicles::VehicleKitRegistry>("vehicle_kit_registry");
+                        for (e, (v, tf, name, route, dynamics)) in state
                             .game_world
                             .world
                             .query::<(
@@ -13570,14 +13998,21 @@ mod native_app {
                                 &crate::ecs::components::Transform,
                                 Option<&crate::ecs::components::Name>,
                                 Option<&crate::ecs::components::VehicleRoute>,
+                                Option<&crate::ecs::components::VehicleDynamics>,
                             )>()
                             .iter()
                         {
+                            let tank = kits
+                                .and_then(|r| r.get_vehicle(&v.item_id))
+                                .map(|k| k.fuel_tank_l)
+                                .unwrap_or(0.0);
                             state.gui_state.vehicles.push(crate::gui::GuiVehicle {
                                 bits: e.to_bits().into(),
                                 name: name.map(|n| n.0.clone()).unwrap_or_else(|| "Vehicle".into()),
                                 distance: (tf.position - cam_pos).length(),
                                 in_transit: route.is_some(),
+                                fuel: (tank > 0.0)
+                                    .then(|| (dynamics.map(|d| d.fuel_l).unwrap_or(tank), tank)),
                             });
                         }
                         // Live production status lines (v0.681) for the same section.
@@ -13969,6 +14404,11 @@ mod native_app {
                             wind_speed: w.wind_speed,
                             event: w.event_name.clone(),
                             warning: String::new(),
+                            forecast: w
+                                .forecast
+                                .as_ref()
+                                .map(|f| format!("{} in {:.0} min", f.event_name, (f.starts_in_s / 60.0).ceil()))
+                                .unwrap_or_default(),
                         });
                         // Foliage wind (v0.1080): publish the live weather
                         // wind to the renderer so the trees lean and gust
@@ -14057,84 +14497,16 @@ mod native_app {
                             state.cloud_event_tint[i] +=
                                 (tt[i] - state.cloud_event_tint[i]) * ek;
                         }
-                        // Vortex core lifecycle + proximity (v0.1038,
-                        // log + HUD scope; forces/damage are the next
-                        // rung, and wind does not move the player yet so
-                        // no physics is invented here). Core is placed
-                        // ONCE per event, 200-600 m from the player in
-                        // PLANET-FRAME metres; a visible funnel needs the
-                        // planet-spin transform the celestial pass owns,
-                        // so it is deferred with the damage rung.
-                        let vortex_hazard = if w.event_id.is_empty() {
-                            None
-                        } else {
-                            state
-                                .data_store
-                                .get::<crate::systems::weather_events::WeatherEventRegistry>(
-                                    "weather_event_registry",
-                                )
-                                .and_then(|r| r.events.iter().find(|e| e.id == w.event_id))
-                                .filter(|e| {
-                                    matches!(
-                                        e.wind_profile,
-                                        crate::systems::weather_events::WindProfile::Vortex { .. }
-                                    )
-                                })
-                                .map(|e| e.hazard.radius_m)
-                        };
-                        match vortex_hazard {
-                            Some(radius_m) if state.frame_lock_body.is_some() => {
-                                let anchor = state.frame_lock_anchor;
-                                let core = *state.event_core.get_or_insert_with(|| {
-                                    // Cheap deterministic-enough rolls from
-                                    // the wall clock micros (placement is
-                                    // cosmetic; no gameplay fairness rides
-                                    // on it).
-                                    let us = state.start_time.elapsed().as_micros() as u64;
-                                    let bearing =
-                                        (us % 6283) as f64 / 1000.0;
-                                    let dist = 200.0 + (us / 7 % 400) as f64;
-                                    let c =
-                                        crate::systems::weather_events::place_event_core(
-                                            anchor, bearing, dist,
-                                        );
-                                    log::info!(
-                                        "[WeatherEvent] vortex core placed {:.0} m from player",
-                                        (c - anchor).length()
-                                    );
-                                    c
-                                });
-                                let dist = (anchor - core).length();
-                                let (near, inside) =
-                                    crate::systems::weather_events::hazard_proximity(
-                                        dist, radius_m,
-                                    );
-                                if near != state.event_hazard_near {
-                                    state.event_hazard_near = near;
-                                    log::info!(
-                                        "[WeatherEvent] hazard zone {} ({:.0} m from core, radius {radius_m} m)",
-                                        if near { "ENTERED (3x radius)" } else { "left" },
-                                        dist
-                                    );
-                                }
-                                if let Some(gw) = state.gui_state.weather.as_mut() {
-                                    gw.warning = if inside {
-                                        format!("{} - TAKE COVER!", w.event_name)
-                                    } else if near {
-                                        format!("{} nearby!", w.event_name)
-                                    } else {
-                                        String::new()
-                                    };
-                                }
-                            }
-                            _ => {
-                                if state.event_core.take().is_some() {
-                                    state.event_hazard_near = false;
-                                }
-                                if let Some(gw) = state.gui_state.weather.as_mut() {
-                                    gw.warning.clear();
-                                }
-                            }
+                        // Hazard proximity (v0.1038; the core lives in the
+                        // weather sim, where its damage lands).
+                        if let Some(gw) = state.gui_state.weather.as_mut() {
+                            gw.warning = if w.hazard_inside {
+                                format!("{} - TAKE COVER!", w.event_name)
+                            } else if w.hazard_near {
+                                format!("{} nearby!", w.event_name)
+                            } else {
+                                String::new()
+                            };
                         }
                     }
 
@@ -14255,6 +14627,23 @@ mod native_app {
                         state.gui_state.power_battery_capacity_wh = ps.battery_capacity_wh;
                         state.gui_state.power_autonomy_hours = ps.autonomy_hours;
                     }
+                    if let Some(r) = state.data_store.get::<std::sync::Mutex<crate::systems::power_grid::GridReport>>("grid_report").and_then(|m| m.lock().ok()) {
+                        state.gui_state.power_grid = r.clone();
+                    }
+                    if std::mem::take(&mut state.gui_state.grid_reset_requested) {
+                        if let Some(mut r) = state.data_store.get::<std::sync::Mutex<bool>>("grid_reset_request").and_then(|m| m.lock().ok()) {
+                            *r = true;
+                        }
+                    }
+                    // Fleet logistics: report in, Home page clicks out.
+                    if let Some(r) = state.data_store.get::<std::sync::Mutex<crate::systems::transportation::LogisticsReport>>("logistics_report").and_then(|m| m.lock().ok()) {
+                        state.gui_state.logistics = r.clone();
+                    }
+                    if !state.gui_state.logistics_requests.is_empty() {
+                        if let Some(mut q) = state.data_store.get::<std::sync::Mutex<Vec<crate::systems::transportation::LogisticsRequest>>>("logistics_request").and_then(|m| m.lock().ok()) {
+                            q.append(&mut state.gui_state.logistics_requests);
+                        }
+                    }
 
                     // Bridge the live home WATER readout (PlumbingSystem writes it via Mutex). (v0.608)
                     if let Some(ws) = state
@@ -14311,18 +14700,19 @@ mod native_app {
                                 });
                             }
                         }
-                        // (tank, battery, container-fill + contents-line)
+                        // (tank, battery, container-fill + contents-line, production line)
                         type LiveStats = (
                             Option<(f32, f32)>,
                             Option<(f32, f32)>,
                             Option<(String, String, &'static str)>,
+                            Option<(String, &'static str)>,
                         );
                         let item_reg = state
                             .data_store
                             .get::<crate::systems::inventory::ItemRegistry>("item_registry");
                         let mut live: std::collections::HashMap<String, LiveStats> =
                             std::collections::HashMap::new();
-                        for (_e, (mid, tank, bat, cont)) in state
+                        for (_e, (mid, tank, bat, cont, fac)) in state
                             .game_world
                             .world
                             .query::<(
@@ -14330,10 +14720,11 @@ mod native_app {
                                 Option<&WaterTank>,
                                 Option<&Battery>,
                                 Option<&crate::systems::inventory::containers::Container>,
+                                Option<&crate::ecs::components::ProductionFacility>,
                             )>()
                             .iter()
                         {
-                            if tank.is_none() && bat.is_none() && cont.is_none() {
+                            if tank.is_none() && bat.is_none() && cont.is_none() && fac.is_none() {
                                 continue;
                             }
                             // Typed container -> a fill line + a contents line
@@ -14359,11 +14750,15 @@ mod native_app {
                                     tank.map(|t| (t.liters, t.capacity_l)),
                                     bat.map(|b| (b.charge_wh, b.capacity_wh)),
                                     cont_rows,
+                                    fac.map(|f| f.status_line()),
                                 ),
                             );
                         }
                         for label in &mut state.gui_state.machine_labels {
-                            let Some((tank, bat, cont)) = live.get(&label.machine_id) else { continue };
+                            let Some((tank, bat, cont, fac)) = live.get(&label.machine_id) else { continue };
+                            if let Some((line, fstatus)) = fac {
+                                patch_stat(&mut label.stats, &["progress"], "progress", line.clone(), fstatus);
+                            }
                             if let Some((liters, cap)) = tank {
                                 let frac = if *cap > 0.0 { liters / cap } else { 0.0 };
                                 let status = if frac < 0.15 { "low" } else { "ok" };
@@ -14734,19 +15129,24 @@ mod native_app {
                         state.gui_state.machine_card_container = container_pub;
                         // Trading post? (v0.747, ladder rung 3): the pinned
                         // card grows a Trade button that opens the vendor.
-                        state.gui_state.machine_card_vendor = state
+                        // The post's zone is its market region.
+                        let post = state
                             .gui_state
                             .selected_machine
                             .and_then(|i| state.gui_state.machine_labels.get(i))
                             .map(|l| l.machine_id.clone())
                             .and_then(|mid| {
-                                state.gui_state.home_machines.as_ref().map(|hm| {
+                                state.gui_state.home_machines.as_ref().and_then(|hm| {
                                     hm.instances
                                         .iter()
-                                        .any(|inst| inst.id == mid && inst.machine == "trading_post")
+                                        .find(|inst| inst.id == mid && inst.machine == "trading_post")
+                                        .map(|inst| (inst.id.clone(), inst.zone.clone()))
                                 })
-                            })
-                            .unwrap_or(false);
+                            });
+                        state.gui_state.machine_card_vendor = post.is_some();
+                        if let Some(post) = post {
+                            state.gui_state.machine_card_vendor_post = post;
+                        }
                     }
 
                     // Dev autopilot (v0.793): zero-click world entry for scripted
@@ -15071,6 +15471,7 @@ mod native_app {
                                             state.pending_sfx.push((
                                                 "sfx.chat_message",
                                                 "audio/ui/chat_message.ogg",
+                                                None,
                                             ));
                                         }
                                         state.gui_state.chat_messages.push(
@@ -15774,6 +16175,7 @@ mod native_app {
                                             state.pending_sfx.push((
                                                 "sfx.chat_message",
                                                 "audio/ui/chat_message.ogg",
+                                                None,
                                             ));
                                         }
                                         let preview = if is_from_me {
@@ -17019,11 +17421,14 @@ mod native_app {
                             if let Ok(entries) = std::fs::read_dir(&dir) {
                                 for entry in entries.filter_map(|e| e.ok()) {
                                     let path = entry.path();
-                                    if path.extension().and_then(|x| x.to_str()) != Some("json") {
+                                    if !crate::persistence::is_save_file(&path) {
                                         continue;
                                     }
-                                    if let Ok(save) = crate::persistence::load_world(&path) {
-                                        if save.name == name {
+                                    // Scan in memory; only the chosen save gets the
+                                    // real (backed-up) migrating load.
+                                    let listed = crate::persistence::read_world(&path);
+                                    if listed.is_ok_and(|save| save.name == name) {
+                                        if let Ok(save) = crate::persistence::load_world(&path) {
                                             crate::save_load::apply_save_to_world(
                                                 &mut state.game_world.world,
                                                 &save,
@@ -17952,40 +18357,21 @@ mod native_app {
                                         state.stride_accum = 0.0;
                                     }
                                 }
-                                // One-shot gameplay SFX queue (v0.983): drain
-                                // whatever action sites pushed this frame
-                                // (collect pickup, door open/close edges) -
-                                // each id resolves through the catalog.
-                                for (id, fallback) in state.pending_sfx.drain(..) {
-                                    let path = format!(
-                                        "assets/{}",
-                                        state.sound_catalog.path_or(id, fallback)
-                                    );
-                                    let vol = state.sound_catalog.volume_or_default(id);
-                                    if let Err(e) = audio.play_sound_vol(&path, vol) {
-                                        static WARNED: std::sync::Once = std::sync::Once::new();
-                                        WARNED.call_once(|| log::warn!("[Audio] sfx {id}: {e}"));
-                                    }
-                                }
-                                // ECS-system SFX channel (v0.985): construction
-                                // and crafting completions arrive here (systems
-                                // have no engine state - see push_sfx_event).
-                                let system_sfx: Vec<(String, String)> = state
-                                    .data_store
-                                    .get::<std::sync::Mutex<Vec<(String, String)>>>("sfx_events")
-                                    .and_then(|m| m.lock().ok().map(|mut e| e.drain(..).collect()))
-                                    .unwrap_or_default();
-                                for (id, fallback) in system_sfx {
-                                    let path = format!(
-                                        "assets/{}",
-                                        state.sound_catalog.path_or(&id, &fallback)
-                                    );
-                                    let vol = state.sound_catalog.volume_or_default(&id);
-                                    if let Err(e) = audio.play_sound_vol(&path, vol) {
-                                        static WARNED2: std::sync::Once = std::sync::Once::new();
-                                        WARNED2.call_once(|| log::warn!("[Audio] sfx {id}: {e}"));
-                                    }
-                                }
+                                // One-shot gameplay SFX (v0.983/v0.985): the
+                                // engine queue + the ECS channel, spatially mixed.
+                                let ear = crate::engine::audio_mix::listener(
+                                    &state.camera,
+                                    &mut state.audio_listener_prev,
+                                    dt,
+                                );
+                                crate::engine::audio_mix::play_queued_sfx(
+                                    audio,
+                                    &ear,
+                                    &mut state.pending_sfx,
+                                    &state.data_store,
+                                    &state.sound_catalog,
+                                    &state.wall_colliders,
+                                );
                                 // UI click (v0.1112): fire ONLY when egui
                                 // reported a REAL widget activation this frame -
                                 // a button/link/toggle was actually clicked -
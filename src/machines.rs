@@ -121,6 +121,41 @@ pub struct MachineDef {
     /// a bad model never blanks the machine.
     #[serde(default)]
     pub model: Option<String>,
+    /// Climate role: a furnace or air conditioner is a thermostat on the room it stands in.
+    /// Spawns an `HvacUnit` rated at the machine's Consumer watts; the thermal network runs it in
+    /// proportion to how far the room is from `target_temp`, and its draw follows. None = no climate
+    /// role (its draw still warms the room, as every running appliance does).
+    #[serde(default)]
+    pub hvac: Option<MachineHvac>,
+    /// Production role: a `facility_recipes` id from `data/manufacturing.ron` this machine
+    /// works unit by unit, pulling inputs from containers beside it and handing its output on to the
+    /// next facility or a container. Spawns a `ProductionFacility`. None = not part of a chain.
+    #[serde(default)]
+    pub facility: Option<MachineFacility>,
+}
+
+/// A machine's production line: which chain recipe it runs and how long one unit takes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MachineFacility {
+    pub recipe: String,
+    #[serde(default = "default_days_per_unit")]
+    pub days_per_unit: f32,
+}
+
+fn default_days_per_unit() -> f32 {
+    1.0
+}
+
+/// A machine's thermostat: "heat" / "cool" / "vent" toward `target_temp` (°C).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MachineHvac {
+    pub mode: String,
+    #[serde(default = "default_hvac_target")]
+    pub target_temp: f32,
+}
+
+fn default_hvac_target() -> f32 {
+    21.0
 }
 
 /// Bulk storage a machine provides for one utility (v0.608). `capacity` is litres for a fluid
@@ -303,6 +338,10 @@ pub struct MachineConnection {
     /// copper that carries the load. `#[serde(default)]` so every existing connection parses unchanged.
     #[serde(default)]
     pub spec: Option<String>,
+    /// The overcurrent device guarding a power run (a `protection.ron` id), or None for a
+    /// standard breaker. Either way it is rated at the cable's ampacity.
+    #[serde(default)]
+    pub protection: Option<String>,
 }
 
 /// One self-sufficiency loop (energy / water / food / nutrients): whether it closes and
@@ -845,6 +884,7 @@ impl MachineHome {
             to: to.to_string(),
             kind: kind.to_string(),
             spec: None,
+            protection: None,
         });
         true
     }
@@ -1125,7 +1165,7 @@ impl MachineHome {
         let power_runs: Vec<&MachineConnection> =
             self.connections.iter().filter(|c| c.kind == "power").collect();
         if !power_runs.is_empty() {
-            const VOLTS: f32 = 120.0; // residential default; per-connection voltage is a later increment
+            const VOLTS: f32 = crate::utilities::HOME_VOLTS;
             let by_id: std::collections::HashMap<&str, &MachineInstance> =
                 all.iter().map(|i| (i.id.as_str(), i)).collect();
             let dist = |a: (f32, f32, f32), b: (f32, f32, f32)| {
@@ -1677,6 +1717,8 @@ mod tests {
             auto_recipe: None,
             container_type: None,
             model: None,
+            hvac: None,
+            facility: None,
         }
     }
 
@@ -1732,6 +1774,7 @@ mod tests {
             to: to.to_string(),
             kind: "water".to_string(),
             spec: None,
+            protection: None,
         };
         let mut home = MachineHome {
             catalog,
@@ -1782,6 +1825,7 @@ mod tests {
                 to: "k1".to_string(),
                 kind: "power".to_string(),
                 spec: None,
+                protection: None,
             }],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
@@ -2135,8 +2179,8 @@ mod tests {
             arrays: Vec::new(),
             // Wired panel -> battery -> load so the Power circuit check sees a complete circuit.
             connections: vec![
-                MachineConnection { from: "p1".into(), to: "b1".into(), kind: "power".into(), spec: None },
-                MachineConnection { from: "b1".into(), to: "l1".into(), kind: "power".into(), spec: None },
+                MachineConnection { from: "p1".into(), to: "b1".into(), kind: "power".into(), spec: None, protection: None },
+                MachineConnection { from: "b1".into(), to: "l1".into(), kind: "power".into(), spec: None, protection: None },
             ],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
@@ -2162,8 +2206,8 @@ mod tests {
             arrays: Vec::new(),
             // Wired panel -> battery -> load so the Power circuit check sees a complete circuit.
             connections: vec![
-                MachineConnection { from: "p1".into(), to: "b1".into(), kind: "power".into(), spec: None },
-                MachineConnection { from: "b1".into(), to: "l1".into(), kind: "power".into(), spec: None },
+                MachineConnection { from: "p1".into(), to: "b1".into(), kind: "power".into(), spec: None, protection: None },
+                MachineConnection { from: "b1".into(), to: "l1".into(), kind: "power".into(), spec: None, protection: None },
             ],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
@@ -2182,7 +2226,7 @@ mod tests {
             catalog,
             instances: vec![MachineInstance { id: "a".into(), machine: "box".into(), room: "garage".into(), offset: (0.0, 0.0, 0.0), rotation: 0.0, zone: "home".into() }],
             arrays: Vec::new(),
-            connections: vec![MachineConnection { from: "a".into(), to: "ghost".into(), kind: "power".into(), spec: None }],
+            connections: vec![MachineConnection { from: "a".into(), to: "ghost".into(), kind: "power".into(), spec: None, protection: None }],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
             conduit_edges: Vec::new(),
@@ -2483,6 +2527,7 @@ mod tests {
                 to: "l1".into(),
                 kind: "power".into(),
                 spec: spec.map(|s| s.to_string()),
+                protection: None,
             }],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
@@ -2567,7 +2612,7 @@ mod tests {
             catalog,
             instances: vec![inst("u", "uplink"), inst("s", "server")],
             arrays: Vec::new(),
-            connections: vec![MachineConnection { from: "u".into(), to: "s".into(), kind: "data".into(), spec: Some("eth_cat6".into()) }],
+            connections: vec![MachineConnection { from: "u".into(), to: "s".into(), kind: "data".into(), spec: Some("eth_cat6".into()), protection: None }],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
             conduit_edges: Vec::new(),
@@ -2607,7 +2652,7 @@ mod tests {
             instances: vec![inst("b1", "batt"), inst("l1", "load")],
             arrays: Vec::new(),
             // load wired to a battery that itself reaches NO generator -> the load can't run.
-            connections: vec![MachineConnection { from: "b1".into(), to: "l1".into(), kind: "power".into(), spec: None }],
+            connections: vec![MachineConnection { from: "b1".into(), to: "l1".into(), kind: "power".into(), spec: None, protection: None }],
             loops: Vec::new(),
             conduit_nodes: Vec::new(),
             conduit_edges: Vec::new(),
@@ -2617,7 +2662,7 @@ mod tests {
         // Now wire a panel onto the same bus -> the load traces to generation -> Pass.
         home.catalog.insert("panel".to_string(), def_with_power(Some(MachinePower::Solar { peak_watts: 800.0 })));
         home.instances.push(inst("p1", "panel"));
-        home.connections.push(MachineConnection { from: "p1".into(), to: "b1".into(), kind: "power".into(), spec: None });
+        home.connections.push(MachineConnection { from: "p1".into(), to: "b1".into(), kind: "power".into(), spec: None, protection: None });
         let circuit = home.power_circuit_check(&home.all_instances()).expect("a circuit check");
         assert_eq!(circuit.status, CheckStatus::Pass, "panel->battery->load now traces: {}", circuit.detail);
     }
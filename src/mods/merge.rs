@@ -0,0 +1,520 @@
+//! Patch-based mod overrides.
+//!
+//! A mod file mirroring a base data path is a PATCH, not a replacement:
+//!
+//! - **CSV**: rows are keyed by the first (id) column of the base header.
+//!   A patch row with a known id overrides just the columns the patch
+//!   header names; an unknown id is appended. A `__delete__` column with
+//!   `true` removes the row.
+//! - **RON / JSON**: deep merge. Structs, maps and objects merge field by
+//!   field; lists append, except elements carrying an `id` (or `kit_item`)
+//!   that matches an existing element, which merge into it. A field or map
+//!   value of `"__delete__"` removes that key; a list element with
+//!   `__delete__: true` removes the element with its id.
+//! - Anything else (TOML, text) still replaces the whole file.
+//!
+//! Every row a mod touches is recorded in a `MergeReport`, so when two mods
+//! both patch `plants.csv` row `wheat` the log says which one won.
+
+use std::collections::HashMap;
+
+use crate::assets::ron_tree::{Document, Node};
+
+/// Value / column / field that deletes instead of setting.
+pub const DELETE_MARKER: &str = "__delete__";
+/// Struct fields that identify a list element (first match wins).
+const ROW_KEYS: &[&str] = &["id", "kit_item"];
+
+/// Who patched one row of one file, in load order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowOwner {
+    /// Data-relative file path (`plants.csv`).
+    pub file: String,
+    /// Row id (CSV id, list-element id) or field path (`trees.passive`).
+    pub row: String,
+    /// Mods that patched it, in load order; the last one won.
+    pub mods: Vec<String>,
+    /// The winning patch deleted the row.
+    pub deleted: bool,
+}
+
+impl RowOwner {
+    pub fn winner(&self) -> &str {
+        self.mods.last().map(String::as_str).unwrap_or_default()
+    }
+}
+
+/// Every row touched by mods while merging, with the winning mod.
+#[derive(Debug, Clone, Default)]
+pub struct MergeReport {
+    pub rows: Vec<RowOwner>,
+}
+
+impl MergeReport {
+    fn touch(&mut self, file: &str, row: &str, mod_id: &str, deleted: bool) {
+        match self.rows.iter_mut().find(|r| r.file == file && r.row == row) {
+            Some(r) => {
+                if r.mods.last().map(String::as_str) != Some(mod_id) {
+                    r.mods.push(mod_id.to_string());
+                }
+                r.deleted = deleted;
+            }
+            None => self.rows.push(RowOwner {
+                file: file.to_string(),
+                row: row.to_string(),
+                mods: vec![mod_id.to_string()],
+                deleted,
+            }),
+        }
+    }
+
+    /// The mod whose patch of `row` in `file` took effect.
+    pub fn winner(&self, file: &str, row: &str) -> Option<&str> {
+        self.rows.iter().find(|r| r.file == file && r.row == row).map(RowOwner::winner)
+    }
+
+    /// Rows patched by more than one mod.
+    pub fn conflicts(&self) -> impl Iterator<Item = &RowOwner> {
+        self.rows.iter().filter(|r| r.mods.len() > 1)
+    }
+
+    /// One line per conflict: `plants.csv[wheat]: more-crops wins over farm-pack`.
+    pub fn conflict_lines(&self) -> Vec<String> {
+        self.conflicts()
+            .map(|r| {
+                let losers = r.mods[..r.mods.len() - 1].join(", ");
+                let verb = if r.deleted { "deletes it" } else { "wins" };
+                format!("{}[{}]: {} {verb} over {losers}", r.file, r.row, r.winner())
+            })
+            .collect()
+    }
+}
+
+/// Apply one mod's patch of `file` (data-relative path) to `base`.
+pub fn apply_patch(
+    file: &str,
+    base: &str,
+    patch: &str,
+    mod_id: &str,
+    report: &mut MergeReport,
+) -> Result<String, String> {
+    let mut cx = Ctx { file, mod_id, report };
+    match file.rsplit('.').next().unwrap_or_default() {
+        "csv" => merge_csv(base, patch, &mut cx),
+        "ron" => {
+            let mut doc = Document::parse(base).map_err(|e| format!("base {file}: {e}"))?;
+            let p = Document::parse(patch).map_err(|e| format!("patch {file}: {e}"))?;
+            merge_ron(&mut doc.root, p.root, "", false, &mut cx);
+            Ok(doc.to_ron())
+        }
+        "json" => {
+            let mut doc: serde_json::Value =
+                serde_json::from_str(base).map_err(|e| format!("base {file}: {e}"))?;
+            let p: serde_json::Value = serde_json::from_str(patch).map_err(|e| format!("patch {file}: {e}"))?;
+            merge_json(&mut doc, p, "", false, &mut cx);
+            serde_json::to_string_pretty(&doc).map_err(|e| e.to_string())
+        }
+        _ => {
+            cx.touch("*", false);
+            Ok(patch.to_string())
+        }
+    }
+}
+
+struct Ctx<'a> {
+    file: &'a str,
+    mod_id: &'a str,
+    report: &'a mut MergeReport,
+}
+
+impl Ctx<'_> {
+    fn touch(&mut self, row: &str, deleted: bool) {
+        self.report.touch(self.file, row, self.mod_id, deleted);
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() { key.to_string() } else { format!("{path}.{key}") }
+}
+
+fn row_path(path: &str, id: &str) -> String {
+    if path.is_empty() { id.to_string() } else { format!("{path}[{id}]") }
+}
+
+fn truthy(v: &str) -> bool {
+    matches!(v.trim().to_ascii_lowercase().as_str(), "true" | "1" | "yes")
+}
+
+// ── CSV ─────────────────────────────────────────────────────────────────
+
+/// Comment lines, header, rows (`#` lines and blanks skipped like
+/// `assets::loader::parse_csv`).
+type CsvTable = (Vec<String>, Vec<String>, Vec<Vec<String>>);
+
+fn split_csv(text: &str) -> Result<CsvTable, String> {
+    let mut comments = Vec::new();
+    let mut body = String::new();
+    for line in text.lines() {
+        if line.trim_start().starts_with('#') {
+            comments.push(line.to_string());
+        } else if !line.trim().is_empty() {
+            body.push_str(line);
+            body.push('\n');
+        }
+    }
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(body.as_bytes());
+    let mut records = reader.records();
+    let header: Vec<String> = match records.next() {
+        Some(r) => r.map_err(|e| e.to_string())?.iter().map(|s| s.trim().to_string()).collect(),
+        None => return Err("no header row".into()),
+    };
+    let rows = records
+        .map(|r| r.map(|r| r.iter().map(str::to_string).collect()).map_err(|e| e.to_string()))
+        .collect::<Result<Vec<Vec<String>>, String>>()?;
+    Ok((comments, header, rows))
+}
+
+fn merge_csv(base: &str, patch: &str, cx: &mut Ctx) -> Result<String, String> {
+    let (comments, header, mut rows) = split_csv(base).map_err(|e| format!("base {}: {e}", cx.file))?;
+    let (_, p_header, p_rows) = split_csv(patch).map_err(|e| format!("patch {}: {e}", cx.file))?;
+    let id_col = &header[0];
+    let pid = p_header
+        .iter()
+        .position(|c| c == id_col)
+        .ok_or_else(|| format!("patch {} has no '{id_col}' column", cx.file))?;
+    let delete_col = p_header.iter().position(|c| c == DELETE_MARKER);
+    // Patch column -> base column (None: delete marker or unknown column).
+    let map: Vec<Option<usize>> = p_header.iter().map(|c| header.iter().position(|h| h == c)).collect();
+    for (c, m) in p_header.iter().zip(&map) {
+        if m.is_none() && c != DELETE_MARKER {
+            log::warn!("[mods] {} patches unknown column '{c}' of {}; ignored", cx.mod_id, cx.file);
+        }
+    }
+    let mut index: HashMap<String, usize> = rows
+        .iter()
+        .enumerate()
+        .filter_map(|(i, r)| r.first().map(|id| (id.trim().to_string(), i)))
+        .collect();
+    let mut deleted = vec![false; rows.len()];
+    for p in p_rows {
+        let Some(id) = p.get(pid).map(|s| s.trim().to_string()).filter(|s| !s.is_empty()) else {
+            continue;
+        };
+        let delete = delete_col.and_then(|i| p.get(i)).is_some_and(|v| truthy(v));
+        let slot = index.get(&id).copied();
+        if delete {
+            if let Some(i) = slot {
+                deleted[i] = true;
+            }
+            cx.touch(&id, true);
+            continue;
+        }
+        let i = match slot {
+            // A row deleted by an earlier mod comes back blank, not stale.
+            Some(i) if deleted[i] => {
+                deleted[i] = false;
+                rows[i] = vec![String::new(); header.len()];
+                rows[i][0] = id.clone();
+                i
+            }
+            Some(i) => i,
+            None => {
+                rows.push(vec![String::new(); header.len()]);
+                deleted.push(false);
+                index.insert(id.clone(), rows.len() - 1);
+                rows.len() - 1
+            }
+        };
+        let row = &mut rows[i];
+        if row.len() < header.len() {
+            row.resize(header.len(), String::new());
+        }
+        for (v, m) in p.iter().zip(&map) {
+            if let Some(col) = m {
+                row[*col] = v.clone();
+            }
+        }
+        cx.touch(&id, false);
+    }
+    let mut w = csv::WriterBuilder::new()
+        .flexible(true)
+        .terminator(csv::Terminator::Any(b'\n'))
+        .from_writer(Vec::new());
+    w.write_record(&header).map_err(|e| e.to_string())?;
+    for (row, gone) in rows.iter().zip(&deleted) {
+        if !gone {
+            w.write_record(row).map_err(|e| e.to_string())?;
+        }
+    }
+    let body = String::from_utf8(w.into_inner().map_err(|e| e.to_string())?).map_err(|e| e.to_string())?;
+    let mut out = comments.join("\n");
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    out.push_str(&body);
+    Ok(out)
+}
+
+// ── RON ─────────────────────────────────────────────────────────────────
+
+fn ron_row_id(node: &Node) -> Option<String> {
+    ROW_KEYS.iter().find_map(|k| node.field(k)).and_then(Node::atom_text).map(str::to_string)
+}
+
+fn ron_is_delete(node: &Node) -> bool {
+    node.atom_text() == Some(DELETE_MARKER)
+}
+
+fn merge_ron(base: &mut Node, patch: Node, path: &str, in_row: bool, cx: &mut Ctx) {
+    match (base, patch) {
+        (Node::Struct { name: bn, fields: bf }, Node::Struct { name: pn, fields: pf })
+            if pn.is_none() || *bn == pn =>
+        {
+            for (k, v) in pf {
+                let at = join(path, &k);
+                let slot = bf.iter().position(|(bk, _)| *bk == k);
+                merge_ron_entry(bf, slot, k, v, &at, in_row, cx);
+            }
+        }
+        (Node::Map(be), Node::Map(pe)) => {
+            for (k, v) in pe {
+                let at = join(path, k.atom_text().unwrap_or_default());
+                let slot = be.iter().position(|(bk, _)| *bk == k);
+                merge_ron_entry(be, slot, k, v, &at, in_row, cx);
+            }
+        }
+        (Node::Seq(bs), Node::Seq(ps)) => {
+            for p in ps {
+                let Some(id) = ron_row_id(&p) else {
+                    bs.push(p);
+                    if !in_row {
+                        cx.touch(&row_path(path, "+"), false);
+                    }
+                    continue;
+                };
+                let at = row_path(path, &id);
+                let delete = p.field(DELETE_MARKER).and_then(Node::atom_text).is_some_and(truthy);
+                let slot = bs.iter().position(|b| ron_row_id(b).as_deref() == Some(id.as_str()));
+                match (slot, delete) {
+                    (Some(i), true) => {
+                        bs.remove(i);
+                    }
+                    (Some(i), false) => merge_ron(&mut bs[i], p, &at, true, cx),
+                    (None, true) => {}
+                    (None, false) => bs.push(p),
+                }
+                if !in_row {
+                    cx.touch(&at, delete);
+                }
+            }
+        }
+        (b, p) => {
+            *b = p;
+            if !in_row {
+                cx.touch(path, false);
+            }
+        }
+    }
+}
+
+/// Set, merge or delete one struct field / map entry.
+fn merge_ron_entry<K>(
+    entries: &mut Vec<(K, Node)>,
+    slot: Option<usize>,
+    key: K,
+    value: Node,
+    at: &str,
+    in_row: bool,
+    cx: &mut Ctx,
+) {
+    let delete = ron_is_delete(&value);
+    let leaf = delete || slot.is_none();
+    match slot {
+        Some(i) if delete => {
+            entries.remove(i);
+        }
+        Some(i) => merge_ron(&mut entries[i].1, value, at, in_row, cx),
+        None if delete => {}
+        None => entries.push((key, value)),
+    }
+    if leaf && !in_row {
+        cx.touch(at, delete);
+    }
+}
+
+// ── JSON ────────────────────────────────────────────────────────────────
+
+fn json_row_id(v: &serde_json::Value) -> Option<String> {
+    ROW_KEYS.iter().find_map(|k| v.get(k)).and_then(|v| v.as_str()).map(str::to_string)
+}
+
+fn merge_json(base: &mut serde_json::Value, patch: serde_json::Value, path: &str, in_row: bool, cx: &mut Ctx) {
+    use serde_json::Value;
+    match (base, patch) {
+        (Value::Object(bo), Value::Object(po)) => {
+            for (k, v) in po {
+                let at = join(path, &k);
+                let delete = v.as_str() == Some(DELETE_MARKER);
+                let leaf = delete || !bo.contains_key(&k);
+                if delete {
+                    bo.remove(&k);
+                } else if let Some(b) = bo.get_mut(&k) {
+                    merge_json(b, v, &at, in_row, cx);
+                } else {
+                    bo.insert(k, v);
+                }
+                if leaf && !in_row {
+                    cx.touch(&at, delete);
+                }
+            }
+        }
+        (Value::Array(ba), Value::Array(pa)) => {
+            for p in pa {
+                let Some(id) = json_row_id(&p) else {
+                    ba.push(p);
+                    if !in_row {
+                        cx.touch(&row_path(path, "+"), false);
+                    }
+                    continue;
+                };
+                let at = row_path(path, &id);
+                let delete = p.get(DELETE_MARKER).is_some_and(|d| d.as_bool() == Some(true));
+                let slot = ba.iter().position(|b| json_row_id(b).as_deref() == Some(id.as_str()));
+                match (slot, delete) {
+                    (Some(i), true) => {
+                        ba.remove(i);
+                    }
+                    (Some(i), false) => merge_json(&mut ba[i], p, &at, true, cx),
+                    (None, true) => {}
+                    (None, false) => ba.push(p),
+                }
+                if !in_row {
+                    cx.touch(&at, delete);
+                }
+            }
+        }
+        (b, p) => {
+            *b = p;
+            if !in_row {
+                cx.touch(path, false);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLANTS: &str = "# header comment\nid,name,growth_days\ntomato,Tomato,70\nwheat,Wheat,120\n";
+
+    #[test]
+    fn csv_rows_append_override_and_delete_by_id() {
+        let mut report = MergeReport::default();
+        let a = "id,name\nrice,Rice\n";
+        let b = "id,growth_days\nwheat,90\nmillet,80\n";
+        let c = "id,__delete__\ntomato,true\n";
+        let out = apply_patch("plants.csv", PLANTS, a, "rice-mod", &mut report).unwrap();
+        let out = apply_patch("plants.csv", &out, b, "grain-mod", &mut report).unwrap();
+        let out = apply_patch("plants.csv", &out, c, "no-tomato", &mut report).unwrap();
+        assert_eq!(out, "# header comment\nid,name,growth_days\nwheat,Wheat,90\nrice,Rice,\nmillet,,80\n");
+        // The merged text still parses through the game's CSV loader.
+        #[derive(serde::Deserialize)]
+        struct Row {
+            id: String,
+        }
+        let rows: Vec<Row> = crate::assets::loader::parse_csv(out.as_bytes()).unwrap();
+        assert_eq!(rows.iter().map(|r| r.id.as_str()).collect::<Vec<_>>(), ["wheat", "rice", "millet"]);
+        assert_eq!(report.winner("plants.csv", "tomato"), Some("no-tomato"));
+        assert!(report.conflicts().next().is_none(), "no row was patched twice");
+    }
+
+    #[test]
+    fn two_mods_on_one_row_report_the_winner() {
+        let mut report = MergeReport::default();
+        let out = apply_patch("plants.csv", PLANTS, "id,growth_days\nwheat,100\n", "farm-pack", &mut report).unwrap();
+        let out = apply_patch("plants.csv", &out, "id,name,growth_days\nwheat,Spelt,95\n", "more-crops", &mut report)
+            .unwrap();
+        assert!(out.contains("wheat,Spelt,95"));
+        assert_eq!(report.conflict_lines(), vec!["plants.csv[wheat]: more-crops wins over farm-pack"]);
+    }
+
+    #[test]
+    fn ron_deep_merges_lists_by_id_and_keeps_variant_names() {
+        let base = r#"(
+            events: [
+                (id: "storm", name: "Storm", seasons: ["Spring"], kind: Wind(speed: 20.0)),
+                (id: "hail", name: "Hail", seasons: ["Summer"], kind: Ice),
+            ],
+            trees: { "passive": Action("wander"), "shy": Action("flee") },
+        )"#;
+        let patch = r#"(
+            events: [
+                (id: "storm", seasons: ["Autumn"], kind: Wind(speed: 35.0)),
+                (id: "hail", __delete__: true),
+                (id: "fog", name: "Fog", seasons: [], kind: Calm),
+            ],
+            trees: { "shy": "__delete__", "grazer": Action("graze") },
+        )"#;
+        let mut report = MergeReport::default();
+        let out = apply_patch("weather/events.ron", base, patch, "wx", &mut report).unwrap();
+        let doc = Document::parse(&out).unwrap();
+        let Some(Node::Seq(events)) = doc.root.field("events") else { panic!("{out}") };
+        let ids: Vec<_> = events.iter().filter_map(ron_row_id).collect();
+        assert_eq!(ids, ["storm", "fog"]);
+        let storm = &events[0];
+        assert_eq!(storm.field("name").and_then(Node::atom_text), Some("Storm"), "untouched fields survive");
+        assert_eq!(storm.field("seasons"), Some(&Node::Seq(vec![Node::Atom("\"Spring\"".into()), Node::Atom("\"Autumn\"".into())])));
+        let kind = storm.field("kind").unwrap();
+        assert!(matches!(kind, Node::Struct { name: Some(n), .. } if n == "Wind"));
+        assert_eq!(kind.field("speed").and_then(Node::atom_text), Some("35.0"));
+        assert!(out.contains("\"grazer\": Action(\"graze\")") && !out.contains("\"shy\""));
+        let rows: Vec<_> = report.rows.iter().map(|r| r.row.as_str()).collect();
+        assert_eq!(rows, ["events[storm]", "events[hail]", "events[fog]", "trees.shy", "trees.grazer"]);
+    }
+
+    #[test]
+    fn ron_patch_of_real_quests_still_loads() {
+        let patch = r#"[
+            (id: "gs_first_steps", name: "First Steps (modded)"),
+            (id: "mod_extra", name: "Extra", description: "From a mod", rewards: [], steps: [
+                (description: "Talk", objective: Talk(npc_id: "mara")),
+            ]),
+        ]"#;
+        let base = std::fs::read_to_string(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/quests/getting_started.ron"),
+        )
+        .unwrap();
+        let mut report = MergeReport::default();
+        let out = apply_patch("quests/getting_started.ron", &base, patch, "m", &mut report).unwrap();
+        let defs: Vec<crate::systems::quests::QuestDef> = ron::from_str(&out).unwrap();
+        let first = defs.iter().find(|q| q.id == "gs_first_steps").unwrap();
+        assert_eq!(first.name, "First Steps (modded)");
+        assert!(!first.steps.is_empty(), "steps kept from base");
+        assert!(defs.iter().any(|q| q.id == "mod_extra"));
+    }
+
+    #[test]
+    fn json_merges_objects_and_arrays() {
+        let base = r#"{"name": "Sol", "bodies": [{"id": "earth", "radius": 1.0}, {"id": "mars", "radius": 0.5}], "old": 1}"#;
+        let patch = r#"{"bodies": [{"id": "mars", "radius": 0.53}, {"id": "vulcan", "radius": 0.1}], "old": "__delete__"}"#;
+        let mut report = MergeReport::default();
+        let out = apply_patch("star_systems/sol.json", base, patch, "astro", &mut report).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(v["bodies"][1]["radius"], 0.53);
+        assert_eq!(v["bodies"][2]["id"], "vulcan");
+        assert!(v.get("old").is_none());
+        assert_eq!(v["name"], "Sol");
+    }
+
+    #[test]
+    fn other_formats_replace_the_whole_file() {
+        let mut report = MergeReport::default();
+        let out = apply_patch("ai/flow_field.toml", "a = 1", "a = 2", "m", &mut report).unwrap();
+        assert_eq!(out, "a = 2");
+        assert_eq!(report.winner("ai/flow_field.toml", "*"), Some("m"));
+    }
+}
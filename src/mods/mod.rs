@@ -3,7 +3,14 @@
 //! Mods are directories in `data/mods/{mod-name}/` that can override
 //! any base data file by mirroring its path structure.
 //! Each mod contains a `mod.json` manifest describing its metadata
-//! and load order.
+//! and load order. A mirrored file is merged into the base as a patch
+//! (see `merge`), so several mods can each add rows to the same file.
+
+pub mod merge;
+pub mod resolve;
+
+pub use merge::{MergeReport, RowOwner, DELETE_MARKER};
+pub use resolve::{ModDependency, ModProblem, ModResolution};
 
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
@@ -24,9 +31,16 @@ pub struct ModManifest {
     /// Load order priority (lower loads first, default 100)
     #[serde(default = "default_load_order")]
     pub load_order: i32,
-    /// List of mod IDs this mod depends on
+    /// Mods this one requires: a bare id (any version) or
+    /// `{"id": "core-pack", "version": "^1.2"}`
+    #[serde(default)]
+    pub dependencies: Vec<ModDependency>,
+    /// Mods to load before this one when installed (same forms)
+    #[serde(default)]
+    pub optional_dependencies: Vec<ModDependency>,
+    /// Mods (and version ranges) this one refuses to load alongside
     #[serde(default)]
-    pub dependencies: Vec<String>,
+    pub conflicts_with: Vec<ModDependency>,
     /// Filesystem path to the mod directory (populated at scan time, not from JSON)
     #[serde(skip)]
     pub path: PathBuf,
@@ -98,31 +112,11 @@ impl ModLoader {
         mods
     }
 
-    /// Sort mods by load_order (ascending), then alphabetically by id for ties.
-    /// Also performs basic dependency validation (warns on missing deps).
+    /// Enabled mod ids in load order: dependencies first, then load_order
+    /// (ascending), then id. Mods failing resolution are left out; see
+    /// `resolve::resolve` for the problems.
     pub fn load_order(mods: &[ModManifest]) -> Vec<String> {
-        let mut sorted: Vec<&ModManifest> = mods.iter().collect();
-        sorted.sort_by(|a, b| {
-            a.load_order
-                .cmp(&b.load_order)
-                .then_with(|| a.id.cmp(&b.id))
-        });
-
-        // Warn about missing dependencies
-        let ids: std::collections::HashSet<&str> =
-            mods.iter().map(|m| m.id.as_str()).collect();
-        for m in &sorted {
-            for dep in &m.dependencies {
-                if !ids.contains(dep.as_str()) {
-                    log::warn!(
-                        "Mod '{}' depends on '{}' which is not installed",
-                        m.id, dep
-                    );
-                }
-            }
-        }
-
-        sorted.iter().map(|m| m.id.clone()).collect()
+        resolve::resolve(mods).order
     }
 
     /// Resolve a relative data path through the mod chain.
@@ -150,6 +144,150 @@ impl ModLoader {
         }
         base.join(relative_path)
     }
+
+    /// `base_text` with every mod's copy of `relative_path` applied as a
+    /// patch, in load order. A mod may also add a file the base lacks; the
+    /// first such copy becomes the base for later mods. A patch that fails
+    /// to parse is logged and skipped.
+    pub fn read_merged(
+        base_text: Option<String>,
+        mods: &[ModManifest],
+        relative_path: &str,
+        report: &mut MergeReport,
+    ) -> Option<String> {
+        let mut text = base_text;
+        for m in mods {
+            let Ok(patch) = std::fs::read_to_string(m.path.join(relative_path)) else {
+                continue;
+            };
+            text = Some(match text {
+                Some(base) => match merge::apply_patch(relative_path, &base, &patch, &m.id, report) {
+                    Ok(merged) => merged,
+                    Err(e) => {
+                        log::warn!("Mod '{}' patch of {relative_path} skipped: {e}", m.id);
+                        base
+                    }
+                },
+                None => patch,
+            });
+        }
+        text
+    }
+}
+
+/// The data directory as the game sees it: base files (disk-first,
+/// embedded fallback) with the enabled mods' patches applied, plus the
+/// dependency resolution and the report of which mod won each patched row.
+pub struct ModData {
+    data_dir: PathBuf,
+    /// Enabled mods, in load order.
+    pub mods: Vec<ModManifest>,
+    pub resolution: ModResolution,
+    pub report: MergeReport,
+}
+
+impl ModData {
+    /// Scan `data_dir/mods` (absent = no mods) and resolve dependencies;
+    /// disabled mods contribute nothing.
+    pub fn scan(data_dir: &Path) -> Self {
+        let mods_dir = data_dir.join("mods");
+        let installed = if mods_dir.is_dir() { ModLoader::scan_mods(&mods_dir) } else { Vec::new() };
+        let resolution = resolve::resolve(&installed);
+        let mods = resolution
+            .order
+            .iter()
+            .filter_map(|id| installed.iter().find(|m| m.id == *id).cloned())
+            .collect();
+        Self { data_dir: data_dir.to_path_buf(), mods, resolution, report: MergeReport::default() }
+    }
+
+    /// A data file, merged through every mod.
+    pub fn read(&mut self, relative_path: &str) -> Option<String> {
+        let base = crate::embedded_data::read_data_or_embedded(&self.data_dir, relative_path);
+        ModLoader::read_merged(base, &self.mods, relative_path, &mut self.report)
+    }
+
+    /// Data-relative paths of the `.{ext}` files in `dir` across the base
+    /// and every mod, sorted (a mod can add files to e.g. `quests/`).
+    pub fn files_in(&self, dir: &str, ext: &str) -> Vec<String> {
+        let mut names = std::collections::BTreeSet::new();
+        let roots = std::iter::once(&self.data_dir).chain(self.mods.iter().map(|m| &m.path));
+        for root in roots {
+            for entry in std::fs::read_dir(root.join(dir)).into_iter().flatten().flatten() {
+                let path = entry.path();
+                if path.extension().is_some_and(|x| x == ext) {
+                    if let Some(name) = path.file_name() {
+                        names.insert(format!("{dir}/{}", name.to_string_lossy()));
+                    }
+                }
+            }
+        }
+        names.into_iter().collect()
+    }
+
+    /// Check the base files, every enabled mod's override and the merged
+    /// result against `schemas/`, logging each problem with file and line.
+    /// Returns the number of problems.
+    pub fn log_schema_errors(&mut self) -> usize {
+        let schemas = match crate::assets::schema::SchemaSet::for_data_dir(&self.data_dir) {
+            Ok(s) => s,
+            Err(e) => {
+                log::warn!("Schema validation skipped: {e}");
+                return 0;
+            }
+        };
+        let errors = schemas.validate(self);
+        for e in &errors {
+            log::warn!("Schema: {e}");
+        }
+        errors.len()
+    }
+
+    /// Log disabled mods, the mods in play and every row two or more of
+    /// them patched.
+    pub fn log_report(&self) {
+        for problem in &self.resolution.problems {
+            log::warn!("Mod problem: {problem}");
+        }
+        if self.mods.is_empty() {
+            return;
+        }
+        log::info!(
+            "{} mod(s) patched {} data row(s)",
+            self.mods.len(),
+            self.report.rows.len()
+        );
+        for line in self.report.conflict_lines() {
+            log::warn!("Mod conflict: {line}");
+        }
+    }
+}
+
+impl crate::assets::schema::DataSource for ModData {
+    fn base(&mut self, rel: &str) -> Option<String> {
+        crate::embedded_data::read_data_or_embedded(&self.data_dir, rel)
+    }
+
+    /// Merged into a scratch report: validation must not add to the
+    /// real one.
+    fn merged(&mut self, rel: &str) -> Option<String> {
+        let base = self.base(rel);
+        ModLoader::read_merged(base, &self.mods, rel, &mut MergeReport::default())
+    }
+
+    fn files_in(&self, dir: &str, ext: &str) -> Vec<String> {
+        ModData::files_in(self, dir, ext)
+    }
+
+    fn patches(&self, rel: &str) -> Vec<(String, String)> {
+        self.mods
+            .iter()
+            .filter_map(|m| {
+                let text = std::fs::read_to_string(m.path.join(rel)).ok()?;
+                Some((format!("mods/{}/{rel}", m.id), text))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -167,6 +305,8 @@ mod tests {
                 description: "".into(),
                 load_order: 200,
                 dependencies: vec![],
+                optional_dependencies: vec![],
+                conflicts_with: vec![],
                 path: PathBuf::new(),
             },
             ModManifest {
@@ -177,6 +317,8 @@ mod tests {
                 description: "".into(),
                 load_order: 100,
                 dependencies: vec![],
+                optional_dependencies: vec![],
+                conflicts_with: vec![],
                 path: PathBuf::new(),
             },
         ];
@@ -191,4 +333,26 @@ mod tests {
         let resolved = ModLoader::resolve_path(base, &mods, "items.csv");
         assert_eq!(resolved, PathBuf::from("/data/items.csv"));
     }
+
+    #[test]
+    fn two_mods_adding_crops_both_land() {
+        let root = std::env::temp_dir().join(format!("hos_mods_{}", std::process::id()));
+        let data = root.join("data");
+        for (id, order, row) in [("farm-pack", 100, "rice,Rice"), ("more-crops", 200, "wheat,Spelt")] {
+            let dir = data.join("mods").join(id);
+            std::fs::create_dir_all(&dir).unwrap();
+            let manifest = format!(
+                r#"{{"name": "{id}", "id": "{id}", "version": "1.0.0", "author": "t", "description": "", "load_order": {order}}}"#
+            );
+            std::fs::write(dir.join("mod.json"), manifest).unwrap();
+            std::fs::write(dir.join("plants.csv"), format!("id,name\n{row}\n")).unwrap();
+        }
+        std::fs::write(data.join("plants.csv"), "id,name\nwheat,Wheat\n").unwrap();
+        let mut modded = ModData::scan(&data);
+        let text = modded.read("plants.csv").unwrap();
+        std::fs::remove_dir_all(&root).ok();
+        assert_eq!(text, "id,name\nwheat,Spelt\nrice,Rice\n");
+        assert_eq!(modded.report.winner("plants.csv", "rice"), Some("farm-pack"));
+        assert_eq!(modded.report.winner("plants.csv", "wheat"), Some("more-crops"));
+    }
 }
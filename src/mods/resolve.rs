@@ -0,0 +1,433 @@
+//! Mod dependency resolution.
+//!
+//! Turns the scanned manifests into the set of mods that actually load, in
+//! the order they load:
+//!
+//! - `dependencies` must be installed, enabled and match their semver range
+//!   (`"core-pack"` = any version, or `{"id": "core-pack", "version": "^1.2"}`).
+//! - `optional_dependencies` only order: when present and in range they
+//!   load first; when absent nothing happens.
+//! - `conflicts_with` disables the declaring mod while the other (in range)
+//!   is enabled.
+//! - Dependency cycles disable every mod on the cycle.
+//!
+//! A mod that fails any check is disabled whole (none of its files are
+//! applied), and so is everything that requires it. The survivors are sorted
+//! topologically — dependencies first, whatever their `load_order` — with
+//! `load_order` then `id` breaking ties. Every problem is kept as data in
+//! `ModResolution` for the diagnostics overlay, not just logged.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+
+use super::ModManifest;
+
+/// One entry of `dependencies` / `optional_dependencies` / `conflicts_with`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModDependency {
+    pub id: String,
+    /// Accepted versions; `*` when the manifest gave a bare id.
+    pub version: VersionReq,
+}
+
+impl ModDependency {
+    pub fn any(id: &str) -> Self {
+        Self { id: id.to_string(), version: VersionReq::STAR }
+    }
+
+    fn accepts(&self, version: &Version) -> bool {
+        self.version.matches(version)
+    }
+}
+
+impl<'de> Deserialize<'de> for ModDependency {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Id(String),
+            Ranged { id: String, version: String },
+        }
+        match Raw::deserialize(d)? {
+            Raw::Id(id) => Ok(Self::any(&id)),
+            Raw::Ranged { id, version } => {
+                let version = VersionReq::parse(&version)
+                    .map_err(|e| serde::de::Error::custom(format!("dependency '{id}' version '{version}': {e}")))?;
+                Ok(Self { id, version })
+            }
+        }
+    }
+}
+
+/// Why a mod was disabled (or, for optional dependencies, warned about).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModProblem {
+    /// The manifest `version` is not semver.
+    InvalidVersion { mod_id: String, version: String },
+    /// Two installed mods share an id; the second is ignored.
+    DuplicateId { mod_id: String },
+    MissingDependency { mod_id: String, dependency: String },
+    IncompatibleVersion { mod_id: String, dependency: String, required: String, found: String },
+    /// A required dependency is installed but was itself disabled.
+    DependencyDisabled { mod_id: String, dependency: String },
+    ConflictsWith { mod_id: String, other: String },
+    /// Mods on a dependency cycle, in id order.
+    Cycle { mods: Vec<String> },
+    /// Optional dependency installed at a version outside its range:
+    /// not disabled, just not ordered against it.
+    OptionalIncompatible { mod_id: String, dependency: String, required: String, found: String },
+}
+
+impl ModProblem {
+    /// Did this problem disable a mod?
+    pub fn is_fatal(&self) -> bool {
+        !matches!(self, ModProblem::OptionalIncompatible { .. })
+    }
+}
+
+impl fmt::Display for ModProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModProblem::InvalidVersion { mod_id, version } => {
+                write!(f, "{mod_id}: version '{version}' is not semver")
+            }
+            ModProblem::DuplicateId { mod_id } => write!(f, "{mod_id}: installed twice"),
+            ModProblem::MissingDependency { mod_id, dependency } => {
+                write!(f, "{mod_id}: requires '{dependency}', which is not installed")
+            }
+            ModProblem::IncompatibleVersion { mod_id, dependency, required, found }
+            | ModProblem::OptionalIncompatible { mod_id, dependency, required, found } => {
+                write!(f, "{mod_id}: needs {dependency} {required}, found {found}")
+            }
+            ModProblem::DependencyDisabled { mod_id, dependency } => {
+                write!(f, "{mod_id}: requires '{dependency}', which is disabled")
+            }
+            ModProblem::ConflictsWith { mod_id, other } => write!(f, "{mod_id}: conflicts with '{other}'"),
+            ModProblem::Cycle { mods } => write!(f, "dependency cycle among {}", mods.join(", ")),
+        }
+    }
+}
+
+/// The outcome of resolving the installed mods.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModResolution {
+    /// Enabled mod ids, in load order.
+    pub order: Vec<String>,
+    /// Installed mods that will not load, sorted.
+    pub disabled: Vec<String>,
+    pub problems: Vec<ModProblem>,
+}
+
+impl ModResolution {
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.order.iter().any(|m| m == id)
+    }
+
+    /// Problems naming `id` (cycles included).
+    pub fn problems_for<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a ModProblem> + 'a {
+        self.problems.iter().filter(move |p| match p {
+            ModProblem::Cycle { mods } => mods.iter().any(|m| m == id),
+            ModProblem::InvalidVersion { mod_id, .. }
+            | ModProblem::DuplicateId { mod_id }
+            | ModProblem::MissingDependency { mod_id, .. }
+            | ModProblem::IncompatibleVersion { mod_id, .. }
+            | ModProblem::DependencyDisabled { mod_id, .. }
+            | ModProblem::ConflictsWith { mod_id, .. }
+            | ModProblem::OptionalIncompatible { mod_id, .. } => mod_id == id,
+        })
+    }
+}
+
+/// Resolve `mods` (any order) into a `ModResolution`.
+pub fn resolve(mods: &[ModManifest]) -> ModResolution {
+    let mut problems = Vec::new();
+    // id -> manifest; first of a duplicated id wins.
+    let mut by_id: HashMap<&str, &ModManifest> = HashMap::new();
+    for m in mods {
+        if by_id.contains_key(m.id.as_str()) {
+            problems.push(ModProblem::DuplicateId { mod_id: m.id.clone() });
+        } else {
+            by_id.insert(&m.id, m);
+        }
+    }
+    let mut ids: Vec<&str> = by_id.keys().copied().collect();
+    ids.sort_unstable();
+    let mut versions: HashMap<&str, Version> = HashMap::new();
+    let mut disabled: BTreeSet<&str> = BTreeSet::new();
+    for &id in &ids {
+        let m = by_id[id];
+        match Version::parse(m.version.trim()) {
+            Ok(v) => {
+                versions.insert(id, v);
+            }
+            Err(_) => {
+                problems.push(ModProblem::InvalidVersion { mod_id: id.to_string(), version: m.version.clone() });
+                disabled.insert(id);
+            }
+        }
+    }
+
+    // Dependencies and cycles settle first; conflicts are judged against what
+    // survived, so a rival disabled for a missing dependency no longer counts.
+    // Settling again strands whatever required a mod a conflict disabled.
+    settle(&ids, &by_id, &versions, &mut disabled, &mut problems);
+    let mut conflicted = false;
+    for &id in &ids {
+        if disabled.contains(id) {
+            continue;
+        }
+        let conflict = by_id[id].conflicts_with.iter().find(|c| {
+            !disabled.contains(c.id.as_str()) && versions.get(c.id.as_str()).is_some_and(|v| c.accepts(v))
+        });
+        if let Some(c) = conflict {
+            problems.push(ModProblem::ConflictsWith { mod_id: id.to_string(), other: c.id.clone() });
+            disabled.insert(id);
+            conflicted = true;
+        }
+    }
+    if conflicted {
+        settle(&ids, &by_id, &versions, &mut disabled, &mut problems);
+    }
+
+    // Optional dependencies out of range are reported once, after the
+    // enabled set is final.
+    for &id in ids.iter().filter(|id| !disabled.contains(*id)) {
+        for dep in &by_id[id].optional_dependencies {
+            let Some(found) = versions.get(dep.id.as_str()) else { continue };
+            if !disabled.contains(dep.id.as_str()) && !dep.accepts(found) {
+                problems.push(ModProblem::OptionalIncompatible {
+                    mod_id: id.to_string(),
+                    dependency: dep.id.clone(),
+                    required: dep.version.to_string(),
+                    found: found.to_string(),
+                });
+            }
+        }
+    }
+
+    // Kahn's algorithm; among ready mods the lowest (load_order, id) goes next.
+    let enabled: Vec<&str> = ids.iter().copied().filter(|id| !disabled.contains(id)).collect();
+    let deps: HashMap<&str, Vec<&str>> =
+        enabled.iter().map(|&id| (id, ordering_deps(by_id[id], &versions, &disabled))).collect();
+    let mut placed: BTreeSet<&str> = BTreeSet::new();
+    let mut order = Vec::with_capacity(enabled.len());
+    while order.len() < enabled.len() {
+        let next = enabled
+            .iter()
+            .copied()
+            .filter(|id| !placed.contains(id) && deps[id].iter().all(|d| placed.contains(d)))
+            .min_by_key(|id| (by_id[id].load_order, *id));
+        let Some(next) = next else { break }; // unreachable: cycles were disabled above
+        placed.insert(next);
+        order.push(next.to_string());
+    }
+
+    ModResolution {
+        order,
+        disabled: disabled.iter().map(|s| s.to_string()).collect(),
+        problems,
+    }
+}
+
+/// Disable until stable: each pass can strand mods that required one
+/// disabled in the previous pass, and a cycle is only looked for once every
+/// dependency check passes.
+fn settle<'a>(
+    ids: &[&'a str],
+    by_id: &HashMap<&'a str, &'a ModManifest>,
+    versions: &HashMap<&str, Version>,
+    disabled: &mut BTreeSet<&'a str>,
+    problems: &mut Vec<ModProblem>,
+) {
+    loop {
+        let mut changed = false;
+        for &id in ids {
+            if disabled.contains(id) {
+                continue;
+            }
+            let problem = by_id[id].dependencies.iter().find_map(|dep| match by_id.get(dep.id.as_str()) {
+                None => Some(ModProblem::MissingDependency { mod_id: id.to_string(), dependency: dep.id.clone() }),
+                Some(_) if disabled.contains(dep.id.as_str()) => {
+                    Some(ModProblem::DependencyDisabled { mod_id: id.to_string(), dependency: dep.id.clone() })
+                }
+                Some(_) => {
+                    let found = &versions[dep.id.as_str()];
+                    (!dep.accepts(found)).then(|| ModProblem::IncompatibleVersion {
+                        mod_id: id.to_string(),
+                        dependency: dep.id.clone(),
+                        required: dep.version.to_string(),
+                        found: found.to_string(),
+                    })
+                }
+            });
+            if let Some(p) = problem {
+                problems.push(p);
+                disabled.insert(id);
+                changed = true;
+            }
+        }
+        if changed {
+            continue;
+        }
+        match find_cycle(ids, disabled, |id| ordering_deps(by_id[id], versions, disabled)) {
+            Some(mut members) => {
+                for &m in &members {
+                    disabled.insert(m);
+                }
+                members.sort_unstable();
+                problems.push(ModProblem::Cycle { mods: members.iter().map(|s| s.to_string()).collect() });
+            }
+            None => break,
+        }
+    }
+}
+
+/// Enabled mods that must load before `m`: its required dependencies plus
+/// optional ones that are installed, enabled and in range.
+fn ordering_deps<'a>(
+    m: &'a ModManifest,
+    versions: &HashMap<&str, Version>,
+    disabled: &BTreeSet<&str>,
+) -> Vec<&'a str> {
+    let required = m.dependencies.iter();
+    let optional = m
+        .optional_dependencies
+        .iter()
+        .filter(|d| versions.get(d.id.as_str()).is_some_and(|v| d.accepts(v)));
+    required
+        .chain(optional)
+        .map(|d| d.id.as_str())
+        .filter(|id| versions.contains_key(id) && !disabled.contains(id))
+        .collect()
+}
+
+/// The members of one dependency cycle among the enabled mods, if any.
+fn find_cycle<'a>(
+    ids: &[&'a str],
+    disabled: &BTreeSet<&str>,
+    deps: impl Fn(&str) -> Vec<&'a str>,
+) -> Option<Vec<&'a str>> {
+    // Iterative DFS with an explicit path; a back edge to a node on the
+    // path closes a cycle.
+    let mut done: BTreeSet<&str> = BTreeSet::new();
+    for &start in ids {
+        if disabled.contains(start) || done.contains(start) {
+            continue;
+        }
+        let mut path: Vec<(&str, Vec<&str>)> = vec![(start, deps(start))];
+        while let Some((_, pending)) = path.last_mut() {
+            match pending.pop() {
+                Some(next) => {
+                    if let Some(i) = path.iter().position(|(n, _)| *n == next) {
+                        return Some(path[i..].iter().map(|(n, _)| *n).collect());
+                    }
+                    if !done.contains(next) {
+                        path.push((next, deps(next)));
+                    }
+                }
+                None => {
+                    let (n, _) = path.pop().unwrap();
+                    done.insert(n);
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn manifest(id: &str, version: &str, order: i32, json_deps: &str) -> ModManifest {
+        let json = format!(
+            r#"{{"name": "{id}", "id": "{id}", "version": "{version}", "author": "t", "description": "",
+                "load_order": {order} {json_deps}}}"#
+        );
+        let mut m: ModManifest = serde_json::from_str(&json).unwrap();
+        m.path = PathBuf::from(id);
+        m
+    }
+
+    #[test]
+    fn dependencies_load_first_regardless_of_load_order() {
+        let mods = vec![
+            manifest("core", "1.4.0", 500, ""),
+            manifest("addon", "0.2.0", 10, r#", "dependencies": [{"id": "core", "version": "^1.2"}]"#),
+            manifest("extra", "1.0.0", 10, r#", "optional_dependencies": ["addon", "absent"]"#),
+            manifest("plain", "1.0.0", 20, ""),
+        ];
+        let r = resolve(&mods);
+        // addon (10) waits for core (500); plain (20) needs nothing and goes first.
+        assert_eq!(r.order, ["plain", "core", "addon", "extra"]);
+        assert!(r.problems.is_empty(), "{:?}", r.problems);
+    }
+
+    #[test]
+    fn incompatible_and_missing_dependencies_disable_the_chain() {
+        let mods = vec![
+            manifest("core", "2.0.0", 100, ""),
+            manifest("old-addon", "1.0.0", 100, r#", "dependencies": [{"id": "core", "version": ">=1, <2"}]"#),
+            manifest("needs-old", "1.0.0", 100, r#", "dependencies": ["old-addon"]"#),
+            manifest("lonely", "1.0.0", 100, r#", "dependencies": ["nowhere"]"#),
+        ];
+        let r = resolve(&mods);
+        assert_eq!(r.order, ["core"]);
+        assert_eq!(r.disabled, ["lonely", "needs-old", "old-addon"]);
+        assert!(r.problems.contains(&ModProblem::IncompatibleVersion {
+            mod_id: "old-addon".into(),
+            dependency: "core".into(),
+            required: ">=1, <2".into(),
+            found: "2.0.0".into(),
+        }));
+        assert!(r.problems_for("needs-old").any(|p| matches!(p, ModProblem::DependencyDisabled { .. })));
+        assert_eq!(r.problems_for("lonely").next().unwrap().to_string(), "lonely: requires 'nowhere', which is not installed");
+    }
+
+    #[test]
+    fn cycles_and_conflicts_are_disabled() {
+        let mods = vec![
+            manifest("a", "1.0.0", 100, r#", "dependencies": ["b"]"#),
+            manifest("b", "1.0.0", 100, r#", "dependencies": ["a"]"#),
+            manifest("c", "1.0.0", 100, r#", "dependencies": ["a"]"#),
+            manifest("realism", "1.0.0", 100, ""),
+            manifest("arcade", "1.0.0", 100, r#", "conflicts_with": [{"id": "realism", "version": "1"}]"#),
+            manifest("broken", "one", 100, ""),
+        ];
+        let r = resolve(&mods);
+        assert_eq!(r.order, ["realism"]);
+        assert!(r.problems.contains(&ModProblem::Cycle { mods: vec!["a".into(), "b".into()] }));
+        assert_eq!(ModProblem::Cycle { mods: vec!["a".into(), "b".into()] }.to_string(), "dependency cycle among a, b");
+        assert!(r.problems_for("c").any(|p| matches!(p, ModProblem::DependencyDisabled { .. })));
+        assert!(r.problems_for("arcade").any(|p| matches!(p, ModProblem::ConflictsWith { .. })));
+        assert!(r.problems_for("broken").any(|p| matches!(p, ModProblem::InvalidVersion { .. })));
+        assert!(r.problems.iter().all(ModProblem::is_fatal));
+    }
+
+    /// A conflict only counts against a rival that survives: when the rival
+    /// is disabled for a missing dependency, the declaring mod loads.
+    #[test]
+    fn a_disabled_rival_is_no_conflict() {
+        let mods = vec![
+            manifest("arcade", "1.0.0", 100, r#", "conflicts_with": ["realism"]"#),
+            manifest("realism", "1.0.0", 100, r#", "dependencies": ["physics-core"]"#),
+            manifest("arcade-hud", "1.0.0", 100, r#", "dependencies": ["arcade"]"#),
+        ];
+        let r = resolve(&mods);
+        assert_eq!(r.order, ["arcade", "arcade-hud"]);
+        assert_eq!(r.disabled, ["realism"]);
+        assert!(r.problems_for("arcade").next().is_none(), "{:?}", r.problems);
+    }
+
+    #[test]
+    fn bad_version_range_is_a_manifest_error() {
+        let json = r#"{"name": "x", "id": "x", "version": "1.0.0", "author": "", "description": "",
+            "dependencies": [{"id": "core", "version": "not a range"}]}"#;
+        let err = serde_json::from_str::<ModManifest>(json).unwrap_err().to_string();
+        assert!(err.contains("core"), "{err}");
+    }
+}
@@ -0,0 +1,114 @@
+//! Compact binary save encoding.
+//!
+//! Layout: `MAGIC` | container version (1 byte) | deflate(CBOR of the save's
+//! JSON value) | BLAKE3 of everything before it (32 bytes). The payload is
+//! the same JSON value `save_world` would write, so `save_version` and the
+//! `migrate` chain apply unchanged; only the envelope differs. A torn or
+//! bit-rotted file fails the checksum instead of loading half a homestead.
+
+use std::io::{Read, Write};
+
+use serde_json::Value;
+
+/// First bytes of every binary save. JSON saves start with `{` (after
+/// optional whitespace), so the two never collide.
+pub const MAGIC: &[u8; 4] = b"HSAV";
+
+/// Envelope version, independent of `SAVE_VERSION` (which versions the
+/// payload). Bump only if the byte layout above changes.
+const CONTAINER_VERSION: u8 = 1;
+
+const CHECKSUM_LEN: usize = 32;
+
+/// Whether `bytes` look like a binary save (start with `MAGIC`).
+pub fn is_binary(bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+/// Encode a save value: CBOR, deflated, framed and checksummed.
+pub fn encode(value: &Value) -> Result<Vec<u8>, String> {
+    let mut cbor = Vec::new();
+    ciborium::into_writer(value, &mut cbor).map_err(|e| format!("Failed to encode world save: {e}"))?;
+
+    let mut out = Vec::with_capacity(cbor.len() / 4 + MAGIC.len() + 1 + CHECKSUM_LEN);
+    out.extend_from_slice(MAGIC);
+    out.push(CONTAINER_VERSION);
+    let mut deflate = flate2::write::DeflateEncoder::new(out, flate2::Compression::default());
+    deflate.write_all(&cbor).map_err(|e| format!("Failed to compress world save: {e}"))?;
+    let mut out = deflate.finish().map_err(|e| format!("Failed to compress world save: {e}"))?;
+
+    let checksum = blake3::hash(&out);
+    out.extend_from_slice(checksum.as_bytes());
+    Ok(out)
+}
+
+/// Verify and decode a binary save back into its JSON value.
+pub fn decode(bytes: &[u8]) -> Result<Value, String> {
+    if !is_binary(bytes) || bytes.len() < MAGIC.len() + 1 + CHECKSUM_LEN {
+        return Err("not a binary save file".into());
+    }
+    let (body, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+    if blake3::hash(body).as_bytes() != checksum {
+        return Err("save file is corrupt (checksum mismatch)".into());
+    }
+    let container = body[MAGIC.len()];
+    if container != CONTAINER_VERSION {
+        return Err(format!(
+            "binary save container v{container} is not supported by this build (v{CONTAINER_VERSION})"
+        ));
+    }
+
+    let mut cbor = Vec::new();
+    flate2::read::DeflateDecoder::new(&body[MAGIC.len() + 1..])
+        .read_to_end(&mut cbor)
+        .map_err(|e| format!("Failed to decompress world save: {e}"))?;
+    ciborium::from_reader(cbor.as_slice()).map_err(|e| format!("Failed to deserialize world save: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn homestead() -> Value {
+        serde_json::from_str(include_str!("../../tests/fixtures/saves/v1_homestead.json")).unwrap()
+    }
+
+    #[test]
+    fn round_trips_the_save_value() {
+        let value = homestead();
+        let bytes = encode(&value).unwrap();
+        assert!(is_binary(&bytes));
+        assert_eq!(decode(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn smaller_than_pretty_json() {
+        let value = homestead();
+        let json = serde_json::to_string_pretty(&value).unwrap();
+        assert!(encode(&value).unwrap().len() < json.len() / 2);
+    }
+
+    #[test]
+    fn any_flipped_byte_fails_the_checksum() {
+        let bytes = encode(&homestead()).unwrap();
+        for i in [MAGIC.len() + 1, bytes.len() / 2, bytes.len() - 1] {
+            let mut bad = bytes.clone();
+            bad[i] ^= 0x40;
+            let err = decode(&bad).unwrap_err();
+            assert!(err.contains("checksum"), "byte {i}: {err}");
+        }
+    }
+
+    #[test]
+    fn truncated_file_is_rejected() {
+        let bytes = encode(&homestead()).unwrap();
+        assert!(decode(&bytes[..bytes.len() - 10]).is_err());
+        assert!(decode(&bytes[..MAGIC.len() + 2]).is_err());
+    }
+
+    #[test]
+    fn json_is_not_mistaken_for_binary() {
+        assert!(!is_binary(b"{\"name\":\"x\"}"));
+        assert!(!is_binary(b""));
+    }
+}
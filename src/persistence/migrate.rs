@@ -0,0 +1,256 @@
+//! Save-format migrations.
+//!
+//! A save is upgraded as raw JSON, one version at a time, before serde sees
+//! it: `STEPS[n]` turns a version-n save into version n + 1. A step may
+//! rename, reshape or fill in fields; it must never drop player progress
+//! (inventory, crops, vehicles, quests). Each past version has a fixture in
+//! `tests/fixtures/saves/` that the tests below load through the whole
+//! chain.
+
+use serde_json::{Map, Value};
+
+use super::{WorldSave, SAVE_VERSION};
+
+type Step = fn(&mut Map<String, Value>) -> Result<(), String>;
+
+/// `STEPS[n]` upgrades a version-n save to n + 1; `STEPS.len()` is
+/// `SAVE_VERSION`.
+const STEPS: &[Step] = &[v0_to_v1, v1_to_v2, v2_to_v3, v3_to_v4, v4_to_v5, v5_to_v6, v6_to_v7, v7_to_v8];
+
+/// A save's `save_version` (absent = 0, a save from before versioning).
+pub fn version_of(value: &Value) -> u32 {
+    value.get("save_version").and_then(Value::as_u64).map_or(0, |v| v as u32)
+}
+
+/// Run every step from the save's version up to `SAVE_VERSION`. Returns the
+/// version it started at. A save from a newer build is refused rather than
+/// loaded with its unknown fields dropped.
+pub fn upgrade(value: &mut Value) -> Result<u32, String> {
+    let from = version_of(value);
+    if from > SAVE_VERSION {
+        return Err(format!(
+            "save is format v{from}, newer than this build understands (v{SAVE_VERSION}); update the game to load it"
+        ));
+    }
+    let map = value.as_object_mut().ok_or("save is not a JSON object")?;
+    for (version, step) in STEPS.iter().enumerate().skip(from as usize) {
+        step(map).map_err(|e| format!("save migration v{version} -> v{}: {e}", version + 1))?;
+        map.insert("save_version".into(), (version as u32 + 1).into());
+    }
+    Ok(from)
+}
+
+/// Migrate and deserialize a parsed save.
+pub fn from_value(mut value: Value) -> Result<WorldSave, String> {
+    upgrade(&mut value)?;
+    serde_json::from_value(value).map_err(|e| format!("Failed to deserialize world save: {e}"))
+}
+
+/// Migrate and deserialize save JSON (no backup; see `load_world`).
+pub fn from_json(json: &str) -> Result<WorldSave, String> {
+    let value = serde_json::from_str(json).map_err(|e| format!("Failed to deserialize world save: {e}"))?;
+    from_value(value)
+}
+
+/// v0 -> v1: the first versioned format. Unversioned saves leaned on
+/// `#[serde(default)]` for every field added after them (kind/design
+/// v0.380, appearance/outfit v0.440, character_name v0.448, placed_items
+/// v0.516, deployed_vehicles v0.677, credits v0.747, quests v0.748, crops
+/// v0.863); write those defaults out so every v1 save is complete and later
+/// steps can rely on the fields being present.
+fn v0_to_v1(save: &mut Map<String, Value>) -> Result<(), String> {
+    let to_value = |v: Result<Value, serde_json::Error>| v.map_err(|e| e.to_string());
+    let defaults = [
+        ("kind", Value::from(super::default_kind())),
+        ("design", Value::from(super::default_design())),
+        ("character_name", Value::from(super::default_character_name())),
+        ("appearance", to_value(serde_json::to_value(crate::ecs::components::Appearance::default()))?),
+        ("outfit", to_value(serde_json::to_value(crate::ecs::components::Outfit::default()))?),
+        ("placed_items", Value::Array(Vec::new())),
+        ("deployed_vehicles", Value::Array(Vec::new())),
+        ("crops", Value::Array(Vec::new())),
+        ("credits", Value::from(super::default_credits())),
+        ("quests", Value::Null),
+    ];
+    for (field, default) in defaults {
+        save.entry(field).or_insert(default);
+    }
+    Ok(())
+}
+
+/// v1 -> v2: saves gain `machines` (battery charge + tank fill), and the
+/// game clock is really written. v1 always wrote `game_time: 0` while crops
+/// kept their game-time `planted_at`, so lift the clock to the newest
+/// planting: no crop may look planted in the future.
+fn v1_to_v2(save: &mut Map<String, Value>) -> Result<(), String> {
+    save.entry("machines").or_insert(Value::Array(Vec::new()));
+    let newest_planting = save
+        .get("crops")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|crop| crop.get("planted_at").and_then(Value::as_f64))
+        .fold(0.0, f64::max);
+    let clock = save.get("game_time").and_then(Value::as_f64).unwrap_or(0.0);
+    save.insert("game_time".into(), clock.max(newest_planting).into());
+    Ok(())
+}
+
+/// v2 -> v3: saves gain `soil_plots`. Existing bed / field crops get their
+/// plot seeded from the area's soil type on the first farming tick.
+fn v2_to_v3(save: &mut Map<String, Value>) -> Result<(), String> {
+    save.entry("soil_plots").or_insert(Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v3 -> v4: saves gain `cultivars`. Crops from older saves have no genome
+/// and keep growing as the plain species.
+fn v3_to_v4(save: &mut Map<String, Value>) -> Result<(), String> {
+    save.entry("cultivars").or_insert(Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v4 -> v5: saves gain `animals`. With none saved, the homestead's starter
+/// herd spawns on the next world load, as it always did.
+fn v4_to_v5(save: &mut Map<String, Value>) -> Result<(), String> {
+    save.entry("animals").or_insert(Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v5 -> v6: saves gain `markets`. Vendors from older saves open with every
+/// good at its target stock and no price history.
+fn v5_to_v6(save: &mut Map<String, Value>) -> Result<(), String> {
+    save.entry("markets").or_insert(Value::Array(Vec::new()));
+    Ok(())
+}
+
+/// v6 -> v7: saves gain the fleet's holds, shipments and standing orders.
+/// With no fleet saved, it is seeded from data/logistics.ron on the next run.
+fn v6_to_v7(save: &mut Map<String, Value>) -> Result<(), String> {
+    for field in ["fleet", "shipments", "standing_orders"] {
+        save.entry(field).or_insert(Value::Array(Vec::new()));
+    }
+    Ok(())
+}
+
+/// v7 -> v8: deployed vehicles gain `fuel_l`. Older vehicles were never
+/// driven on fuel, so they come back with a full tank: the kit's
+/// `fuel_tank_l` from the shipped kits.ron. A vehicle no shipped kit builds
+/// (a mod's) is left unset and DrivingSystem fills it from the live registry.
+fn v7_to_v8(save: &mut Map<String, Value>) -> Result<(), String> {
+    const KITS: &str = include_str!("../../data/vehicles/kits.ron");
+    let kits = crate::systems::vehicles::VehicleKitRegistry::from_ron(KITS.as_bytes())
+        .map_err(|e| format!("shipped kits.ron: {e}"))?;
+    if let Some(Value::Array(vehicles)) = save.get_mut("deployed_vehicles") {
+        for vehicle in vehicles.iter_mut().filter_map(Value::as_object_mut) {
+            let tank = vehicle
+                .get("item_id")
+                .and_then(Value::as_str)
+                .and_then(|id| kits.get_vehicle(id))
+                .map(|kit| kit.fuel_tank_l);
+            if let Some(tank) = tank {
+                vehicle.entry("fuel_l").or_insert(tank.into());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One fixture per past format, oldest first: (version, file, contents).
+    /// Bumping `SAVE_VERSION` means adding the new version's fixture here.
+    const FIXTURES: &[(u32, &str, &str)] = &[
+        (0, "v0_pre_kind.json", include_str!("../../tests/fixtures/saves/v0_pre_kind.json")),
+        (0, "v0_homestead.json", include_str!("../../tests/fixtures/saves/v0_homestead.json")),
+        (1, "v1_homestead.json", include_str!("../../tests/fixtures/saves/v1_homestead.json")),
+        (2, "v2_homestead.json", include_str!("../../tests/fixtures/saves/v2_homestead.json")),
+        (3, "v3_homestead.json", include_str!("../../tests/fixtures/saves/v3_homestead.json")),
+        (4, "v4_homestead.json", include_str!("../../tests/fixtures/saves/v4_homestead.json")),
+        (5, "v5_homestead.json", include_str!("../../tests/fixtures/saves/v5_homestead.json")),
+        (6, "v6_homestead.json", include_str!("../../tests/fixtures/saves/v6_homestead.json")),
+        (7, "v7_homestead.json", include_str!("../../tests/fixtures/saves/v7_homestead.json")),
+        (8, "v8_homestead.json", include_str!("../../tests/fixtures/saves/v8_homestead.json")),
+    ];
+
+    #[test]
+    fn steps_cover_every_version() {
+        assert_eq!(STEPS.len(), SAVE_VERSION as usize);
+        let newest = FIXTURES.iter().map(|f| f.0).max();
+        assert_eq!(newest, Some(SAVE_VERSION), "add a fixture for the current save format");
+    }
+
+    #[test]
+    fn every_fixture_loads_at_the_current_version() {
+        for (version, file, json) in FIXTURES {
+            let mut value: Value = serde_json::from_str(json).unwrap();
+            assert_eq!(version_of(&value), *version, "{file}");
+            assert_eq!(upgrade(&mut value).unwrap(), *version, "{file}");
+            assert_eq!(version_of(&value), SAVE_VERSION, "{file}");
+            let save: WorldSave = serde_json::from_value(value).unwrap_or_else(|e| panic!("{file}: {e}"));
+            assert_eq!(save.save_version, SAVE_VERSION, "{file}");
+        }
+    }
+
+    /// The long-running homestead keeps its crops, vehicles, wallet and
+    /// quests across every migration.
+    #[test]
+    fn homestead_progress_survives_migration() {
+        for (_, file, json) in FIXTURES.iter().filter(|f| f.1.ends_with("homestead.json")) {
+            let save = from_json(json).unwrap_or_else(|e| panic!("{file}: {e}"));
+            assert_eq!(save.crops.len(), 2, "{file}");
+            assert_eq!(save.crops[0].crop_def_id, "tomato", "{file}");
+            assert_eq!(save.crops[1].tower_slot, Some(3), "{file}");
+            assert_eq!(save.deployed_vehicles.len(), 1, "{file}");
+            assert_eq!(save.deployed_vehicles[0].item_id, "truck_pickup_0", "{file}");
+            assert_eq!(save.credits, 12_450, "{file}");
+            assert_eq!(save.inventory, vec![("iron_ore_0".to_string(), 12)], "{file}");
+            let quests = save.quests.as_ref().unwrap_or_else(|| panic!("{file}: quests lost"));
+            assert_eq!(quests.completed_quests, vec!["gs_first_steps".to_string()], "{file}");
+        }
+    }
+
+    #[test]
+    fn unversioned_save_gets_explicit_defaults() {
+        let mut value: Value = serde_json::from_str(FIXTURES[0].2).unwrap();
+        upgrade(&mut value).unwrap();
+        assert_eq!(value["kind"], "offline");
+        assert_eq!(value["design"], "fibonacci");
+        assert_eq!(value["credits"], -1);
+        assert_eq!(value["crops"], Value::Array(Vec::new()));
+        assert!(value["quests"].is_null());
+    }
+
+    #[test]
+    fn v1_clock_is_lifted_to_the_newest_planting() {
+        let mut value: Value = serde_json::from_str(FIXTURES[2].2).unwrap();
+        let ahead = from_value(value.clone()).unwrap();
+        assert_eq!(ahead.game_time, 864_000.0, "a clock already past every planting is kept");
+        assert!(ahead.machines.is_empty());
+        value["game_time"] = 0.0.into();
+        let lifted = from_value(value).unwrap();
+        assert_eq!(lifted.game_time, 840_000.0, "the lettuce was planted last");
+    }
+
+    #[test]
+    fn vehicle_fuel_defaults_to_a_full_tank_before_v8() {
+        let mut value: Value = serde_json::from_str(FIXTURES[8].2).unwrap();
+        assert!(value["deployed_vehicles"][0].get("fuel_l").is_none());
+        upgrade(&mut value).unwrap();
+        assert_eq!(value["deployed_vehicles"][0]["fuel_l"].as_f64(), Some(98.0), "the pickup's full tank");
+        let upgraded: WorldSave = serde_json::from_value(value).unwrap();
+        assert_eq!(upgraded.deployed_vehicles[0].fuel_l, Some(98.0));
+        let current = from_json(FIXTURES[9].2).unwrap();
+        assert_eq!(current.deployed_vehicles[0].fuel_l, Some(42.5));
+    }
+
+    #[test]
+    fn newer_save_is_refused() {
+        let mut value: Value = serde_json::from_str(FIXTURES[2].2).unwrap();
+        value["save_version"] = (SAVE_VERSION + 1).into();
+        let err = upgrade(&mut value).unwrap_err();
+        assert!(err.contains("newer than this build"), "{err}");
+    }
+}
@@ -0,0 +1,867 @@
+//! World persistence — save/load game state to JSON files.
+//!
+//! Save directory: platform data dir + "/saves/"
+//! - Windows: `%APPDATA%/HumanityOS/saves/`
+//! - Linux:   `$XDG_DATA_HOME/HumanityOS/saves/` or `~/.local/share/HumanityOS/saves/`
+//! - macOS:   `~/Library/Application Support/HumanityOS/saves/`
+//!
+//! Every save carries a `save_version`. Loading an older one runs the
+//! `migrate` chain over its JSON (after copying the file aside), so a
+//! renamed or restructured field is carried over instead of silently
+//! resetting to its default.
+//!
+//! A save is written either as pretty JSON (`.json`) or in the compact
+//! checksummed `binary` encoding (`.hsav`), picked by the file extension.
+//! Writes go to a temp file that is renamed over the target, so a crash
+//! mid-write leaves the previous save intact. Loading sniffs the content,
+//! not the extension.
+
+pub mod binary;
+pub mod migrate;
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The save format this build writes. Bump it together with a new step in
+/// `migrate::STEPS` and a fixture in `tests/fixtures/saves/`.
+pub const SAVE_VERSION: u32 = 8;
+
+/// Extension of binary saves (see `binary`).
+pub const BINARY_EXTENSION: &str = "hsav";
+
+/// How many rolling `auto_save_<n>` slots `auto_save` cycles through.
+pub const AUTO_SAVE_SLOTS: usize = 3;
+
+/// On-disk encoding of a save file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveFormat {
+    /// Pretty-printed JSON: readable and diffable, the default.
+    Json,
+    /// Deflated CBOR with a BLAKE3 footer: small and corruption-evident, for
+    /// large homesteads.
+    Binary,
+}
+
+impl SaveFormat {
+    /// The format a path asks for: `.hsav` is binary, anything else JSON.
+    pub fn for_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(BINARY_EXTENSION) => SaveFormat::Binary,
+            _ => SaveFormat::Json,
+        }
+    }
+
+    /// File extension for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            SaveFormat::Json => "json",
+            SaveFormat::Binary => BINARY_EXTENSION,
+        }
+    }
+}
+
+/// Whether `path` names a save file of either format (temp files and
+/// migration backups are not saves).
+pub fn is_save_file(path: &Path) -> bool {
+    matches!(path.extension().and_then(|ext| ext.to_str()), Some("json" | BINARY_EXTENSION))
+}
+
+/// Complete world save state. A "home" (the homes-as-save-profiles model, v0.380)
+/// IS a WorldSave that knows its `kind` + `design`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorldSave {
+    /// Format version (`SAVE_VERSION` when written; 0 = a save from before
+    /// versioning). `load_world` migrates older saves up to the current one.
+    #[serde(default)]
+    pub save_version: u32,
+    pub name: String,
+    pub timestamp: u64,
+    pub game_time: f64,
+    pub player_position: [f32; 3],
+    pub player_rotation: [f32; 4],
+    pub player_health: f32,
+    pub inventory: Vec<(String, u32)>,
+    pub skills: HashMap<String, (u32, u32)>,
+    pub constructions: Vec<ConstructionSave>,
+    pub weather_state: String,
+    /// What this home IS (the "who owns the truth" axis): "offline" (you own the
+    /// local save), "server" (a relay owns it), or "real" (physical sensors own
+    /// it). Defaults to "offline" so pre-v0.380 saves load unchanged. Server + Real
+    /// are deferred; offline is the only live kind today.
+    #[serde(default = "default_kind")]
+    pub kind: String,
+    /// The Design (blueprint) this home is built from, e.g. "fibonacci". Defaults
+    /// to "fibonacci" (the first + only design today).
+    #[serde(default = "default_design")]
+    pub design: String,
+    /// The GAME character's name (v0.448), DECOUPLED from the chat/network profile name
+    /// (which is the Dilithium identity). A character lives in the save (self-custodial,
+    /// local), separate from who you are in chat. serde-default for old saves.
+    #[serde(default = "default_character_name")]
+    pub character_name: String,
+    /// The player's avatar appearance (v0.440). serde-default so pre-v0.440 saves load
+    /// unchanged (they get the default blockman).
+    #[serde(default)]
+    pub appearance: crate::ecs::components::Appearance,
+    /// The player's equipped cosmetic outfit (v0.440). serde-default for old saves.
+    #[serde(default)]
+    pub outfit: crate::ecs::components::Outfit,
+    /// The organize-layer container contents (v0.516): every item that lives in a
+    /// container other than the live backpack, tagged with its container path. The
+    /// backpack itself is `inventory` above. serde-default so pre-v0.517 saves load
+    /// with an empty pool (the inventory then re-seeds from data/places/seed.json).
+    #[serde(default)]
+    pub placed_items: Vec<crate::gui::PlacedItem>,
+    /// Vehicles standing in the world (economy Phase 2 Stage 1, v0.677), deployed
+    /// from kit items. serde-default so older saves load with none. NOTE: the
+    /// separate `constructions` field above is dormant schema (never written or
+    /// read by the live save path) — do not conflate the two.
+    #[serde(default)]
+    pub deployed_vehicles: Vec<VehicleSave>,
+    /// Growing crops (v0.863): every CropInstance round-trips so the garden
+    /// survives restarts (operator: "We should just be able to load up and have
+    /// our changes persist"). serde-default so older saves load with none, and
+    /// the showcase auto-seed then fills the grow surfaces instead.
+    #[serde(default)]
+    pub crops: Vec<crate::ecs::components::CropInstance>,
+    /// The player's credit balance (v0.747, ladder rung 3). serde-default -1 =
+    /// "no wallet saved yet" so pre-v0.747 saves keep the fresh-start default
+    /// (10,000 CR) instead of loading as broke.
+    #[serde(default = "default_credits")]
+    pub credits: i64,
+    /// Quest progress (v0.748, ladder rung 4). None = a pre-quest-save file;
+    /// the player then starts fresh with the auto-accepted First Steps chain,
+    /// exactly as before. Some = the full tracker round-trips, so completing
+    /// gs_first_steps twice is no longer the player experience.
+    #[serde(default)]
+    pub quests: Option<crate::systems::quests::QuestTracker>,
+    /// Live state of the home's machines (v2): battery charge and tank fill,
+    /// keyed by machine instance id, so the offline catch-up starts from where
+    /// the home was left instead of the 50% spawn defaults.
+    #[serde(default)]
+    pub machines: Vec<MachineSave>,
+    /// Soil chemistry of every bed / tray / field unit (v3), so depleted or
+    /// amended plots and their crop-rotation history survive a restart.
+    #[serde(default)]
+    pub soil_plots: Vec<crate::systems::farming::soil::SoilPlot>,
+    /// Cultivars bred by saving seed (v4): each one's parent genomes and
+    /// name, so its `seed_<plant>_cv<n>` items still sow the same line.
+    #[serde(default)]
+    pub cultivars: Vec<crate::systems::farming::breeding::Cultivar>,
+    /// The herd (v5): every husbandry animal with its hunger, mood, sex,
+    /// genome and age, so tamed and bred stock survives a restart. Empty on
+    /// older saves, and the homestead's starter herd spawns instead.
+    #[serde(default)]
+    pub animals: Vec<AnimalSave>,
+    /// NPC vendor markets (v6): each trading post's stock and price history,
+    /// so a glut the player sold into is still there after a restart.
+    #[serde(default)]
+    pub markets: Vec<crate::systems::economy::market::VendorMarket>,
+    /// Fleet logistics (v7): every ship's and depot's hold. Empty on older
+    /// saves, and the fleet is seeded from data/logistics.ron instead.
+    #[serde(default)]
+    pub fleet: Vec<FleetNodeSave>,
+    /// Cargo runs in transit (v7), each with its payload and progress along
+    /// its route, so goods on the way are not lost to a restart.
+    #[serde(default)]
+    pub shipments: Vec<crate::ecs::components::CargoVehicle>,
+    /// Standing delivery orders (v7) the player set up between holds.
+    #[serde(default)]
+    pub standing_orders: Vec<crate::ecs::components::StandingOrder>,
+}
+
+fn default_credits() -> i64 {
+    -1
+}
+
+/// One deployed vehicle in a save: what it is + where it stands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VehicleSave {
+    /// The ASSEMBLED vehicle's items.csv id (e.g. "truck_pickup_0").
+    pub item_id: String,
+    pub position: [f32; 3],
+    /// Yaw around +Y in radians (a reloaded vehicle settles its own tilt).
+    pub yaw: f32,
+    /// Litres left in the tank (v8); None = never simulated, full.
+    #[serde(default)]
+    pub fuel_l: Option<f32>,
+}
+
+/// One herd animal in a save: everything its entity is rebuilt from, so a
+/// load needs no creature registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimalSave {
+    pub name: String,
+    pub creature: crate::ecs::components::Creature,
+    pub position: [f32; 3],
+    pub husbandry: crate::ecs::components::Husbandry,
+    pub age: crate::ecs::components::Age,
+    pub health: crate::ecs::components::Health,
+    #[serde(default)]
+    pub harvestable: Option<crate::ecs::components::Harvestable>,
+    /// Kill loot (id, chance, min, max), as resolved at spawn.
+    #[serde(default)]
+    pub loot: Vec<(String, f32, u32, u32)>,
+}
+
+/// One fleet ship or settlement depot in a save: the node and its hold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetNodeSave {
+    pub node: crate::ecs::components::LogisticsNode,
+    /// (item id, count).
+    pub cargo: Vec<(String, u32)>,
+}
+
+/// One home machine's live state in a save. Only the parts it has are set.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MachineSave {
+    /// The machine instance id (`data/machines/home.ron` `id`).
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub battery_wh: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub water_l: Option<f32>,
+}
+
+fn default_kind() -> String {
+    "offline".to_string()
+}
+fn default_design() -> String {
+    "fibonacci".to_string()
+}
+fn default_character_name() -> String {
+    "Wanderer".to_string()
+}
+
+impl WorldSave {
+    /// A fresh OFFLINE home built from the given design, with empty progress. The
+    /// "save wrapper" entry point: a home is a WorldSave that knows its kind +
+    /// design. `timestamp` is left 0; the save flow stamps it when the file is
+    /// written.
+    pub fn new_offline(name: impl Into<String>, design: impl Into<String>) -> Self {
+        WorldSave {
+            save_version: SAVE_VERSION,
+            name: name.into(),
+            timestamp: 0,
+            game_time: 0.0,
+            player_position: [0.0, 0.0, 0.0],
+            player_rotation: [0.0, 0.0, 0.0, 1.0],
+            player_health: 100.0,
+            inventory: Vec::new(),
+            skills: HashMap::new(),
+            constructions: Vec::new(),
+            weather_state: "clear".to_string(),
+            kind: "offline".to_string(),
+            design: design.into(),
+            character_name: default_character_name(),
+            appearance: Default::default(),
+            outfit: Default::default(),
+            placed_items: Vec::new(),
+            deployed_vehicles: Vec::new(),
+            crops: Vec::new(),
+            credits: -1,
+            quests: None,
+            machines: Vec::new(),
+            soil_plots: Vec::new(),
+            cultivars: Vec::new(),
+            animals: Vec::new(),
+            markets: Vec::new(),
+            fleet: Vec::new(),
+            shipments: Vec::new(),
+            standing_orders: Vec::new(),
+        }
+    }
+}
+
+/// A saved construction/building in the world.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstructionSave {
+    pub blueprint_id: String,
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+    pub health: f32,
+}
+
+/// Get the platform-appropriate saves directory.
+///
+/// Falls back to `./saves/` if the platform data directory cannot be determined.
+pub fn saves_dir() -> PathBuf {
+    // Portable mode (v0.707): saves travel beside the exe.
+    if let Some(p) = crate::storage::portable_saves_dir() {
+        return p;
+    }
+    // Try standard platform data dirs without adding a dependency.
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(appdata) = std::env::var("APPDATA") {
+            return PathBuf::from(appdata)
+                .join("HumanityOS")
+                .join("saves");
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        if let Ok(home) = std::env::var("HOME") {
+            return PathBuf::from(home)
+                .join("Library")
+                .join("Application Support")
+                .join("HumanityOS")
+                .join("saves");
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Ok(xdg) = std::env::var("XDG_DATA_HOME") {
+            return PathBuf::from(xdg)
+                .join("HumanityOS")
+                .join("saves");
+        }
+        if let Ok(home) = std::env::var("HOME") {
+            return PathBuf::from(home)
+                .join(".local")
+                .join("share")
+                .join("HumanityOS")
+                .join("saves");
+        }
+    }
+
+    PathBuf::from("saves")
+}
+
+/// Save the world state to the given path, in the format its extension
+/// picks (`SaveFormat::for_path`). Always written as `SAVE_VERSION`.
+pub fn save_world(path: &Path, save: &WorldSave) -> Result<(), String> {
+    save_world_as(path, save, SaveFormat::for_path(path))
+}
+
+/// Save the world state to the given path in an explicit format. The bytes
+/// land in `<path>.tmp` first and are renamed over `path` only once fully
+/// written, so an interrupted save never truncates the existing file.
+pub fn save_world_as(path: &Path, save: &WorldSave, format: SaveFormat) -> Result<(), String> {
+    // Ensure parent directory exists.
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create save directory: {e}"))?;
+    }
+
+    let mut value = serde_json::to_value(save)
+        .map_err(|e| format!("Failed to serialize world save: {e}"))?;
+    value["save_version"] = SAVE_VERSION.into();
+    let bytes = match format {
+        SaveFormat::Json => serde_json::to_vec_pretty(&value)
+            .map_err(|e| format!("Failed to serialize world save: {e}"))?,
+        SaveFormat::Binary => binary::encode(&value)?,
+    };
+
+    write_atomic(path, &bytes)?;
+
+    log::info!("World saved to {}", path.display());
+    Ok(())
+}
+
+/// Write `bytes` to a sibling temp file, flush it to disk, then rename it
+/// over `path`. The temp file is removed if any step fails.
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+
+    let mut tmp_name = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp = PathBuf::from(tmp_name);
+    let result = (|| {
+        let mut file = std::fs::File::create(&tmp)?;
+        file.write_all(bytes)?;
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(&tmp, path)
+    })();
+    result.map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        format!("Failed to write save file: {e}")
+    })
+}
+
+/// Parse a save file's bytes (binary or JSON, sniffed from the content)
+/// into its raw JSON value, before migration.
+fn parse_save(bytes: &[u8]) -> Result<serde_json::Value, String> {
+    if binary::is_binary(bytes) {
+        return binary::decode(bytes);
+    }
+    serde_json::from_slice(bytes).map_err(|e| format!("Failed to deserialize world save: {e}"))
+}
+
+/// Load a world save (JSON or binary) from the given path, migrating an
+/// older format up to `SAVE_VERSION`. Before a migration (or on meeting a
+/// save from a newer build) the untouched file is copied to
+/// `<file>.v<version>.bak` beside it, so a bad step never costs the
+/// original.
+pub fn load_world(path: &Path) -> Result<WorldSave, String> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| format!("Failed to read save file: {e}"))?;
+
+    let value = parse_save(&bytes)?;
+    let version = migrate::version_of(&value);
+    if version != SAVE_VERSION {
+        backup_before_migrate(path, version);
+    }
+    let save = migrate::from_value(value)?;
+
+    if version != SAVE_VERSION {
+        log::info!("World loaded from {} (migrated from save v{version})", path.display());
+    } else {
+        log::info!("World loaded from {}", path.display());
+    }
+    Ok(save)
+}
+
+/// Read a save for display (launcher and showroom listings): migrated in
+/// memory through `migrate::from_value`, with no backup written. Loading a
+/// save into the world goes through `load_world`.
+pub fn read_world(path: &Path) -> Result<WorldSave, String> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| format!("Failed to read save file: {e}"))?;
+    migrate::from_value(parse_save(&bytes)?)
+}
+
+/// Path of the pre-migration copy of `path` at `version`. The whole file
+/// name is kept, so `home.json` and `home.hsav` never share a backup.
+pub fn backup_path(path: &Path, version: u32) -> PathBuf {
+    let name = path.file_name().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    path.with_file_name(format!("{name}.v{version}.bak"))
+}
+
+/// Copy `path` aside before it is migrated. An existing copy is kept: the
+/// first one is the pristine file. A failed copy is logged, not fatal (the
+/// migrated save is only written back by the next save anyway).
+fn backup_before_migrate(path: &Path, version: u32) {
+    let backup = backup_path(path, version);
+    if backup.exists() {
+        return;
+    }
+    match std::fs::copy(path, &backup) {
+        Ok(_) => log::info!("Save v{version} backed up to {} before migrating", backup.display()),
+        Err(e) => log::warn!("Could not back up {} before migrating: {e}", path.display()),
+    }
+}
+
+/// List all save files in the saves directory with their names and timestamps.
+///
+/// Returns a list of `(name, timestamp)` pairs sorted by timestamp descending
+/// (most recent first).
+pub fn list_saves(saves_dir: &Path) -> Vec<(String, u64)> {
+    let entries = match std::fs::read_dir(saves_dir) {
+        Ok(e) => e,
+        Err(_) => return Vec::new(),
+    };
+
+    let mut saves: Vec<(String, u64)> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| is_save_file(&e.path()))
+        .filter_map(|e| read_world(&e.path()).ok())
+        .map(|save| (save.name, save.timestamp))
+        .collect();
+
+    // Sort by timestamp, most recent first.
+    saves.sort_by(|a, b| b.1.cmp(&a.1));
+    saves
+}
+
+/// Auto-save the world into the next of `AUTO_SAVE_SLOTS` rolling slots
+/// (`auto_save_0` .. `auto_save_<N-1>` in the given directory): the first
+/// empty slot, else the one written longest ago. A bad autosave therefore
+/// never replaces the only good one.
+pub fn auto_save(saves_dir: &Path, save: &WorldSave, format: SaveFormat) {
+    let slot = next_auto_save_slot(saves_dir);
+    let path = saves_dir.join(format!("auto_save_{slot}.{}", format.extension()));
+    match save_world_as(&path, save, format) {
+        Ok(_) => {
+            // Switching formats must not leave the slot's older twin behind
+            // to be picked over the fresh save.
+            for other in [SaveFormat::Json, SaveFormat::Binary] {
+                if other != format {
+                    let _ = std::fs::remove_file(path.with_extension(other.extension()));
+                }
+            }
+            log::info!("Auto-save complete (slot {slot})");
+        }
+        Err(e) => log::error!("Auto-save failed: {e}"),
+    }
+}
+
+/// The autosave slot to write next: the lowest empty slot, else the one
+/// with the oldest modification time.
+fn next_auto_save_slot(saves_dir: &Path) -> usize {
+    let written = |slot: usize| {
+        [SaveFormat::Json, SaveFormat::Binary]
+            .iter()
+            .filter_map(|f| {
+                let path = saves_dir.join(format!("auto_save_{slot}.{}", f.extension()));
+                std::fs::metadata(path).and_then(|m| m.modified()).ok()
+            })
+            .max()
+    };
+    let mut oldest: Option<(usize, std::time::SystemTime)> = None;
+    for slot in 0..AUTO_SAVE_SLOTS {
+        let Some(modified) = written(slot) else {
+            return slot;
+        };
+        if oldest.is_none_or(|(_, t)| modified < t) {
+            oldest = Some((slot, modified));
+        }
+    }
+    oldest.map_or(0, |(slot, _)| slot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn test_save() -> WorldSave {
+        WorldSave {
+            save_version: SAVE_VERSION,
+            name: "Test Save".to_string(),
+            timestamp: 1700000000,
+            game_time: 3600.0,
+            player_position: [10.0, 5.0, -3.0],
+            player_rotation: [0.0, 0.0, 0.0, 1.0],
+            player_health: 100.0,
+            inventory: vec![
+                ("wood".to_string(), 50),
+                ("stone".to_string(), 25),
+            ],
+            skills: {
+                let mut m = HashMap::new();
+                m.insert("mining".to_string(), (5, 1200));
+                m.insert("farming".to_string(), (3, 450));
+                m
+            },
+            constructions: vec![
+                ConstructionSave {
+                    blueprint_id: "wooden_wall".to_string(),
+                    position: [20.0, 0.0, 15.0],
+                    rotation: [0.0, 0.0, 0.0, 1.0],
+                    health: 100.0,
+                },
+            ],
+            weather_state: "clear".to_string(),
+            kind: "offline".to_string(),
+            design: "fibonacci".to_string(),
+            character_name: "Test Character".to_string(),
+            appearance: Default::default(),
+            outfit: Default::default(),
+            placed_items: Vec::new(),
+            deployed_vehicles: Vec::new(),
+            crops: Vec::new(),
+            credits: -1,
+            quests: None,
+            machines: vec![MachineSave { id: "battery_1".to_string(), battery_wh: Some(4200.0), water_l: None }],
+            soil_plots: vec![crate::systems::farming::soil::SoilPlot {
+                area_id: "potato_grow_bed".to_string(),
+                unit: 0,
+                soil_type: "loam".to_string(),
+                soil: crate::systems::farming::soil::Soil { potassium: 0.1, ..Default::default() },
+                crop: Some("potato".to_string()),
+                previous_crop: Some("bean".to_string()),
+            }],
+            cultivars: vec![crate::systems::farming::breeding::Cultivar {
+                seed_id: "seed_tomato_cv1".to_string(),
+                plant_id: "tomato".to_string(),
+                name: "Sunburst".to_string(),
+                generation: 3,
+                mother: Default::default(),
+                father: Default::default(),
+            }],
+            animals: vec![AnimalSave {
+                name: "Goat".to_string(),
+                creature: crate::ecs::components::Creature {
+                    def_id: "goat".to_string(),
+                    anchor: glam::Vec3::new(27.0, 0.0, 65.0),
+                    range: 3.0,
+                    phase: 1.7,
+                    speed: 0.9,
+                    tint: [0.62, 0.52, 0.38],
+                    body_side: 0.39,
+                },
+                position: [27.5, 0.0, 64.0],
+                husbandry: crate::ecs::components::Husbandry {
+                    hunger: 0.2,
+                    mood: 0.8,
+                    female: true,
+                    genome: None,
+                    breed_cooldown: 120.0,
+                },
+                age: crate::ecs::components::Age { years: 0.1, life_stage: "adult".to_string() },
+                health: crate::ecs::components::Health { current: 50.0, max: 55.0 },
+                harvestable: None,
+                loot: vec![("raw_mutton_0".to_string(), 1.0, 1, 2)],
+            }],
+            markets: vec![crate::systems::economy::market::VendorMarket {
+                id: "market_c1".to_string(),
+                region: "commons".to_string(),
+                stock: [("tomato_0".to_string(), 31.5)].into_iter().collect(),
+                history: [("tomato_0".to_string(), vec![9.1, 8.4])].into_iter().collect(),
+            }],
+            fleet: vec![FleetNodeSave {
+                node: crate::ecs::components::LogisticsNode {
+                    id: "mule".to_string(),
+                    name: "Mule".to_string(),
+                    kind: "ship".to_string(),
+                    capacity_kg: 12000.0,
+                },
+                cargo: vec![("iron_ore_0".to_string(), 90)],
+            }],
+            shipments: vec![crate::ecs::components::CargoVehicle {
+                route_id: "mule-home".to_string(),
+                progress: 0.4,
+                speed_per_day: 2.0,
+                payload: vec![("iron_ore_0".to_string(), 30)],
+                arrived: false,
+                origin: "mule".to_string(),
+                destination: "home".to_string(),
+                order: Some("home_ore".to_string()),
+            }],
+            standing_orders: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn new_offline_has_defaults() {
+        let h = WorldSave::new_offline("My Homestead", "fibonacci");
+        assert_eq!(h.kind, "offline");
+        assert_eq!(h.design, "fibonacci");
+        assert_eq!(h.name, "My Homestead");
+        assert!(h.inventory.is_empty());
+    }
+
+    #[test]
+    fn round_trip_save_load() {
+        let dir = std::env::temp_dir().join("humanity_test_saves");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("test_roundtrip.json");
+
+        let save = test_save();
+        save_world(&path, &save).expect("save should succeed");
+        let loaded = load_world(&path).expect("load should succeed");
+
+        assert_eq!(loaded.name, save.name);
+        assert_eq!(loaded.timestamp, save.timestamp);
+        assert!((loaded.game_time - save.game_time).abs() < f64::EPSILON);
+        assert_eq!(loaded.inventory.len(), save.inventory.len());
+        assert_eq!(loaded.constructions.len(), save.constructions.len());
+        assert_eq!(loaded.kind, save.kind);
+        assert_eq!(loaded.design, save.design);
+        assert_eq!(loaded.machines, save.machines);
+        assert_eq!(loaded.soil_plots.len(), 1);
+        assert_eq!(loaded.soil_plots[0].soil, save.soil_plots[0].soil);
+        assert_eq!(loaded.soil_plots[0].previous_crop.as_deref(), Some("bean"));
+        assert_eq!(loaded.cultivars.len(), 1);
+        assert_eq!(loaded.cultivars[0].name, "Sunburst");
+        assert_eq!(loaded.animals.len(), 1);
+        assert!(loaded.animals[0].husbandry.female);
+        assert_eq!(loaded.animals[0].age.life_stage, "adult");
+        assert_eq!(loaded.markets.len(), 1);
+        assert_eq!(loaded.markets[0].stock.get("tomato_0"), Some(&31.5));
+        assert_eq!(loaded.markets[0].history["tomato_0"], vec![9.1, 8.4]);
+        assert_eq!(loaded.fleet[0].cargo, vec![("iron_ore_0".to_string(), 90)]);
+        assert_eq!(loaded.shipments[0].destination, "home");
+        assert_eq!(loaded.shipments[0].order.as_deref(), Some("home_ore"));
+
+        // Clean up
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[test]
+    fn legacy_save_without_kind_design_defaults() {
+        // A pre-v0.380 save has no kind/design fields; serde defaults must fill them
+        // (kind=offline, design=fibonacci) so old saves load unchanged.
+        let dir = std::env::temp_dir().join("humanity_test_legacy_save");
+        let _ = std::fs::create_dir_all(&dir);
+        let path = dir.join("legacy.json");
+        let legacy = r#"{"name":"Old","timestamp":1,"game_time":0.0,"player_position":[0.0,0.0,0.0],"player_rotation":[0.0,0.0,0.0,1.0],"player_health":100.0,"inventory":[],"skills":{},"constructions":[],"weather_state":"clear"}"#;
+        std::fs::write(&path, legacy).unwrap();
+        let loaded = load_world(&path).expect("legacy load should succeed");
+        assert_eq!(loaded.kind, "offline");
+        assert_eq!(loaded.design, "fibonacci");
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_dir(&dir);
+    }
+
+    #[test]
+    fn migrating_load_backs_up_the_original_first() {
+        let dir = std::env::temp_dir().join("humanity_test_migrate_backup");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("offline_home.json");
+        let v0 = include_str!("../../tests/fixtures/saves/v0_homestead.json");
+        std::fs::write(&path, v0).unwrap();
+
+        let loaded = load_world(&path).expect("v0 save should migrate");
+        assert_eq!(loaded.save_version, SAVE_VERSION);
+        assert_eq!(loaded.crops.len(), 2);
+        let backup = backup_path(&path, 0);
+        assert_eq!(backup, dir.join("offline_home.json.v0.bak"));
+        assert_ne!(backup, backup_path(&dir.join("offline_home.hsav"), 0), "each format keeps its own");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), v0, "backup is the untouched file");
+        // Backups are not .json, so they never show up as saves.
+        assert_eq!(list_saves(&dir).len(), 1);
+
+        // Saving writes the current version; the next load needs no backup.
+        save_world(&path, &loaded).unwrap();
+        std::fs::remove_file(&backup).unwrap();
+        load_world(&path).unwrap();
+        assert!(!backup.exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn listing_read_migrates_without_a_backup() {
+        let dir = std::env::temp_dir().join("humanity_test_read_world");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("offline_home.json");
+        let v0 = include_str!("../../tests/fixtures/saves/v0_homestead.json");
+        std::fs::write(&path, v0).unwrap();
+
+        let listed = read_world(&path).expect("v0 save should migrate in memory");
+        assert_eq!(listed.save_version, SAVE_VERSION);
+        assert!(!backup_path(&path, 0).exists(), "a listing writes no backup");
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), v0, "the file is untouched");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn binary_save_round_trips_and_is_auto_detected() {
+        let dir = std::env::temp_dir().join("humanity_test_binary_save");
+        let _ = std::fs::remove_dir_all(&dir);
+        let mut save = test_save();
+        save.crops = (0..200)
+            .map(|i| crate::ecs::components::CropInstance {
+                crop_def_id: "tomato".to_string(),
+                growth_stage: "flowering".to_string(),
+                planted_at: i as f64,
+                water_level: 0.5,
+                health: 1.0,
+                tower_id: Some("tower_a".to_string()),
+                tower_slot: Some(i),
+                genome: None,
+                cultivar: None,
+            })
+            .collect();
+
+        let bin = dir.join("home.hsav");
+        let json = dir.join("home.json");
+        save_world(&bin, &save).unwrap();
+        save_world(&json, &save).unwrap();
+        let bin_len = std::fs::metadata(&bin).unwrap().len();
+        let json_len = std::fs::metadata(&json).unwrap().len();
+        assert!(bin_len * 4 < json_len, "binary {bin_len} B vs json {json_len} B");
+
+        // Detection is by content: a binary save under a .json name still loads.
+        let renamed = dir.join("misnamed.json");
+        std::fs::copy(&bin, &renamed).unwrap();
+        for path in [&bin, &json, &renamed] {
+            let loaded = load_world(path).unwrap();
+            assert_eq!(loaded.crops.len(), 200);
+            assert_eq!(loaded.crops[199].tower_slot, Some(199));
+            assert_eq!(loaded.skills, save.skills);
+        }
+        assert_eq!(list_saves(&dir).len(), 3);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn corrupt_binary_save_is_refused_not_half_loaded() {
+        let dir = std::env::temp_dir().join("humanity_test_corrupt_save");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("home.hsav");
+        save_world(&path, &test_save()).unwrap();
+        let mut bytes = std::fs::read(&path).unwrap();
+        let mid = bytes.len() / 2;
+        bytes[mid] ^= 0xff;
+        std::fs::write(&path, bytes).unwrap();
+
+        let err = load_world(&path).unwrap_err();
+        assert!(err.contains("checksum"), "{err}");
+        assert!(list_saves(&dir).is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn save_replaces_atomically_and_leaves_no_temp_file() {
+        let dir = std::env::temp_dir().join("humanity_test_atomic_save");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("home.json");
+        let mut save = test_save();
+        save_world(&path, &save).unwrap();
+        save.name = "Renamed".to_string();
+        save_world(&path, &save).unwrap();
+
+        assert_eq!(load_world(&path).unwrap().name, "Renamed");
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().filter_map(|e| e.ok()).map(|e| e.file_name()).collect();
+        assert_eq!(files, vec![std::ffi::OsString::from("home.json")]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn auto_save_rolls_through_its_slots() {
+        let dir = std::env::temp_dir().join("humanity_test_auto_save_slots");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut save = test_save();
+        for n in 0..AUTO_SAVE_SLOTS {
+            save.timestamp = n as u64;
+            auto_save(&dir, &save, SaveFormat::Json);
+            assert!(dir.join(format!("auto_save_{n}.json")).exists());
+            // Keep modification times strictly ordered on coarse clocks.
+            std::thread::sleep(std::time::Duration::from_millis(20));
+        }
+
+        // All slots full: the oldest (slot 0) is reused, here as binary, and
+        // its JSON twin goes so the slot holds one save.
+        save.timestamp = 99;
+        auto_save(&dir, &save, SaveFormat::Binary);
+        assert!(dir.join("auto_save_0.hsav").exists());
+        assert!(!dir.join("auto_save_0.json").exists());
+        let saves = list_saves(&dir);
+        assert_eq!(saves.len(), AUTO_SAVE_SLOTS);
+        assert_eq!(saves[0].1, 99);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn list_saves_works() {
+        let dir = std::env::temp_dir().join("humanity_test_list_saves");
+        let _ = std::fs::create_dir_all(&dir);
+
+        let mut s1 = test_save();
+        s1.name = "Save One".to_string();
+        s1.timestamp = 1000;
+        save_world(&dir.join("save1.json"), &s1).unwrap();
+
+        let mut s2 = test_save();
+        s2.name = "Save Two".to_string();
+        s2.timestamp = 2000;
+        save_world(&dir.join("save2.json"), &s2).unwrap();
+
+        let saves = list_saves(&dir);
+        assert_eq!(saves.len(), 2);
+        // Most recent first
+        assert_eq!(saves[0].0, "Save Two");
+        assert_eq!(saves[1].0, "Save One");
+
+        // Clean up
+        let _ = std::fs::remove_file(dir.join("save1.json"));
+        let _ = std::fs::remove_file(dir.join("save2.json"));
+        let _ = std::fs::remove_dir(&dir);
+    }
+}
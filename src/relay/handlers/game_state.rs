@@ -706,37 +706,12 @@ impl GameWorld {
             .unwrap_or(false);
         if !complete { return None; }
 
-        let next = match current_id.as_str() {
-            "explore_ship" => Some(serde_json::json!({
-                "id": "meet_the_crew",
-                "title": "Meet the crew",
-                "description": "Talk to every named crew member to learn who's aboard.",
-                "talked_to": [],
-                "total_npcs": total_npcs,
-                "complete": false,
-                "reward": {
-                    "xp": 200,
-                    "reputation": 10,
-                    "message": "You know the crew now. They know you.",
-                },
-            })),
-            "meet_the_crew" => Some(serde_json::json!({
-                "id": "survey_storage",
-                "title": "Survey the storage",
-                "description": "Inspect every storage container aboard the ship — lockers, cabinets, and bins.",
-                "scanned": [],
-                "total": total_storage,
-                "complete": false,
-                "reward": {
-                    "xp": 300,
-                    "reputation": 15,
-                    "message": "Inventory's tracked. Chief Tan would approve.",
-                },
-            })),
-            // survey_storage is the current end of the starter chain. Future
-            // quests can be added here without touching the handlers.
-            _ => None,
-        };
+        // The chain's order lives in the quest graph: the next quest is the
+        // first successor of the one just completed.
+        let next = starter_quests()
+            .successors(&current_id)
+            .first()
+            .and_then(|def| starter_quest_payload(def, total_npcs, total_storage));
         if let Some(ref q) = next {
             entity.components["current_quest"] = q.clone();
         }
@@ -1497,6 +1472,90 @@ impl GameWorld {
     }
 }
 
+/// The relay's starter chain as a quest graph (explore_ship → meet_the_crew
+/// → survey_storage). Their goals are relay-side counters, so the defs carry
+/// no steps; only the prerequisite edges and the journal copy are used.
+fn starter_quests() -> &'static crate::systems::quests::QuestRegistry {
+    static GRAPH: std::sync::OnceLock<crate::systems::quests::QuestRegistry> = std::sync::OnceLock::new();
+    GRAPH.get_or_init(|| {
+        let def = |id: &str, name: &str, description: &str, prerequisite: Option<&str>| {
+            crate::systems::quests::QuestDef {
+                id: id.to_string(),
+                name: name.to_string(),
+                description: description.to_string(),
+                steps: Vec::new(),
+                rewards: Vec::new(),
+                xp_rewards: Vec::new(),
+                prerequisite: prerequisite.map(String::from),
+                requires: Vec::new(),
+                branches: Vec::new(),
+                choice_npc: None,
+                repeat: Default::default(),
+                standing_rewards: Vec::new(),
+            }
+        };
+        let quests = [
+            def(
+                "explore_ship",
+                "Find your bearings",
+                "Visit each room aboard the Pioneer to learn the ship's layout.",
+                None,
+            ),
+            def(
+                "meet_the_crew",
+                "Meet the crew",
+                "Talk to every named crew member to learn who's aboard.",
+                Some("explore_ship"),
+            ),
+            def(
+                "survey_storage",
+                "Survey the storage",
+                "Inspect every storage container aboard the ship — lockers, cabinets, and bins.",
+                Some("meet_the_crew"),
+            ),
+        ];
+        crate::systems::quests::QuestRegistry {
+            quests: quests.into_iter().map(|q| (q.id.clone(), q)).collect(),
+        }
+    })
+}
+
+/// The `current_quest` payload for a starter-chain quest: the def's journal
+/// copy plus the relay-side goal counter and reward. None for a quest the
+/// relay has no goal for.
+fn starter_quest_payload(
+    def: &crate::systems::quests::QuestDef,
+    total_npcs: usize,
+    total_storage: usize,
+) -> Option<serde_json::Value> {
+    let mut payload = match def.id.as_str() {
+        "meet_the_crew" => serde_json::json!({
+            "talked_to": [],
+            "total_npcs": total_npcs,
+            "reward": {
+                "xp": 200,
+                "reputation": 10,
+                "message": "You know the crew now. They know you.",
+            },
+        }),
+        "survey_storage" => serde_json::json!({
+            "scanned": [],
+            "total": total_storage,
+            "reward": {
+                "xp": 300,
+                "reputation": 15,
+                "message": "Inventory's tracked. Chief Tan would approve.",
+            },
+        }),
+        _ => return None,
+    };
+    payload["id"] = serde_json::json!(def.id);
+    payload["title"] = serde_json::json!(def.name);
+    payload["description"] = serde_json::json!(def.description);
+    payload["complete"] = serde_json::json!(false);
+    Some(payload)
+}
+
 /// Convert a RoomType enum into the snake_case string used by room_equipment().
 fn room_type_to_str(rt: &crate::ship::layout::RoomType) -> &'static str {
     use crate::ship::layout::RoomType::*;
@@ -1731,6 +1790,30 @@ mod tests {
         assert_eq!(rep2, 15, "reputation restored");
     }
 
+    /// chain_next_quest follows the starter quest graph's prerequisite
+    /// edges: explore_ship → meet_the_crew → survey_storage, then ends.
+    #[test]
+    fn chain_next_quest_walks_the_starter_graph() {
+        let mut world = GameWorld::new();
+        let player_id = world.spawn_player("pk_chain", [0.0, 1.0, 0.0]);
+        assert!(world.chain_next_quest(player_id).is_none(), "incomplete quests don't chain");
+
+        let mut walked = Vec::new();
+        for _ in 0..4 {
+            world.entities.get_mut(&player_id).unwrap().components["current_quest"]["complete"] =
+                serde_json::Value::Bool(true);
+            match world.chain_next_quest(player_id) {
+                Some(q) => {
+                    assert_eq!(q["complete"], serde_json::Value::Bool(false));
+                    walked.push(q["id"].as_str().unwrap().to_string());
+                }
+                None => break,
+            }
+        }
+        assert_eq!(walked, ["meet_the_crew", "survey_storage"]);
+        assert_eq!(starter_quests().successors("explore_ship")[0].id, "meet_the_crew");
+    }
+
     // ── Crew chore AI (v0.663) ──
 
     /// The rotation is deterministic: one slot forward per completed chore,
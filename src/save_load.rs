@@ -13,21 +13,31 @@
 //! playing round-trips the save instead of overwriting it with an empty inventory.
 //!
 //! Deferred (need new WorldSave fields or extra care -- see docs/design/
-//! homes-as-profiles.md): health, position, game_time (TimeSystem owns its own
-//! clock), vitals, crops, quests. So on reload you wake rested at home with your
-//! inventory + skills intact.
+//! homes-as-profiles.md): health, position, vitals. So on reload you wake rested
+//! at home with your inventory + skills intact.
+//!
+//! The game clock and the home machines' battery charge + tank fill are saved
+//! too (save v2), and `catch_up_active_home` advances the home by the time the
+//! app was closed (`systems::catch_up`).
 
 use crate::ecs::components::Controllable;
-use crate::persistence::{self, WorldSave};
+use crate::hot_reload::data_store::DataStore;
+use crate::persistence::{self, MachineSave, SaveFormat, WorldSave};
+use crate::systems::catch_up::{self, AwayReport, CatchUpConfig};
+use crate::systems::economy::market::VendorMarkets;
+use crate::systems::time::GameTime;
 use crate::systems::inventory::Inventory;
 use crate::systems::skills::{PlayerSkills, SkillProgress};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Wall-clock seconds at the last periodic save (0 = not armed yet). A process
 /// singleton, fine for a single-instance desktop app.
 static LAST_SAVE_SECS: AtomicU64 = AtomicU64::new(0);
 
+/// Wall-clock seconds at the last rolling autosave (0 = not armed yet).
+static LAST_AUTO_SAVE_SECS: AtomicU64 = AtomicU64::new(0);
+
 fn now_secs() -> u64 {
     std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
@@ -37,8 +47,37 @@ fn now_secs() -> u64 {
 
 /// The active offline home's save file. Progressive disclosure: one home for now
 /// (the homes-as-profiles model). Multi-home selection comes with multiplayer.
+/// JSON or binary, whichever was written last (the JSON path on a first run).
 pub fn active_home_path() -> PathBuf {
-    persistence::saves_dir().join("offline_home.json")
+    active_home_in(&persistence::saves_dir())
+}
+
+/// `offline_home.<ext>` in `dir` for `format`.
+fn home_path_in(dir: &Path, format: SaveFormat) -> PathBuf {
+    dir.join(format!("offline_home.{}", format.extension()))
+}
+
+/// The newest of the home's JSON and binary files in `dir`.
+fn active_home_in(dir: &Path) -> PathBuf {
+    [SaveFormat::Json, SaveFormat::Binary]
+        .into_iter()
+        .map(|f| home_path_in(dir, f))
+        .filter_map(|p| std::fs::metadata(&p).and_then(|m| m.modified()).ok().map(|t| (t, p)))
+        .max_by_key(|(t, _)| *t)
+        .map_or_else(|| home_path_in(dir, SaveFormat::Json), |(_, p)| p)
+}
+
+/// Write the home into `dir` in `format`, removing its twin in the other
+/// format so a stale copy is never picked over the fresh one.
+pub fn write_active_home(dir: &Path, save: &WorldSave, format: SaveFormat) -> Result<PathBuf, String> {
+    let path = home_path_in(dir, format);
+    persistence::save_world_as(&path, save, format)?;
+    for other in [SaveFormat::Json, SaveFormat::Binary] {
+        if other != format {
+            let _ = std::fs::remove_file(home_path_in(dir, other));
+        }
+    }
+    Ok(path)
 }
 
 /// Load the active offline home's save, if it exists + parses. None on first run.
@@ -95,12 +134,14 @@ pub fn extract_world_save(world: &hecs::World) -> WorldSave {
         .query::<(
             &crate::ecs::components::Vehicle,
             &crate::ecs::components::Transform,
+            Option<&crate::ecs::components::VehicleDynamics>,
         )>()
         .iter()
-        .map(|(_e, (v, t))| crate::persistence::VehicleSave {
+        .map(|(_e, (v, t, d))| crate::persistence::VehicleSave {
             item_id: v.item_id.clone(),
             position: t.position.to_array(),
             yaw: t.rotation.to_euler(glam::EulerRot::YXZ).0,
+            fuel_l: d.map(|d| d.fuel_l),
         })
         .collect();
     // Wallet (v0.747, ladder rung 3): credits survive restarts.
@@ -121,18 +162,157 @@ pub fn extract_world_save(world: &hecs::World) -> WorldSave {
         break;
     }
     // Crops (v0.863): the whole garden round-trips. planted_at is game-time
-    // seconds and game_time is saved above, so growth resumes exactly where
-    // it left off.
+    // seconds and save_active_home stamps game_time, so growth resumes exactly
+    // where it left off.
     save.crops = world
         .query::<&crate::ecs::components::CropInstance>()
         .iter()
         .map(|(_e, c)| c.clone())
         .collect();
+    save.machines = machine_states(world);
+    // Soil plots (v3): depleted / amended chemistry and rotation history.
+    save.soil_plots = world
+        .query::<&crate::systems::farming::soil::SoilPlot>()
+        .iter()
+        .map(|(_e, p)| p.clone())
+        .collect();
+    // Cultivars (v4): bred seed lots, so their seed items keep sowing.
+    save.cultivars = world
+        .query::<&crate::systems::farming::breeding::Cultivar>()
+        .iter()
+        .map(|(_e, c)| c.clone())
+        .collect();
+    // The herd (v5): living husbandry animals, where they stand and graze.
+    save.animals = world
+        .query::<(
+            &crate::ecs::components::Creature,
+            &crate::ecs::components::Husbandry,
+            &crate::ecs::components::Age,
+            &crate::ecs::components::Transform,
+            &crate::ecs::components::Name,
+            &crate::ecs::components::Health,
+            Option<&crate::ecs::components::Harvestable>,
+            Option<&crate::ecs::components::LootTable>,
+        )>()
+        .without::<&crate::ecs::components::Dead>()
+        .iter()
+        .map(|(_e, (c, hb, age, tf, name, hp, h, loot))| crate::persistence::AnimalSave {
+            name: name.0.clone(),
+            creature: c.clone(),
+            position: tf.position.to_array(),
+            husbandry: hb.clone(),
+            age: age.clone(),
+            health: hp.clone(),
+            harvestable: h.cloned(),
+            loot: loot.map(|l| l.entries.clone()).unwrap_or_default(),
+        })
+        .collect();
+    // Fleet logistics (v7): ship and depot holds, cargo in transit, standing orders.
+    save.fleet = world
+        .query::<(&crate::ecs::components::LogisticsNode, &Inventory)>()
+        .iter()
+        .map(|(_e, (node, inv))| crate::persistence::FleetNodeSave {
+            node: node.clone(),
+            cargo: inv.slots.iter().flatten().map(|s| (s.item_id.clone(), s.quantity)).collect(),
+        })
+        .collect();
+    save.shipments = world
+        .query::<&crate::ecs::components::CargoVehicle>()
+        .iter()
+        .map(|(_e, v)| v.clone())
+        .collect();
+    save.standing_orders = world
+        .query::<&crate::ecs::components::StandingOrder>()
+        .iter()
+        .map(|(_e, o)| o.clone())
+        .collect();
     save
 }
 
-/// Apply a loaded WorldSave's inventory + skills + vehicles + crops onto the
-/// live world. Health/position/vitals are left fresh -- not yet persisted.
+/// Battery charge + tank fill of every home machine, by instance id.
+pub fn machine_states(world: &hecs::World) -> Vec<MachineSave> {
+    use crate::ecs::components::{Battery, MachineInstanceId, WaterTank};
+    let mut machines: Vec<MachineSave> = world
+        .query::<(&MachineInstanceId, Option<&Battery>, Option<&WaterTank>)>()
+        .iter()
+        .filter(|(_e, (_, b, t))| b.is_some() || t.is_some())
+        .map(|(_e, (id, b, t))| MachineSave {
+            id: id.0.clone(),
+            battery_wh: b.map(|b| b.charge_wh),
+            water_l: t.map(|t| t.liters),
+        })
+        .collect();
+    // Stable order so an unchanged home writes an unchanged save.
+    machines.sort_by(|a, b| a.id.cmp(&b.id));
+    machines
+}
+
+/// Put saved battery charge + tank fill back onto the spawned home machines,
+/// matched by instance id. Machines the save doesn't mention keep their spawn
+/// defaults; values are clamped to today's capacity (the layout can change).
+pub fn apply_machine_state(world: &mut hecs::World, machines: &[MachineSave]) {
+    use crate::ecs::components::{Battery, MachineInstanceId, WaterTank};
+    if machines.is_empty() {
+        return;
+    }
+    for (_e, (id, battery, tank)) in
+        world.query_mut::<(&MachineInstanceId, Option<&mut Battery>, Option<&mut WaterTank>)>()
+    {
+        let Some(saved) = machines.iter().find(|m| m.id == id.0) else {
+            continue;
+        };
+        if let (Some(b), Some(wh)) = (battery, saved.battery_wh) {
+            b.charge_wh = wh.clamp(0.0, b.capacity_wh);
+        }
+        if let (Some(t), Some(l)) = (tank, saved.water_l) {
+            t.liters = l.clamp(0.0, t.capacity_l);
+        }
+    }
+}
+
+/// Boot-time half of the save: restore the saved game clock and machine state
+/// onto the freshly spawned home, then run the offline catch-up for the time
+/// the app was closed. Call once the home machines are spawned and the data
+/// registries are loaded. Returns the "while you were away" report, if the
+/// absence was long enough to simulate.
+pub fn catch_up_active_home(
+    world: &mut hecs::World,
+    data: &DataStore,
+    data_dir: &std::path::Path,
+) -> Option<AwayReport> {
+    let save = load_active_home()?;
+    if save.kind != "offline" {
+        return None;
+    }
+    apply_machine_state(world, &save.machines);
+    if let Some(markets) = data.get::<std::sync::Mutex<VendorMarkets>>("vendor_markets") {
+        if let Ok(mut m) = markets.lock() {
+            m.restore(&save.markets);
+        }
+    }
+    // TimeSystem owns the clock; seed the shared copy for the catch-up and ask
+    // TimeSystem to adopt it on its first tick.
+    if let Some(slot) = data.get::<std::sync::Mutex<GameTime>>("game_time") {
+        if let Ok(mut g) = slot.lock() {
+            g.set_elapsed(save.game_time);
+        }
+    }
+    if let Some(req) = data.get::<std::sync::Mutex<Option<f64>>>("time_set_elapsed_request") {
+        if let Ok(mut r) = req.lock() {
+            *r = Some(save.game_time);
+        }
+    }
+    if save.timestamp == 0 {
+        return None;
+    }
+    let away = now_secs().saturating_sub(save.timestamp) as f64;
+    let config = data.get::<CatchUpConfig>("catch_up_config").cloned().unwrap_or_default();
+    catch_up::run(world, data, data_dir, &config, away)
+}
+
+/// Apply a loaded WorldSave's inventory + skills + vehicles + crops + soil +
+/// cultivars + herd + fleet onto the live world. Health/position/vitals are left fresh -- not
+/// yet persisted.
 /// Idempotent; called at startup and on character select.
 pub fn apply_save_to_world(world: &mut hecs::World, save: &WorldSave) {
     // Only offline homes are supported today.
@@ -220,7 +400,7 @@ pub fn apply_save_to_world(world: &mut hecs::World, save: &WorldSave) {
         // Same tuple VehicleSystem::handle_deploy spawns, minus Name (the display
         // name lives in the kit registry, which this fn deliberately has no access
         // to; nothing reads a vehicle's Name yet — revisit when nameplates land).
-        world.spawn((
+        let e = world.spawn((
             crate::ecs::components::Vehicle { item_id: vs.item_id.clone() },
             crate::ecs::components::Transform {
                 position: glam::Vec3::from_array(vs.position),
@@ -233,6 +413,13 @@ pub fn apply_save_to_world(world: &mut hecs::World, save: &WorldSave) {
                 seat_type: "pilot".to_string(),
             },
         ));
+        // The tank keeps what was left; DrivingSystem fills an unsaved one.
+        if let Some(fuel_l) = vs.fuel_l {
+            let _ = world.insert_one(
+                e,
+                crate::ecs::components::VehicleDynamics { fuel_l, ..Default::default() },
+            );
+        }
     }
     // Crops (v0.863): save is AUTHORITATIVE, same clear-then-rebuild rule as
     // vehicles above (this fn re-applies on character select, not just boot).
@@ -247,16 +434,103 @@ pub fn apply_save_to_world(world: &mut hecs::World, save: &WorldSave) {
     for c in &save.crops {
         world.spawn((c.clone(),));
     }
+    // Soil plots (v3): same clear-then-rebuild rule as the crops they feed.
+    let existing: Vec<hecs::Entity> = world
+        .query_mut::<&crate::systems::farming::soil::SoilPlot>()
+        .into_iter()
+        .map(|(e, _)| e)
+        .collect();
+    for e in existing {
+        let _ = world.despawn(e);
+    }
+    for p in &save.soil_plots {
+        world.spawn((p.clone(),));
+    }
+    // Cultivars (v4): same rule; a seed lot belongs to the save it was bred in.
+    let existing: Vec<hecs::Entity> = world
+        .query_mut::<&crate::systems::farming::breeding::Cultivar>()
+        .into_iter()
+        .map(|(e, _)| e)
+        .collect();
+    for e in existing {
+        let _ = world.despawn(e);
+    }
+    for c in &save.cultivars {
+        world.spawn((c.clone(),));
+    }
+    // The herd (v5): same rule. Wild and dev-spawned creatures are untouched;
+    // load_world only adds the starter herd when none came back.
+    let existing: Vec<hecs::Entity> = world
+        .query_mut::<(
+            &crate::ecs::components::Creature,
+            &crate::ecs::components::Husbandry,
+        )>()
+        .into_iter()
+        .map(|(e, _)| e)
+        .collect();
+    for e in existing {
+        let _ = world.despawn(e);
+    }
+    for a in &save.animals {
+        let e = world.spawn((
+            a.creature.clone(),
+            a.husbandry.clone(),
+            a.age.clone(),
+            crate::ecs::components::Transform {
+                position: glam::Vec3::from_array(a.position),
+                ..Default::default()
+            },
+            crate::ecs::components::Name(a.name.clone()),
+            a.health.clone(),
+            crate::ecs::components::LootTable { entries: a.loot.clone() },
+        ));
+        if let Some(h) = &a.harvestable {
+            let _ = world.insert_one(e, h.clone());
+        }
+    }
+    // Fleet logistics (v7): same rule, but only when the save has a fleet --
+    // an older save keeps the one TransportationSystem seeded from the data.
+    if !save.fleet.is_empty() {
+        let mut existing: Vec<hecs::Entity> = world
+            .query_mut::<&crate::ecs::components::LogisticsNode>()
+            .into_iter()
+            .map(|(e, _)| e)
+            .collect();
+        existing.extend(world.query_mut::<&crate::ecs::components::CargoVehicle>().into_iter().map(|(e, _)| e));
+        existing.extend(world.query_mut::<&crate::ecs::components::StandingOrder>().into_iter().map(|(e, _)| e));
+        for e in existing {
+            let _ = world.despawn(e);
+        }
+        for f in &save.fleet {
+            let mut inv = Inventory::new(8);
+            inv.ensure_slots(f.cargo.len());
+            for (item_id, qty) in &f.cargo {
+                inv.add_item(item_id, *qty, 99);
+            }
+            world.spawn((f.node.clone(), inv));
+        }
+        for v in &save.shipments {
+            world.spawn((v.clone(),));
+        }
+        for o in &save.standing_orders {
+            world.spawn((o.clone(),));
+        }
+    }
 }
 
 /// Extract + write the active offline home to disk. Logs on failure. `placed` is the
 /// organize-layer container pool (GuiState-owned, not in the ECS world), persisted
 /// alongside the world-derived save so container contents + transfers survive a restart.
-pub fn save_active_home(world: &hecs::World, placed: &[crate::gui::PlacedItem]) {
-    let mut save = extract_world_save(world);
-    save.placed_items = placed.to_vec();
-    let path = active_home_path();
-    if let Err(e) = persistence::save_world(&path, &save) {
+/// The game clock is read from the DataStore's `game_time` slot (TimeSystem's export).
+/// `format` is the player's Settings > Gameplay choice (`SettingsState::save_format`).
+pub fn save_active_home(
+    world: &hecs::World,
+    data: &DataStore,
+    placed: &[crate::gui::PlacedItem],
+    format: SaveFormat,
+) {
+    let save = active_home_save(world, data, placed);
+    if let Err(e) = write_active_home(&persistence::saves_dir(), &save, format) {
         log::error!("save_active_home failed: {e}");
     } else {
         log::info!(
@@ -267,25 +541,69 @@ pub fn save_active_home(world: &hecs::World, placed: &[crate::gui::PlacedItem])
     }
 }
 
+/// The live home as a save: the world's state plus the organize-layer pool,
+/// vendor markets and game clock held outside it.
+fn active_home_save(world: &hecs::World, data: &DataStore, placed: &[crate::gui::PlacedItem]) -> WorldSave {
+    let mut save = extract_world_save(world);
+    save.placed_items = placed.to_vec();
+    // Vendor markets (v6) live in the DataStore, not the world.
+    if let Some(markets) = data.get::<std::sync::Mutex<VendorMarkets>>("vendor_markets") {
+        if let Ok(m) = markets.lock() {
+            save.markets = m.to_save();
+        }
+    }
+    if let Some(clock) = data.get::<std::sync::Mutex<GameTime>>("game_time") {
+        if let Ok(g) = clock.lock() {
+            save.game_time = g.elapsed_seconds;
+        }
+    }
+    save
+}
+
+/// Whether `interval_secs` have passed since `last`, restarting the clock
+/// when they have. The first call only arms it (avoids writing an empty home
+/// before any play happens on a fresh first run).
+fn interval_due(last: &AtomicU64, interval_secs: u64) -> bool {
+    let now = now_secs();
+    let prev = last.load(Ordering::Relaxed);
+    if prev == 0 {
+        last.store(now, Ordering::Relaxed);
+        return false;
+    }
+    let due = now.saturating_sub(prev) >= interval_secs;
+    if due {
+        last.store(now, Ordering::Relaxed);
+    }
+    due
+}
+
 /// Save the offline home at most once per `interval_secs` of wall-clock time. Call
 /// every frame from the main loop; it self-throttles. Robust to ANY exit path
 /// (in-app quit, crash, kill) where the graceful close-save would not fire.
 pub fn maybe_periodic_save(
     world: &hecs::World,
+    data: &DataStore,
     placed: &[crate::gui::PlacedItem],
+    format: SaveFormat,
     interval_secs: u64,
 ) {
-    let now = now_secs();
-    let last = LAST_SAVE_SECS.load(Ordering::Relaxed);
-    if last == 0 {
-        // First call: arm the timer; do NOT save immediately (avoids writing an
-        // empty home before any play happens on a fresh first run).
-        LAST_SAVE_SECS.store(now, Ordering::Relaxed);
-        return;
+    if interval_due(&LAST_SAVE_SECS, interval_secs) {
+        save_active_home(world, data, placed, format);
     }
-    if now.saturating_sub(last) >= interval_secs {
-        LAST_SAVE_SECS.store(now, Ordering::Relaxed);
-        save_active_home(world, placed);
+}
+
+/// Write a rolling autosave slot (`persistence::auto_save`) at most once per
+/// `interval_secs`. Self-throttles like `maybe_periodic_save`; the slots are
+/// the fallback if the home file itself is ever lost or corrupted.
+pub fn maybe_auto_save(
+    world: &hecs::World,
+    data: &DataStore,
+    placed: &[crate::gui::PlacedItem],
+    format: SaveFormat,
+    interval_secs: u64,
+) {
+    if interval_due(&LAST_AUTO_SAVE_SECS, interval_secs) {
+        persistence::auto_save(&persistence::saves_dir(), &active_home_save(world, data, placed), format);
     }
 }
 
@@ -446,12 +764,14 @@ mod tests {
             },
             Velocity::default(),
             VehicleSeat { occupant_key: None, seat_type: "pilot".to_string() },
+            crate::ecs::components::VehicleDynamics { fuel_l: 41.5, ..Default::default() },
         ));
 
         let save = extract_world_save(&world);
         assert_eq!(save.deployed_vehicles.len(), 1);
         assert_eq!(save.deployed_vehicles[0].item_id, "truck_pickup_0");
         assert!((save.deployed_vehicles[0].yaw - 1.25).abs() < 1e-4);
+        assert_eq!(save.deployed_vehicles[0].fuel_l, Some(41.5));
 
         // Serde round trip (what actually hits disk), then apply to a FRESH world.
         let json = serde_json::to_string(&save).expect("serialize");
@@ -474,6 +794,12 @@ mod tests {
         assert_eq!(vehicles.len(), 1, "the parked truck came back");
         assert_eq!(vehicles[0].0, "truck_pickup_0");
         assert!((vehicles[0].1 - glam::Vec3::new(12.0, 0.0, -7.5)).length() < 1e-4);
+        let fuel = fresh
+            .query_mut::<&crate::ecs::components::VehicleDynamics>()
+            .into_iter()
+            .map(|(_e, d)| d.fuel_l)
+            .next();
+        assert_eq!(fuel, Some(41.5), "the tank kept its fuel");
 
         // Applying the same save again must NOT duplicate the vehicle.
         apply_save_to_world(&mut fresh, &loaded);
@@ -489,6 +815,33 @@ mod tests {
         assert_eq!(n, 0, "vehicles absent from the applied save are despawned");
     }
 
+    /// Battery charge + tank fill round-trip by machine instance id, clamped
+    /// to the respawned machine's capacity, unknown ids ignored.
+    #[test]
+    fn machine_state_round_trips_by_instance_id() {
+        use crate::ecs::components::{Battery, HomeMachine, MachineInstanceId, WaterTank};
+        let spawn_home = |world: &mut hecs::World, charge: f32, liters: f32| {
+            let battery = Battery { charge_wh: charge, capacity_wh: 8000.0, max_charge_w: 2000.0, max_discharge_w: 2000.0 };
+            world.spawn((HomeMachine, MachineInstanceId("battery_1".into()), battery));
+            world.spawn((HomeMachine, MachineInstanceId("cistern_1".into()), WaterTank { liters, capacity_l: 1500.0 }));
+            world.spawn((HomeMachine, MachineInstanceId("grow_light_1".into())));
+        };
+        let mut world = hecs::World::new();
+        spawn_home(&mut world, 7600.0, 1200.0);
+        let mut saved = machine_states(&world);
+        assert_eq!(saved.len(), 2, "only machines with state are saved: {saved:?}");
+        saved[1].water_l = Some(9000.0);
+        saved.push(MachineSave { id: "removed_machine".into(), battery_wh: Some(1.0), water_l: None });
+
+        let mut fresh = hecs::World::new();
+        spawn_home(&mut fresh, 4000.0, 750.0);
+        apply_machine_state(&mut fresh, &saved);
+        let battery = fresh.query_mut::<&Battery>().into_iter().next().unwrap().1.charge_wh;
+        let water = fresh.query_mut::<&WaterTank>().into_iter().next().unwrap().1.liters;
+        assert_eq!(battery, 7600.0);
+        assert_eq!(water, 1500.0, "clamped to the tank's capacity");
+    }
+
     /// Two vehicles deployed at the IDENTICAL pose (deploy twice without moving)
     /// must both come back after a restart. The v0.677 same-pose skip guard
     /// collapsed them to one — two kits paid, one truck restored (review fix).
@@ -635,4 +988,130 @@ mod tests {
         let old: WorldSave = serde_json::from_str(old_json).expect("old save loads");
         assert!(old.placed_items.is_empty(), "old save defaults to an empty pool");
     }
+
+    /// The herd round-trips: a tamed animal keeps its husbandry state, age and
+    /// pasture, re-applying rebuilds instead of duplicating, and wild
+    /// creatures are not the save's to touch.
+    #[test]
+    fn herd_round_trips_and_reapplies_without_duplicates() {
+        use crate::ecs::components::{Age, Creature, Health, Husbandry, Name, Transform};
+        let creature = |id: &str| Creature {
+            def_id: id.to_string(),
+            anchor: glam::Vec3::new(27.0, 0.0, 65.0),
+            range: 3.0,
+            phase: 0.0,
+            speed: 0.8,
+            tint: [1.0; 3],
+            body_side: 0.4,
+        };
+        let mut world = hecs::World::new();
+        world.spawn((
+            creature("horse"),
+            Husbandry { hunger: 0.25, mood: 0.7, female: false, genome: None, breed_cooldown: 30.0 },
+            Age { years: 0.3, life_stage: "adult".to_string() },
+            Transform { position: glam::Vec3::new(28.0, 0.0, 66.0), ..Default::default() },
+            Name("Horse".to_string()),
+            Health { current: 90.0, max: 120.0 },
+        ));
+        world.spawn((creature("berry_bush"), Transform::default()));
+
+        let save = extract_world_save(&world);
+        assert_eq!(save.animals.len(), 1, "only the herd is saved");
+        apply_save_to_world(&mut world, &save);
+        apply_save_to_world(&mut world, &save);
+
+        let herd: Vec<(Husbandry, String, f32)> = world
+            .query_mut::<(&Husbandry, &Age, &Transform)>()
+            .into_iter()
+            .map(|(_e, (hb, age, tf))| (hb.clone(), age.life_stage.clone(), tf.position.x))
+            .collect();
+        assert_eq!(herd.len(), 1);
+        assert_eq!(herd[0].0.hunger, 0.25);
+        assert_eq!(herd[0].1, "adult");
+        assert_eq!(herd[0].2, 28.0);
+        assert_eq!(world.query_mut::<&Creature>().into_iter().count(), 2, "the bush stays");
+    }
+
+    /// The fleet round-trips: holds, cargo in transit and standing orders come
+    /// back once (re-applying doesn't duplicate), and a save without a fleet
+    /// leaves the seeded one alone.
+    #[test]
+    fn fleet_round_trips_and_an_old_save_keeps_the_seeded_fleet() {
+        use crate::ecs::components::{CargoVehicle, LogisticsNode, StandingOrder};
+        let mut world = hecs::World::new();
+        let mut hold = Inventory::new(4);
+        hold.add_item("iron_ore_0", 150, 99);
+        world.spawn((
+            LogisticsNode { id: "mule".into(), name: "Mule".into(), kind: "ship".into(), capacity_kg: 12000.0 },
+            hold,
+        ));
+        world.spawn((CargoVehicle {
+            route_id: "mule-home".into(),
+            progress: 0.5,
+            speed_per_day: 2.0,
+            payload: vec![("coal_0".into(), 5)],
+            arrived: false,
+            origin: "mule".into(),
+            destination: "home".into(),
+            order: None,
+        },));
+        world.spawn((StandingOrder {
+            id: "ore".into(),
+            from: "mule".into(),
+            to: "home".into(),
+            item: "iron_ore_0".into(),
+            keep: 10,
+            lot: 10,
+            every_days: 1.0,
+            since_days: 0.5,
+            paused: true,
+        },));
+
+        let save = extract_world_save(&world);
+        assert_eq!(save.fleet.len(), 1);
+        apply_save_to_world(&mut world, &save);
+        apply_save_to_world(&mut world, &save);
+        let holds: Vec<u32> = world
+            .query_mut::<(&LogisticsNode, &Inventory)>()
+            .into_iter()
+            .map(|(_e, (_n, inv))| inv.count_item("iron_ore_0"))
+            .collect();
+        assert_eq!(holds, vec![150]);
+        assert_eq!(world.query_mut::<&CargoVehicle>().into_iter().count(), 1);
+        let orders: Vec<StandingOrder> = world.query_mut::<&StandingOrder>().into_iter().map(|(_e, o)| o.clone()).collect();
+        assert_eq!(orders.len(), 1);
+        assert!(orders[0].paused);
+
+        apply_save_to_world(&mut world, &WorldSave::new_offline("Old", "fibonacci"));
+        assert_eq!(world.query_mut::<&LogisticsNode>().into_iter().count(), 1, "no fleet saved: keep ours");
+    }
+
+    /// The live save path honours the Settings format: a binary home lands
+    /// as `offline_home.hsav` (replacing the JSON twin, and picked as the
+    /// active home), and the rolling autosave writes a binary slot beside it.
+    #[test]
+    fn live_save_path_writes_the_chosen_format() {
+        let dir = std::env::temp_dir().join("humanity_test_live_save_format");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut world = hecs::World::new();
+        let mut inv = Inventory::new(36);
+        inv.add_item("wood_plank_0", 12, 99);
+        world.spawn((inv, PlayerSkills::new(), Controllable));
+        let save = active_home_save(&world, &DataStore::new(), &[]);
+
+        write_active_home(&dir, &save, SaveFormat::Json).unwrap();
+        let path = write_active_home(&dir, &save, SaveFormat::Binary).unwrap();
+        assert_eq!(path, dir.join("offline_home.hsav"));
+        assert!(persistence::binary::is_binary(&std::fs::read(&path).unwrap()));
+        assert!(!dir.join("offline_home.json").exists(), "the JSON twin is replaced");
+        assert_eq!(active_home_in(&dir), path);
+        let loaded = persistence::load_world(&path).unwrap();
+        assert_eq!(loaded.inventory, save.inventory);
+
+        persistence::auto_save(&dir, &save, SaveFormat::Binary);
+        assert!(dir.join("auto_save_0.hsav").exists(), "autosave slot written in binary");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -224,6 +224,22 @@ impl InteriorWall {
     pub fn total_thickness(&self) -> f32 {
         self.resolved_thickness() + self.layers.iter().map(|l| l.thickness_m.max(0.0)).sum::<f32>()
     }
+
+    /// Conductive R-value (m²·K/W) through the whole stack -- the structural wall plus every surface
+    /// layer, in series. Surface air films are the thermal network's business, not the wall's. An
+    /// unknown material id conducts like the legacy default (k = 1).
+    pub fn r_value(&self) -> f32 {
+        let r = |id: u32, t: f32| wall_material(id).map_or(t.max(0.0), |m| m.r_value(t));
+        r(self.material, self.resolved_thickness())
+            + self.layers.iter().map(|l| r(l.material, l.thickness_m)).sum::<f32>()
+    }
+
+    /// Thermal mass (J/m²·K) of the whole stack, per square metre of wall face.
+    pub fn heat_capacity_per_m2(&self) -> f32 {
+        let c = |id: u32, t: f32| wall_material(id).map_or(0.0, |m| m.heat_capacity_per_m2(t));
+        c(self.material, self.resolved_thickness())
+            + self.layers.iter().map(|l| c(l.material, l.thickness_m)).sum::<f32>()
+    }
 }
 
 /// A home (or any structure): a FIXED outer box + freely-placed interior walls.
@@ -423,9 +439,37 @@ pub struct WallMaterial {
     /// Default wall thickness in metres when a wall does not override it (sheet metals are thin,
     /// framed/poured materials are thick). Real-ish figures, so thickness teaches too. (v0.556)
     pub default_thickness_m: f32,
+    /// Thermal conductivity k (W/m·K). A slab's R-value is its thickness over k, so a steel sheet
+    /// barely slows heat while a wool batt holds it in. Drives the home's thermal network.
+    #[serde(default = "default_conductivity")]
+    pub conductivity_w_mk: f32,
+    /// Specific heat (J/kg·K). With density and thickness, the heat a wall or floor soaks up per
+    /// degree -- the thermal mass that carries a sunny afternoon into the night.
+    #[serde(default = "default_specific_heat")]
+    pub specific_heat_j_kgk: f32,
     pub note: String,
 }
 
+fn default_conductivity() -> f32 {
+    1.0
+}
+
+fn default_specific_heat() -> f32 {
+    900.0
+}
+
+impl WallMaterial {
+    /// Thermal resistance (m²·K/W) of a slab of this material `thickness_m` thick.
+    pub fn r_value(&self, thickness_m: f32) -> f32 {
+        thickness_m.max(0.0) / self.conductivity_w_mk.max(1e-4)
+    }
+
+    /// Heat stored per square metre per kelvin (J/m²·K) by a slab `thickness_m` thick.
+    pub fn heat_capacity_per_m2(&self, thickness_m: f32) -> f32 {
+        self.density_kg_m3 * self.specific_heat_j_kgk * thickness_m.max(0.0)
+    }
+}
+
 /// The wall-material registry, parsed once. Embedded at compile time so both the editor and the
 /// renderer read the same list with no runtime path dependency. Edit the .ron + rebuild to change it.
 pub fn wall_materials() -> &'static [WallMaterial] {
@@ -448,6 +492,20 @@ pub fn wall_material(id: u32) -> Option<&'static WallMaterial> {
 }
 
 impl HomeStructure {
+    /// Remove placed structure `i`, re-pointing teleporter/train pairs across the removed index (a
+    /// partner of the removed piece is unpaired). No-op past the end.
+    pub fn remove_structure(&mut self, i: usize) {
+        if i >= self.structures.len() {
+            return;
+        }
+        self.structures.remove(i);
+        for s in &mut self.structures {
+            if let Some(p) = s.pair {
+                if p == i { s.pair = None; } else if p > i { s.pair = Some(p - 1); }
+            }
+        }
+    }
+
     /// Mint a unique zone id (v0.631), e.g. "zone_3".
     pub fn unique_zone_id(&self) -> String {
         let mut n = self.zones.len();
@@ -600,6 +658,18 @@ impl HomeStructure {
             .unwrap_or_else(|| wall_material(self.shell_material).map_or(WALL_THICKNESS, |m| m.default_thickness_m))
     }
 
+    /// Conductive R-value (m²·K/W) of the outer shell walls.
+    pub fn shell_r_value(&self) -> f32 {
+        let t = self.shell_resolved_thickness();
+        wall_material(self.shell_material).map_or(t, |m| m.r_value(t))
+    }
+
+    /// Conductive R-value (m²·K/W) of the roof, at its material's default thickness (the roof has no
+    /// thickness of its own).
+    pub fn roof_r_value(&self) -> f32 {
+        wall_material(self.roof_material).map_or(WALL_THICKNESS, |m| m.r_value(m.default_thickness_m))
+    }
+
     /// Generate the renderable meshes: the fixed box shell (one floor + 4 outer walls + ceiling)
     /// plus each interior wall segment, in the existing `HomesteadMeshes` form so the renderer path
     /// is a drop-in. One room ("home") for now -- room subdivision by interior walls is a later stage.
@@ -1310,23 +1380,38 @@ impl HomeStructure {
     /// room (unchanged); each wall that fully partitions the space splits off another room. Robust to
     /// arbitrary, non-rectangular, L-shaped regions (grid flood-fill, not planar-graph faces).
     pub fn detect_rooms(&self) -> Vec<RoomInfo> {
+        self.room_map().rooms
+    }
+
+    /// The flood-fill behind `detect_rooms`, keeping the cell grid: which room each 0.5 m
+    /// floor cell belongs to, so a point can be placed in a room and a room's true floor area counted
+    /// (an L-shaped room's AABB overstates it). The empty-box and degenerate fallbacks label every
+    /// cell as the single "home" room.
+    pub fn room_map(&self) -> RoomMap {
         let w = self.width.max(1.0);
         let d = self.depth.max(1.0);
         let h = self.height.max(1.0);
-        // No interior walls -> the whole box is one room (keeps the empty-box behavior identical).
-        if self.walls.is_empty() {
-            return vec![RoomInfo {
+        const CELL: f32 = ROOM_CELL;
+        let nx = (w / CELL).ceil() as usize;
+        let nz = (d / CELL).ceil() as usize;
+        let whole_box = || RoomMap {
+            rooms: vec![RoomInfo {
                 id: "home".to_string(),
                 center: Vec3::new(w * 0.5, h * 0.5, d * 0.5),
                 dimensions: Vec3::new(w, h, d),
                 is_hologram_room: false,
                 is_spawn_room: true,
-            }];
+            }],
+            nx,
+            nz,
+            labels: vec![Some(0); nx * nz],
+            cells: vec![nx * nz],
+        };
+        // No interior walls -> the whole box is one room (keeps the empty-box behavior identical).
+        if self.walls.is_empty() {
+            return whole_box();
         }
 
-        const CELL: f32 = 0.5;
-        let nx = (w / CELL).ceil() as usize;
-        let nz = (d / CELL).ceil() as usize;
         // Block every cell within a wall's half-thickness of any interior wall segment. The grid is
         // coarse (0.5 m), so even a 1 mm wall still blocks (the CELL term dominates) and rooms stay
         // separated -- per-wall thickness only widens fat walls. (v0.556)
@@ -1347,7 +1432,9 @@ impl HomeStructure {
 
         // Flood-fill the open cells into connected components; each is a room.
         let mut comp = vec![usize::MAX; nx * nz];
+        let mut comp_room: Vec<Option<usize>> = Vec::new();
         let mut rooms: Vec<RoomInfo> = Vec::new();
+        let mut cells: Vec<usize> = Vec::new();
         let mut stack: Vec<(usize, usize)> = Vec::new();
         let mut next = 0usize;
         for sz in 0..nz {
@@ -1383,6 +1470,8 @@ impl HomeStructure {
                 if count >= 4 {
                     let (x0, x1) = (minx as f32 * CELL, (maxx + 1) as f32 * CELL);
                     let (z0, z1) = (minz as f32 * CELL, (maxz + 1) as f32 * CELL);
+                    comp_room.push(Some(rooms.len()));
+                    cells.push(count);
                     rooms.push(RoomInfo {
                         id: format!("room_{}", rooms.len() + 1),
                         center: Vec3::new((x0 + x1) * 0.5, h * 0.5, (z0 + z1) * 0.5),
@@ -1390,19 +1479,15 @@ impl HomeStructure {
                         is_hologram_room: false,
                         is_spawn_room: false,
                     });
+                } else {
+                    comp_room.push(None);
                 }
             }
         }
 
         if rooms.is_empty() {
             // Fully walled-in / degenerate -> fall back to the whole box as one room.
-            return vec![RoomInfo {
-                id: "home".to_string(),
-                center: Vec3::new(w * 0.5, h * 0.5, d * 0.5),
-                dimensions: Vec3::new(w, h, d),
-                is_hologram_room: false,
-                is_spawn_room: true,
-            }];
+            return whole_box();
         }
         // Spawn in the largest room.
         let best = rooms
@@ -1416,7 +1501,42 @@ impl HomeStructure {
             .map(|(i, _)| i)
             .unwrap_or(0);
         rooms[best].is_spawn_room = true;
-        rooms
+        let labels = comp.iter().map(|&c| comp_room.get(c).copied().flatten()).collect();
+        RoomMap { rooms, nx, nz, labels, cells }
+    }
+}
+
+/// Floor-plan grid pitch (metres) of the room flood-fill.
+pub const ROOM_CELL: f32 = 0.5;
+
+/// The rooms `detect_rooms` finds plus the grid they were found on. `labels[cz * nx + cx]`
+/// is the room index of that `ROOM_CELL` floor cell, None where a wall (or a sliver) sits.
+#[derive(Debug, Clone)]
+pub struct RoomMap {
+    pub rooms: Vec<RoomInfo>,
+    pub nx: usize,
+    pub nz: usize,
+    pub labels: Vec<Option<usize>>,
+    /// Open floor cells per room, parallel to `rooms`.
+    pub cells: Vec<usize>,
+}
+
+impl RoomMap {
+    /// The room under box-local point (x, z), or None inside a wall or outside the box.
+    pub fn room_at(&self, x: f32, z: f32) -> Option<usize> {
+        if x < 0.0 || z < 0.0 {
+            return None;
+        }
+        let (cx, cz) = ((x / ROOM_CELL) as usize, (z / ROOM_CELL) as usize);
+        if cx >= self.nx || cz >= self.nz {
+            return None;
+        }
+        self.labels[cz * self.nx + cx]
+    }
+
+    /// Floor area (m²) of room `i`, counted cell by cell.
+    pub fn floor_area(&self, i: usize) -> f32 {
+        self.cells.get(i).copied().unwrap_or(0) as f32 * ROOM_CELL * ROOM_CELL
     }
 }
 
@@ -2074,7 +2194,7 @@ mod tests {
         // Locks the embedded wall_materials.ron against a syntax break (which would otherwise
         // silently degrade every wall to grid-grey). Update the count if you add/remove a material.
         let mats = wall_materials();
-        assert_eq!(mats.len(), 8, "all 8 wall materials parse");
+        assert_eq!(mats.len(), 9, "all 9 wall materials parse");
         let steel = wall_material(1).expect("steel id 1 present");
         assert_eq!(steel.name, "Steel");
         assert_eq!(steel.color.3, 1.0, "steel is opaque");
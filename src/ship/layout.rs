@@ -91,6 +91,21 @@ pub struct ShipDef {
     pub height: f32,
     /// Decks from bottom to top.
     pub decks: Vec<DeckDef>,
+    /// The cargo hold: what the ship can carry and what it starts with. The fleet's
+    /// logistics (`systems::transportation`) load it into the ship's inventory.
+    #[serde(default)]
+    pub hold: ShipHold,
+}
+
+/// A ship's (or settlement depot's) cargo hold as authored in RON.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ShipHold {
+    /// Most cargo the hold takes, by mass.
+    #[serde(default)]
+    pub capacity_kg: f32,
+    /// Starting cargo: (item id, count).
+    #[serde(default)]
+    pub cargo: Vec<(String, u32)>,
 }
 
 /// Runtime wrapper providing queries over a parsed `ShipDef`.
@@ -502,11 +502,24 @@ fn world_to_local_xz(pos: (f32, f32, f32), yaw_rad: f32, px: f32, pz: f32) -> (f
     (dx * c - dz * s, dx * s + dz * c)
 }
 
+/// The piece's LOCAL (x,z) -> world (x,z): the forward yaw `structure_mesh` applies. Lets the framing
+/// pass put a stair's top landing or an elevator's posts where the rendered geometry has them.
+pub fn local_to_world_xz(pos: (f32, f32, f32), yaw_rad: f32, lx: f32, lz: f32) -> (f32, f32) {
+    let (s, c) = yaw_rad.sin_cos();
+    (pos.0 + lx * c + lz * s, pos.2 - lx * s + lz * c)
+}
+
 /// Is world (px,pz) within this piece's footprint (a small tolerance so edges count)? (v0.584)
 pub fn in_footprint(ty: &StructureType, pos: (f32, f32, f32), yaw_rad: f32, px: f32, pz: f32) -> bool {
+    near_footprint(ty, pos, yaw_rad, px, pz, 0.05)
+}
+
+/// `in_footprint` with a caller-chosen margin (metres) around the footprint -- how far a deck edge
+/// may sit from the stair top or wall it rests on and still bear on it.
+pub fn near_footprint(ty: &StructureType, pos: (f32, f32, f32), yaw_rad: f32, px: f32, pz: f32, margin: f32) -> bool {
     let (w, _, d) = ty.size;
     let (lx, lz) = world_to_local_xz(pos, yaw_rad, px, pz);
-    lx.abs() <= w * 0.5 + 0.05 && lz.abs() <= d * 0.5 + 0.05
+    lx.abs() <= w * 0.5 + margin && lz.abs() <= d * 0.5 + margin
 }
 
 /// The WALKABLE-surface world height at (px,pz) for a piece placed at `pos`/`yaw_rad`, or None if
@@ -17,7 +17,7 @@ use glam::Vec3;
 pub const PLAYER_RADIUS: f32 = 0.3;
 
 /// A blocking wall segment in the XZ plane (a..b), inflated by `half_thickness`.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct WallSegment {
     pub a: (f32, f32),
     pub b: (f32, f32),
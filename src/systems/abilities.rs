@@ -7,20 +7,49 @@
 //! knowing it). One stat pipeline, one request channel, same validate-consume
 //! shape as machine automation.
 //!
-//! v1 scope is deliberately SELF-scoped: healing abilities restore Health and
-//! energy pays the cost (mana_cost + stamina_cost both draw from the energy
-//! vital until a separate stamina vital exists - casting makes you tired,
-//! which makes abilities part of the survival economy). Offensive rows load
-//! in the registry but are not castable until the combat arc gives them
-//! targets - the GUI says so honestly instead of fizzling.
-
-use crate::ecs::components::{Controllable, Health, Vitals};
+//! Healing abilities restore Health and energy pays the cost (mana_cost +
+//! stamina_cost both draw from the energy vital until a separate stamina
+//! vital exists - casting makes you tired, which makes abilities part of the
+//! survival economy).
+//!
+//! Offensive rows read their delivery from the columns (`Delivery`):
+//! one creature (the faced one, else the crosshair's, else the nearest
+//! hostile in range), an area around the caster, a cone, or a ground area
+//! where the crosshair lands. `projectile_speed_mps` rows fly and hit the
+//! first body in their path; `duration_s` rows spread `damage_base` over
+//! time as pulses. Every hit rides `damage_events`, so CombatSystem owns
+//! mitigation, death and loot - one damage pipeline.
+
+use crate::ecs::components::{AIBehavior, Controllable, Dead, Health, Transform, Vitals};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
+use crate::systems::combat::damage::{self, DamageEvent, DamageType};
+use crate::systems::combat::hit::{aim_ray, colliders, first_on_segment, Aim, Body};
 use crate::systems::skills::PlayerSkills;
+use glam::Vec3;
 use serde::Deserialize;
 use std::collections::HashMap;
 
+/// Reach of rows with `range_m = -1` ("unlimited").
+const UNLIMITED_RANGE_M: f32 = 200.0;
+/// Radius of a range-0 damage row with no aoe (a touch aura).
+const TOUCH_RADIUS_M: f32 = 2.0;
+/// Half-width of a ring's band around its radius.
+const RING_BAND_M: f32 = 1.0;
+/// Half-length of a wall with no aoe of its own, and its half-thickness.
+const WALL_HALF_LENGTH_M: f32 = 4.0;
+const WALL_HALF_THICKNESS_M: f32 = 0.75;
+/// Cosine of a cone's half-angle (~32 degrees).
+const CONE_COS: f32 = 0.85;
+/// How far above or below an area's plane a body still counts as inside.
+const AREA_HEIGHT_M: f32 = 3.0;
+/// Seconds between pulses of a lingering (duration_s) effect.
+const PULSE_S: f32 = 0.5;
+/// Radius of a projectile, added to the body it may hit.
+const PROJECTILE_RADIUS_M: f32 = 0.3;
+/// Slack on an explicit target's range (the player Transform trails the camera).
+const RANGE_SLACK_M: f32 = 2.0;
+
 // ── Definitions (data/abilities.csv) ────────────────────────────────
 
 /// One abilities.csv row. Columns the engine does not consume yet (aoe,
@@ -49,6 +78,7 @@ pub struct AbilityDef {
     pub aoe_shape: String,
     #[serde(default)]
     pub damage_base: f32,
+    /// Element (fire, ice, poison...): a damage_types.csv source name.
     #[serde(default)]
     pub damage_type: String,
     #[serde(default)]
@@ -68,6 +98,67 @@ pub struct AbilityDef {
     /// real | tech | fantasy - Real mode shows real+tech (a data view).
     #[serde(default)]
     pub flavor: String,
+    /// Flight speed; 0 = the effect lands the moment it is cast.
+    #[serde(default)]
+    pub projectile_speed_mps: f32,
+}
+
+/// How a row reaches what it hits, read from its columns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Delivery {
+    /// Heals the caster (healing rows).
+    SelfCast,
+    /// One creature: the faced one, the crosshair's, or the nearest hostile.
+    Single,
+    /// An area centred on (and following) the caster: range 0 rows.
+    AroundCaster,
+    /// A cone `range_m` long out of the caster along the aim.
+    Cone,
+    /// An area (sphere / ring / wall) where the crosshair meets the ground.
+    Ground,
+}
+
+/// The shape of an area effect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AreaShape {
+    Sphere,
+    /// A band `RING_BAND_M` either side of the radius.
+    Ring,
+    /// Apex at `center`, `radius` long, opening along `dir`.
+    Cone,
+    /// A strip across `dir`, `radius` either side of `center`.
+    Wall,
+}
+
+/// One area in world space. Tests are horizontal, within `AREA_HEIGHT_M`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Area {
+    pub shape: AreaShape,
+    pub center: Vec3,
+    /// Horizontal unit aim (cone axis, wall normal).
+    pub dir: Vec3,
+    pub radius: f32,
+}
+
+impl Area {
+    pub fn contains(&self, p: Vec3) -> bool {
+        if (p.y - self.center.y).abs() > AREA_HEIGHT_M.max(self.radius) {
+            return false;
+        }
+        let d = Vec3::new(p.x - self.center.x, 0.0, p.z - self.center.z);
+        let dist = d.length();
+        match self.shape {
+            AreaShape::Sphere => dist <= self.radius,
+            AreaShape::Ring => (dist - self.radius).abs() <= RING_BAND_M,
+            AreaShape::Cone => {
+                dist <= self.radius && (dist < 1e-3 || d.dot(self.dir) / dist >= CONE_COS)
+            }
+            AreaShape::Wall => {
+                let across = Vec3::new(-self.dir.z, 0.0, self.dir.x);
+                d.dot(self.dir).abs() <= WALL_HALF_THICKNESS_M && d.dot(across).abs() <= self.radius
+            }
+        }
+    }
 }
 
 impl AbilityDef {
@@ -77,12 +168,56 @@ impl AbilityDef {
         self.mana_cost + self.stamina_cost
     }
 
-    /// Does this row do anything in the v1 self-scoped pipeline? Healing
-    /// abilities are live; damage rows wait for the combat arc's targets.
+    /// Does this row heal its caster? (Healing wins over a token damage
+    /// number, e.g. cauterize.)
     pub fn self_castable(&self) -> bool {
         self.healing_base > 0.0
     }
 
+    /// How the row is delivered; None for rows that neither heal nor hurt.
+    pub fn delivery(&self) -> Option<Delivery> {
+        if self.self_castable() {
+            return Some(Delivery::SelfCast);
+        }
+        if self.damage_base <= 0.0 {
+            return None;
+        }
+        Some(match self.aoe_shape.as_str() {
+            "cone" => Delivery::Cone,
+            _ if self.range_m == 0.0 => Delivery::AroundCaster,
+            "wall" => Delivery::Ground,
+            _ if self.aoe_m > 0.0 => Delivery::Ground,
+            _ => Delivery::Single,
+        })
+    }
+
+    /// Reach in metres (`-1` = unlimited, capped at `UNLIMITED_RANGE_M`).
+    pub fn reach_m(&self) -> f32 {
+        if self.range_m < 0.0 {
+            UNLIMITED_RANGE_M
+        } else {
+            self.range_m
+        }
+    }
+
+    /// The area this row covers around `center`, aimed along `dir`.
+    pub fn area(&self, center: Vec3, dir: Vec3) -> Area {
+        let shape = match self.aoe_shape.as_str() {
+            "ring" => AreaShape::Ring,
+            "cone" => AreaShape::Cone,
+            "wall" => AreaShape::Wall,
+            _ => AreaShape::Sphere,
+        };
+        let radius = match shape {
+            AreaShape::Cone => self.reach_m().max(self.aoe_m),
+            AreaShape::Wall if self.aoe_m <= 0.0 => WALL_HALF_LENGTH_M,
+            _ if self.aoe_m <= 0.0 => TOUCH_RADIUS_M,
+            _ => self.aoe_m,
+        };
+        let flat = Vec3::new(dir.x, 0.0, dir.z).try_normalize().unwrap_or(Vec3::NEG_Z);
+        Area { shape, center, dir: flat, radius }
+    }
+
     /// Does the caster's training meet this row's skill gate? Level-1 gates
     /// are baseline-open (everyone has starter competence; untrained skills
     /// read as level 0), matching the recipe convention of gating at 2+.
@@ -124,21 +259,90 @@ impl AbilityRegistry {
 
 // ── The system ──────────────────────────────────────────────────────
 
-/// Drains the `ability_request` channel (GUI Cast clicks -> ability ids),
-/// validates skill gate + cost + cooldown, applies the self-scoped effect,
+/// One cast on the `ability_request` channel.
+#[derive(Debug, Clone, Default)]
+pub struct CastRequest {
+    pub ability: String,
+    /// Entity bits of the creature the caster faces, if any (v0.760).
+    pub target: Option<u64>,
+    /// Where the caster was looking (see `hit::aim_ray`).
+    pub aim: Option<Aim>,
+}
+
+impl CastRequest {
+    pub fn new(ability: &str, target: Option<u64>, aim: Option<Aim>) -> Self {
+        Self { ability: ability.to_string(), target, aim }
+    }
+}
+
+/// What a cast (or a landing projectile) does to what it reaches.
+#[derive(Debug, Clone)]
+struct Payload {
+    name: String,
+    damage_type: DamageType,
+    damage: f32,
+    duration_s: f32,
+}
+
+/// A cast in flight.
+#[derive(Debug, Clone)]
+pub struct Projectile {
+    pub position: Vec3,
+    /// Unit direction of travel.
+    pub dir: Vec3,
+    pub speed_mps: f32,
+    /// Metres left before it lands (ground rows) or fizzles (single rows).
+    pub remaining_m: f32,
+    payload: Payload,
+    /// The area it bursts into, centred where it lands; None = strikes one.
+    burst: Option<Area>,
+}
+
+/// Where a lingering effect lands each pulse.
+#[derive(Debug, Clone, Copy)]
+enum Victims {
+    /// One creature (damage over time).
+    One(hecs::Entity),
+    /// A fixed area.
+    Area(Area),
+    /// An area kept centred on the caster (auras).
+    AroundCaster(Area),
+}
+
+/// A `duration_s` effect: `damage` spread over the duration in pulses.
+#[derive(Debug, Clone)]
+struct Lingering {
+    payload: Payload,
+    victims: Victims,
+    remaining_s: f32,
+    until_pulse: f32,
+}
+
+/// Drains the `ability_request` channel (GUI Cast clicks / hotbar keys),
+/// validates skill gate + cost + cooldown, applies or launches the effect,
 /// and reports one honest line back through `ability_status`. Cooldowns tick
-/// down here and are published to `ability_cooldowns` for the GUI.
+/// down here and are published to `ability_cooldowns` for the GUI;
+/// projectiles and lingering areas are published to `ability_markers`.
 pub struct AbilitySystem {
     /// Seconds remaining per ability id (session-scoped, like machine timers).
     cooldowns: HashMap<String, f32>,
+    projectiles: Vec<Projectile>,
+    lingering: Vec<Lingering>,
 }
 
 impl AbilitySystem {
     pub fn new() -> Self {
         Self {
             cooldowns: HashMap::new(),
+            projectiles: Vec::new(),
+            lingering: Vec::new(),
         }
     }
+
+    /// Casts still in flight.
+    pub fn projectiles(&self) -> &[Projectile] {
+        &self.projectiles
+    }
 }
 
 impl Default for AbilitySystem {
@@ -159,18 +363,62 @@ impl System for AbilitySystem {
             *t > 0.0
         });
 
-        // Drain this frame's cast requests: (ability id, optional target
-        // entity bits - the creature the caster faces, v0.760).
-        let requests: Vec<(String, Option<u64>)> = data
-            .get::<std::sync::Mutex<Vec<(String, Option<u64>)>>>("ability_request")
+        // Drain this frame's cast requests.
+        let requests: Vec<CastRequest> = data
+            .get::<std::sync::Mutex<Vec<CastRequest>>>("ability_request")
             .and_then(|m| m.lock().ok().map(|mut v| std::mem::take(&mut *v)))
             .unwrap_or_default();
 
-        if !requests.is_empty() {
-            let mut status = String::new();
-            for (id, target) in requests {
-                status = self.cast(world, data, &id, target);
+        let mut status = String::new();
+        for req in &requests {
+            status = self.cast(world, data, req);
+        }
+
+        // Flights: each step is tested against every body it passes; a
+        // landing reports its own line.
+        let bodies = colliders(world);
+        let mut landed: Vec<(Projectile, Option<hecs::Entity>)> = Vec::new();
+        self.projectiles.retain_mut(|p| {
+            let step = (p.speed_mps * dt).min(p.remaining_m);
+            match first_on_segment(&bodies, p.position, p.dir, step, PROJECTILE_RADIUS_M) {
+                Some((e, t)) => {
+                    p.position += p.dir * t;
+                    landed.push((p.clone(), Some(e)));
+                    false
+                }
+                None => {
+                    p.position += p.dir * step;
+                    p.remaining_m -= step;
+                    if p.remaining_m > 1e-4 {
+                        return true;
+                    }
+                    landed.push((p.clone(), None));
+                    false
+                }
+            }
+        });
+        for (p, struck) in landed {
+            status = self.land(world, data, &bodies, p, struck);
+        }
+
+        // Lingering effects pulse their share of the damage.
+        let caster = caster(world).map(|(_, pos)| pos);
+        let mut pulses: Vec<(hecs::Entity, DamageEvent)> = Vec::new();
+        self.lingering.retain_mut(|l| {
+            l.remaining_s -= dt;
+            l.until_pulse -= dt;
+            while l.until_pulse <= 0.0 {
+                l.until_pulse += PULSE_S;
+                let amount = l.payload.damage * PULSE_S / l.payload.duration_s;
+                for e in lingering_victims(&bodies, l.victims, caster) {
+                    pulses.push((e, event(&l.payload, amount)));
+                }
             }
+            l.remaining_s > 0.0
+        });
+        queue_hits(data, pulses);
+
+        if !status.is_empty() {
             if let Some(s) = data.get::<std::sync::Mutex<String>>("ability_status") {
                 if let Ok(mut slot) = s.lock() {
                     *slot = status;
@@ -184,86 +432,57 @@ impl System for AbilitySystem {
                 *slot = self.cooldowns.clone();
             }
         }
+        // ... and what is in the air or on the ground, for the overlay.
+        if let Some(m) = data.get::<std::sync::Mutex<Vec<(Vec3, f32)>>>("ability_markers") {
+            if let Ok(mut slot) = m.lock() {
+                slot.clear();
+                slot.extend(self.projectiles.iter().map(|p| (p.position, PROJECTILE_RADIUS_M)));
+                slot.extend(self.lingering.iter().filter_map(|l| match l.victims {
+                    Victims::Area(a) => Some((a.center, a.radius)),
+                    Victims::AroundCaster(a) => caster.map(|c| (c, a.radius)),
+                    Victims::One(_) => None,
+                }));
+            }
+        }
     }
 }
 
-/// Map an abilities.csv damage_type string onto the combat system's damage
-/// categories (Armor.resistance keys). Fantasy elements collapse into the
-/// physical categories they behave like; "true" damage picks kinetic, which
-/// creatures carry no resistance against today.
-fn combat_damage_type(s: &str) -> crate::systems::combat::damage::DamageType {
-    use crate::systems::combat::damage::DamageType as D;
-    match s {
-        "fire" | "ice" => D::Thermal,
-        "lightning" | "psychic" | "holy" | "shadow" => D::Energy,
-        "poison" => D::Chemical,
-        _ => D::Kinetic, // physical, true, none, unknown
-    }
+/// A cast's aim and target, settled before anything is paid.
+enum Plan {
+    /// One creature, hit now or by a projectile launched from `from`.
+    Strike { target: hecs::Entity, name: String, from: Vec3 },
+    /// An area, covered now or burst into after a flight from `from`.
+    Area { area: Area, around_caster: bool, from: Vec3 },
 }
 
 impl AbilitySystem {
-    /// Validate + apply one cast against the player. Returns the status line.
-    fn cast(
-        &mut self,
-        world: &mut hecs::World,
-        data: &DataStore,
-        id: &str,
-        target: Option<u64>,
-    ) -> String {
+    /// Validate + apply one cast by the player. Returns the status line.
+    fn cast(&mut self, world: &mut hecs::World, data: &DataStore, req: &CastRequest) -> String {
         let Some(reg) = data.get::<AbilityRegistry>("ability_registry") else {
             return "Abilities are still loading".to_string();
         };
-        let Some(def) = reg.get(id) else {
-            return format!("Unknown ability {id}");
+        let Some(def) = reg.get(&req.ability) else {
+            return format!("Unknown ability {}", req.ability);
         };
-        let offensive = def.damage_base > 0.0 && !def.self_castable();
-        if !def.self_castable() && !offensive {
+        let Some(delivery) = def.delivery() else {
             return format!("{} does nothing yet - a later arc wires it", def.name);
-        }
-        if let Some(t) = self.cooldowns.get(id) {
+        };
+        if let Some(t) = self.cooldowns.get(&def.id) {
             return format!("{} recharging ({:.0}s)", def.name, t.max(1.0));
         }
 
-        // Offensive casts need a living target within range (v0.760).
-        let mut target_entity: Option<hecs::Entity> = None;
-        let mut target_name = String::new();
-        if offensive {
-            let Some(bits) = target else {
-                return format!("{} needs a target - face a creature", def.name);
-            };
-            let Some(e) = hecs::Entity::from_bits(bits) else {
-                return format!("{} needs a target - face a creature", def.name);
-            };
-            if !world.contains(e)
-                || world.get::<&crate::ecs::components::Dead>(e).is_ok()
-                || world.get::<&Health>(e).is_err()
-            {
-                return format!("{}: that target is gone", def.name);
-            }
-            // Range gate: caster position vs target position, with a small
-            // tolerance since the player Transform trails the camera.
-            if def.range_m > 0.0 {
-                let caster_pos = world
-                    .query_mut::<(&crate::ecs::components::Transform, &Controllable)>()
-                    .into_iter()
-                    .next()
-                    .map(|(_e, (t, _c))| t.position);
-                if let (Some(cp), Ok(tt)) = (
-                    caster_pos,
-                    world.get::<&crate::ecs::components::Transform>(e),
-                ) {
-                    if (tt.position - cp).length() > def.range_m + 2.0 {
-                        return format!("{}: out of range ({:.0}m)", def.name, def.range_m);
-                    }
-                }
+        // Offensive casts find their target or spot first, so a
+        // refusal costs nothing.
+        let plan = if delivery == Delivery::SelfCast {
+            None
+        } else {
+            match plan_attack(world, def, delivery, req) {
+                Ok(plan) => Some(plan),
+                Err(refusal) => return refusal,
             }
-            target_name = world
-                .get::<&crate::ecs::components::Name>(e)
-                .map(|n| n.0.clone())
-                .unwrap_or_else(|_| "the target".to_string());
-            target_entity = Some(e);
-        }
+        };
 
+        let mut paid: Option<String> = None;
         for (_e, (skills, vitals, health, _c)) in world
             .query_mut::<(&PlayerSkills, &mut Vitals, &mut Health, &Controllable)>()
         {
@@ -278,48 +497,289 @@ impl AbilitySystem {
                 return format!("Too tired to cast {} ({cost:.0} energy)", def.name);
             }
             vitals.energy -= cost;
-            if !offensive {
+            paid = Some(if plan.is_none() {
                 let healed = def
                     .healing_base
                     .min((health.max - health.current).max(0.0));
                 health.current = (health.current + def.healing_base).min(health.max);
-                self.cooldowns.insert(id.to_string(), def.cooldown_s);
-                if !def.skill_required.is_empty() {
-                    crate::systems::skills::award_skill_xp(data, &def.skill_required, 5);
-                }
-                return if healed > 0.0 {
+                if healed > 0.0 {
                     format!("{} restores {healed:.0} health", def.name)
                 } else {
                     format!("{} cast (already at full health)", def.name)
-                };
+                }
+            } else {
+                String::new()
+            });
+            break;
+        }
+        let Some(line) = paid else {
+            return "No caster in the world yet".to_string();
+        };
+        self.cooldowns.insert(def.id.clone(), def.cooldown_s);
+        if !def.skill_required.is_empty() {
+            crate::systems::skills::award_skill_xp(data, &def.skill_required, 5);
+        }
+        let Some(plan) = plan else {
+            return line;
+        };
+
+        // Offensive: queue the hits for CombatSystem (armor mitigation +
+        // death + loot all live there - one damage pipeline).
+        let payload = Payload {
+            name: def.name.clone(),
+            damage_type: damage::damage_type_for(data, &def.damage_type),
+            damage: def.damage_base,
+            duration_s: def.duration_s,
+        };
+        let flies = def.projectile_speed_mps > 0.0;
+        let bodies = colliders(world);
+        match plan {
+            Plan::Strike { target, name, from } if flies => {
+                let to = bodies.iter().find(|b| b.0 == target).map(|b| b.1).unwrap_or(from);
+                self.projectiles.push(Projectile {
+                    position: from,
+                    dir: (to - from).try_normalize().unwrap_or(Vec3::NEG_Z),
+                    speed_mps: def.projectile_speed_mps,
+                    remaining_m: def.reach_m() + RANGE_SLACK_M,
+                    payload,
+                    burst: None,
+                });
+                format!("{} flies at {name}", def.name)
+            }
+            Plan::Strike { target, name, .. } => self.strike(data, target, &name, payload),
+            Plan::Area { area, around_caster: false, from } if flies => {
+                let to = area.center - from;
+                self.projectiles.push(Projectile {
+                    position: from,
+                    dir: to.try_normalize().unwrap_or(Vec3::NEG_Z),
+                    speed_mps: def.projectile_speed_mps,
+                    remaining_m: to.length(),
+                    payload,
+                    burst: Some(area),
+                });
+                format!("{} launched", def.name)
             }
-            // Offensive: queue the hit for CombatSystem (armor mitigation +
-            // death + loot all live there - one damage pipeline).
-            self.cooldowns.insert(id.to_string(), def.cooldown_s);
-            if !def.skill_required.is_empty() {
-                crate::systems::skills::award_skill_xp(data, &def.skill_required, 5);
+            Plan::Area { area, around_caster, .. } => {
+                self.cover(data, &bodies, area, around_caster, payload)
             }
-            if let (Some(e), Some(chan)) = (
-                target_entity,
-                data.get::<std::sync::Mutex<
-                    Vec<(u64, crate::systems::combat::damage::DamageEvent)>,
-                >>("damage_events"),
-            ) {
-                if let Ok(mut q) = chan.lock() {
-                    q.push((
-                        e.to_bits().into(),
-                        crate::systems::combat::damage::DamageEvent {
-                            damage_type: combat_damage_type(&def.damage_type),
-                            amount: def.damage_base,
-                            source_name: None,
-                            source_is_player: true,
-                        },
-                    ));
+        }
+    }
+
+    /// One creature takes the payload: now, or spread over its duration.
+    fn strike(&mut self, data: &DataStore, target: hecs::Entity, name: &str, payload: Payload) -> String {
+        if payload.duration_s > 0.0 {
+            let line = format!("{} takes hold of {name} ({:.0}s)", payload.name, payload.duration_s);
+            let remaining_s = payload.duration_s;
+            self.lingering.push(Lingering {
+                payload,
+                victims: Victims::One(target),
+                remaining_s,
+                until_pulse: PULSE_S,
+            });
+            return line;
+        }
+        let line = format!("{} hits {name} for {:.0}", payload.name, payload.damage);
+        queue_hits(data, vec![(target, event(&payload, payload.damage))]);
+        line
+    }
+
+    /// Every body in the area takes the payload: now, or as a lingering
+    /// area pulsing its share of the damage.
+    fn cover(
+        &mut self,
+        data: &DataStore,
+        bodies: &[Body],
+        area: Area,
+        around_caster: bool,
+        payload: Payload,
+    ) -> String {
+        if payload.duration_s > 0.0 {
+            let line = format!("{} lingers for {:.0}s", payload.name, payload.duration_s);
+            let remaining_s = payload.duration_s;
+            self.lingering.push(Lingering {
+                payload,
+                victims: if around_caster { Victims::AroundCaster(area) } else { Victims::Area(area) },
+                remaining_s,
+                until_pulse: PULSE_S,
+            });
+            return line;
+        }
+        let hit: Vec<(hecs::Entity, DamageEvent)> = bodies
+            .iter()
+            .filter(|b| area.contains(b.1))
+            .map(|b| (b.0, event(&payload, payload.damage)))
+            .collect();
+        let line = match hit.len() {
+            0 => format!("{} hits nothing", payload.name),
+            1 => format!("{} hits 1 target for {:.0}", payload.name, payload.damage),
+            n => format!("{} hits {n} targets for {:.0}", payload.name, payload.damage),
+        };
+        queue_hits(data, hit);
+        line
+    }
+
+    /// A projectile came down: burst, strike what it hit, or miss.
+    fn land(
+        &mut self,
+        world: &hecs::World,
+        data: &DataStore,
+        bodies: &[Body],
+        p: Projectile,
+        struck: Option<hecs::Entity>,
+    ) -> String {
+        match (p.burst, struck) {
+            (Some(mut area), _) => {
+                area.center = p.position;
+                self.cover(data, bodies, area, false, p.payload)
+            }
+            (None, Some(e)) => {
+                let name = display_name(world, e);
+                self.strike(data, e, &name, p.payload)
+            }
+            (None, None) => format!("{} misses", p.payload.name),
+        }
+    }
+}
+
+/// Settle what an offensive cast aims at (see `Delivery`), or the refusal.
+fn plan_attack(
+    world: &hecs::World,
+    def: &AbilityDef,
+    delivery: Delivery,
+    req: &CastRequest,
+) -> Result<Plan, String> {
+    let Some((caster_e, caster_pos)) = caster(world) else {
+        return Err("No caster in the world yet".to_string());
+    };
+    let facing = world
+        .get::<&Transform>(caster_e)
+        .map(|t| t.rotation * Vec3::NEG_Z)
+        .unwrap_or(Vec3::NEG_Z);
+    let (origin, dir) = aim_ray(req.aim, caster_pos, facing);
+    let reach = def.reach_m();
+
+    // The faced creature (v0.760) wins when it is still there and in range.
+    let faced = match req.target.and_then(hecs::Entity::from_bits) {
+        Some(e) => {
+            if !world.contains(e) || world.get::<&Dead>(e).is_ok() || world.get::<&Health>(e).is_err() {
+                return Err(format!("{}: that target is gone", def.name));
+            }
+            if def.range_m > 0.0 {
+                if let Ok(t) = world.get::<&Transform>(e) {
+                    if (t.position - caster_pos).length() > def.range_m + RANGE_SLACK_M {
+                        return Err(format!("{}: out of range ({:.0}m)", def.name, def.range_m));
+                    }
                 }
             }
-            return format!("{} hits {} for {:.0}", def.name, target_name, def.damage_base);
+            Some(e)
+        }
+        None => None,
+    };
+
+    let bodies = colliders(world);
+    Ok(match delivery {
+        Delivery::Single => {
+            let target = faced
+                .or_else(|| first_on_segment(&bodies, origin, dir, reach, PROJECTILE_RADIUS_M).map(|(e, _)| e))
+                .or_else(|| nearest_hostile(world, caster_pos, reach));
+            let Some(target) = target else {
+                return Err(format!("{} needs a target - aim at a creature", def.name));
+            };
+            Plan::Strike { target, name: display_name(world, target), from: origin }
+        }
+        Delivery::Ground => {
+            let center = match faced.and_then(|e| world.get::<&Transform>(e).ok().map(|t| t.position)) {
+                Some(p) => p,
+                None => ground_point(origin, dir, caster_pos.y, reach),
+            };
+            Plan::Area { area: def.area(center, dir), around_caster: false, from: origin }
+        }
+        Delivery::AroundCaster | Delivery::Cone => Plan::Area {
+            area: def.area(caster_pos, dir),
+            around_caster: delivery == Delivery::AroundCaster,
+            from: origin,
+        },
+        Delivery::SelfCast => unreachable!("self casts are not planned"),
+    })
+}
+
+/// The player entity and where it stands.
+fn caster(world: &hecs::World) -> Option<(hecs::Entity, Vec3)> {
+    world
+        .query::<(&Transform, &Controllable)>()
+        .iter()
+        .next()
+        .map(|(e, (t, _))| (e, t.position))
+}
+
+/// The nearest aggressive or predator creature within `reach` of `from`.
+fn nearest_hostile(world: &hecs::World, from: Vec3, reach: f32) -> Option<hecs::Entity> {
+    world
+        .query::<(&Transform, &Health, &AIBehavior)>()
+        .without::<&Dead>()
+        .iter()
+        .filter(|(_, (_, _, ai))| matches!(ai.behavior_type.as_str(), "aggressive" | "predator"))
+        .map(|(e, (t, _, _))| (e, t.position.distance(from)))
+        .filter(|&(_, d)| d <= reach)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(e, _)| e)
+}
+
+/// Where the crosshair ray meets the ground plane at `ground_y`, no
+/// further than `reach` across; level or upward aim lands at full reach.
+fn ground_point(origin: Vec3, dir: Vec3, ground_y: f32, reach: f32) -> Vec3 {
+    if dir.y < -1e-3 {
+        let hit = origin + dir * ((ground_y - origin.y) / dir.y);
+        if Vec3::new(hit.x - origin.x, 0.0, hit.z - origin.z).length() <= reach {
+            return hit;
         }
-        "No caster in the world yet".to_string()
+    }
+    let flat = Vec3::new(dir.x, 0.0, dir.z).try_normalize().unwrap_or(Vec3::NEG_Z);
+    Vec3::new(origin.x + flat.x * reach, ground_y, origin.z + flat.z * reach)
+}
+
+/// Who a lingering effect reaches this pulse.
+fn lingering_victims(bodies: &[Body], victims: Victims, caster: Option<Vec3>) -> Vec<hecs::Entity> {
+    match victims {
+        Victims::One(e) => bodies.iter().filter(|b| b.0 == e).map(|b| b.0).collect(),
+        Victims::Area(area) => bodies.iter().filter(|b| area.contains(b.1)).map(|b| b.0).collect(),
+        Victims::AroundCaster(area) => match caster {
+            Some(c) => {
+                let area = Area { center: c, ..area };
+                bodies.iter().filter(|b| area.contains(b.1)).map(|b| b.0).collect()
+            }
+            None => Vec::new(),
+        },
+    }
+}
+
+fn display_name(world: &hecs::World, e: hecs::Entity) -> String {
+    world
+        .get::<&crate::ecs::components::Name>(e)
+        .map(|n| n.0.clone())
+        .unwrap_or_else(|_| "the target".to_string())
+}
+
+fn event(payload: &Payload, amount: f32) -> DamageEvent {
+    DamageEvent {
+        damage_type: payload.damage_type.clone(),
+        amount,
+        source_name: None,
+        source_is_player: true,
+        skill: None,
+    }
+}
+
+/// Push hits onto the `damage_events` channel CombatSystem drains.
+fn queue_hits(data: &DataStore, hits: Vec<(hecs::Entity, DamageEvent)>) {
+    if hits.is_empty() {
+        return;
+    }
+    if let Some(mut q) = data
+        .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+        .and_then(|m| m.lock().ok())
+    {
+        q.extend(hits.into_iter().map(|(e, ev)| (e.to_bits().into(), ev)));
     }
 }
 
@@ -394,13 +854,17 @@ mod tests {
         data.insert("ability_registry", shipped_registry());
         data.insert(
             "ability_request",
-            std::sync::Mutex::new(Vec::<(String, Option<u64>)>::new()),
+            std::sync::Mutex::new(Vec::<CastRequest>::new()),
         );
         data.insert("ability_status", std::sync::Mutex::new(String::new()));
         data.insert(
             "ability_cooldowns",
             std::sync::Mutex::new(HashMap::<String, f32>::new()),
         );
+        data.insert(
+            "ability_markers",
+            std::sync::Mutex::new(Vec::<(Vec3, f32)>::new()),
+        );
         data.insert(
             "xp_grants",
             std::sync::Mutex::new(Vec::<crate::systems::skills::SkillXPEvent>::new()),
@@ -414,6 +878,33 @@ mod tests {
         (world, data)
     }
 
+    fn status(data: &DataStore) -> String {
+        data.get::<std::sync::Mutex<String>>("ability_status")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .clone()
+    }
+
+    /// Take the queued hits as (entity bits, amount).
+    fn take_hits(data: &DataStore) -> Vec<(u64, f32)> {
+        data.get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|(e, ev)| (e, ev.amount))
+            .collect()
+    }
+
+    fn spawn_body(world: &mut hecs::World, name: &str, at: Vec3) -> hecs::Entity {
+        world.spawn((
+            crate::ecs::components::Name(name.to_string()),
+            Health { current: 500.0, max: 500.0 },
+            Transform { position: at, ..Default::default() },
+        ))
+    }
+
     /// THE cast loop: a healing ability pays energy, restores health, starts
     /// its cooldown (second cast refused), and recharges over time.
     #[test]
@@ -425,18 +916,11 @@ mod tests {
         // Gate: pyromancy 1 - level-1 gates are baseline-open, so a fresh
         // player (untrained = level 0) can still cast it.
         let push = |data: &DataStore, id: &str| {
-            data.get::<std::sync::Mutex<Vec<(String, Option<u64>)>>>("ability_request")
-                .unwrap()
-                .lock()
-                .unwrap()
-                .push((id.to_string(), None));
-        };
-        let status = |data: &DataStore| -> String {
-            data.get::<std::sync::Mutex<String>>("ability_status")
+            data.get::<std::sync::Mutex<Vec<CastRequest>>>("ability_request")
                 .unwrap()
                 .lock()
                 .unwrap()
-                .clone()
+                .push(CastRequest::new(id, None, None));
         };
 
         push(&data, "cauterize");
@@ -470,22 +954,23 @@ mod tests {
         }
     }
 
-    /// Refusals are honest and free: an offensive row without a target and
-    /// an unaffordable cast change nothing.
+    /// Refusals are honest and free: an offensive row with nothing to aim
+    /// at and an unaffordable cast change nothing.
     #[test]
     fn refused_casts_change_nothing() {
         let (mut world, data) = cast_world();
         let mut sys = AbilitySystem::new();
 
-        // Offensive row with no target: refused, nothing spent.
-        let msg = sys.cast(&mut world, &data, "fireball", None);
+        // Single-target row, empty crosshair, no hostile: refused, nothing spent.
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("ember_shot", None, None));
         assert!(msg.contains("needs a target"), "got: {msg}");
+        assert!(sys.projectiles().is_empty());
 
         // Drain energy below any cost: refused, health unchanged.
         for (_e, (v, _c)) in world.query_mut::<(&mut Vitals, &Controllable)>() {
             v.energy = 1.0;
         }
-        let msg = sys.cast(&mut world, &data, "cauterize", None);
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("cauterize", None, None));
         assert!(msg.contains("Too tired"), "got: {msg}");
         let mut q = world.query::<(&Health, &Vitals)>();
         let (_, (h, v)) = q.iter().next().unwrap();
@@ -494,19 +979,17 @@ mod tests {
     }
 
     /// The attack path (v0.760): an offensive cast at a living creature pays
-    /// energy, queues the hit on the damage_events channel for CombatSystem,
-    /// and range/dead gates refuse honestly.
+    /// energy, flies (ember_shot is a projectile), queues the
+    /// hit on the damage_events channel for CombatSystem when it lands, and
+    /// range/dead gates refuse honestly.
     #[test]
     fn offensive_cast_queues_damage_at_a_target() {
-        use crate::ecs::components::{Name, Transform};
-        use glam::Vec3;
-
         let (mut world, data) = cast_world();
         let mut sys = AbilitySystem::new();
 
-        // A chicken 5m away (ember_shot: 18 kinetic-mapped fire, range 25m).
+        // A chicken 5m away (ember_shot: 18 fire, range 25m, 30 m/s).
         let hen = world.spawn((
-            Name("Chicken".to_string()),
+            crate::ecs::components::Name("Chicken".to_string()),
             Health { current: 15.0, max: 15.0 },
             Transform {
                 position: Vec3::new(5.0, 0.0, 0.0),
@@ -514,19 +997,16 @@ mod tests {
             },
         ));
 
-        let msg = sys.cast(&mut world, &data, "ember_shot", Some(hen.to_bits().into()));
-        assert!(msg.contains("hits Chicken for 18"), "got: {msg}");
-        let queued = data
-            .get::<std::sync::Mutex<Vec<(u64, crate::systems::combat::damage::DamageEvent)>>>(
-                "damage_events",
-            )
-            .unwrap()
-            .lock()
-            .unwrap()
-            .clone();
-        assert_eq!(queued.len(), 1);
-        assert_eq!(queued[0].0, u64::from(hen.to_bits()));
-        assert_eq!(queued[0].1.amount, 18.0);
+        let bits = Some(hen.to_bits().into());
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("ember_shot", bits, None));
+        assert!(msg.contains("flies at Chicken"), "got: {msg}");
+        assert!(take_hits(&data).is_empty(), "nothing lands before the flight");
+        for _ in 0..10 {
+            sys.tick(&mut world, 0.05, &data);
+        }
+        assert!(sys.projectiles().is_empty(), "the bolt landed");
+        assert!(status(&data).contains("hits Chicken for 18"), "got: {}", status(&data));
+        assert_eq!(take_hits(&data), vec![(u64::from(hen.to_bits()), 18.0)]);
 
         // Out of range: a far target refuses without spending.
         sys.tick(&mut world, 5.0, &data); // clear ember_shot's 1s cooldown
@@ -534,12 +1014,112 @@ mod tests {
             let mut t = world.get::<&mut Transform>(hen).unwrap();
             t.position = Vec3::new(100.0, 0.0, 0.0);
         }
-        let msg = sys.cast(&mut world, &data, "ember_shot", Some(hen.to_bits().into()));
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("ember_shot", bits, None));
         assert!(msg.contains("out of range"), "got: {msg}");
 
         // A dead target refuses.
         world.insert_one(hen, crate::ecs::components::Dead::default()).unwrap();
-        let msg = sys.cast(&mut world, &data, "ember_shot", Some(hen.to_bits().into()));
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("ember_shot", bits, None));
         assert!(msg.contains("gone"), "got: {msg}");
     }
+
+    /// A ground area: fireball flies to where the crosshair meets the ground
+    /// and bursts, hitting every body inside its 3m sphere and no other.
+    #[test]
+    fn fireball_bursts_over_every_creature_in_its_area() {
+        let (mut world, data) = cast_world();
+        let mut sys = AbilitySystem::new();
+        let a = spawn_body(&mut world, "Boar", Vec3::new(0.0, 0.0, -10.0));
+        let b = spawn_body(&mut world, "Boar", Vec3::new(2.0, 0.0, -10.0));
+        let _far = spawn_body(&mut world, "Boar", Vec3::new(8.0, 0.0, -10.0));
+
+        let eye = Vec3::new(0.0, 1.6, 0.0);
+        let aim = (eye, (Vec3::new(0.0, 0.0, -10.0) - eye).normalize());
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("fireball", None, Some(aim)));
+        assert!(msg.contains("launched"), "got: {msg}");
+        for _ in 0..20 {
+            sys.tick(&mut world, 0.05, &data);
+        }
+        assert!(status(&data).contains("hits 2 targets for 45"), "got: {}", status(&data));
+        let mut hit: Vec<u64> = take_hits(&data).into_iter().map(|(e, _)| e).collect();
+        hit.sort_unstable();
+        let mut want = vec![u64::from(a.to_bits()), u64::from(b.to_bits())];
+        want.sort_unstable();
+        assert_eq!(hit, want);
+    }
+
+    /// A projectile strikes the first body on its path: a creature standing
+    /// between the caster and the faced target takes the shot.
+    #[test]
+    fn projectile_hits_the_first_body_in_its_path() {
+        let (mut world, data) = cast_world();
+        let mut sys = AbilitySystem::new();
+        let near = spawn_body(&mut world, "Deer", Vec3::new(0.0, 0.0, -5.0));
+        let far = spawn_body(&mut world, "Wolf", Vec3::new(0.0, 0.0, -12.0));
+
+        let aim = (Vec3::new(0.0, 0.9, 0.0), Vec3::NEG_Z);
+        let msg = sys.cast(
+            &mut world,
+            &data,
+            &CastRequest::new("ember_shot", Some(far.to_bits().into()), Some(aim)),
+        );
+        assert!(msg.contains("flies at Wolf"), "got: {msg}");
+        for _ in 0..20 {
+            sys.tick(&mut world, 0.05, &data);
+        }
+        assert!(status(&data).contains("hits Deer"), "got: {}", status(&data));
+        assert_eq!(take_hits(&data), vec![(u64::from(near.to_bits()), 18.0)]);
+    }
+
+    /// A duration_s row spreads damage_base over its duration in pulses,
+    /// and stops when the duration runs out.
+    #[test]
+    fn lingering_damage_pulses_over_its_duration() {
+        let (mut world, mut data) = cast_world();
+        let mut reg = shipped_registry();
+        let mut searing = reg.get("ember_shot").unwrap().clone();
+        searing.id = "searing".to_string();
+        searing.name = "Searing".to_string();
+        searing.projectile_speed_mps = 0.0;
+        searing.damage_base = 20.0;
+        searing.duration_s = 2.0;
+        reg.defs.insert(searing.id.clone(), searing);
+        data.insert("ability_registry", reg);
+        let mut sys = AbilitySystem::new();
+        let hog = spawn_body(&mut world, "Hog", Vec3::new(0.0, 0.0, -3.0));
+
+        let msg = sys.cast(
+            &mut world,
+            &data,
+            &CastRequest::new("searing", Some(hog.to_bits().into()), None),
+        );
+        assert!(msg.contains("takes hold of Hog"), "got: {msg}");
+        for _ in 0..8 {
+            sys.tick(&mut world, 0.5, &data);
+        }
+        let hits = take_hits(&data);
+        assert_eq!(hits.len(), 4, "one pulse per 0.5s over 2s: {hits:?}");
+        assert!(hits.iter().all(|&(e, amount)| e == u64::from(hog.to_bits()) && amount == 5.0));
+    }
+
+    /// With no faced target and nothing under the crosshair, a single-target
+    /// row finds the nearest hostile in reach - not a closer passive animal.
+    #[test]
+    fn single_target_falls_back_to_the_nearest_hostile() {
+        let (mut world, data) = cast_world();
+        let mut sys = AbilitySystem::new();
+        let hen = spawn_body(&mut world, "Chicken", Vec3::new(3.0, 0.0, 0.0));
+        world
+            .insert_one(hen, AIBehavior { behavior_type: "passive".to_string(), state: "idle".to_string(), target: None })
+            .unwrap();
+        let wolf = spawn_body(&mut world, "Wolf", Vec3::new(12.0, 0.0, 0.0));
+        world
+            .insert_one(wolf, AIBehavior { behavior_type: "aggressive".to_string(), state: "idle".to_string(), target: None })
+            .unwrap();
+
+        // lightning_bolt: instant, 50 electric, range 35m; the aim is empty sky.
+        let msg = sys.cast(&mut world, &data, &CastRequest::new("lightning_bolt", None, None));
+        assert!(msg.contains("hits Wolf for 50"), "got: {msg}");
+        assert_eq!(take_hits(&data), vec![(u64::from(wolf.to_bits()), 50.0)]);
+    }
 }
@@ -3,25 +3,51 @@
 //!
 //! Time conversion: 1 game day = 20 real minutes (`SECONDS_PER_GAME_DAY`).
 //! 1 game year = 365 game days. So 1 real second ≈ 2.28e-6 game years.
+//!
+//! Herd animals (an `Age` on a `Creature`) climb the much shorter
+//! `animal_stages` ladder instead of the human one.
 
 use std::path::Path;
 
 use serde::Deserialize;
 
-use crate::ecs::components::Age;
+use crate::ecs::components::{Age, Creature};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
 
 /// 20 real minutes per game day, 365 days per game year.
-const REAL_SECONDS_PER_GAME_YEAR: f32 = 1200.0 * 365.0;
+pub const REAL_SECONDS_PER_GAME_YEAR: f32 = 1200.0 * 365.0;
 
-/// Top-level RON schema for `data/aging_fitness.ron`.
+/// Top-level RON schema for `data/aging_fitness.ron`. Every section is
+/// optional so one malformed block does not cost the whole file.
 #[derive(Debug, Deserialize)]
 pub struct AgingData {
+    #[serde(default)]
     pub age_stages: Vec<ron::Value>,
+    #[serde(default)]
+    pub animal_stages: Vec<ron::Value>,
+    #[serde(default)]
     pub fitness_levels: Vec<ron::Value>,
+    #[serde(default, rename = "exercise_types")]
     pub exercises: Vec<ron::Value>,
-    pub sleep: Vec<ron::Value>,
+    #[serde(default = "no_sleep_table")]
+    pub sleep: ron::Value,
+}
+
+fn no_sleep_table() -> ron::Value {
+    ron::Value::Unit
+}
+
+impl Default for AgingData {
+    fn default() -> Self {
+        Self {
+            age_stages: Vec::new(),
+            animal_stages: Vec::new(),
+            fitness_levels: Vec::new(),
+            exercises: Vec::new(),
+            sleep: no_sleep_table(),
+        }
+    }
 }
 
 /// (life_stage_id, lower_age_inclusive, upper_age_exclusive). Used by `tick`
@@ -38,11 +64,23 @@ fn default_stage_ladder() -> Vec<(&'static str, f32, f32)> {
     ]
 }
 
+/// Herd-animal ladder in game-years: breeding age after about a week of game
+/// days, old (no longer breeding) after about half a year.
+fn default_animal_ladder() -> Vec<(&'static str, f32, f32)> {
+    vec![
+        ("juvenile", 0.0,  0.02),
+        ("adult",    0.02, 0.5),
+        ("elder",    0.5,  f32::INFINITY),
+    ]
+}
+
 /// Tracks age stages, fitness, exercise, and sleep for entities.
 pub struct AgingSystem {
     pub data: AgingData,
     /// Resolved (id, lower, upper) ladder built from the data file or the default.
     stage_ladder: Vec<(String, f32, f32)>,
+    /// The same for herd animals (`animal_stages`).
+    animal_ladder: Vec<(String, f32, f32)>,
 }
 
 impl AgingSystem {
@@ -54,23 +92,28 @@ impl AgingSystem {
         });
         let data: AgingData = ron::from_str(&text).unwrap_or_else(|e| {
             log::warn!("Failed to parse aging_fitness.ron: {e}");
-            AgingData { age_stages: vec![], fitness_levels: vec![], exercises: vec![], sleep: vec![] }
+            AgingData::default()
         });
         log::info!("Loaded aging data: {} stages, {} exercises", data.age_stages.len(), data.exercises.len());
 
-        // Build the stage ladder from data, falling back to the default if parsing
-        // any individual stage entry fails.
-        let mut ladder: Vec<(String, f32, f32)> = data.age_stages.iter()
-            .filter_map(|v| Self::parse_stage(v))
+        let stage_ladder = Self::build_ladder(&data.age_stages, default_stage_ladder());
+        let animal_ladder = Self::build_ladder(&data.animal_stages, default_animal_ladder());
+        Self { data, stage_ladder, animal_ladder }
+    }
+
+    /// Build a ladder from data, falling back to the default if no stage entry
+    /// parses.
+    fn build_ladder(stages: &[ron::Value], fallback: Vec<(&str, f32, f32)>) -> Vec<(String, f32, f32)> {
+        let mut ladder: Vec<(String, f32, f32)> = stages.iter()
+            .filter_map(Self::parse_stage)
             .collect();
         if ladder.is_empty() {
-            ladder = default_stage_ladder().into_iter()
+            ladder = fallback.into_iter()
                 .map(|(id, lo, hi)| (id.to_string(), lo, hi))
                 .collect();
         }
         ladder.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
-
-        Self { data, stage_ladder: ladder }
+        ladder
     }
 
     /// Try to extract `(id, lower, upper)` from an `age_stages[].(id, age_range)` entry.
@@ -87,15 +130,17 @@ impl AgingSystem {
         Some((id, lo, hi))
     }
 
-    /// Resolve an age in years to a life-stage id.
-    fn life_stage_for_age(&self, age: f32) -> &str {
-        for (id, lo, hi) in &self.stage_ladder {
-            if age >= *lo && age < *hi {
-                return id;
-            }
-        }
-        // Past the last threshold — return the last stage.
-        self.stage_ladder.last().map(|(id, _, _)| id.as_str()).unwrap_or("adult")
+    /// Resolve an age in years to a life-stage id: the last stage whose lower
+    /// bound it has reached, so whole-year gaps in the data (teen ends at 17,
+    /// young_adult starts at 18) stay in the earlier stage.
+    fn life_stage_for_age(ladder: &[(String, f32, f32)], age: f32) -> &str {
+        ladder
+            .iter()
+            .take_while(|(_, lo, _)| age >= *lo)
+            .last()
+            .or(ladder.first())
+            .map(|(id, _, _)| id.as_str())
+            .unwrap_or("adult")
     }
 }
 
@@ -109,9 +154,10 @@ impl System for AgingSystem {
         // Collect transitions outside the borrow so we can log them after.
         let mut transitions: Vec<(hecs::Entity, String, String)> = Vec::new();
 
-        for (entity, age) in world.query_mut::<&mut Age>() {
+        for (entity, (age, creature)) in world.query_mut::<(&mut Age, Option<&Creature>)>() {
             age.years += years_per_tick;
-            let new_stage = self.life_stage_for_age(age.years);
+            let ladder = if creature.is_some() { &self.animal_ladder } else { &self.stage_ladder };
+            let new_stage = Self::life_stage_for_age(ladder, age.years);
             if new_stage != age.life_stage {
                 transitions.push((entity, age.life_stage.clone(), new_stage.to_string()));
                 age.life_stage = new_stage.to_string();
@@ -123,3 +169,35 @@ impl System for AgingSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glam::Vec3;
+
+    #[test]
+    fn shipped_ladders_stage_people_and_animals() {
+        let sys = AgingSystem::new(&Path::new(env!("CARGO_MANIFEST_DIR")).join("data"));
+        assert!(sys.data.age_stages.len() >= 6, "aging_fitness.ron parses");
+        assert_eq!(AgingSystem::life_stage_for_age(&sys.stage_ladder, 12.5), "child");
+        assert_eq!(AgingSystem::life_stage_for_age(&sys.animal_ladder, 0.0), "juvenile");
+
+        let mut world = hecs::World::new();
+        let person = world.spawn((Age { years: 17.9, life_stage: "teen".into() },));
+        let creature = Creature {
+            def_id: "goat".into(),
+            anchor: Vec3::ZERO,
+            range: 1.0,
+            phase: 0.0,
+            speed: 0.5,
+            tint: [1.0; 3],
+            body_side: 0.4,
+        };
+        let kid = world.spawn((Age { years: 0.0, life_stage: "juvenile".into() }, creature));
+        let mut sys = sys;
+        // A tenth of a game-year: the teen comes of age, the kid grows up.
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_YEAR * 0.1, &DataStore::new());
+        assert_eq!(world.get::<&Age>(person).unwrap().life_stage, "young_adult");
+        assert_eq!(world.get::<&Age>(kid).unwrap().life_stage, "adult");
+    }
+}
@@ -1,9 +1,23 @@
 //! Data-driven behavior trees for NPC decision-making.
 //!
-//! Tree definitions loaded from `data/behaviors.ron`.
+//! Tree definitions loaded from `data/behaviors.ron`: one tree per
+//! `AIBehavior::behavior_type`. The interpreter here is generic over the
+//! caller's context type so the leaves live with the system that owns the
+//! world snapshot (`AISystem` registers wander / flee / attack as named
+//! leaves in `mod.rs`); this file knows nothing about creatures.
+//!
+//! Composites are REACTIVE: every tick re-evaluates a Sequence/Selector from
+//! its first child, so a flee branch placed above a wander branch preempts it
+//! the moment its condition holds. Decorators (Inverter, Repeat, Cooldown,
+//! Timeout) keep per-node memory in the entity's `Blackboard` component,
+//! addressed by the node's pre-order index in its tree.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::ecs::components::Blackboard;
+
 /// Result of a behavior tree node tick.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BehaviorStatus {
@@ -23,4 +37,360 @@ pub enum BehaviorNode {
     Action(String),
     /// A leaf condition check identified by name.
     Condition(String),
+    /// Swap the child's Success and Failure; Running passes through.
+    Inverter(Box<BehaviorNode>),
+    /// Run the child to Success `times` times, reporting Running in between
+    /// (0 = forever). A child Failure fails the repeat and resets the count.
+    Repeat { times: u32, child: Box<BehaviorNode> },
+    /// Once the child completes (Success or Failure), fail WITHOUT ticking it
+    /// for `seconds`. Gates bursts like a howl or a charge.
+    Cooldown { seconds: f32, child: Box<BehaviorNode> },
+    /// Fail the child if it has been Running continuously for longer than
+    /// `seconds`. A branch that was preempted restarts its clock.
+    Timeout { seconds: f32, child: Box<BehaviorNode> },
+}
+
+impl BehaviorNode {
+    /// Nodes in this subtree, counting itself (pre-order index span).
+    pub fn size(&self) -> u32 {
+        1 + self.children().map(BehaviorNode::size).sum::<u32>()
+    }
+
+    fn children(&self) -> Box<dyn Iterator<Item = &BehaviorNode> + '_> {
+        match self {
+            Self::Sequence(c) | Self::Selector(c) => Box::new(c.iter()),
+            Self::Inverter(c)
+            | Self::Repeat { child: c, .. }
+            | Self::Cooldown { child: c, .. }
+            | Self::Timeout { child: c, .. } => Box::new(std::iter::once(c.as_ref())),
+            Self::Action(_) | Self::Condition(_) => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Every leaf name in the tree as (is_action, name), for validation.
+    fn leaves<'a>(&'a self, out: &mut Vec<(bool, &'a str)>) {
+        match self {
+            Self::Action(n) => out.push((true, n)),
+            Self::Condition(n) => out.push((false, n)),
+            _ => self.children().for_each(|c| c.leaves(out)),
+        }
+    }
+}
+
+// ── Leaf registry ───────────────────────────────────────────────────────
+
+/// A named action leaf: may run over several ticks (returns Running).
+pub type ActionFn<C> = fn(&mut C, &mut Blackboard) -> BehaviorStatus;
+/// A named condition leaf: instant yes/no. Takes the blackboard mutably so a
+/// "find X" condition can remember what it found for the actions after it.
+pub type ConditionFn<C> = fn(&mut C, &mut Blackboard) -> bool;
+
+/// The named leaves a tree may reference, for one context type `C`.
+pub struct LeafRegistry<C> {
+    actions: HashMap<&'static str, ActionFn<C>>,
+    conditions: HashMap<&'static str, ConditionFn<C>>,
+}
+
+impl<C> Default for LeafRegistry<C> {
+    fn default() -> Self {
+        Self {
+            actions: HashMap::new(),
+            conditions: HashMap::new(),
+        }
+    }
+}
+
+impl<C> LeafRegistry<C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn action(mut self, name: &'static str, f: ActionFn<C>) -> Self {
+        self.actions.insert(name, f);
+        self
+    }
+
+    pub fn condition(mut self, name: &'static str, f: ConditionFn<C>) -> Self {
+        self.conditions.insert(name, f);
+        self
+    }
+
+    pub fn has_action(&self, name: &str) -> bool {
+        self.actions.contains_key(name)
+    }
+
+    pub fn has_condition(&self, name: &str) -> bool {
+        self.conditions.contains_key(name)
+    }
+}
+
+// ── Interpreter ─────────────────────────────────────────────────────────
+
+/// Tick `tree` once for one entity. Advances the blackboard's clock by `dt`
+/// first, so decorator timing is in the same seconds the caller simulates.
+/// An unknown leaf name fails (the registry load already warned about it).
+pub fn tick_tree<C>(
+    tree: &BehaviorNode,
+    leaves: &LeafRegistry<C>,
+    ctx: &mut C,
+    bb: &mut Blackboard,
+    dt: f32,
+) -> BehaviorStatus {
+    bb.tick += 1;
+    bb.clock += dt;
+    tick_node(tree, 0, leaves, ctx, bb)
+}
+
+fn tick_node<C>(
+    node: &BehaviorNode,
+    id: u32,
+    leaves: &LeafRegistry<C>,
+    ctx: &mut C,
+    bb: &mut Blackboard,
+) -> BehaviorStatus {
+    use BehaviorStatus::*;
+    match node {
+        BehaviorNode::Sequence(children) | BehaviorNode::Selector(children) => {
+            // Sequence stops on the first non-Success, Selector on the first
+            // non-Failure; whatever stopped it is the composite's result.
+            let stop_on = if matches!(node, BehaviorNode::Sequence(_)) { Success } else { Failure };
+            let mut child_id = id + 1;
+            for child in children {
+                let status = tick_node(child, child_id, leaves, ctx, bb);
+                if status != stop_on {
+                    return status;
+                }
+                child_id += child.size();
+            }
+            stop_on
+        }
+        BehaviorNode::Action(name) => match leaves.actions.get(name.as_str()) {
+            Some(f) => f(ctx, bb),
+            None => Failure,
+        },
+        BehaviorNode::Condition(name) => match leaves.conditions.get(name.as_str()) {
+            Some(f) if f(ctx, bb) => Success,
+            _ => Failure,
+        },
+        BehaviorNode::Inverter(child) => match tick_node(child, id + 1, leaves, ctx, bb) {
+            Success => Failure,
+            Failure => Success,
+            Running => Running,
+        },
+        BehaviorNode::Repeat { times, child } => {
+            let status = tick_node(child, id + 1, leaves, ctx, bb);
+            let mem = bb.nodes.entry(id).or_default();
+            match status {
+                Running => Running,
+                Failure => {
+                    mem.count = 0;
+                    Failure
+                }
+                Success => {
+                    mem.count += 1;
+                    if *times > 0 && mem.count >= *times {
+                        mem.count = 0;
+                        Success
+                    } else {
+                        Running
+                    }
+                }
+            }
+        }
+        BehaviorNode::Cooldown { seconds, child } => {
+            let clock = bb.clock;
+            if bb.nodes.get(&id).is_some_and(|m| clock < m.ready_at) {
+                return Failure;
+            }
+            let status = tick_node(child, id + 1, leaves, ctx, bb);
+            if status != Running {
+                bb.nodes.entry(id).or_default().ready_at = clock + seconds;
+            }
+            status
+        }
+        BehaviorNode::Timeout { seconds, child } => {
+            let (tick, clock) = (bb.tick, bb.clock);
+            let mem = bb.nodes.entry(id).or_default();
+            // Not ticked last frame (preempted, or finished) = a fresh run.
+            if mem.last_tick == 0 || mem.last_tick + 1 != tick {
+                mem.started = clock;
+            }
+            mem.last_tick = tick;
+            if clock - mem.started > *seconds {
+                // Force a fresh start the next time this branch is entered.
+                mem.last_tick = 0;
+                return Failure;
+            }
+            let status = tick_node(child, id + 1, leaves, ctx, bb);
+            if status != Running {
+                if let Some(m) = bb.nodes.get_mut(&id) {
+                    m.last_tick = 0;
+                }
+            }
+            status
+        }
+    }
+}
+
+// ── Registry (data/behaviors.ron) ──────────────────────────────────────
+
+/// All trees, keyed by the `AIBehavior::behavior_type` they drive.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BehaviorTreeRegistry {
+    pub trees: HashMap<String, BehaviorNode>,
+}
+
+impl BehaviorTreeRegistry {
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, String> {
+        let text =
+            std::str::from_utf8(bytes).map_err(|e| format!("behaviors.ron not UTF-8: {e}"))?;
+        ron::from_str(text).map_err(|e| format!("behaviors.ron parse error: {e}"))
+    }
+
+    /// The embedded copy of `data/behaviors.ron`. AISystem falls back to it
+    /// when no registry was loaded (headless tests, a broken modded file), so
+    /// creatures always run the shipped trees rather than standing still.
+    pub fn builtin() -> Self {
+        Self::from_ron(crate::embedded_data::BEHAVIORS_RON.as_bytes()).unwrap_or_else(|e| {
+            log::warn!("embedded {e}");
+            Self::default()
+        })
+    }
+
+    pub fn get(&self, behavior_type: &str) -> Option<&BehaviorNode> {
+        self.trees.get(behavior_type)
+    }
+
+    pub fn len(&self) -> usize {
+        self.trees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trees.is_empty()
+    }
+
+    /// Every leaf a tree names that `leaves` does not provide, as
+    /// "tree: Action(name)" lines (sorted, so the log reads stably).
+    pub fn unknown_leaves<C>(&self, leaves: &LeafRegistry<C>) -> Vec<String> {
+        let mut out = Vec::new();
+        for (tree_name, root) in &self.trees {
+            let mut names = Vec::new();
+            root.leaves(&mut names);
+            for (is_action, name) in names {
+                let known = if is_action {
+                    leaves.has_action(name)
+                } else {
+                    leaves.has_condition(name)
+                };
+                if !known {
+                    let kind = if is_action { "Action" } else { "Condition" };
+                    out.push(format!("{tree_name}: {kind}(\"{name}\")"));
+                }
+            }
+        }
+        out.sort();
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Toy context: a counter the leaves bump, plus a switch the condition reads.
+    #[derive(Default)]
+    struct Ctx {
+        calls: u32,
+        flag: bool,
+    }
+
+    fn leaves() -> LeafRegistry<Ctx> {
+        LeafRegistry::<Ctx>::new()
+            .action("ok", |c, _| {
+                c.calls += 1;
+                BehaviorStatus::Success
+            })
+            .action("busy", |c, _| {
+                c.calls += 1;
+                BehaviorStatus::Running
+            })
+            .condition("flag", |c, _| c.flag)
+    }
+
+    fn act(n: &str) -> BehaviorNode {
+        BehaviorNode::Action(n.to_string())
+    }
+
+    #[test]
+    fn composites_are_reactive() {
+        // Selector(Sequence(flag, ok), busy): busy runs until flag flips, then
+        // the higher-priority branch takes over on the very next tick.
+        let tree = BehaviorNode::Selector(vec![
+            BehaviorNode::Sequence(vec![BehaviorNode::Condition("flag".into()), act("ok")]),
+            act("busy"),
+        ]);
+        let (reg, mut bb) = (leaves(), Blackboard::default());
+        let mut ctx = Ctx::default();
+        assert_eq!(tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Running);
+        ctx.flag = true;
+        assert_eq!(tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Success);
+    }
+
+    #[test]
+    fn inverter_and_unknown_leaf() {
+        let (reg, mut bb, mut ctx) = (leaves(), Blackboard::default(), Ctx::default());
+        let inv = BehaviorNode::Inverter(Box::new(BehaviorNode::Condition("flag".into())));
+        assert_eq!(tick_tree(&inv, &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Success);
+        assert_eq!(tick_tree(&act("nope"), &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Failure);
+    }
+
+    #[test]
+    fn repeat_counts_successes() {
+        let tree = BehaviorNode::Repeat { times: 3, child: Box::new(act("ok")) };
+        let (reg, mut bb, mut ctx) = (leaves(), Blackboard::default(), Ctx::default());
+        let statuses: Vec<_> =
+            (0..4).map(|_| tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1)).collect();
+        use BehaviorStatus::*;
+        assert_eq!(statuses, vec![Running, Running, Success, Running]);
+    }
+
+    #[test]
+    fn cooldown_blocks_until_elapsed() {
+        let tree = BehaviorNode::Cooldown { seconds: 1.0, child: Box::new(act("ok")) };
+        let (reg, mut bb, mut ctx) = (leaves(), Blackboard::default(), Ctx::default());
+        assert_eq!(tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Success);
+        for _ in 0..9 {
+            assert_eq!(tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Failure);
+        }
+        assert_eq!(ctx.calls, 1, "the child is not ticked while cooling down");
+        tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.2);
+        assert_eq!(ctx.calls, 2);
+    }
+
+    #[test]
+    fn timeout_fails_a_long_run_and_restarts_after_preemption() {
+        let tree = BehaviorNode::Timeout { seconds: 0.5, child: Box::new(act("busy")) };
+        let (reg, mut bb, mut ctx) = (leaves(), Blackboard::default(), Ctx::default());
+        let mut last = BehaviorStatus::Running;
+        for _ in 0..10 {
+            last = tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1);
+            if last == BehaviorStatus::Failure {
+                break;
+            }
+        }
+        assert_eq!(last, BehaviorStatus::Failure, "timed out");
+        // Next entry starts a fresh clock.
+        assert_eq!(tick_tree(&tree, &reg, &mut ctx, &mut bb, 0.1), BehaviorStatus::Running);
+    }
+
+    #[test]
+    fn shipped_trees_parse_and_use_only_known_leaves() {
+        let reg = BehaviorTreeRegistry::builtin();
+        assert!(!reg.is_empty(), "data/behaviors.ron parses");
+        for kind in ["passive", "aggressive", "herd", "predator", "guard", "stationary"] {
+            assert!(reg.get(kind).is_some(), "tree for {kind}");
+        }
+        let unknown = reg.unknown_leaves(&super::super::creature_leaves());
+        assert!(unknown.is_empty(), "unknown leaves: {unknown:?}");
+    }
 }
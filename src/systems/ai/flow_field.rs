@@ -1,16 +1,168 @@
 //! Flow field pathfinding — supports million-agent navigation.
 //!
-//! Grid parameters loaded from `config/flow_field.toml`.
+//! One field answers "which way to the goal" for EVERY cell at once, so a
+//! herd or a pack shares a single bake instead of one search per animal.
+//! Three layers live on one XZ grid (row-major, cell (0,0) at `origin`):
+//! - cost field: 1 = open ground, higher = slow going (water), `IMPASSABLE`
+//!   = wall or machine. Built from `NavObstacles`: the home's wall segments
+//!   (the same `wall_collision` spans the player collides with, so doorways
+//!   are gaps), machine footprints, and water discs.
+//! - integration field: Dijkstra distance to the nearest goal cell.
+//! - direction field: unit vector toward the cheapest neighbour.
+//!
+//! Changing a cost after a bake marks the cell dirty; `rebake` then repairs
+//! only the part of the integration field whose paths ran through it instead
+//! of re-running Dijkstra over the whole grid.
+//!
+//! Grid parameters loaded from `data/ai/flow_field.toml`.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
 
 use glam::Vec2;
+use serde::Deserialize;
+
+use crate::ship::wall_collision::WallSegment;
+
+/// Cost of a cell nothing may enter.
+pub const IMPASSABLE: u8 = u8::MAX;
+const NO_PARENT: u32 = u32::MAX;
+/// The 8 neighbour offsets; the first four are orthogonal.
+const NEIGHBOURS: [(i32, i32); 8] =
+    [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+/// Tunables from `data/ai/flow_field.toml`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FlowFieldConfig {
+    /// Cell edge in metres. A quarter metre keeps a 1 m doorway open (two
+    /// cell centres) after its piers are inflated by `clearance_m`.
+    pub cell_size: f32,
+    /// Open ground kept around the obstacles' bounding box (metres).
+    pub margin_m: f32,
+    /// Grid size cap; a larger obstacle spread coarsens nothing, it simply
+    /// falls back to direct steering.
+    pub max_cells: u32,
+    /// Cost of a water cell (open ground = 1): creatures wade rather than
+    /// swim laps, but still cross a stream when it is the only way.
+    pub water_cost: u8,
+    /// Agent radius: walls and machines are inflated by this much.
+    pub clearance_m: f32,
+    /// A cached field is reused while its goal stays within this distance.
+    pub goal_snap_m: f32,
+    /// Inside this range of the goal, steer straight at it.
+    pub direct_range_m: f32,
+    /// Baked fields kept at once (least recently used is dropped).
+    pub max_fields: usize,
+}
+
+impl Default for FlowFieldConfig {
+    fn default() -> Self {
+        Self {
+            cell_size: 0.25,
+            margin_m: 20.0,
+            max_cells: 400_000,
+            water_cost: 6,
+            clearance_m: 0.2,
+            goal_snap_m: 2.0,
+            direct_range_m: 2.0,
+            max_fields: 8,
+        }
+    }
+}
+
+impl FlowFieldConfig {
+    pub fn from_toml(text: &str) -> Result<Self, String> {
+        #[derive(Deserialize)]
+        struct File {
+            #[serde(default)]
+            flow_field: FlowFieldConfig,
+        }
+        toml::from_str::<File>(text)
+            .map(|f| f.flow_field)
+            .map_err(|e| format!("flow_field.toml parse error: {e}"))
+    }
+
+    /// The embedded copy (defaults if it somehow fails to parse).
+    pub fn builtin() -> Self {
+        Self::from_toml(crate::embedded_data::AI_FLOW_FIELD_TOML).unwrap_or_default()
+    }
+}
+
+/// The world geometry creatures path around, published on the DataStore as
+/// `"nav_obstacles"` (`Mutex<NavObstacles>`) by the engine whenever the home
+/// or its machines change. `version` bumps whenever the geometry really
+/// changes, so AISystem can tell a new world from the one it already baked.
+#[derive(Debug, Clone, Default)]
+pub struct NavObstacles {
+    pub version: u64,
+    /// Blocking wall spans (XZ), doorways already cut out.
+    pub walls: Vec<WallSegment>,
+    /// Solid footprints (machines), as XZ (min, max) boxes.
+    pub solids: Vec<(Vec2, Vec2)>,
+    /// Slow ground (water), as XZ (centre, radius) discs.
+    pub water: Vec<(Vec2, f32)>,
+}
+
+impl NavObstacles {
+    /// Replace the walls; bumps `version` only on a real change, so a
+    /// per-frame editor drag that moves nothing costs no re-bake.
+    pub fn set_walls(&mut self, walls: Vec<WallSegment>) {
+        if self.walls != walls {
+            self.walls = walls;
+            self.version += 1;
+        }
+    }
+
+    pub fn set_solids(&mut self, solids: Vec<(Vec2, Vec2)>) {
+        if self.solids != solids {
+            self.solids = solids;
+            self.version += 1;
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.walls.is_empty() && self.solids.is_empty() && self.water.is_empty()
+    }
+
+    /// XZ bounding box of everything, or None when there is nothing to avoid.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        let points = self
+            .walls
+            .iter()
+            .flat_map(|w| [Vec2::new(w.a.0, w.a.1), Vec2::new(w.b.0, w.b.1)])
+            .chain(self.solids.iter().flat_map(|(lo, hi)| [*lo, *hi]))
+            .chain(
+                self.water
+                    .iter()
+                    .flat_map(|(c, r)| [*c - Vec2::splat(*r), *c + Vec2::splat(*r)]),
+            );
+        points.fold(None, |acc, p| match acc {
+            None => Some((p, p)),
+            Some((lo, hi)) => Some((lo.min(p), hi.max(p))),
+        })
+    }
+}
 
 /// A flow field for mass agent navigation on a 2D grid.
+#[derive(Debug, Clone)]
 pub struct FlowField {
     pub width: u32,
     pub height: u32,
     pub cell_size: f32,
+    /// World XZ of cell (0,0)'s lower corner.
+    pub origin: Vec2,
+    /// Entry cost per cell (1 = open, `IMPASSABLE` = blocked).
+    pub costs: Vec<u8>,
+    /// Distance to the nearest goal (`f32::INFINITY` = unreachable).
+    pub integration: Vec<f32>,
     /// Direction vectors per cell (flattened row-major).
     pub directions: Vec<Vec2>,
+    /// Next cell on each cell's best path (`NO_PARENT` at goals / unreached).
+    parents: Vec<u32>,
+    goals: Vec<usize>,
+    /// Cells whose cost changed since the last bake, with their old cost.
+    dirty: Vec<(usize, u8)>,
 }
 
 impl FlowField {
@@ -20,7 +172,665 @@ impl FlowField {
             width,
             height,
             cell_size,
+            origin: Vec2::ZERO,
+            costs: vec![1; count],
+            integration: vec![f32::INFINITY; count],
             directions: vec![Vec2::ZERO; count],
+            parents: vec![NO_PARENT; count],
+            goals: Vec::new(),
+            dirty: Vec::new(),
+        }
+    }
+
+    pub fn with_origin(mut self, origin: Vec2) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// A grid covering `bounds` plus the configured margin, with the
+    /// obstacles rasterised into its cost field. None when there is nothing
+    /// to avoid or the area would exceed `max_cells`.
+    pub fn from_obstacles(obstacles: &NavObstacles, cfg: &FlowFieldConfig) -> Option<Self> {
+        let (lo, hi) = obstacles.bounds()?;
+        let (lo, hi) = (lo - Vec2::splat(cfg.margin_m), hi + Vec2::splat(cfg.margin_m));
+        let cs = cfg.cell_size.max(0.05);
+        let (w, h) = (((hi.x - lo.x) / cs).ceil() as u32, ((hi.y - lo.y) / cs).ceil() as u32);
+        if w == 0 || h == 0 || w.saturating_mul(h) > cfg.max_cells {
+            return None;
+        }
+        let mut field = Self::new(w, h, cs).with_origin(lo);
+        field.rasterise(obstacles, cfg);
+        Some(field)
+    }
+
+    /// Write every obstacle into the cost field (water first, so a machine
+    /// standing in a pond still blocks).
+    pub fn rasterise(&mut self, obstacles: &NavObstacles, cfg: &FlowFieldConfig) {
+        for (centre, radius) in &obstacles.water {
+            self.add_cost_disc(*centre, *radius, cfg.water_cost);
+        }
+        for wall in &obstacles.walls {
+            self.block_segment(
+                Vec2::new(wall.a.0, wall.a.1),
+                Vec2::new(wall.b.0, wall.b.1),
+                wall.half_thickness + cfg.clearance_m,
+            );
+        }
+        for (lo, hi) in &obstacles.solids {
+            let pad = Vec2::splat(cfg.clearance_m);
+            self.block_rect(*lo - pad, *hi + pad);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// Same grid (size, cell, origin) as `other`, so costs can be diffed.
+    pub fn same_grid(&self, other: &FlowField) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.cell_size == other.cell_size
+            && self.origin == other.origin
+    }
+
+    pub fn cell_at(&self, p: Vec2) -> Option<usize> {
+        let local = (p - self.origin) / self.cell_size;
+        if local.x < 0.0 || local.y < 0.0 {
+            return None;
+        }
+        let (x, y) = (local.x as u32, local.y as u32);
+        (x < self.width && y < self.height).then(|| self.index(x, y))
+    }
+
+    pub fn cell_center(&self, i: usize) -> Vec2 {
+        let (x, y) = self.coords(i);
+        self.origin + (Vec2::new(x as f32, y as f32) + 0.5) * self.cell_size
+    }
+
+    fn index(&self, x: u32, y: u32) -> usize {
+        (y * self.width + x) as usize
+    }
+
+    fn coords(&self, i: usize) -> (u32, u32) {
+        (i as u32 % self.width, i as u32 / self.width)
+    }
+
+    fn offset(&self, i: usize, dx: i32, dy: i32) -> Option<usize> {
+        let (x, y) = self.coords(i);
+        let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+        (nx >= 0 && ny >= 0 && (nx as u32) < self.width && (ny as u32) < self.height)
+            .then(|| self.index(nx as u32, ny as u32))
+    }
+
+    pub fn passable(&self, i: usize) -> bool {
+        self.costs[i] != IMPASSABLE
+    }
+
+    /// Whether a creature may stand at world point `p` (outside the grid
+    /// counts as open ground).
+    pub fn walkable(&self, p: Vec2) -> bool {
+        self.cell_at(p).is_none_or(|i| self.passable(i))
+    }
+
+    // ── Cost field ──────────────────────────────────────────────────────
+
+    /// Set one cell's cost. After a bake the change is queued for `rebake`.
+    pub fn set_cost(&mut self, i: usize, cost: u8) {
+        let old = self.costs[i];
+        if old == cost {
+            return;
+        }
+        if !self.goals.is_empty() {
+            self.dirty.push((i, old));
+        }
+        self.costs[i] = cost.max(1);
+    }
+
+    /// Block every cell whose centre lies within `radius` of segment a-b.
+    pub fn block_segment(&mut self, a: Vec2, b: Vec2, radius: f32) {
+        let (lo, hi) = (a.min(b) - Vec2::splat(radius), a.max(b) + Vec2::splat(radius));
+        for i in self.cells_in(lo, hi) {
+            if distance_to_segment(self.cell_center(i), a, b) <= radius {
+                self.set_cost(i, IMPASSABLE);
+            }
+        }
+    }
+
+    /// Block every cell whose centre lies inside the box.
+    pub fn block_rect(&mut self, lo: Vec2, hi: Vec2) {
+        for i in self.cells_in(lo, hi) {
+            let c = self.cell_center(i);
+            if c.x >= lo.x && c.x <= hi.x && c.y >= lo.y && c.y <= hi.y {
+                self.set_cost(i, IMPASSABLE);
+            }
+        }
+    }
+
+    /// Raise open cells inside a disc to at least `cost`.
+    pub fn add_cost_disc(&mut self, centre: Vec2, radius: f32, cost: u8) {
+        let r = Vec2::splat(radius);
+        for i in self.cells_in(centre - r, centre + r) {
+            if self.passable(i) && self.costs[i] < cost && self.cell_center(i).distance(centre) <= radius
+            {
+                self.set_cost(i, cost);
+            }
+        }
+    }
+
+    /// Indices of the cells overlapping a world box (clamped to the grid).
+    fn cells_in(&self, lo: Vec2, hi: Vec2) -> Vec<usize> {
+        let to_cell = |p: Vec2| ((p - self.origin) / self.cell_size).floor();
+        let (a, b) = (to_cell(lo), to_cell(hi));
+        let clamp_x = |v: f32| v.clamp(0.0, self.width as f32 - 1.0) as u32;
+        let clamp_y = |v: f32| v.clamp(0.0, self.height as f32 - 1.0) as u32;
+        if b.x < 0.0 || b.y < 0.0 || a.x >= self.width as f32 || a.y >= self.height as f32 {
+            return Vec::new();
+        }
+        let mut out = Vec::new();
+        for y in clamp_y(a.y)..=clamp_y(b.y) {
+            for x in clamp_x(a.x)..=clamp_x(b.x) {
+                out.push(self.index(x, y));
+            }
+        }
+        out
+    }
+
+    // ── Integration + directions ────────────────────────────────────────
+
+    /// Bake the integration and direction fields toward `goals` (full
+    /// Dijkstra). A goal inside a wall snaps to the nearest open cell; goals
+    /// off the grid are ignored. Returns false when no goal landed.
+    pub fn bake(&mut self, goals: &[Vec2]) -> bool {
+        self.goals = goals
+            .iter()
+            .filter_map(|g| self.cell_at(*g))
+            .filter_map(|i| self.nearest_passable(i, 4))
+            .collect();
+        self.goals.sort_unstable();
+        self.goals.dedup();
+        self.dirty.clear();
+        self.integration.fill(f32::INFINITY);
+        self.parents.fill(NO_PARENT);
+        let mut heap = BinaryHeap::new();
+        for &g in &self.goals {
+            self.integration[g] = 0.0;
+            heap.push(Open { dist: 0.0, cell: g });
+        }
+        self.relax(heap, None);
+        for i in 0..self.len() {
+            self.directions[i] = self.direction_of(i);
+        }
+        !self.goals.is_empty()
+    }
+
+    /// Repair the fields after `set_cost` changes since the last bake.
+    /// Cells whose cost rose invalidate every cell whose best path ran
+    /// through them (their parent-chain subtree); cells whose cost fell seed
+    /// improvements from their neighbours. Only touched cells get new
+    /// directions. Returns false when there was nothing to repair.
+    pub fn rebake(&mut self) -> bool {
+        if self.dirty.is_empty() || self.goals.is_empty() {
+            return false;
+        }
+        let dirty = std::mem::take(&mut self.dirty);
+        let mut raised: Vec<usize> = Vec::new();
+        let mut lowered: Vec<usize> = Vec::new();
+        for &(i, old) in &dirty {
+            if self.costs[i] > old {
+                raised.push(i);
+                // A new wall also removes the diagonal shortcuts around it.
+                if !self.passable(i) {
+                    raised.extend(NEIGHBOURS.iter().filter_map(|&(dx, dy)| self.offset(i, dx, dy)));
+                }
+            } else {
+                lowered.push(i);
+            }
+        }
+
+        // Invalidate the raised cells' subtrees (children = cells pointing at them).
+        let mut touched: Vec<usize> = Vec::new();
+        if !raised.is_empty() {
+            let mut children: Vec<Vec<u32>> = vec![Vec::new(); self.len()];
+            for (c, &p) in self.parents.iter().enumerate() {
+                if p != NO_PARENT {
+                    children[p as usize].push(c as u32);
+                }
+            }
+            let mut invalid = vec![false; self.len()];
+            let mut stack = raised;
+            while let Some(i) = stack.pop() {
+                if std::mem::replace(&mut invalid[i], true) {
+                    continue;
+                }
+                touched.push(i);
+                stack.extend(children[i].iter().map(|&c| c as usize));
+            }
+            for &i in &touched {
+                self.integration[i] = f32::INFINITY;
+                self.parents[i] = NO_PARENT;
+            }
+        }
+
+        // Seeds: goals that lost their value, plus every settled neighbour
+        // of an invalidated or cheapened cell.
+        let mut heap = BinaryHeap::new();
+        self.goals.retain(|&g| self.costs[g] != IMPASSABLE);
+        for &g in &self.goals {
+            if self.integration[g] != 0.0 {
+                self.integration[g] = 0.0;
+                heap.push(Open { dist: 0.0, cell: g });
+            }
+        }
+        for &i in touched.iter().chain(&lowered) {
+            for &(dx, dy) in &NEIGHBOURS {
+                if let Some(n) = self.offset(i, dx, dy) {
+                    if self.integration[n].is_finite() {
+                        heap.push(Open { dist: self.integration[n], cell: n });
+                    }
+                }
+            }
+        }
+        touched.extend(lowered);
+        self.relax(heap, Some(&mut touched));
+
+        let mut redo: Vec<usize> = Vec::with_capacity(touched.len() * 9);
+        for &i in &touched {
+            redo.push(i);
+            redo.extend(NEIGHBOURS.iter().filter_map(|&(dx, dy)| self.offset(i, dx, dy)));
+        }
+        redo.sort_unstable();
+        redo.dedup();
+        for i in redo {
+            self.directions[i] = self.direction_of(i);
+        }
+        true
+    }
+
+    /// Label-correcting Dijkstra from whatever is on the heap. Cells whose
+    /// value improves are appended to `touched` when given.
+    fn relax(&mut self, mut heap: BinaryHeap<Open>, mut touched: Option<&mut Vec<usize>>) {
+        while let Some(Open { dist, cell }) = heap.pop() {
+            if dist > self.integration[cell] {
+                continue;
+            }
+            for (k, &(dx, dy)) in NEIGHBOURS.iter().enumerate() {
+                let Some(n) = self.offset(cell, dx, dy) else { continue };
+                if !self.passable(n) || (k >= 4 && !self.diagonal_open(cell, dx, dy)) {
+                    continue;
+                }
+                let step = if k >= 4 { std::f32::consts::SQRT_2 } else { 1.0 };
+                let nd = dist + step * self.costs[n] as f32;
+                if nd < self.integration[n] {
+                    self.integration[n] = nd;
+                    self.parents[n] = cell as u32;
+                    if let Some(t) = touched.as_deref_mut() {
+                        t.push(n);
+                    }
+                    heap.push(Open { dist: nd, cell: n });
+                }
+            }
+        }
+    }
+
+    /// No corner cutting: a diagonal step needs both orthogonal cells open.
+    fn diagonal_open(&self, i: usize, dx: i32, dy: i32) -> bool {
+        let open = |n: Option<usize>| n.is_some_and(|n| self.passable(n));
+        open(self.offset(i, dx, 0)) && open(self.offset(i, 0, dy))
+    }
+
+    /// The neighbour with the lowest integration value, if better than `i`.
+    fn best_neighbour(&self, i: usize) -> Option<usize> {
+        let mut best = (self.integration[i], None);
+        for (k, &(dx, dy)) in NEIGHBOURS.iter().enumerate() {
+            let Some(n) = self.offset(i, dx, dy) else { continue };
+            if k >= 4 && !self.diagonal_open(i, dx, dy) {
+                continue;
+            }
+            if self.passable(n) && self.integration[n] < best.0 {
+                best = (self.integration[n], Some(n));
+            }
+        }
+        best.1
+    }
+
+    fn direction_of(&self, i: usize) -> Vec2 {
+        match self.best_neighbour(i) {
+            Some(n) => (self.cell_center(n) - self.cell_center(i)).normalize_or_zero(),
+            None => Vec2::ZERO,
+        }
+    }
+
+    /// Closest open cell to `i` within `radius` cells (ring search).
+    fn nearest_passable(&self, i: usize, radius: i32) -> Option<usize> {
+        if self.passable(i) {
+            return Some(i);
+        }
+        for r in 1..=radius {
+            let ring = (-r..=r)
+                .flat_map(|dx| (-r..=r).map(move |dy| (dx, dy)))
+                .filter(|(dx, dy)| dx.abs() == r || dy.abs() == r);
+            let here = self.cell_center(i);
+            let best = ring
+                .filter_map(|(dx, dy)| self.offset(i, dx, dy))
+                .filter(|&n| self.passable(n))
+                .min_by(|&a, &b| {
+                    let (da, db) = (self.cell_center(a).distance(here), self.cell_center(b).distance(here));
+                    da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+                });
+            if best.is_some() {
+                return best;
+            }
+        }
+        None
+    }
+
+    // ── Queries ─────────────────────────────────────────────────────────
+
+    /// Which way to walk from world point `p`: toward the centre of the
+    /// cell's best neighbour (smoother than the cell's 8-way direction).
+    /// None off the grid, at a goal, or where no goal is reachable.
+    pub fn steer(&self, p: Vec2) -> Option<Vec2> {
+        let i = self.cell_at(p)?;
+        if !self.integration[i].is_finite() {
+            return None;
+        }
+        let n = self.best_neighbour(i)?;
+        Some((self.cell_center(n) - p).normalize_or_zero())
+    }
+
+    /// Whether the straight walk a -> b crosses only open, unpenalised
+    /// cells (sampled every half cell). Clear lines skip the field entirely.
+    pub fn line_clear(&self, a: Vec2, b: Vec2) -> bool {
+        let steps = (a.distance(b) / (self.cell_size * 0.5)).ceil().max(1.0) as u32;
+        (0..=steps).all(|k| {
+            let p = a.lerp(b, k as f32 / steps as f32);
+            self.cell_at(p).is_none_or(|i| self.costs[i] == 1)
+        })
+    }
+
+    /// The open neighbouring direction that best matches `desired` (for
+    /// fleeing: keep running away, but slide along walls instead of into
+    /// them). Returns `desired` unchanged when its own step is open.
+    pub fn deflect(&self, p: Vec2, desired: Vec2) -> Vec2 {
+        let Some(i) = self.cell_at(p) else { return desired };
+        let ahead = p + desired.normalize_or_zero() * self.cell_size;
+        if self.walkable(ahead) {
+            return desired;
+        }
+        let mut best = (f32::MIN, Vec2::ZERO);
+        for (k, &(dx, dy)) in NEIGHBOURS.iter().enumerate() {
+            let Some(n) = self.offset(i, dx, dy) else { continue };
+            if !self.passable(n) || (k >= 4 && !self.diagonal_open(i, dx, dy)) {
+                continue;
+            }
+            let dir = Vec2::new(dx as f32, dy as f32).normalize();
+            let score = dir.dot(desired.normalize_or_zero());
+            if score > best.0 {
+                best = (score, dir);
+            }
+        }
+        best.1 * desired.length()
+    }
+}
+
+/// Heap entry: min-heap on distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Open {
+    dist: f32,
+    cell: usize,
+}
+
+impl Eq for Open {}
+
+impl Ord for Open {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist.partial_cmp(&self.dist).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Open {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// ── Navigator ───────────────────────────────────────────────────────────
+
+/// AISystem's pathfinding state: the cost grid built from the current
+/// `NavObstacles`, plus a small LRU of fields baked toward recent goals.
+/// Creatures chasing the same player or regrouping on the same herd centre
+/// share one field; a new world publish diffs the costs into every cached
+/// field and repairs it with `rebake` instead of throwing it away.
+#[derive(Debug, Default)]
+pub struct Navigator {
+    base: Option<FlowField>,
+    /// (obstacle version, water discs) the base grid was built from.
+    built_from: Option<(u64, Vec<(Vec2, f32)>)>,
+    /// (goal, field, last-used stamp).
+    fields: Vec<(Vec2, FlowField, u64)>,
+    stamp: u64,
+}
+
+impl Navigator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether there is anything to path around.
+    pub fn active(&self) -> bool {
+        self.base.is_some()
+    }
+
+    /// Bring the grid up to date with the published obstacles (plus
+    /// `water`, gathered from the world by the caller). Cheap when nothing
+    /// changed.
+    pub fn sync(&mut self, obstacles: Option<&NavObstacles>, water: &[(Vec2, f32)], cfg: &FlowFieldConfig) {
+        let version = obstacles.map_or(0, |o| o.version);
+        if self
+            .built_from
+            .as_ref()
+            .is_some_and(|(v, w)| *v == version && w.as_slice() == water)
+        {
+            return;
+        }
+        self.built_from = Some((version, water.to_vec()));
+        let mut merged = obstacles.cloned().unwrap_or_default();
+        merged.water.extend_from_slice(water);
+        let Some(base) = FlowField::from_obstacles(&merged, cfg) else {
+            self.base = None;
+            self.fields.clear();
+            return;
+        };
+        // Same grid: patch the changed cells into every cached field.
+        let same = self.base.as_ref().is_some_and(|old| old.same_grid(&base));
+        if same {
+            for (_, field, _) in &mut self.fields {
+                for i in 0..base.len() {
+                    field.set_cost(i, base.costs[i]);
+                }
+                field.rebake();
+            }
+        } else {
+            self.fields.clear();
+        }
+        self.base = Some(base);
+    }
+
+    /// Direction to walk from `from` toward `goal`. None = no field needed
+    /// (no obstacles, off the grid, close by, or a clear straight line);
+    /// `Vec2::ZERO` = the goal cannot be reached from here.
+    pub fn steer(&mut self, from: Vec2, goal: Vec2, cfg: &FlowFieldConfig) -> Option<Vec2> {
+        let base = self.base.as_ref()?;
+        base.cell_at(from)?;
+        base.cell_at(goal)?;
+        if from.distance(goal) <= cfg.direct_range_m || base.line_clear(from, goal) {
+            return None;
+        }
+        self.stamp += 1;
+        let stamp = self.stamp;
+        let slot = match self
+            .fields
+            .iter()
+            .position(|(g, _, _)| g.distance(goal) <= cfg.goal_snap_m)
+        {
+            Some(slot) => slot,
+            None => {
+                let mut field = base.clone();
+                field.bake(&[goal]);
+                if self.fields.len() >= cfg.max_fields.max(1) {
+                    let oldest = (0..self.fields.len()).min_by_key(|&k| self.fields[k].2).unwrap_or(0);
+                    self.fields.swap_remove(oldest);
+                }
+                self.fields.push((goal, field, stamp));
+                self.fields.len() - 1
+            }
+        };
+        let (_, field, used) = &mut self.fields[slot];
+        *used = stamp;
+        Some(field.steer(from).unwrap_or(Vec2::ZERO))
+    }
+
+    /// See `FlowField::deflect`; `desired` unchanged with no grid.
+    pub fn deflect(&self, p: Vec2, desired: Vec2) -> Vec2 {
+        self.base.as_ref().map_or(desired, |b| b.deflect(p, desired))
+    }
+
+    /// Clamp a step a -> b so it never ends inside a wall or machine: try
+    /// the full step, then each axis alone (sliding along the wall). A
+    /// creature already standing in a blocked cell may walk out.
+    pub fn constrain(&self, a: Vec2, b: Vec2) -> Vec2 {
+        let Some(base) = self.base.as_ref() else { return b };
+        if base.walkable(b) || !base.walkable(a) {
+            return b;
+        }
+        [Vec2::new(b.x, a.y), Vec2::new(a.x, b.y)]
+            .into_iter()
+            .find(|p| base.walkable(*p))
+            .unwrap_or(a)
+    }
+}
+
+fn distance_to_segment(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let t = if ab.length_squared() > 0.0 {
+        ((p - a).dot(ab) / ab.length_squared()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    p.distance(a + ab * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 20 x 20 m, 1 m cells, a wall across x = 10 with a 2 m gap at the top.
+    fn walled() -> FlowField {
+        let mut f = FlowField::new(20, 20, 1.0);
+        f.block_segment(Vec2::new(10.5, 0.0), Vec2::new(10.5, 16.0), 0.4);
+        f
+    }
+
+    /// Follow the field from `start`; returns the cells visited.
+    fn walk(f: &FlowField, start: Vec2, steps: usize) -> Vec<Vec2> {
+        let mut p = start;
+        let mut path = vec![p];
+        for _ in 0..steps {
+            let Some(d) = f.steer(p) else { break };
+            p += d * 0.5;
+            assert!(f.walkable(p), "walked into a wall at {p}");
+            path.push(p);
+        }
+        path
+    }
+
+    #[test]
+    fn path_goes_around_the_wall_through_the_gap() {
+        let mut f = walled();
+        assert!(f.bake(&[Vec2::new(15.5, 2.5)]));
+        let path = walk(&f, Vec2::new(5.5, 2.5), 200);
+        let end = *path.last().unwrap();
+        assert!(end.distance(Vec2::new(15.5, 2.5)) < 1.0, "reached the goal, ended at {end}");
+        assert!(path.iter().any(|p| p.y > 16.0), "detoured through the gap");
+    }
+
+    #[test]
+    fn unreachable_goal_gives_no_direction() {
+        let mut f = FlowField::new(10, 10, 1.0);
+        f.block_rect(Vec2::new(4.0, 0.0), Vec2::new(5.0, 10.0));
+        f.bake(&[Vec2::new(8.5, 5.5)]);
+        assert!(f.steer(Vec2::new(1.5, 5.5)).is_none());
+    }
+
+    #[test]
+    fn water_is_avoided_when_a_dry_route_is_short() {
+        let mut f = FlowField::new(20, 20, 1.0);
+        f.add_cost_disc(Vec2::new(10.0, 10.0), 3.0, 20);
+        f.bake(&[Vec2::new(18.5, 10.5)]);
+        let path = walk(&f, Vec2::new(1.5, 10.5), 200);
+        assert!(
+            path.iter().all(|p| p.distance(Vec2::new(10.0, 10.0)) > 2.0),
+            "went around the pond"
+        );
+    }
+
+    #[test]
+    fn incremental_rebake_matches_a_full_bake() {
+        let goal = [Vec2::new(15.5, 2.5)];
+        let mut inc = walled();
+        inc.bake(&goal);
+        // Close the gap, open a new one lower down, and add a pond.
+        inc.block_segment(Vec2::new(10.5, 16.0), Vec2::new(10.5, 20.0), 0.4);
+        for y in 6..9 {
+            let i = inc.cell_at(Vec2::new(10.5, y as f32 + 0.5)).unwrap();
+            inc.set_cost(i, 1);
         }
+        inc.add_cost_disc(Vec2::new(5.0, 12.0), 2.0, 6);
+        assert!(inc.rebake());
+
+        let mut full = inc.clone();
+        full.bake(&goal);
+        for i in 0..full.len() {
+            let (a, b) = (inc.integration[i], full.integration[i]);
+            assert!(
+                (a.is_infinite() && b.is_infinite()) || (a - b).abs() < 1e-3,
+                "cell {i}: incremental {a} vs full {b}"
+            );
+        }
+        assert!(!inc.rebake(), "nothing left to repair");
+        let path = walk(&inc, Vec2::new(5.5, 2.5), 200);
+        assert!(path.last().unwrap().distance(goal[0]) < 1.0);
+        assert!(path.iter().all(|p| p.y < 16.0), "used the new gap");
+    }
+
+    #[test]
+    fn obstacles_build_an_inflated_grid_with_doorways() {
+        // One 6 m wall with a door cut (two piers), as wall_collision emits it.
+        let obstacles = NavObstacles {
+            version: 1,
+            walls: vec![
+                WallSegment { a: (0.0, 0.0), b: (2.5, 0.0), half_thickness: 0.1 },
+                WallSegment { a: (3.5, 0.0), b: (6.0, 0.0), half_thickness: 0.1 },
+            ],
+            solids: vec![(Vec2::new(1.0, 3.0), Vec2::new(2.0, 4.0))],
+            water: Vec::new(),
+        };
+        let cfg = FlowFieldConfig { margin_m: 2.0, ..Default::default() };
+        let f = FlowField::from_obstacles(&obstacles, &cfg).expect("grid");
+        assert!(!f.walkable(Vec2::new(1.0, 0.0)), "pier blocks");
+        assert!(f.walkable(Vec2::new(3.0, 0.0)), "doorway stays open");
+        assert!(!f.walkable(Vec2::new(1.5, 3.5)), "machine blocks");
+        assert!(f.walkable(Vec2::new(1.5, -1.5)));
+    }
+
+    #[test]
+    fn shipped_config_parses() {
+        let cfg = FlowFieldConfig::from_toml(crate::embedded_data::AI_FLOW_FIELD_TOML).unwrap();
+        assert!(cfg.cell_size > 0.0 && cfg.max_fields > 0);
     }
 }
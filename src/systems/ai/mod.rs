@@ -1,8 +1,19 @@
-//! AI system — behavior state machine for NPCs and creatures.
+//! AI system — behavior trees for NPCs and creatures.
 //!
-//! Queries entities with `AIBehavior + Transform + Health` and runs a simple
-//! state machine driven by `behavior_type`. No pathfinding yet — movement is
-//! direct toward/away from targets.
+//! Queries entities with `AIBehavior + Transform + Health` and ticks the
+//! behavior tree named by `behavior_type` (data/behaviors.ron, loaded as the
+//! `"behavior_tree_registry"`; the embedded copy stands in when absent). The
+//! tree's leaves are the named conditions/actions in `creature_leaves()`,
+//! which wrap the wander / flee / hunt / attack logic this system always had.
+//! Each AI entity gets a `Blackboard` component holding its target, wander
+//! destination, timers and decorator memory.
+//!
+//! Pathfinding: leaves that head somewhere (hunt, pursue, regroup, wander)
+//! record a goal; when the straight line to it is blocked, the creature
+//! follows a flow field baked around the walls, machines and water the
+//! engine publishes as `"nav_obstacles"`. Fleeing slides along walls, and
+//! no step may end inside one. With nothing published, movement stays
+//! direct.
 //!
 //! Registered live in v0.761 (combat arc increment 2). Predators now count
 //! the PLAYER as prey, the `attacking` state lands real bites through the
@@ -10,20 +21,25 @@
 //! and the system integrates its own movement (position += velocity * dt)
 //! so hostiles actually close distance.
 //!
-//! Behavior tree node types live in `behavior.rs` (data-driven RON format).
-//! Flow field pathfinding in `flow_field.rs` (future integration).
+//! Behavior tree interpreter + registry live in `behavior.rs`.
+//! Flow field grid, obstacle rasterising + goal cache in `flow_field.rs`.
 //! Off-screen autonomy in `autonomy.rs`.
 
 pub mod behavior;
 pub mod flow_field;
 
-use glam::Vec3;
+use glam::{Vec3, Vec3Swizzles};
 use rand::Rng;
 
-use crate::ecs::components::{AIBehavior, Controllable, Faction, Health, Transform, Velocity};
+use crate::ecs::components::{
+    AIBehavior, Blackboard, Controllable, Faction, Health, Transform, Velocity,
+};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
 
+use behavior::{BehaviorStatus, BehaviorTreeRegistry, LeafRegistry};
+use flow_field::{FlowFieldConfig, NavObstacles, Navigator};
+
 /// How close an entity must be to a target to count as "in range" (meters).
 const ATTACK_RANGE: f32 = 5.0;
 /// Detection radius for finding targets (meters).
@@ -46,23 +62,33 @@ const BITE_COOLDOWN_S: f32 = 1.5;
 const BITE_DAMAGE_FRACTION: f32 = 0.15;
 const BITE_DAMAGE_CAP: f32 = 25.0;
 
-/// Behavior state machine runner for all AI-controlled entities.
+/// Behavior tree runner for all AI-controlled entities.
 pub struct AISystem {
-    /// Per-entity timers keyed by entity id bits. Used for wander cooldowns,
-    /// rest timers, etc. Cleaned up lazily when entities despawn.
-    timers: std::collections::HashMap<u64, f32>,
-    /// Per-entity wander targets.
-    wander_targets: std::collections::HashMap<u64, Vec3>,
+    /// The named leaves every tree may reference.
+    leaves: LeafRegistry<AiContext>,
+    /// Embedded trees, used when the DataStore carries no registry.
+    builtin: BehaviorTreeRegistry,
     /// Per-entity seconds until the next bite lands. (v0.761)
     bite_timers: std::collections::HashMap<u64, f32>,
+    /// Flow fields around the published obstacles.
+    nav: Navigator,
+    /// Embedded grid parameters, used when the DataStore carries none.
+    nav_config: FlowFieldConfig,
 }
 
 impl AISystem {
     pub fn new() -> Self {
+        let leaves = creature_leaves();
+        let builtin = BehaviorTreeRegistry::builtin();
+        for missing in builtin.unknown_leaves(&leaves) {
+            log::warn!("behaviors.ron names an unknown leaf: {missing}");
+        }
         Self {
-            timers: std::collections::HashMap::new(),
-            wander_targets: std::collections::HashMap::new(),
+            leaves,
+            builtin,
             bite_timers: std::collections::HashMap::new(),
+            nav: Navigator::new(),
+            nav_config: FlowFieldConfig::builtin(),
         }
     }
 }
@@ -93,33 +119,101 @@ impl System for AISystem {
                 max_health: hp.max,
                 faction: faction.map(|f| f.id.clone()),
                 behavior_type: ai.map(|a| a.behavior_type.clone()),
-                state: ai.map(|a| a.state.clone()),
                 alive: hp.current > 0.0,
                 is_player: player.is_some(),
                 name: name.map(|n| n.0.clone()),
             })
             .collect();
 
-        // ── 2. Decide new state + velocity for each AI entity ───────────
+        // ── 2. Refresh the flow-field grid if the world changed ──────────
+        let nav_config = data
+            .get::<FlowFieldConfig>("flow_field_config")
+            .unwrap_or(&self.nav_config);
+        // Positioned water bodies (surface area -> disc radius) count as slow
+        // ground on top of whatever the engine published.
+        let water: Vec<(glam::Vec2, f32)> = world
+            .query::<(&crate::systems::hydrology::WaterBody, &Transform)>()
+            .iter()
+            .map(|(_, (w, tf))| {
+                let radius = (w.surface_area_m2 as f32 / std::f32::consts::PI).sqrt();
+                (tf.position.xz(), radius)
+            })
+            .collect();
+        match data.get::<std::sync::Mutex<NavObstacles>>("nav_obstacles") {
+            Some(m) => match m.lock() {
+                Ok(obstacles) => self.nav.sync(Some(&obstacles), &water, nav_config),
+                Err(_) => self.nav.sync(None, &water, nav_config),
+            },
+            None => self.nav.sync(None, &water, nav_config),
+        }
+
+        // ── 3. Tick each AI entity's tree into a new state + velocity ────
+        let trees = data
+            .get::<BehaviorTreeRegistry>("behavior_tree_registry")
+            .unwrap_or(&self.builtin);
+        let mut ctx = AiContext {
+            snaps: snapshots,
+            me: 0,
+            dt,
+            state: String::new(),
+            velocity: Vec3::ZERO,
+            goal: None,
+        };
         let mut decisions: Vec<(u64, String, Option<u64>, Vec3)> = Vec::new();
+        let mut new_blackboards: Vec<(hecs::Entity, Blackboard)> = Vec::new();
 
-        for snap in &snapshots {
-            let Some(ref btype) = snap.behavior_type else { continue };
+        for i in 0..ctx.snaps.len() {
+            let snap = &ctx.snaps[i];
+            let Some(btype) = snap.behavior_type.clone() else { continue };
             if !snap.alive { continue; }
-
-            let (new_state, target, desired_vel) = match btype.as_str() {
-                "passive" => self.tick_passive(snap, &snapshots, dt),
-                "aggressive" => self.tick_aggressive(snap, &snapshots, dt),
-                "herd" => self.tick_herd(snap, &snapshots, dt),
-                "predator" => self.tick_predator(snap, &snapshots, dt),
-                "guard" => self.tick_guard(snap, &snapshots, dt),
-                _ => ("idle".to_string(), None, Vec3::ZERO),
+            let id = snap.id;
+            let entity = hecs::Entity::from_bits(id).expect("valid entity bits");
+            let tree = trees.get(&btype);
+
+            ctx.me = i;
+            ctx.state = "idle".to_string();
+            ctx.velocity = Vec3::ZERO;
+            ctx.goal = None;
+            let target = match world.get::<&mut Blackboard>(entity) {
+                Ok(mut bb) => {
+                    if let Some(tree) = tree {
+                        behavior::tick_tree(tree, &self.leaves, &mut ctx, &mut bb, dt);
+                    }
+                    bb.target
+                }
+                Err(_) => {
+                    let mut bb = Blackboard::default();
+                    if let Some(tree) = tree {
+                        behavior::tick_tree(tree, &self.leaves, &mut ctx, &mut bb, dt);
+                    }
+                    let target = bb.target;
+                    new_blackboards.push((entity, bb));
+                    target
+                }
             };
 
-            decisions.push((snap.id, new_state, target, desired_vel));
+            // Route around obstacles: follow the field toward the goal, or
+            // slide along walls while fleeing.
+            let here = ctx.me().position.xz();
+            let speed = ctx.velocity.length();
+            if self.nav.active() && speed > 0.0 {
+                let flat = match ctx.goal {
+                    Some(goal) => self.nav.steer(here, goal.xz(), nav_config),
+                    None => Some(self.nav.deflect(here, ctx.velocity.xz())),
+                };
+                if let Some(dir) = flat {
+                    ctx.velocity = Vec3::new(dir.x, 0.0, dir.y).normalize_or_zero() * speed;
+                }
+            }
+
+            decisions.push((id, std::mem::take(&mut ctx.state), target, ctx.velocity));
+        }
+        for (entity, bb) in new_blackboards {
+            let _ = world.insert_one(entity, bb);
         }
+        let snapshots = ctx.snaps;
 
-        // ── 3. Apply decisions back to components ───────────────────────
+        // ── 4. Apply decisions back to components ───────────────────────
         // Bite bookkeeping: cooldowns tick down for everyone. (v0.761)
         for t in self.bite_timers.values_mut() {
             *t -= dt;
@@ -143,7 +237,9 @@ impl System for AISystem {
             // AI entities, so the system owns its own locomotion. (v0.761)
             if desired_vel.length_squared() > 0.001 {
                 if let Ok(mut tf) = world.get::<&mut Transform>(entity) {
-                    tf.position += desired_vel * dt;
+                    let next = tf.position + desired_vel * dt;
+                    let flat = self.nav.constrain(tf.position.xz(), next.xz());
+                    tf.position = Vec3::new(flat.x, next.y, flat.y);
                     tf.rotation =
                         glam::Quat::from_rotation_y(f32::atan2(desired_vel.x, desired_vel.z));
                 }
@@ -174,10 +270,11 @@ impl System for AISystem {
                                     target_id,
                                     crate::systems::combat::damage::DamageEvent {
                                         damage_type:
-                                            crate::systems::combat::damage::DamageType::Kinetic,
+                                            crate::systems::combat::damage::DamageType::kinetic(),
                                         amount: damage,
                                         source_name: my_name,
                                         source_is_player: false,
+                                        skill: None,
                                     },
                                 ));
                             }
@@ -199,199 +296,181 @@ struct EntitySnapshot {
     max_health: f32,
     faction: Option<String>,
     behavior_type: Option<String>,
-    state: Option<String>,
     alive: bool,
     /// The player (Controllable) - predators count them as prey. (v0.761)
     is_player: bool,
     name: Option<String>,
 }
 
-impl AISystem {
-    // ── Passive: wander, flee when hurt ──────────────────────────────
-
-    fn tick_passive(
-        &mut self,
-        me: &EntitySnapshot,
-        others: &[EntitySnapshot],
-        dt: f32,
-    ) -> (String, Option<u64>, Vec3) {
-        // Flee if health is low and an aggressor is nearby
-        if me.health_frac < FLEE_THRESHOLD {
-            if let Some(threat) = nearest_hostile(me, others, DETECT_RANGE) {
-                let away = flee_direction(me.position, threat.position);
-                return ("fleeing".to_string(), Some(threat.id), away * FLEE_SPEED);
-            }
-        }
+/// What a creature's tree sees while it ticks: this frame's snapshot of
+/// everyone, which one is "me", and the state + velocity the leaves decide.
+pub(crate) struct AiContext {
+    snaps: Vec<EntitySnapshot>,
+    me: usize,
+    dt: f32,
+    state: String,
+    velocity: Vec3,
+    /// Where the chosen action is heading, for flow-field steering.
+    goal: Option<Vec3>,
+}
 
-        // Wander randomly
-        self.wander(me, dt)
+impl AiContext {
+    fn me(&self) -> &EntitySnapshot {
+        &self.snaps[self.me]
     }
 
-    // ── Aggressive: patrol, attack different-faction entities ────────
-
-    fn tick_aggressive(
-        &mut self,
-        me: &EntitySnapshot,
-        others: &[EntitySnapshot],
-        dt: f32,
-    ) -> (String, Option<u64>, Vec3) {
-        // Look for nearest different-faction entity
-        if let Some(target) = nearest_different_faction(me, others, DETECT_RANGE) {
-            let dist = me.position.distance(target.position);
-            if dist <= ATTACK_RANGE {
-                // Close enough — attack (face target, zero velocity)
-                return ("attacking".to_string(), Some(target.id), Vec3::ZERO);
-            }
-            // Move toward target
-            let dir = (target.position - me.position).normalize_or_zero();
-            return ("patrolling".to_string(), Some(target.id), dir * MOVE_SPEED);
-        }
-
-        // No targets — wander/patrol
-        self.wander(me, dt)
+    fn find(&self, id: Option<u64>) -> Option<&EntitySnapshot> {
+        let id = id?;
+        self.snaps.iter().find(|s| s.id == id)
     }
 
-    // ── Herd: stay near same-type entities, flee from aggressors ────
-
-    fn tick_herd(
-        &mut self,
-        me: &EntitySnapshot,
-        others: &[EntitySnapshot],
-        dt: f32,
-    ) -> (String, Option<u64>, Vec3) {
-        // Flee from nearby aggressive entities
-        if let Some(threat) = nearest_with_behavior(me, others, "aggressive", DETECT_RANGE) {
-            let away = flee_direction(me.position, threat.position);
-            return ("fleeing".to_string(), Some(threat.id), away * FLEE_SPEED);
-        }
-        if let Some(threat) = nearest_with_behavior(me, others, "predator", DETECT_RANGE) {
-            let away = flee_direction(me.position, threat.position);
-            return ("fleeing".to_string(), Some(threat.id), away * FLEE_SPEED);
-        }
+    /// Record a decision. Leaves that act return Running: they keep going
+    /// for as long as the branch above them keeps choosing them.
+    fn decide(&mut self, state: &str, velocity: Vec3) -> BehaviorStatus {
+        self.state = state.to_string();
+        self.velocity = velocity;
+        BehaviorStatus::Running
+    }
 
-        // Move toward herd center if too far
-        let herd_center = compute_herd_center(me, others);
-        if let Some(center) = herd_center {
-            let to_center = center - me.position;
-            if to_center.length() > HERD_RADIUS {
-                let dir = to_center.normalize_or_zero();
-                return ("wandering".to_string(), None, dir * MOVE_SPEED * 0.6);
+    /// Move toward (speed > 0) the blackboard target; Failure if it is gone.
+    fn toward_target(&mut self, bb: &Blackboard, state: &str, speed: f32) -> BehaviorStatus {
+        let me = self.me().position;
+        match self.find(bb.target).map(|t| t.position) {
+            Some(pos) => {
+                self.goal = Some(pos);
+                self.decide(state, (pos - me).normalize_or_zero() * speed)
             }
+            None => BehaviorStatus::Failure,
         }
-
-        // Gentle wander
-        self.wander(me, dt)
     }
 
-    // ── Predator: hunt passive/herd, rest after kill ────────────────
-
-    fn tick_predator(
-        &mut self,
-        me: &EntitySnapshot,
-        others: &[EntitySnapshot],
-        dt: f32,
-    ) -> (String, Option<u64>, Vec3) {
-        let timer = self.timers.entry(me.id).or_insert(0.0);
-
-        // If resting, count down
-        if me.state.as_deref() == Some("resting") {
-            *timer -= dt;
-            if *timer > 0.0 {
-                return ("resting".to_string(), None, Vec3::ZERO);
+    /// Remember `found` as the blackboard target; true if there was one.
+    fn pick(bb: &mut Blackboard, found: Option<&EntitySnapshot>) -> bool {
+        match found {
+            Some(t) => {
+                bb.target = Some(t.id);
+                true
             }
-            // Done resting
+            None => false,
         }
+    }
+}
 
-        // Hunt the nearest prey: passive/herd animals or the PLAYER (v0.761).
-        let prey = others
-            .iter()
-            .filter(|o| {
-                o.id != me.id
-                    && o.alive
-                    && (o.is_player
-                        || matches!(o.behavior_type.as_deref(), Some("passive") | Some("herd")))
-            })
-            .min_by(|a, b| {
-                let da = me.position.distance_squared(a.position);
-                let db = me.position.distance_squared(b.position);
-                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-        if let Some(target) = prey {
-            let dist = me.position.distance(target.position);
-            if dist > DETECT_RANGE {
-                // Too far, idle
-                return self.wander(me, dt);
+/// The named conditions and actions `data/behaviors.ron` trees are built
+/// from. Conditions that look for someone store them as the blackboard
+/// target; the actions after them in a Sequence act on that target.
+pub(crate) fn creature_leaves() -> LeafRegistry<AiContext> {
+    LeafRegistry::<AiContext>::new()
+        // ── Conditions ──
+        .condition("health_low", |c, _| c.me().health_frac < FLEE_THRESHOLD)
+        .condition("hostile_near", |c, bb| {
+            AiContext::pick(bb, nearest_hostile(c.me(), &c.snaps, DETECT_RANGE))
+        })
+        .condition("aggressor_near", |c, bb| {
+            AiContext::pick(bb, nearest_with_behavior(c.me(), &c.snaps, "aggressive", DETECT_RANGE))
+        })
+        .condition("predator_near", |c, bb| {
+            AiContext::pick(bb, nearest_with_behavior(c.me(), &c.snaps, "predator", DETECT_RANGE))
+        })
+        .condition("enemy_near", |c, bb| {
+            AiContext::pick(bb, nearest_different_faction(c.me(), &c.snaps, DETECT_RANGE))
+        })
+        .condition("prey_near", |c, bb| {
+            AiContext::pick(bb, nearest_prey(c.me(), &c.snaps, DETECT_RANGE))
+        })
+        .condition("in_attack_range", |c, bb| {
+            let me = c.me().position;
+            c.find(bb.target)
+                .is_some_and(|t| me.distance(t.position) <= ATTACK_RANGE)
+        })
+        // Last frame's target now lies dead at our feet.
+        .condition("prey_killed", |c, bb| {
+            let me = c.me().position;
+            c.find(bb.target)
+                .is_some_and(|t| !t.alive && me.distance(t.position) <= ATTACK_RANGE)
+        })
+        .condition("resting", |_, bb| bb.timers.get("rest").is_some_and(|t| *t > 0.0))
+        .condition("far_from_herd", |c, _| {
+            compute_herd_center(c.me(), &c.snaps)
+                .is_some_and(|center| (center - c.me().position).length() > HERD_RADIUS)
+        })
+        // ── Actions ──
+        .action("idle", |c, bb| {
+            bb.target = None;
+            c.decide("idle", Vec3::ZERO)
+        })
+        .action("wander", wander)
+        .action("flee", |c, bb| {
+            let me = c.me().position;
+            match c.find(bb.target).map(|t| t.position) {
+                Some(threat) => c.decide("fleeing", flee_direction(me, threat) * FLEE_SPEED),
+                None => BehaviorStatus::Failure,
             }
-            if dist <= ATTACK_RANGE {
-                // Check if prey is dead — if so, rest
-                if !target.alive {
-                    *timer = REST_DURATION;
-                    return ("resting".to_string(), None, Vec3::ZERO);
+        })
+        // Close enough: face the target at zero velocity; bites land in the
+        // apply pass.
+        .action("attack", |c, bb| {
+            if c.find(bb.target).is_none() {
+                return BehaviorStatus::Failure;
+            }
+            c.decide("attacking", Vec3::ZERO)
+        })
+        .action("hunt", |c, bb| c.toward_target(bb, "hunting", MOVE_SPEED * 1.3))
+        .action("pursue", |c, bb| c.toward_target(bb, "patrolling", MOVE_SPEED))
+        // Guards step out slower, staying near their post.
+        .action("intercept", |c, bb| c.toward_target(bb, "patrolling", MOVE_SPEED * 0.8))
+        .action("regroup", |c, bb| {
+            bb.target = None;
+            match compute_herd_center(c.me(), &c.snaps) {
+                Some(center) => {
+                    c.goal = Some(center);
+                    let dir = (center - c.me().position).normalize_or_zero();
+                    c.decide("wandering", dir * MOVE_SPEED * 0.6)
                 }
-                return ("attacking".to_string(), Some(target.id), Vec3::ZERO);
+                None => BehaviorStatus::Failure,
             }
-            // Chase
-            let dir = (target.position - me.position).normalize_or_zero();
-            return ("hunting".to_string(), Some(target.id), dir * MOVE_SPEED * 1.3);
-        }
-
-        self.wander(me, dt)
-    }
-
-    // ── Guard: stand ground, attack hostile faction in range ─────────
-
-    fn tick_guard(
-        &mut self,
-        me: &EntitySnapshot,
-        others: &[EntitySnapshot],
-        _dt: f32,
-    ) -> (String, Option<u64>, Vec3) {
-        // Attack nearest hostile-faction entity within detect range
-        if let Some(target) = nearest_different_faction(me, others, DETECT_RANGE) {
-            let dist = me.position.distance(target.position);
-            if dist <= ATTACK_RANGE {
-                return ("attacking".to_string(), Some(target.id), Vec3::ZERO);
+        })
+        .action("start_rest", |c, bb| {
+            bb.target = None;
+            bb.timers.insert("rest".to_string(), REST_DURATION);
+            c.decide("resting", Vec3::ZERO);
+            BehaviorStatus::Success
+        })
+        .action("rest", |c, bb| {
+            if let Some(t) = bb.timers.get_mut("rest") {
+                *t -= c.dt;
             }
-            // Move toward threat but stay close to guard position
-            let dir = (target.position - me.position).normalize_or_zero();
-            return ("patrolling".to_string(), Some(target.id), dir * MOVE_SPEED * 0.8);
-        }
+            c.decide("resting", Vec3::ZERO)
+        })
+}
 
-        // Stand ground
-        ("idle".to_string(), None, Vec3::ZERO)
+/// Shared wander logic: walk to a random point near here, pick a new one
+/// every 3-8 seconds.
+fn wander(c: &mut AiContext, bb: &mut Blackboard) -> BehaviorStatus {
+    bb.target = None;
+    let me = c.me().position;
+    let timer = bb.timers.entry("wander".to_string()).or_insert(0.0);
+    *timer -= c.dt;
+
+    if *timer <= 0.0 {
+        // Pick a new wander target
+        let mut rng = rand::rng();
+        let angle: f32 = rng.random_range(0.0..std::f32::consts::TAU);
+        let dist: f32 = rng.random_range(2.0..WANDER_RADIUS);
+        bb.move_to = Some(me + Vec3::new(angle.cos() * dist, 0.0, angle.sin() * dist));
+        *timer = rng.random_range(3.0..8.0); // wander for 3-8 seconds
     }
 
-    // ── Shared wander logic ─────────────────────────────────────────
-
-    fn wander(
-        &mut self,
-        me: &EntitySnapshot,
-        dt: f32,
-    ) -> (String, Option<u64>, Vec3) {
-        let timer = self.timers.entry(me.id).or_insert(0.0);
-        *timer -= dt;
-
-        if *timer <= 0.0 {
-            // Pick a new wander target
-            let mut rng = rand::thread_rng();
-            let angle: f32 = rng.gen_range(0.0..std::f32::consts::TAU);
-            let dist: f32 = rng.gen_range(2.0..WANDER_RADIUS);
-            let target_pos = me.position + Vec3::new(angle.cos() * dist, 0.0, angle.sin() * dist);
-            self.wander_targets.insert(me.id, target_pos);
-            *timer = rng.gen_range(3.0..8.0); // wander for 3-8 seconds
+    if let Some(target_pos) = bb.move_to {
+        let to_target = target_pos - me;
+        if to_target.length() > 0.5 {
+            c.goal = Some(target_pos);
+            let dir = to_target.normalize_or_zero();
+            return c.decide("wandering", dir * MOVE_SPEED * 0.4);
         }
-
-        if let Some(&target_pos) = self.wander_targets.get(&me.id) {
-            let to_target = target_pos - me.position;
-            if to_target.length() > 0.5 {
-                let dir = to_target.normalize_or_zero();
-                return ("wandering".to_string(), None, dir * MOVE_SPEED * 0.4);
-            }
-        }
-
-        ("idle".to_string(), None, Vec3::ZERO)
     }
+
+    c.decide("idle", Vec3::ZERO)
 }
 
 // ── Free functions for target selection ─────────────────────────────────
@@ -420,6 +499,30 @@ fn nearest_different_faction<'a>(
         })
 }
 
+/// Find the nearest live prey: passive/herd animals or the PLAYER (v0.761).
+fn nearest_prey<'a>(
+    me: &EntitySnapshot,
+    others: &'a [EntitySnapshot],
+    range: f32,
+) -> Option<&'a EntitySnapshot> {
+    let range_sq = range * range;
+
+    others
+        .iter()
+        .filter(|o| {
+            o.id != me.id
+                && o.alive
+                && (o.is_player
+                    || matches!(o.behavior_type.as_deref(), Some("passive") | Some("herd")))
+                && me.position.distance_squared(o.position) <= range_sq
+        })
+        .min_by(|a, b| {
+            let da = me.position.distance_squared(a.position);
+            let db = me.position.distance_squared(b.position);
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        })
+}
+
 /// Find nearest entity that could be a threat (aggressive/predator with different faction or no faction).
 fn nearest_hostile<'a>(
     me: &EntitySnapshot,
@@ -578,4 +681,112 @@ mod tests {
             bites.len()
         );
     }
+
+    /// The predator tree's rest branch: once the prey it was biting lies
+    /// dead at its feet it rests for REST_DURATION, then goes back to work.
+    #[test]
+    fn predator_rests_after_a_kill() {
+        let mut world = hecs::World::new();
+        let data = DataStore::new();
+        let sheep = world.spawn((
+            AIBehavior {
+                behavior_type: "herd".to_string(),
+                state: "idle".to_string(),
+                target: None,
+            },
+            Transform {
+                position: Vec3::new(2.0, 0.0, 0.0),
+                ..Default::default()
+            },
+            Health { current: 40.0, max: 40.0 },
+        ));
+        let wolf = world.spawn((
+            AIBehavior {
+                behavior_type: "predator".to_string(),
+                state: "idle".to_string(),
+                target: None,
+            },
+            Transform::default(),
+            Health { current: 65.0, max: 65.0 },
+        ));
+
+        let mut sys = AISystem::new();
+        sys.tick(&mut world, 0.1, &data);
+        assert_eq!(world.get::<&AIBehavior>(wolf).unwrap().state, "attacking");
+        assert_eq!(
+            world.get::<&AIBehavior>(sheep).unwrap().state,
+            "fleeing",
+            "the herd tree bolts from a predator"
+        );
+        assert!(world.get::<&Blackboard>(wolf).is_ok(), "blackboard attached");
+
+        // Kill the sheep where it stands (it fled a little; still in range).
+        world.get::<&mut Health>(sheep).unwrap().current = 0.0;
+        world.get::<&mut Transform>(sheep).unwrap().position = Vec3::new(2.0, 0.0, 0.0);
+        sys.tick(&mut world, 0.1, &data);
+        assert_eq!(world.get::<&AIBehavior>(wolf).unwrap().state, "resting");
+        for _ in 0..40 {
+            sys.tick(&mut world, 0.1, &data);
+        }
+        assert_eq!(world.get::<&AIBehavior>(wolf).unwrap().state, "resting");
+        for _ in 0..20 {
+            sys.tick(&mut world, 0.1, &data);
+        }
+        assert_ne!(
+            world.get::<&AIBehavior>(wolf).unwrap().state,
+            "resting",
+            "rest ends after REST_DURATION"
+        );
+    }
+
+    /// Walls are real for creatures: with a wall between them, the wolf
+    /// follows the flow field around its end instead of walking through it,
+    /// and still gets its bite in.
+    #[test]
+    fn predator_paths_around_a_wall() {
+        let mut world = hecs::World::new();
+        let mut data = DataStore::new();
+        let mut obstacles = NavObstacles::default();
+        obstacles.set_walls(vec![crate::ship::wall_collision::WallSegment {
+            a: (7.0, -10.0),
+            b: (7.0, 10.0),
+            half_thickness: 0.1,
+        }]);
+        data.insert("nav_obstacles", std::sync::Mutex::new(obstacles));
+
+        world.spawn((
+            Controllable,
+            Transform::default(),
+            Health { current: 100.0, max: 100.0 },
+        ));
+        let wolf = world.spawn((
+            AIBehavior {
+                behavior_type: "predator".to_string(),
+                state: "idle".to_string(),
+                target: None,
+            },
+            Transform {
+                position: Vec3::new(15.0, 0.0, 0.0),
+                ..Default::default()
+            },
+            Health { current: 65.0, max: 65.0 },
+        ));
+
+        let mut sys = AISystem::new();
+        let mut went_around = false;
+        for _ in 0..400 {
+            sys.tick(&mut world, 0.1, &data);
+            let p = world.get::<&Transform>(wolf).unwrap().position;
+            assert!(
+                !((6.85..=7.15).contains(&p.x) && p.z.abs() < 10.0),
+                "wolf walked through the wall at {p}"
+            );
+            went_around |= p.z.abs() >= 10.0;
+            if world.get::<&AIBehavior>(wolf).unwrap().state == "attacking" {
+                break;
+            }
+        }
+        assert!(went_around, "took the way round the wall's end");
+        assert_eq!(world.get::<&AIBehavior>(wolf).unwrap().state, "attacking");
+    }
 }
@@ -424,6 +424,13 @@ impl System for AtmosphereSystem {
                 let co2 = sp.atmosphere.gas_percent("CO2");
                 let new_co2 = (co2 + HOME_OCCUPANTS * CO2_RISE_PER_PERSON_PER_S * step_dt - co2_scrub * step_dt).max(0.04);
                 sp.atmosphere.composition.insert("CO2".to_string(), new_co2);
+                // The home air's temperature is the thermal network's room mean, not an equalized guess.
+                if let Some(mean_c) = data
+                    .get::<std::sync::Mutex<crate::systems::thermal::ThermalStatus>>("thermal_status")
+                    .and_then(|m| m.lock().ok().filter(|t| !t.rooms.is_empty()).map(|t| t.mean_c))
+                {
+                    sp.atmosphere.temperature_k = mean_c + 273.15;
+                }
                 Self::evaluate_atmosphere(&mut sp.atmosphere);
             }
         }
@@ -0,0 +1,507 @@
+//! Offline catch-up — advances the homestead by the time the app was closed.
+//!
+//! On boot, `save_load::catch_up_active_home` hands `run` the wall-clock
+//! seconds since the save was written. Instead of replaying frames, the home
+//! is moved forward in coarse steps (`data/catch_up.ron`, a minute by default):
+//!   1. Solar panels produce their MEAN output over the step
+//!      (`solar::mean_sun_factor`), so a step length never changes the energy.
+//!   2. `ElectricalSystem` then `PlumbingSystem` integrate the step: batteries
+//!      charge and drain, pumps fill the cistern only while they have power.
+//!      Every power is the step's mean (solar averaged, a battery's charge
+//!      spread over the step), so the cable solve sees mean currents: line
+//!      loss still counts, and only an overload that lasts trips a breaker.
+//!   3. `FarmAutomation` evolves crop water + health in closed form, tending
+//!      the garden while the cistern holds water.
+//!
+//! Livestock regrowth and food spoilage are plain timers, so they take the
+//! whole span at once (spoilage through the `spoilage_catch_up` slot, since
+//! FoodSystem owns its timers). Last, the game clock moves to the saved time
+//! plus the span; growth is age-based, so crops ripen on that settle tick.
+//!
+//! Game seconds are real seconds at time_scale 1.0. The save does not carry a
+//! scale, so the catch-up runs at 1.0.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::ecs::components::{
+    Battery, CropInstance, Dead, Harvestable, PowerGenerator, SolarPanel, WaterTank, STAGE_DEAD,
+};
+use crate::ecs::systems::System;
+use crate::hot_reload::data_store::DataStore;
+use crate::systems::economy::market::VendorMarkets;
+use crate::systems::economy::TradeGoodsRegistry;
+use crate::systems::electrical::ElectricalSystem;
+use crate::systems::farming::automation::FarmAutomation;
+use crate::systems::farming::{FarmingSystem, PlantRegistry};
+use crate::systems::food::FoodSystem;
+use crate::systems::inventory::Inventory;
+use crate::systems::plumbing::PlumbingSystem;
+use crate::systems::solar::mean_sun_factor;
+use crate::systems::time::{GameTime, SECONDS_PER_DAY};
+
+/// Top-level RON schema for `data/catch_up.ron`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CatchUpConfig {
+    /// Longest absence simulated; the rest is skipped.
+    #[serde(default = "default_max_away_hours")]
+    pub max_away_hours: f32,
+    /// Length of one simulation step.
+    #[serde(default = "default_step_seconds")]
+    pub step_seconds: f32,
+    /// Absences shorter than this (a quick restart) are not simulated.
+    #[serde(default = "default_min_away_seconds")]
+    pub min_away_seconds: f32,
+}
+
+fn default_max_away_hours() -> f32 {
+    168.0
+}
+
+fn default_step_seconds() -> f32 {
+    60.0
+}
+
+fn default_min_away_seconds() -> f32 {
+    120.0
+}
+
+impl Default for CatchUpConfig {
+    fn default() -> Self {
+        Self {
+            max_away_hours: default_max_away_hours(),
+            step_seconds: default_step_seconds(),
+            min_away_seconds: default_min_away_seconds(),
+        }
+    }
+}
+
+impl CatchUpConfig {
+    /// Parse `catch_up.ron` (already merged through the enabled mods).
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, String> {
+        let text = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+}
+
+/// What happened to the homestead while the app was closed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AwayReport {
+    /// Wall-clock seconds since the save was written.
+    pub away_secs: f64,
+    /// Seconds actually simulated (`away_secs` capped at `max_away_hours`).
+    pub simulated_secs: f64,
+    /// Total battery charge before and after (Wh), and bank capacity.
+    pub battery_wh: (f32, f32),
+    pub battery_capacity_wh: f32,
+    /// Total stored water before and after (L), and tank capacity.
+    pub water_l: (f32, f32),
+    pub water_capacity_l: f32,
+    /// Most crops the automation kept irrigated at once.
+    pub crops_tended: u32,
+    /// Crops that reached their harvest stage.
+    pub crops_ripened: u32,
+    /// Crops lost to drought or RF stress.
+    pub crops_died: u32,
+    /// Animals whose product regrew to ready.
+    pub animals_ready: u32,
+    /// Food stacks in inventories that went off.
+    pub food_spoiled: u32,
+}
+
+impl AwayReport {
+    /// One summary line per thing that changed, for the "While you were away" card.
+    pub fn lines(&self) -> Vec<String> {
+        let mut lines = vec![if self.simulated_secs < self.away_secs {
+            format!(
+                "Away {} (the home ran for the first {})",
+                fmt_duration(self.away_secs),
+                fmt_duration(self.simulated_secs)
+            )
+        } else {
+            format!("Away {}", fmt_duration(self.away_secs))
+        }];
+        if self.battery_capacity_wh > 0.0 {
+            lines.push(format!(
+                "Batteries: {:.0} -> {:.0} Wh of {:.0}",
+                self.battery_wh.0, self.battery_wh.1, self.battery_capacity_wh
+            ));
+        }
+        if self.water_capacity_l > 0.0 {
+            lines.push(format!(
+                "Cistern: {:.0} -> {:.0} L of {:.0}",
+                self.water_l.0, self.water_l.1, self.water_capacity_l
+            ));
+        }
+        if self.crops_tended > 0 {
+            lines.push(format!("Garden: {} crops kept watered", self.crops_tended));
+        }
+        if self.crops_ripened > 0 {
+            lines.push(format!("{} crops ready to harvest", self.crops_ripened));
+        }
+        if self.crops_died > 0 {
+            lines.push(format!("{} crops died of thirst or RF stress", self.crops_died));
+        }
+        if self.animals_ready > 0 {
+            lines.push(format!("{} animals ready to harvest", self.animals_ready));
+        }
+        if self.food_spoiled > 0 {
+            lines.push(format!("{} food stacks spoiled", self.food_spoiled));
+        }
+        lines
+    }
+}
+
+/// "2d 3h", "5h 12m", "7m".
+fn fmt_duration(secs: f64) -> String {
+    let mins = (secs / 60.0) as u64;
+    let (d, h, m) = (mins / 1440, mins / 60 % 24, mins % 60);
+    if d > 0 {
+        format!("{d}d {h}h")
+    } else if h > 0 {
+        format!("{h}h {m}m")
+    } else {
+        format!("{m}m")
+    }
+}
+
+/// (stored, capacity) summed over every battery bank.
+fn battery_totals(world: &hecs::World) -> (f32, f32) {
+    world
+        .query::<&Battery>()
+        .iter()
+        .fold((0.0, 0.0), |(s, c), (_, b)| (s + b.charge_wh, c + b.capacity_wh))
+}
+
+/// (stored, capacity) summed over every water tank.
+fn water_totals(world: &hecs::World) -> (f32, f32) {
+    world
+        .query::<&WaterTank>()
+        .iter()
+        .fold((0.0, 0.0), |(s, c), (_, t)| (s + t.liters, c + t.capacity_l))
+}
+
+/// (harvest-ready, dead) crop counts.
+fn crop_counts(world: &hecs::World, plants: Option<&PlantRegistry>) -> (u32, u32) {
+    let mut counts = (0, 0);
+    for (_, crop) in world.query::<&CropInstance>().iter() {
+        if crop.growth_stage == STAGE_DEAD {
+            counts.1 += 1;
+        } else if plants
+            .and_then(|reg| reg.get(&crop.crop_def_id))
+            .is_some_and(|def| crop.growth_stage == def.last_stage())
+        {
+            counts.0 += 1;
+        }
+    }
+    counts
+}
+
+/// Advance the home by `away_secs` of absence. Starts from the clock in the
+/// `game_time` slot (the saved time, restored by the caller) and leaves the
+/// clock at the end of the simulated span, both in the slot and through the
+/// `time_set_elapsed_request` channel for TimeSystem. None when the absence
+/// is too short to bother.
+pub fn run(
+    world: &mut hecs::World,
+    data: &DataStore,
+    data_dir: &Path,
+    config: &CatchUpConfig,
+    away_secs: f64,
+) -> Option<AwayReport> {
+    if away_secs < config.min_away_seconds as f64 {
+        return None;
+    }
+    let span = away_secs.min(config.max_away_hours as f64 * 3600.0);
+    let step = (config.step_seconds as f64).max(1.0);
+    let mut clock = data
+        .get::<std::sync::Mutex<GameTime>>("game_time")
+        .and_then(|m| m.lock().ok().map(|g| g.clone()))
+        .unwrap_or_default();
+    let start = clock.elapsed_seconds;
+    let plants = data.get::<PlantRegistry>("plant_registry");
+
+    let mut report = AwayReport { away_secs, simulated_secs: span, ..Default::default() };
+    let (battery_before, battery_cap) = battery_totals(world);
+    let (water_before, water_cap) = water_totals(world);
+    report.battery_capacity_wh = battery_cap;
+    report.water_capacity_l = water_cap;
+    let (ripe_before, dead_before) = crop_counts(world, plants);
+
+    // 1-3. Power, water and the garden, step by step.
+    let mut electrical = ElectricalSystem::new(data_dir);
+    electrical.mute_logs(span as f32);
+    let mut plumbing = PlumbingSystem::new();
+    let farm = FarmAutomation::new(data_dir);
+    let mut t = 0.0;
+    while t < span {
+        let dt = step.min(span - t);
+        let hour = ((start + t) % SECONDS_PER_DAY / SECONDS_PER_DAY * 24.0) as f32;
+        let sun = mean_sun_factor(hour, (dt / SECONDS_PER_DAY * 24.0) as f32);
+        for (_e, (gen, panel)) in world.query_mut::<(&mut PowerGenerator, &SolarPanel)>() {
+            gen.output_watts = panel.peak_watts * sun;
+        }
+        electrical.tick(world, dt as f32, data);
+        plumbing.tick(world, dt as f32, data);
+        // Same cistern gate as the live irrigation (FarmingSystem).
+        let water = plumbing.status;
+        let water_available = water.capacity_l <= 0.0 || water.stored_l > water.capacity_l * 0.02;
+        let outcome = farm.step(world, dt as f32, water_available, plants);
+        report.crops_tended = report.crops_tended.max(outcome.tended);
+        t += dt;
+    }
+    report.battery_wh = (battery_before, battery_totals(world).0);
+    report.water_l = (water_before, water_totals(world).0);
+
+    // Livestock: regrowth is a clamped timer, so the whole span at once.
+    for (_e, (h, dead)) in world.query_mut::<(&mut Harvestable, Option<&Dead>)>() {
+        if dead.is_some() || h.time_since_harvest >= h.regrow_time {
+            continue;
+        }
+        h.time_since_harvest = (h.time_since_harvest + span as f32).min(h.regrow_time);
+        if h.time_since_harvest >= h.regrow_time {
+            report.animals_ready += 1;
+        }
+    }
+
+    // Spoilage: count what went off, and hand FoodSystem the backlog.
+    for (_e, inv) in world.query::<&Inventory>().iter() {
+        report.food_spoiled += inv
+            .slots
+            .iter()
+            .flatten()
+            .filter(|st| FoodSystem::freshness_seconds(&st.item_id).is_some_and(|f| f as f64 <= span))
+            .count() as u32;
+    }
+    if let Some(slot) = data.get::<std::sync::Mutex<f32>>("spoilage_catch_up") {
+        if let Ok(mut backlog) = slot.lock() {
+            *backlog += span as f32;
+        }
+    }
+
+    // Vendor markets: shelves refill over the whole span at once.
+    if let (Some(markets), Some(goods)) = (
+        data.get::<std::sync::Mutex<VendorMarkets>>("vendor_markets"),
+        data.get::<TradeGoodsRegistry>("trade_goods_registry"),
+    ) {
+        if let Ok(mut m) = markets.lock() {
+            m.catch_up(goods, (span / SECONDS_PER_DAY) as f32);
+        }
+    }
+
+    // Move the clock, then let one zero-length farming tick settle the stages.
+    clock.set_elapsed(start + span);
+    if let Some(slot) = data.get::<std::sync::Mutex<GameTime>>("game_time") {
+        if let Ok(mut g) = slot.lock() {
+            *g = clock.clone();
+        }
+    }
+    if let Some(req) = data.get::<std::sync::Mutex<Option<f64>>>("time_set_elapsed_request") {
+        if let Ok(mut r) = req.lock() {
+            *r = Some(clock.elapsed_seconds);
+        }
+    }
+    FarmingSystem::new().tick(world, 0.0, data);
+    let (ripe_after, dead_after) = crop_counts(world, data.get::<PlantRegistry>("plant_registry"));
+    report.crops_ripened = ripe_after.saturating_sub(ripe_before);
+    report.crops_died = dead_after.saturating_sub(dead_before);
+
+    log::info!(
+        "Offline catch-up: simulated {} of {} away",
+        fmt_duration(span),
+        fmt_duration(away_secs)
+    );
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{MachineInstanceId, PowerCircuit, PowerConsumer};
+    use crate::systems::power_grid::GridCable;
+
+    fn data_dir() -> &'static Path {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"))
+    }
+
+    /// DataStore with the slots the boot path registers.
+    fn make_store(start: f64) -> DataStore {
+        let mut data = DataStore::new();
+        let plants = PlantRegistry::from_csv(include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/plants.csv"
+        )))
+        .expect("plants.csv");
+        data.insert("plant_registry", plants);
+        let mut clock = GameTime::default();
+        clock.set_elapsed(start);
+        data.insert("game_time", std::sync::Mutex::new(clock));
+        data.insert("time_set_elapsed_request", std::sync::Mutex::new(Option::<f64>::None));
+        data.insert("spoilage_catch_up", std::sync::Mutex::new(0.0f32));
+        data
+    }
+
+    fn crop(def: &str, stage: &str, planted_at: f64) -> CropInstance {
+        CropInstance {
+            crop_def_id: def.into(),
+            growth_stage: stage.into(),
+            planted_at,
+            water_level: 1.0,
+            health: 100.0,
+            tower_id: None,
+            tower_slot: None,
+            genome: None,
+            cultivar: None,
+        }
+    }
+
+    #[test]
+    fn short_absence_is_not_simulated() {
+        let mut world = hecs::World::new();
+        let data = make_store(0.0);
+        assert!(run(&mut world, &data, data_dir(), &CatchUpConfig::default(), 30.0).is_none());
+    }
+
+    /// Two days away: the solar bank charges over the daylight, the garden is
+    /// tended and ripens, the clock moves on, and the meat in the pack spoils.
+    #[test]
+    fn two_days_away_charges_grows_and_spoils() {
+        let mut world = hecs::World::new();
+        world.spawn((
+            PowerGenerator { output_watts: 0.0, fuel_per_second: 0.0, active: true },
+            SolarPanel { peak_watts: 2000.0 },
+        ));
+        world.spawn((Battery { charge_wh: 100.0, capacity_wh: 5000.0, max_charge_w: 3000.0, max_discharge_w: 3000.0 },));
+        world.spawn((PowerConsumer { draw_watts: 100.0, priority: 1, enabled: true },));
+        world.spawn((WaterTank { liters: 500.0, capacity_l: 2000.0 },));
+        let lettuce = world.spawn((crop("lettuce", "seed", 0.0),));
+        let mut inv = Inventory::new(4);
+        inv.add_item("cooked_meat_0", 1, 99);
+        world.spawn((inv,));
+
+        let data = make_store(0.0);
+        let away = 2.0 * 86_400.0;
+        let report = run(&mut world, &data, data_dir(), &CatchUpConfig::default(), away).expect("ran");
+
+        assert_eq!(report.simulated_secs, away);
+        assert!(report.battery_wh.1 > report.battery_wh.0, "{report:?}");
+        assert_eq!(report.water_l, (500.0, 500.0), "no pumps, no flow");
+        assert_eq!(report.crops_tended, 1);
+        assert_eq!(report.crops_died, 0);
+        assert_eq!(report.crops_ripened, 1, "two real days is weeks of game growth");
+        let lettuce = world.get::<&CropInstance>(lettuce).unwrap();
+        let last = data.get::<PlantRegistry>("plant_registry").unwrap().get("lettuce").unwrap().last_stage().to_string();
+        assert_eq!(lettuce.growth_stage, last);
+        assert_eq!(report.food_spoiled, 1);
+        assert_eq!(*data.get::<std::sync::Mutex<f32>>("spoilage_catch_up").unwrap().lock().unwrap(), away as f32);
+        let requested = *data.get::<std::sync::Mutex<Option<f64>>>("time_set_elapsed_request").unwrap().lock().unwrap();
+        assert_eq!(requested, Some(away));
+        assert!(report.lines().iter().any(|l| l.starts_with("Batteries:")));
+    }
+
+    #[test]
+    fn long_absence_is_capped_and_says_so() {
+        let mut world = hecs::World::new();
+        let data = make_store(600.0);
+        let config = CatchUpConfig { max_away_hours: 1.0, step_seconds: 600.0, min_away_seconds: 0.0 };
+        let report = run(&mut world, &data, data_dir(), &config, 3.0 * 86_400.0).expect("ran");
+        assert_eq!(report.simulated_secs, 3600.0);
+        assert_eq!(report.lines()[0], "Away 3d 0h (the home ran for the first 1h 0m)");
+        let clock = data.get::<std::sync::Mutex<GameTime>>("game_time").unwrap().lock().unwrap().clone();
+        assert_eq!(clock.elapsed_seconds, 4200.0, "saved clock + simulated span");
+    }
+
+    /// The config loads like the other data files: the embedded copy when
+    /// the data dir has none, with a mod's partial patch merged over it.
+    #[cfg(feature = "native")]
+    #[test]
+    fn config_reads_embedded_and_merges_mods() {
+        let root = std::env::temp_dir().join(format!("hos_catch_up_{}", std::process::id()));
+        let dir = root.join("data").join("mods").join("short-away");
+        std::fs::create_dir_all(&dir).unwrap();
+        let manifest = r#"{"name": "short-away", "id": "short-away", "version": "1.0.0", "author": "t", "description": ""}"#;
+        std::fs::write(dir.join("mod.json"), manifest).unwrap();
+        std::fs::write(dir.join("catch_up.ron"), "(max_away_hours: 24.0)").unwrap();
+        let text = crate::mods::ModData::scan(&root.join("data")).read("catch_up.ron");
+        std::fs::remove_dir_all(&root).ok();
+        let config = CatchUpConfig::from_ron(text.expect("embedded fallback").as_bytes()).unwrap();
+        assert_eq!(config.max_away_hours, 24.0, "the mod's value wins");
+        assert_eq!(config.step_seconds, 60.0, "the rest comes from the embedded file");
+    }
+
+    #[test]
+    fn a_dry_cistern_lets_the_garden_wilt() {
+        let mut world = hecs::World::new();
+        world.spawn((WaterTank { liters: 0.0, capacity_l: 2000.0 },));
+        world.spawn((crop("lettuce", "seed", 0.0),));
+        let data = make_store(0.0);
+        let report = run(&mut world, &data, data_dir(), &CatchUpConfig::default(), 3600.0).expect("ran");
+        assert_eq!(report.crops_tended, 0);
+        assert_eq!(report.crops_died, 1);
+    }
+
+    #[test]
+    fn livestock_regrows_over_the_absence() {
+        let mut world = hecs::World::new();
+        let h = |since: f32| Harvestable { resource: "milk_0".into(), amount: 1.0, regrow_time: 600.0, time_since_harvest: since };
+        world.spawn((h(0.0),));
+        world.spawn((h(600.0),));
+        let data = make_store(0.0);
+        let report = run(&mut world, &data, data_dir(), &CatchUpConfig::default(), 900.0).expect("ran");
+        assert_eq!(report.animals_ready, 1, "the already-ready animal is not counted again");
+    }
+
+    /// A genset feeding `to` through an 8 m extension cord (13 A).
+    fn corded(world: &mut hecs::World, to: &str) -> hecs::Entity {
+        world.spawn((
+            MachineInstanceId("genset".into()),
+            PowerCircuit { island: 0 },
+            PowerGenerator { output_watts: 5000.0, fuel_per_second: 0.0, active: true },
+        ));
+        world.spawn((GridCable {
+            from: "genset".into(),
+            to: to.into(),
+            conduit: "cu_awg16_cord".into(),
+            protection: "breaker".into(),
+            length_m: 8.0,
+            amps: 0.0,
+            heat: 0.0,
+            tripped: false,
+        },))
+    }
+
+    /// A nearly full battery would gulp 25 A for a few seconds live; over a
+    /// catch-up step that is a small mean current, so the cord holds.
+    #[test]
+    fn a_brief_spike_does_not_trip_while_away() {
+        let mut world = hecs::World::new();
+        let cord = corded(&mut world, "battery");
+        world.spawn((
+            MachineInstanceId("battery".into()),
+            PowerCircuit { island: 0 },
+            Battery { charge_wh: 4990.0, capacity_wh: 5000.0, max_charge_w: 3000.0, max_discharge_w: 3000.0 },
+        ));
+        let data = make_store(0.0);
+        let report = run(&mut world, &data, data_dir(), &CatchUpConfig::default(), 3600.0).expect("ran");
+        assert!(!world.get::<&GridCable>(cord).unwrap().tripped);
+        assert!((report.battery_wh.1 - 5000.0).abs() < 0.01, "{report:?}");
+    }
+
+    /// A furnace on the same cord pulls 25 A every step: the breaker trips
+    /// and the furnace is shed, as it would be with the player home.
+    #[test]
+    fn a_sustained_overload_trips_while_away() {
+        let mut world = hecs::World::new();
+        let cord = corded(&mut world, "furnace");
+        let furnace = world.spawn((
+            MachineInstanceId("furnace".into()),
+            PowerCircuit { island: 0 },
+            PowerConsumer { draw_watts: 3000.0, priority: 2, enabled: true },
+        ));
+        let data = make_store(0.0);
+        run(&mut world, &data, data_dir(), &CatchUpConfig::default(), 7.0 * 86_400.0).expect("ran");
+        assert!(world.get::<&GridCable>(cord).unwrap().tripped);
+        assert!(!world.get::<&PowerConsumer>(furnace).unwrap().enabled, "cut off from the genset");
+    }
+}
@@ -1,17 +1,46 @@
 //! Damage types and resistance calculations.
 //!
-//! Damage type definitions loaded from `data/damage_types.csv`.
+//! Damage type definitions loaded from `data/damage_types.csv` into the
+//! `DamageTypeRegistry` (DataStore `"damage_type_registry"`). A type is a row,
+//! not a variant: its armour channel (the `Armor.resistance` key, fed by the
+//! `armor_<channel>` columns of equipment.csv), the status effect a hit may
+//! leave, the combat skill a killing blow trains, and the source names
+//! (ability elements, weather events, disasters) that deal it. Adding "cold"
+//! or "electric" is a CSV edit.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-/// Categories of damage.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum DamageType {
-    Kinetic,
-    Thermal,
-    Energy,
-    Chemical,
-    Radiation,
+use crate::hot_reload::data_store::DataStore;
+
+/// The untyped blow: bites, swings, debris, and anything no row claims.
+pub const DEFAULT_DAMAGE_TYPE: &str = "kinetic";
+
+/// A damage category, by `damage_types.csv` id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DamageType(pub String);
+
+impl DamageType {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+
+    /// The default physical type (`DEFAULT_DAMAGE_TYPE`).
+    pub fn kinetic() -> Self {
+        Self::new(DEFAULT_DAMAGE_TYPE)
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for DamageType {
+    fn default() -> Self {
+        Self::kinetic()
+    }
 }
 
 /// A damage event applied to an entity.
@@ -26,4 +55,194 @@ pub struct DamageEvent {
     /// (a wolf's kill is not your loot). (v0.761)
     #[serde(default)]
     pub source_is_player: bool,
+    /// Skill a killing blow trains, set by the weapon that dealt it (a rifle
+    /// round trains ranged though it is kinetic); None = the type's skill.
+    #[serde(default)]
+    pub skill: Option<String>,
+}
+
+/// One row of `data/damage_types.csv`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DamageTypeDef {
+    pub id: String,
+    pub name: String,
+    /// `Armor.resistance` key this type is mitigated by; empty = its own id.
+    #[serde(default)]
+    pub armor_channel: String,
+    /// status_effects.csv id a hit may leave behind; empty = none.
+    #[serde(default)]
+    pub status_effect: String,
+    /// Chance the status effect lands per 10 points of damage taken, so a
+    /// per-tick hazard and one big hit weigh the same.
+    #[serde(default)]
+    pub effect_chance: f32,
+    /// Effect duration in seconds; 0 = the effect's own `duration_s`.
+    #[serde(default)]
+    pub effect_s: f32,
+    /// Combat skill a player's killing blow of this type trains.
+    #[serde(default)]
+    pub skill: String,
+    /// Pipe-separated source names that deal this type: ability elements
+    /// (abilities.csv `damage_type`), weather event ids, disaster ids.
+    #[serde(default)]
+    pub sources: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+impl DamageTypeDef {
+    pub fn channel(&self) -> &str {
+        if self.armor_channel.is_empty() {
+            &self.id
+        } else {
+            &self.armor_channel
+        }
+    }
+
+    pub fn sources(&self) -> impl Iterator<Item = &str> {
+        self.sources.split('|').map(str::trim).filter(|s| !s.is_empty())
+    }
+}
+
+/// All damage types keyed by id, plus the source-name index.
+#[derive(Debug, Default)]
+pub struct DamageTypeRegistry {
+    pub types: HashMap<String, DamageTypeDef>,
+    by_source: HashMap<String, String>,
+}
+
+impl DamageTypeRegistry {
+    /// Build from raw `damage_types.csv` bytes. A source claimed by two rows
+    /// goes to the first and the clash is logged.
+    pub fn from_csv(data: &[u8]) -> Result<Self, String> {
+        let rows: Vec<DamageTypeDef> = crate::assets::loader::parse_csv(data)?;
+        let mut reg = Self::default();
+        for row in rows {
+            for source in row.sources() {
+                match reg.by_source.get(source) {
+                    Some(owner) if owner != &row.id => log::warn!(
+                        "damage_types.csv: source '{source}' is claimed by both '{owner}' and '{}'",
+                        row.id
+                    ),
+                    Some(_) => {}
+                    None => {
+                        reg.by_source.insert(source.to_string(), row.id.clone());
+                    }
+                }
+            }
+            reg.types.insert(row.id.clone(), row);
+        }
+        Ok(reg)
+    }
+
+    pub fn get(&self, id: &str) -> Option<&DamageTypeDef> {
+        self.types.get(id)
+    }
+
+    /// The type a source name deals: a type id names itself, otherwise the
+    /// row listing it under `sources`.
+    pub fn for_source(&self, source: &str) -> Option<DamageType> {
+        if self.types.contains_key(source) {
+            return Some(DamageType::new(source));
+        }
+        self.by_source.get(source).map(|id| DamageType::new(id.clone()))
+    }
+
+    /// The `Armor.resistance` key that mitigates this type. Unknown ids are
+    /// their own channel, so a stray type is still resistible by name.
+    pub fn channel<'a>(&'a self, damage_type: &'a DamageType) -> &'a str {
+        self.get(damage_type.id())
+            .map(DamageTypeDef::channel)
+            .unwrap_or(damage_type.id())
+    }
+
+    /// The combat skill a killing blow trains; ranged when the row names none.
+    pub fn skill(&self, damage_type: &DamageType) -> &str {
+        self.get(damage_type.id())
+            .map(|d| d.skill.as_str())
+            .filter(|s| !s.is_empty())
+            .unwrap_or("ranged")
+    }
+
+    /// Every distinct armour channel, sorted (the equipment.csv columns
+    /// that matter).
+    pub fn channels(&self) -> Vec<&str> {
+        let mut out: Vec<&str> = self.types.values().map(DamageTypeDef::channel).collect();
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+}
+
+/// The damage type a source deals, through the registry in the DataStore;
+/// kinetic when the registry is missing or no row claims the source.
+pub fn damage_type_for(data: &DataStore, source: &str) -> DamageType {
+    data.get::<DamageTypeRegistry>("damage_type_registry")
+        .and_then(|r| r.for_source(source))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shipped() -> DamageTypeRegistry {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/damage_types.csv");
+        DamageTypeRegistry::from_csv(&std::fs::read(path).unwrap()).unwrap()
+    }
+
+    /// The shipped rows resolve every source the rest of the data names:
+    /// ability elements, weather hazards and disasters all land on a type.
+    #[test]
+    fn shipped_types_resolve_their_sources() {
+        let reg = shipped();
+        for id in ["kinetic", "thermal", "cold", "energy", "electric", "chemical", "radiation"] {
+            assert!(reg.get(id).is_some(), "missing shipped type {id}");
+        }
+        let ty = |s: &str| reg.for_source(s).map(|t| t.0);
+        assert_eq!(ty("fire").as_deref(), Some("thermal"));
+        assert_eq!(ty("ice").as_deref(), Some("cold"));
+        assert_eq!(ty("lightning").as_deref(), Some("electric"));
+        assert_eq!(ty("blizzard").as_deref(), Some("cold"));
+        assert_eq!(ty("tornado").as_deref(), Some("kinetic"));
+        assert_eq!(ty("solar_flare").as_deref(), Some("radiation"));
+        assert_eq!(ty("cold").as_deref(), Some("cold"), "an id names itself");
+        assert_eq!(ty("tea_party"), None);
+
+        // Channels: electric rides energy armour, cold has its own column.
+        assert_eq!(reg.channel(&DamageType::new("electric")), "energy");
+        assert_eq!(reg.channel(&DamageType::new("cold")), "cold");
+        assert_eq!(reg.channel(&DamageType::new("unlisted")), "unlisted");
+        assert_eq!(reg.skill(&DamageType::kinetic()), "melee");
+        assert_eq!(reg.skill(&DamageType::new("thermal")), "ranged");
+
+        // Every status effect a type applies exists.
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/status_effects.csv");
+        let effects = crate::systems::status_effects::StatusEffectRegistry::from_csv(
+            &std::fs::read(path).unwrap(),
+        )
+        .unwrap();
+        for def in reg.types.values().filter(|d| !d.status_effect.is_empty()) {
+            assert!(effects.get(&def.status_effect).is_some(), "{}: unknown effect {}", def.id, def.status_effect);
+        }
+    }
+
+    /// A modded row needs no Rust: a new type with its own channel and an
+    /// existing one claiming a new source both resolve.
+    #[test]
+    fn a_modded_row_adds_a_type() {
+        let csv = "# comment\n\
+            id,name,armor_channel,status_effect,effect_chance,effect_s,skill,sources,description\n\
+            kinetic,Kinetic,,,0,0,melee,physical,Blunt force\n\
+            sonic,Sonic,sonic,stunned,0.5,2,ranged,screech|banshee,Pressure waves\n";
+        let reg = DamageTypeRegistry::from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(reg.for_source("banshee"), Some(DamageType::new("sonic")));
+        assert_eq!(reg.channel(&DamageType::new("sonic")), "sonic");
+        assert_eq!(reg.channels(), vec!["kinetic", "sonic"]);
+
+        let mut data = DataStore::new();
+        assert_eq!(damage_type_for(&data, "screech"), DamageType::kinetic(), "no registry: kinetic");
+        data.insert("damage_type_registry", reg);
+        assert_eq!(damage_type_for(&data, "screech").id(), "sonic");
+    }
 }
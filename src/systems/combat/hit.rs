@@ -0,0 +1,114 @@
+//! Hit tests against creature colliders.
+//!
+//! Every living non-player body with Health and a Transform is a sphere: a
+//! creature's centred at 0.8 of its `body_side` above its feet with that
+//! side as radius (the same centre the walk-up targeting cone aims at), any
+//! other body (NPCs, targets) a 0.5 m sphere at chest height. Abilities and
+//! weapons both test their rays and flight segments against this one list,
+//! so what a fireball hits and what an arrow hits never disagree.
+
+use glam::Vec3;
+
+use crate::ecs::components::{Controllable, Creature, Dead, Health, Transform};
+
+/// A body that may be hit: entity, collider centre, collider radius.
+pub type Body = (hecs::Entity, Vec3, f32);
+
+/// The crosshair ray: (origin, unit direction) in world space.
+pub type Aim = (Vec3, Vec3);
+
+/// Eye height the crosshair ray starts from when no aim was sent.
+const EYE_HEIGHT_M: f32 = 1.6;
+
+/// The ray an attack travels along: the sent crosshair ray when it has a
+/// usable direction, else straight ahead from the attacker's eyes.
+pub fn aim_ray(aim: Option<Aim>, feet: Vec3, facing: Vec3) -> Aim {
+    aim.and_then(|(o, d)| d.try_normalize().map(|d| (o, d)))
+        .unwrap_or((feet + Vec3::Y * EYE_HEIGHT_M, facing))
+}
+
+/// Every living body an attack can hit (never the player).
+pub fn colliders(world: &hecs::World) -> Vec<Body> {
+    world
+        .query::<(&Transform, &Health, Option<&Creature>)>()
+        .without::<&Dead>()
+        .without::<&Controllable>()
+        .iter()
+        .map(|(e, (t, _h, c))| match c {
+            Some(c) => (e, t.position + Vec3::Y * (c.body_side * 0.8), c.body_side.max(0.3)),
+            None => (e, t.position + Vec3::Y * 0.9, 0.5),
+        })
+        .collect()
+}
+
+/// The first body a segment from `from` along unit `dir` for `len` metres
+/// passes within `pad` of (the projectile's own radius), with the distance
+/// along the segment.
+pub fn first_on_segment(
+    bodies: &[Body],
+    from: Vec3,
+    dir: Vec3,
+    len: f32,
+    pad: f32,
+) -> Option<(hecs::Entity, f32)> {
+    bodies
+        .iter()
+        .filter_map(|&(e, c, r)| {
+            let t = (c - from).dot(dir).clamp(0.0, len);
+            let miss = (from + dir * t - c).length();
+            (miss <= r + pad).then_some((e, t))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A creature's collider scales with its body; the player and the dead
+    /// are never hit; a segment reports the nearest body it passes.
+    #[test]
+    fn segments_hit_the_nearest_living_collider() {
+        let mut world = hecs::World::new();
+        world.spawn((Controllable, Health { current: 100.0, max: 100.0 }, Transform::default()));
+        let cow = world.spawn((
+            Creature {
+                def_id: "cow".to_string(),
+                anchor: Vec3::new(0.0, 0.0, -10.0),
+                range: 0.0,
+                phase: 0.0,
+                speed: 0.0,
+                tint: [1.0; 3],
+                body_side: 1.2,
+            },
+            Health { current: 50.0, max: 50.0 },
+            Transform { position: Vec3::new(0.0, 0.0, -10.0), ..Default::default() },
+        ));
+        let dead = world.spawn((
+            Health { current: 0.0, max: 50.0 },
+            Transform { position: Vec3::new(0.0, 0.0, -4.0), ..Default::default() },
+            Dead::default(),
+        ));
+        let bodies = colliders(&world);
+        assert_eq!(bodies.len(), 1, "player and corpse are not colliders");
+        assert!(bodies.iter().all(|b| b.0 != dead));
+
+        // Waist-high ray: the big cow's 1.2 m sphere catches it 1.3 m off-axis.
+        let from = Vec3::new(1.3, 0.9, 0.0);
+        assert_eq!(first_on_segment(&bodies, from, Vec3::NEG_Z, 20.0, 0.2).map(|h| h.0), Some(cow));
+        assert_eq!(first_on_segment(&bodies, from, Vec3::NEG_Z, 5.0, 0.2), None, "too short");
+        assert_eq!(first_on_segment(&bodies, from, Vec3::X, 20.0, 0.2), None, "wrong way");
+    }
+
+    /// A sent crosshair ray is used normalized; a missing or zero one falls
+    /// back to eye height along the facing.
+    #[test]
+    fn aim_falls_back_to_the_eyes() {
+        let feet = Vec3::new(2.0, 0.0, 0.0);
+        let sent = aim_ray(Some((Vec3::Y, Vec3::new(0.0, 0.0, -4.0))), feet, Vec3::X);
+        assert_eq!(sent, (Vec3::Y, Vec3::NEG_Z));
+        let eyes = (Vec3::new(2.0, 1.6, 0.0), Vec3::X);
+        assert_eq!(aim_ray(None, feet, Vec3::X), eyes);
+        assert_eq!(aim_ray(Some((Vec3::Y, Vec3::ZERO)), feet, Vec3::X), eyes);
+    }
+}
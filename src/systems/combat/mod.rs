@@ -1,7 +1,9 @@
 //! Combat system — damage processing, hit detection, death, status effects.
 //!
 //! Handles DamageEvents queued by other systems (spells, weapons, environment).
-//! Applies armor/resistance, tracks status effects, processes death.
+//! Applies armor/resistance, tracks status effects, processes death. What a
+//! damage type means - its armour channel, the effect it leaves, the skill
+//! its kills train - is a `damage_types.csv` row (`damage::DamageTypeRegistry`).
 //!
 //! Registered live in v0.760 (combat arc increment 1). Hits arrive through
 //! the `damage_events` DataStore channel (entity bits + event, the same
@@ -10,57 +12,55 @@
 //! frame bridge settles into the killer's pack. Dead CREATURES despawn after
 //! a short decay so the pasture does not fill with corpses; a dead PLAYER is
 //! never despawned (the respawn flow owns that Dead marker).
+//!
+//! The player's own attacks are `weapons::WeaponSystem` (melee swings and
+//! ballistic rounds), hit-tested against `hit`'s creature colliders.
 
 pub mod damage;
 pub mod effects;
+pub mod hit;
+pub mod weapons;
 
 use rand::Rng;
 
-use crate::ecs::components::{Armor, Creature, Dead, Health, LootTable, Transform};
+use crate::ecs::components::{Armor, Creature, Dead, Health, LootTable, StatusEffects, Transform};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
-use damage::{DamageEvent, DamageType};
+use crate::systems::status_effects::StatusEffectRegistry;
+use damage::{DamageEvent, DamageType, DamageTypeRegistry};
 use effects::StatusEffect;
 
 /// Game-seconds a dead creature lingers before despawning.
 const CREATURE_DECAY_S: f32 = 20.0;
 
-/// Convert a DamageType enum into the lowercase string key used in Armor.resistance.
-fn damage_type_key(t: &DamageType) -> &'static str {
-    match t {
-        DamageType::Kinetic   => "kinetic",
-        DamageType::Thermal   => "thermal",
-        DamageType::Energy    => "energy",
-        DamageType::Chemical  => "chemical",
-        DamageType::Radiation => "radiation",
-    }
-}
+/// Damage per roll of a type's `effect_chance` (damage_types.csv).
+const EFFECT_ROLL_DAMAGE: f32 = 10.0;
 
 /// One player melee swing at a target (v0.765): validates the target is a
-/// living creature within `range` of `caster_pos`, queues the kinetic hit on
-/// the damage_events channel, and returns the honest status line. Pure over
-/// world + channel so lib.rs's F-key bridge and the tests share one rule.
+/// living creature within the weapon's reach of `caster_pos`, queues the hit
+/// (of the weapon's damage type) on the damage_events channel, and
+/// returns the status line - Err for a refusal, which `WeaponSystem` does not
+/// count as wear. Pure over world + channel so the system and the tests
+/// share one rule.
 pub fn player_swing(
     world: &mut hecs::World,
     data: &DataStore,
     target: hecs::Entity,
     caster_pos: glam::Vec3,
-    weapon_name: &str,
-    damage: f32,
-    range: f32,
-) -> String {
+    weapon: &weapons::Weapon,
+) -> Result<String, String> {
     if !world.contains(target)
         || world.get::<&Dead>(target).is_ok()
         || world.get::<&Health>(target).is_err()
     {
-        return "Nothing in reach".to_string();
+        return Err("Nothing in reach".to_string());
     }
     let target_pos = match world.get::<&Transform>(target) {
         Ok(t) => t.position,
-        Err(_) => return "Nothing in reach".to_string(),
+        Err(_) => return Err("Nothing in reach".to_string()),
     };
-    if (target_pos - caster_pos).length() > range + 0.5 {
-        return "Out of reach - step closer".to_string();
+    if (target_pos - caster_pos).length() > weapon.range_m + 0.5 {
+        return Err("Out of reach - step closer".to_string());
     }
     let target_name = world
         .get::<&crate::ecs::components::Name>(target)
@@ -71,15 +71,16 @@ pub fn player_swing(
             q.push((
                 target.to_bits().into(),
                 DamageEvent {
-                    damage_type: DamageType::Kinetic,
-                    amount: damage,
+                    damage_type: weapon.damage_type.clone(),
+                    amount: weapon.damage,
                     source_name: None,
                     source_is_player: true,
+                    skill: Some("melee".to_string()),
                 },
             ));
         }
     }
-    format!("{weapon_name} hits {target_name} for {damage:.0}")
+    Ok(format!("{} hits {target_name} for {:.0}", weapon.name, weapon.damage))
 }
 
 /// Combat system: processes damage events, status effects, and death.
@@ -130,43 +131,82 @@ impl System for CombatSystem {
 
         // ── Process pending damage events ──
         let events: Vec<_> = self.pending_damage.drain(..).collect();
-        let mut deaths_to_handle: Vec<(hecs::Entity, Option<String>, bool, DamageType)> =
-            Vec::new();
+        // Each death with the blow that caused it.
+        let mut deaths_to_handle: Vec<(hecs::Entity, DamageEvent)> = Vec::new();
+        let fallback = DamageTypeRegistry::default();
+        let types = data
+            .get::<DamageTypeRegistry>("damage_type_registry")
+            .unwrap_or(&fallback);
+        let effect_defs = data.get::<StatusEffectRegistry>("status_effect_registry");
+        let mut rng = rand::thread_rng();
 
         for (entity, event) in events {
             // Skip if already dead.
             if world.get::<&Dead>(entity).is_ok() { continue; }
 
-            // Apply armor mitigation: damage *= (1.0 - resistance).
-            let key = damage_type_key(&event.damage_type);
+            // Apply armor mitigation: damage *= (1.0 - resistance), read
+            // from the type's armour channel.
+            let channel = types.channel(&event.damage_type);
             let resistance = world
                 .get::<&Armor>(entity)
                 .ok()
-                .and_then(|a| a.resistance.get(key).copied())
+                .and_then(|a| a.resistance.get(channel).copied())
                 .unwrap_or(0.0)
                 .clamp(0.0, 1.0);
             let mitigated = event.amount * (1.0 - resistance);
 
+            let mut died = false;
             if let Ok(mut health) = world.get::<&mut Health>(entity) {
                 health.current = (health.current - mitigated).max(0.0);
-                if health.current <= 0.0 {
-                    log::info!(
-                        "Entity {:?} killed by {:.1} {:?} damage ({:.0}% mitigated by armor)",
-                        entity, mitigated, event.damage_type, resistance * 100.0
-                    );
-                    deaths_to_handle.push((
-                        entity,
-                        event.source_name,
-                        event.source_is_player,
-                        event.damage_type,
-                    ));
+                died = health.current <= 0.0;
+            }
+            if died {
+                log::info!(
+                    "Entity {:?} killed by {:.1} {} damage ({:.0}% mitigated by armor)",
+                    entity, mitigated, event.damage_type.id(), resistance * 100.0
+                );
+                deaths_to_handle.push((entity, event));
+                continue;
+            }
+
+            // The type's status effect: one roll per EFFECT_ROLL_DAMAGE taken
+            // (after armor), so per-tick hazards and big hits weigh the same.
+            // Entities carrying StatusEffects get it there (where movement,
+            // regen and the effect tick read it); others are tracked here.
+            if let Some(def) = types
+                .get(event.damage_type.id())
+                .filter(|d| !d.status_effect.is_empty() && d.effect_chance > 0.0)
+            {
+                let chance = (def.effect_chance * mitigated / EFFECT_ROLL_DAMAGE).min(1.0);
+                if mitigated > 0.0 && rng.gen::<f32>() < chance {
+                    let effect = effect_defs.and_then(|r| r.get(&def.status_effect));
+                    let duration = if def.effect_s > 0.0 {
+                        def.effect_s
+                    } else {
+                        effect.map(|e| e.duration_s).unwrap_or(0.0)
+                    };
+                    if duration > 0.0 {
+                        match world.get::<&mut StatusEffects>(entity) {
+                            Ok(mut fx) => fx.apply(&def.status_effect, duration),
+                            Err(_) => self.apply_effect(
+                                entity,
+                                StatusEffect::new(
+                                    def.status_effect.clone(),
+                                    effect
+                                        .map(|e| e.name.clone())
+                                        .unwrap_or_else(|| def.status_effect.clone()),
+                                    duration,
+                                    true,
+                                ),
+                            ),
+                        }
+                    }
                 }
             }
         }
 
         // ── Trigger death: insert Dead, roll loot into the loot_drops channel ──
-        let mut rng = rand::thread_rng();
-        for (entity, source_name, source_is_player, killing_type) in deaths_to_handle {
+        for (entity, killing) in deaths_to_handle {
             // Insert Dead marker (no-op if already present).
             let _ = world.insert_one(entity, Dead::default());
 
@@ -178,7 +218,7 @@ impl System for CombatSystem {
                 {
                     if let Ok(mut s) = slot.lock() {
                         if s.is_none() {
-                            *s = Some(match &source_name {
+                            *s = Some(match &killing.source_name {
                                 Some(n) => format!("killed by a {n}"),
                                 None => "killed in combat".to_string(),
                             });
@@ -189,14 +229,21 @@ impl System for CombatSystem {
             }
 
             // The player's killing blow trains a combat skill (v0.762) - the
-            // first XP source for the combat category. Kinetic kills train
-            // melee; everything else (spells, energy) trains ranged.
-            if source_is_player {
-                let skill = match killing_type {
-                    DamageType::Kinetic => "melee",
-                    _ => "ranged",
-                };
+            // first XP source for the combat category. The type's row names
+            // the skill (kinetic trains melee; spells and energy ranged)
+            // unless the weapon named its own (a rifle round trains ranged).
+            if killing.source_is_player {
+                let skill = killing.skill.as_deref().unwrap_or_else(|| types.skill(&killing.damage_type));
                 crate::systems::skills::award_skill_xp(data, skill, 10);
+                // ... and counts toward Kill objectives for the species.
+                if let Ok(creature) = world.get::<&Creature>(entity) {
+                    crate::systems::quests::push_quest_event(
+                        data,
+                        crate::systems::quests::QuestEvent::Killed {
+                            creature_id: creature.def_id.clone(),
+                        },
+                    );
+                }
             }
 
             // Roll loot if the entity has a LootTable: per entry, chance
@@ -229,7 +276,7 @@ impl System for CombatSystem {
                 // Deliver to the killer's pack ONLY when the player landed
                 // the killing blow - a wolf's kill is the wolf's dinner, not
                 // your loot. (v0.761)
-                if source_is_player {
+                if killing.source_is_player {
                     if let Some(chan) =
                         data.get::<std::sync::Mutex<Vec<(String, u32)>>>("loot_drops")
                     {
@@ -297,6 +344,15 @@ mod tests {
             "xp_grants",
             std::sync::Mutex::new(Vec::<crate::systems::skills::SkillXPEvent>::new()),
         );
+        data.insert(
+            "quest_events",
+            std::sync::Mutex::new(Vec::<crate::systems::quests::QuestEvent>::new()),
+        );
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/damage_types.csv");
+        data.insert(
+            "damage_type_registry",
+            DamageTypeRegistry::from_csv(&std::fs::read(path).unwrap()).unwrap(),
+        );
         data
     }
 
@@ -338,10 +394,11 @@ mod tests {
                 .lock()
                 .unwrap()
                 .push((hen.to_bits().into(), DamageEvent {
-                    damage_type: DamageType::Thermal,
+                    damage_type: DamageType::new("thermal"),
                     amount,
                     source_name: None,
                     source_is_player: true,
+                    skill: None,
                 }));
         };
 
@@ -371,6 +428,17 @@ mod tests {
             .map(|g| (g.skill_id.clone(), g.amount))
             .collect::<Vec<_>>();
         assert_eq!(grants, vec![("ranged".to_string(), 10)]);
+        // ... and counted toward Kill objectives for its species.
+        let kills = data
+            .get::<std::sync::Mutex<Vec<crate::systems::quests::QuestEvent>>>("quest_events")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .clone();
+        assert_eq!(
+            kills,
+            vec![crate::systems::quests::QuestEvent::Killed { creature_id: "chicken".into() }]
+        );
 
         // Overkill on a corpse: no double death, no double loot.
         push_hit(50.0);
@@ -404,10 +472,11 @@ mod tests {
             .lock()
             .unwrap()
             .push((hen.to_bits().into(), DamageEvent {
-                damage_type: DamageType::Thermal,
+                damage_type: DamageType::new("thermal"),
                 amount: 10.0,
                 source_name: None,
                 source_is_player: true,
+                skill: None,
             }));
         sys.tick(&mut world, 0.016, &data);
         assert_eq!(
@@ -423,10 +492,11 @@ mod tests {
             .lock()
             .unwrap()
             .push((hen.to_bits().into(), DamageEvent {
-                damage_type: DamageType::Kinetic,
+                damage_type: DamageType::kinetic(),
                 amount: 100.0,
                 source_name: None,
                 source_is_player: true,
+                skill: None,
             }));
         sys.tick(&mut world, 0.016, &data);
         assert!(world.get::<&Dead>(hen).is_ok());
@@ -436,8 +506,8 @@ mod tests {
         assert!(world.contains(player), "a dead player is never despawned");
     }
 
-    /// The melee swing (v0.765): in-range hits queue kinetic damage with the
-    /// weapon's number; out-of-range and dead targets refuse without a hit.
+    /// The melee swing (v0.765): in-range hits queue the weapon's damage;
+    /// out-of-range and dead targets refuse without a hit.
     #[test]
     fn player_swing_hits_in_range_and_refuses_otherwise() {
         use glam::Vec3;
@@ -447,8 +517,15 @@ mod tests {
         let hen = spawn_hen(&mut world);
         // Hen sits at origin (spawn_hen default Transform).
 
+        let axe = weapons::Weapon {
+            name: "Axe".to_string(),
+            damage: 14.0,
+            range_m: 2.2,
+            ..weapons::Weapon::bare_hands()
+        };
+
         // In axe range (2.2 + 0.5 tolerance) from 2m away: hit for 14.
-        let msg = player_swing(&mut world, &data, hen, Vec3::new(2.0, 0.0, 0.0), "Axe", 14.0, 2.2);
+        let msg = player_swing(&mut world, &data, hen, Vec3::new(2.0, 0.0, 0.0), &axe).unwrap();
         assert!(msg.contains("hits") && msg.contains("14"), "got: {msg}");
         let queued = data
             .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
@@ -461,7 +538,7 @@ mod tests {
         assert!(queued[0].1.source_is_player);
 
         // Too far: refused, nothing queued.
-        let msg = player_swing(&mut world, &data, hen, Vec3::new(9.0, 0.0, 0.0), "Axe", 14.0, 2.2);
+        let msg = player_swing(&mut world, &data, hen, Vec3::new(9.0, 0.0, 0.0), &axe).unwrap_err();
         assert!(msg.contains("Out of reach"), "got: {msg}");
         let n = data
             .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
@@ -473,7 +550,7 @@ mod tests {
 
         // Dead target: refused.
         world.insert_one(hen, Dead::default()).unwrap();
-        let msg = player_swing(&mut world, &data, hen, Vec3::new(1.0, 0.0, 0.0), "Axe", 14.0, 2.2);
+        let msg = player_swing(&mut world, &data, hen, Vec3::new(1.0, 0.0, 0.0), &axe).unwrap_err();
         assert!(msg.contains("Nothing in reach"), "got: {msg}");
     }
 
@@ -502,10 +579,11 @@ mod tests {
             .lock()
             .unwrap()
             .push((player.to_bits().into(), DamageEvent {
-                damage_type: DamageType::Kinetic,
+                damage_type: DamageType::kinetic(),
                 amount: 10.0,
                 source_name: Some("Wolf".to_string()),
                 source_is_player: false,
+                skill: None,
             }));
         // The wolf also kills the hen: no loot for the player's pack.
         data.get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
@@ -513,10 +591,11 @@ mod tests {
             .lock()
             .unwrap()
             .push((hen.to_bits().into(), DamageEvent {
-                damage_type: DamageType::Kinetic,
+                damage_type: DamageType::kinetic(),
                 amount: 100.0,
                 source_name: Some("Wolf".to_string()),
                 source_is_player: false,
+                skill: None,
             }));
         sys.tick(&mut world, 0.016, &data);
 
@@ -538,4 +617,69 @@ mod tests {
             .clone();
         assert!(drops.is_empty(), "a wolf's kill is not the player's loot");
     }
+
+    /// Mitigation follows the registry's armour channel (electric
+    /// rides energy armour, a modded "cold" row its own column), a hit can
+    /// leave the type's status effect, and the row names the kill skill.
+    #[test]
+    fn registry_rows_drive_mitigation_effects_and_skill() {
+        let mut world = hecs::World::new();
+        let mut data = store_with_channels();
+        let csv = "id,name,armor_channel,status_effect,effect_chance,effect_s,skill,sources,description\n\
+            electric,Electric,energy,,0,0,ranged,lightning,Current\n\
+            cold,Cold,cold,frostbite,10,20,melee,ice,Freezing\n";
+        data.insert("damage_type_registry", DamageTypeRegistry::from_csv(csv.as_bytes()).unwrap());
+
+        let hen = spawn_hen(&mut world);
+        let mut resistance = std::collections::HashMap::new();
+        resistance.insert("energy".to_string(), 0.5f32);
+        resistance.insert("cold".to_string(), 0.25f32);
+        world
+            .insert(hen, (Armor { resistance }, StatusEffects::default()))
+            .unwrap();
+
+        let mut sys = CombatSystem::new();
+        let hit = |ty: &str, amount: f32| {
+            data.get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+                .unwrap()
+                .lock()
+                .unwrap()
+                .push((hen.to_bits().into(), DamageEvent {
+                    damage_type: DamageType::new(ty),
+                    amount,
+                    source_name: None,
+                    source_is_player: true,
+                    skill: None,
+                }));
+        };
+        hit("electric", 4.0);
+        sys.tick(&mut world, 0.016, &data);
+        assert_eq!(world.get::<&Health>(hen).unwrap().current, 13.0, "energy armour halves electric");
+
+        hit("cold", 4.0);
+        sys.tick(&mut world, 0.016, &data);
+        assert_eq!(world.get::<&Health>(hen).unwrap().current, 10.0, "the cold channel takes a quarter");
+        let fx = world.get::<&StatusEffects>(hen).unwrap();
+        assert!(fx.has("frostbite"), "a certain roll leaves frostbite");
+        assert_eq!(fx.active[0].remaining, 20.0, "for the row's effect_s");
+        drop(fx);
+
+        // An unlisted type is its own channel and unmitigated here.
+        hit("sonic", 2.0);
+        sys.tick(&mut world, 0.016, &data);
+        assert_eq!(world.get::<&Health>(hen).unwrap().current, 8.0);
+
+        // A cold kill trains the row's skill.
+        hit("cold", 100.0);
+        sys.tick(&mut world, 0.016, &data);
+        let grants = data
+            .get::<std::sync::Mutex<Vec<crate::systems::skills::SkillXPEvent>>>("xp_grants")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|g| g.skill_id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(grants, vec!["melee".to_string()]);
+    }
 }
@@ -0,0 +1,568 @@
+//! Player weapons (combat arc): the hands-slot item swings or fires.
+//!
+//! A weapon is an equipment.csv row in the `hands` slot with `damage > 0`;
+//! anything else (gloves, nothing at all) is bare hands. Melee rows strike the
+//! faced creature, else the first collider on the crosshair within reach.
+//! Rows with `ammo` fire a round: one ammo item leaves the pack `Inventory`,
+//! and the round flies at `projectile_speed_mps` under gravity, tested
+//! against creature colliders (`hit`) each frame until it strikes or has
+//! flown its `range_m`. `attack_interval_s` paces swings and shots, and every
+//! swing that connects or shot fired wears the weapon one point of its
+//! items.csv durability (`Outfit.wear`); worn through, it breaks and leaves
+//! the hands. Every hit rides `damage_events`, so armour, death, loot and
+//! kill XP are CombatSystem's as for any other source.
+
+use glam::Vec3;
+
+use super::damage::{damage_type_for, DamageEvent, DamageType};
+use super::hit::{aim_ray, colliders, first_on_segment, Aim};
+use super::player_swing;
+use crate::ecs::components::{Controllable, Outfit, Transform};
+use crate::ecs::systems::System;
+use crate::hot_reload::data_store::DataStore;
+use crate::systems::economy::EquipmentRegistry;
+use crate::systems::inventory::{Inventory, ItemRegistry};
+
+/// Seconds between swings for rows (and hands) with no `attack_interval_s`.
+pub const DEFAULT_ATTACK_INTERVAL_S: f32 = 0.8;
+/// Bare hands: a shove, not a weapon.
+const BARE_HANDS_DAMAGE: f32 = 3.0;
+/// Shortest reach any swing has (bare hands, stubby tools).
+const MIN_REACH_M: f32 = 1.5;
+const GRAVITY_MPS2: f32 = 9.81;
+/// Radius of a round, added to the collider it may hit.
+const ROUND_RADIUS_M: f32 = 0.05;
+
+/// One attack on the `attack_request` channel (F / the attack bind).
+#[derive(Debug, Clone, Default)]
+pub struct AttackRequest {
+    /// Entity bits of the creature the player faces, if any.
+    pub target: Option<u64>,
+    /// Where the player was looking; None swings straight ahead.
+    pub aim: Option<Aim>,
+}
+
+/// The weapon in the player's hands, resolved from equipment.csv + items.csv.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weapon {
+    /// items.csv id; None = bare hands.
+    pub item_id: Option<String>,
+    pub name: String,
+    pub damage: f32,
+    pub damage_type: DamageType,
+    /// Melee reach, or how far a round flies before it is spent.
+    pub range_m: f32,
+    pub interval_s: f32,
+    /// items.csv id each shot takes from the pack; None = melee.
+    pub ammo: Option<String>,
+    pub projectile_speed_mps: f32,
+    /// Uses before it breaks; 0 = never wears.
+    pub durability: u32,
+}
+
+impl Weapon {
+    pub fn bare_hands() -> Self {
+        Self {
+            item_id: None,
+            name: "Bare hands".to_string(),
+            damage: BARE_HANDS_DAMAGE,
+            damage_type: DamageType::kinetic(),
+            range_m: MIN_REACH_M,
+            interval_s: DEFAULT_ATTACK_INTERVAL_S,
+            ammo: None,
+            projectile_speed_mps: 0.0,
+            durability: 0,
+        }
+    }
+
+    pub fn is_ranged(&self) -> bool {
+        self.ammo.is_some() && self.projectile_speed_mps > 0.0
+    }
+
+    /// What `item_id` is as a weapon; None when it has no damage row.
+    pub fn from_item(data: &DataStore, item_id: &str) -> Option<Self> {
+        let def = data
+            .get::<EquipmentRegistry>("equipment_registry")?
+            .get(item_id)
+            .filter(|d| d.damage > 0.0)?;
+        let item = data
+            .get::<ItemRegistry>("item_registry")
+            .and_then(|r| r.items.get(item_id));
+        let ammo = Some(def.ammo.clone()).filter(|a| !a.is_empty());
+        let ranged = ammo.is_some() && def.projectile_speed_mps > 0.0;
+        Some(Self {
+            item_id: Some(item_id.to_string()),
+            name: item.map(|d| d.name.clone()).unwrap_or_else(|| item_id.to_string()),
+            damage: def.damage,
+            damage_type: damage_type_for(data, &def.damage_type),
+            range_m: if ranged { def.range_m } else { def.range_m.max(MIN_REACH_M) },
+            interval_s: if def.attack_interval_s > 0.0 {
+                def.attack_interval_s
+            } else {
+                DEFAULT_ATTACK_INTERVAL_S
+            },
+            ammo,
+            projectile_speed_mps: def.projectile_speed_mps,
+            durability: item.map(|d| d.durability).unwrap_or(0),
+        })
+    }
+
+    /// The weapon in the player's hands, or bare hands.
+    pub fn held(world: &hecs::World, data: &DataStore) -> Self {
+        world
+            .query::<(&Outfit, &Controllable)>()
+            .iter()
+            .next()
+            .and_then(|(_, (o, _))| o.equipped.get("hands").cloned())
+            .and_then(|id| Self::from_item(data, &id))
+            .unwrap_or_else(Self::bare_hands)
+    }
+}
+
+/// A round in flight.
+#[derive(Debug, Clone)]
+pub struct Round {
+    pub position: Vec3,
+    pub velocity: Vec3,
+    /// Metres it may still fly before it is spent.
+    pub remaining_m: f32,
+    weapon_name: String,
+    damage: f32,
+    damage_type: DamageType,
+}
+
+/// Drains `attack_request`, swings or fires the held weapon, flies rounds
+/// and reports one line through `weapon_status`; rounds in the air are
+/// published to `weapon_markers` for the overlay.
+pub struct WeaponSystem {
+    /// Seconds until the next swing or shot.
+    ready_in: f32,
+    rounds: Vec<Round>,
+}
+
+impl WeaponSystem {
+    pub fn new() -> Self {
+        Self { ready_in: 0.0, rounds: Vec::new() }
+    }
+
+    /// Rounds still in flight.
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// One attack. Returns the status line; empty while the last swing or
+    /// shot is still recovering (held F does not spam the HUD).
+    fn attack(&mut self, world: &mut hecs::World, data: &DataStore, req: &AttackRequest) -> String {
+        if self.ready_in > 0.0 {
+            return String::new();
+        }
+        let Some((player, pos, facing)) = world
+            .query::<(&Transform, &Controllable)>()
+            .iter()
+            .next()
+            .map(|(e, (t, _))| (e, t.position, t.rotation * Vec3::NEG_Z))
+        else {
+            return String::new();
+        };
+        let weapon = Weapon::held(world, data);
+        let (origin, dir) = aim_ray(req.aim, pos, facing);
+        let faced = req
+            .target
+            .and_then(hecs::Entity::from_bits)
+            .filter(|e| world.contains(*e));
+
+        if let Some(ammo) = &weapon.ammo {
+            if weapon.is_ranged() {
+                let items = data.get::<ItemRegistry>("item_registry");
+                let ammo_name = items
+                    .and_then(|r| r.items.get(ammo))
+                    .map(|d| d.name.clone())
+                    .unwrap_or_else(|| ammo.clone());
+                let left = {
+                    let Ok(mut inv) = world.get::<&mut Inventory>(player) else {
+                        return format!("Out of {ammo_name}");
+                    };
+                    if inv.count_item(ammo) == 0 {
+                        return format!("Out of {ammo_name}");
+                    }
+                    inv.remove_item(ammo, 1);
+                    inv.count_item(ammo)
+                };
+                // A faced creature is aimed at directly; otherwise the
+                // round leaves along the crosshair.
+                let bodies = colliders(world);
+                let aim = faced
+                    .and_then(|e| bodies.iter().find(|b| b.0 == e))
+                    .and_then(|b| (b.1 - origin).try_normalize())
+                    .unwrap_or(dir);
+                self.rounds.push(Round {
+                    position: origin,
+                    velocity: aim * weapon.projectile_speed_mps,
+                    remaining_m: weapon.range_m,
+                    weapon_name: weapon.name.clone(),
+                    damage: weapon.damage,
+                    damage_type: weapon.damage_type.clone(),
+                });
+                self.ready_in = weapon.interval_s;
+                let line = format!("{} fired - {left} {ammo_name} left", weapon.name);
+                return wear(world, player, &weapon, line);
+            }
+        }
+
+        // Melee: the faced creature, else the first collider on the
+        // crosshair within reach. A whiff still costs the swing.
+        self.ready_in = weapon.interval_s;
+        let target = faced.or_else(|| {
+            first_on_segment(&colliders(world), origin, dir, weapon.range_m, ROUND_RADIUS_M)
+                .map(|(e, _)| e)
+        });
+        let Some(target) = target else {
+            return "Nothing in reach".to_string();
+        };
+        match player_swing(world, data, target, pos, &weapon) {
+            Ok(line) => wear(world, player, &weapon, line),
+            Err(refusal) => refusal,
+        }
+    }
+}
+
+impl Default for WeaponSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One use off the weapon's durability; at zero it breaks and leaves the
+/// hands. Returns `line` with any wear note appended.
+fn wear(world: &mut hecs::World, player: hecs::Entity, weapon: &Weapon, line: String) -> String {
+    let Some(id) = &weapon.item_id else { return line };
+    if weapon.durability == 0 {
+        return line;
+    }
+    let Ok(mut outfit) = world.get::<&mut Outfit>(player) else {
+        return line;
+    };
+    let used = outfit.wear.entry(id.clone()).or_insert(0);
+    *used += 1;
+    let left = weapon.durability.saturating_sub(*used);
+    if left == 0 {
+        outfit.wear.remove(id);
+        outfit.equipped.remove("hands");
+        return format!("{line} - your {} breaks", weapon.name);
+    }
+    if left == (weapon.durability / 10).max(1) {
+        return format!("{line} - {} is nearly worn out", weapon.name);
+    }
+    line
+}
+
+impl System for WeaponSystem {
+    fn name(&self) -> &str {
+        "WeaponSystem"
+    }
+
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
+        self.ready_in = (self.ready_in - dt).max(0.0);
+
+        let requests: Vec<AttackRequest> = data
+            .get::<std::sync::Mutex<Vec<AttackRequest>>>("attack_request")
+            .and_then(|m| m.lock().ok().map(|mut v| std::mem::take(&mut *v)))
+            .unwrap_or_default();
+        let mut status = String::new();
+        for req in &requests {
+            let line = self.attack(world, data, req);
+            if !line.is_empty() {
+                status = line;
+            }
+        }
+
+        // Ballistics: each frame's arc segment is tested against every
+        // collider it passes.
+        if !self.rounds.is_empty() && dt > 0.0 {
+            let bodies = colliders(world);
+            let mut hits: Vec<(u64, DamageEvent)> = Vec::new();
+            self.rounds.retain_mut(|r| {
+                r.velocity.y -= GRAVITY_MPS2 * dt;
+                let step = r.velocity * dt;
+                let len = step.length().min(r.remaining_m);
+                let Some(dir) = step.try_normalize() else { return false };
+                if let Some((e, _)) = first_on_segment(&bodies, r.position, dir, len, ROUND_RADIUS_M) {
+                    let name = world
+                        .get::<&crate::ecs::components::Name>(e)
+                        .map(|n| n.0.clone())
+                        .unwrap_or_else(|_| "the target".to_string());
+                    status = format!("{} hits {name} for {:.0}", r.weapon_name, r.damage);
+                    hits.push((
+                        e.to_bits().into(),
+                        DamageEvent {
+                            damage_type: r.damage_type.clone(),
+                            amount: r.damage,
+                            source_name: None,
+                            source_is_player: true,
+                            skill: Some("ranged".to_string()),
+                        },
+                    ));
+                    return false;
+                }
+                r.position += dir * len;
+                r.remaining_m -= len;
+                if r.remaining_m > 1e-3 {
+                    return true;
+                }
+                status = format!("{} misses", r.weapon_name);
+                false
+            });
+            if !hits.is_empty() {
+                if let Some(mut q) = data
+                    .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+                    .and_then(|m| m.lock().ok())
+                {
+                    q.extend(hits);
+                }
+            }
+        }
+
+        if !status.is_empty() {
+            if let Some(mut slot) = data
+                .get::<std::sync::Mutex<String>>("weapon_status")
+                .and_then(|m| m.lock().ok())
+            {
+                *slot = status;
+            }
+        }
+        if let Some(mut slot) = data
+            .get::<std::sync::Mutex<Vec<(Vec3, f32)>>>("weapon_markers")
+            .and_then(|m| m.lock().ok())
+        {
+            slot.clear();
+            slot.extend(self.rounds.iter().map(|r| (r.position, 0.15)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::{Health, Name};
+    use crate::systems::combat::damage::DamageTypeRegistry;
+
+    fn read(rel: &str) -> Vec<u8> {
+        std::fs::read(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(rel)).unwrap()
+    }
+
+    /// A player holding `hands` with a pack, facing -Z, and the channels.
+    fn armed(hands: Option<&str>) -> (hecs::World, DataStore, hecs::Entity) {
+        let mut world = hecs::World::new();
+        let mut outfit = Outfit::default();
+        if let Some(id) = hands {
+            outfit.equipped.insert("hands".to_string(), id.to_string());
+        }
+        let player = world.spawn((
+            Controllable,
+            Transform::default(),
+            Inventory::new(20),
+            outfit,
+        ));
+        let mut data = DataStore::new();
+        data.insert(
+            "equipment_registry",
+            EquipmentRegistry::from_csv(&read("data/equipment.csv")).unwrap(),
+        );
+        data.insert("item_registry", ItemRegistry::from_csv(&read("data/items.csv")).unwrap());
+        data.insert(
+            "damage_type_registry",
+            DamageTypeRegistry::from_csv(&read("data/damage_types.csv")).unwrap(),
+        );
+        data.insert("attack_request", std::sync::Mutex::new(Vec::<AttackRequest>::new()));
+        data.insert("weapon_status", std::sync::Mutex::new(String::new()));
+        data.insert("weapon_markers", std::sync::Mutex::new(Vec::<(Vec3, f32)>::new()));
+        data.insert("damage_events", std::sync::Mutex::new(Vec::<(u64, DamageEvent)>::new()));
+        (world, data, player)
+    }
+
+    fn target(world: &mut hecs::World, at: Vec3) -> hecs::Entity {
+        world.spawn((
+            Name("Wolf".to_string()),
+            Health { current: 200.0, max: 200.0 },
+            Transform { position: at, ..Default::default() },
+        ))
+    }
+
+    fn attack(sys: &mut WeaponSystem, world: &mut hecs::World, data: &DataStore, req: AttackRequest) {
+        data.get::<std::sync::Mutex<Vec<AttackRequest>>>("attack_request")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .push(req);
+        sys.tick(world, 0.0, data);
+    }
+
+    fn status(data: &DataStore) -> String {
+        data.get::<std::sync::Mutex<String>>("weapon_status").unwrap().lock().unwrap().clone()
+    }
+
+    fn hits(data: &DataStore) -> Vec<(u64, f32)> {
+        data.get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|(e, ev)| (e, ev.amount))
+            .collect()
+    }
+
+    /// The shipped rows resolve: tools swing, bows and guns fire their ammo,
+    /// durability comes from items.csv, and gloves are bare hands.
+    #[test]
+    fn held_weapons_resolve_from_the_shipped_rows() {
+        let (_, data, _) = armed(None);
+        let axe = Weapon::from_item(&data, "axe_0").expect("axe is a weapon");
+        assert!(!axe.is_ranged());
+        assert_eq!((axe.damage, axe.interval_s, axe.durability), (14.0, 1.0, 200));
+        let bow = Weapon::from_item(&data, "bow_recurve_0").expect("bow is a weapon");
+        assert!(bow.is_ranged());
+        assert_eq!(bow.ammo.as_deref(), Some("arrow_0"));
+        for id in ["bow_compound_0", "crossbow_0", "pistol_0", "rifle_0"] {
+            let w = Weapon::from_item(&data, id).unwrap_or_else(|| panic!("{id} missing"));
+            let ammo = w.ammo.clone().unwrap();
+            assert!(
+                data.get::<ItemRegistry>("item_registry").unwrap().items.contains_key(&ammo),
+                "{id}: unknown ammo {ammo}"
+            );
+        }
+        assert_eq!(Weapon::from_item(&data, "gloves_work_0"), None, "gloves are not weapons");
+    }
+
+    /// Melee: the crosshair finds a creature within reach, the swing queues
+    /// the hit, the interval paces the next swing, and a whiff hits nothing.
+    #[test]
+    fn melee_swings_hit_in_reach_and_are_paced() {
+        let (mut world, data, _) = armed(Some("spear_0"));
+        let mut sys = WeaponSystem::new();
+        let wolf = target(&mut world, Vec3::new(0.0, 0.0, -2.5));
+        let aim = Some((Vec3::new(0.0, 1.0, 0.0), Vec3::NEG_Z));
+
+        attack(&mut sys, &mut world, &data, AttackRequest { target: None, aim });
+        assert!(status(&data).contains("Spear hits Wolf for 15"), "got: {}", status(&data));
+        assert_eq!(hits(&data), vec![(u64::from(wolf.to_bits()), 15.0)]);
+
+        // Again at once: still recovering - nothing happens, no new line.
+        attack(&mut sys, &mut world, &data, AttackRequest { target: None, aim });
+        assert!(hits(&data).is_empty(), "the spear's 1s interval paces swings");
+
+        // Recovered, but aimed away: a whiff.
+        sys.tick(&mut world, 1.0, &data);
+        let away = Some((Vec3::new(0.0, 1.0, 0.0), Vec3::X));
+        attack(&mut sys, &mut world, &data, AttackRequest { target: None, aim: away });
+        assert_eq!(status(&data), "Nothing in reach");
+    }
+
+    /// Ranged: a shot takes one arrow, flies, drops under gravity and hits
+    /// the creature on its path; with no arrows left the bow refuses.
+    #[test]
+    fn ranged_shots_spend_ammo_and_fly_to_the_target() {
+        let (mut world, data, player) = armed(Some("bow_recurve_0"));
+        world.get::<&mut Inventory>(player).unwrap().add_item("arrow_0", 1, 50);
+        let mut sys = WeaponSystem::new();
+        let wolf = target(&mut world, Vec3::new(0.0, 0.0, -20.0));
+        let aim = Some((Vec3::new(0.0, 1.2, 0.0), Vec3::NEG_Z));
+
+        attack(&mut sys, &mut world, &data, AttackRequest { target: None, aim });
+        assert!(status(&data).contains("0 Arrow left"), "got: {}", status(&data));
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item("arrow_0"), 0);
+        assert_eq!(sys.rounds().len(), 1);
+        assert!(hits(&data).is_empty(), "an arrow takes time to arrive");
+
+        for _ in 0..30 {
+            sys.tick(&mut world, 1.0 / 60.0, &data);
+        }
+        assert!(sys.rounds().is_empty(), "the arrow landed");
+        assert!(status(&data).contains("hits Wolf for 16"), "got: {}", status(&data));
+        assert_eq!(hits(&data), vec![(u64::from(wolf.to_bits()), 16.0)]);
+
+        sys.tick(&mut world, 2.0, &data);
+        attack(&mut sys, &mut world, &data, AttackRequest { target: None, aim });
+        assert_eq!(status(&data), "Out of Arrow");
+        assert!(sys.rounds().is_empty());
+    }
+
+    /// A rifle round is kinetic, which alone would train melee; the weapon
+    /// names its own skill, so the kill trains ranged.
+    #[test]
+    fn rifle_kills_train_ranged() {
+        let (mut world, mut data, player) = armed(Some("rifle_0"));
+        data.insert("loot_drops", std::sync::Mutex::new(Vec::<(String, u32)>::new()));
+        data.insert("quest_events", std::sync::Mutex::new(Vec::<crate::systems::quests::QuestEvent>::new()));
+        data.insert("xp_grants", std::sync::Mutex::new(Vec::<crate::systems::skills::SkillXPEvent>::new()));
+        world.get::<&mut Inventory>(player).unwrap().add_item("bullet_rifle_0", 1, 50);
+        let wolf = target(&mut world, Vec3::new(0.0, 0.0, -20.0));
+        world.get::<&mut Health>(wolf).unwrap().current = 10.0;
+        let mut sys = WeaponSystem::new();
+        attack(&mut sys, &mut world, &data, AttackRequest { target: None, aim: Some((Vec3::new(0.0, 1.2, 0.0), Vec3::NEG_Z)) });
+        for _ in 0..10 {
+            sys.tick(&mut world, 1.0 / 60.0, &data);
+        }
+        crate::systems::combat::CombatSystem::new().tick(&mut world, 0.016, &data);
+        assert!(world.get::<&crate::ecs::components::Dead>(wolf).is_ok(), "{}", status(&data));
+        let grants: Vec<_> = data
+            .get::<std::sync::Mutex<Vec<crate::systems::skills::SkillXPEvent>>>("xp_grants")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|g| g.skill_id.clone())
+            .collect();
+        assert_eq!(grants, vec!["ranged".to_string()]);
+    }
+
+    /// A round spent at its range misses; gravity pulls it below the aim.
+    #[test]
+    fn rounds_fall_and_are_spent_at_range() {
+        let (mut world, data, player) = armed(Some("bow_recurve_0"));
+        world.get::<&mut Inventory>(player).unwrap().add_item("arrow_0", 5, 50);
+        let mut sys = WeaponSystem::new();
+        attack(&mut sys, &mut world, &data, AttackRequest {
+            target: None,
+            aim: Some((Vec3::new(0.0, 1.6, 0.0), Vec3::NEG_Z)),
+        });
+        sys.tick(&mut world, 0.5, &data);
+        assert!(sys.rounds()[0].position.y < 1.6 - 1.0, "half a second of drop");
+        for _ in 0..10 {
+            sys.tick(&mut world, 0.5, &data);
+        }
+        assert!(sys.rounds().is_empty());
+        assert_eq!(status(&data), "Recurve Bow misses");
+    }
+
+    /// Each connecting swing wears the weapon; worn through it breaks and
+    /// leaves the hands, and the next swing is bare-handed.
+    #[test]
+    fn weapons_wear_out_and_break() {
+        let (mut world, data, player) = armed(Some("knife_utility_0"));
+        let mut sys = WeaponSystem::new();
+        let wolf = target(&mut world, Vec3::new(0.0, 0.0, -1.0));
+        let faced = AttackRequest { target: Some(wolf.to_bits().into()), aim: None };
+        let set_wear = |world: &mut hecs::World, used: u32| {
+            world.get::<&mut Outfit>(player).unwrap().wear.insert("knife_utility_0".to_string(), used);
+        };
+
+        // 120 points: the warning lands with a tenth left...
+        set_wear(&mut world, 107);
+        attack(&mut sys, &mut world, &data, faced.clone());
+        assert!(status(&data).contains("nearly worn out"), "got: {}", status(&data));
+        // ... and the last point breaks it.
+        set_wear(&mut world, 119);
+        sys.tick(&mut world, 1.0, &data);
+        attack(&mut sys, &mut world, &data, faced.clone());
+        assert!(status(&data).contains("your Utility Knife breaks"), "got: {}", status(&data));
+        {
+            let outfit = world.get::<&Outfit>(player).unwrap();
+            assert!(!outfit.equipped.contains_key("hands"));
+            assert!(!outfit.wear.contains_key("knife_utility_0"));
+        }
+        hits(&data);
+        sys.tick(&mut world, 1.0, &data);
+        attack(&mut sys, &mut world, &data, faced);
+        assert!(status(&data).contains("Bare hands hits Wolf for 3"), "got: {}", status(&data));
+    }
+}
@@ -0,0 +1,600 @@
+//! Framing graphs for the homes players build.
+//!
+//! `solver` proved the node-beam model on hand-built frames; this module feeds it real geometry. A
+//! zone's `HomeStructure` is framed the way a builder would frame it: every wall -- the four shell
+//! edges and each interior wall -- gets a stud every `STUD_SPACING_M` and at both jambs of every
+//! door and window, tied along the top by the wall itself and, over an opening, by a header. Studs
+//! and headers take their capacity from the wall's `wall_materials.ron` tensile strength and its
+//! thickness; a header is checked as a simply supported beam, so it weakens with span and gains
+//! with the square of the wall left above the opening. The roof rests on the full-height walls.
+//! Decks rest on whatever wall tops, stairs, ladders and elevator frames meet them at their level,
+//! and anything standing above the floor needs a deck under it.
+//!
+//! An opening that runs past either end of its wall has nothing to bear on there, so the wall
+//! above it hangs free: the solver islands it and the wall comes down. That is the door cut wider
+//! than its wall.
+//!
+//! The construction editor re-solves every zone on each design change and outlines strained and
+//! broken members; in survival (creative mode off) `settle_ship` takes the failed walls and pieces
+//! down before the home is re-meshed.
+
+use glam::Vec3;
+
+use super::solver::{member_capacity, solve, FramingMember, FramingNode, MemberState, SolveResult, StructuralVerdict};
+use crate::ship::home_structure::{wall_material, HomeStructure, OpeningKind, ShellCut};
+use crate::ship::ship_structure::ShipStructure;
+use crate::ship::structure::{local_to_world_xz, near_footprint, structure_type, StructureKind};
+
+/// Stud spacing along a wall (metres) -- 600 mm centres, the common framing module.
+const STUD_SPACING_M: f32 = 0.6;
+/// Stations closer than this (metres) are the same station; also the jamb tolerance.
+const EDGE_EPS_M: f32 = 0.01;
+/// Walls shorter than this (metres) are slivers and carry nothing.
+const MIN_WALL_M: f32 = 0.05;
+/// Least wall strip a stud stands in (metres): a mullion squeezed between two openings.
+const MIN_TRIB_M: f32 = 0.05;
+/// Less wall than this (metres) above an opening is no header: the opening reaches the top.
+const MIN_HEADER_DEPTH_M: f32 = 0.02;
+/// A deck and what it rests on must meet within this height (metres).
+const LEVEL_TOL_M: f32 = 0.3;
+/// How far past a deck's footprint (metres) a wall top or stair head still bears on it.
+const BEARING_REACH_M: f32 = 0.3;
+const GRAVITY: f32 = 9.81;
+/// Self-weight of a deck or stair flight per square metre of footprint (Pa).
+const DEAD_LOAD_PA: f32 = 1000.0;
+/// Occupancy load per square metre (Pa) -- the residential 2 kPa design floor load.
+const LIVE_LOAD_PA: f32 = 2000.0;
+/// An elevator car at its rated load (N), hung from the top of its frame.
+const ELEVATOR_CAR_N: f32 = 10_000.0;
+/// Cross-section (m²) of a stair stringer pair, ladder rail pair or elevator post.
+const PIECE_MEMBER_M2: f32 = 0.004;
+/// Cross-section (m²) of the joists tying a deck to each thing it rests on.
+const DECK_JOIST_M2: f32 = 0.006;
+/// Simply supported beam under a uniform load: it fails at W = 8·σ·S / L with S = t·d² / 6.
+const HEADER_FACTOR: f32 = 8.0 / 6.0;
+/// Tensile strength (MPa) + density (kg/m³) of a material id the registry does not know (steel).
+const FALLBACK_STRENGTH: (f32, f32) = (250.0, 7850.0);
+/// Collapse passes `settle` runs: each one can strand what rested on the last one's rubble.
+const SETTLE_PASSES: usize = 8;
+
+/// What a node or member belongs to, so a solve can be read back as "which wall failed".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FramingPart {
+    /// A perimeter shell edge, in `ShellCut.edge` winding (0: z=0, 1: x=w, 2: z=d, 3: x=0).
+    Shell(usize),
+    /// `HomeStructure.walls[i]`.
+    Wall(usize),
+    /// `HomeStructure.structures[i]` -- a deck, stair flight, ladder or elevator frame.
+    Piece(usize),
+}
+
+impl FramingPart {
+    /// How the editor names the part ("Wall 3", "Stairs 2", "Outer wall 1").
+    pub fn label(&self, hs: &HomeStructure) -> String {
+        match *self {
+            FramingPart::Shell(e) => format!("Outer wall {}", e + 1),
+            FramingPart::Wall(i) => format!("Wall {}", i + 1),
+            FramingPart::Piece(i) => {
+                let name = hs
+                    .structures
+                    .get(i)
+                    .and_then(|ps| structure_type(&ps.type_id))
+                    .map_or("Piece", |t| t.label.as_str());
+                format!("{name} {}", i + 1)
+            }
+        }
+    }
+}
+
+/// A design fault found while framing, before any load is applied.
+#[derive(Debug, Clone)]
+pub struct FramingIssue {
+    pub part: FramingPart,
+    pub message: String,
+}
+
+/// A zone's frame, in world space (the zone origin applied), ready for `solver::solve`.
+#[derive(Debug, Clone, Default)]
+pub struct FramingGraph {
+    pub nodes: Vec<FramingNode>,
+    pub members: Vec<FramingMember>,
+    /// Owner of each node (parallel to `nodes`).
+    pub node_part: Vec<FramingPart>,
+    /// Owner of each member (parallel to `members`).
+    pub member_part: Vec<FramingPart>,
+    /// Nodes a deck can rest on: every wall top and the head of every stair, ladder and frame.
+    bearings: Vec<usize>,
+    /// Top nodes of full-height walls with the length of roof edge each one carries (metres).
+    roof_bearings: Vec<(usize, f32)>,
+}
+
+impl FramingGraph {
+    fn node(&mut self, node: FramingNode, part: FramingPart) -> usize {
+        self.nodes.push(node);
+        self.node_part.push(part);
+        self.nodes.len() - 1
+    }
+
+    fn member(&mut self, a: usize, b: usize, capacity_n: f32, part: FramingPart) {
+        self.members.push(FramingMember { a, b, capacity_n });
+        self.member_part.push(part);
+    }
+}
+
+/// A zone framed and solved: the graph, the solver's verdict on it and any design faults.
+#[derive(Debug, Clone)]
+pub struct FramingReport {
+    pub graph: FramingGraph,
+    pub result: SolveResult,
+    pub issues: Vec<FramingIssue>,
+}
+
+impl FramingReport {
+    pub fn verdict(&self) -> StructuralVerdict {
+        self.result.verdict
+    }
+
+    /// Parts with a broken member or a node that lost its path to the floor (sorted, no repeats).
+    pub fn failed_parts(&self) -> Vec<FramingPart> {
+        let broken = self
+            .result
+            .member_state
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| **s == MemberState::Broken)
+            .map(|(i, _)| self.graph.member_part[i]);
+        let islanded = self.result.island_nodes.iter().map(|&n| self.graph.node_part[n]);
+        let mut parts: Vec<FramingPart> = broken.chain(islanded).collect();
+        parts.sort_unstable();
+        parts.dedup();
+        parts
+    }
+
+    /// Parts still standing but with a member past the deform threshold (sorted, no repeats).
+    pub fn strained_parts(&self) -> Vec<FramingPart> {
+        let failed = self.failed_parts();
+        let mut parts: Vec<FramingPart> = self
+            .result
+            .member_state
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| **s == MemberState::Strained)
+            .map(|(i, _)| self.graph.member_part[i])
+            .filter(|p| !failed.contains(p))
+            .collect();
+        parts.sort_unstable();
+        parts.dedup();
+        parts
+    }
+
+    /// Every strained or broken member as a world-space segment -- the editor's overlay.
+    pub fn flagged_members(&self) -> Vec<(Vec3, Vec3, MemberState)> {
+        self.graph
+            .members
+            .iter()
+            .zip(&self.result.member_state)
+            .filter(|(_, s)| **s != MemberState::Ok)
+            .map(|(m, s)| (self.graph.nodes[m.a].pos, self.graph.nodes[m.b].pos, *s))
+            .collect()
+    }
+
+    /// World positions of the nodes left hanging with no path to the floor.
+    pub fn island_points(&self) -> Vec<Vec3> {
+        self.result.island_nodes.iter().map(|&n| self.graph.nodes[n].pos).collect()
+    }
+}
+
+/// An opening as the framer sees it, in metres along the wall from corner `a` (NOT clamped to the
+/// wall: an overrun is exactly what we are looking for).
+struct Cut {
+    lo: f32,
+    hi: f32,
+    /// Top of the aperture above the floor.
+    top: f32,
+}
+
+/// One wall to frame, interior or shell.
+struct WallSpec {
+    part: FramingPart,
+    a: (f32, f32),
+    b: (f32, f32),
+    height: f32,
+    material: u32,
+    thickness: f32,
+}
+
+/// (tensile MPa, density kg/m³) of a wall material id.
+fn strength(id: u32) -> (f32, f32) {
+    wall_material(id).map_or(FALLBACK_STRENGTH, |m| (m.tensile_mpa, m.density_kg_m3))
+}
+
+/// Frame one wall into `g`: a top node per station, a stud to an anchored floor node wherever the
+/// station is not inside an opening, top members between stations (plate over solid wall, header
+/// over an opening that bears on jambs at both ends), and the wall's own weight on its top nodes.
+fn frame_wall(g: &mut FramingGraph, origin: Vec3, roof_height: f32, spec: &WallSpec, cuts: &[Cut]) {
+    let (dx, dz) = (spec.b.0 - spec.a.0, spec.b.1 - spec.a.1);
+    let len = (dx * dx + dz * dz).sqrt();
+    if len < MIN_WALL_M || spec.height <= 0.0 {
+        return;
+    }
+    let dir = (dx / len, dz / len);
+    let (tensile, density) = strength(spec.material);
+    let t = spec.thickness.max(0.0);
+    let overrun = |c: &Cut| c.lo < -EDGE_EPS_M || c.hi > len + EDGE_EPS_M;
+    let full_height = |c: &Cut| spec.height - c.top < MIN_HEADER_DEPTH_M;
+    let inside = |s: f32| cuts.iter().find(|c| s > c.lo + EDGE_EPS_M && s < c.hi - EDGE_EPS_M);
+    let over = |mid: f32| cuts.iter().find(|c| mid > c.lo && mid < c.hi);
+
+    let mut stations: Vec<f32> = (0..=(len / STUD_SPACING_M) as usize).map(|k| k as f32 * STUD_SPACING_M).collect();
+    stations.push(len);
+    for c in cuts {
+        stations.push(c.lo.clamp(0.0, len));
+        stations.push(c.hi.clamp(0.0, len));
+    }
+    stations.sort_by(f32::total_cmp);
+    stations.dedup_by(|x, y| (*x - *y).abs() < EDGE_EPS_M);
+    if let Some(last) = stations.last_mut() {
+        *last = len;
+    }
+    // No wall stands inside an opening that reaches the top plate, so nothing is framed there.
+    stations.retain(|&s| !inside(s).is_some_and(full_height));
+
+    let at = |s: f32, y: f32| Vec3::new(origin.x + spec.a.0 + dir.0 * s, origin.y + y, origin.z + spec.a.1 + dir.1 * s);
+    let tops: Vec<usize> = stations
+        .iter()
+        .map(|&s| g.node(FramingNode::loaded(at(s, spec.height), 0.0), spec.part))
+        .collect();
+    g.bearings.extend(&tops);
+
+    let mut solid = vec![0.0f32; stations.len()];
+    let mut roof = vec![0.0f32; stations.len()];
+    for i in 0..stations.len().saturating_sub(1) {
+        let (s0, s1) = (stations[i], stations[i + 1]);
+        let bay = s1 - s0;
+        let (capacity, wall_above) = match over((s0 + s1) * 0.5) {
+            None => {
+                solid[i] += bay * 0.5;
+                solid[i + 1] += bay * 0.5;
+                (Some(member_capacity(tensile, t * spec.height)), spec.height)
+            }
+            Some(c) if full_height(c) => (None, 0.0),
+            Some(c) => {
+                let depth = spec.height - c.top;
+                let span = (c.hi.min(len) - c.lo.max(0.0)).max(0.1);
+                // A header bears on a jamb at each end; past the end of the wall there is none.
+                let header = (!overrun(c)).then(|| HEADER_FACTOR * tensile * 1.0e6 * t * depth * depth / span);
+                (header, depth)
+            }
+        };
+        if wall_above > 0.0 {
+            roof[i] += bay * 0.5;
+            roof[i + 1] += bay * 0.5;
+        }
+        if let Some(cap) = capacity {
+            g.member(tops[i], tops[i + 1], cap, spec.part);
+        }
+        let weight = density * t * bay * wall_above * GRAVITY;
+        g.nodes[tops[i]].load_n += weight * 0.5;
+        g.nodes[tops[i + 1]].load_n += weight * 0.5;
+    }
+
+    for (i, &s) in stations.iter().enumerate() {
+        if inside(s).is_none() {
+            let foot = g.node(FramingNode::anchor(at(s, 0.0)), spec.part);
+            g.member(tops[i], foot, member_capacity(tensile, t * solid[i].max(MIN_TRIB_M)), spec.part);
+        }
+    }
+    if spec.height >= roof_height - LEVEL_TOL_M {
+        g.roof_bearings.extend(tops.iter().zip(roof).filter(|(_, r)| *r > 0.0).map(|(&n, r)| (n, r)));
+    }
+}
+
+/// Frame a zone: shell edges (with any corridor `shell_cuts`), interior walls and their openings,
+/// the roof on the full-height walls, and the stairs, ladders, elevator frames and decks placed in it.
+/// `origin` is the zone's world offset; returned positions are world space.
+pub fn frame_home(hs: &HomeStructure, origin: Vec3, shell_cuts: &[ShellCut]) -> (FramingGraph, Vec<FramingIssue>) {
+    let mut g = FramingGraph::default();
+    let mut issues = Vec::new();
+    let (w, d) = (hs.width, hs.depth);
+    let corners = [(0.0, 0.0), (w, 0.0), (w, d), (0.0, d)];
+    for edge in 0..4 {
+        let cuts: Vec<Cut> = shell_cuts
+            .iter()
+            .filter(|c| c.edge == edge)
+            .map(|c| Cut { lo: c.at, hi: c.at + c.width, top: c.height })
+            .collect();
+        let spec = WallSpec {
+            part: FramingPart::Shell(edge),
+            a: corners[edge],
+            b: corners[(edge + 1) % 4],
+            height: hs.height,
+            material: hs.shell_material,
+            thickness: hs.shell_resolved_thickness(),
+        };
+        frame_wall(&mut g, origin, hs.height, &spec, &cuts);
+    }
+
+    for (wi, wall) in hs.walls.iter().enumerate() {
+        let part = FramingPart::Wall(wi);
+        let len = ((wall.b.0 - wall.a.0).powi(2) + (wall.b.1 - wall.a.1).powi(2)).sqrt();
+        let mut cuts = Vec::new();
+        for (oi, o) in wall.openings.iter().enumerate() {
+            let cut = Cut { lo: o.at, hi: o.at + o.width, top: o.sill + o.height };
+            let kind = match o.kind {
+                OpeningKind::Door => "Door",
+                OpeningKind::Window => "Window",
+            };
+            if o.width > len + EDGE_EPS_M {
+                issues.push(FramingIssue {
+                    part,
+                    message: format!("{kind} {} is {:.2} m wide but {} is only {len:.2} m long", oi + 1, o.width, part.label(hs)),
+                });
+            } else if cut.lo < -EDGE_EPS_M || cut.hi > len + EDGE_EPS_M {
+                issues.push(FramingIssue {
+                    part,
+                    message: format!("{kind} {} runs past the end of {}; its header has nothing to bear on", oi + 1, part.label(hs)),
+                });
+            }
+            cuts.push(cut);
+        }
+        let spec = WallSpec {
+            part,
+            a: wall.a,
+            b: wall.b,
+            height: wall.height,
+            material: wall.material,
+            thickness: wall.resolved_thickness(),
+        };
+        frame_wall(&mut g, origin, hs.height, &spec, &cuts);
+    }
+
+    // The roof's weight, shared out along the walls that reach it.
+    let roof_t = wall_material(hs.roof_material).map_or(0.05, |m| m.default_thickness_m);
+    let roof_n = strength(hs.roof_material).1 * roof_t * w * d * GRAVITY;
+    let edge_m: f32 = g.roof_bearings.iter().map(|(_, r)| r).sum();
+    if edge_m > 0.0 {
+        for (n, r) in std::mem::take(&mut g.roof_bearings) {
+            g.nodes[n].load_n += roof_n * r / edge_m;
+        }
+    }
+
+    // Pieces: decks first (a flight's foot may stand on one), then everything that stands upright.
+    let (piece_tensile, _) = strength(hs.shell_material);
+    let piece_cap = member_capacity(piece_tensile, PIECE_MEMBER_M2);
+    let joist_cap = member_capacity(piece_tensile, DECK_JOIST_M2);
+    let mut decks: Vec<(usize, usize)> = Vec::new();
+    for (i, ps) in hs.structures.iter().enumerate() {
+        let Some(ty) = structure_type(&ps.type_id) else { continue };
+        if ty.kind != StructureKind::Deck {
+            continue;
+        }
+        let pos = origin + Vec3::new(ps.pos.0, ps.pos.1, ps.pos.2);
+        let node = if ps.pos.1 <= LEVEL_TOL_M {
+            FramingNode::anchor(pos)
+        } else {
+            FramingNode::loaded(pos, (DEAD_LOAD_PA + LIVE_LOAD_PA) * ty.size.0 * ty.size.2)
+        };
+        decks.push((i, g.node(node, FramingPart::Piece(i))));
+    }
+    let mut feet: Vec<(usize, FramingPart)> = Vec::new();
+    for (i, ps) in hs.structures.iter().enumerate() {
+        let Some(ty) = structure_type(&ps.type_id) else { continue };
+        let (pw, ph, pd) = ty.size;
+        let legs: &[(f32, f32, f32, f32)] = match ty.kind {
+            StructureKind::Stairs => &[(0.0, -0.5, 0.0, 0.5)],
+            StructureKind::Ladder => &[(0.0, 0.0, 0.0, 0.0)],
+            StructureKind::Elevator => &[(-0.5, 0.0, -0.5, 0.0), (0.5, 0.0, 0.5, 0.0)],
+            _ => continue,
+        };
+        let part = FramingPart::Piece(i);
+        let yaw = ps.rot_deg.to_radians();
+        let at = |lx: f32, lz: f32, y: f32| {
+            let (x, z) = local_to_world_xz(ps.pos, yaw, lx * pw, lz * pd);
+            origin + Vec3::new(x, ps.pos.1 + y, z)
+        };
+        let mut load = (DEAD_LOAD_PA + LIVE_LOAD_PA) * pw * pd;
+        if ty.kind == StructureKind::Elevator {
+            load += ELEVATOR_CAR_N;
+        }
+        let share = load / legs.len() as f32;
+        let mut heads = Vec::new();
+        for &(bx, bz, tx, tz) in legs {
+            let foot = if ps.pos.1 <= LEVEL_TOL_M {
+                g.node(FramingNode::anchor(at(bx, bz, 0.0)), part)
+            } else {
+                let n = g.node(FramingNode::loaded(at(bx, bz, 0.0), 0.0), part);
+                feet.push((n, part));
+                n
+            };
+            let head = g.node(FramingNode::loaded(at(tx, tz, ph), share), part);
+            g.member(foot, head, piece_cap, part);
+            heads.push(head);
+        }
+        if let [l, r] = heads[..] {
+            g.member(l, r, piece_cap, part); // the elevator frame's lintel
+        }
+        g.bearings.extend(heads);
+    }
+
+    // Tie each raised deck to what meets it at its level, and each raised foot to the deck under it.
+    for &(i, deck) in &decks {
+        let ps = &hs.structures[i];
+        if ps.pos.1 <= LEVEL_TOL_M {
+            continue;
+        }
+        let Some(ty) = structure_type(&ps.type_id) else { continue };
+        let yaw = ps.rot_deg.to_radians();
+        let meets = |p: Vec3| {
+            let local = p - origin;
+            (local.y - ps.pos.1).abs() <= LEVEL_TOL_M && near_footprint(ty, ps.pos, yaw, local.x, local.z, BEARING_REACH_M)
+        };
+        let supports: Vec<usize> = g.bearings.iter().copied().filter(|&b| meets(g.nodes[b].pos)).collect();
+        for b in supports {
+            g.member(deck, b, joist_cap, FramingPart::Piece(i));
+        }
+        let stands: Vec<(usize, FramingPart)> = feet.iter().copied().filter(|&(f, _)| meets(g.nodes[f].pos)).collect();
+        for (f, part) in stands {
+            g.member(f, deck, joist_cap, part);
+        }
+    }
+
+    (g, issues)
+}
+
+/// Frame and solve one zone.
+pub fn analyze_home(hs: &HomeStructure, origin: Vec3, shell_cuts: &[ShellCut]) -> FramingReport {
+    let (graph, issues) = frame_home(hs, origin, shell_cuts);
+    let result = solve(&graph.nodes, &graph.members);
+    FramingReport { graph, result, issues }
+}
+
+/// Frame and solve every zone of the ship (indexed like `ship.zones`).
+pub fn analyze_ship(ship: &ShipStructure) -> Vec<FramingReport> {
+    ship.zones
+        .iter()
+        .enumerate()
+        .map(|(zi, z)| analyze_home(&z.body, z.origin_vec(), &ship.shell_cuts_for_zone(zi)))
+        .collect()
+}
+
+/// Bring down whatever fails in a zone: broken or stranded interior walls and pieces are removed,
+/// then the zone is solved again, since what rested on them may now be hanging. The shell is the
+/// hull and stays. Returns the labels of what fell, in the order it fell.
+pub fn settle(hs: &mut HomeStructure, origin: Vec3, shell_cuts: &[ShellCut]) -> Vec<String> {
+    let mut fallen = Vec::new();
+    for _ in 0..SETTLE_PASSES {
+        let failed = analyze_home(hs, origin, shell_cuts).failed_parts();
+        let walls: Vec<usize> = failed.iter().filter_map(|p| if let FramingPart::Wall(i) = p { Some(*i) } else { None }).collect();
+        let pieces: Vec<usize> = failed.iter().filter_map(|p| if let FramingPart::Piece(i) = p { Some(*i) } else { None }).collect();
+        if walls.is_empty() && pieces.is_empty() {
+            break;
+        }
+        fallen.extend(failed.iter().filter(|p| !matches!(p, FramingPart::Shell(_))).map(|p| p.label(hs)));
+        for &i in walls.iter().rev() {
+            hs.walls.remove(i);
+        }
+        for &i in pieces.iter().rev() {
+            hs.remove_structure(i);
+        }
+    }
+    fallen
+}
+
+/// `settle` every zone of the ship. Labels carry the zone's name when the ship has more than one.
+pub fn settle_ship(ship: &mut ShipStructure) -> Vec<String> {
+    let many = ship.zones.len() > 1;
+    let mut fallen = Vec::new();
+    for zi in 0..ship.zones.len() {
+        let cuts = ship.shell_cuts_for_zone(zi);
+        let zone = &mut ship.zones[zi];
+        let origin = zone.origin_vec();
+        for label in settle(&mut zone.body, origin, &cuts) {
+            fallen.push(if many { format!("{}: {label}", zone.label) } else { label });
+        }
+    }
+    fallen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ship::home_structure::{InteriorWall, Opening, PlacedStructure};
+
+    fn box_home(w: f32, d: f32) -> HomeStructure {
+        ron::from_str(&format!("(width: {w}, depth: {d}, height: 3.0)")).expect("box parses")
+    }
+
+    fn wall(a: (f32, f32), b: (f32, f32), openings: Vec<Opening>) -> InteriorWall {
+        let mut wall: InteriorWall = ron::from_str("(a: (0.0, 0.0), b: (1.0, 0.0))").expect("wall parses");
+        wall.a = a;
+        wall.b = b;
+        wall.openings = openings;
+        wall
+    }
+
+    fn opening(kind: &str, at: f32, width: f32, sill: f32, height: f32) -> Opening {
+        ron::from_str(&format!("(kind: {kind}, at: {at}, width: {width}, sill: {sill}, height: {height})")).expect("opening parses")
+    }
+
+    fn piece(type_id: &str, pos: (f32, f32, f32)) -> PlacedStructure {
+        PlacedStructure { type_id: type_id.to_string(), pos, rot_deg: 0.0, pair: None }
+    }
+
+    #[test]
+    fn a_sound_home_stands() {
+        let mut hs = box_home(10.0, 6.0);
+        hs.walls.push(wall(
+            (5.0, 0.0),
+            (5.0, 6.0),
+            vec![opening("Door", 1.0, 0.9, 0.0, 2.1), opening("Window", 3.5, 1.2, 1.0, 1.2)],
+        ));
+        let report = analyze_home(&hs, Vec3::ZERO, &[]);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+        assert_eq!(report.verdict(), StructuralVerdict::Stable);
+        assert!(report.failed_parts().is_empty());
+        assert!(report.graph.members.len() > 50, "walls are framed stud by stud: {}", report.graph.members.len());
+        let loaded: f32 = report.graph.nodes.iter().map(|n| n.load_n).sum();
+        assert!(loaded > 100_000.0, "a steel shell under a glass roof weighs something: {loaded} N");
+    }
+
+    #[test]
+    fn a_door_wider_than_its_wall_brings_the_wall_down() {
+        let mut hs = box_home(10.0, 6.0);
+        hs.walls.push(wall((5.0, 0.0), (5.0, 3.0), vec![opening("Door", 0.0, 4.0, 0.0, 2.1)]));
+        let report = analyze_home(&hs, Vec3::ZERO, &[]);
+        assert_eq!(report.issues.len(), 1);
+        assert!(report.issues[0].message.contains("only 3.00 m long"), "{}", report.issues[0].message);
+        assert_eq!(report.verdict(), StructuralVerdict::Collapsed);
+        assert_eq!(report.failed_parts(), vec![FramingPart::Wall(0)]);
+        assert!(!report.island_points().is_empty(), "the wall over the door hangs free");
+
+        assert_eq!(settle(&mut hs, Vec3::ZERO, &[]), vec!["Wall 1".to_string()]);
+        assert!(hs.walls.is_empty());
+        assert_eq!(analyze_home(&hs, Vec3::ZERO, &[]).verdict(), StructuralVerdict::Stable);
+    }
+
+    #[test]
+    fn a_paper_wall_under_the_roof_breaks() {
+        let mut hs = box_home(10.0, 6.0);
+        let mut screen = wall((5.0, 0.0), (5.0, 6.0), Vec::new());
+        screen.material = 9; // mineral wool
+        screen.thickness = Some(0.001);
+        hs.walls.push(screen);
+        let report = analyze_home(&hs, Vec3::ZERO, &[]);
+        assert_eq!(report.failed_parts(), vec![FramingPart::Wall(0)]);
+        assert!(report.result.broken_count > 0);
+        let flagged = report.flagged_members();
+        assert!(flagged.iter().any(|(_, _, s)| *s == MemberState::Broken));
+        assert!(flagged.iter().all(|(a, b, _)| a.x == 5.0 && b.x == 5.0), "only the screen is overloaded");
+    }
+
+    #[test]
+    fn a_deck_needs_something_under_it() {
+        let mut hs = box_home(10.0, 10.0);
+        hs.structures.push(piece("deck", (5.0, 3.0, 6.0)));
+        let report = analyze_home(&hs, Vec3::new(100.0, 0.0, 0.0), &[]);
+        assert_eq!(report.failed_parts(), vec![FramingPart::Piece(0)]);
+        assert_eq!(report.verdict(), StructuralVerdict::Collapsed);
+
+        // A flight whose head lands on the deck's edge carries it.
+        hs.structures.push(piece("stairs", (5.0, 0.0, 2.2)));
+        let report = analyze_home(&hs, Vec3::new(100.0, 0.0, 0.0), &[]);
+        assert!(report.failed_parts().is_empty(), "{:?}", report.failed_parts());
+
+        // A ladder standing on the deck is carried down through it; one in mid-air is not.
+        hs.structures.push(piece("ladder", (5.0, 3.0, 6.0)));
+        hs.structures.push(piece("ladder", (1.0, 3.0, 1.0)));
+        let report = analyze_home(&hs, Vec3::new(100.0, 0.0, 0.0), &[]);
+        assert_eq!(report.failed_parts(), vec![FramingPart::Piece(3)]);
+        assert_eq!(settle(&mut hs, Vec3::ZERO, &[]), vec!["Ladder 4".to_string()]);
+        assert_eq!(hs.structures.len(), 3);
+    }
+
+    #[test]
+    fn the_shipped_ship_stands() {
+        let ship = ShipStructure::load(std::path::Path::new("data/blueprints/ship_structure.ron")).expect("ship loads");
+        for (zone, report) in ship.zones.iter().zip(analyze_ship(&ship)) {
+            assert!(report.failed_parts().is_empty(), "{}: {:?} {:?}", zone.label, report.failed_parts(), report.issues);
+        }
+    }
+}
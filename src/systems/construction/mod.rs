@@ -6,6 +6,7 @@
 pub mod structural;
 pub mod routing;
 pub mod solver;
+pub mod framing;
 
 use crate::ecs::components::Transform;
 use crate::ecs::systems::System;
@@ -237,13 +238,19 @@ impl System for ConstructionSystem {
             );
             // Completion is PROGRESS (v0.746): the construction quest chain's
             // Build objectives finally advance, and building trains the builder.
-            crate::systems::quests::push_quest_event(data, format!("build_{bp_id}"));
+            crate::systems::quests::push_quest_event(
+                data,
+                crate::systems::quests::QuestEvent::Built { blueprint_id: bp_id.clone() },
+            );
             crate::systems::skills::award_skill_xp(data, "shelter_building", 15);
-            // Placement thunk (v0.985): a finished structure lands audibly.
+            // Placement thunk (v0.985): a finished structure lands audibly,
+            // from where it stands.
+            let site = world.get::<&Transform>(entity).ok().map(|tf| tf.position);
             crate::systems::push_sfx_event(
                 data,
                 "sfx.place_block",
                 "audio/sfx/place_block.ogg",
+                site,
             );
             status = Some(format!("{name} complete"));
         }
@@ -309,7 +316,10 @@ mod tests {
         data.insert("blueprint_registry", reg);
         data.insert("build_request", std::sync::Mutex::new(request));
         data.insert("build_status", std::sync::Mutex::new(String::new()));
-        data.insert("quest_events", std::sync::Mutex::new(Vec::<String>::new()));
+        data.insert(
+            "quest_events",
+            std::sync::Mutex::new(Vec::<crate::systems::quests::QuestEvent>::new()),
+        );
         data
     }
 
@@ -355,13 +365,15 @@ mod tests {
         assert_eq!(world.query::<&Structure>().iter().count(), 1, "structure completed");
         assert_eq!(world.query::<&Construction>().iter().count(), 0);
         let events = data
-            .get::<std::sync::Mutex<Vec<String>>>("quest_events")
+            .get::<std::sync::Mutex<Vec<crate::systems::quests::QuestEvent>>>("quest_events")
             .unwrap()
             .lock()
             .unwrap()
             .clone();
         assert!(
-            events.iter().any(|e| e == "build_wood_wall"),
+            events.contains(&crate::systems::quests::QuestEvent::Built {
+                blueprint_id: "wood_wall".into()
+            }),
             "build quest event fired, got {events:?}"
         );
     }
@@ -37,4 +37,10 @@ impl StructuralAnalyzer {
     ) -> StructuralResult {
         super::solver::solve(nodes, members).verdict.into()
     }
+
+    /// Frame a built zone (walls, openings, roof, decks, stairs -- see `super::framing`) and reduce
+    /// the solve to the three-state verdict.
+    pub fn analyze_home(&self, hs: &crate::ship::home_structure::HomeStructure) -> StructuralResult {
+        super::framing::analyze_home(hs, glam::Vec3::ZERO, &[]).verdict().into()
+    }
 }
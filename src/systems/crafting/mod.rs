@@ -494,13 +494,17 @@ impl CraftingSystem {
     /// Post-craft hooks fired when a craft completes: award skill XP (#8a) and
     /// emit a quest-progress event (#8c) for any Craft objective tracking this
     /// recipe. Both no-op cleanly if their channel/skill is absent.
-    fn on_craft_complete(data: &DataStore, recipe: &Recipe) {
+    fn on_craft_complete(data: &DataStore, recipe: &Recipe, at: Option<glam::Vec3>) {
         if let Some(skill) = &recipe.skill_required {
             crate::systems::skills::award_skill_xp(data, skill, 10 + recipe.skill_level * 5);
         }
-        crate::systems::quests::push_quest_event(data, format!("craft_{}", recipe.id));
-        // Craft-complete thunk (v0.985): the workbench hammer lands.
-        crate::systems::push_sfx_event(data, "sfx.hammer", "audio/sfx/hammer.ogg");
+        crate::systems::quests::push_quest_event(
+            data,
+            crate::systems::quests::QuestEvent::Crafted { recipe_id: recipe.id.clone() },
+        );
+        // Craft-complete thunk (v0.985): the workbench hammer lands, heard
+        // from the bench (or the player, for hand crafts).
+        crate::systems::push_sfx_event(data, "sfx.hammer", "audio/sfx/hammer.ogg", at);
     }
 
     /// Tech-unlock gate: does the crafter meet the recipe's `skill_level`?
@@ -1186,7 +1190,13 @@ impl CraftingSystem {
             }
         }
 
-        Self::on_craft_complete(data, recipe);
+        let at = pad.map(|(pos, _)| pos).or_else(|| {
+            world
+                .get::<&crate::ecs::components::Transform>(crafter_vessel.unwrap_or(target))
+                .ok()
+                .map(|t| t.position)
+        });
+        Self::on_craft_complete(data, recipe, at);
     }
 }
 
@@ -3,13 +3,21 @@
 //! Spawns disasters based on conditions and location, applies damage in an
 //! area of effect, and triggers chain reactions (earthquake -> tsunami,
 //! volcano -> ash cloud + lava, meteor -> shockwave + crater).
+//!
+//! Requests arrive on the `disaster_requests` channel as (type, position,
+//! intensity) - WeatherSystem throws the strikes its extreme events list
+//! (lightning, meteor impacts). Damage
+//! rides `damage_events` when CombatSystem is running, so armor and death
+//! causes apply; weather disasters never reach a player inside the sealed
+//! home. The damage type is data: the damage_types.csv row whose sources
+//! list the disaster's `source_id`.
 
 use glam::Vec3;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
-use crate::ecs::components::{Health, Transform};
+use crate::ecs::components::{Controllable, Dead, EnvironmentContext, Health, Transform};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
 
@@ -46,18 +54,6 @@ pub enum DisasterType {
     BlackHoleApproach,
 }
 
-/// What kind of damage a disaster inflicts.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-pub enum DamageType {
-    Heat,
-    Force,
-    Radiation,
-    Chemical,
-    Cold,
-    Electric,
-    Gravitational,
-}
-
 /// An active disaster event in the world.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActiveDisaster {
@@ -73,8 +69,6 @@ pub struct ActiveDisaster {
     pub duration: f32,
     /// Damage applied per second to entities in the radius.
     pub damage_per_second: f32,
-    /// Type of damage inflicted.
-    pub damage_type: DamageType,
     /// Visual effect identifier (for the renderer to look up).
     pub vfx_id: String,
 }
@@ -82,37 +76,76 @@ pub struct ActiveDisaster {
 // ── Disaster parameter defaults ────────────────────────────
 
 impl DisasterType {
-    /// Default parameters for each disaster type.
-    fn defaults(self) -> (f32, f32, f32, DamageType, &'static str) {
-        // (radius, duration, dps, damage_type, vfx_id)
+    /// Default parameters for each disaster type. The damage type is not
+    /// here: it is whichever damage_types.csv row lists `source_id`.
+    fn defaults(self) -> (f32, f32, f32, &'static str) {
+        // (radius, duration, dps, vfx_id)
         match self {
             // Weather
-            DisasterType::Lightning => (5.0, 0.5, 500.0, DamageType::Electric, "vfx_lightning"),
-            DisasterType::Tornado => (200.0, 120.0, 30.0, DamageType::Force, "vfx_tornado"),
-            DisasterType::Hurricane => (5000.0, 600.0, 10.0, DamageType::Force, "vfx_hurricane"),
-            DisasterType::Blizzard => (3000.0, 300.0, 5.0, DamageType::Cold, "vfx_blizzard"),
-            DisasterType::Heatwave => (10000.0, 600.0, 2.0, DamageType::Heat, "vfx_heatwave"),
-            DisasterType::Flood => (1000.0, 300.0, 8.0, DamageType::Force, "vfx_flood"),
+            DisasterType::Lightning => (5.0, 0.5, 500.0, "vfx_lightning"),
+            DisasterType::Tornado => (200.0, 120.0, 30.0, "vfx_tornado"),
+            DisasterType::Hurricane => (5000.0, 600.0, 10.0, "vfx_hurricane"),
+            DisasterType::Blizzard => (3000.0, 300.0, 5.0, "vfx_blizzard"),
+            DisasterType::Heatwave => (10000.0, 600.0, 2.0, "vfx_heatwave"),
+            DisasterType::Flood => (1000.0, 300.0, 8.0, "vfx_flood"),
             // Geological
-            DisasterType::Earthquake => (5000.0, 30.0, 15.0, DamageType::Force, "vfx_earthquake"),
-            DisasterType::Volcano => (3000.0, 600.0, 40.0, DamageType::Heat, "vfx_volcano"),
-            DisasterType::Landslide => (500.0, 15.0, 50.0, DamageType::Force, "vfx_landslide"),
-            DisasterType::Tsunami => (8000.0, 60.0, 35.0, DamageType::Force, "vfx_tsunami"),
-            DisasterType::Sinkhole => (100.0, 10.0, 200.0, DamageType::Force, "vfx_sinkhole"),
+            DisasterType::Earthquake => (5000.0, 30.0, 15.0, "vfx_earthquake"),
+            DisasterType::Volcano => (3000.0, 600.0, 40.0, "vfx_volcano"),
+            DisasterType::Landslide => (500.0, 15.0, 50.0, "vfx_landslide"),
+            DisasterType::Tsunami => (8000.0, 60.0, 35.0, "vfx_tsunami"),
+            DisasterType::Sinkhole => (100.0, 10.0, 200.0, "vfx_sinkhole"),
             // Cosmic
-            DisasterType::SolarFlare => (100000.0, 120.0, 20.0, DamageType::Radiation, "vfx_solar_flare"),
-            DisasterType::MeteorImpact => (500.0, 5.0, 300.0, DamageType::Force, "vfx_meteor"),
-            DisasterType::AsteroidImpact => (50000.0, 30.0, 500.0, DamageType::Force, "vfx_asteroid"),
-            DisasterType::GammaRayBurst => (1000000.0, 10.0, 1000.0, DamageType::Radiation, "vfx_grb"),
+            DisasterType::SolarFlare => (100000.0, 120.0, 20.0, "vfx_solar_flare"),
+            DisasterType::MeteorImpact => (500.0, 5.0, 300.0, "vfx_meteor"),
+            DisasterType::AsteroidImpact => (50000.0, 30.0, 500.0, "vfx_asteroid"),
+            DisasterType::GammaRayBurst => (1000000.0, 10.0, 1000.0, "vfx_grb"),
             // Artificial
-            DisasterType::NuclearExplosion => (15000.0, 30.0, 200.0, DamageType::Radiation, "vfx_nuke"),
-            DisasterType::ChemicalSpill => (500.0, 300.0, 10.0, DamageType::Chemical, "vfx_chemical"),
-            DisasterType::ReactorMeltdown => (5000.0, 600.0, 50.0, DamageType::Radiation, "vfx_meltdown"),
+            DisasterType::NuclearExplosion => (15000.0, 30.0, 200.0, "vfx_nuke"),
+            DisasterType::ChemicalSpill => (500.0, 300.0, 10.0, "vfx_chemical"),
+            DisasterType::ReactorMeltdown => (5000.0, 600.0, 50.0, "vfx_meltdown"),
             // Extreme
-            DisasterType::SuperVolcano => (100000.0, 1800.0, 80.0, DamageType::Heat, "vfx_supervolcano"),
-            DisasterType::PlanetaryCollision => (1e7, 60.0, 10000.0, DamageType::Force, "vfx_collision"),
-            DisasterType::BlackHoleApproach => (1e8, 300.0, 0.0, DamageType::Gravitational, "vfx_blackhole"),
+            DisasterType::SuperVolcano => (100000.0, 1800.0, 80.0, "vfx_supervolcano"),
+            DisasterType::PlanetaryCollision => (1e7, 60.0, 10000.0, "vfx_collision"),
+            DisasterType::BlackHoleApproach => (1e8, 300.0, 0.0, "vfx_blackhole"),
+        }
+    }
+
+    /// Weather disasters stop at a sealed hull; quakes, impacts and
+    /// radiation do not.
+    pub fn sheltered_by_hull(self) -> bool {
+        matches!(
+            self,
+            DisasterType::Lightning
+                | DisasterType::Tornado
+                | DisasterType::Hurricane
+                | DisasterType::Blizzard
+                | DisasterType::Heatwave
+        )
+    }
+
+    /// "MeteorImpact" -> "meteor_impact": the source name damage_types.csv
+    /// rows list to claim this disaster's damage.
+    pub fn source_id(self) -> String {
+        let mut out = String::new();
+        for (i, c) in format!("{self:?}").chars().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
         }
+        out
+    }
+
+    /// "MeteorImpact" -> "Meteor Impact", for death-cause lines.
+    pub fn display_name(self) -> String {
+        let mut out = String::new();
+        for (i, c) in format!("{self:?}").chars().enumerate() {
+            if i > 0 && c.is_uppercase() {
+                out.push(' ');
+            }
+            out.push(c);
+        }
+        out
     }
 
     /// Chain reactions triggered when this disaster occurs.
@@ -165,7 +198,7 @@ impl DisasterSystem {
         intensity: f32,
     ) {
         let intensity = intensity.clamp(0.0, 1.0);
-        let (radius, duration, dps, damage_type, vfx_id) = disaster_type.defaults();
+        let (radius, duration, dps, vfx_id) = disaster_type.defaults();
 
         let disaster = ActiveDisaster {
             disaster_type,
@@ -174,7 +207,6 @@ impl DisasterSystem {
             intensity,
             duration,
             damage_per_second: dps * intensity,
-            damage_type,
             vfx_id: vfx_id.to_string(),
         };
 
@@ -187,7 +219,7 @@ impl DisasterSystem {
 
         // Queue chain reactions.
         for &chain_type in disaster_type.chain_reactions() {
-            let (c_radius, c_duration, c_dps, c_dmg_type, c_vfx) = chain_type.defaults();
+            let (c_radius, c_duration, c_dps, c_vfx) = chain_type.defaults();
             let chain_intensity = (intensity * 0.6).clamp(0.1, 1.0);
             // Offset position slightly for chain reactions.
             let offset = Vec3::new(
@@ -204,7 +236,6 @@ impl DisasterSystem {
                 intensity: chain_intensity,
                 duration: c_duration,
                 damage_per_second: c_dps * chain_intensity,
-                damage_type: c_dmg_type,
                 vfx_id: c_vfx.to_string(),
             });
 
@@ -223,23 +254,34 @@ impl DisasterSystem {
         &self.active_disasters
     }
 
-    /// Apply damage from all active disasters to nearby entities.
-    fn apply_damage(&self, world: &mut hecs::World, dt: f32) {
+    /// Apply damage from all active disasters to nearby entities. With the
+    /// `damage_events` channel present the hits go through CombatSystem
+    /// (one event per disaster per entity); without it (bare tests) Health
+    /// is reduced directly. A player inside the sealed home
+    /// (`environment_context`) is out of reach of weather disasters.
+    fn apply_damage(&self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         if self.active_disasters.is_empty() {
             return;
         }
-
-        // Collect entities with position and health.
-        let entities: Vec<(hecs::Entity, Vec3)> = world
-            .query_mut::<&Transform>()
+        let sheltered = data
+            .get::<EnvironmentContext>("environment_context")
+            .map(|c| c.sealed)
+            .unwrap_or(true);
+
+        // Collect living entities with position and health.
+        let entities: Vec<(hecs::Entity, Vec3, bool)> = world
+            .query_mut::<(&Transform, &Health, Option<&Controllable>)>()
+            .without::<&Dead>()
             .into_iter()
-            .map(|(e, t)| (e, t.position))
+            .map(|(e, (t, _h, c))| (e, t.position, c.is_some()))
             .collect();
 
-        for (entity, pos) in &entities {
-            let mut total_damage = 0.0_f32;
-
+        let mut hits: Vec<(hecs::Entity, &ActiveDisaster, f32)> = Vec::new();
+        for (entity, pos, is_player) in &entities {
             for disaster in &self.active_disasters {
+                if *is_player && sheltered && disaster.disaster_type.sheltered_by_hull() {
+                    continue;
+                }
                 let center = Vec3::from_array(disaster.position);
                 let dist = pos.distance(center);
 
@@ -279,12 +321,38 @@ impl DisasterSystem {
                     dmg *= 0.5; // atmosphere provides some shielding.
                 }
 
-                total_damage += dmg;
+                if dmg > 0.0 {
+                    hits.push((*entity, disaster, dmg));
+                }
             }
+        }
 
-            if total_damage > 0.0 {
-                if let Ok(mut health) = world.get::<&mut Health>(*entity) {
-                    health.current = (health.current - total_damage).max(0.0);
+        match data
+            .get::<std::sync::Mutex<Vec<(u64, crate::systems::combat::damage::DamageEvent)>>>("damage_events")
+            .and_then(|m| m.lock().ok())
+        {
+            Some(mut q) => {
+                for (entity, disaster, dmg) in hits {
+                    q.push((
+                        entity.to_bits().into(),
+                        crate::systems::combat::damage::DamageEvent {
+                            damage_type: crate::systems::combat::damage::damage_type_for(
+                                data,
+                                &disaster.disaster_type.source_id(),
+                            ),
+                            amount: dmg,
+                            source_name: Some(disaster.disaster_type.display_name()),
+                            source_is_player: false,
+                            skill: None,
+                        },
+                    ));
+                }
+            }
+            None => {
+                for (entity, _disaster, dmg) in hits {
+                    if let Ok(mut health) = world.get::<&mut Health>(entity) {
+                        health.current = (health.current - dmg).max(0.0);
+                    }
                 }
             }
         }
@@ -296,7 +364,17 @@ impl System for DisasterSystem {
         "DisasterSystem"
     }
 
-    fn tick(&mut self, world: &mut hecs::World, dt: f32, _data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
+        // Requests from other systems (weather strikes).
+        let requests: Vec<(DisasterType, Vec3, f32)> = data
+            .get::<std::sync::Mutex<Vec<(DisasterType, Vec3, f32)>>>("disaster_requests")
+            .and_then(|m| m.lock().ok())
+            .map(|mut q| q.drain(..).collect())
+            .unwrap_or_default();
+        for (kind, position, intensity) in requests {
+            self.spawn_disaster(kind, position, intensity);
+        }
+
         // Drain pending chain reactions into active list.
         self.active_disasters.append(&mut self.pending_chains);
 
@@ -312,7 +390,7 @@ impl System for DisasterSystem {
         });
 
         // Apply damage from active disasters.
-        self.apply_damage(world, dt);
+        self.apply_damage(world, dt, data);
 
         // Periodic random disaster check (placeholder — real implementation would
         // check terrain features, fault lines, weather conditions, etc.).
@@ -334,7 +412,7 @@ mod tests {
 
     #[test]
     fn test_disaster_defaults() {
-        let (radius, duration, dps, _, _) = DisasterType::Lightning.defaults();
+        let (radius, duration, dps, _) = DisasterType::Lightning.defaults();
         assert!(radius > 0.0);
         assert!(duration > 0.0);
         assert!(dps > 0.0);
@@ -442,6 +520,53 @@ mod tests {
         assert!(chain_types.contains(&DisasterType::Tsunami));
     }
 
+    /// Requests on the channel spawn disasters, hits ride
+    /// damage_events when it exists, and a player in the sealed home is out
+    /// of reach of weather disasters but not of an earthquake.
+    #[test]
+    fn requests_spawn_and_hits_route_through_combat() {
+        use crate::systems::combat::damage::DamageEvent;
+        let mut system = DisasterSystem::new();
+        let mut world = hecs::World::new();
+        let mut data = DataStore::new();
+        data.insert("disaster_requests", std::sync::Mutex::new(vec![(DisasterType::Lightning, Vec3::ZERO, 1.0_f32)]));
+        data.insert("damage_events", std::sync::Mutex::new(Vec::<(u64, DamageEvent)>::new()));
+        data.insert("environment_context", EnvironmentContext::default());
+        let types = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/damage_types.csv");
+        data.insert(
+            "damage_type_registry",
+            crate::systems::combat::damage::DamageTypeRegistry::from_csv(&std::fs::read(types).unwrap()).unwrap(),
+        );
+        let spot = Transform { position: Vec3::new(1.0, 0.0, 0.0), ..Default::default() };
+        let player = world.spawn((spot.clone(), Health::default(), Controllable));
+        let cow = world.spawn((spot, Health::default()));
+
+        system.tick(&mut world, 0.1, &data);
+        assert_eq!(system.active_disasters().len(), 1);
+        let hits: Vec<(u64, DamageEvent)> = data
+            .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect();
+        assert!(hits.iter().any(|(b, e)| *b == u64::from(cow.to_bits()) && e.source_name.as_deref() == Some("Lightning")));
+        assert!(hits.iter().all(|(_, e)| e.damage_type.id() == "electric"), "damage_types.csv claims lightning");
+        assert!(hits.iter().all(|(b, _)| *b != u64::from(player.to_bits())), "sealed home shelters the player");
+        assert_eq!(world.get::<&Health>(cow).unwrap().current, 100.0, "combat applies it, not us");
+
+        data.get::<std::sync::Mutex<Vec<(DisasterType, Vec3, f32)>>>("disaster_requests")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .push((DisasterType::Earthquake, Vec3::ZERO, 1.0));
+        system.tick(&mut world, 0.1, &data);
+        let hits = data.get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events").unwrap().lock().unwrap().clone();
+        assert!(hits.iter().any(|(b, e)| *b == u64::from(player.to_bits()) && e.damage_type.id() == "kinetic"), "walls don't stop a quake");
+        assert_eq!(DisasterType::MeteorImpact.display_name(), "Meteor Impact");
+        assert_eq!(DisasterType::MeteorImpact.source_id(), "meteor_impact");
+    }
+
     #[test]
     fn test_system_ticks_without_panic() {
         let mut system = DisasterSystem::new();
@@ -1,12 +1,18 @@
 //! Fleet-wide resource pools — shared resources across a player's fleet.
 //!
-//! Resource definitions loaded from `data/resources.csv`.
+//! The pool is a snapshot, not a store of its own: `TransportationSystem`
+//! totals the real `Inventory` of every ship `LogisticsNode` each tick,
+//! so spending from a ship's hold shows up in the pool and nothing
+//! can be conjured into it.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::ecs::components::LogisticsNode;
+use crate::systems::inventory::Inventory;
+
 /// Shared resource pool across all ships in a fleet.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FleetResources {
     /// Resource id -> quantity.
     pub resources: HashMap<String, f64>,
@@ -19,6 +25,20 @@ impl FleetResources {
         }
     }
 
+    /// Total every ship's hold in the world.
+    pub fn from_world(world: &hecs::World) -> Self {
+        let mut pool = Self::new();
+        for (_e, (node, inv)) in world.query::<(&LogisticsNode, &Inventory)>().iter() {
+            if node.kind != "ship" {
+                continue;
+            }
+            for (id, qty) in inv.summary() {
+                pool.add(&id, qty as f64);
+            }
+        }
+        pool
+    }
+
     pub fn get(&self, resource_id: &str) -> f64 {
         self.resources.get(resource_id).copied().unwrap_or(0.0)
     }
@@ -0,0 +1,344 @@
+//! NPC vendor markets — per-vendor stock and supply-and-demand prices.
+//!
+//! Each trading post is a [`VendorMarket`] keyed by its machine instance id and
+//! tagged with the region (the zone it stands in, `data/markets.ron`). A good's
+//! price follows how far the vendor's stock sits from its target: selling to a
+//! vendor fills its shelf and lowers what it pays, buying empties it and raises
+//! what it charges. Regions price categories differently, so hauling produce
+//! from the homestead to the commons pays. Stock drifts back to target every
+//! game day, and a daily price sample per good feeds the vendor window's chart.
+//!
+//! Lives in the DataStore as `Mutex<VendorMarkets>` under `"vendor_markets"`;
+//! the vendors themselves round-trip through the save (format v6).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{TradeGood, TradeGoodsRegistry};
+
+/// A price region: one settlement's tastes (`data/markets.ron` `regions`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarketRegion {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// Scales every good's target stock (a hub keeps more on the shelf).
+    #[serde(default = "one")]
+    pub stock_multiplier: f32,
+    /// Price multiplier per trade-goods category (absent = 1).
+    #[serde(default)]
+    pub category_prices: HashMap<String, f32>,
+    /// Target-stock multiplier per category (absent = 1).
+    #[serde(default)]
+    pub category_stock: HashMap<String, f32>,
+}
+
+fn one() -> f32 {
+    1.0
+}
+
+/// Market tuning (`data/markets.ron`).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct MarketConfig {
+    /// How hard price answers stock: multiplier = (target / stock) ^ elasticity.
+    pub elasticity: f32,
+    /// Units of each good a vendor aims to hold before region multipliers.
+    pub target_stock: f32,
+    /// Share of the gap to target a vendor closes per game day.
+    pub restock_per_day: f32,
+    /// Bounds on the supply multiplier, as fractions of base value.
+    pub price_floor: f32,
+    pub price_ceiling: f32,
+    /// Vendor charges mid x markup, pays mid x markdown.
+    pub markup: f32,
+    pub markdown: f32,
+    /// Daily price samples kept per good.
+    pub history_days: usize,
+    pub regions: Vec<MarketRegion>,
+}
+
+impl Default for MarketConfig {
+    fn default() -> Self {
+        Self {
+            elasticity: 0.7,
+            target_stock: 20.0,
+            restock_per_day: 0.3,
+            price_floor: 0.25,
+            price_ceiling: 4.0,
+            markup: 1.25,
+            markdown: 0.5,
+            history_days: 30,
+            regions: Vec::new(),
+        }
+    }
+}
+
+impl MarketConfig {
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, String> {
+        let text = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    pub fn region(&self, id: &str) -> Option<&MarketRegion> {
+        self.regions.iter().find(|r| r.id == id)
+    }
+}
+
+/// One vendor's shelf: stock per good it has traded (others sit at target) and the
+/// daily mid-price samples.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VendorMarket {
+    /// Machine instance id of the trading post.
+    pub id: String,
+    /// Region id (`data/markets.ron`); unknown = base prices.
+    pub region: String,
+    #[serde(default)]
+    pub stock: HashMap<String, f32>,
+    #[serde(default)]
+    pub history: HashMap<String, Vec<f32>>,
+}
+
+/// Every vendor plus the tuning they share.
+#[derive(Debug, Default)]
+pub struct VendorMarkets {
+    pub config: MarketConfig,
+    pub vendors: HashMap<String, VendorMarket>,
+}
+
+impl VendorMarkets {
+    pub fn new(config: MarketConfig) -> Self {
+        Self { config, vendors: HashMap::new() }
+    }
+
+    /// The vendor's market, opened on first contact.
+    pub fn vendor(&mut self, id: &str, region: &str) -> &mut VendorMarket {
+        self.vendors
+            .entry(id.to_string())
+            .or_insert_with(|| VendorMarket { id: id.to_string(), region: region.to_string(), ..Default::default() })
+    }
+
+    /// Units of `good` the vendor aims to hold.
+    pub fn target(&self, region: &str, good: &TradeGood) -> f32 {
+        let r = self.config.region(region);
+        let regional = r.map_or(1.0, |r| r.stock_multiplier * r.category_stock.get(&good.category).copied().unwrap_or(1.0));
+        (self.config.target_stock * regional).max(1.0)
+    }
+
+    /// Units on the vendor's shelf (target until it has traded the good).
+    pub fn stock(&self, vendor: &str, region: &str, good: &TradeGood) -> f32 {
+        self.vendors
+            .get(vendor)
+            .and_then(|v| v.stock.get(&good.id).copied())
+            .unwrap_or_else(|| self.target(region, good))
+    }
+
+    /// The vendor's mid price at a given stock level.
+    fn mid_at(&self, region: &str, good: &TradeGood, stock: f32) -> f64 {
+        let c = &self.config;
+        let regional = c.region(region).and_then(|r| r.category_prices.get(&good.category).copied()).unwrap_or(1.0);
+        let supply = (self.target(region, good) / stock.max(0.5)).powf(c.elasticity).clamp(c.price_floor, c.price_ceiling);
+        good.base_value as f64 * regional as f64 * supply as f64
+    }
+
+    /// Mid price now (what the history samples).
+    pub fn mid_price(&self, vendor: &str, region: &str, good: &TradeGood) -> f64 {
+        self.mid_at(region, good, self.stock(vendor, region, good))
+    }
+
+    /// What the vendor charges for the next unit (min 1).
+    pub fn sell_price(&self, vendor: &str, region: &str, good: &TradeGood) -> i64 {
+        ((self.mid_price(vendor, region, good) * self.config.markup as f64).ceil() as i64).max(1)
+    }
+
+    /// What the vendor pays for the next unit.
+    pub fn buy_price(&self, vendor: &str, region: &str, good: &TradeGood) -> i64 {
+        (self.mid_at(region, good, self.stock(vendor, region, good)) * self.config.markdown as f64).floor() as i64
+    }
+
+    /// Total for `qty` units bought from (`qty` > 0) or sold to (`qty` < 0) the vendor, each
+    /// unit priced at the stock it leaves behind. Does not move the stock.
+    pub fn quote(&self, vendor: &str, region: &str, good: &TradeGood, qty: i64) -> i64 {
+        let mut stock = self.stock(vendor, region, good);
+        let mut total = 0;
+        for _ in 0..qty.unsigned_abs() {
+            let mid = self.mid_at(region, good, stock);
+            if qty > 0 {
+                total += ((mid * self.config.markup as f64).ceil() as i64).max(1);
+                stock -= 1.0;
+            } else {
+                total += (mid * self.config.markdown as f64).floor() as i64;
+                stock += 1.0;
+            }
+        }
+        total
+    }
+
+    /// Move the vendor's stock by `delta` units (negative = the player bought them).
+    /// A traded good is charted from then on, even once its stock settles.
+    pub fn trade(&mut self, vendor: &str, region: &str, good: &TradeGood, delta: f32) {
+        let now = self.stock(vendor, region, good);
+        let v = self.vendor(vendor, region);
+        v.stock.insert(good.id.clone(), (now + delta).max(0.0));
+        v.history.entry(good.id.clone()).or_default();
+    }
+
+    /// Advance every vendor `days` game days: stock closes `restock_per_day` of its gap
+    /// to target per day (compounded). Goods back at target are forgotten.
+    pub fn restock(&mut self, goods: &TradeGoodsRegistry, days: f32) {
+        if days <= 0.0 {
+            return;
+        }
+        let keep = (1.0 - self.config.restock_per_day.clamp(0.0, 1.0)).powf(days);
+        let targets: Vec<(String, String, f32)> = self
+            .vendors
+            .values()
+            .flat_map(|v| {
+                v.stock
+                    .keys()
+                    .filter_map(|id| goods.get(id).map(|g| (v.id.clone(), id.clone(), self.target(&v.region, g))))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        for (vendor, id, target) in targets {
+            let Some(v) = self.vendors.get_mut(&vendor) else { continue };
+            if let Some(s) = v.stock.get_mut(&id) {
+                *s = target + (*s - target) * keep;
+                if (*s - target).abs() < 0.05 {
+                    v.stock.remove(&id);
+                }
+            }
+        }
+    }
+
+    /// Take the day's price sample for every good a vendor has traded or charted.
+    pub fn sample_history(&mut self, goods: &TradeGoodsRegistry) {
+        let len = self.config.history_days.max(1);
+        let samples: Vec<(String, String, f32)> = self
+            .vendors
+            .values()
+            .flat_map(|v| {
+                let mut ids: Vec<&String> = v.stock.keys().chain(v.history.keys()).collect();
+                ids.sort();
+                ids.dedup();
+                ids.into_iter()
+                    .filter_map(|id| goods.get(id).map(|g| (v.id.clone(), id.clone(), self.mid_price(&v.id, &v.region, g) as f32)))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        for (vendor, id, price) in samples {
+            if let Some(v) = self.vendors.get_mut(&vendor) {
+                let series = v.history.entry(id).or_default();
+                series.push(price);
+                if series.len() > len {
+                    series.drain(..series.len() - len);
+                }
+            }
+        }
+    }
+
+    /// Advance the markets over a long absence (offline catch-up): restock the
+    /// whole span and take one sample per game day crossed, up to a chart's
+    /// worth, so the history shows the shelves recovering.
+    pub fn catch_up(&mut self, goods: &TradeGoodsRegistry, days: f32) {
+        let sampled = (days.floor() as usize).min(self.config.history_days);
+        self.restock(goods, days - sampled as f32);
+        for _ in 0..sampled {
+            self.restock(goods, 1.0);
+            self.sample_history(goods);
+        }
+    }
+
+    /// The vendors to write into a save, in a stable order.
+    pub fn to_save(&self) -> Vec<VendorMarket> {
+        let mut out: Vec<VendorMarket> = self.vendors.values().cloned().collect();
+        out.sort_by(|a, b| a.id.cmp(&b.id));
+        out
+    }
+
+    /// Replace the vendors with a save's (the save is authoritative).
+    pub fn restore(&mut self, saved: &[VendorMarket]) {
+        self.vendors = saved.iter().map(|v| (v.id.clone(), v.clone())).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn goods() -> TradeGoodsRegistry {
+        TradeGoodsRegistry::from_ron(
+            br#"[
+                (id: "tomato_0", name: "Tomato", category: "food_raw", base_value: 10),
+                (id: "hammer_0", name: "Hammer", category: "tool", base_value: 40),
+            ]"#,
+        )
+        .unwrap()
+    }
+
+    fn markets() -> VendorMarkets {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/markets.ron");
+        VendorMarkets::new(MarketConfig::from_ron(&std::fs::read(path).unwrap()).expect("markets.ron parses"))
+    }
+
+    #[test]
+    fn flooding_a_vendor_drives_its_price_down_and_restock_recovers_it() {
+        let goods = goods();
+        let tomato = goods.get("tomato_0").unwrap();
+        let mut m = markets();
+        let fresh = m.buy_price("post", "commons", tomato);
+        assert_eq!(m.quote("post", "commons", tomato, -1), fresh, "one unit quotes the posted price");
+        let fifty = m.quote("post", "commons", tomato, -50);
+        assert!(fifty < fresh * 50, "each tomato pays less than the last: {fifty} vs {}", fresh * 50);
+        m.trade("post", "commons", tomato, 50.0);
+        let glut = m.buy_price("post", "commons", tomato);
+        assert!(glut * 2 <= fresh, "a glut halves the offer: {glut} vs {fresh}");
+        assert!(m.sell_price("post", "commons", tomato) < m.sell_price("other", "commons", tomato), "only this vendor is flooded");
+
+        m.sample_history(&goods);
+        for _ in 0..20 {
+            m.restock(&goods, 1.0);
+            m.sample_history(&goods);
+        }
+        let history = &m.vendors["post"].history["tomato_0"];
+        assert_eq!(history.len(), 21);
+        assert!(history.windows(2).all(|w| w[1] >= w[0]), "the price climbs back as the glut clears: {history:?}");
+        assert!(m.buy_price("post", "commons", tomato) >= fresh - 1, "restocked to normal");
+
+        m.trade("post", "commons", tomato, -1000.0);
+        assert_eq!(m.stock("post", "commons", tomato), 0.0, "stock never goes negative");
+        let dear = m.sell_price("post", "commons", tomato) as f64;
+        assert!(dear <= 10.0 * 1.2 * 4.0 * 1.25 + 1.0, "the ceiling caps a sold-out price: {dear}");
+    }
+
+    #[test]
+    fn regions_price_the_same_good_differently() {
+        let goods = goods();
+        let tomato = goods.get("tomato_0").unwrap();
+        let hammer = goods.get("hammer_0").unwrap();
+        let m = markets();
+        assert!(m.buy_price("a", "commons", tomato) > m.buy_price("b", "home", tomato), "the hub pays more for produce");
+        assert!(m.sell_price("a", "commons", hammer) < m.sell_price("b", "home", hammer), "and sells tools for less");
+        assert!(m.stock("a", "commons", hammer) > m.stock("b", "home", hammer), "with deeper stock");
+        assert_eq!(m.sell_price("c", "nowhere", hammer), 50, "an unknown region trades at base x 1.25");
+        assert_eq!(m.buy_price("c", "nowhere", hammer), 20);
+    }
+
+    #[test]
+    fn a_long_absence_refills_the_shelf_and_charts_it_daily() {
+        let goods = goods();
+        let hammer = goods.get("hammer_0").unwrap();
+        let mut m = markets();
+        m.trade("post", "home", hammer, -4.0);
+        assert_eq!(m.stock("post", "home", hammer), 6.0, "the homestead's 10 hammers, 4 bought");
+        let saved = m.to_save();
+
+        let mut back = markets();
+        back.restore(&saved);
+        back.catch_up(&goods, 100.5);
+        let history = &back.vendors["post"].history["hammer_0"];
+        assert_eq!(history.len(), 30, "one sample per day, a chart's worth");
+        assert!((back.stock("post", "home", hammer) - 10.0).abs() < 0.1, "the shelf is full again");
+    }
+}
@@ -7,8 +7,10 @@
 //!
 //! Data: data/economy.ron (formula, earning rates, trade fees)
 //!       data/trade_goods.ron (255 item base values -> TradeGoodsRegistry)
+//!       data/markets.ron (vendor stock, elasticity, regions -> market::VendorMarkets)
 
 pub mod fleet;
+pub mod market;
 
 use crate::hot_reload::data_store::DataStore;
 use crate::ecs::systems::System;
@@ -79,22 +81,27 @@ impl TradeGoodsRegistry {
 pub struct EquipmentDef {
     pub id: String,
     pub slot: String,
-    #[serde(default)]
-    pub armor_kinetic: f32,
-    #[serde(default)]
-    pub armor_thermal: f32,
-    #[serde(default)]
-    pub armor_energy: f32,
-    #[serde(default)]
-    pub armor_chemical: f32,
-    #[serde(default)]
-    pub armor_radiation: f32,
+    /// Resistance 0..1 per armour channel, from every `armor_<channel>`
+    /// column - the channel set is data (damage_types.csv names which
+    /// channel each damage type lands on), so a modded column needs no new
+    /// field. Zero cells are left out.
+    #[serde(skip)]
+    pub armor: HashMap<String, f32>,
     #[serde(default)]
     pub damage: f32,
     #[serde(default)]
     pub damage_type: String,
     #[serde(default)]
     pub range_m: f32,
+    /// Seconds between swings or shots; 0 = the default pace.
+    #[serde(default)]
+    pub attack_interval_s: f32,
+    /// items.csv id a shot consumes; empty = melee.
+    #[serde(default)]
+    pub ammo: String,
+    /// Muzzle speed of a ranged round.
+    #[serde(default)]
+    pub projectile_speed_mps: f32,
     #[serde(default)]
     pub stat_modifiers: String,
     #[serde(default)]
@@ -110,8 +117,10 @@ pub struct EquipmentRegistry {
 impl EquipmentRegistry {
     pub fn from_csv(data: &[u8]) -> Result<Self, String> {
         let rows: Vec<EquipmentDef> = crate::assets::loader::parse_csv(data)?;
+        let mut armor = crate::assets::loader::parse_csv_prefixed(data, "armor_")?;
         let mut defs = HashMap::new();
-        for row in rows {
+        for mut row in rows {
+            row.armor = armor.remove(&row.id).unwrap_or_default();
             defs.insert(row.id.clone(), row);
         }
         Ok(Self { defs })
@@ -156,8 +165,9 @@ impl EquipmentRegistry {
     }
 
     /// Sum worn items' armor columns into the Armor component's
-    /// per-damage-type resistance map (keys match CombatSystem's damage_type
-    /// strings), capped at 0.85 per type so no outfit is ever immune. (v0.761)
+    /// per-channel resistance map (keys are the damage_types.csv
+    /// `armor_channel`s), capped at 0.85 per channel so no outfit is ever
+    /// immune. (v0.761)
     pub fn armor_from_worn<'a>(
         &self,
         worn: impl IntoIterator<Item = &'a str>,
@@ -165,15 +175,9 @@ impl EquipmentRegistry {
         let mut map: HashMap<String, f32> = HashMap::new();
         for id in worn {
             if let Some(def) = self.get(id) {
-                for (key, v) in [
-                    ("kinetic", def.armor_kinetic),
-                    ("thermal", def.armor_thermal),
-                    ("energy", def.armor_energy),
-                    ("chemical", def.armor_chemical),
-                    ("radiation", def.armor_radiation),
-                ] {
+                for (channel, &v) in &def.armor {
                     if v > 0.0 {
-                        *map.entry(key.to_string()).or_insert(0.0) += v;
+                        *map.entry(channel.clone()).or_insert(0.0) += v;
                     }
                 }
             }
@@ -204,10 +208,23 @@ impl EquipmentRegistry {
     }
 }
 
+/// The vendor a trade settles against: its market (stock-driven prices) plus
+/// the trading post's instance id and region. `None` in [`vendor_buy`] /
+/// [`vendor_sell`] trades at the fixed base-value prices.
+pub struct VendorDesk<'a> {
+    pub markets: &'a mut market::VendorMarkets,
+    pub vendor: &'a str,
+    pub region: &'a str,
+}
+
 /// Buy `qty` of `item_id` from an NPC vendor: charges the wallet, adds the
 /// items volume-gated (a full pack refuses rather than losing paid goods).
 /// Pure over the borrowed parts so it is directly testable; lib.rs's vendor
 /// bridge calls it. Returns a human-readable receipt or refusal.
+///
+/// With a desk, each unit is priced at the vendor's stock as it
+/// empties, a vendor can't sell more than it holds, and the sale draws the
+/// stock down (dearer next time).
 pub fn vendor_buy(
     inv: &mut crate::systems::inventory::Inventory,
     credits: &mut i64,
@@ -215,11 +232,19 @@ pub fn vendor_buy(
     items: Option<&crate::systems::inventory::ItemRegistry>,
     item_id: &str,
     qty: u32,
+    desk: Option<VendorDesk>,
 ) -> Result<String, String> {
-    let price = goods
-        .vendor_sell_price(item_id)
-        .ok_or_else(|| format!("{item_id} is not traded here"))?;
-    let total = price * qty as i64;
+    let good = goods.get(item_id).ok_or_else(|| format!("{item_id} is not traded here"))?;
+    let total = match &desk {
+        Some(d) => {
+            let stock = d.markets.stock(d.vendor, d.region, good).floor() as u32;
+            if stock < qty {
+                return Err(format!("The vendor only has {stock}x {item_id}"));
+            }
+            d.markets.quote(d.vendor, d.region, good, qty as i64)
+        }
+        None => goods.vendor_sell_price(item_id).unwrap_or(1) * qty as i64,
+    };
     if *credits < total {
         return Err(format!("Not enough credits ({total} CR needed)"));
     }
@@ -233,26 +258,37 @@ pub fn vendor_buy(
         return Err("Not enough room in your pack".to_string());
     }
     *credits -= total;
+    if let Some(d) = desk {
+        d.markets.trade(d.vendor, d.region, good, -(qty as f32));
+    }
     Ok(format!("Bought {qty}x {item_id} for {total} CR"))
 }
 
-/// Sell `qty` of `item_id` to an NPC vendor: removes the items, pays 0.5x base.
+/// Sell `qty` of `item_id` to an NPC vendor: removes the items, pays 0.5x base
+/// (with a desk: the vendor's stock-driven offer, each unit paying a little
+/// less as its shelf fills).
 pub fn vendor_sell(
     inv: &mut crate::systems::inventory::Inventory,
     credits: &mut i64,
     goods: &TradeGoodsRegistry,
     item_id: &str,
     qty: u32,
+    desk: Option<VendorDesk>,
 ) -> Result<String, String> {
-    let price = goods
-        .vendor_buy_price(item_id)
-        .ok_or_else(|| format!("{item_id} is not traded here"))?;
+    let good = goods.get(item_id).ok_or_else(|| format!("{item_id} is not traded here"))?;
     let have = inv.count_item(item_id);
     if have < qty {
         return Err(format!("You only have {have}x {item_id}"));
     }
     inv.remove_item(item_id, qty);
-    let total = price * qty as i64;
+    let total = match desk {
+        Some(d) => {
+            let total = d.markets.quote(d.vendor, d.region, good, -(qty as i64));
+            d.markets.trade(d.vendor, d.region, good, qty as f32);
+            total
+        }
+        None => goods.vendor_buy_price(item_id).unwrap_or(0) * qty as i64,
+    };
     *credits += total;
     Ok(format!("Sold {qty}x {item_id} for {total} CR"))
 }
@@ -265,6 +301,8 @@ pub struct EconomySystem {
     pub passive_income_per_day: f32,
     /// Seconds of passive income accumulated since last payout
     passive_timer: f32,
+    /// Game seconds since the vendor markets last took a price sample.
+    market_timer: f32,
 }
 
 impl EconomySystem {
@@ -273,6 +311,7 @@ impl EconomySystem {
             credits_per_day_alive: 1.0,
             passive_income_per_day: 1.0,
             passive_timer: 0.0,
+            market_timer: 0.0,
         }
     }
 
@@ -328,7 +367,7 @@ impl System for EconomySystem {
         "economy"
     }
 
-    fn tick(&mut self, world: &mut hecs::World, dt: f32, _data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         // Passive income (v0.747, REAL): 1 credit per GAME day (1200 s, the
         // TimeSystem day length) into every Wallet — "nobody is ever stuck at
         // zero" (economy.ron's design note). Was a TODO log line since the
@@ -343,6 +382,23 @@ impl System for EconomySystem {
             }
             log::debug!("Passive income: +{income} CR");
         }
+
+        // Vendor markets: stock drifts back toward target on game
+        // time, and every game day each post samples its prices for the chart.
+        if let (Some(markets), Some(goods)) = (
+            data.get::<std::sync::Mutex<market::VendorMarkets>>("vendor_markets"),
+            data.get::<TradeGoodsRegistry>("trade_goods_registry"),
+        ) {
+            if let Ok(mut markets) = markets.lock() {
+                let game_dt = crate::systems::time::scaled_dt(dt, data);
+                markets.restock(goods, game_dt / day_seconds);
+                self.market_timer += game_dt;
+                while self.market_timer >= day_seconds {
+                    self.market_timer -= day_seconds;
+                    markets.sample_history(goods);
+                }
+            }
+        }
     }
 }
 
@@ -393,25 +449,25 @@ mod tests {
         let mut credits: i64 = 20;
 
         // Buy 2 iron ore at 7 CR each.
-        let receipt = vendor_buy(&mut inv, &mut credits, &goods, None, "iron_ore_0", 2).unwrap();
+        let receipt = vendor_buy(&mut inv, &mut credits, &goods, None, "iron_ore_0", 2, None).unwrap();
         assert!(receipt.contains("14 CR"), "{receipt}");
         assert_eq!(credits, 6);
         assert_eq!(inv.count_item("iron_ore_0"), 2);
 
         // Too broke for 2 more: refused, nothing changes.
-        let err = vendor_buy(&mut inv, &mut credits, &goods, None, "iron_ore_0", 2).unwrap_err();
+        let err = vendor_buy(&mut inv, &mut credits, &goods, None, "iron_ore_0", 2, None).unwrap_err();
         assert!(err.contains("Not enough credits"), "{err}");
         assert_eq!(credits, 6);
         assert_eq!(inv.count_item("iron_ore_0"), 2);
 
         // Sell both back at 2 CR each.
-        let receipt = vendor_sell(&mut inv, &mut credits, &goods, "iron_ore_0", 2).unwrap();
+        let receipt = vendor_sell(&mut inv, &mut credits, &goods, "iron_ore_0", 2, None).unwrap();
         assert!(receipt.contains("4 CR"), "{receipt}");
         assert_eq!(credits, 10);
         assert_eq!(inv.count_item("iron_ore_0"), 0);
 
         // Selling what you don't have: refused.
-        assert!(vendor_sell(&mut inv, &mut credits, &goods, "iron_ore_0", 1).is_err());
+        assert!(vendor_sell(&mut inv, &mut credits, &goods, "iron_ore_0", 1, None).is_err());
     }
 
     /// v0.750 (ladder rung 8): the shipped equipment.csv parses; the stat
@@ -455,6 +511,8 @@ mod tests {
             ["coat_winter_0", "hat_beanie_0", "gloves_winter_0"].iter().copied(),
         );
         assert!((map.get("thermal").copied().unwrap_or(0.0) - 0.50).abs() < 1e-4);
+        // ... and the cold channel (a column, not a field): 0.45 + 0.10 + 0.10.
+        assert!((map.get("cold").copied().unwrap_or(0.0) - 0.65).abs() < 1e-4);
 
         // Cap: stacking the same big piece many times never exceeds 0.85.
         let stack = ["helmet_combat_0"; 5];
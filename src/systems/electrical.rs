@@ -13,6 +13,11 @@
 //! AND island batteries low — and burns from the machine's own fuel drum
 //! (its `Container` component, flammable-class contents). An empty drum means
 //! no watts: the deep-tail backstop is a real consequence, not a stat line.
+//!
+//! Cabled grid: when the home's power runs exist as `GridCable`s, the islands are
+//! re-derived from the closed cables every tick, each island's cable losses are charged to its
+//! demand, and after the balance `power_grid::solve` flows the current, sags the far ends and trips
+//! overloaded runs (see `systems::power_grid`).
 
 use std::path::Path;
 
@@ -35,6 +40,8 @@ pub struct PowerStatus {
     pub battery_capacity_wh: f32,
     /// Hours the stored charge would run the current load with zero generation.
     pub autonomy_hours: f32,
+    /// Resistive loss in the home's cables (watts), part of the demand.
+    pub line_loss_w: f32,
 }
 
 /// Integrate one tick of grid balance into a single battery. `balance_w` = generation - demand
@@ -91,6 +98,9 @@ pub struct ElectricalSystem {
     /// slow burn (1.5 L/h at 60 fps) still consumes whole fuel UNITS from the
     /// drum once enough has accumulated. Keyed by the genset entity. (v0.733)
     fuel_accum: std::collections::HashMap<hecs::Entity, f32>,
+    /// Cable loss per island from the last grid solve (watts), charged to the island's demand on
+    /// the next tick. Empty when the world has no grid cables.
+    line_loss: std::collections::HashMap<Option<u32>, f32>,
 }
 
 impl ElectricalSystem {
@@ -112,10 +122,20 @@ impl ElectricalSystem {
             total_consumption: 0.0,
             log_cooldown: 0.0,
             fuel_accum: std::collections::HashMap::new(),
+            line_loss: std::collections::HashMap::new(),
         }
     }
 }
 
+impl ElectricalSystem {
+    /// Hold off the periodic balance log for `secs` of sim time. The offline
+    /// catch-up steps through days in minutes and would otherwise log a
+    /// deficit warning for every night-time step.
+    pub fn mute_logs(&mut self, secs: f32) {
+        self.log_cooldown = secs;
+    }
+}
+
 impl System for ElectricalSystem {
     fn name(&self) -> &str { "ElectricalSystem" }
 
@@ -129,6 +149,18 @@ impl System for ElectricalSystem {
         // all share the `None` bucket, which reproduces the old whole-world summing exactly.
         // The published PowerStatus aggregates across islands so the Home card still shows the home.
 
+        // Cabled grid: a Home-page reset closes every open run first, then the islands are
+        // re-derived from the cables that are still closed.
+        let reset = data
+            .get::<std::sync::Mutex<bool>>("grid_reset_request")
+            .and_then(|m| m.lock().ok().map(|mut r| std::mem::take(&mut *r)))
+            .unwrap_or(false);
+        if reset {
+            let n = crate::systems::power_grid::reset_tripped(world);
+            log::info!("Grid reset: closed {n} open run(s)");
+        }
+        let cabled = crate::systems::power_grid::assign_live_islands(world);
+
         // 0. Backstop gensets (v0.733): decide per fueled generator whether it
         // RUNS this tick — its island's free sources short of raw demand AND
         // the island batteries low — and burn drum fuel while it does. This
@@ -250,6 +282,8 @@ impl System for ElectricalSystem {
         let mut to_enable: Vec<hecs::Entity> = Vec::new();
         let (mut total_gen_all, mut consumed_all, mut demand_all) = (0.0_f32, 0.0_f32, 0.0_f32);
         let (mut battery_wh, mut battery_cap) = (0.0_f32, 0.0_f32);
+        let mut line_loss_all = 0.0_f32;
+        let mut battery_applied: HashMap<hecs::Entity, f32> = HashMap::new();
 
         // 2. Balance + shed + integrate batteries, ONE ISLAND AT A TIME.
         for key in keys {
@@ -257,10 +291,13 @@ impl System for ElectricalSystem {
             let mut consumers = cons_by.remove(&key).unwrap_or_default();
             // Highest priority shed FIRST (convention: priority 5 = optional, 1 = critical).
             consumers.sort_by(|a, b| b.2.cmp(&a.2));
-            let total_demand: f32 = consumers.iter().map(|(_, w, _)| *w).sum();
+            // Cable losses come off the top: the wiring takes its I^2 R before any load does.
+            let loss = self.line_loss.get(&key).copied().unwrap_or(0.0);
+            line_loss_all += loss;
+            let total_demand: f32 = consumers.iter().map(|(_, w, _)| *w).sum::<f32>() + loss;
             demand_all += total_demand;
 
-            let mut remaining = total_gen;
+            let mut remaining = total_gen - loss;
             let mut consumed = 0.0_f32;
             if total_demand <= total_gen {
                 for (e, draw, _) in &consumers {
@@ -295,6 +332,7 @@ impl System for ElectricalSystem {
                             b.charge_wh, b.capacity_wh, b.max_charge_w, b.max_discharge_w, grid_balance, dt,
                         );
                         b.charge_wh = new_charge;
+                        battery_applied.insert(e, applied_w);
                         grid_balance -= applied_w;
                         battery_wh += b.charge_wh;
                         battery_cap += b.capacity_wh;
@@ -313,9 +351,22 @@ impl System for ElectricalSystem {
 
         self.total_generation = total_gen_all;
         self.total_consumption = consumed_all;
-        self.power_balance = total_gen_all - consumed_all;
+        self.power_balance = total_gen_all - consumed_all - line_loss_all;
         let autonomy_hours = if demand_all > 1.0 { battery_wh / demand_all } else { 0.0 };
 
+        // 3b. Flow the balanced island through its cables: currents, loss, sag, and trips.
+        if cabled {
+            let (report, losses) = crate::systems::power_grid::solve(world, dt, &battery_applied);
+            self.line_loss = losses;
+            if let Some(slot) = data.get::<std::sync::Mutex<crate::systems::power_grid::GridReport>>("grid_report") {
+                if let Ok(mut r) = slot.lock() {
+                    *r = report;
+                }
+            }
+        } else {
+            self.line_loss.clear();
+        }
+
         // 4. Publish the aggregated live readout to the DataStore for the GUI.
         if let Some(status) = data.get::<std::sync::Mutex<PowerStatus>>("power_status") {
             if let Ok(mut s) = status.lock() {
@@ -325,6 +376,7 @@ impl System for ElectricalSystem {
                 s.battery_wh = battery_wh;
                 s.battery_capacity_wh = battery_cap;
                 s.autonomy_hours = autonomy_hours;
+                s.line_loss_w = line_loss_all;
             }
         }
 
@@ -1,14 +1,194 @@
-//! Off-screen farming autonomy — simulates crop growth while player is away.
+//! Off-screen farming autonomy — tends the garden while the player is away.
 //!
-//! Autonomy rules loaded from `config/farming_automation.ron`.
+//! The offline catch-up (`systems::catch_up`) advances the home in coarse
+//! steps of a minute or more, not frames. `FarmAutomation::step` evolves each
+//! crop's water and health across one such step in closed form, matching what
+//! the per-frame `FarmingSystem` tick would have done: water drains linearly,
+//! health recovers while the crop is above the stress threshold and decays
+//! below it, RF stress bites throughout. Growth itself is age-based, so once
+//! the clock has moved forward the next live tick settles every stage.
+//!
+//! Autonomy rules loaded from `data/farming_automation.ron`.
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::{
+    home_rf_level, PlantRegistry, DEHYDRATION_RATE, HEALTH_DECAY_RATE, HEALTH_RECOVERY_RATE,
+    RF_HARM_THRESHOLD, RF_HEALTH_PENALTY, WATER_STRESS_THRESHOLD,
+};
+use crate::ecs::components::{CropInstance, STAGE_DEAD};
+
+/// Top-level RON schema for `data/farming_automation.ron`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AutomationRules {
+    /// Keep crops irrigated while the home has water. The live garden's
+    /// per-area irrigation targets are GUI state and are not saved, so without
+    /// this an away session longer than a few minutes wilts everything.
+    #[serde(default = "default_tend_crops")]
+    pub tend_crops: bool,
+    /// Water level tended crops are topped up to (0..1).
+    #[serde(default = "default_water_target")]
+    pub water_target: f32,
+}
+
+fn default_tend_crops() -> bool {
+    true
+}
+
+fn default_water_target() -> f32 {
+    0.8
+}
+
+impl Default for AutomationRules {
+    fn default() -> Self {
+        Self { tend_crops: default_tend_crops(), water_target: default_water_target() }
+    }
+}
+
+/// What one catch-up step did to the garden.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StepOutcome {
+    /// Growing crops that were irrigated this step.
+    pub tended: u32,
+    /// Crops whose health ran out this step.
+    pub died: u32,
+}
 
-/// Handles off-screen farm simulation (tick-based catch-up).
+/// Handles off-screen farm simulation (coarse-step catch-up).
 pub struct FarmAutomation {
-    // TODO: queued growth ticks, harvest results
+    pub rules: AutomationRules,
 }
 
 impl FarmAutomation {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(data_dir: &Path) -> Self {
+        let path = data_dir.join("farming_automation.ron");
+        let rules = match std::fs::read_to_string(&path) {
+            Ok(text) => ron::from_str(&text).unwrap_or_else(|e| {
+                log::warn!("Failed to parse farming_automation.ron: {e}");
+                AutomationRules::default()
+            }),
+            Err(e) => {
+                log::warn!("Failed to read {}: {e}", path.display());
+                AutomationRules::default()
+            }
+        };
+        Self { rules }
+    }
+
+    /// Advance every growing crop by `dt` seconds. `water_available` is the
+    /// home's cistern state for this step (the same gate the live irrigation
+    /// uses). Mature and dead crops are left alone, as in the live tick.
+    pub fn step(
+        &self,
+        world: &mut hecs::World,
+        dt: f32,
+        water_available: bool,
+        plants: Option<&PlantRegistry>,
+    ) -> StepOutcome {
+        let mut outcome = StepOutcome::default();
+        if dt <= 0.0 {
+            return outcome;
+        }
+        let home_rf = home_rf_level(world);
+        let irrigate_to = (self.rules.tend_crops && water_available).then_some(self.rules.water_target);
+        for (_e, crop) in world.query_mut::<&mut CropInstance>() {
+            if crop.growth_stage == STAGE_DEAD {
+                continue;
+            }
+            let mature = plants
+                .and_then(|reg| reg.get(&crop.crop_def_id))
+                .is_some_and(|def| crop.growth_stage == def.last_stage());
+            if mature {
+                continue;
+            }
+            if irrigate_to.is_some() {
+                outcome.tended += 1;
+            }
+            if advance_crop(crop, dt, irrigate_to, home_rf) {
+                outcome.died += 1;
+            }
+        }
+        outcome
+    }
+}
+
+/// Closed-form water + health evolution of one crop over `dt` seconds: water
+/// falls at `DEHYDRATION_RATE` down to `irrigate_to` (or dry), health
+/// recovers while water is above `WATER_STRESS_THRESHOLD` and decays after.
+/// Returns true if the crop died in this span.
+pub fn advance_crop(crop: &mut CropInstance, dt: f32, irrigate_to: Option<f32>, home_rf: f32) -> bool {
+    let floor = irrigate_to.unwrap_or(0.0).clamp(0.0, 1.0);
+    let start = crop.water_level.max(floor);
+    crop.water_level = (start - DEHYDRATION_RATE * dt).max(floor);
+    // Time spent well watered before the level crosses into stress.
+    let watered_for = if floor >= WATER_STRESS_THRESHOLD {
+        dt
+    } else {
+        ((start - WATER_STRESS_THRESHOLD) / DEHYDRATION_RATE).clamp(0.0, dt)
+    };
+    let mut health = (crop.health + HEALTH_RECOVERY_RATE * watered_for).min(100.0);
+    health -= HEALTH_DECAY_RATE * (dt - watered_for);
+    if home_rf > RF_HARM_THRESHOLD {
+        health -= RF_HEALTH_PENALTY * home_rf * dt;
+    }
+    crop.health = health.max(0.0);
+    if crop.health <= 0.0 {
+        crop.growth_stage = STAGE_DEAD.to_string();
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crop(water: f32, health: f32) -> CropInstance {
+        CropInstance {
+            crop_def_id: "tomato".into(),
+            growth_stage: "seedling".into(),
+            planted_at: 0.0,
+            water_level: water,
+            health,
+            tower_id: None,
+            tower_slot: None,
+            genome: None,
+            cultivar: None,
+        }
+    }
+
+    /// One long step lands where many per-frame steps of the live rules would.
+    #[test]
+    fn one_coarse_step_matches_many_fine_steps() {
+        for irrigate_to in [None, Some(0.1), Some(0.8)] {
+            let mut coarse = crop(1.0, 60.0);
+            advance_crop(&mut coarse, 450.0, irrigate_to, 0.0);
+            let mut fine = crop(1.0, 60.0);
+            for _ in 0..45_000 {
+                advance_crop(&mut fine, 0.01, irrigate_to, 0.0);
+            }
+            assert!((coarse.water_level - fine.water_level).abs() < 1e-3, "{irrigate_to:?}");
+            assert!((coarse.health - fine.health).abs() < 0.5, "{irrigate_to:?}: {} vs {}", coarse.health, fine.health);
+            assert_eq!(coarse.growth_stage, fine.growth_stage, "{irrigate_to:?}");
+        }
+    }
+
+    #[test]
+    fn untended_crops_wilt_and_tended_ones_thrive() {
+        let mut world = hecs::World::new();
+        world.spawn((crop(1.0, 50.0),));
+        let tended = FarmAutomation { rules: AutomationRules::default() };
+        let out = tended.step(&mut world, 3600.0, true, None);
+        assert_eq!(out, StepOutcome { tended: 1, died: 0 });
+        let c = world.query_mut::<&CropInstance>().into_iter().next().unwrap().1.clone();
+        assert_eq!(c.health, 100.0);
+
+        // A dry cistern stops the tending, same as the live irrigation gate.
+        let out = tended.step(&mut world, 3600.0, false, None);
+        assert_eq!(out, StepOutcome { tended: 0, died: 1 });
+        let c = world.query_mut::<&CropInstance>().into_iter().next().unwrap().1.clone();
+        assert_eq!(c.growth_stage, STAGE_DEAD);
     }
 }
@@ -0,0 +1,353 @@
+//! Seed saving and plant breeding.
+//!
+//! Every crop sown while `data/genetics.ron` is loaded carries a diploid
+//! `Genome` of the plant traits. A plain `seed_<plant>_0` sows founder
+//! (landrace) stock with some spread around the species norm. Saving seed
+//! from a mature crop records a `Cultivar`: that crop as seed parent, an
+//! adjacent crop of the same species as pollen parent (itself when it stood
+//! alone), under a new seed item `seed_<plant>_cv<n>`. Every seed sown from
+//! a cultivar is a fresh cross of the two, so a line keeps segregating and
+//! selection across generations is the player choosing which crops to save
+//! seed from. Cultivars live as ECS entities and round-trip in the save.
+
+use std::collections::HashMap;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::ecs::components::{CropInstance, Genome, STAGE_DEAD};
+use crate::systems::economy::{TradeGood, TradeGoodsRegistry};
+use crate::systems::genetics::GeneticsSystem;
+use crate::systems::inventory::{ItemDef, ItemRegistry};
+
+/// `genetics.ron` plant traits the garden expresses.
+pub const YIELD: &str = "plant_yield";
+pub const GROWTH_SPEED: &str = "plant_growth_speed";
+pub const DROUGHT_RESISTANCE: &str = "plant_drought_resistance";
+pub const COLOR: &str = "plant_color";
+
+/// Seeds a harvest returns, from the lot the crop was sown from (survival)
+/// or from a new cultivar when seed is saved.
+pub const SEEDS_PER_HARVEST: u32 = 2;
+
+/// A named seed lot: two parent genomes and the item its seeds trade as.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cultivar {
+    /// The seed item id, `seed_<plant>_cv<n>`.
+    pub seed_id: String,
+    pub plant_id: String,
+    pub name: String,
+    /// Rounds of seed saving since founder stock (1 = first saved seed).
+    pub generation: u32,
+    /// Seed parent and pollen parent.
+    pub mother: Genome,
+    pub father: Genome,
+}
+
+impl Cultivar {
+    /// One seed of this lot: a fresh cross of its parents.
+    pub fn sow<R: Rng + ?Sized>(&self, genetics: &GeneticsSystem, rng: &mut R) -> Genome {
+        genetics.breed(&self.mother, &self.father, rng)
+    }
+}
+
+/// How a genome shows in the garden. Multipliers are relative to the
+/// species norm, so a crop without a genome is all 1.0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Phenotype {
+    /// Harvest yield multiplier.
+    pub yield_mult: f32,
+    /// Growth progress multiplier; days to maturity divide by it.
+    pub growth_mult: f32,
+    /// Health lost to water stress, relative to the species norm.
+    pub drought_mult: f32,
+    pub color: Option<String>,
+}
+
+impl Default for Phenotype {
+    fn default() -> Self {
+        Self { yield_mult: 1.0, growth_mult: 1.0, drought_mult: 1.0, color: None }
+    }
+}
+
+impl Phenotype {
+    pub fn of(genetics: Option<&GeneticsSystem>, genome: Option<&Genome>) -> Self {
+        let (Some(g), Some(genome)) = (genetics, genome) else {
+            return Self::default();
+        };
+        // Resistance r against the norm b: stress bites by (1 - r) / (1 - b).
+        let base = g.spec(DROUGHT_RESISTANCE).and_then(|s| s.base_value);
+        let drought_mult = match (g.express(genome, DROUGHT_RESISTANCE), base) {
+            (Some(r), Some(b)) if b < 1.0 => ((1.0 - r) / (1.0 - b)).clamp(0.1, 2.0),
+            _ => 1.0,
+        };
+        Self {
+            yield_mult: g.relative(genome, YIELD),
+            growth_mult: g.relative(genome, GROWTH_SPEED).max(0.1),
+            drought_mult,
+            color: g.express_allele(genome, COLOR).map(str::to_string),
+        }
+    }
+
+    /// Garden card readout, e.g. "yield 1.08x · 54 days · drought 0.92x · red".
+    pub fn describe(&self, growth_days: f32) -> String {
+        let mut s = format!(
+            "yield {:.2}x · {:.0} days · drought {:.2}x",
+            self.yield_mult,
+            growth_days / self.growth_mult,
+            self.drought_mult
+        );
+        if let Some(c) = &self.color {
+            s.push_str(" · ");
+            s.push_str(c);
+        }
+        s
+    }
+}
+
+/// The cultivar a seed item belongs to, if it is one.
+pub fn find(world: &hecs::World, seed_id: &str) -> Option<Cultivar> {
+    world
+        .query::<&Cultivar>()
+        .iter()
+        .find(|(_e, c)| c.seed_id == seed_id)
+        .map(|(_e, c)| c.clone())
+}
+
+/// Display name per cultivar seed item id.
+pub fn cultivar_names(world: &hecs::World) -> HashMap<String, String> {
+    world
+        .query::<&Cultivar>()
+        .iter()
+        .map(|(_e, c)| (c.seed_id.clone(), c.name.clone()))
+        .collect()
+}
+
+/// Register every cultivar's seed as an item, copied from its plant's plain
+/// `seed_<plant>_0` row under the cultivar's name, so bred seed stacks and
+/// weighs like the seed it came from. Skips a plant with no base row.
+pub fn register_seed_items(world: &hecs::World, items: &mut ItemRegistry) {
+    for (_e, c) in world.query::<&Cultivar>().iter() {
+        if let Some(base) = items.items.get(&format!("seed_{}_0", c.plant_id)) {
+            let def = ItemDef { id: c.seed_id.clone(), name: format!("{} Seeds", c.name), ..base.clone() };
+            items.items.insert(c.seed_id.clone(), def);
+        }
+    }
+}
+
+/// Register every cultivar's seed as a trade good priced like its plant's
+/// plain seed, so vendors buy and sell bred seed.
+pub fn register_seed_goods(world: &hecs::World, goods: &mut TradeGoodsRegistry) {
+    for (_e, c) in world.query::<&Cultivar>().iter() {
+        if let Some(base) = goods.get(&format!("seed_{}_0", c.plant_id)) {
+            let good = TradeGood { id: c.seed_id.clone(), name: format!("{} Seeds", c.name), ..base.clone() };
+            goods.goods.insert(c.seed_id.clone(), good);
+        }
+    }
+}
+
+/// Genome and cultivar tag for one seed sown: a cross from its cultivar, or
+/// founder stock for a plain seed. Both None when genetics is not loaded.
+pub fn sow(
+    world: &hecs::World,
+    genetics: Option<&GeneticsSystem>,
+    seed_id: &str,
+) -> (Option<Genome>, Option<String>) {
+    let Some(g) = genetics else {
+        return (None, None);
+    };
+    let mut rng = rand::rng();
+    match find(world, seed_id) {
+        Some(c) => (Some(c.sow(g, &mut rng)), Some(c.seed_id)),
+        None => (Some(g.founder("plant", &mut rng)), None),
+    }
+}
+
+/// The pollen parent for a crop: a live crop of the same species in the
+/// next unit either side of the same grow area. None when it stands alone.
+fn pollen_parent(world: &hecs::World, crop: &CropInstance) -> Option<Genome> {
+    let (area, slot) = (crop.tower_id.as_deref()?, crop.tower_slot?);
+    let neighbours: Vec<Genome> = world
+        .query::<&CropInstance>()
+        .iter()
+        .filter(|(_e, c)| {
+            c.crop_def_id == crop.crop_def_id
+                && c.growth_stage != STAGE_DEAD
+                && c.tower_id.as_deref() == Some(area)
+                && c.tower_slot.is_some_and(|s| s.abs_diff(slot) == 1)
+        })
+        .filter_map(|(_e, c)| c.genome.clone())
+        .collect();
+    if neighbours.is_empty() {
+        return None;
+    }
+    let i = rand::rng().random_range(0..neighbours.len());
+    neighbours.into_iter().nth(i)
+}
+
+/// Save seed from a mature crop (call before it is despawned): records a new
+/// cultivar of this crop crossed with its pollen parent and returns its seed
+/// item id. None for a crop with no genome.
+pub fn save_seed(world: &mut hecs::World, plant_name: &str, crop: &CropInstance) -> Option<String> {
+    let mother = crop.genome.clone()?;
+    let father = pollen_parent(world, crop).unwrap_or_else(|| mother.clone());
+    let generation = crop
+        .cultivar
+        .as_deref()
+        .and_then(|id| find(world, id))
+        .map_or(1, |c| c.generation + 1);
+    let prefix = format!("seed_{}_cv", crop.crop_def_id);
+    let n = world
+        .query::<&Cultivar>()
+        .iter()
+        .filter_map(|(_e, c)| c.seed_id.strip_prefix(&prefix)?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let seed_id = format!("{prefix}{n}");
+    world.spawn((Cultivar {
+        seed_id: seed_id.clone(),
+        plant_id: crop.crop_def_id.clone(),
+        name: format!("{plant_name} selection {n}"),
+        generation,
+        mother,
+        father,
+    },));
+    Some(seed_id)
+}
+
+/// Give a cultivar a player-chosen name. False when no such cultivar.
+pub fn rename(world: &mut hecs::World, seed_id: &str, name: &str) -> bool {
+    let name = name.trim();
+    if name.is_empty() {
+        return false;
+    }
+    match world.query_mut::<&mut Cultivar>().into_iter().find(|(_e, c)| c.seed_id == seed_id) {
+        Some((_e, c)) => {
+            c.name = name.to_string();
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GENETICS: &str = r#"(
+        plant_traits: [
+            (id: "plant_yield", applies_to: "plant", inheritance_pattern: "polygenic",
+             mutation_chance: 0.0, range_min: 0.5, range_max: 3.0, base_value: 1.0),
+            (id: "plant_drought_resistance", applies_to: "plant", inheritance_pattern: "codominant",
+             mutation_chance: 0.0, range_min: 0.0, range_max: 1.0, base_value: 0.3),
+            (id: "plant_color", applies_to: "plant", inheritance_pattern: "dominant",
+             mutation_chance: 0.0, alleles: ["red", "yellow"]),
+        ],
+    )"#;
+
+    fn genome(yield_a: &str, yield_b: &str, color: (&str, &str)) -> Genome {
+        let mut alleles = HashMap::new();
+        alleles.insert(YIELD.to_string(), (yield_a.to_string(), yield_b.to_string()));
+        alleles.insert(COLOR.to_string(), (color.0.to_string(), color.1.to_string()));
+        Genome { alleles }
+    }
+
+    fn crop(slot: u32, genome: Option<Genome>) -> CropInstance {
+        CropInstance {
+            crop_def_id: "tomato".into(),
+            growth_stage: "ripe".into(),
+            planted_at: 0.0,
+            water_level: 1.0,
+            health: 100.0,
+            tower_id: Some("tomato_grow_bed".into()),
+            tower_slot: Some(slot),
+            genome,
+            cultivar: None,
+        }
+    }
+
+    #[test]
+    fn phenotype_follows_inheritance_patterns() {
+        let g = GeneticsSystem::from_ron(GENETICS).unwrap();
+        let p = Phenotype::of(Some(&g), Some(&genome("1.0", "2.0", ("yellow", "red"))));
+        assert!((p.yield_mult - 1.5).abs() < 1e-4, "polygenic = mean of the pair");
+        assert_eq!(p.color.as_deref(), Some("red"), "red is listed first, so dominant");
+        assert_eq!(p.drought_mult, 1.0, "a missing trait expresses as the norm");
+        assert_eq!(Phenotype::of(Some(&g), None), Phenotype::default());
+
+        // Founders carry every plant trait inside its range.
+        let founder = g.founder("plant", &mut rand::rng());
+        assert_eq!(founder.alleles.len(), 3);
+        let y = g.express(&founder, YIELD).unwrap();
+        assert!((0.75..=1.25).contains(&y));
+    }
+
+    #[test]
+    fn saved_seed_crosses_with_the_neighbour_and_breeds_true_when_fixed() {
+        let g = GeneticsSystem::from_ron(GENETICS).unwrap();
+        let mut world = hecs::World::new();
+        let mother = crop(0, Some(genome("2.0", "2.0", ("yellow", "yellow"))));
+        world.spawn((crop(1, Some(genome("2.0", "2.0", ("red", "red")))),));
+        world.spawn((crop(5, Some(genome("0.5", "0.5", ("red", "red")))),));
+
+        let seed = save_seed(&mut world, "Tomato", &mother).unwrap();
+        assert_eq!(seed, "seed_tomato_cv1");
+        let lot = find(&world, &seed).unwrap();
+        assert_eq!(lot.generation, 1);
+        assert_eq!(lot.father.alleles[YIELD].0, "2.0", "slot 1 is the pollen parent, not slot 5");
+
+        // Every seed of the lot is a yellow x red cross: red, and the fixed yield.
+        for _ in 0..20 {
+            let (child, tag) = sow(&world, Some(&g), &seed);
+            let p = Phenotype::of(Some(&g), child.as_ref());
+            assert_eq!(p.color.as_deref(), Some("red"));
+            assert!((p.yield_mult - 2.0).abs() < 1e-4);
+            assert_eq!(tag.as_deref(), Some("seed_tomato_cv1"));
+        }
+
+        // The next selection from that line counts a generation on.
+        let mut child = crop(9, Some(genome("2.0", "2.0", ("red", "yellow"))));
+        child.cultivar = Some(seed.clone());
+        let next = save_seed(&mut world, "Tomato", &child).unwrap();
+        assert_eq!(next, "seed_tomato_cv2");
+        assert_eq!(find(&world, &next).unwrap().generation, 2);
+
+        assert!(rename(&mut world, &next, "Sunburst"));
+        assert_eq!(cultivar_names(&world)[&next], "Sunburst");
+        assert!(save_seed(&mut world, "Tomato", &crop(3, None)).is_none());
+    }
+
+    #[test]
+    fn cultivar_seed_trades_like_its_base_seed() {
+        use crate::systems::economy::{vendor_buy, vendor_sell};
+        use crate::systems::inventory::Inventory;
+        let data = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let mut items = ItemRegistry::from_csv(&std::fs::read(data.join("items.csv")).unwrap()).unwrap();
+        let mut goods =
+            TradeGoodsRegistry::from_ron(&std::fs::read(data.join("trade_goods.ron")).unwrap()).unwrap();
+        let mut world = hecs::World::new();
+        let mother = crop(0, Some(genome("1.0", "1.0", ("red", "red"))));
+        let seed = save_seed(&mut world, "Tomato", &mother).unwrap();
+        assert!(rename(&mut world, &seed, "Highland Red"));
+
+        register_seed_items(&world, &mut items);
+        register_seed_goods(&world, &mut goods);
+        let def = &items.items[&seed];
+        assert_eq!(def.name, "Highland Red Seeds");
+        assert_eq!(def.max_stack, items.items["seed_tomato_0"].max_stack);
+        let good = goods.get(&seed).expect("bred seed is a trade good");
+        assert_eq!(good.base_value, goods.get("seed_tomato_0").unwrap().base_value);
+
+        // Sell the saved seed, then buy it back.
+        let mut inv = Inventory::new(8);
+        inv.add_item(&seed, 4, 50);
+        let mut credits = 0;
+        vendor_sell(&mut inv, &mut credits, &goods, &seed, 4, None).unwrap();
+        assert_eq!(inv.count_item(&seed), 0);
+        assert_eq!(credits, 4, "0.5x of base 2 per seed");
+        vendor_buy(&mut inv, &mut credits, &goods, Some(&items), &seed, 1, None).unwrap();
+        assert_eq!(inv.count_item(&seed), 1);
+        assert_eq!(credits, 1, "1.25x of base 2, rounded up");
+    }
+}
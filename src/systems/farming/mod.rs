@@ -5,6 +5,7 @@
 //! Growth stages are data-driven: each plant species defines its own stage
 //! names in plants.csv (colon-separated). Default stages are used when missing.
 
+pub mod breeding;
 pub mod crops;
 pub mod soil;
 pub mod automation;
@@ -13,6 +14,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::ecs::components::{CropInstance, DEFAULT_GROWTH_STAGES, STAGE_DEAD};
+use soil::{AmendmentRegistry, SoilPlot, SoilRegistry};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
 
@@ -23,6 +25,10 @@ pub struct PlantDef {
     pub id: String,
     /// Display name.
     pub name: String,
+    /// plants.csv `type` column: fruit, vegetable, grain, legume, herb, fiber.
+    /// Legumes fix nitrogen into their soil plot instead of drawing it.
+    #[serde(default)]
+    pub category: String,
     /// Total real-world days from seed to harvest.
     pub growth_days: f32,
     /// Water consumption in liters per day per plant.
@@ -42,11 +48,13 @@ pub struct PlantDef {
     pub yield_min: f32,
     pub yield_max: f32,
     /// Relative nutrient demand fractions (N, P, K) from plants.csv. Shown per
-    /// crop in the Garden table; feed the future shared-reservoir mix math.
+    /// crop in the Garden table; a soil-grown crop draws them from its plot
+    /// stage by stage (`soil::Soil::draw_for_stages`).
     pub nutrient_n: f32,
     pub nutrient_p: f32,
     pub nutrient_k: f32,
-    /// Preferred reservoir pH window (for the future tower compatibility check).
+    /// Preferred pH window: the tower reservoir check, and the soil pH band
+    /// outside which a bed / field crop slows.
     pub ph_min: f32,
     pub ph_max: f32,
     /// Tolerated air/water temperature window, Celsius.
@@ -65,6 +73,11 @@ pub struct PlantDef {
 }
 
 impl PlantDef {
+    /// Nitrogen-fixing crop (beans, peas, clover)?
+    pub fn is_legume(&self) -> bool {
+        self.category == "legume"
+    }
+
     /// Returns this plant's growth stages, falling back to defaults if empty.
     pub fn stages(&self) -> Vec<&str> {
         if self.growth_stages.is_empty() {
@@ -121,6 +134,7 @@ impl PlantRegistry {
                 PlantDef {
                     id: row.id,
                     name: row.name,
+                    category: row.category,
                     growth_days: row.growth_days,
                     water_per_day: row.water_liters_per_day,
                     seasons: split_colon_list(&row.seasons),
@@ -145,7 +159,8 @@ impl PlantRegistry {
 }
 
 /// One row of `plants.csv`. The columns `PlantRegistry` consumes; extra CSV
-/// columns (value/skill/companions/adverse) are still ignored. Nutrient demand
+/// columns (value/skill/companions/adverse) are still ignored. `type` feeds
+/// the soil model (legumes fix nitrogen). Nutrient demand
 /// (N/P/K), the pH window, the temperature window, and the humidity window are
 /// now parsed so the Garden table can show per-crop needs and a future tower
 /// compatibility check can compute the shared-reservoir window. Every numeric
@@ -155,6 +170,8 @@ impl PlantRegistry {
 struct PlantRow {
     id: String,
     name: String,
+    #[serde(default, rename = "type")]
+    category: String,
     #[serde(default)]
     growth_days: f32,
     #[serde(default)]
@@ -318,6 +335,20 @@ const RF_HEALTH_PENALTY: f32 = 1.5;
 /// Seconds per in-game day (must match time system).
 const SECONDS_PER_DAY: f64 = 1200.0;
 
+/// Sum of every POWERED RF emitter's strength: the home RF level crops are
+/// stressed by. Shared by the live tick and the offline catch-up.
+fn home_rf_level(world: &hecs::World) -> f32 {
+    use crate::ecs::components::{PowerConsumer, RfEmitter};
+    let mut rf = 0.0f32;
+    for (_, (em, power)) in world.query::<(&RfEmitter, Option<&PowerConsumer>)>().iter() {
+        let powered = !em.needs_power || power.map(|c| c.enabled).unwrap_or(false);
+        if powered {
+            rf += em.strength;
+        }
+    }
+    rf
+}
+
 /// Determine growth stage from progress fraction (0.0 to 1.0+) using
 /// a data-driven stage list. Stages are evenly distributed across the
 /// 0.0-1.0 range unless custom thresholds are added later.
@@ -338,11 +369,13 @@ fn stage_index(stage: &str, stages: &[&str]) -> Option<usize> {
 }
 
 /// Map a seed item id (`seed_<plant>_0`) to its plant-definition id (`<plant>`).
-/// Strips the `seed_` prefix and a trailing `_<n>` item-instance suffix.
+/// Strips the `seed_` prefix and a trailing `_<n>` item-instance suffix, or a
+/// cultivar's `_cv<n>` (`breeding::save_seed`).
 fn plant_id_from_seed(seed_id: &str) -> Option<String> {
     let body = seed_id.strip_prefix("seed_")?;
     if let Some((base, suffix)) = body.rsplit_once('_') {
-        if !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()) {
+        let digits = suffix.strip_prefix("cv").unwrap_or(suffix);
+        if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
             return Some(base.to_string());
         }
     }
@@ -400,6 +433,12 @@ impl System for FarmingSystem {
 
     fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         let plant_registry = data.get::<PlantRegistry>("plant_registry");
+        // Soil chemistry: beds / trays / fields get a SoilPlot per unit (absent
+        // registry = soil-less, every crop grows as if in perfect soil).
+        let soil_registry = data.get::<SoilRegistry>("soil_registry");
+        let amendments = data.get::<AmendmentRegistry>("soil_amendment_registry");
+        // Plant traits (genetics.ron): crops sown with it carry a genome.
+        let genetics = data.get::<crate::systems::genetics::GeneticsSystem>("genetics");
 
         // Get current elapsed time from TimeSystem's GameTime if available
         let elapsed_seconds = data
@@ -464,17 +503,7 @@ impl System for FarmingSystem {
         // RF -> FOOD coupling (v0.620): sum every POWERED RF emitter (a WiFi router) into a home RF
         // level. Sensitive crops lose health under RF -- the operator's "the user doesn't want a WiFi
         // router because it harms a plant they're growing." Run wired (Cat6/fibre, zero RF) to stay clean.
-        let home_rf: f32 = {
-            use crate::ecs::components::{PowerConsumer, RfEmitter};
-            let mut rf = 0.0f32;
-            for (_, (em, power)) in world.query::<(&RfEmitter, Option<&PowerConsumer>)>().iter() {
-                let powered = !em.needs_power || power.map(|c| c.enabled).unwrap_or(false);
-                if powered {
-                    rf += em.strength;
-                }
-            }
-            rf
-        };
+        let home_rf = home_rf_level(world);
 
         // Creative mode (default ON in early dev): planting + fertilizing skip the
         // inventory requirement + consumption. Absent flag (tests) = survival =
@@ -512,6 +541,7 @@ impl System for FarmingSystem {
                         }
                     }
                     if planted {
+                        let (genome, cultivar) = breeding::sow(world, genetics, &seed_id);
                         world.spawn((CropInstance {
                             crop_def_id: plant_id.clone(),
                             growth_stage: first_stage,
@@ -520,6 +550,8 @@ impl System for FarmingSystem {
                             health: 100.0,
                             tower_id: None,
                             tower_slot: None,
+                            genome,
+                            cultivar,
                         },));
                         log::info!("[Farming] planted {plant_id} (from {seed_id})");
                     }
@@ -575,8 +607,8 @@ impl System for FarmingSystem {
                     let _ = world.despawn(e);
                 }
                 // Survival: consume one seed for this variety, skip if absent.
+                let seed_id = format!("seed_{plant_id}_0");
                 if !creative {
-                    let seed_id = format!("seed_{plant_id}_0");
                     let mut had = false;
                     for (_e, (inv, _ctrl)) in world.query_mut::<(
                         &mut crate::systems::inventory::Inventory,
@@ -593,6 +625,7 @@ impl System for FarmingSystem {
                         continue;
                     }
                 }
+                let (genome, cultivar) = breeding::sow(world, genetics, &seed_id);
                 world.spawn((CropInstance {
                     crop_def_id: plant_id,
                     growth_stage: first_stage,
@@ -601,6 +634,8 @@ impl System for FarmingSystem {
                     health: 100.0,
                     tower_id: Some(tower_id.clone()),
                     tower_slot: Some(slot_idx),
+                    genome,
+                    cultivar,
                 },));
                 planted += 1;
             }
@@ -634,6 +669,7 @@ impl System for FarmingSystem {
                 for slot in 0..slots {
                     let frac = slot as f32 / (slots - 1) as f32;
                     let stage_i = ((frac * n_stages as f32).floor() as usize).min(n_stages - 1);
+                    let genome = genetics.map(|g| g.founder("plant", &mut rand::rng()));
                     world.spawn((CropInstance {
                         crop_def_id: plant_id.clone(),
                         growth_stage: stages[stage_i].to_string(),
@@ -642,6 +678,8 @@ impl System for FarmingSystem {
                         health: 100.0,
                         tower_id: Some(tower_id.clone()),
                         tower_slot: Some(slot),
+                        genome,
+                        cultivar: None,
                     },));
                 }
                 log::info!("[Farming] showcase: {tower_id} filled with staggered {plant_id}");
@@ -653,11 +691,16 @@ impl System for FarmingSystem {
         // (live crop keeps its unit, dead ones are replaced), one seed per unit
         // in survival, free in creative. The grow-area MACHINE id rides in
         // `tower_id` (it is the crop's grow-area tag; the Garden GUI groups by
-        // it), with `tower_slot` as the unit index.
+        // it), with `tower_slot` as the unit index. The crop may name a seed
+        // item instead of a plant (`seed_tomato_cv2`) to sow a cultivar.
         let plant_bed = data
             .get::<std::sync::Mutex<Option<(String, String, u32)>>>("plant_bed_request")
             .and_then(|m| m.lock().ok().and_then(|mut s| s.take()));
-        if let Some((area_id, plant_id, count)) = plant_bed {
+        if let Some((area_id, crop, count)) = plant_bed {
+            let (plant_id, seed_id) = match plant_id_from_seed(&crop) {
+                Some(plant_id) => (plant_id, crop),
+                None => (crop.clone(), format!("seed_{crop}_0")),
+            };
             let first_stage = plant_registry
                 .and_then(|reg| reg.get(&plant_id))
                 .map(|d| d.first_stage().to_string());
@@ -684,7 +727,6 @@ impl System for FarmingSystem {
                         let _ = world.despawn(e);
                     }
                     if !creative {
-                        let seed_id = format!("seed_{plant_id}_0");
                         let mut had = false;
                         for (_e, (inv, _ctrl)) in world.query_mut::<(
                             &mut crate::systems::inventory::Inventory,
@@ -700,6 +742,7 @@ impl System for FarmingSystem {
                             continue;
                         }
                     }
+                    let (genome, cultivar) = breeding::sow(world, genetics, &seed_id);
                     world.spawn((CropInstance {
                         crop_def_id: plant_id.clone(),
                         growth_stage: first_stage.clone(),
@@ -708,7 +751,15 @@ impl System for FarmingSystem {
                         health: 100.0,
                         tower_id: Some(area_id.clone()),
                         tower_slot: Some(unit),
+                        genome,
+                        cultivar,
                     },));
+                    // The unit's soil remembers what grew here (crop rotation).
+                    if let Some(plot) = soil_registry.and_then(|r| soil::plot_for(world, r, &area_id, unit)) {
+                        if let Ok(mut plot) = world.get::<&mut SoilPlot>(plot) {
+                            plot.sow(&plant_id);
+                        }
+                    }
                     planted += 1;
                 }
                 log::info!("[Farming] bed-planted {planted}x {plant_id} in {area_id}");
@@ -772,13 +823,82 @@ impl System for FarmingSystem {
                     }
                     break;
                 }
+                let mut fed_unit: Option<(String, u32)> = None;
                 if had_fertilizer {
                     if let Ok(mut crop) = world.get::<&mut CropInstance>(entity) {
                         crop.health = (crop.health + 40.0).min(100.0);
                         crop.water_level = crop.water_level.max(0.5);
+                        fed_unit = crop.tower_id.clone().zip(crop.tower_slot);
                         log::info!("[Farming] fertilized a crop (+health)");
                     }
                 }
+                // A soil-grown crop's fertilizer also feeds its plot.
+                if let (Some((area_id, unit)), Some(soils), Some(dose)) = (
+                    fed_unit,
+                    soil_registry,
+                    amendments.and_then(|r| r.get("fertilizer_0")),
+                ) {
+                    if let Some(plot) = soil::plot_for(world, soils, &area_id, unit) {
+                        if let Ok(mut plot) = world.get::<&mut SoilPlot>(plot) {
+                            plot.soil.amend(dose);
+                        }
+                    }
+                }
+            }
+        }
+
+        // AMEND SOIL: work one amendment item (compost, lime, ...) into each
+        // unit of a bed / tray / field. One item per unit in survival, free in
+        // creative; stops when the player runs out. (area id, item id, units).
+        let amend = data
+            .get::<std::sync::Mutex<Option<(String, String, u32)>>>("amend_soil_request")
+            .and_then(|m| m.lock().ok().and_then(|mut s| s.take()));
+        if let (Some((area_id, item_id, count)), Some(soils)) = (amend, soil_registry) {
+            match amendments.and_then(|r| r.get(&item_id)) {
+                Some(_) if soils.for_area(&area_id).is_none() => {
+                    log::warn!("[Farming] {area_id} is not soil-grown; {item_id} not applied")
+                }
+                Some(dose) => {
+                    let mut treated = 0u32;
+                    for unit in 0..count {
+                        if !creative {
+                            let had = world
+                                .query_mut::<(
+                                    &mut crate::systems::inventory::Inventory,
+                                    &crate::ecs::components::Controllable,
+                                )>()
+                                .into_iter()
+                                .next()
+                                .is_some_and(|(_e, (inv, _ctrl))| {
+                                    inv.has_item(&item_id, 1) && inv.remove_item(&item_id, 1) == 0
+                                });
+                            if !had {
+                                break;
+                            }
+                        }
+                        let Some(plot) = soil::plot_for(world, soils, &area_id, unit) else {
+                            break;
+                        };
+                        if let Ok(mut plot) = world.get::<&mut SoilPlot>(plot) {
+                            plot.soil.amend(dose);
+                        }
+                        treated += 1;
+                    }
+                    log::info!("[Farming] worked {item_id} into {treated} unit(s) of {area_id}");
+                }
+                None => log::warn!("[Farming] {item_id} is not a soil amendment; {area_id} untouched"),
+            }
+        }
+
+        // NAME CULTIVAR: (seed item id, name) from the Garden card.
+        let name_cultivar = data
+            .get::<std::sync::Mutex<Option<(String, String)>>>("name_cultivar_request")
+            .and_then(|m| m.lock().ok().and_then(|mut s| s.take()));
+        if let Some((seed_id, name)) = name_cultivar {
+            if breeding::rename(world, &seed_id, &name) {
+                log::info!("[Farming] {seed_id} named \"{}\"", name.trim());
+            } else {
+                log::warn!("[Farming] can't name {seed_id}: no such cultivar (or empty name)");
             }
         }
 
@@ -808,43 +928,54 @@ impl System for FarmingSystem {
         // home vessel (the grain silo) instead of vanishing — collected here,
         // applied after the inventory borrow ends. (v0.729)
         let mut vessel_routes: Vec<(String, u32)> = Vec::new();
-        let mut harvest_list: Vec<u64> = Vec::new();
+        // (crop bits, save seed from it)
+        let mut harvest_list: Vec<(u64, bool)> = Vec::new();
         if let Some(bits) = data
             .get::<std::sync::Mutex<Option<u64>>>("harvest_request")
             .and_then(|m| m.lock().ok().and_then(|mut s| s.take()))
         {
-            harvest_list.push(bits);
+            harvest_list.push((bits, false));
+        }
+        // Save seed: harvest as usual, but the seeds returned start a new
+        // cultivar from this crop (see `breeding`).
+        if let Some(bits) = data
+            .get::<std::sync::Mutex<Option<u64>>>("save_seed_request")
+            .and_then(|m| m.lock().ok().and_then(|mut s| s.take()))
+        {
+            harvest_list.push((bits, true));
         }
         // Bulk harvest (v0.739): the Garden group's "Harvest N ready" button sends
         // every mature crop's bits at once — same code path, one loop.
         if let Some(m) = data.get::<std::sync::Mutex<Vec<u64>>>("harvest_many_request") {
             if let Ok(mut s) = m.lock() {
-                harvest_list.append(&mut s);
+                harvest_list.extend(s.drain(..).map(|bits| (bits, false)));
             }
         }
-        for bits in harvest_list {
+        for (bits, save_seed) in harvest_list {
             if let Some(entity) = hecs::Entity::from_bits(bits) {
-                // Read the crop (immutable, scoped) to confirm maturity + plant id.
-                let plant_id = world.get::<&CropInstance>(entity).ok().and_then(|crop| {
+                // Read the crop (immutable, scoped) to confirm maturity; cloned
+                // out so seed saving can look at its neighbours.
+                let harvested = world.get::<&CropInstance>(entity).ok().and_then(|crop| {
                     let stages: Vec<&str> = plant_registry
                         .and_then(|reg| reg.get(&crop.crop_def_id))
                         .map(|d| d.stages())
                         .unwrap_or_else(|| default_stages.clone());
                     let mature = stages.last().map(|l| crop.growth_stage == *l).unwrap_or(false);
                     if mature {
-                        Some(crop.crop_def_id.clone())
+                        Some(CropInstance::clone(&crop))
                     } else {
                         None
                     }
                 });
-                if let Some(plant_id) = plant_id {
-                    if let Some(yield_item) = harvest_item_for(&plant_id, plant_registry, item_registry) {
+                if let Some(crop) = harvested {
+                    let plant_id = crop.crop_def_id.as_str();
+                    if let Some(yield_item) = harvest_item_for(plant_id, plant_registry, item_registry) {
                         // Yield range from the plant def. Yields are FRACTIONAL (f32):
                         // saffron's 0.3 means less than one unit per plant per harvest.
                         // Sanitize the window (min >= 0, max >= min); unknown plants
                         // fall back to exactly 1 unit as before.
                         let (ymin, ymax) = plant_registry
-                            .and_then(|reg| reg.get(&plant_id))
+                            .and_then(|reg| reg.get(plant_id))
                             .map(|d| {
                                 let lo = d.yield_min.max(0.0);
                                 (lo, d.yield_max.max(lo))
@@ -857,11 +988,26 @@ impl System for FarmingSystem {
                         // crops average their real output over repeated harvests instead
                         // of being silently rounded up to a full unit (3x inflation for
                         // saffron) or floored to permanent zero.
-                        let rolled = ymin + rand::random::<f32>() * (ymax - ymin);
+                        // A bred crop's yield trait scales the roll.
+                        let phenotype = breeding::Phenotype::of(genetics, crop.genome.as_ref());
+                        let rolled = (ymin + rand::random::<f32>() * (ymax - ymin))
+                            * phenotype.yield_mult;
                         let qty = rolled.floor() as u32
                             + u32::from(rand::random::<f32>() < rolled.fract());
                         let max_stack =
                             item_registry.map(|r| r.max_stack_for(&yield_item)).unwrap_or(99);
+                        // Seeds come back from the lot this crop was sown from;
+                        // saving seed starts a new cultivar instead (given even
+                        // in creative: a cultivar only exists as its seeds).
+                        let plant_name = plant_registry
+                            .and_then(|reg| reg.get(plant_id))
+                            .map_or(plant_id, |d| d.name.as_str());
+                        let saved = save_seed
+                            .then(|| breeding::save_seed(world, plant_name, &crop))
+                            .flatten();
+                        let seed_id = saved.clone().unwrap_or_else(|| {
+                            crop.cultivar.clone().unwrap_or_else(|| format!("seed_{plant_id}_0"))
+                        });
                         for (_e, (inv, _ctrl)) in world.query_mut::<(
                             &mut crate::systems::inventory::Inventory,
                             &crate::ecs::components::Controllable,
@@ -880,13 +1026,20 @@ impl System for FarmingSystem {
                             // the garden is self-sustaining (plant 1 -> harvest -> get 2
                             // back -> replant + surplus). Creative needs no seeds, so it
                             // stays clean. Plot-agnostic: works for any plot type.
-                            if !creative {
-                                let seed_id = format!("seed_{plant_id}_0");
+                            if !creative || saved.is_some() {
                                 let seed_stack =
                                     item_registry.map(|r| r.max_stack_for(&seed_id)).unwrap_or(99);
                                 let seed_vol =
                                     item_registry.map(|r| r.volume_for(&seed_id)).unwrap_or(0.0);
-                                inv.add_item_volume_gated(&seed_id, 2, seed_stack, seed_vol);
+                                inv.add_item_volume_gated(
+                                    &seed_id,
+                                    breeding::SEEDS_PER_HARVEST,
+                                    seed_stack,
+                                    seed_vol,
+                                );
+                            }
+                            if let Some(seed_id) = &saved {
+                                log::info!("[Farming] saved seed from {plant_id} as {seed_id}");
                             }
                             log::info!("[Farming] harvested {qty}x {yield_item} from {plant_id}");
                             // Harvesting trains Farming (scales lightly with yield).
@@ -894,7 +1047,9 @@ impl System for FarmingSystem {
                             // Quest progress: a harvest of this crop (Harvest objectives).
                             crate::systems::quests::push_quest_event(
                                 data,
-                                format!("harvest_{}", plant_id),
+                                crate::systems::quests::QuestEvent::Harvested {
+                                    crop_id: plant_id.to_string(),
+                                },
                             );
                             break;
                         }
@@ -949,6 +1104,39 @@ impl System for FarmingSystem {
             }
         }
 
+        // Soil plots keyed by (grow area, unit). Crops that predate soil (old
+        // saves) get their plot here, seeded from the area's soil type.
+        let mut plots: HashMap<(String, u32), (hecs::Entity, soil::Soil, bool)> = world
+            .query::<&SoilPlot>()
+            .iter()
+            .map(|(e, p)| ((p.area_id.clone(), p.unit), (e, p.soil.clone(), p.is_monoculture())))
+            .collect();
+        if let Some(soils) = soil_registry {
+            let unplotted: Vec<(String, u32, String)> = world
+                .query::<&CropInstance>()
+                .iter()
+                .filter_map(|(_e, c)| {
+                    let key = (c.tower_id.clone()?, c.tower_slot?);
+                    (!plots.contains_key(&key) && soils.for_area(&key.0).is_some())
+                        .then(|| (key.0, key.1, c.crop_def_id.clone()))
+                })
+                .collect();
+            for (area_id, unit, plant_id) in unplotted {
+                let Some(e) = soil::plot_for(world, soils, &area_id, unit) else {
+                    continue;
+                };
+                if let Ok(mut plot) = world.get::<&mut SoilPlot>(e) {
+                    if plot.crop.is_none() {
+                        plot.sow(&plant_id);
+                    }
+                    plots.insert((area_id, unit), (e, plot.soil.clone(), plot.is_monoculture()));
+                }
+            }
+        }
+        // (plot, plant id, stages advanced, stage count, monoculture) to draw
+        // from the soil once the crop loop releases the world.
+        let mut soil_draws: Vec<(hecs::Entity, String, u32, usize, bool)> = Vec::new();
+
         // Collect entities to update (avoid borrow conflict with world)
         let mut updates: Vec<(hecs::Entity, CropInstance)> = Vec::new();
 
@@ -991,10 +1179,14 @@ impl System for FarmingSystem {
                 }
             }
 
+            // Bred traits: drought resistance here, growth speed below.
+            let phenotype = breeding::Phenotype::of(genetics, crop.genome.as_ref());
+
             // Health effects from water level
             if crop.water_level < WATER_STRESS_THRESHOLD {
                 // Water stress -- health decays
-                crop.health = (crop.health - HEALTH_DECAY_RATE * dt).max(0.0);
+                crop.health =
+                    (crop.health - HEALTH_DECAY_RATE * phenotype.drought_mult * dt).max(0.0);
             } else {
                 // Well watered -- health recovers toward 100
                 crop.health = (crop.health + HEALTH_RECOVERY_RATE * dt).min(100.0);
@@ -1068,8 +1260,21 @@ impl System for FarmingSystem {
                         } else {
                             1.0
                         };
-                        let effective_progress =
-                            progress * health_factor * nutrient_factor * climate_factor;
+                        // Soil chemistry: the scarcest nutrient and the pH
+                        // band of this unit's plot. Soil-less crops skip it.
+                        let plot = crop
+                            .tower_id
+                            .clone()
+                            .zip(crop.tower_slot)
+                            .and_then(|key| plots.get(&key));
+                        let soil_factor =
+                            plot.map_or(1.0, |(_, soil, _)| soil.growth_factor(plant_def));
+                        let effective_progress = progress
+                            * health_factor
+                            * nutrient_factor
+                            * climate_factor
+                            * soil_factor
+                            * phenotype.growth_mult;
 
                         let new_stage =
                             stage_from_progress(effective_progress, &plant_stages);
@@ -1080,6 +1285,15 @@ impl System for FarmingSystem {
 
                         if let (Some(cur), Some(nxt)) = (current_idx, new_idx) {
                             if nxt > cur {
+                                if let Some((plot_e, _, monoculture)) = plot {
+                                    soil_draws.push((
+                                        *plot_e,
+                                        crop.crop_def_id.clone(),
+                                        (nxt - cur) as u32,
+                                        plant_stages.len(),
+                                        *monoculture,
+                                    ));
+                                }
                                 crop.growth_stage = new_stage.to_string();
                                 log::debug!(
                                     "Crop {} advanced to {}",
@@ -1101,6 +1315,15 @@ impl System for FarmingSystem {
                 *existing = crop;
             }
         }
+        // Each stage a soil crop advanced takes its share of N/P/K.
+        for (plot_e, plant_id, stages, n_stages, monoculture) in soil_draws {
+            let Some(def) = plant_registry.and_then(|r| r.get(&plant_id)) else {
+                continue;
+            };
+            if let Ok(mut plot) = world.get::<&mut SoilPlot>(plot_e) {
+                plot.soil.draw_for_stages(def, stages, n_stages, monoculture);
+            }
+        }
 
         self._initialized = true;
     }
@@ -1155,6 +1378,27 @@ mod gardening_tests {
             std::sync::Mutex::new(Option::<(String, String, u32)>::None),
         );
         data.insert("harvest_many_request", std::sync::Mutex::new(Vec::<u64>::new()));
+        let soils = soil::SoilRegistry::from_csv(include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/soil_types.csv"
+        )))
+        .expect("soil_types.csv");
+        let amendments = soil::AmendmentRegistry::from_csv(include_bytes!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/data/soil_amendments.csv"
+        )))
+        .expect("soil_amendments.csv");
+        data.insert("soil_registry", soils);
+        data.insert("soil_amendment_registry", amendments);
+        data.insert(
+            "amend_soil_request",
+            std::sync::Mutex::new(Option::<(String, String, u32)>::None),
+        );
+        data.insert("save_seed_request", std::sync::Mutex::new(Option::<u64>::None));
+        data.insert(
+            "name_cultivar_request",
+            std::sync::Mutex::new(Option::<(String, String)>::None),
+        );
         data
     }
 
@@ -1456,6 +1700,7 @@ mod gardening_tests {
     fn seed_and_harvest_id_mapping() {
         assert_eq!(plant_id_from_seed("seed_tomato_0").as_deref(), Some("tomato"));
         assert_eq!(plant_id_from_seed("seed_sweet_potato_0").as_deref(), Some("sweet_potato"));
+        assert_eq!(plant_id_from_seed("seed_sweet_potato_cv12").as_deref(), Some("sweet_potato"));
         assert_eq!(plant_id_from_seed("iron_ore_0"), None);
         let items = ItemRegistry::from_csv(include_bytes!(concat!(
             env!("CARGO_MANIFEST_DIR"),
@@ -1519,6 +1764,8 @@ mod gardening_tests {
             health: 40.0,
             tower_id: None,
             tower_slot: None,
+            genome: None,
+            cultivar: None,
         },));
 
         *data
@@ -1564,6 +1811,8 @@ mod gardening_tests {
             health: 80.0,
             tower_id: Some(tower.to_string()),
             tower_slot: Some(0),
+            genome: None,
+            cultivar: None,
         };
         // Irrigated crop lives in the configured "nutrition" tower.
         let irrigated = world.spawn((dry("nutrition"),));
@@ -1616,6 +1865,8 @@ mod gardening_tests {
             health: 80.0,
             tower_id: Some("nutrition".to_string()),
             tower_slot: Some(0),
+            genome: None,
+            cultivar: None,
         };
 
         // Control: a FULL cistern -> irrigation works -> the crop stays topped up.
@@ -1656,6 +1907,8 @@ mod gardening_tests {
             health: 80.0,
             tower_id: None,
             tower_slot: None,
+            genome: None,
+            cultivar: None,
         };
         let data = make_store();
         let mut sys = FarmingSystem::new();
@@ -1713,6 +1966,8 @@ mod gardening_tests {
             health: 100.0,
             tower_id: Some(tower.to_string()),
             tower_slot: Some(0),
+            genome: None,
+            cultivar: None,
         };
         let rich = world.spawn((young("nutrition"),));
         let starved = world.spawn((young("apothecary"),));
@@ -1731,4 +1986,158 @@ mod gardening_tests {
             starved_idx
         );
     }
+
+    /// Bed crops live on per-unit soil: planting creates the plot, growth
+    /// draws it down (a legume leaves nitrogen behind instead), a starved
+    /// plot grows slower, and compost from the pack builds it back up.
+    #[test]
+    fn soil_plots_feed_and_limit_bed_crops() {
+        use soil::SoilPlot;
+        let data = make_store();
+        let mut sys = FarmingSystem::new();
+        let mut world = hecs::World::new();
+        let mut inv = Inventory::new(24);
+        inv.add_item("seed_potato_0", 1, 50);
+        inv.add_item("seed_bean_0", 1, 50);
+        inv.add_item("compost_0", 1, 10);
+        let player = world.spawn((inv, Controllable));
+        let bed_request = |area: &str, plant: &str| {
+            *data
+                .get::<std::sync::Mutex<Option<(String, String, u32)>>>("plant_bed_request")
+                .unwrap()
+                .lock()
+                .unwrap() = Some((area.to_string(), plant.to_string(), 1));
+        };
+        bed_request("potato_grow_bed", "potato");
+        sys.tick(&mut world, 1.0, &data);
+        bed_request("bean_bed", "bean");
+        sys.tick(&mut world, 1.0, &data);
+        let plot = |world: &hecs::World, area: &str| {
+            world
+                .query::<&SoilPlot>()
+                .iter()
+                .find(|(_, p)| p.area_id == area)
+                .map(|(_, p)| p.clone())
+                .expect("planting a bed made its soil plot")
+        };
+        let fresh = plot(&world, "potato_grow_bed");
+        assert_eq!(fresh.soil_type, "loam");
+        assert_eq!(fresh.crop.as_deref(), Some("potato"));
+
+        // Grow both to maturity: the potato draws N/P/K, the bean fixes N.
+        {
+            let gt = data
+                .get::<std::sync::Mutex<crate::systems::time::GameTime>>("game_time")
+                .unwrap();
+            gt.lock().unwrap().elapsed_seconds = 200.0 * SECONDS_PER_DAY;
+        }
+        sys.tick(&mut world, 0.0, &data);
+        let potato = plot(&world, "potato_grow_bed");
+        let bean = plot(&world, "bean_bed");
+        assert!(potato.soil.potassium < fresh.soil.potassium, "potato drew potassium");
+        assert!(potato.soil.nitrogen < fresh.soil.nitrogen, "potato drew nitrogen");
+        assert!(bean.soil.nitrogen > fresh.soil.nitrogen, "bean fixed nitrogen");
+
+        // A starved plot slows the next crop; compost restores it, one bag per unit.
+        for (_e, p) in world.query_mut::<&mut SoilPlot>() {
+            p.soil.potassium = 0.0;
+        }
+        let plants = data.get::<PlantRegistry>("plant_registry").unwrap();
+        let starved = plot(&world, "potato_grow_bed").soil;
+        assert!(starved.growth_factor(plants.get("potato").unwrap()) < 0.5);
+        *data
+            .get::<std::sync::Mutex<Option<(String, String, u32)>>>("amend_soil_request")
+            .unwrap()
+            .lock()
+            .unwrap() = Some(("potato_grow_bed".to_string(), "compost_0".to_string(), 2));
+        sys.tick(&mut world, 0.0, &data);
+        let units: Vec<f32> = world
+            .query::<&SoilPlot>()
+            .iter()
+            .filter(|(_, p)| p.area_id == "potato_grow_bed")
+            .map(|(_, p)| p.soil.potassium)
+            .collect();
+        assert_eq!(units.len(), 1, "one bag treated one unit; the pack ran out before the second");
+        assert!(units[0] > 0.0, "compost put potassium back");
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item("compost_0"), 0);
+    }
+
+    /// Seed saving: a saved crop becomes a cultivar with its own seed item,
+    /// which sows (genome from the lot), renames, and breeds on at harvest.
+    #[test]
+    fn saved_seed_becomes_a_cultivar_that_sows_and_breeds_on() {
+        use breeding::Cultivar;
+        let mut data = make_store();
+        data.insert(
+            "genetics",
+            crate::systems::genetics::GeneticsSystem::from_ron(include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/data/genetics.ron"
+            )))
+            .expect("genetics.ron"),
+        );
+        let mut sys = FarmingSystem::new();
+        let mut world = hecs::World::new();
+        let mut inv = Inventory::new(24);
+        inv.add_item("seed_potato_0", 2, 50);
+        let player = world.spawn((inv, Controllable));
+        let bed_request = |crop: &str, count: u32| {
+            *data
+                .get::<std::sync::Mutex<Option<(String, String, u32)>>>("plant_bed_request")
+                .unwrap()
+                .lock()
+                .unwrap() = Some(("potato_grow_bed".to_string(), crop.to_string(), count));
+        };
+        let crop_in = |world: &hecs::World, unit: u32| {
+            world
+                .query::<&CropInstance>()
+                .iter()
+                .find(|(_, c)| c.tower_slot == Some(unit))
+                .map(|(e, c)| (e, c.clone()))
+        };
+        bed_request("potato", 2);
+        sys.tick(&mut world, 0.0, &data);
+        let (first, founder) = crop_in(&world, 0).expect("unit 0 sown");
+        assert!(founder.genome.is_some(), "founder stock carries a genome");
+        assert_eq!(founder.cultivar, None);
+
+        // Save seed from unit 0 (its neighbour in unit 1 is the pollen parent).
+        *data.get::<std::sync::Mutex<bool>>("dev_grow_crops").unwrap().lock().unwrap() = true;
+        sys.tick(&mut world, 0.0, &data);
+        *data.get::<std::sync::Mutex<Option<u64>>>("save_seed_request").unwrap().lock().unwrap() =
+            Some(first.to_bits().into());
+        sys.tick(&mut world, 0.0, &data);
+        let count = |world: &hecs::World, id: &str| world.get::<&Inventory>(player).unwrap().count_item(id);
+        assert_eq!(count(&world, "seed_potato_cv1"), breeding::SEEDS_PER_HARVEST);
+        assert_eq!(count(&world, "seed_potato_0"), 0, "saved seed replaces the plain seed return");
+        let lot = breeding::find(&world, "seed_potato_cv1").expect("cultivar recorded");
+        assert_eq!(lot.generation, 1);
+        assert_eq!(lot.name, "Potato selection 1");
+
+        *data
+            .get::<std::sync::Mutex<Option<(String, String)>>>("name_cultivar_request")
+            .unwrap()
+            .lock()
+            .unwrap() = Some(("seed_potato_cv1".to_string(), " Highland Red ".to_string()));
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(breeding::cultivar_names(&world)["seed_potato_cv1"], "Highland Red");
+
+        // The cultivar's seed sows the emptied unit, tagged with its lot...
+        bed_request("seed_potato_cv1", 2);
+        sys.tick(&mut world, 0.0, &data);
+        let (sown, child) = crop_in(&world, 0).expect("cultivar sown into unit 0");
+        assert_eq!(child.crop_def_id, "potato");
+        assert_eq!(child.cultivar.as_deref(), Some("seed_potato_cv1"));
+        assert!(child.genome.is_some());
+        assert_eq!(count(&world, "seed_potato_cv1"), 1);
+
+        // ...and a plain harvest returns seed of the same line.
+        *data.get::<std::sync::Mutex<bool>>("dev_grow_crops").unwrap().lock().unwrap() = true;
+        sys.tick(&mut world, 0.0, &data);
+        *data.get::<std::sync::Mutex<Option<u64>>>("harvest_request").unwrap().lock().unwrap() =
+            Some(sown.to_bits().into());
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(count(&world, "seed_potato_cv1"), 1 + breeding::SEEDS_PER_HARVEST);
+        assert_eq!(world.query::<&Cultivar>().iter().count(), 1);
+    }
 }
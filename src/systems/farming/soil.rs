@@ -1,11 +1,41 @@
 //! Soil chemistry — pH, NPK, water content.
 //!
-//! Soil type defaults loaded from `data/soil_types.csv`.
+//! Soil type defaults loaded from `data/soil_types.csv`. Every bed / tray /
+//! field unit carries its own `SoilPlot`: crops draw N/P/K from it as they
+//! move through their growth stages (legumes fix nitrogen instead), a pH
+//! outside the plant's band slows growth, and the items in
+//! `data/soil_amendments.csv` (compost, fertilizer, lime, ...) restore it.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::PlantDef;
+
+/// Soil units drawn per unit of plants.csv nutrient demand over a full crop.
+const DRAW_PER_CROP: f32 = 1.5;
+
+/// A nutrient is fully sufficient at this multiple of the plant's demand.
+const SUFFICIENT_MULTIPLE: f32 = 2.0;
+
+/// Growth never drops below this fraction from a starved nutrient.
+const NUTRIENT_FLOOR: f32 = 0.2;
+
+/// Growth lost per pH unit outside the plant's band, and its floor.
+const PH_PENALTY_PER_UNIT: f32 = 0.25;
+const PH_FLOOR: f32 = 0.25;
+
+/// Nitrogen a legume fixes into the plot over a full crop.
+const LEGUME_FIXATION: f32 = 0.15;
+
+/// Draw multiplier when a plot grows the same species twice running.
+const MONOCULTURE_DRAW: f32 = 1.5;
+
+/// Fraction of organic matter that mineralizes into nitrogen over a full crop.
+const MINERALIZATION: f32 = 0.1;
+
 /// Soil state for a farming plot.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Soil {
     pub ph: f32,
     pub nitrogen: f32,
@@ -27,3 +57,357 @@ impl Default for Soil {
         }
     }
 }
+
+impl Soil {
+    /// Growth multiplier (0.05..1.0) this soil gives `plant`: the scarcest
+    /// nutrient relative to the plant's demand (Liebig's law of the minimum)
+    /// times the pH penalty.
+    pub fn growth_factor(&self, plant: &PlantDef) -> f32 {
+        let sufficiency = |have: f32, demand: f32| {
+            if demand <= 0.0 {
+                1.0
+            } else {
+                (have / (demand * SUFFICIENT_MULTIPLE)).clamp(NUTRIENT_FLOOR, 1.0)
+            }
+        };
+        let nitrogen = if plant.is_legume() { 1.0 } else { sufficiency(self.nitrogen, plant.nutrient_n) };
+        let nutrients = nitrogen
+            .min(sufficiency(self.phosphorus, plant.nutrient_p))
+            .min(sufficiency(self.potassium, plant.nutrient_k));
+        nutrients * self.ph_factor(plant)
+    }
+
+    /// 1.0 inside the plant's pH band, falling off per unit outside it. A
+    /// plant without a band (both ends 0) is indifferent.
+    pub fn ph_factor(&self, plant: &PlantDef) -> f32 {
+        if plant.ph_min <= 0.0 && plant.ph_max <= 0.0 {
+            return 1.0;
+        }
+        let off = if self.ph < plant.ph_min {
+            plant.ph_min - self.ph
+        } else if self.ph > plant.ph_max {
+            self.ph - plant.ph_max
+        } else {
+            0.0
+        };
+        (1.0 - off * PH_PENALTY_PER_UNIT).clamp(PH_FLOOR, 1.0)
+    }
+
+    /// Take what `plant` uses across `stages` of its `n_stages` stage list.
+    /// A full crop (first stage to last) draws `DRAW_PER_CROP` x its demand,
+    /// more when it repeats the plot's previous crop; a legume fixes nitrogen
+    /// instead of drawing it. Organic matter mineralizes a little as it goes.
+    pub fn draw_for_stages(&mut self, plant: &PlantDef, stages: u32, n_stages: usize, monoculture: bool) {
+        if stages == 0 {
+            return;
+        }
+        let fraction = stages as f32 / n_stages.saturating_sub(1).max(1) as f32;
+        let draw = DRAW_PER_CROP * fraction * if monoculture { MONOCULTURE_DRAW } else { 1.0 };
+        let released = self.organic_matter * MINERALIZATION * fraction;
+        self.organic_matter -= released;
+        self.nitrogen += released;
+        if plant.is_legume() {
+            self.nitrogen += LEGUME_FIXATION * fraction;
+        } else {
+            self.nitrogen -= plant.nutrient_n * draw;
+        }
+        self.phosphorus -= plant.nutrient_p * draw;
+        self.potassium -= plant.nutrient_k * draw;
+        self.clamp();
+    }
+
+    /// Work one dose of an amendment into the soil.
+    pub fn amend(&mut self, amendment: &Amendment) {
+        self.nitrogen += amendment.nitrogen;
+        self.phosphorus += amendment.phosphorus;
+        self.potassium += amendment.potassium;
+        self.organic_matter += amendment.organic_matter;
+        self.ph += amendment.ph_shift;
+        self.clamp();
+    }
+
+    fn clamp(&mut self) {
+        self.nitrogen = self.nitrogen.clamp(0.0, 1.0);
+        self.phosphorus = self.phosphorus.clamp(0.0, 1.0);
+        self.potassium = self.potassium.clamp(0.0, 1.0);
+        self.organic_matter = self.organic_matter.clamp(0.0, 1.0);
+        self.ph = self.ph.clamp(3.0, 10.0);
+    }
+}
+
+/// One unit of a soil grow area (bed / tray / field) and its chemistry.
+/// Keyed like the crops on it: the grow-area machine id plus the unit index
+/// (`CropInstance.tower_id` / `tower_slot`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoilPlot {
+    pub area_id: String,
+    pub unit: u32,
+    /// `soil_types.csv` id the plot started from.
+    pub soil_type: String,
+    pub soil: Soil,
+    /// Species growing (or last grown) here, and the one before it. Crop
+    /// rotation reads these: repeating a species draws the soil harder.
+    #[serde(default)]
+    pub crop: Option<String>,
+    #[serde(default)]
+    pub previous_crop: Option<String>,
+}
+
+impl SoilPlot {
+    /// Record a new planting; the old crop becomes the rotation history.
+    pub fn sow(&mut self, plant_id: &str) {
+        self.previous_crop = self.crop.replace(plant_id.to_string());
+    }
+
+    /// Is the plot growing the same species it grew last time?
+    pub fn is_monoculture(&self) -> bool {
+        self.crop.is_some() && self.crop == self.previous_crop
+    }
+}
+
+/// A soil type row from `soil_types.csv`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SoilType {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub ph: f32,
+    #[serde(default)]
+    pub nitrogen: f32,
+    #[serde(default)]
+    pub phosphorus: f32,
+    #[serde(default)]
+    pub potassium: f32,
+    #[serde(default)]
+    pub water_content: f32,
+    #[serde(default)]
+    pub organic_matter: f32,
+    /// Colon-separated machine ids / id suffixes this soil fills.
+    #[serde(default)]
+    pub grow_areas: String,
+}
+
+impl SoilType {
+    /// Fresh soil of this type.
+    pub fn soil(&self) -> Soil {
+        Soil {
+            ph: self.ph,
+            nitrogen: self.nitrogen,
+            phosphorus: self.phosphorus,
+            potassium: self.potassium,
+            water_content: self.water_content,
+            organic_matter: self.organic_matter,
+        }
+    }
+
+    fn fills(&self, area_id: &str) -> bool {
+        self.grow_areas
+            .split(':')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .any(|p| area_id == p || (p.starts_with('_') && area_id.ends_with(p)))
+    }
+}
+
+/// Soil types in file order, cached in DataStore as "soil_registry".
+#[derive(Debug, Clone, Default)]
+pub struct SoilRegistry {
+    pub types: Vec<SoilType>,
+}
+
+impl SoilRegistry {
+    /// Build the registry from raw `soil_types.csv` bytes.
+    pub fn from_csv(data: &[u8]) -> Result<Self, String> {
+        let types: Vec<SoilType> = crate::assets::loader::parse_csv(data)?;
+        Ok(Self { types })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&SoilType> {
+        self.types.iter().find(|t| t.id == id)
+    }
+
+    /// The soil a grow area is filled with (first matching row), or None for
+    /// areas that are not soil-grown (aeroponic towers, racks, tanks).
+    pub fn for_area(&self, area_id: &str) -> Option<&SoilType> {
+        self.types.iter().find(|t| t.fills(area_id))
+    }
+}
+
+/// A soil amendment row from `soil_amendments.csv` (keyed by item id).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Amendment {
+    pub id: String,
+    #[serde(default)]
+    pub nitrogen: f32,
+    #[serde(default)]
+    pub phosphorus: f32,
+    #[serde(default)]
+    pub potassium: f32,
+    #[serde(default)]
+    pub organic_matter: f32,
+    #[serde(default)]
+    pub ph_shift: f32,
+}
+
+/// Amendments by item id, cached in DataStore as "soil_amendment_registry".
+#[derive(Debug, Clone, Default)]
+pub struct AmendmentRegistry {
+    pub amendments: HashMap<String, Amendment>,
+}
+
+impl AmendmentRegistry {
+    /// Build the registry from raw `soil_amendments.csv` bytes.
+    pub fn from_csv(data: &[u8]) -> Result<Self, String> {
+        let rows: Vec<Amendment> = crate::assets::loader::parse_csv(data)?;
+        Ok(Self { amendments: rows.into_iter().map(|a| (a.id.clone(), a)).collect() })
+    }
+
+    pub fn get(&self, item_id: &str) -> Option<&Amendment> {
+        self.amendments.get(item_id)
+    }
+}
+
+/// The plot entity for one grow-area unit, spawned from the area's soil type
+/// on first use. None when the area is not soil-grown.
+pub fn plot_for(
+    world: &mut hecs::World,
+    soils: &SoilRegistry,
+    area_id: &str,
+    unit: u32,
+) -> Option<hecs::Entity> {
+    let existing = world
+        .query::<&SoilPlot>()
+        .iter()
+        .find(|(_e, p)| p.area_id == area_id && p.unit == unit)
+        .map(|(e, _)| e);
+    if existing.is_some() {
+        return existing;
+    }
+    let soil_type = soils.for_area(area_id)?;
+    Some(world.spawn((SoilPlot {
+        area_id: area_id.to_string(),
+        unit,
+        soil_type: soil_type.id.clone(),
+        soil: soil_type.soil(),
+        crop: None,
+        previous_crop: None,
+    },)))
+}
+
+/// Mean soil per grow area, for the garden edit modal's readout.
+pub fn area_averages(world: &hecs::World) -> HashMap<String, Soil> {
+    let mut sums: HashMap<String, (Soil, u32)> = HashMap::new();
+    for (_e, plot) in world.query::<&SoilPlot>().iter() {
+        let (sum, n) = sums.entry(plot.area_id.clone()).or_insert_with(|| {
+            (Soil { ph: 0.0, nitrogen: 0.0, phosphorus: 0.0, potassium: 0.0, water_content: 0.0, organic_matter: 0.0 }, 0)
+        });
+        sum.ph += plot.soil.ph;
+        sum.nitrogen += plot.soil.nitrogen;
+        sum.phosphorus += plot.soil.phosphorus;
+        sum.potassium += plot.soil.potassium;
+        sum.water_content += plot.soil.water_content;
+        sum.organic_matter += plot.soil.organic_matter;
+        *n += 1;
+    }
+    sums.into_iter()
+        .map(|(area, (s, n))| {
+            let n = n as f32;
+            let mean = Soil {
+                ph: s.ph / n,
+                nitrogen: s.nitrogen / n,
+                phosphorus: s.phosphorus / n,
+                potassium: s.potassium / n,
+                water_content: s.water_content / n,
+                organic_matter: s.organic_matter / n,
+            };
+            (area, mean)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::farming::PlantRegistry;
+
+    fn plants() -> PlantRegistry {
+        let csv = b"id,name,type,growth_days,nutrient_n,nutrient_p,nutrient_k,ph_min,ph_max,growth_stages\n\
+                    tomato,Tomato,fruit,70,0.15,0.05,0.20,6.0,6.8,seed:sprout:flower:ripe\n\
+                    bean,Bean,legume,60,0.02,0.04,0.08,6.0,7.0,seed:sprout:flower:mature\n";
+        PlantRegistry::from_csv(csv).expect("parse")
+    }
+
+    #[test]
+    fn shipped_soil_data_parses_and_covers_every_soil_grow_area() {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let types = std::fs::read(dir.join("soil_types.csv")).expect("soil_types.csv reads");
+        let soils = SoilRegistry::from_csv(&types).expect("soil_types.csv parses");
+        assert_eq!(soils.for_area("potato_grow_bed").map(|t| t.id.as_str()), Some("loam"));
+        assert_eq!(soils.for_area("staple_grain_tray").map(|t| t.id.as_str()), Some("potting_mix"));
+        assert_eq!(soils.for_area("grain_field").map(|t| t.id.as_str()), Some("silt"));
+        assert!(soils.for_area("nutrition").is_none(), "aeroponic towers are soil-less");
+
+        let amend = std::fs::read(dir.join("soil_amendments.csv")).expect("soil_amendments.csv reads");
+        let amendments = AmendmentRegistry::from_csv(&amend).expect("soil_amendments.csv parses");
+        for id in ["fertilizer_0", "compost_0", "garden_lime_0"] {
+            assert!(amendments.get(id).is_some(), "{id} restores soil");
+        }
+    }
+
+    #[test]
+    fn a_full_crop_draws_its_demand_and_a_legume_fixes_nitrogen() {
+        let reg = plants();
+        let tomato = reg.get("tomato").unwrap();
+        let mut soil = Soil { organic_matter: 0.0, ..Soil::default() };
+        soil.draw_for_stages(tomato, 3, 4, false);
+        assert!((soil.nitrogen - (0.5 - 0.15 * DRAW_PER_CROP)).abs() < 1e-5);
+        assert!((soil.potassium - (0.5 - 0.20 * DRAW_PER_CROP)).abs() < 1e-5);
+
+        // Repeating the species draws harder than rotating.
+        let mut repeat = Soil { organic_matter: 0.0, ..Soil::default() };
+        repeat.draw_for_stages(tomato, 3, 4, true);
+        assert!(repeat.potassium < soil.potassium);
+
+        let bean = reg.get("bean").unwrap();
+        let mut soil = Soil { organic_matter: 0.0, ..Soil::default() };
+        soil.draw_for_stages(bean, 3, 4, false);
+        assert!((soil.nitrogen - (0.5 + LEGUME_FIXATION)).abs() < 1e-5, "beans leave nitrogen behind");
+        assert!(soil.phosphorus < 0.5);
+    }
+
+    #[test]
+    fn starved_or_off_ph_soil_slows_growth_and_amendments_fix_it() {
+        let reg = plants();
+        let tomato = reg.get("tomato").unwrap();
+        assert_eq!(Soil::default().growth_factor(tomato), 1.0);
+
+        let mut starved = Soil { potassium: 0.1, ..Soil::default() };
+        assert!((starved.growth_factor(tomato) - 0.25).abs() < 1e-5, "K is the limiting nutrient");
+        let ash = Amendment { id: "wood_ash_0".into(), nitrogen: 0.0, phosphorus: 0.0, potassium: 0.35, organic_matter: 0.0, ph_shift: 0.0 };
+        starved.amend(&ash);
+        assert_eq!(starved.growth_factor(tomato), 1.0);
+
+        let acid = Soil { ph: 4.8, ..Soil::default() };
+        assert!((acid.ph_factor(tomato) - 0.7).abs() < 1e-5);
+        let alkaline = Soil { ph: 12.0, ..Soil::default() };
+        assert_eq!(alkaline.ph_factor(tomato), PH_FLOOR);
+    }
+
+    #[test]
+    fn plots_spawn_once_per_unit_and_track_rotation() {
+        let soils = SoilRegistry::from_csv(b"id,name,ph,nitrogen,grow_areas\nloam,Loam,6.5,0.5,_bed\n").unwrap();
+        let mut world = hecs::World::new();
+        let a = plot_for(&mut world, &soils, "potato_grow_bed", 0).expect("beds have soil");
+        assert_eq!(plot_for(&mut world, &soils, "potato_grow_bed", 0), Some(a));
+        assert_ne!(plot_for(&mut world, &soils, "potato_grow_bed", 1), Some(a));
+        assert!(plot_for(&mut world, &soils, "nutrition", 0).is_none());
+
+        let mut plot = world.get::<&mut SoilPlot>(a).unwrap();
+        plot.sow("bean");
+        assert!(!plot.is_monoculture());
+        plot.sow("potato");
+        assert!(!plot.is_monoculture());
+        plot.sow("potato");
+        assert!(plot.is_monoculture());
+    }
+}
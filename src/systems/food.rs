@@ -116,6 +116,10 @@ const BODY_TEMP_RATE: f32 = 0.5;
 const HYPOTHERMIA_C: f32 = 35.0;
 const HEAT_EXHAUSTION_C: f32 = 39.0;
 const TEMP_DAMAGE_PER_SEC: f32 = 2.0;
+/// Indoors (sealed) the body holds 37 °C while the room sits in this band; each °C outside
+/// it shifts the body's target by INDOOR_TEMP_PULL (a furnace-less room at 5 °C chills you).
+const INDOOR_COMFORT_C: (f32, f32) = (18.0, 26.0);
+const INDOOR_TEMP_PULL: f32 = 0.2;
 // ── Sanitation (organic waste → compost → fertilizer). ──
 /// Waste accrued per real second while living, + per meal eaten (real
 /// scale, v0.1005: background rise fills over ~3 days; meals dominate).
@@ -187,6 +191,13 @@ impl FoodSystem {
         }
     }
 
+    /// Seconds a fresh stack of `item_id` keeps before spoiling; None for
+    /// non-food. The offline catch-up uses it to report what went off.
+    pub fn freshness_seconds(item_id: &str) -> Option<f32> {
+        Self::is_food(item_id)
+            .then(|| Self::base_freshness(item_id) * Self::preservation_multiplier(item_id))
+    }
+
     /// Check whether an item_id represents food (vs tools, ores, etc.).
     fn is_food(item_id: &str) -> bool {
         let food_prefixes = [
@@ -534,9 +545,16 @@ impl System for FoodSystem {
                 effects.remove("suffocation");
             }
 
-            // Body temperature drifts toward 37 °C when sealed, toward ambient when
-            // exposed; far from baseline -> hypothermia / heat exhaustion + damage.
-            let temp_target = if env_sealed { 37.0 } else { env_ambient_c };
+            // Body temperature drifts toward 37 °C when sealed (pulled off it by a room
+            // outside the comfort band), toward ambient when exposed; far from baseline
+            // -> hypothermia / heat exhaustion + damage.
+            let temp_target = if env_sealed {
+                let (lo, hi) = INDOOR_COMFORT_C;
+                37.0 - (lo - env_ambient_c).max(0.0) * INDOOR_TEMP_PULL
+                    + (env_ambient_c - hi).max(0.0) * INDOOR_TEMP_PULL
+            } else {
+                env_ambient_c
+            };
             let diff = temp_target - vitals.body_temp_c;
             let step = (BODY_TEMP_RATE * dt).min(diff.abs());
             vitals.body_temp_c += step * diff.signum();
@@ -635,6 +653,13 @@ impl System for FoodSystem {
         }
         self.log_cooldown -= dt;
 
+        // Time the app was closed (published once by the offline catch-up):
+        // every stack in an inventory has aged that long, on top of this tick.
+        let backlog = data
+            .get::<std::sync::Mutex<f32>>("spoilage_catch_up")
+            .and_then(|m| m.lock().ok().map(|mut b| std::mem::take(&mut *b)))
+            .unwrap_or(0.0);
+
         // Track which food keys are still alive this tick (for cleanup)
         let mut active_keys = std::collections::HashSet::new();
 
@@ -676,7 +701,7 @@ impl System for FoodSystem {
                 }
 
                 // Advance spoilage timer
-                state.spoilage_timer += dt;
+                state.spoilage_timer += dt + backlog;
 
                 if state.spoilage_timer >= state.max_freshness {
                     state.spoiled = true;
@@ -1009,6 +1034,28 @@ mod nutrition_tests {
     /// from (last-to-first, see `Inventory::remove_item`), not just the
     /// first matching slot found. This is the exact bug an adversarial
     /// review caught in the initial BUG-044 fix.
+    /// Time the app was closed ages every stack once: enough of it spoils
+    /// food on the first tick, and the backlog is not applied twice.
+    #[test]
+    fn offline_backlog_ages_food_once() {
+        let mut sys = FoodSystem::new(data_dir());
+        let mut data = make_store();
+        let fresh_for = FoodSystem::freshness_seconds("cooked_meat_0").expect("cooked meat is food");
+        assert_eq!(FoodSystem::freshness_seconds("iron_ore_0"), None);
+        data.insert("spoilage_catch_up", std::sync::Mutex::new(fresh_for + 1.0));
+
+        let mut world = hecs::World::new();
+        let mut inv = Inventory::new(8);
+        inv.add_item("cooked_meat_0", 1, 99);
+        let player = world.spawn((inv, vitals(80.0, 80.0), StatusEffects::default(), Health::default()));
+        sys.tick(&mut world, 0.0, &data);
+
+        let entity_bits: u64 = player.to_bits().into();
+        assert!(sys.spoilage.get(&(entity_bits, 0)).is_some_and(|s| s.spoiled));
+        let left = *data.get::<std::sync::Mutex<f32>>("spoilage_catch_up").unwrap().lock().unwrap();
+        assert_eq!(left, 0.0, "the backlog is consumed by the first tick");
+    }
+
     #[test]
     fn spoilage_check_matches_the_slot_remove_item_actually_consumes() {
         let mut sys = FoodSystem::new(data_dir());
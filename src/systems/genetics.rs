@@ -5,6 +5,12 @@
 //! Each trait pair contributes one allele (50/50 random) to the child;
 //! a small mutation chance flips each child allele to a random one drawn
 //! from the trait's defined allele pool in `data/genetics.ron`.
+//!
+//! Numeric traits (`range_min` / `range_max` / `base_value`) carry their
+//! allele values as decimal strings; a mutation nudges the value within the
+//! range instead of drawing from a pool, and `express` turns the pair into a
+//! phenotype by the trait's `inheritance_pattern`. Loaded into the DataStore
+//! as "genetics" (crop seed saving, `systems::farming::breeding`).
 
 use std::collections::HashMap;
 use std::path::Path;
@@ -18,6 +24,12 @@ use crate::hot_reload::data_store::DataStore;
 
 /// Default mutation chance per allele if not specified per-trait. Tunable.
 const DEFAULT_MUTATION_RATE: f32 = 0.001;
+/// A numeric mutation moves the allele by up to this fraction of the
+/// trait's range, either way.
+const MUTATION_STEP: f32 = 0.05;
+/// Founder (landrace) alleles scatter up to this fraction of the trait's
+/// range around `base_value`, so a first planting has variation to select on.
+const FOUNDER_SPREAD: f32 = 0.1;
 
 /// Top-level RON schema for `data/genetics.ron`.
 /// (Real schema uses `plant_traits` / `animal_traits` rather than a single
@@ -31,12 +43,37 @@ pub struct GeneticsData {
     #[serde(default)] pub breeding_methods: Vec<ron::Value>,
 }
 
+/// The inheritance fields of one trait row. Numeric traits have a range;
+/// categorical ones an allele pool instead (see `allele_pools`).
+#[derive(Debug, Clone)]
+pub struct TraitSpec {
+    pub id: String,
+    pub applies_to: String,
+    /// polygenic / codominant (mean), dominant (higher), recessive (lower).
+    pub inheritance_pattern: String,
+    pub mutation_chance: Option<f32>,
+    pub range_min: Option<f32>,
+    pub range_max: Option<f32>,
+    pub base_value: Option<f32>,
+}
+
+impl TraitSpec {
+    /// `(min, max, base)` for a numeric trait.
+    fn numeric(&self) -> Option<(f32, f32, f32)> {
+        let (lo, hi) = (self.range_min?, self.range_max?);
+        Some((lo, hi, self.base_value.unwrap_or((lo + hi) / 2.0).clamp(lo, hi)))
+    }
+}
+
 /// Manages breeding, trait inheritance, mutations, and genetic diseases.
 pub struct GeneticsSystem {
     pub data: GeneticsData,
     /// Mapping `trait_id -> Vec<allele_name>` extracted from the data file
     /// for use in mutation rolls. Empty traits fall back to copying parents.
+    /// Pool order is dominance order: the earlier allele expresses.
     allele_pools: HashMap<String, Vec<String>>,
+    /// Every trait row by id (plant and animal).
+    specs: HashMap<String, TraitSpec>,
 }
 
 impl GeneticsSystem {
@@ -46,23 +83,118 @@ impl GeneticsSystem {
             log::warn!("Failed to read {}: {e}", path.display());
             "(plant_traits:[],animal_traits:[],mutations:[],diseases:[],breeding_methods:[])".to_string()
         });
-        let data: GeneticsData = ron::from_str(&text).unwrap_or_else(|e| {
+        Self::from_ron(&text).unwrap_or_else(|e| {
             log::warn!("Failed to parse genetics.ron: {e}");
-            GeneticsData::default()
-        });
+            Self::from_data(GeneticsData::default())
+        })
+    }
+
+    /// Parse `genetics.ron` text (the registry loader's entry point).
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        let data: GeneticsData =
+            ron::from_str(text).map_err(|e| format!("genetics.ron: {e}"))?;
         log::info!(
             "Loaded genetics data: {} plant traits, {} animal traits",
             data.plant_traits.len(), data.animal_traits.len()
         );
+        Ok(Self::from_data(data))
+    }
 
+    fn from_data(data: GeneticsData) -> Self {
         let mut allele_pools: HashMap<String, Vec<String>> = HashMap::new();
+        let mut specs: HashMap<String, TraitSpec> = HashMap::new();
         for v in data.plant_traits.iter().chain(data.animal_traits.iter()) {
             if let Some((id, alleles)) = Self::parse_trait_alleles(v) {
                 allele_pools.insert(id, alleles);
             }
+            if let Some(spec) = Self::parse_trait_spec(v) {
+                specs.insert(spec.id.clone(), spec);
+            }
         }
 
-        Self { data, allele_pools }
+        Self { data, allele_pools, specs }
+    }
+
+    /// A founder genome for an organism kind ("plant" / "animal"): every
+    /// trait that applies, numeric alleles scattered around the base value,
+    /// categorical ones drawn from the pool. Landrace stock, no pedigree.
+    pub fn founder<R: Rng + ?Sized>(&self, applies_to: &str, rng: &mut R) -> Genome {
+        let mut alleles = HashMap::new();
+        for spec in self.specs.values().filter(|s| s.applies_to == applies_to) {
+            let mut draw = || {
+                if let Some((lo, hi, base)) = spec.numeric() {
+                    let spread = (hi - lo) * FOUNDER_SPREAD;
+                    Some(format_allele((base + rng.random_range(-spread..=spread)).clamp(lo, hi)))
+                } else {
+                    let pool = self.allele_pools.get(&spec.id).filter(|p| !p.is_empty())?;
+                    Some(pool[rng.random_range(0..pool.len())].clone())
+                }
+            };
+            if let (Some(a), Some(b)) = (draw(), draw()) {
+                alleles.insert(spec.id.clone(), (a, b));
+            }
+        }
+        Genome { alleles }
+    }
+
+    /// The expressed value of a numeric trait, by its inheritance pattern.
+    /// None when the trait is not numeric or the genome lacks it.
+    pub fn express(&self, genome: &Genome, trait_id: &str) -> Option<f32> {
+        let spec = self.specs.get(trait_id)?;
+        let (lo, hi, _) = spec.numeric()?;
+        let (a, b) = genome.alleles.get(trait_id)?;
+        let (a, b) = (a.parse::<f32>().ok()?, b.parse::<f32>().ok()?);
+        let v = match spec.inheritance_pattern.as_str() {
+            "dominant" => a.max(b),
+            "recessive" => a.min(b),
+            _ => (a + b) / 2.0,
+        };
+        Some(v.clamp(lo, hi))
+    }
+
+    /// A numeric trait's expression relative to its base value (1.0 = the
+    /// species norm). 1.0 when the trait or allele is missing.
+    pub fn relative(&self, genome: &Genome, trait_id: &str) -> f32 {
+        let base = self.specs.get(trait_id).and_then(|s| s.numeric()).map(|n| n.2);
+        match (self.express(genome, trait_id), base) {
+            (Some(v), Some(base)) if base > 0.0 => v / base,
+            _ => 1.0,
+        }
+    }
+
+    /// The expressed allele of a categorical trait: whichever of the pair
+    /// comes first in the trait's pool (dominance order).
+    pub fn express_allele<'a>(&self, genome: &'a Genome, trait_id: &str) -> Option<&'a str> {
+        let (a, b) = genome.alleles.get(trait_id)?;
+        let rank = |x: &str| {
+            self.allele_pools
+                .get(trait_id)
+                .and_then(|p| p.iter().position(|y| y == x))
+                .unwrap_or(usize::MAX)
+        };
+        Some(if rank(b) < rank(a) { b.as_str() } else { a.as_str() })
+    }
+
+    /// The trait row for an id.
+    pub fn spec(&self, trait_id: &str) -> Option<&TraitSpec> {
+        self.specs.get(trait_id)
+    }
+
+    /// Read the inheritance fields of a trait definition (field by field: a
+    /// bare RON number does not deserialize into an `Option`).
+    fn parse_trait_spec(v: &ron::Value) -> Option<TraitSpec> {
+        let map = v.clone().into_rust::<HashMap<String, ron::Value>>().ok()?;
+        let text = |k: &str| map.get(k).and_then(|x| x.clone().into_rust::<String>().ok());
+        let number = |k: &str| map.get(k).and_then(|x| x.clone().into_rust::<f32>().ok());
+        Some(TraitSpec {
+            id: text("id")?,
+            applies_to: text("applies_to").unwrap_or_default(),
+            inheritance_pattern: text("inheritance_pattern").unwrap_or_default(),
+            mutation_chance: number("mutation_chance"),
+            range_min: number("range_min"),
+            range_max: number("range_max"),
+            base_value: number("base_value"),
+        })
     }
 
     /// Try to extract `(trait_id, [allele_name...])` from a trait definition.
@@ -104,9 +236,10 @@ impl GeneticsSystem {
     }
 
     /// Combine two parent genomes into a child. Each trait pair in the union
-    /// of parent traits contributes one allele picked at random; a small
-    /// `DEFAULT_MUTATION_RATE` chance per allele swaps it for a random one
-    /// from the trait's allele pool (if known).
+    /// of parent traits contributes one allele picked at random; the trait's
+    /// `mutation_chance` (else `DEFAULT_MUTATION_RATE`) per allele swaps it
+    /// for a random one from the trait's allele pool, or nudges a numeric
+    /// allele within its range.
     pub fn breed<R: Rng + ?Sized>(
         &self,
         parent_a: &Genome,
@@ -124,18 +257,17 @@ impl GeneticsSystem {
 
             // Pick one allele from each parent (random within the parent's pair).
             let from_a = a_pair
-                .map(|(a1, a2)| if rng.gen_bool(0.5) { a1.clone() } else { a2.clone() })
+                .map(|(a1, a2)| if rng.random_bool(0.5) { a1.clone() } else { a2.clone() })
                 .or_else(|| b_pair.map(|(b1, _)| b1.clone()))
                 .unwrap_or_default();
             let from_b = b_pair
-                .map(|(b1, b2)| if rng.gen_bool(0.5) { b1.clone() } else { b2.clone() })
+                .map(|(b1, b2)| if rng.random_bool(0.5) { b1.clone() } else { b2.clone() })
                 .or_else(|| a_pair.map(|(a1, _)| a1.clone()))
                 .unwrap_or_default();
 
             // Apply mutations.
-            let pool = self.allele_pools.get(trait_id);
-            let mutated_a = self.maybe_mutate(from_a, pool, rng);
-            let mutated_b = self.maybe_mutate(from_b, pool, rng);
+            let mutated_a = self.maybe_mutate(trait_id, from_a, rng);
+            let mutated_b = self.maybe_mutate(trait_id, from_b, rng);
             alleles.insert(trait_id.clone(), (mutated_a, mutated_b));
         }
 
@@ -144,14 +276,22 @@ impl GeneticsSystem {
 
     fn maybe_mutate<R: Rng + ?Sized>(
         &self,
+        trait_id: &str,
         allele: String,
-        pool: Option<&Vec<String>>,
         rng: &mut R,
     ) -> String {
-        if rng.gen::<f32>() >= DEFAULT_MUTATION_RATE { return allele; }
-        match pool {
+        let spec = self.specs.get(trait_id);
+        let rate = spec.and_then(|s| s.mutation_chance).unwrap_or(DEFAULT_MUTATION_RATE);
+        if rng.random::<f32>() >= rate { return allele; }
+        if let Some((lo, hi, _)) = spec.and_then(|s| s.numeric()) {
+            if let Ok(v) = allele.parse::<f32>() {
+                let step = (hi - lo) * MUTATION_STEP;
+                return format_allele((v + rng.random_range(-step..=step)).clamp(lo, hi));
+            }
+        }
+        match self.allele_pools.get(trait_id) {
             Some(p) if !p.is_empty() => {
-                let idx = rng.gen_range(0..p.len());
+                let idx = rng.random_range(0..p.len());
                 p[idx].clone()
             }
             _ => allele,
@@ -159,6 +299,12 @@ impl GeneticsSystem {
     }
 }
 
+/// Numeric alleles are stored as strings in `Genome`; three decimals is
+/// finer than any trait range needs.
+fn format_allele(v: f32) -> String {
+    format!("{v:.3}")
+}
+
 impl System for GeneticsSystem {
     fn name(&self) -> &str { "GeneticsSystem" }
 
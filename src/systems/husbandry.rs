@@ -0,0 +1,722 @@
+//! Livestock husbandry — the active half of `systems::livestock`.
+//!
+//! A herd animal is a `Creature` carrying `Husbandry` and `Age`. Hunger rises
+//! by the game day; grazers on pasture hold it at `pasture_hunger` on their
+//! own, anything past that takes hand feeding from the pack (diet → feed items
+//! in `data/entities/husbandry.ron`). Starving drains the ordinary Health
+//! component to death; being fed heals it back. Mood eases toward how fed and
+//! healthy the animal is.
+//!
+//! Breeding: an adult dam and sire of the same species, both fed and content,
+//! within `breed_radius` of each other produce one young next to the dam. Its
+//! genome is `GeneticsSystem::breed` of the pair, so the animal traits of
+//! genetics.ron carry down the line: fertility shortens the dam's recovery,
+//! the production traits speed the renewable yield, speed the amble, size the
+//! slaughter yield, temperament the taming odds. Life stages come from
+//! `AgingSystem`'s animal ladder.
+//!
+//! Player verbs arrive on the `husbandry_request` channel: Tend feeds a herd
+//! animal, or tames a wild domesticable creature (creatures.csv) into the herd
+//! with the same feed; Slaughter rolls the species' creatures.csv loot table
+//! into `loot_drops`. Outcomes go back on `husbandry_status`.
+
+use glam::Vec3;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::ecs::components::{
+    AIBehavior, Age, Controllable, Creature, Dead, Genome, Harvestable, Health, Husbandry, Name,
+    Transform,
+};
+use crate::hot_reload::data_store::DataStore;
+use crate::systems::genetics::GeneticsSystem;
+use crate::systems::inventory::{Inventory, ItemRegistry};
+use crate::systems::livestock::{CreatureDef, CreatureRegistry};
+use crate::systems::time::SECONDS_PER_DAY;
+
+/// `genetics.ron` animal traits the herd expresses.
+pub const SIZE: &str = "animal_size";
+pub const SPEED: &str = "animal_speed";
+pub const TEMPERAMENT: &str = "animal_temperament";
+pub const FERTILITY: &str = "animal_fertility";
+
+/// Life stage (AgingSystem's animal ladder) that breeds.
+pub const BREEDING_STAGE: &str = "adult";
+
+/// One husbandry.ron feed row: what an animal of a creatures.csv diet eats.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeedRow {
+    pub diet: String,
+    /// Hunger one feeding takes off.
+    pub nutrition: f32,
+    /// items.csv ids, taken from the pack in this order.
+    pub items: Vec<String>,
+}
+
+/// Husbandry tuning, rates per game day. DataStore: `"husbandry_config"`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HusbandryConfig {
+    pub hunger_per_day: f32,
+    /// Hunger a grazer on pasture never rises above.
+    pub pasture_hunger: f32,
+    /// Diets that graze.
+    pub grazing_diets: Vec<String>,
+    /// Fraction of max health lost per day while starving.
+    pub starve_damage_per_day: f32,
+    /// Fraction of max health regained per day while well fed.
+    pub heal_per_day: f32,
+    /// How fast mood closes on its target (1/day).
+    pub mood_per_day: f32,
+    /// Mood one feeding adds.
+    pub feed_mood: f32,
+    pub breed_max_hunger: f32,
+    pub breed_min_mood: f32,
+    pub breed_radius: f32,
+    /// Dam recovery between young at fertility 1.0.
+    pub breed_cooldown_days: f32,
+    /// Hunger a birth costs the dam.
+    pub birth_hunger: f32,
+    /// Taming odds per feeding at temperament 0.5.
+    pub tame_chance: f32,
+    pub feed: Vec<FeedRow>,
+}
+
+impl HusbandryConfig {
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, String> {
+        let text = std::str::from_utf8(bytes).map_err(|e| e.to_string())?;
+        let cfg: Self = ron::from_str(text).map_err(|e| e.to_string())?;
+        if let Some(row) = cfg.feed.iter().find(|f| f.items.is_empty()) {
+            return Err(format!("feed row for diet {} lists no items", row.diet));
+        }
+        Ok(cfg)
+    }
+
+    /// The feed row for a diet, if the animal eats anything we stock.
+    pub fn feed_for(&self, diet: &str) -> Option<&FeedRow> {
+        self.feed.iter().find(|f| f.diet == diet)
+    }
+
+    fn grazes(&self, diet: &str) -> bool {
+        self.grazing_diets.iter().any(|d| d == diet)
+    }
+}
+
+/// A player verb on the faced animal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HusbandryAction {
+    /// Feed a herd animal, or try to tame a wild one.
+    Tend,
+    Slaughter,
+}
+
+/// Founder stock for an animal with no pedigree (starter herd, the tamed).
+pub fn founder(genetics: Option<&GeneticsSystem>) -> Option<Genome> {
+    genetics.map(|g| g.founder("animal", &mut rand::rng()))
+}
+
+/// Express a genome on a freshly spawned animal: speed scales the amble, the
+/// product trait the renewable regrow time (twice the trait, half the wait).
+pub fn apply_traits(
+    genetics: Option<&GeneticsSystem>,
+    genome: Option<&Genome>,
+    creature: &mut Creature,
+    harvestable: Option<&mut Harvestable>,
+) {
+    let (Some(g), Some(genome)) = (genetics, genome) else {
+        return;
+    };
+    creature.speed *= g.relative(genome, SPEED).max(0.1);
+    if let Some(h) = harvestable {
+        if let Some(t) = product_trait(&h.resource) {
+            h.regrow_time /= g.relative(genome, t).max(0.1);
+            h.time_since_harvest = h.time_since_harvest.min(h.regrow_time);
+        }
+    }
+}
+
+/// The genetics.ron trait behind a renewable product, by item family.
+pub fn product_trait(item: &str) -> Option<&'static str> {
+    if item.starts_with("egg") {
+        Some("animal_egg_production")
+    } else if item.starts_with("milk") {
+        Some("animal_milk_production")
+    } else if item.starts_with("wool") {
+        Some("animal_wool_quality")
+    } else {
+        None
+    }
+}
+
+/// The whole husbandry step, run from LivestockSystem's tick: player verbs,
+/// then needs, then breeding. A no-op without `husbandry_config`.
+pub fn tick(world: &mut hecs::World, dt: f32, data: &DataStore) {
+    let Some(cfg) = data.get::<HusbandryConfig>("husbandry_config") else {
+        return;
+    };
+    let genetics = data.get::<GeneticsSystem>("genetics");
+    let registry = data.get::<CreatureRegistry>("creature_registry");
+    let items = data.get::<ItemRegistry>("item_registry");
+    let requests: Vec<(u64, HusbandryAction)> = data
+        .get::<std::sync::Mutex<Vec<(u64, HusbandryAction)>>>("husbandry_request")
+        .and_then(|m| m.lock().ok().map(|mut v| std::mem::take(&mut *v)))
+        .unwrap_or_default();
+    for (bits, action) in requests {
+        let Some(target) = hecs::Entity::from_bits(bits).filter(|e| world.contains(*e)) else {
+            continue;
+        };
+        let status = match action {
+            HusbandryAction::Tend => tend(world, data, cfg, target),
+            HusbandryAction::Slaughter => match slaughter(world, registry, items, genetics, target) {
+                Ok(drops) => {
+                    if let Some(chan) =
+                        data.get::<std::sync::Mutex<Vec<(String, u32)>>>("loot_drops")
+                    {
+                        if let Ok(mut v) = chan.lock() {
+                            v.extend(drops);
+                        }
+                    }
+                    continue; // the loot notice speaks for it
+                }
+                Err(e) => e,
+            },
+        };
+        if let Some(slot) = data.get::<std::sync::Mutex<String>>("husbandry_status") {
+            if let Ok(mut s) = slot.lock() {
+                *s = status;
+            }
+        }
+    }
+    if let Some(registry) = registry {
+        needs(world, dt, cfg, registry);
+        breed(world, cfg, genetics, registry, items);
+    }
+}
+
+/// Hunger, health and mood for every living herd animal over `dt` seconds.
+pub fn needs(world: &mut hecs::World, dt: f32, cfg: &HusbandryConfig, registry: &CreatureRegistry) {
+    let days = dt / SECONDS_PER_DAY as f32;
+    let mut starved: Vec<hecs::Entity> = Vec::new();
+    for (e, (c, hb, hp)) in world
+        .query_mut::<(&Creature, &mut Husbandry, &mut Health)>()
+        .without::<&Dead>()
+    {
+        let diet = registry.get(&c.def_id).map(|d| d.diet.as_str()).unwrap_or("");
+        let rise = cfg.hunger_per_day * days;
+        hb.hunger = (hb.hunger + rise).min(1.0);
+        // Pasture: a grazer above the pasture line eats its way back down.
+        if cfg.grazes(diet) && hb.hunger > cfg.pasture_hunger {
+            hb.hunger = (hb.hunger - rise * 2.0).max(cfg.pasture_hunger);
+        }
+        if hb.hunger >= 1.0 {
+            hp.current = (hp.current - hp.max * cfg.starve_damage_per_day * days).max(0.0);
+            if hp.current <= 0.0 {
+                starved.push(e);
+            }
+        } else if hb.hunger <= cfg.breed_max_hunger {
+            hp.current = (hp.current + hp.max * cfg.heal_per_day * days).min(hp.max);
+        }
+        let target = (1.0 - hb.hunger) * (hp.current / hp.max.max(1.0));
+        hb.mood += (target - hb.mood) * (cfg.mood_per_day * days).min(1.0);
+        hb.breed_cooldown = (hb.breed_cooldown - dt).max(0.0);
+    }
+    for e in starved {
+        log::info!("Husbandry: {e:?} starved");
+        let _ = world.insert_one(e, Dead::default());
+    }
+}
+
+/// Pair up ready dams and sires and spawn their young. Returns births.
+pub fn breed(
+    world: &mut hecs::World,
+    cfg: &HusbandryConfig,
+    genetics: Option<&GeneticsSystem>,
+    registry: &CreatureRegistry,
+    items: Option<&ItemRegistry>,
+) -> u32 {
+    struct Ready {
+        e: hecs::Entity,
+        def_id: String,
+        female: bool,
+        pos: Vec3,
+        genome: Option<Genome>,
+    }
+    let ready: Vec<Ready> = world
+        .query::<(&Creature, &Husbandry, &Age, &Transform)>()
+        .without::<&Dead>()
+        .iter()
+        .filter(|(_e, (_c, hb, age, _tf))| {
+            age.life_stage == BREEDING_STAGE
+                && hb.breed_cooldown <= 0.0
+                && hb.hunger <= cfg.breed_max_hunger
+                && hb.mood >= cfg.breed_min_mood
+        })
+        .map(|(e, (c, hb, _age, tf))| Ready {
+            e,
+            def_id: c.def_id.clone(),
+            female: hb.female,
+            pos: tf.position,
+            genome: hb.genome.clone(),
+        })
+        .collect();
+    let mut used: Vec<hecs::Entity> = Vec::new();
+    let mut births = 0;
+    let mut rng = rand::rng();
+    for dam in ready.iter().filter(|r| r.female) {
+        let sire = ready
+            .iter()
+            .filter(|r| !r.female && r.def_id == dam.def_id && !used.contains(&r.e))
+            .map(|r| (r, r.pos.distance(dam.pos)))
+            .filter(|(_, d)| *d <= cfg.breed_radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let (Some((sire, _)), Some(def)) = (sire, registry.get(&dam.def_id)) else {
+            continue;
+        };
+        used.push(sire.e);
+        let genome = match (genetics, &dam.genome, &sire.genome) {
+            (Some(g), Some(a), Some(b)) => Some(g.breed(a, b, &mut rng)),
+            _ => founder(genetics),
+        };
+        let fertility = match (genetics, &dam.genome) {
+            (Some(g), Some(genome)) => g.relative(genome, FERTILITY).max(0.1),
+            _ => 1.0,
+        };
+        let recovery = cfg.breed_cooldown_days * SECONDS_PER_DAY as f32;
+        if let Ok(mut hb) = world.get::<&mut Husbandry>(dam.e) {
+            hb.breed_cooldown = recovery / fertility;
+            hb.hunger = (hb.hunger + cfg.birth_hunger).min(1.0);
+        }
+        if let Ok(mut hb) = world.get::<&mut Husbandry>(sire.e) {
+            hb.breed_cooldown = recovery * 0.25;
+        }
+        let Some(mother) = world.get::<&Creature>(dam.e).ok().map(|c| Creature::clone(&c)) else {
+            continue;
+        };
+        let young = Husbandry {
+            hunger: cfg.birth_hunger,
+            mood: 0.5,
+            female: rng.random_bool(0.5),
+            genome,
+            breed_cooldown: 0.0,
+        };
+        let pos = dam.pos + Vec3::new(0.4, 0.0, 0.0);
+        spawn_herd_animal(world, def, items, genetics, pos, &mother, young, Age {
+            years: 0.0,
+            life_stage: "juvenile".into(),
+        });
+        log::info!("Husbandry: a {} was born", def.name);
+        births += 1;
+    }
+    births
+}
+
+/// Spawn a herd animal of `def` at `pos` grazing `like`'s pasture (anchor,
+/// range, tint): the spawn_creature_at bundle without any AIBehavior, plus its
+/// husbandry and age. Renewable product starts regrowing, not ready.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_herd_animal(
+    world: &mut hecs::World,
+    def: &CreatureDef,
+    items: Option<&ItemRegistry>,
+    genetics: Option<&GeneticsSystem>,
+    pos: Vec3,
+    like: &Creature,
+    husbandry: Husbandry,
+    age: Age,
+) -> hecs::Entity {
+    let e = crate::systems::livestock::spawn_creature_at(world, def, items, pos, like.tint);
+    if let Ok(mut h) = world.get::<&mut Harvestable>(e) {
+        h.time_since_harvest = 0.0;
+    }
+    adopt(world, e, genetics, like.anchor, like.range, husbandry, age);
+    e
+}
+
+/// Make `e` a herd animal grazing `anchor`: husbandry + age, no AI, traits
+/// expressed on its Creature and Harvestable.
+fn adopt(
+    world: &mut hecs::World,
+    e: hecs::Entity,
+    genetics: Option<&GeneticsSystem>,
+    anchor: Vec3,
+    range: f32,
+    husbandry: Husbandry,
+    age: Age,
+) {
+    let _ = world.remove_one::<AIBehavior>(e);
+    if let Ok((c, h)) = world.query_one_mut::<(&mut Creature, Option<&mut Harvestable>)>(e) {
+        c.anchor = anchor;
+        c.range = range;
+        apply_traits(genetics, husbandry.genome.as_ref(), c, h);
+    }
+    let _ = world.insert(e, (husbandry, age));
+}
+
+/// Feed the faced animal from the pack: a herd animal eats, a wild
+/// domesticable one gets a taming roll. Returns the status line.
+pub fn tend(world: &mut hecs::World, data: &DataStore, cfg: &HusbandryConfig, target: hecs::Entity) -> String {
+    let Some(registry) = data.get::<CreatureRegistry>("creature_registry") else {
+        return String::new();
+    };
+    let Some(def) = world.get::<&Creature>(target).ok().and_then(|c| registry.get(&c.def_id)) else {
+        return "Nothing to feed".to_string();
+    };
+    if world.get::<&Dead>(target).is_ok() {
+        return format!("The {} is dead", def.name);
+    }
+    let hunger = world.get::<&Husbandry>(target).ok().map(|hb| hb.hunger);
+    if hunger.is_none() && !def.is_domesticable() {
+        return format!("The {} cannot be tamed", def.name);
+    }
+    if hunger.is_some_and(|h| h < 0.05) {
+        return format!("The {} is not hungry", def.name);
+    }
+    let Some(row) = cfg.feed_for(&def.diet) else {
+        return format!("Nothing you stock feeds a {}", def.name);
+    };
+    let items = data.get::<ItemRegistry>("item_registry");
+    let item_name = |id: &str| {
+        items
+            .and_then(|r| r.items.get(id))
+            .map(|d| d.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+    let creative = data
+        .get::<std::sync::Mutex<bool>>("creative_mode")
+        .and_then(|m| m.lock().ok().map(|g| *g))
+        .unwrap_or(false);
+    let mut fed: Option<String> = None;
+    if let Some((_e, (inv, _ctrl))) = world.query_mut::<(&mut Inventory, &Controllable)>().into_iter().next() {
+        fed = row.items.iter().find(|id| creative || inv.has_item(id, 1)).cloned();
+        if let Some(id) = fed.as_deref().filter(|_| !creative) {
+            inv.remove_item(id, 1);
+        }
+    }
+    let Some(feed) = fed else {
+        return format!("You have no feed for the {} ({})", def.name, item_name(&row.items[0]));
+    };
+    let feed = item_name(&feed);
+
+    if let Ok(mut hb) = world.get::<&mut Husbandry>(target) {
+        hb.hunger = (hb.hunger - row.nutrition).max(0.0);
+        hb.mood = (hb.mood + cfg.feed_mood).min(1.0);
+        return format!("Fed the {} ({feed})", def.name);
+    }
+
+    // Wild: the offer is a taming roll, better odds for a docile animal.
+    let genetics = data.get::<GeneticsSystem>("genetics");
+    let genome = founder(genetics);
+    let temperament = match (genetics, &genome) {
+        (Some(g), Some(genome)) => g.express(genome, TEMPERAMENT).unwrap_or(0.5),
+        _ => 0.5,
+    };
+    let mut rng = rand::rng();
+    let chance = (cfg.tame_chance * (0.5 + temperament)).clamp(0.0, 1.0);
+    if !rng.random_bool(chance as f64) {
+        return format!("The {} eats the {feed} but stays wary", def.name);
+    }
+    let pos = world.get::<&Transform>(target).map(|t| t.position).unwrap_or_default();
+    // Join the nearest herd's pasture, or graze where it was tamed.
+    let (anchor, range) = world
+        .query::<(&Creature, &Husbandry)>()
+        .iter()
+        .map(|(_e, (c, _))| (c.anchor, c.range))
+        .min_by(|a, b| a.0.distance(pos).total_cmp(&b.0.distance(pos)))
+        .unwrap_or((pos, 3.0));
+    let husbandry = Husbandry {
+        hunger: (0.5 - row.nutrition).max(0.0),
+        mood: 0.5,
+        female: rng.random_bool(0.5),
+        genome,
+        breed_cooldown: 0.0,
+    };
+    let age = Age { years: 0.1, life_stage: BREEDING_STAGE.into() };
+    adopt(world, target, genetics, anchor, range, husbandry, age);
+    if world.get::<&Harvestable>(target).is_err() {
+        if let Some(p) = def.renewable() {
+            let _ = world.insert_one(target, Harvestable {
+                resource: p.item,
+                amount: p.amount as f32,
+                regrow_time: p.regrow_s,
+                time_since_harvest: 0.0,
+            });
+        }
+    }
+    format!("The {} is tamed and joins the herd", def.name)
+}
+
+/// Slaughter a living herd animal: roll its creatures.csv loot table, counts
+/// scaled by body size, and despawn it. Wild animals are the hunt's, not this.
+pub fn slaughter(
+    world: &mut hecs::World,
+    registry: Option<&CreatureRegistry>,
+    items: Option<&ItemRegistry>,
+    genetics: Option<&GeneticsSystem>,
+    target: hecs::Entity,
+) -> Result<Vec<(String, u32)>, String> {
+    let name = world
+        .get::<&Name>(target)
+        .map(|n| n.0.clone())
+        .unwrap_or_else(|_| "animal".to_string());
+    if world.get::<&Dead>(target).is_ok() {
+        return Err(format!("The {name} is already dead"));
+    }
+    let genome = match world.get::<&Husbandry>(target) {
+        Ok(hb) => hb.genome.clone(),
+        Err(_) => return Err(format!("The {name} is not one of your herd")),
+    };
+    let entries = match (registry, world.get::<&Creature>(target)) {
+        (Some(reg), Ok(c)) => reg.get(&c.def_id).map(|d| d.loot_entries(items)),
+        _ => None,
+    };
+    let entries = match entries {
+        Some(e) => e,
+        None => world
+            .get::<&crate::ecs::components::LootTable>(target)
+            .map(|t| t.entries.clone())
+            .unwrap_or_default(),
+    };
+    let size = match (genetics, &genome) {
+        (Some(g), Some(genome)) => g.relative(genome, SIZE),
+        _ => 1.0,
+    };
+    let juvenile = world
+        .get::<&Age>(target)
+        .is_ok_and(|a| a.life_stage == "juvenile");
+    let scale = if juvenile { size * 0.5 } else { size };
+    let drops = roll_yield(&entries, scale, &mut rand::rng());
+    let _ = world.despawn(target);
+    Ok(drops)
+}
+
+/// Roll loot entries (id, chance, min, max) with counts scaled by `scale`;
+/// a drop that rolls still yields at least one.
+pub fn roll_yield<R: Rng + ?Sized>(
+    entries: &[(String, f32, u32, u32)],
+    scale: f32,
+    rng: &mut R,
+) -> Vec<(String, u32)> {
+    let mut drops = Vec::new();
+    for (id, chance, min, max) in entries {
+        if rng.random::<f32>() < *chance {
+            let n = rng.random_range(*min..=*max) as f32 * scale;
+            drops.push((id.clone(), (n.round() as u32).max(1)));
+        }
+    }
+    drops
+}
+
+/// Walk-up prompt suffix: a herd animal's state and verbs, or the taming
+/// offer on a wild domesticable creature. None for anything else.
+pub fn prompt(world: &hecs::World, e: hecs::Entity, registry: Option<&CreatureRegistry>) -> Option<String> {
+    if world.get::<&Dead>(e).is_ok() {
+        return None;
+    }
+    if let Ok(hb) = world.get::<&Husbandry>(e) {
+        let stage = world.get::<&Age>(e).map(|a| a.life_stage.clone()).unwrap_or_default();
+        let sex = if hb.female { "female" } else { "male" };
+        return Some(format!(
+            "{sex} {stage} · fed {:.0}% · mood {:.0}% · [X] feed, [K] slaughter",
+            (1.0 - hb.hunger) * 100.0,
+            hb.mood * 100.0
+        ));
+    }
+    let def = registry?.get(&world.get::<&Creature>(e).ok()?.def_id)?;
+    def.is_domesticable().then(|| "[X] offer feed to tame".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shipped(rel: &str) -> Vec<u8> {
+        std::fs::read(std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data").join(rel)).unwrap()
+    }
+
+    fn store() -> DataStore {
+        let mut data = DataStore::new();
+        data.insert("husbandry_config", HusbandryConfig::from_ron(&shipped("entities/husbandry.ron")).unwrap());
+        data.insert("creature_registry", CreatureRegistry::from_csv(&shipped("creatures.csv")).unwrap());
+        data.insert("item_registry", ItemRegistry::from_csv(&shipped("items.csv")).unwrap());
+        data.insert(
+            "genetics",
+            GeneticsSystem::from_ron(std::str::from_utf8(&shipped("genetics.ron")).unwrap()).unwrap(),
+        );
+        data.insert("husbandry_request", std::sync::Mutex::new(Vec::<(u64, HusbandryAction)>::new()));
+        data.insert("husbandry_status", std::sync::Mutex::new(String::new()));
+        data.insert("loot_drops", std::sync::Mutex::new(Vec::<(String, u32)>::new()));
+        data
+    }
+
+    fn goat(world: &mut hecs::World, data: &DataStore, female: bool, x: f32) -> hecs::Entity {
+        let reg = data.get::<CreatureRegistry>("creature_registry").unwrap();
+        let like = Creature {
+            def_id: "goat".into(),
+            anchor: Vec3::ZERO,
+            range: 3.0,
+            phase: 0.0,
+            speed: 0.5,
+            tint: [0.6, 0.5, 0.4],
+            body_side: 0.4,
+        };
+        let hb = Husbandry {
+            hunger: 0.0,
+            mood: 1.0,
+            female,
+            genome: founder(data.get::<GeneticsSystem>("genetics")),
+            breed_cooldown: 0.0,
+        };
+        let age = Age { years: 0.1, life_stage: BREEDING_STAGE.into() };
+        spawn_herd_animal(world, reg.get("goat").unwrap(), None, None, Vec3::new(x, 0.0, 0.0), &like, hb, age)
+    }
+
+    fn request(data: &DataStore, e: hecs::Entity, action: HusbandryAction) {
+        let chan = data.get::<std::sync::Mutex<Vec<(u64, HusbandryAction)>>>("husbandry_request").unwrap();
+        chan.lock().unwrap().push((u64::from(e.to_bits()), action));
+    }
+
+    #[test]
+    fn shipped_feed_resolves_to_real_items() {
+        let data = store();
+        let cfg = data.get::<HusbandryConfig>("husbandry_config").unwrap();
+        let items = data.get::<ItemRegistry>("item_registry").unwrap();
+        for row in &cfg.feed {
+            assert!(!row.items.is_empty(), "{}: no feed", row.diet);
+            for id in &row.items {
+                assert!(items.items.contains_key(id), "{}: {id} is not in items.csv", row.diet);
+            }
+        }
+        // Every domesticable land species eats something we stock.
+        let reg = data.get::<CreatureRegistry>("creature_registry").unwrap();
+        for id in ["chicken", "cow", "pig", "sheep", "goat", "horse", "cat"] {
+            assert!(cfg.feed_for(&reg.get(id).unwrap().diet).is_some(), "{id} has no feed");
+        }
+
+        // A feed row with nothing in it is a load error, not a feeding panic.
+        let text = String::from_utf8(shipped("entities/husbandry.ron")).unwrap();
+        let broken = text.replacen("feed: [", "feed: [(diet: \"lithovore\", nutrition: 0.1, items: []),", 1);
+        let err = HusbandryConfig::from_ron(broken.as_bytes()).unwrap_err();
+        assert!(err.contains("lithovore"), "{err}");
+    }
+
+    #[test]
+    fn fed_pair_breeds_young_with_inherited_genome() {
+        let data = store();
+        let mut world = hecs::World::new();
+        let dam = goat(&mut world, &data, true, 0.0);
+        let sire = goat(&mut world, &data, false, 1.0);
+        assert!(world.get::<&AIBehavior>(dam).is_err(), "herd animals graze, no AI");
+
+        tick(&mut world, 1.0, &data);
+        let young: Vec<(hecs::Entity, Husbandry, String)> = world
+            .query::<(&Husbandry, &Age)>()
+            .iter()
+            .filter(|(e, _)| *e != dam && *e != sire)
+            .map(|(e, (hb, age))| (e, hb.clone(), age.life_stage.clone()))
+            .collect();
+        assert_eq!(young.len(), 1, "one young per pairing");
+        assert_eq!(young[0].2, "juvenile");
+        let child = young[0].1.genome.as_ref().expect("bred from two genomes");
+        let dam_genome = world.get::<&Husbandry>(dam).unwrap().genome.clone().unwrap();
+        assert_eq!(child.alleles.len(), dam_genome.alleles.len(), "every animal trait inherited");
+        assert!(world.get::<&Husbandry>(dam).unwrap().breed_cooldown > 0.0, "dam recovers");
+
+        // The pair is spent: no second birth straight away.
+        tick(&mut world, 1.0, &data);
+        assert_eq!(world.query::<&Husbandry>().iter().count(), 3);
+    }
+
+    #[test]
+    fn hunger_stops_at_pasture_and_starvation_kills_carnivores() {
+        let data = store();
+        let cfg = data.get::<HusbandryConfig>("husbandry_config").unwrap();
+        let reg = data.get::<CreatureRegistry>("creature_registry").unwrap();
+        let mut world = hecs::World::new();
+        let g = goat(&mut world, &data, true, 0.0);
+        let cat = spawn_creature_at_herd(&mut world, reg, "cat");
+        // Ten game days alone.
+        for _ in 0..100 {
+            needs(&mut world, SECONDS_PER_DAY as f32 / 10.0, cfg, reg);
+        }
+        let hb = Husbandry::clone(&world.get::<&Husbandry>(g).unwrap());
+        assert!((hb.hunger - cfg.pasture_hunger).abs() < 1e-4, "the goat grazes (hunger {})", hb.hunger);
+        assert!(hb.mood < 0.6, "pasture alone is not contentment");
+        assert!(world.get::<&Dead>(cat).is_ok(), "a cat does not graze");
+
+        // Hand feeding brings the goat to breeding condition.
+        request(&data, g, HusbandryAction::Tend);
+        let player = world.spawn((Inventory::new(20), Controllable));
+        world.get::<&mut Inventory>(player).unwrap().add_item("grain_oat_0", 1, 99);
+        tick(&mut world, 0.0, &data);
+        assert!(world.get::<&Husbandry>(g).unwrap().hunger < 0.15);
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item("grain_oat_0"), 0);
+        let status = data.get::<std::sync::Mutex<String>>("husbandry_status").unwrap();
+        assert_eq!(*status.lock().unwrap(), "Fed the Goat (Oat Grain)");
+    }
+
+    fn spawn_creature_at_herd(world: &mut hecs::World, reg: &CreatureRegistry, id: &str) -> hecs::Entity {
+        let def = reg.get(id).unwrap();
+        let like = Creature {
+            def_id: id.into(),
+            anchor: Vec3::new(50.0, 0.0, 0.0),
+            range: 2.0,
+            phase: 0.0,
+            speed: 0.5,
+            tint: [1.0; 3],
+            body_side: def.body_side(),
+        };
+        let hb = Husbandry { hunger: 0.5, mood: 0.5, female: true, genome: None, breed_cooldown: 0.0 };
+        let age = Age { years: 0.1, life_stage: BREEDING_STAGE.into() };
+        spawn_herd_animal(world, def, None, None, like.anchor, &like, hb, age)
+    }
+
+    #[test]
+    fn wild_domesticable_creatures_tame_and_herd_animals_slaughter() {
+        let data = store();
+        let reg = data.get::<CreatureRegistry>("creature_registry").unwrap();
+        let mut world = hecs::World::new();
+        let player = world.spawn((Inventory::new(20), Controllable));
+        world.get::<&mut Inventory>(player).unwrap().add_item("grain_oat_0", 99, 99);
+        let herd = goat(&mut world, &data, true, 0.0);
+        let horse = crate::systems::livestock::spawn_creature_at(
+            &mut world,
+            reg.get("horse").unwrap(),
+            None,
+            Vec3::new(40.0, 0.0, 0.0),
+            [1.0; 3],
+        );
+        world.insert_one(horse, AIBehavior {
+            behavior_type: "passive".into(),
+            state: "idle".into(),
+            target: None,
+        }).unwrap();
+        let wolf = crate::systems::livestock::spawn_creature_at(
+            &mut world,
+            reg.get("wolf").unwrap(),
+            None,
+            Vec3::new(60.0, 0.0, 0.0),
+            [1.0; 3],
+        );
+        assert_eq!(prompt(&world, horse, Some(reg)).as_deref(), Some("[X] offer feed to tame"));
+
+        let cfg = data.get::<HusbandryConfig>("husbandry_config").unwrap();
+        assert_eq!(tend(&mut world, &data, cfg, wolf), "The Wolf cannot be tamed");
+        let mut tries = 0;
+        while world.get::<&Husbandry>(horse).is_err() {
+            tries += 1;
+            assert!(tries < 60, "taming never took");
+            tend(&mut world, &data, cfg, horse);
+        }
+        assert!(world.get::<&AIBehavior>(horse).is_err(), "tamed animals leave the AI");
+        assert_eq!(world.get::<&Creature>(horse).unwrap().anchor, Vec3::ZERO, "joins the goats' pasture");
+        assert!(prompt(&world, horse, Some(reg)).unwrap().contains("[K] slaughter"));
+
+        // Slaughter: the creatures.csv goat table lands in loot_drops.
+        request(&data, herd, HusbandryAction::Slaughter);
+        request(&data, wolf, HusbandryAction::Slaughter);
+        tick(&mut world, 0.0, &data);
+        assert!(!world.contains(herd));
+        assert!(world.contains(wolf), "wild animals are not slaughtered");
+        let drops = data.get::<std::sync::Mutex<Vec<(String, u32)>>>("loot_drops").unwrap();
+        assert!(drops.lock().unwrap().iter().any(|(id, n)| id == "raw_mutton_0" && *n >= 1));
+    }
+}
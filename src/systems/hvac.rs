@@ -1,21 +1,32 @@
 //! HVAC system — heating, ventilation, air conditioning, and life support.
 //!
 //! Each tick:
-//!   1. Each `RoomEnvironment` drifts toward the ambient outdoor temperature
-//!      (slow exponential decay — represents thermal bleed through hull/walls).
-//!   2. Each `HvacUnit` adjusts the nearest `RoomEnvironment` within range,
-//!      pushing temp toward `target_temp` proportional to `power_kw`.
+//!   1. The home's rooms (`ThermalRoom` + `RoomEnvironment`, spawned from the home structure) run as a
+//!      thermal network (see `systems::thermal`): machines, occupants and sun through a glass roof
+//!      heat the room they are in, walls and open doors carry heat between rooms, and the shell, the
+//!      roof and leaking air lose it to the weather. `HvacUnit`s act as thermostats on the room they
+//!      stand in. Time runs on the game clock, so a night cools the house as a night should.
+//!   2. Any other `RoomEnvironment` drifts toward the ambient outdoor temperature (slow exponential
+//!      decay) and is pulled toward nearby `HvacUnit` targets, as before.
 //!   3. Vent-mode units reduce CO2 (atmosphere swap with outside).
 //!   4. CO2 from occupants accumulates (handled crudely — every Player or
 //!      AIBehavior entity in a room contributes a constant ppm/s).
+//!
+//! The network's temperatures are published as a `ThermalStatus` (key `thermal_status`).
 
 use std::path::Path;
 
 use serde::Deserialize;
 
-use crate::ecs::components::{AIBehavior, Controllable, HvacUnit, RoomEnvironment, Transform};
+use crate::ecs::components::{
+    AIBehavior, Controllable, HvacUnit, PowerConsumer, PowerGenerator, RoomEnvironment, Transform,
+};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
+use crate::systems::thermal::{
+    door_exchange_w_k, door_open_fraction, room_index_at, step, RoomTemp, ThermalLink, ThermalRoom, ThermalStatus,
+    ThermalTuning, AIR_J_M3K,
+};
 
 /// Max distance an HVAC unit can affect a room (meters).
 const MAX_AFFECT_DIST: f32 = 10.0;
@@ -27,6 +38,8 @@ const DEFAULT_OUTSIDE_TEMP: f32 = 12.0;
 const BLEED_RATE: f32 = 0.0005;
 /// Per-second CO2 ppm emitted by each occupant.
 const CO2_PER_OCCUPANT_PER_SEC: f32 = 0.05;
+/// Physical seconds per game-clock second: a 24 h day passes in `SECONDS_PER_DAY` of play.
+const PHYSICAL_PER_GAME_SECOND: f32 = (86_400.0 / crate::systems::time::SECONDS_PER_DAY) as f32;
 
 /// Top-level RON schema for `data/hvac.ron`.
 #[derive(Debug, Deserialize)]
@@ -36,6 +49,8 @@ pub struct HvacData {
     #[serde(default)] pub ventilation: Vec<ron::Value>,
     #[serde(default)] pub life_support: Vec<ron::Value>,
     #[serde(default)] pub sensors: Vec<ron::Value>,
+    /// Thermal network tuning: occupants, infiltration, sun, gensets, doors, thermostats.
+    #[serde(default)] pub thermal: ThermalTuning,
 }
 
 /// Tracks heating, cooling, and ventilation per room.
@@ -52,17 +67,168 @@ impl HvacSystem {
         });
         let data: HvacData = ron::from_str(&text).unwrap_or_else(|e| {
             log::warn!("Failed to parse hvac.ron: {e}");
-            HvacData { heating: vec![], cooling: vec![], ventilation: vec![], life_support: vec![], sensors: vec![] }
+            HvacData {
+                heating: vec![],
+                cooling: vec![],
+                ventilation: vec![],
+                life_support: vec![],
+                sensors: vec![],
+                thermal: ThermalTuning::default(),
+            }
         });
         log::info!("Loaded HVAC data: {} heating, {} cooling", data.heating.len(), data.cooling.len());
         Self { data }
     }
+
+    /// Step the home's thermal network and publish `thermal_status`. No-op until the rooms
+    /// are spawned.
+    fn tick_network(&self, world: &mut hecs::World, dt: f32, data: &DataStore, occupants: &[glam::Vec3]) {
+        let rooms: Vec<(hecs::Entity, ThermalRoom, f32)> = world
+            .query::<(&ThermalRoom, &RoomEnvironment)>()
+            .iter()
+            .map(|(e, (r, env))| (e, r.clone(), env.temp_c))
+            .collect();
+        if rooms.is_empty() {
+            return;
+        }
+        let t = &self.data.thermal;
+        let seconds = crate::systems::time::scaled_dt(dt, data) * PHYSICAL_PER_GAME_SECOND;
+        let hour = data
+            .get::<std::sync::Mutex<crate::systems::time::GameTime>>("game_time")
+            .and_then(|m| m.lock().ok().map(|g| g.hour))
+            .unwrap_or(12.0);
+        let outside_c = data
+            .get::<std::sync::Mutex<crate::systems::weather::Weather>>("weather")
+            .and_then(|m| m.lock().ok().map(|w| w.temperature))
+            .unwrap_or(DEFAULT_OUTSIDE_TEMP);
+        let bounds: Vec<((f32, f32), (f32, f32))> = rooms.iter().map(|(_, r, _)| (r.min, r.max)).collect();
+        let at = |p: glam::Vec3| room_index_at(bounds.iter().copied(), p.x, p.z);
+        let mut temps: Vec<f32> = rooms.iter().map(|(_, _, temp)| *temp).collect();
+        let caps: Vec<f32> = rooms.iter().map(|(_, r, _)| r.capacity_j_k).collect();
+
+        // Heat in: the sun through a glass roof, people, and every running machine. An appliance's
+        // draw all ends up as heat where it is used; a genset sheds the fuel energy it does not turn
+        // into electricity, part of it indoors.
+        let sun = crate::systems::solar::sun_factor(hour);
+        let mut gains: Vec<f32> = rooms
+            .iter()
+            .map(|(_, r, _)| r.glazed_roof_m2 * t.solar_irradiance_w_m2 * t.solar_heat_gain * sun)
+            .collect();
+        let mut occupancy = vec![0.0f32; rooms.len()];
+        for i in occupants.iter().filter_map(|p| at(*p)) {
+            gains[i] += t.occupant_w;
+            occupancy[i] += 1.0;
+        }
+        for (_, (tf, c)) in world.query::<(&Transform, &PowerConsumer)>().without::<&HvacUnit>().iter() {
+            if let Some(i) = at(tf.position).filter(|_| c.enabled) {
+                gains[i] += c.draw_watts;
+            }
+        }
+        for (_, (tf, g)) in world.query::<(&Transform, &PowerGenerator)>().iter() {
+            if let Some(i) = at(tf.position).filter(|_| g.active && g.fuel_per_second > 0.0) {
+                let fuel_w = g.fuel_per_second * t.fuel_kwh_per_l * 3.6e6;
+                gains[i] += (fuel_w - g.output_watts).max(0.0) * t.genset_room_fraction;
+            }
+        }
+        // Thermostats: a unit runs in proportion to how far its room is from the set point, and its
+        // electrical draw follows (a satisfied furnace draws nothing). Vents swap room air outside.
+        let band = t.thermostat_band_c.max(0.1);
+        let mut vent_kw = vec![0.0f32; rooms.len()];
+        for (_, (tf, unit, consumer)) in world.query_mut::<(&Transform, &HvacUnit, Option<&mut PowerConsumer>)>() {
+            let Some(i) = at(tf.position) else { continue };
+            let duty = match unit.mode.as_str() {
+                "heat" => ((unit.target_temp - temps[i]) / band + 0.5).clamp(0.0, 1.0),
+                "cool" => ((temps[i] - unit.target_temp) / band + 0.5).clamp(0.0, 1.0),
+                "vent" => 1.0,
+                _ => 0.0,
+            };
+            let powered = consumer.as_ref().is_none_or(|c| c.enabled);
+            if let Some(c) = consumer {
+                c.draw_watts = unit.power_kw * 1000.0 * duty;
+            }
+            if !powered {
+                continue;
+            }
+            match unit.mode.as_str() {
+                "heat" => gains[i] += unit.power_kw * 1000.0 * duty,
+                "cool" => gains[i] -= unit.power_kw * 1000.0 * duty,
+                "vent" => vent_kw[i] += unit.power_kw,
+                _ => {}
+            }
+        }
+
+        // Links: walls and shut doors as built, plus the air an open door swaps; then infiltration
+        // and vents to the outside.
+        let index: std::collections::HashMap<&str, usize> =
+            rooms.iter().enumerate().map(|(i, (_, r, _))| (r.id.as_str(), i)).collect();
+        let panels: Vec<((f32, f32), f32)> = data
+            .get::<std::sync::Mutex<Vec<((f32, f32), f32)>>>("door_open")
+            .and_then(|m| m.lock().ok().map(|v| v.clone()))
+            .unwrap_or_default();
+        let mut links: Vec<(usize, Option<usize>, f32)> = Vec::new();
+        for (_, link) in world.query::<&ThermalLink>().iter() {
+            let Some(&a) = index.get(link.a.as_str()) else { continue };
+            let b = match &link.b {
+                Some(id) => match index.get(id.as_str()) {
+                    Some(&b) => Some(b),
+                    None => continue,
+                },
+                None => None,
+            };
+            let mut g = link.ua_w_k;
+            if let Some(door) = &link.door {
+                let open = door_open_fraction(door, &panels);
+                if open > 0.0 {
+                    let tb = b.map_or(outside_c, |b| temps[b]);
+                    g += door_exchange_w_k(door, open, temps[a], tb, t.door_discharge);
+                }
+            }
+            links.push((a, b, g));
+        }
+        for (i, (_, r, _)) in rooms.iter().enumerate() {
+            let air_m3s = r.volume_m3 * t.air_changes_per_hour / 3600.0 + vent_kw[i] * t.vent_m3s_per_kw;
+            links.push((i, None, air_m3s * AIR_J_M3K));
+        }
+        step(&mut temps, &caps, &gains, &links, outside_c, seconds);
+
+        let mut status = ThermalStatus { outside_c, ..Default::default() };
+        let mut volume = 0.0f32;
+        for (i, (e, r, _)) in rooms.iter().enumerate() {
+            if let Ok(mut env) = world.get::<&mut RoomEnvironment>(*e) {
+                env.temp_c = temps[i];
+                breathe(&mut env, occupancy[i], vent_kw[i], dt);
+            }
+            status.mean_c += temps[i] * r.volume_m3;
+            volume += r.volume_m3;
+            status.rooms.push(RoomTemp { id: r.id.clone(), min: r.min, max: r.max, temp_c: temps[i], gain_w: gains[i] });
+        }
+        status.mean_c /= volume.max(1e-3);
+        if let Some(slot) = data.get::<std::sync::Mutex<ThermalStatus>>("thermal_status") {
+            if let Ok(mut s) = slot.lock() {
+                *s = status;
+            }
+        }
+    }
+}
+
+/// CO2 from occupants (ppm/sec) and the vents' swap with outside air; humidity drifts toward 0.45
+/// (comfortable indoor). Not driven yet.
+fn breathe(env: &mut RoomEnvironment, occupants: f32, vent_strength: f32, dt: f32) {
+    env.co2_ppm += occupants * CO2_PER_OCCUPANT_PER_SEC * dt;
+    if vent_strength > 0.0 {
+        let outside_co2 = 420.0;
+        let vent_rate = (vent_strength * 0.002).min(0.1);
+        env.co2_ppm += (outside_co2 - env.co2_ppm) * vent_rate * dt;
+    }
+    env.co2_ppm = env.co2_ppm.max(380.0);
+    env.humidity += (0.45 - env.humidity) * 0.0001 * dt;
+    env.humidity = env.humidity.clamp(0.0, 1.0);
 }
 
 impl System for HvacSystem {
     fn name(&self) -> &str { "HvacSystem" }
 
-    fn tick(&mut self, world: &mut hecs::World, dt: f32, _data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         if dt <= 0.0 { return; }
 
         // Snapshot HVAC unit positions + state.
@@ -79,9 +245,13 @@ impl System for HvacSystem {
             .map(|(_, (t, _))| t.position)
             .collect();
 
-        // Snapshot room positions (we'll re-borrow to mutate).
+        // The home's rooms run as a thermal network.
+        self.tick_network(world, dt, data, &occupant_positions);
+
+        // Snapshot the remaining room positions (we'll re-borrow to mutate).
         let rooms: Vec<(hecs::Entity, glam::Vec3)> = world
             .query::<(&Transform, &RoomEnvironment)>()
+            .without::<&ThermalRoom>()
             .iter()
             .map(|(e, (t, _))| (e, t.position))
             .collect();
@@ -126,19 +296,67 @@ impl System for HvacSystem {
                     env.temp_c += (weighted_target - env.temp_c) * rate * dt;
                 }
 
-                // CO2 emission from occupants (ppm/sec) + vent reduction.
-                env.co2_ppm += occupants * CO2_PER_OCCUPANT_PER_SEC * dt;
-                if vent_strength > 0.0 {
-                    let outside_co2 = 420.0;
-                    let vent_rate = (vent_strength * 0.002).min(0.1);
-                    env.co2_ppm += (outside_co2 - env.co2_ppm) * vent_rate * dt;
-                }
-                env.co2_ppm = env.co2_ppm.max(380.0);
-
-                // Humidity drift toward 0.45 (comfortable indoor). Not driven yet.
-                env.humidity += (0.45 - env.humidity) * 0.0001 * dt;
-                env.humidity = env.humidity.clamp(0.0, 1.0);
+                // CO2 emission from occupants (ppm/sec) + vent reduction, humidity drift.
+                breathe(&mut env, occupants, vent_strength, dt);
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ship::home_structure::{HomeStructure, InteriorWall};
+    use crate::systems::thermal::build_network;
+
+    #[test]
+    fn a_furnace_heats_its_room_and_the_neighbour_lags() {
+        let mut hs: HomeStructure = ron::from_str("(width: 10.0, depth: 6.0, height: 3.0)").expect("box parses");
+        hs.roof_material = 2;
+        hs.walls.push(InteriorWall {
+            a: (5.0, 0.0),
+            b: (5.0, 6.0),
+            height: 3.0,
+            material: 1,
+            openings: Vec::new(),
+            thickness: None,
+            layers: Vec::new(),
+        });
+        let mut world = hecs::World::new();
+        let (rooms, links) = build_network(&hs, glam::Vec3::ZERO);
+        for r in rooms {
+            world.spawn((RoomEnvironment { temp_c: 5.0, ..Default::default() }, r));
+        }
+        for l in links {
+            world.spawn((l,));
+        }
+        let at = |x: f32| Transform { position: glam::Vec3::new(x, 0.5, 3.0), ..Default::default() };
+        world.spawn((
+            at(2.0),
+            HvacUnit { mode: "heat".into(), target_temp: 21.0, power_kw: 15.0 },
+            PowerConsumer { draw_watts: 0.0, priority: 2, enabled: true },
+        ));
+
+        // A freezing night: no sun, -10 °C outside.
+        let mut data = DataStore::new();
+        let mut weather = crate::systems::weather::Weather::default();
+        weather.temperature = -10.0;
+        data.insert("weather", std::sync::Mutex::new(weather));
+        let mut clock = crate::systems::time::GameTime::default();
+        clock.hour = 0.0;
+        data.insert("game_time", std::sync::Mutex::new(clock));
+        data.insert("thermal_status", std::sync::Mutex::new(ThermalStatus::default()));
+        let mut hvac = HvacSystem { data: ron::from_str("()").expect("empty hvac data") };
+        for _ in 0..1200 {
+            hvac.tick(&mut world, 1.0, &data);
+        }
+
+        let status = data.get::<std::sync::Mutex<ThermalStatus>>("thermal_status").unwrap().lock().unwrap().clone();
+        let heated = status.temp_at(2.0, 3.0).expect("furnace room");
+        let cold = status.temp_at(8.0, 3.0).expect("neighbour");
+        assert!((heated - 21.0).abs() < 1.0, "the thermostat holds its room near 21 °C: {heated}");
+        assert!(cold < heated - 3.0 && cold > -10.0, "the neighbour warms only through the wall: {cold}");
+        let draw = world.query::<&PowerConsumer>().iter().map(|(_, c)| c.draw_watts).next().unwrap();
+        assert!(draw > 0.0 && draw < 15_000.0, "at set point the furnace throttles: {draw} W");
+    }
+}
@@ -291,6 +291,10 @@ pub struct ItemDef {
     pub content_class: String,
     pub stackable: bool,
     pub max_stack: u32,
+    /// Uses before the item breaks (items.csv durability); 0 = never wears.
+    /// Worn weapons count down against it.
+    #[serde(default)]
+    pub durability: u32,
 }
 
 /// Registry of all item definitions, keyed by item ID.
@@ -338,8 +342,7 @@ impl ItemRegistry {
     /// Uses the shared CSV loader (skips `#` comments, header-mapped, row-resilient
     /// so one malformed row never blanks the catalog). Only the columns the
     /// registry needs are deserialized; the rest of items.csv (category,
-    /// subcategory, base_material, durability, description, content_class) is
-    /// ignored. This is the constructor the runtime calls to populate
+    /// subcategory, base_material, description) is ignored. This is the constructor the runtime calls to populate
     /// `DataStore["item_registry"]` — before v0.323 the CSV was loaded then
     /// discarded, so item-name/stack/mass lookups silently fell back to defaults.
     pub fn from_csv(data: &[u8]) -> Result<Self, String> {
@@ -361,6 +364,7 @@ impl ItemRegistry {
                     },
                     stackable: max_stack > 1,
                     max_stack,
+                    durability: row.durability,
                 },
             );
         }
@@ -385,6 +389,8 @@ struct ItemRow {
     content_class: String,
     #[serde(default = "default_item_stack")]
     stack_size: u32,
+    #[serde(default)]
+    durability: u32,
 }
 
 fn default_item_stack() -> u32 {
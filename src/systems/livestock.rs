@@ -3,8 +3,9 @@
 //! data/creatures.csv (92 species) finally gets its loader. This module keeps
 //! deliberately to the PASSIVE half: farm animals that wander near the fields
 //! and yield a renewable product (egg, milk, wool) on walk-up + E, tracked by
-//! the previously-unconsumed Harvestable component. Hostile spawning, combat,
-//! and taming build on this same registry later.
+//! the previously-unconsumed Harvestable component. Hostile spawning and
+//! combat build on this same registry; feeding, breeding, slaughter and
+//! taming live in `systems::husbandry`, stepped from this system's tick.
 //!
 //! Placement comes from data/entities/livestock.ron (which animals, how many,
 //! near which home machine); what an animal yields comes from the creature's
@@ -90,6 +91,11 @@ impl CreatureDef {
         })
     }
 
+    /// Whether the species can be tamed into the herd (domesticable column).
+    pub fn is_domesticable(&self) -> bool {
+        self.domesticable.trim().eq_ignore_ascii_case("true")
+    }
+
     /// Placeholder body-box side length (metres) from the species' mass at
     /// roughly water density: chicken ~0.15, sheep ~0.43, cow ~0.89. Clamped
     /// so a beetle is still visible and a whale still fits on screen.
@@ -353,17 +359,20 @@ impl System for LivestockSystem {
         "LivestockSystem"
     }
 
-    fn tick(&mut self, world: &mut hecs::World, dt: f32, _data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         self.t += dt;
+        crate::systems::husbandry::tick(world, dt, data);
 
         // Regrowth: every Harvestable in the world ages toward ready (animals
         // today; wild berry bushes ride the same pass when they land). Clamped
         // at ready so the float never grows unbounded across long sessions.
-        // Dead animals stop producing (v0.760).
-        for (_e, (h, dead)) in
-            world.query_mut::<(&mut Harvestable, Option<&crate::ecs::components::Dead>)>()
-        {
-            if dead.is_some() {
+        // Dead animals stop producing (v0.760), and so do starving ones.
+        for (_e, (h, dead, hb)) in world.query_mut::<(
+            &mut Harvestable,
+            Option<&crate::ecs::components::Dead>,
+            Option<&crate::ecs::components::Husbandry>,
+        )>() {
+            if dead.is_some() || hb.is_some_and(|hb| hb.hunger >= 1.0) {
                 continue;
             }
             if h.time_since_harvest < h.regrow_time {
@@ -2,31 +2,214 @@
 //! recipes. Each tick advances `progress` by `dt / (days_per_unit * SECONDS_PER_DAY)`.
 //! When progress hits 1.0, `output_count` increments and progress resets.
 //!
-//! For now this is the minimal "factory ticks out widgets over time" loop.
-//! Future expansion (per `data/manufacturing.ron`):
-//!   - assembly chains (multi-stage facilities)
-//!   - quality checks based on operator skill
-//!   - waste byproduct emission (feeds WasteSystem)
+//! Facility chains: a facility whose recipe is listed in
+//! `data/manufacturing.ron` `facility_recipes` works on real goods.
+//!   - Inputs are pulled from typed `Container`s within `ADJACENT_M`, and from
+//!     upstream facilities that hand on what they finish; a unit starts only once
+//!     its inputs are consumed.
+//!   - Outputs go to a downstream facility that wants them first, then into a
+//!     nearby container that takes them (same content or empty, class-compatible).
+//!   - Each finished unit rolls a quality tier (`quality_levels`) from the operator's
+//!     level in the recipe's skill against the stage's `skill_level` (the nearest
+//!     `PlayerSkills` holder within `OPERATOR_RANGE_M`; unattended = level 0).
+//!     A tier that is not sellable is scrapped. A player operator earns XP per unit.
+//!   - Byproducts (`waste_products`) and scrap go to the WasteSystem through
+//!     `emit_waste`.
+//!   - A facility without power, inputs or room for its output stalls and says why
+//!     (`ProductionFacility::stall`).
+//!
+//! A recipe id not in `facility_recipes` keeps the bare "factory ticks out widgets
+//! over time" loop.
 
 use std::path::Path;
 
 use serde::Deserialize;
 
-use crate::ecs::components::ProductionFacility;
+use crate::ecs::components::{Controllable, PowerConsumer, ProductionFacility, Transform};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
+use crate::systems::inventory::containers::{Container, ContainerRegistry, StoreOutcome};
+use crate::systems::inventory::ItemRegistry;
+use crate::systems::skills::{award_skill_xp, PlayerSkills};
+use crate::systems::waste::emit_waste;
 
 /// 1 game day = 1200 real seconds.
 const REAL_SECONDS_PER_GAME_DAY: f32 = 1200.0;
+/// Containers and facilities this close (meters) feed each other.
+const ADJACENT_M: f32 = 3.0;
+/// A crafter this close (meters) counts as the facility's operator.
+const OPERATOR_RANGE_M: f32 = 4.0;
+/// A facility stocks inputs for this many units ahead.
+const INPUT_BUFFER_UNITS: u32 = 2;
+/// A facility holds this many units of output before it stalls as "output full".
+const OUTPUT_BUFFER_UNITS: u32 = 4;
+/// Quality score gained per skill level above the stage's requirement (lost per level below).
+const QUALITY_PER_LEVEL: f32 = 0.06;
+/// Width of the random spread around a unit's expected quality score.
+const QUALITY_SPREAD: f32 = 0.3;
+/// Operator XP per finished unit, when the operator is the player.
+const XP_PER_UNIT: u32 = 5;
+/// Waste category a scrapped unit is dumped as.
+const SCRAP_CATEGORY: &str = "scrap";
+
+/// One stage of production (`production_stages`). Only the fields the system acts on.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProductionStage {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// Skill id the stage leans on (data ids are broad, e.g. "crafting"; a recipe may name its own).
+    #[serde(default)]
+    pub skill_required: String,
+    /// Operator level at which a unit comes out at "standard" quality on average.
+    #[serde(default)]
+    pub skill_level: u32,
+}
+
+/// A quality tier (`quality_levels`): a unit whose score falls in `[threshold_min, threshold_max)`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QualityLevel {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    pub threshold_min: f32,
+    pub threshold_max: f32,
+    #[serde(default = "default_price_multiplier")]
+    pub price_multiplier: f32,
+    /// A tier that cannot be sold is scrapped on the spot.
+    #[serde(default = "default_sellable")]
+    pub sellable: bool,
+}
+
+fn default_price_multiplier() -> f32 {
+    1.0
+}
+
+fn default_sellable() -> bool {
+    true
+}
+
+/// A waste byproduct (`waste_products`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct WasteProduct {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default = "default_waste_kg")]
+    pub weight_kg_per_unit: f32,
+}
+
+fn default_waste_kg() -> f32 {
+    1.0
+}
+
+/// What a facility makes (`facility_recipes`): item ids and counts per unit.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FacilityRecipe {
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+    /// A `production_stages` id: sets the skill requirement.
+    pub stage: String,
+    /// Skill id from `data/skills/skills.csv` the operator is judged on (default: the stage's).
+    #[serde(default)]
+    pub skill: Option<String>,
+    #[serde(default)]
+    pub inputs: Vec<(String, u32)>,
+    #[serde(default)]
+    pub outputs: Vec<(String, u32)>,
+    /// (`waste_products` id, units of it per unit made).
+    #[serde(default)]
+    pub byproducts: Vec<(String, f32)>,
+}
 
 /// Top-level RON schema for `data/manufacturing.ron`.
 /// (Field name is `production_stages` in the data file, not `stages`.)
 #[derive(Debug, Deserialize)]
 pub struct ManufacturingData {
-    #[serde(default)] pub production_stages: Vec<ron::Value>,
+    #[serde(default)] pub production_stages: Vec<ProductionStage>,
     #[serde(default)] pub assembly_lines: Vec<ron::Value>,
-    #[serde(default)] pub quality_levels: Vec<ron::Value>,
-    #[serde(default)] pub waste_products: Vec<ron::Value>,
+    #[serde(default)] pub quality_levels: Vec<QualityLevel>,
+    #[serde(default)] pub waste_products: Vec<WasteProduct>,
+    #[serde(default)] pub facility_recipes: Vec<FacilityRecipe>,
+}
+
+impl ManufacturingData {
+    pub fn recipe(&self, id: &str) -> Option<&FacilityRecipe> {
+        self.facility_recipes.iter().find(|r| r.id == id)
+    }
+
+    pub fn stage(&self, id: &str) -> Option<&ProductionStage> {
+        self.production_stages.iter().find(|s| s.id == id)
+    }
+
+    /// The tier a quality score lands in (the top tier includes a perfect 1.0).
+    pub fn quality_for(&self, score: f32) -> Option<&QualityLevel> {
+        self.quality_levels
+            .iter()
+            .find(|q| score >= q.threshold_min && score < q.threshold_max)
+            .or_else(|| self.quality_levels.iter().max_by(|a, b| a.threshold_max.total_cmp(&b.threshold_max)))
+    }
+}
+
+/// Expected quality score of a unit for an operator at `level` on a stage needing `required`,
+/// spread by `roll` (0..1, 0.5 = the expected score).
+pub fn quality_score(level: u32, required: u32, roll: f32) -> f32 {
+    let margin = level as f32 - required as f32;
+    (0.5 + margin * QUALITY_PER_LEVEL + (roll - 0.5) * QUALITY_SPREAD).clamp(0.0, 1.0)
+}
+
+/// Deterministic 0..1 roll per facility and unit (splitmix64): no RNG state to carry.
+fn roll(entity: hecs::Entity, unit: u32) -> f32 {
+    let mut z = entity.to_bits().get() ^ (unit as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Take up to `qty` of `item` out of a container. Returns how many came out.
+fn take_from(c: &mut Container, item: &str, qty: u32) -> u32 {
+    if c.is_broken() || c.current_content_item.as_deref() != Some(item) {
+        return 0;
+    }
+    let n = qty.min(c.current_qty);
+    if n == 0 {
+        return 0;
+    }
+    let unit_vol = c.used_liters / c.current_qty as f32;
+    c.current_qty -= n;
+    c.used_liters = (c.used_liters - unit_vol * n as f32).max(0.0);
+    if c.current_qty == 0 {
+        c.spill();
+    }
+    n
+}
+
+/// Offer `qty` of `item` to a container. A vessel that would not accept its content class is
+/// skipped rather than damaged (an automated line does not force goods in). Returns how many went in.
+fn offer_to(c: &mut Container, item: &str, qty: u32, items: Option<&ItemRegistry>, containers: Option<&ContainerRegistry>) -> u32 {
+    let class = items.map_or("solid", |r| r.class_for(item));
+    let unit_vol = items.map_or(0.0, |r| r.volume_for(item));
+    if let Some(reg) = containers {
+        if !reg.check(&c.container_type_id, class).is_accepted() {
+            return 0;
+        }
+        return match reg.try_store(c, item, class, unit_vol, qty) {
+            StoreOutcome::Stored { quantity } => quantity,
+            _ => 0,
+        };
+    }
+    if c.is_broken() || c.current_content_item.as_deref().is_some_and(|cur| cur != item) {
+        return 0;
+    }
+    let n = if unit_vol > 0.0 { ((c.remaining_liters() / unit_vol).floor() as u32).min(qty) } else { qty };
+    if n > 0 {
+        c.current_content_item = Some(item.to_string());
+        c.current_qty += n;
+        c.used_liters += unit_vol * n as f32;
+    }
+    n
 }
 
 /// Manages production stages, assembly lines, and quality control.
@@ -51,14 +234,96 @@ impl ManufacturingSystem {
                 assembly_lines: vec![],
                 quality_levels: vec![],
                 waste_products: vec![],
+                facility_recipes: vec![],
             }
         });
         log::info!(
-            "Loaded manufacturing data: {} stages, {} assembly lines",
-            data.production_stages.len(), data.assembly_lines.len()
+            "Loaded manufacturing data: {} stages, {} facility recipes",
+            data.production_stages.len(), data.facility_recipes.len()
         );
         Self { data, lifetime_units_produced: 0 }
     }
+
+    /// Operator level for each facility: the best-placed `PlayerSkills` holder in range, judged on
+    /// the recipe's skill, with that operator and the skill. Facilities without a chain recipe get 0.
+    fn operator_levels(
+        &self,
+        world: &hecs::World,
+        facilities: &[(hecs::Entity, glam::Vec3, ProductionFacility)],
+    ) -> Vec<(u32, Option<(hecs::Entity, String)>)> {
+        let crafters: Vec<(hecs::Entity, glam::Vec3)> = world
+            .query::<(&Transform, &PlayerSkills)>()
+            .iter()
+            .map(|(e, (t, _))| (e, t.position))
+            .collect();
+        facilities
+            .iter()
+            .map(|(_, pos, f)| {
+                let Some(recipe) = self.data.recipe(&f.recipe_id) else { return (0, None) };
+                let skill = recipe
+                    .skill
+                    .clone()
+                    .or_else(|| self.data.stage(&recipe.stage).map(|s| s.skill_required.clone()))
+                    .filter(|s| !s.is_empty());
+                let Some(skill) = skill else { return (0, None) };
+                let operator = crafters
+                    .iter()
+                    .filter(|(_, p)| p.distance(*pos) <= OPERATOR_RANGE_M)
+                    .min_by(|a, b| a.1.distance_squared(*pos).total_cmp(&b.1.distance_squared(*pos)));
+                let level = operator
+                    .and_then(|(e, _)| world.get::<&PlayerSkills>(*e).ok().map(|s| s.level(&skill)))
+                    .unwrap_or(0);
+                (level, operator.map(|(e, _)| (*e, skill)))
+            })
+            .collect()
+    }
+
+    /// Hand finished goods on: downstream facilities that want them first, then containers.
+    fn push_outputs(
+        &self,
+        facilities: &mut [(hecs::Entity, glam::Vec3, ProductionFacility)],
+        containers: &mut [(hecs::Entity, glam::Vec3, Container)],
+        items: Option<&ItemRegistry>,
+        registry: Option<&ContainerRegistry>,
+    ) {
+        for i in 0..facilities.len() {
+            let pos = facilities[i].1;
+            let mut goods: Vec<(String, u32)> =
+                facilities[i].2.output_buffer.iter().filter(|(_, q)| **q > 0).map(|(k, q)| (k.clone(), *q)).collect();
+            goods.sort();
+            for (item, mut left) in goods {
+                for (j, (_, jpos, downstream)) in facilities.iter_mut().enumerate() {
+                    if left == 0 || j == i || jpos.distance(pos) > ADJACENT_M {
+                        continue;
+                    }
+                    let Some(want) = self
+                        .data
+                        .recipe(&downstream.recipe_id)
+                        .and_then(|r| r.inputs.iter().find(|(id, _)| *id == item))
+                        .map(|(_, q)| q * INPUT_BUFFER_UNITS)
+                    else {
+                        continue;
+                    };
+                    let slot = downstream.input_buffer.entry(item.clone()).or_insert(0);
+                    let n = want.saturating_sub(*slot).min(left);
+                    *slot += n;
+                    left -= n;
+                }
+                for (_, cpos, c) in containers.iter_mut() {
+                    if left == 0 || cpos.distance(pos) > ADJACENT_M {
+                        continue;
+                    }
+                    left -= offer_to(c, &item, left, items, registry);
+                }
+                let f = &mut facilities[i].2;
+                if left == 0 {
+                    f.output_buffer.remove(&item);
+                } else {
+                    f.output_buffer.insert(item, left);
+                }
+            }
+        }
+    }
 }
 
 impl System for ManufacturingSystem {
@@ -69,19 +334,154 @@ impl System for ManufacturingSystem {
         // Production timers run on GAME time (v0.663): "accelerated for testing"
         // speeds facilities too. Absent game_time (unit tests) = raw dt.
         let day_fraction = crate::systems::time::scaled_dt(dt, data) / REAL_SECONDS_PER_GAME_DAY;
+        let items = data.get::<ItemRegistry>("item_registry");
+        let registry = data.get::<ContainerRegistry>("container_registry");
 
-        let mut completions: Vec<(hecs::Entity, String, u32)> = Vec::new();
+        let mut facilities: Vec<(hecs::Entity, glam::Vec3, ProductionFacility)> = world
+            .query::<(&ProductionFacility, Option<&Transform>)>()
+            .iter()
+            .map(|(e, (f, t))| (e, t.map_or(glam::Vec3::ZERO, |t| t.position), f.clone()))
+            .collect();
+        if facilities.is_empty() {
+            return;
+        }
+        let powered: Vec<bool> = facilities
+            .iter()
+            .map(|(e, _, _)| world.get::<&PowerConsumer>(*e).ok().is_none_or(|c| c.enabled))
+            .collect();
+        let operators = self.operator_levels(world, &facilities);
+        let mut containers: Vec<(hecs::Entity, glam::Vec3, Container)> = world
+            .query::<(&Container, &Transform)>()
+            .iter()
+            .map(|(e, (c, t))| (e, t.position, c.clone()))
+            .collect();
 
-        for (entity, facility) in world.query_mut::<&mut ProductionFacility>() {
+        self.push_outputs(&mut facilities, &mut containers, items, registry);
+
+        let mut completions: Vec<(hecs::Entity, String, u32)> = Vec::new();
+        for (i, (entity, pos, facility)) in facilities.iter_mut().enumerate() {
             if !facility.running { continue; }
             if facility.days_per_unit <= 0.0 { continue; }
+            let Some(recipe) = self.data.recipe(&facility.recipe_id) else {
+                // No chain recipe: the bare timer.
+                facility.progress += day_fraction / facility.days_per_unit;
+                // Multiple units may complete in a single tick at very high time_scale.
+                while facility.progress >= 1.0 {
+                    facility.progress -= 1.0;
+                    facility.output_count += 1;
+                    completions.push((*entity, facility.recipe_id.clone(), facility.output_count));
+                }
+                continue;
+            };
 
-            facility.progress += day_fraction / facility.days_per_unit;
-            // Multiple units may complete in a single tick at very high time_scale.
-            while facility.progress >= 1.0 {
-                facility.progress -= 1.0;
-                facility.output_count += 1;
-                completions.push((entity, facility.recipe_id.clone(), facility.output_count));
+            // Stock up from containers in reach.
+            for (item, per_unit) in &recipe.inputs {
+                let want = per_unit * INPUT_BUFFER_UNITS;
+                for (_, cpos, c) in containers.iter_mut() {
+                    let have = facility.input_buffer.get(item).copied().unwrap_or(0);
+                    if have >= want {
+                        break;
+                    }
+                    if cpos.distance(*pos) <= ADJACENT_M {
+                        let got = take_from(c, item, want - have);
+                        *facility.input_buffer.entry(item.clone()).or_insert(0) += got;
+                    }
+                }
+            }
+            if !powered[i] {
+                facility.stall = Some("no power".to_string());
+                continue;
+            }
+
+            let (level, operator) = &operators[i];
+            // Only the player trains through the XP channel; an NPC crafter just sets the quality.
+            let skill = operator
+                .as_ref()
+                .filter(|(e, _)| world.get::<&Controllable>(*e).is_ok())
+                .map(|(_, skill)| skill);
+            let required = self.data.stage(&recipe.stage).map_or(0, |s| s.skill_level);
+            let mut budget = day_fraction / facility.days_per_unit;
+            loop {
+                if !facility.loaded {
+                    let full = recipe
+                        .outputs
+                        .iter()
+                        .any(|(id, q)| facility.output_buffer.get(id).copied().unwrap_or(0) >= q * OUTPUT_BUFFER_UNITS);
+                    if full {
+                        facility.stall = Some("output full".to_string());
+                        break;
+                    }
+                    let short: Vec<String> = recipe
+                        .inputs
+                        .iter()
+                        .filter(|(id, q)| facility.input_buffer.get(id).copied().unwrap_or(0) < *q)
+                        .map(|(id, q)| format!("{q}x {id}"))
+                        .collect();
+                    if !short.is_empty() {
+                        facility.stall = Some(format!("waiting for {}", short.join(", ")));
+                        break;
+                    }
+                    for (id, q) in &recipe.inputs {
+                        if let Some(have) = facility.input_buffer.get_mut(id) {
+                            *have -= q;
+                        }
+                    }
+                    facility.input_buffer.retain(|_, q| *q > 0);
+                    facility.loaded = true;
+                }
+                facility.stall = None;
+                let step = budget.min(1.0 - facility.progress);
+                facility.progress += step;
+                budget -= step;
+                if facility.progress < 1.0 {
+                    break;
+                }
+
+                // A unit is done: grade it, hand it on or scrap it, dump the byproducts.
+                facility.progress = 0.0;
+                facility.loaded = false;
+                let unit = facility.output_count + facility.scrapped;
+                let score = quality_score(*level, required, roll(*entity, unit));
+                let tier = self.data.quality_for(score);
+                let tier_id = tier.map_or_else(|| "standard".to_string(), |q| q.id.clone());
+                *facility.quality_counts.entry(tier_id.clone()).or_insert(0) += 1;
+                facility.last_quality = Some(tier_id);
+                if tier.is_none_or(|q| q.sellable) {
+                    for (id, q) in &recipe.outputs {
+                        *facility.output_buffer.entry(id.clone()).or_insert(0) += q;
+                    }
+                    facility.output_count += 1;
+                    completions.push((*entity, facility.recipe_id.clone(), facility.output_count));
+                } else {
+                    facility.scrapped += 1;
+                    let kg: f32 = recipe
+                        .outputs
+                        .iter()
+                        .map(|(id, q)| items.map_or(1.0, |r| r.mass_for(id).max(0.1)) * *q as f32)
+                        .sum();
+                    emit_waste(data, *pos, SCRAP_CATEGORY, kg);
+                }
+                for (waste, units) in &recipe.byproducts {
+                    let per = self.data.waste_products.iter().find(|w| w.id == *waste).map_or(1.0, |w| w.weight_kg_per_unit);
+                    emit_waste(data, *pos, waste, per * units);
+                }
+                if let Some(skill) = skill {
+                    award_skill_xp(data, skill, XP_PER_UNIT);
+                }
+                if budget <= 0.0 {
+                    break;
+                }
+            }
+        }
+
+        for (entity, _, facility) in facilities {
+            if let Ok(mut f) = world.get::<&mut ProductionFacility>(entity) {
+                *f = facility;
+            }
+        }
+        for (entity, _, container) in containers {
+            if let Ok(mut c) = world.get::<&mut Container>(entity) {
+                *c = container;
             }
         }
 
@@ -94,3 +494,204 @@ impl System for ManufacturingSystem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecs::components::WasteAccumulator;
+    use crate::systems::skills::SkillProgress;
+    use crate::systems::waste::{WasteData, WasteEmission, WasteSystem};
+
+    const DATA: &str = r#"(
+        production_stages: [
+            (id: "refining", skill_required: "crafting", skill_level: 3),
+            (id: "component_fabrication", skill_required: "crafting", skill_level: 5),
+        ],
+        quality_levels: [
+            (id: "defective", threshold_min: 0.0, threshold_max: 0.2, sellable: false),
+            (id: "standard", threshold_min: 0.2, threshold_max: 0.8),
+            (id: "excellent", threshold_min: 0.8, threshold_max: 1.0),
+        ],
+        waste_products: [(id: "slag", weight_kg_per_unit: 2.5), (id: "metal_shavings", weight_kg_per_unit: 0.5)],
+        facility_recipes: [
+            (id: "bloomery_iron", stage: "refining", skill: Some("metalworking"),
+             inputs: [("iron_ore_0", 2), ("coal_0", 1)], outputs: [("iron_ingot_0", 1)], byproducts: [("slag", 1.0)]),
+            (id: "forge_hammer", stage: "component_fabrication", skill: Some("metalworking"),
+             inputs: [("iron_ingot_0", 1), ("wood_plank_0", 1)], outputs: [("hammer_0", 1)],
+             byproducts: [("metal_shavings", 0.5)]),
+        ],
+    )"#;
+
+    fn system() -> ManufacturingSystem {
+        ManufacturingSystem { data: ron::from_str(DATA).expect("test data parses"), lifetime_units_produced: 0 }
+    }
+
+    fn at(x: f32, z: f32) -> Transform {
+        Transform { position: glam::Vec3::new(x, 0.0, z), ..Default::default() }
+    }
+
+    fn bin(item: Option<&str>, qty: u32) -> Container {
+        let mut c = Container::new("bin", 1000.0);
+        if let Some(item) = item {
+            c.current_content_item = Some(item.to_string());
+            c.current_qty = qty;
+        }
+        c
+    }
+
+    fn facility(recipe: &str) -> ProductionFacility {
+        ProductionFacility { recipe_id: recipe.to_string(), days_per_unit: 0.1, ..Default::default() }
+    }
+
+    fn channel() -> DataStore {
+        let mut data = DataStore::new();
+        data.insert("waste_emissions", std::sync::Mutex::new(Vec::<WasteEmission>::new()));
+        data
+    }
+
+    #[test]
+    fn shipped_recipes_name_real_stages_and_waste() {
+        let text = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/data/manufacturing.ron")).unwrap();
+        let data: ManufacturingData = ron::from_str(&text).expect("manufacturing.ron parses");
+        assert!(data.quality_levels.iter().any(|q| !q.sellable), "a scrap tier exists");
+        assert!(!data.facility_recipes.is_empty());
+        for r in &data.facility_recipes {
+            assert!(data.stage(&r.stage).is_some(), "{}: unknown stage {}", r.id, r.stage);
+            for (w, _) in &r.byproducts {
+                assert!(data.waste_products.iter().any(|p| p.id == *w), "{}: unknown waste {w}", r.id);
+            }
+        }
+    }
+
+    #[test]
+    fn a_two_stage_chain_turns_ore_into_hammers_and_dumps_its_waste() {
+        let mut world = hecs::World::new();
+        let bloomery = world.spawn((at(0.0, 0.0), facility("bloomery_iron")));
+        let forge = world.spawn((at(2.0, 0.0), facility("forge_hammer")));
+        world.spawn((at(-1.0, 0.0), bin(Some("iron_ore_0"), 100)));
+        world.spawn((at(-1.0, 1.0), bin(Some("coal_0"), 50)));
+        world.spawn((at(3.0, 0.0), bin(Some("wood_plank_0"), 50)));
+        let out = world.spawn((at(4.0, 0.0), bin(None, 0)));
+        let dump = world.spawn((at(1.0, 0.0), WasteAccumulator { capacity: 1000.0, ..Default::default() }));
+        let data = channel();
+        let mut mfg = system();
+        let mut waste = WasteSystem { data: WasteData { waste_categories: vec![], recycling: vec![], pollution: vec![] }, atmospheric_pollution: 0.0 };
+        for _ in 0..30 {
+            mfg.tick(&mut world, 120.0, &data);
+            waste.tick(&mut world, 120.0, &data);
+        }
+
+        let b = ProductionFacility::clone(&world.get::<&ProductionFacility>(bloomery).unwrap());
+        let f = ProductionFacility::clone(&world.get::<&ProductionFacility>(forge).unwrap());
+        assert!(b.output_count >= 10, "the bloomery kept working: {b:?}");
+        assert_eq!(b.output_count + b.scrapped, b.quality_counts.values().sum::<u32>(), "every unit graded");
+        assert!(f.output_count > 0, "ingots reached the forge without a container between: {f:?}");
+        let hammers = Container::clone(&world.get::<&Container>(out).unwrap());
+        assert_eq!(hammers.current_content_item.as_deref(), Some("hammer_0"));
+        assert!(hammers.current_qty > 0 && hammers.current_qty <= f.output_count);
+        let acc = WasteAccumulator::clone(&world.get::<&WasteAccumulator>(dump).unwrap());
+        let slag = acc.by_category.get("slag").copied().unwrap_or(0.0);
+        assert!((slag - 2.5 * (b.output_count + b.scrapped) as f32).abs() < 1e-3, "slag per bloom: {slag}");
+        assert!(acc.by_category.get("metal_shavings").copied().unwrap_or(0.0) > 0.0);
+        assert_eq!(mfg.lifetime_units_produced, (b.output_count + f.output_count) as u64);
+    }
+
+    #[test]
+    fn a_facility_says_why_it_stalls() {
+        let mut world = hecs::World::new();
+        let starved = world.spawn((at(0.0, 0.0), facility("bloomery_iron")));
+        let dark = world.spawn((
+            at(20.0, 0.0),
+            facility("forge_hammer"),
+            PowerConsumer { draw_watts: 1500.0, priority: 3, enabled: false },
+        ));
+        let data = channel();
+        system().tick(&mut world, 120.0, &data);
+        assert_eq!(
+            world.get::<&ProductionFacility>(starved).unwrap().stall.as_deref(),
+            Some("waiting for 2x iron_ore_0, 1x coal_0")
+        );
+        assert_eq!(world.get::<&ProductionFacility>(dark).unwrap().stall.as_deref(), Some("no power"));
+
+        // Nowhere for the output to go: it fills its own buffer and stops.
+        let mut world = hecs::World::new();
+        let full = world.spawn((at(0.0, 0.0), facility("bloomery_iron")));
+        world.spawn((at(-1.0, 0.0), bin(Some("iron_ore_0"), 100)));
+        world.spawn((at(0.0, 1.0), bin(Some("coal_0"), 100)));
+        let mut mfg = system();
+        for _ in 0..20 {
+            mfg.tick(&mut world, 120.0, &data);
+        }
+        let f = ProductionFacility::clone(&world.get::<&ProductionFacility>(full).unwrap());
+        assert_eq!(f.stall.as_deref(), Some("output full"));
+        assert_eq!(f.output_buffer.get("iron_ingot_0"), Some(&4));
+    }
+
+    #[test]
+    fn a_skilled_operator_makes_better_goods() {
+        let data = system().data;
+        for roll in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let master = quality_score(10, 3, roll);
+            let novice = quality_score(0, 3, roll);
+            assert!(master > novice + 0.4, "ten levels of margin: {master} vs {novice}");
+        }
+        assert_eq!(data.quality_for(quality_score(10, 3, 0.5)).unwrap().id, "excellent");
+        assert_eq!(data.quality_for(quality_score(0, 5, 0.0)).unwrap().id, "defective");
+        assert_eq!(data.quality_for(1.0).unwrap().id, "excellent", "a perfect unit is top tier");
+
+        let run = |operator: Option<u32>| {
+            let mut world = hecs::World::new();
+            let f = world.spawn((at(0.0, 0.0), facility("bloomery_iron")));
+            world.spawn((at(-1.0, 0.0), bin(Some("iron_ore_0"), 1000)));
+            world.spawn((at(0.0, 1.0), bin(Some("coal_0"), 1000)));
+            world.spawn((at(1.0, 0.0), bin(None, 0)));
+            if let Some(level) = operator {
+                let mut skills = PlayerSkills::new();
+                skills.skills.insert("metalworking".to_string(), SkillProgress { level, xp: 0 });
+                world.spawn((at(0.5, 0.5), skills));
+            }
+            let mut mfg = system();
+            let data = channel();
+            for _ in 0..60 {
+                mfg.tick(&mut world, 120.0, &data);
+            }
+            let done = ProductionFacility::clone(&world.get::<&ProductionFacility>(f).unwrap());
+            done
+        };
+        let alone = run(None);
+        let master = run(Some(10));
+        assert!(alone.scrapped > 0, "unattended, some blooms are scrap: {alone:?}");
+        assert_eq!(master.scrapped, 0, "a master wastes none: {master:?}");
+        assert!(master.quality_counts.get("excellent").copied().unwrap_or(0) > 0);
+        assert!(alone.quality_counts.get("excellent").is_none());
+    }
+
+    /// An NPC crafter sets the quality but earns nothing; the player's units train them.
+    #[test]
+    fn only_a_player_operator_earns_xp() {
+        for player in [false, true] {
+            let mut world = hecs::World::new();
+            world.spawn((at(0.0, 0.0), facility("bloomery_iron")));
+            world.spawn((at(-1.0, 0.0), bin(Some("iron_ore_0"), 1000)));
+            world.spawn((at(0.0, 1.0), bin(Some("coal_0"), 1000)));
+            world.spawn((at(1.0, 0.0), bin(None, 0)));
+            let crafter = world.spawn((at(0.5, 0.5), PlayerSkills::new()));
+            if player {
+                world.insert_one(crafter, Controllable).unwrap();
+            }
+            let mut data = channel();
+            data.insert("xp_grants", std::sync::Mutex::new(Vec::<crate::systems::skills::SkillXPEvent>::new()));
+            let mut mfg = system();
+            for _ in 0..10 {
+                mfg.tick(&mut world, 120.0, &data);
+            }
+            let grants = data.get::<std::sync::Mutex<Vec<crate::systems::skills::SkillXPEvent>>>("xp_grants").unwrap();
+            let grants = grants.lock().unwrap();
+            if player {
+                assert!(!grants.is_empty() && grants.iter().all(|g| g.skill_id == "metalworking"));
+            } else {
+                assert!(grants.is_empty(), "{} grants to an NPC", grants.len());
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@ pub mod quests;
 pub mod crafting;
 pub mod vehicles;
 pub mod livestock;
+pub mod husbandry;
 pub mod abilities;
 pub mod ai;
 pub mod skills;
@@ -27,9 +28,11 @@ pub mod atmosphere;
 pub mod body_environment;
 pub mod disasters;
 pub mod electrical;
+pub mod power_grid;
 pub mod plumbing;
 pub mod solar;
 pub mod hvac;
+pub mod thermal;
 pub mod fire;
 pub mod medical;
 pub mod status_effects;
@@ -47,12 +50,26 @@ pub mod waste;
 pub mod genetics;
 pub mod transportation;
 pub mod offline;
+pub mod catch_up;
 pub mod self_sufficiency;
 
+/// One-shot SFX request on the `"sfx_events"` channel: a catalog id, the
+/// fallback path if the catalog lacks it, and where it happened. Catalog
+/// entries marked `spatial` are mixed relative to the listener
+/// (`audio::spatial`); a `None` position (or a non-spatial entry) plays flat.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SfxEvent {
+    pub id: String,
+    pub fallback: String,
+    pub position: Option<glam::Vec3>,
+    /// Source velocity for doppler (moving vehicles); zero otherwise.
+    pub velocity: glam::Vec3,
+}
+
 /// Push a one-shot SFX request onto the shared `"sfx_events"` DataStore
 /// channel (v0.985): ECS systems (construction, crafting) have no engine
-/// state, so they emit (catalog id, fallback path) pairs here; the native
-/// client's audio frame-sync drains the channel alongside
+/// state, so they emit (catalog id, fallback path, world position) here; the
+/// native client's audio frame-sync drains the channel alongside
 /// `EngineState::pending_sfx`. Lives HERE (not in the native-gated audio
 /// module) so relay builds compile: on a headless relay the channel is
 /// simply never registered and this no-ops - the same degradation contract
@@ -61,10 +78,27 @@ pub fn push_sfx_event(
     data: &crate::hot_reload::data_store::DataStore,
     id: &str,
     fallback: &str,
+    position: Option<glam::Vec3>,
+) {
+    push_moving_sfx_event(data, id, fallback, position, glam::Vec3::ZERO);
+}
+
+/// `push_sfx_event` for a moving source: `velocity` drives the doppler shift.
+pub fn push_moving_sfx_event(
+    data: &crate::hot_reload::data_store::DataStore,
+    id: &str,
+    fallback: &str,
+    position: Option<glam::Vec3>,
+    velocity: glam::Vec3,
 ) {
-    if let Some(lock) = data.get::<std::sync::Mutex<Vec<(String, String)>>>("sfx_events") {
+    if let Some(lock) = data.get::<std::sync::Mutex<Vec<SfxEvent>>>("sfx_events") {
         if let Ok(mut events) = lock.lock() {
-            events.push((id.to_string(), fallback.to_string()));
+            events.push(SfxEvent {
+                id: id.to_string(),
+                fallback: fallback.to_string(),
+                position,
+                velocity,
+            });
         }
     }
 }
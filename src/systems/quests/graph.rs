@@ -0,0 +1,327 @@
+//! Quest graph — what gates a quest (completed quests, branches taken,
+//! faction standing, skill levels), the mutually exclusive branches a quest
+//! can fork into, and how often it may be taken (once, repeatable, daily).
+//!
+//! A quest's `steps` are its trunk. When the trunk is done and the quest has
+//! `branches`, it waits for the player to pick one in dialogue
+//! (`QuestEvent::Chose`); the chosen branch's steps then run and its rewards
+//! are granted on top of the quest's own. Follow-up quests can require a
+//! specific branch (`BranchTaken`), so one choice opens one path and closes
+//! the others.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{QuestDef, QuestRefs, QuestStep, QuestTracker};
+use crate::systems::skills::PlayerSkills;
+
+/// One gate on accepting a quest. All of a quest's requirements must hold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuestRequirement {
+    /// Another quest has been completed (by any branch).
+    QuestCompleted { quest_id: String },
+    /// Another quest was completed through this particular branch.
+    BranchTaken { quest_id: String, branch_id: String },
+    /// Standing with a faction (data/factions.ron id) is at least `min`.
+    FactionStanding { faction_id: String, min: i32 },
+    /// The player has reached `level` in a skill.
+    SkillLevel { skill_id: String, level: u32 },
+}
+
+impl QuestRequirement {
+    /// Whether this gate is open for `tracker` (+ the player's skills).
+    pub fn is_met(&self, tracker: &QuestTracker, skills: Option<&PlayerSkills>) -> bool {
+        match self {
+            Self::QuestCompleted { quest_id } => tracker.is_completed(quest_id),
+            Self::BranchTaken { quest_id, branch_id } => {
+                tracker.branch_taken(quest_id) == Some(branch_id.as_str())
+            }
+            Self::FactionStanding { faction_id, min } => tracker.standing(faction_id) >= *min,
+            Self::SkillLevel { skill_id, level } => skills.is_some_and(|s| s.level(skill_id) >= *level),
+        }
+    }
+
+    /// The quest this requirement waits on, if any (completing it may
+    /// unlock the dependent quest).
+    pub fn quest_id(&self) -> Option<&str> {
+        match self {
+            Self::QuestCompleted { quest_id } | Self::BranchTaken { quest_id, .. } => Some(quest_id),
+            Self::FactionStanding { .. } | Self::SkillLevel { .. } => None,
+        }
+    }
+}
+
+/// One of a quest's mutually exclusive continuations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestBranch {
+    /// Unique within the quest (e.g., "side_with_council").
+    pub id: String,
+    /// The dialogue choice text offered for this branch.
+    pub name: String,
+    /// Steps run after the quest's own steps once this branch is chosen.
+    pub steps: Vec<QuestStep>,
+    /// Extra item rewards for finishing through this branch.
+    #[serde(default)]
+    pub rewards: Vec<(String, u32)>,
+    /// Extra skill XP for finishing through this branch.
+    #[serde(default)]
+    pub xp_rewards: Vec<(String, u32)>,
+    /// Faction standing changes for finishing through this branch.
+    #[serde(default)]
+    pub standing_rewards: Vec<(String, i32)>,
+}
+
+/// How often a quest can be taken.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuestRepeat {
+    /// Once per save (the historical behaviour).
+    #[default]
+    Once,
+    /// Can be accepted again as soon as it is completed.
+    Repeatable,
+    /// Can be accepted again on the next in-game day after completion.
+    Daily,
+}
+
+impl QuestDef {
+    /// The branch with this id.
+    pub fn branch(&self, branch_id: &str) -> Option<&QuestBranch> {
+        self.branches.iter().find(|b| b.id == branch_id)
+    }
+
+    /// Step `index` of the quest as played through `branch`: the trunk
+    /// first, then the branch's steps. None past the end, and at the fork
+    /// while no branch is chosen.
+    pub fn step_at(&self, branch: Option<&str>, index: usize) -> Option<&QuestStep> {
+        match self.steps.get(index) {
+            Some(step) => Some(step),
+            None => branch
+                .and_then(|b| self.branch(b))
+                .and_then(|b| b.steps.get(index - self.steps.len())),
+        }
+    }
+
+    /// Total steps along `branch` (the trunk alone when unchosen).
+    pub fn step_count(&self, branch: Option<&str>) -> usize {
+        self.steps.len() + branch.and_then(|b| self.branch(b)).map_or(0, |b| b.steps.len())
+    }
+
+    /// Whether a quest at step `index` is waiting on a dialogue choice.
+    pub fn awaits_choice(&self, branch: Option<&str>, index: usize) -> bool {
+        !self.branches.is_empty() && branch.is_none() && index >= self.steps.len()
+    }
+
+    /// Whether `tracker` may accept this quest now: not already active, the
+    /// legacy prerequisite and every requirement met, and the repeat rule
+    /// allows it (`day` is the in-game day, for Daily quests).
+    pub fn is_available(&self, tracker: &QuestTracker, skills: Option<&PlayerSkills>, day: Option<u32>) -> bool {
+        if tracker.is_active(&self.id) {
+            return false;
+        }
+        let repeat_ok = match self.repeat {
+            QuestRepeat::Once => !tracker.is_completed(&self.id),
+            QuestRepeat::Repeatable => true,
+            QuestRepeat::Daily => match (tracker.last_completed_day.get(&self.id), day) {
+                (None, _) => true,
+                (Some(done), Some(today)) => today > *done,
+                (Some(_), None) => false,
+            },
+        };
+        repeat_ok
+            && self.prerequisite.as_deref().is_none_or(|p| tracker.is_completed(p))
+            && self.requires.iter().all(|r| r.is_met(tracker, skills))
+    }
+
+    /// Whether completing `quest_id` is one of this quest's gates (its
+    /// prerequisite or a requirement), so that completion should offer it.
+    pub fn follows(&self, quest_id: &str) -> bool {
+        self.prerequisite.as_deref() == Some(quest_id)
+            || self.requires.iter().any(|r| r.quest_id() == Some(quest_id))
+    }
+
+    /// Graph problems with this quest against the whole quest set: branch
+    /// ids repeated, requirements naming unknown quests / branches / skills /
+    /// factions, and branch steps or rewards naming unknown ids.
+    pub(super) fn graph_problems(
+        &self,
+        quests: &std::collections::HashMap<String, QuestDef>,
+        refs: &QuestRefs,
+    ) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut seen = HashSet::new();
+        for b in &self.branches {
+            if !seen.insert(b.id.as_str()) {
+                problems.push(format!("branch '{}' defined twice", b.id));
+            }
+            for (i, step) in b.steps.iter().enumerate() {
+                for bad in step.objective.unknown_refs(refs) {
+                    problems.push(format!("branch '{}' step {}: {bad}", b.id, i + 1));
+                }
+            }
+            for (item_id, _) in &b.rewards {
+                if refs.items.as_ref().is_some_and(|s| !s.contains(item_id)) {
+                    problems.push(format!("branch '{}' reward: unknown item '{item_id}'", b.id));
+                }
+            }
+        }
+        let unknown_faction = |f: &String| refs.factions.as_ref().is_some_and(|s| !s.contains(f));
+        for (faction_id, _) in self
+            .standing_rewards
+            .iter()
+            .chain(self.branches.iter().flat_map(|b| &b.standing_rewards))
+        {
+            if unknown_faction(faction_id) {
+                problems.push(format!("standing reward: unknown faction '{faction_id}'"));
+            }
+        }
+        for r in &self.requires {
+            match r {
+                QuestRequirement::QuestCompleted { quest_id } if !quests.contains_key(quest_id) => {
+                    problems.push(format!("requires unknown quest '{quest_id}'"));
+                }
+                QuestRequirement::BranchTaken { quest_id, branch_id } => {
+                    match quests.get(quest_id) {
+                        None => problems.push(format!("requires unknown quest '{quest_id}'")),
+                        Some(q) if q.branch(branch_id).is_none() => problems
+                            .push(format!("requires unknown branch '{branch_id}' of '{quest_id}'")),
+                        Some(_) => {}
+                    }
+                }
+                QuestRequirement::FactionStanding { faction_id, .. } if unknown_faction(faction_id) => {
+                    problems.push(format!("requires unknown faction '{faction_id}'"));
+                }
+                QuestRequirement::SkillLevel { skill_id, .. }
+                    if refs.skills.as_ref().is_some_and(|s| !s.contains(skill_id)) =>
+                {
+                    problems.push(format!("requires unknown skill '{skill_id}'"));
+                }
+                _ => {}
+            }
+        }
+        problems
+    }
+}
+
+/// A dialogue choice the player can make right now: an active quest at its
+/// fork, offered by `npc_id` (the quest's `choice_npc`; empty = any NPC).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PendingChoice {
+    pub quest_id: String,
+    pub npc_id: String,
+    /// (branch id, choice text), in file order.
+    pub options: Vec<(String, String)>,
+}
+
+/// Every fork the tracker's active quests are waiting on.
+pub fn pending_choices(quests: &super::QuestRegistry, tracker: &QuestTracker) -> Vec<PendingChoice> {
+    tracker
+        .active_quests
+        .iter()
+        .filter_map(|a| {
+            let def = quests.get(&a.quest_id)?;
+            def.awaits_choice(a.branch.as_deref(), a.current_step).then(|| PendingChoice {
+                quest_id: def.id.clone(),
+                npc_id: def.choice_npc.clone().unwrap_or_default(),
+                options: def.branches.iter().map(|b| (b.id.clone(), b.name.clone())).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Ids of a RON list of `(id: ...)` definitions (factions.ron and the like);
+/// every other field is ignored.
+pub fn ids_from_ron(text: &str) -> Result<HashSet<String>, String> {
+    #[derive(Deserialize)]
+    struct Row {
+        id: String,
+    }
+    let rows: Vec<Row> = ron::from_str(text).map_err(|e| e.to_string())?;
+    Ok(rows.into_iter().map(|r| r.id).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::quests::QuestObjective;
+
+    fn forked() -> QuestDef {
+        ron::from_str(
+            r#"(
+                id: "q_fork", name: "Fork", description: "",
+                steps: [(description: "", objective: Talk(npc_id: "elder"))],
+                rewards: [],
+                prerequisite: None,
+                branches: [
+                    (id: "council", name: "Side with the council", steps: [(description: "", objective: Talk(npc_id: "mayor"))]),
+                    (id: "rebels", name: "Side with the rebels", steps: []),
+                ],
+                choice_npc: Some("elder"),
+            )"#,
+        )
+        .expect("branching quest parses with the old fields plus the new")
+    }
+
+    #[test]
+    fn steps_run_trunk_then_the_chosen_branch() {
+        let q = forked();
+        assert_eq!(q.step_count(None), 1);
+        assert!(q.awaits_choice(None, 1));
+        assert!(q.step_at(None, 1).is_none(), "nothing past the fork until a choice");
+        assert_eq!(q.step_count(Some("council")), 2);
+        assert!(!q.awaits_choice(Some("council"), 1));
+        assert!(matches!(
+            &q.step_at(Some("council"), 1).unwrap().objective,
+            QuestObjective::Talk { npc_id } if npc_id == "mayor"
+        ));
+        assert_eq!(q.step_count(Some("rebels")), 1);
+    }
+
+    #[test]
+    fn requirements_gate_availability() {
+        let mut q = forked();
+        q.requires = vec![
+            QuestRequirement::BranchTaken { quest_id: "q_prev".into(), branch_id: "a".into() },
+            QuestRequirement::FactionStanding { faction_id: "nova_corp".into(), min: 10 },
+        ];
+        let mut t = QuestTracker::default();
+        assert!(!q.is_available(&t, None, Some(1)));
+        t.completed_quests.push("q_prev".into());
+        t.branches_taken.insert("q_prev".into(), "b".into());
+        t.faction_standing.insert("nova_corp".into(), 10);
+        assert!(!q.is_available(&t, None, Some(1)), "the other branch was taken");
+        t.branches_taken.insert("q_prev".into(), "a".into());
+        assert!(q.is_available(&t, None, Some(1)));
+        assert!(q.follows("q_prev"));
+
+        q.requires = vec![QuestRequirement::SkillLevel { skill_id: "farming".into(), level: 3 }];
+        assert!(!q.is_available(&t, None, Some(1)), "no skills component: skill gates stay shut");
+    }
+
+    #[test]
+    fn repeat_rules() {
+        let mut q = forked();
+        let mut t = QuestTracker::default();
+        t.completed_quests.push(q.id.clone());
+        t.last_completed_day.insert(q.id.clone(), 4);
+        assert!(!q.is_available(&t, None, Some(4)), "Once: done is done");
+        q.repeat = QuestRepeat::Repeatable;
+        assert!(q.is_available(&t, None, Some(4)));
+        q.repeat = QuestRepeat::Daily;
+        assert!(!q.is_available(&t, None, Some(4)), "Daily: not again today");
+        assert!(q.is_available(&t, None, Some(5)));
+    }
+
+    #[test]
+    fn graph_validation_catches_dangling_edges() {
+        let mut q = forked();
+        q.requires = vec![
+            QuestRequirement::BranchTaken { quest_id: "q_fork".into(), branch_id: "nobody".into() },
+            QuestRequirement::QuestCompleted { quest_id: "q_missing".into() },
+            QuestRequirement::FactionStanding { faction_id: "pirates".into(), min: 0 },
+        ];
+        let quests = [(q.id.clone(), q.clone())].into_iter().collect();
+        let refs = QuestRefs { factions: Some(["nova_corp".to_string()].into()), ..Default::default() };
+        assert_eq!(q.graph_problems(&quests, &refs).len(), 3);
+    }
+}
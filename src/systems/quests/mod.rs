@@ -3,7 +3,20 @@
 //! Quest definitions live in `data/quests/*.ron` and are deserialized into `QuestDef`.
 //! The `QuestSystem` checks active quest objectives each tick, advances steps when
 //! objectives are met, and awards item rewards on completion.
+//!
+//! Progress arrives as typed `QuestEvent`s (crafted, harvested, built, arrived,
+//! talked, killed) on the `"quest_events"` channel; Gather, ReachSkill and
+//! Survive objectives read live state instead. Quest files are validated at
+//! load against the item / recipe / crop / blueprint / creature / skill / NPC
+//! data, and a quest naming an unknown id is rejected (`reject_invalid`).
+//!
+//! Quests form a graph (`graph`): requirements on other quests, branches
+//! taken, faction standing and skill levels; mutually exclusive branches
+//! picked in dialogue; timed steps that fail the quest; and repeatable or
+//! daily quests. The tracker records branch, failure and standing state so
+//! it all survives a save round-trip.
 
+pub mod graph;
 pub mod objectives;
 
 use std::collections::HashMap;
@@ -13,8 +26,10 @@ use serde::{Deserialize, Serialize};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
 use crate::systems::inventory::Inventory;
+use crate::systems::skills::PlayerSkills;
 
-pub use objectives::{QuestObjective, QuestStep};
+pub use graph::{pending_choices, PendingChoice, QuestBranch, QuestRepeat, QuestRequirement};
+pub use objectives::{QuestEvent, QuestObjective, QuestRefs, QuestStep};
 
 // ── Quest definition (deserialized from RON) ────────────────
 
@@ -38,6 +53,49 @@ pub struct QuestDef {
     pub xp_rewards: Vec<(String, u32)>,
     /// Quest ID that must be completed before this quest can be accepted.
     pub prerequisite: Option<String>,
+    /// Further gates, all of which must hold (quest completed, branch
+    /// taken, faction standing, skill level). The graph's edges.
+    #[serde(default)]
+    pub requires: Vec<QuestRequirement>,
+    /// Mutually exclusive continuations, chosen in dialogue once `steps`
+    /// are done. Empty = a linear quest.
+    #[serde(default)]
+    pub branches: Vec<QuestBranch>,
+    /// `npc_talk_key` of the NPC who offers the branch choice; None = any.
+    #[serde(default)]
+    pub choice_npc: Option<String>,
+    /// Once (default), Repeatable or Daily.
+    #[serde(default)]
+    pub repeat: QuestRepeat,
+    /// Faction standing changes on completion: (faction_id, delta).
+    #[serde(default)]
+    pub standing_rewards: Vec<(String, i32)>,
+}
+
+impl QuestDef {
+    /// Everything completing this quest through `branch` grants: items,
+    /// skill XP and faction standing (the quest's own plus the branch's).
+    pub fn completion_rewards(&self, branch: Option<&str>) -> QuestRewards {
+        let mut out = QuestRewards {
+            items: self.rewards.clone(),
+            xp: self.xp_rewards.clone(),
+            standing: self.standing_rewards.clone(),
+        };
+        if let Some(b) = branch.and_then(|b| self.branch(b)) {
+            out.items.extend(b.rewards.iter().cloned());
+            out.xp.extend(b.xp_rewards.iter().cloned());
+            out.standing.extend(b.standing_rewards.iter().cloned());
+        }
+        out
+    }
+}
+
+/// What one quest completion grants.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuestRewards {
+    pub items: Vec<(String, u32)>,
+    pub xp: Vec<(String, u32)>,
+    pub standing: Vec<(String, i32)>,
 }
 
 /// Registry of all quest definitions, keyed by quest ID.
@@ -59,23 +117,14 @@ impl QuestRegistry {
     /// CSV registries). This is the constructor the runtime calls to populate
     /// `DataStore["quest_registry"]`; without it QuestSystem finds no quests.
     pub fn from_ron_dir(dir: &std::path::Path) -> Self {
-        let mut quests = HashMap::new();
+        let mut files = Vec::new();
         match std::fs::read_dir(dir) {
             Ok(entries) => {
                 for entry in entries.flatten() {
                     let path = entry.path();
                     if path.extension().map(|x| x == "ron").unwrap_or(false) {
                         match std::fs::read_to_string(&path) {
-                            Ok(text) => match ron::from_str::<Vec<QuestDef>>(&text) {
-                                Ok(defs) => {
-                                    for def in defs {
-                                        quests.insert(def.id.clone(), def);
-                                    }
-                                }
-                                Err(e) => {
-                                    log::warn!("Quest file {} parse error: {e}", path.display())
-                                }
-                            },
+                            Ok(text) => files.push((path.display().to_string(), text)),
                             Err(e) => {
                                 log::warn!("Quest file {} read error: {e}", path.display())
                             }
@@ -85,9 +134,155 @@ impl QuestRegistry {
             }
             Err(e) => log::warn!("Quests dir {} unreadable: {e}", dir.display()),
         }
+        Self::from_ron_files(files)
+    }
+
+    /// Build from already-read `(name, text)` quest files (the mod-merged
+    /// `quests/*.ron` set at startup). Later files win duplicate ids.
+    pub fn from_ron_files(files: impl IntoIterator<Item = (String, String)>) -> Self {
+        let mut quests = HashMap::new();
+        for (name, text) in files {
+            match ron::from_str::<Vec<QuestDef>>(&text) {
+                Ok(defs) => {
+                    for def in defs {
+                        quests.insert(def.id.clone(), def);
+                    }
+                }
+                Err(e) => log::warn!("Quest file {name} parse error: {e}"),
+            }
+        }
         log::info!("Loaded {} quest definitions", quests.len());
         Self { quests }
     }
+
+    /// Every problem with every quest, as (quest id, message): objectives
+    /// or rewards naming ids `refs` does not know, and prerequisites naming
+    /// quests that do not exist. Sorted for stable logs.
+    pub fn validate(&self, refs: &QuestRefs) -> Vec<(String, String)> {
+        let mut problems = Vec::new();
+        for q in self.quests.values() {
+            for (i, step) in q.steps.iter().enumerate() {
+                for bad in step.objective.unknown_refs(refs) {
+                    problems.push((q.id.clone(), format!("step {}: {bad}", i + 1)));
+                }
+            }
+            for (item_id, _) in &q.rewards {
+                if refs.items.as_ref().is_some_and(|s| !s.contains(item_id)) {
+                    problems.push((q.id.clone(), format!("reward: unknown item '{item_id}'")));
+                }
+            }
+            for (skill_id, _) in &q.xp_rewards {
+                if refs.skills.as_ref().is_some_and(|s| !s.contains(skill_id)) {
+                    problems.push((q.id.clone(), format!("xp reward: unknown skill '{skill_id}'")));
+                }
+            }
+            if let Some(pre) = &q.prerequisite {
+                if !self.quests.contains_key(pre) {
+                    problems.push((q.id.clone(), format!("unknown prerequisite quest '{pre}'")));
+                }
+            }
+            for problem in q.graph_problems(&self.quests, refs) {
+                problems.push((q.id.clone(), problem));
+            }
+        }
+        problems.sort();
+        problems
+    }
+
+    /// Accept `quest_id` for `tracker` if it is available to them now (see
+    /// `QuestDef::is_available`). Returns whether it was accepted.
+    pub fn accept(
+        &self,
+        tracker: &mut QuestTracker,
+        quest_id: &str,
+        skills: Option<&PlayerSkills>,
+        day: Option<u32>,
+    ) -> bool {
+        let ok = self.get(quest_id).is_some_and(|q| q.is_available(tracker, skills, day));
+        if ok {
+            tracker.start_quest(quest_id);
+        }
+        ok
+    }
+
+    /// Quests `tracker` could accept right now, sorted by name.
+    pub fn available<'a>(
+        &'a self,
+        tracker: &QuestTracker,
+        skills: Option<&PlayerSkills>,
+        day: Option<u32>,
+    ) -> Vec<&'a QuestDef> {
+        let mut out: Vec<&QuestDef> =
+            self.quests.values().filter(|q| q.is_available(tracker, skills, day)).collect();
+        out.sort_by(|a, b| a.name.cmp(&b.name));
+        out
+    }
+
+    /// Quests that follow `quest_id` in the graph (name it as their
+    /// prerequisite or a requirement), sorted by id. Whether each is
+    /// available yet is the caller's `QuestDef::is_available` check.
+    pub fn successors(&self, quest_id: &str) -> Vec<&QuestDef> {
+        let mut out: Vec<&QuestDef> = self.quests.values().filter(|q| q.follows(quest_id)).collect();
+        out.sort_by(|a, b| a.id.cmp(&b.id));
+        out
+    }
+
+    /// Drop every quest `validate` finds a problem with (logging each one)
+    /// and return the rejected ids. A quest that names a nonexistent thing
+    /// could never complete, so it is better absent than stuck.
+    pub fn reject_invalid(&mut self, refs: &QuestRefs) -> Vec<String> {
+        let mut rejected: Vec<String> = Vec::new();
+        for (id, problem) in self.validate(refs) {
+            log::warn!("Quest '{id}' rejected: {problem}");
+            if !rejected.contains(&id) {
+                rejected.push(id);
+            }
+        }
+        for id in &rejected {
+            self.quests.remove(id);
+        }
+        rejected
+    }
+}
+
+impl QuestRefs {
+    /// Id sets from the registries already in the DataStore (each category
+    /// whose registry is absent stays unchecked), plus the NPC talk keys
+    /// and faction ids read from their RON files.
+    pub fn from_data(
+        data: &DataStore,
+        npcs: Option<std::collections::HashSet<String>>,
+        factions: Option<std::collections::HashSet<String>>,
+    ) -> Self {
+        fn ids<'a>(keys: impl Iterator<Item = &'a String>) -> Option<std::collections::HashSet<String>> {
+            Some(keys.cloned().collect())
+        }
+        Self {
+            items: data
+                .get::<crate::systems::inventory::ItemRegistry>("item_registry")
+                .and_then(|r| ids(r.items.keys())),
+            recipes: data
+                .get::<crate::systems::crafting::RecipeRegistry>("recipe_registry")
+                .and_then(|r| ids(r.recipes.keys())),
+            crops: data
+                .get::<crate::systems::farming::PlantRegistry>("plant_registry")
+                .and_then(|r| ids(r.plants.keys())),
+            blueprints: data
+                .get::<crate::systems::construction::BlueprintRegistry>("blueprint_registry")
+                .and_then(|r| ids(r.blueprints.keys())),
+            destinations: data
+                .get::<DestinationList>("quest_destinations")
+                .and_then(|d| ids(d.destinations.iter().map(|d| &d.id))),
+            creatures: data
+                .get::<crate::systems::livestock::CreatureRegistry>("creature_registry")
+                .and_then(|r| ids(r.defs.keys())),
+            skills: data
+                .get::<crate::systems::skills::SkillRegistry>("skill_registry")
+                .and_then(|r| ids(r.skills.keys())),
+            npcs,
+            factions,
+        }
+    }
 }
 
 // ── Travel destinations (v0.979, the Travel-objective emitter) ──────
@@ -170,15 +365,22 @@ pub fn npc_talk_key(name: &str) -> String {
     out
 }
 
-/// Push a quest-progress event key (e.g. `"craft_smelt_iron"`, `"harvest_potato"`)
-/// onto the shared `"quest_events"` DataStore channel. Action systems call this on
+/// The in-game day from the `"game_time"` clock (Survive steps, Daily quests).
+pub fn current_day(data: &DataStore) -> Option<u32> {
+    data.get::<std::sync::Mutex<crate::systems::time::GameTime>>("game_time")
+        .and_then(|m| m.lock().ok().map(|t| t.day_count))
+}
+
+/// Push a typed quest-progress event (e.g. `QuestEvent::Crafted`) onto the
+/// shared `"quest_events"` DataStore channel. Action systems call this on
 /// completion; [`QuestSystem`] drains it each tick and bumps matching progress
-/// counters so count-based objectives (Craft/Harvest/…) advance. No-ops cleanly if
-/// the channel is absent (e.g. a headless/test world that never registered it).
-pub fn push_quest_event(data: &DataStore, key: String) {
-    if let Some(lock) = data.get::<std::sync::Mutex<Vec<String>>>("quest_events") {
+/// counters so count-based objectives (Craft/Harvest/Kill/…) advance. No-ops
+/// cleanly if the channel is absent (e.g. a headless/test world that never
+/// registered it).
+pub fn push_quest_event(data: &DataStore, event: QuestEvent) {
+    if let Some(lock) = data.get::<std::sync::Mutex<Vec<QuestEvent>>>("quest_events") {
         if let Ok(mut events) = lock.lock() {
-            events.push(key);
+            events.push(event);
         }
     }
 }
@@ -192,8 +394,15 @@ pub struct ActiveQuest {
     pub quest_id: String,
     /// Index into QuestDef::steps for the current step (0-based).
     pub current_step: usize,
-    /// Progress counters keyed by objective description (for count-based objectives).
+    /// Progress counters keyed by `QuestObjective::counter` (for count-based
+    /// objectives); Survive steps also park their start day here.
     pub progress: HashMap<String, u32>,
+    /// The branch chosen in dialogue, once the player has picked one.
+    #[serde(default)]
+    pub branch: Option<String>,
+    /// Seconds spent on the current step (for timed steps).
+    #[serde(default)]
+    pub step_elapsed_s: f32,
 }
 
 /// Attach to the player entity to track quest state.
@@ -203,6 +412,18 @@ pub struct QuestTracker {
     pub active_quests: Vec<ActiveQuest>,
     /// IDs of completed quests.
     pub completed_quests: Vec<String>,
+    /// IDs of quests whose timed step ran out (cleared on a retry).
+    #[serde(default)]
+    pub failed_quests: Vec<String>,
+    /// The branch each completed branching quest was finished through.
+    #[serde(default)]
+    pub branches_taken: HashMap<String, String>,
+    /// In-game day each quest was last completed on (Daily quests).
+    #[serde(default)]
+    pub last_completed_day: HashMap<String, u32>,
+    /// Standing with each faction, moved by quest rewards.
+    #[serde(default)]
+    pub faction_standing: HashMap<String, i32>,
 }
 
 impl QuestTracker {
@@ -216,18 +437,66 @@ impl QuestTracker {
         self.active_quests.iter().any(|q| q.quest_id == quest_id)
     }
 
+    /// Whether a quest's last attempt failed.
+    pub fn is_failed(&self, quest_id: &str) -> bool {
+        self.failed_quests.iter().any(|id| id == quest_id)
+    }
+
+    /// The branch a completed quest was finished through.
+    pub fn branch_taken(&self, quest_id: &str) -> Option<&str> {
+        self.branches_taken.get(quest_id).map(String::as_str)
+    }
+
+    /// Standing with a faction (0 when never touched).
+    pub fn standing(&self, faction_id: &str) -> i32 {
+        self.faction_standing.get(faction_id).copied().unwrap_or(0)
+    }
+
     /// Start tracking a new quest (no-op if already active or completed).
+    /// Requirement-aware acceptance is `QuestRegistry::accept`.
     pub fn accept_quest(&mut self, quest_id: &str) {
-        if self.is_active(quest_id) || self.is_completed(quest_id) {
+        if self.is_completed(quest_id) {
             return;
         }
+        self.start_quest(quest_id);
+    }
+
+    /// Start a quest from step 0 without checking its requirements (no-op
+    /// if already active). Clears an earlier failure.
+    pub fn start_quest(&mut self, quest_id: &str) {
+        if self.is_active(quest_id) {
+            return;
+        }
+        self.failed_quests.retain(|id| id != quest_id);
         self.active_quests.push(ActiveQuest {
             quest_id: quest_id.to_string(),
             current_step: 0,
             progress: HashMap::new(),
+            branch: None,
+            step_elapsed_s: 0.0,
         });
         log::info!("Quest accepted: {}", quest_id);
     }
+
+    /// Record a completion: completed list, branch taken, day, and the
+    /// standing rewards.
+    fn record_completion(&mut self, active: ActiveQuest, standing: &[(String, i32)], day: Option<u32>) {
+        let id = active.quest_id;
+        if !self.is_completed(&id) {
+            self.completed_quests.push(id.clone());
+        }
+        if let Some(branch) = active.branch {
+            self.branches_taken.insert(id.clone(), branch);
+        }
+        if let Some(today) = day {
+            self.last_completed_day.insert(id.clone(), today);
+        }
+        for (faction_id, delta) in standing {
+            *self.faction_standing.entry(faction_id.clone()).or_insert(0) += delta;
+        }
+        self.failed_quests.retain(|f| *f != id);
+        log::info!("Quest completed: {}", id);
+    }
 }
 
 // ── Reward granting ─────────────────────────────────────────
@@ -257,54 +526,80 @@ impl QuestSystem {
         }
     }
 
-    /// Check if a single objective is met given the player's inventory and progress map.
+    /// Check if a single objective is met given the player's inventory,
+    /// skills, progress map and the current in-game day.
     fn check_objective(
         objective: &QuestObjective,
         inventory: Option<&Inventory>,
+        skills: Option<&PlayerSkills>,
         progress: &HashMap<String, u32>,
+        day: Option<u32>,
     ) -> bool {
+        if let Some((key, needed)) = objective.counter() {
+            return progress.get(&key).copied().unwrap_or(0) >= needed;
+        }
         match objective {
-            QuestObjective::Gather { item_id, quantity } => {
-                // Check inventory for required items
-                inventory
-                    .map(|inv| inv.count_item(item_id) >= *quantity)
-                    .unwrap_or(false)
-            }
-            QuestObjective::Craft { recipe_id, quantity } => {
-                // Track via progress counter (crafting system increments this)
-                let key = format!("craft_{}", recipe_id);
-                progress.get(&key).copied().unwrap_or(0) >= *quantity
-            }
-            QuestObjective::Harvest { crop_id, quantity } => {
-                // Track via progress counter (farming system increments this)
-                let key = format!("harvest_{}", crop_id);
-                progress.get(&key).copied().unwrap_or(0) >= *quantity
+            QuestObjective::Gather { item_id, quantity } => inventory
+                .map(|inv| inv.count_item(item_id) >= *quantity)
+                .unwrap_or(false),
+            QuestObjective::ReachSkill { skill_id, level } => {
+                skills.is_some_and(|s| s.level(skill_id) >= *level)
             }
-            QuestObjective::Build { blueprint_id } => {
-                // Track via progress counter (construction system sets this)
-                let key = format!("build_{}", blueprint_id);
-                progress.get(&key).copied().unwrap_or(0) >= 1
-            }
-            QuestObjective::Travel { destination } => {
-                // Track via progress counter (navigation system sets this)
-                let key = format!("travel_{}", destination);
-                progress.get(&key).copied().unwrap_or(0) >= 1
+            QuestObjective::Survive { days } => match (progress.get(SURVIVE_FROM_KEY), day) {
+                (Some(from), Some(today)) => today.saturating_sub(*from) >= *days,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Live-state bookkeeping for the current step before it is checked:
+    /// a Deliver step consumes the items when this tick's events include
+    /// talking to its NPC, and a Survive step records its start day
+    /// (restarting on death).
+    fn prepare_step(
+        objective: &QuestObjective,
+        active: &mut ActiveQuest,
+        inventory: Option<&mut Inventory>,
+        events: &[QuestEvent],
+        dead: bool,
+        day: Option<u32>,
+    ) {
+        match objective {
+            QuestObjective::Deliver { item_id, quantity, npc_id } => {
+                let talked = events
+                    .iter()
+                    .any(|e| matches!(e, QuestEvent::Talked { npc_id: n } if n == npc_id));
+                if let (true, Some(inv)) = (talked, inventory) {
+                    if inv.count_item(item_id) >= *quantity {
+                        inv.remove_item(item_id, *quantity);
+                        let (key, _) = objective.counter().expect("Deliver counts");
+                        active.progress.insert(key, *quantity);
+                        log::info!("Quest '{}': delivered {quantity}x {item_id} to {npc_id}", active.quest_id);
+                    }
+                }
             }
-            QuestObjective::Talk { npc_id } => {
-                // Track via progress counter (interaction system sets this)
-                let key = format!("talk_{}", npc_id);
-                progress.get(&key).copied().unwrap_or(0) >= 1
+            QuestObjective::Survive { .. } => {
+                if dead {
+                    active.progress.remove(SURVIVE_FROM_KEY);
+                } else if let Some(today) = day {
+                    active.progress.entry(SURVIVE_FROM_KEY.to_string()).or_insert(today);
+                }
             }
+            _ => {}
         }
     }
 }
 
+/// Progress-map key holding the day a Survive step began.
+const SURVIVE_FROM_KEY: &str = "survive_from_day";
+
 impl System for QuestSystem {
     fn name(&self) -> &str {
         "QuestSystem"
     }
 
-    fn tick(&mut self, world: &mut hecs::World, _dt: f32, data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         let registry = match data.get::<QuestRegistry>("quest_registry") {
             Some(r) => r,
             None => return, // No quests loaded yet
@@ -324,119 +619,175 @@ impl System for QuestSystem {
                 .next()
                 .map(|(_, (tf, _))| (tf.position.x, tf.position.z));
             if let Some(xz) = player_xz {
-                for id in travel_transitions(xz, dests, &mut self.inside_destinations) {
-                    push_quest_event(data, format!("travel_{id}"));
+                for destination in travel_transitions(xz, dests, &mut self.inside_destinations) {
+                    push_quest_event(data, QuestEvent::Arrived { destination });
                 }
             }
         }
 
         // Drain quest-progress events the action systems pushed this frame
-        // ("craft_<recipe>", "harvest_<crop>", ...). Applied to every active
-        // quest's progress map below so count-based objectives advance. (Gather
-        // objectives are checked against live inventory and need no events.)
-        let events: Vec<String> = data
-            .get::<std::sync::Mutex<Vec<String>>>("quest_events")
+        // (Crafted, Harvested, Killed, ...). Applied to every active quest's
+        // progress map below so count-based objectives advance. (Gather,
+        // ReachSkill and Survive read live state and need no events.)
+        let events: Vec<QuestEvent> = data
+            .get::<std::sync::Mutex<Vec<QuestEvent>>>("quest_events")
             .and_then(|m| m.lock().ok().map(|mut e| e.drain(..).collect()))
             .unwrap_or_default();
-
-        // Collect entities with QuestTracker to process. Completed tuples carry
-        // (quest_id, item rewards, xp rewards).
-        #[allow(clippy::type_complexity)]
-        let mut updates: Vec<(
-            hecs::Entity,
-            QuestTracker,
-            Vec<(String, Vec<(String, u32)>, Vec<(String, u32)>)>,
-        )> = Vec::new();
-
-        for (entity, (tracker, inventory)) in
-            world.query_mut::<(&QuestTracker, Option<&Inventory>)>()
-        {
+        let day = current_day(data);
+
+        // Collect entities with QuestTracker to process, with the rewards of
+        // the quests each completed this tick.
+        let mut updates: Vec<(hecs::Entity, QuestTracker, Vec<QuestRewards>)> = Vec::new();
+
+        for (entity, (tracker, mut inventory, skills, dead)) in world.query_mut::<(
+            &QuestTracker,
+            Option<&mut Inventory>,
+            Option<&PlayerSkills>,
+            Option<&crate::ecs::components::Dead>,
+        )>() {
+            let before = tracker
+                .active_quests
+                .iter()
+                .map(|q| (q.progress.clone(), q.branch.clone()))
+                .collect::<Vec<_>>();
             let mut tracker = tracker.clone();
-            // Apply this frame's progress events to every active quest's counters
-            // (e.g. a "craft_smelt_iron" event bumps progress["craft_smelt_iron"]).
-            let events_applied = !events.is_empty() && !tracker.active_quests.is_empty();
-            if events_applied {
-                for active in tracker.active_quests.iter_mut() {
-                    for key in &events {
-                        *active.progress.entry(key.clone()).or_insert(0) += 1;
+            // Apply this frame's events to every active quest: counters for
+            // progress (e.g. Crafted { smelt_iron } bumps
+            // progress["craft_smelt_iron"]) and branch picks for its fork.
+            for active in tracker.active_quests.iter_mut() {
+                let def = registry.get(&active.quest_id);
+                for event in &events {
+                    match event {
+                        QuestEvent::Chose { quest_id, branch_id } => {
+                            if *quest_id == active.quest_id
+                                && active.branch.is_none()
+                                && def.is_some_and(|d| d.branch(branch_id).is_some())
+                            {
+                                active.branch = Some(branch_id.clone());
+                                log::info!("Quest '{quest_id}': chose branch '{branch_id}'");
+                            }
+                        }
+                        _ => {
+                            if let Some(key) = event.counter() {
+                                *active.progress.entry(key).or_insert(0) += 1;
+                            }
+                        }
                     }
                 }
+                let step = def.and_then(|d| d.step_at(active.branch.as_deref(), active.current_step));
+                if let Some(step) = step {
+                    Self::prepare_step(
+                        &step.objective,
+                        active,
+                        inventory.as_deref_mut(),
+                        &events,
+                        dead.is_some(),
+                        day,
+                    );
+                }
             }
-            let mut completed_this_tick: Vec<(String, Vec<(String, u32)>, Vec<(String, u32)>)> =
-                Vec::new();
+            let events_applied = tracker
+                .active_quests
+                .iter()
+                .map(|q| (&q.progress, &q.branch))
+                .ne(before.iter().map(|(p, b)| (p, b)));
+            let mut completed_this_tick: Vec<QuestRewards> = Vec::new();
             let mut quests_to_advance: Vec<(usize, usize)> = Vec::new(); // (quest_index, new_step)
-            let mut quests_to_complete: Vec<usize> = Vec::new();
+            let mut quests_timed: Vec<(usize, f32)> = Vec::new(); // (quest_index, new elapsed)
+            // (quest_index, rewards if completed / None if it ran out of time)
+            let mut quests_to_finish: Vec<(usize, Option<QuestRewards>)> = Vec::new();
 
             for (qi, active) in tracker.active_quests.iter().enumerate() {
                 let quest_def = match registry.get(&active.quest_id) {
                     Some(def) => def,
                     None => continue, // Quest definition not found
                 };
-
-                // Check if current step is within bounds
-                if active.current_step >= quest_def.steps.len() {
-                    // All steps done — mark for completion
-                    quests_to_complete.push(qi);
-                    completed_this_tick.push((
-                        active.quest_id.clone(),
-                        quest_def.rewards.clone(),
-                        quest_def.xp_rewards.clone(),
-                    ));
-                    continue;
+                let branch = active.branch.as_deref();
+                if quest_def.awaits_choice(branch, active.current_step) {
+                    continue; // At the fork: waiting on dialogue
                 }
 
-                let step = &quest_def.steps[active.current_step];
-                if Self::check_objective(&step.objective, inventory, &active.progress) {
+                let step = match quest_def.step_at(branch, active.current_step) {
+                    Some(step) => step,
+                    None => {
+                        // All steps done — mark for completion
+                        quests_to_finish.push((qi, Some(quest_def.completion_rewards(branch))));
+                        continue;
+                    }
+                };
+                if Self::check_objective(
+                    &step.objective,
+                    inventory.as_deref(),
+                    skills,
+                    &active.progress,
+                    day,
+                ) {
                     let next_step = active.current_step + 1;
-                    if next_step >= quest_def.steps.len() {
+                    if next_step >= quest_def.step_count(branch)
+                        && !quest_def.awaits_choice(branch, next_step)
+                    {
                         // Final step completed
-                        quests_to_complete.push(qi);
-                        completed_this_tick.push((
-                            active.quest_id.clone(),
-                            quest_def.rewards.clone(),
-                            quest_def.xp_rewards.clone(),
-                        ));
+                        quests_to_finish.push((qi, Some(quest_def.completion_rewards(branch))));
                     } else {
                         quests_to_advance.push((qi, next_step));
                     }
+                } else if let Some(limit) = step.time_limit_s {
+                    let elapsed = active.step_elapsed_s + dt;
+                    if elapsed > limit {
+                        quests_to_finish.push((qi, None));
+                    } else {
+                        quests_timed.push((qi, elapsed));
+                    }
                 }
             }
 
-            // Apply step advances (do this before removals to keep indices valid)
+            // Apply step advances and timers (before removals to keep indices valid)
             for (qi, new_step) in &quests_to_advance {
-                tracker.active_quests[*qi].current_step = *new_step;
-                log::info!(
-                    "Quest '{}': advanced to step {}",
-                    tracker.active_quests[*qi].quest_id,
-                    new_step
-                );
+                let active = &mut tracker.active_quests[*qi];
+                active.current_step = *new_step;
+                active.step_elapsed_s = 0.0;
+                log::info!("Quest '{}': advanced to step {}", active.quest_id, new_step);
+            }
+            for (qi, elapsed) in &quests_timed {
+                tracker.active_quests[*qi].step_elapsed_s = *elapsed;
             }
 
-            // Complete quests (remove in reverse order to preserve indices)
-            quests_to_complete.sort_unstable();
-            for qi in quests_to_complete.into_iter().rev() {
-                let quest_id = tracker.active_quests[qi].quest_id.clone();
-                tracker.active_quests.remove(qi);
-                tracker.completed_quests.push(quest_id.clone());
-                log::info!("Quest completed: {}", quest_id);
+            // Complete or fail quests (remove in reverse order to preserve indices)
+            let finished = !quests_to_finish.is_empty();
+            quests_to_finish.sort_unstable_by_key(|(qi, _)| *qi);
+            let mut completed_ids: Vec<String> = Vec::new();
+            for (qi, outcome) in quests_to_finish.into_iter().rev() {
+                let active = tracker.active_quests.remove(qi);
+                match outcome {
+                    Some(rewards) => {
+                        completed_ids.push(active.quest_id.clone());
+                        tracker.record_completion(active, &rewards.standing, day);
+                        completed_this_tick.push(rewards);
+                    }
+                    None => {
+                        log::info!("Quest failed (out of time): {}", active.quest_id);
+                        if !tracker.is_failed(&active.quest_id) {
+                            tracker.failed_quests.push(active.quest_id);
+                        }
+                    }
+                }
             }
 
-            // Prerequisite chaining: completing a quest auto-accepts any quest whose
-            // prerequisite it satisfies (and that isn't already active or completed).
-            for (completed_id, _, _) in &completed_this_tick {
-                for def in registry.quests.values() {
-                    if def.prerequisite.as_deref() == Some(completed_id.as_str())
-                        && !tracker.is_active(&def.id)
-                        && !tracker.is_completed(&def.id)
-                    {
-                        tracker.accept_quest(&def.id);
+            // Graph chaining: completing a quest auto-accepts every quest that
+            // names it as a prerequisite or requirement and is now available
+            // (a BranchTaken edge only opens for the branch actually taken).
+            for completed_id in &completed_ids {
+                for def in registry.successors(completed_id) {
+                    if def.is_available(&tracker, skills, day) {
+                        tracker.start_quest(&def.id);
                     }
                 }
             }
 
             if events_applied
-                || !completed_this_tick.is_empty()
+                || finished
                 || !quests_to_advance.is_empty()
+                || !quests_timed.is_empty()
             {
                 updates.push((entity, tracker, completed_this_tick));
             }
@@ -449,9 +800,10 @@ impl System for QuestSystem {
             }
 
             // Grant item + XP rewards for completed quests (XP via the shared
-            // xp_grants channel, drained by SkillSystem later this same frame).
-            for (_quest_id, rewards, xp_rewards) in completed {
-                for (item_id, quantity) in rewards {
+            // xp_grants channel, drained by SkillSystem later this same frame;
+            // standing was applied to the tracker above).
+            for QuestRewards { items, xp, .. } in completed {
+                for (item_id, quantity) in items {
                     if let Ok(mut inv) = world.get::<&mut Inventory>(entity) {
                         let overflow = inv.add_item(&item_id, quantity, 99);
                         if overflow > 0 {
@@ -463,7 +815,7 @@ impl System for QuestSystem {
                         }
                     }
                 }
-                for (skill_id, amount) in xp_rewards {
+                for (skill_id, amount) in xp {
                     crate::systems::skills::award_skill_xp(data, &skill_id, amount);
                 }
             }
@@ -484,10 +836,16 @@ mod quest_tests {
             steps: vec![QuestStep {
                 description: String::new(),
                 objective: obj,
+                time_limit_s: None,
             }],
             rewards: reward,
             xp_rewards: Vec::new(),
             prerequisite: prereq.map(|s| s.to_string()),
+            requires: Vec::new(),
+            branches: Vec::new(),
+            choice_npc: None,
+            repeat: QuestRepeat::Once,
+            standing_rewards: Vec::new(),
         }
     }
 
@@ -638,6 +996,14 @@ mod quest_tests {
                         q.id
                     ),
                     QuestObjective::Talk { .. } => {} // relay-runtime names, see doc
+                    QuestObjective::Deliver { item_id, .. } => assert!(
+                        items.contains(item_id),
+                        "quest {}: Deliver names unknown item {item_id}",
+                        q.id
+                    ),
+                    QuestObjective::Kill { .. }
+                    | QuestObjective::ReachSkill { .. }
+                    | QuestObjective::Survive { .. } => {} // shipped_quests_pass_the_validator
                 }
                 // Reward items must exist too - a completed quest that grants
                 // a phantom item would vanish the reward silently.
@@ -745,7 +1111,7 @@ mod quest_tests {
         );
         let mut data = DataStore::new();
         data.insert("quest_registry", reg);
-        data.insert("quest_events", std::sync::Mutex::new(Vec::<String>::new()));
+        data.insert("quest_events", std::sync::Mutex::new(Vec::<QuestEvent>::new()));
 
         let mut world = hecs::World::new();
         let mut tracker = QuestTracker::default();
@@ -757,7 +1123,7 @@ mod quest_tests {
         assert!(!world.get::<&QuestTracker>(player).unwrap().is_completed("q_craft"));
 
         // Emit the craft event CraftingSystem would push on completing smelt_iron.
-        push_quest_event(&data, "craft_smelt_iron".to_string());
+        push_quest_event(&data, QuestEvent::Crafted { recipe_id: "smelt_iron".into() });
         sys.tick(&mut world, 0.0, &data);
 
         let t = world.get::<&QuestTracker>(player).unwrap();
@@ -767,4 +1133,342 @@ mod quest_tests {
             "completing q_craft auto-accepts its dependent q_next"
         );
     }
+
+    /// The load-time validator (typed-objectives increment): every shipped
+    /// quest resolves against the real registries + NPC roster, including
+    /// the Kill / Deliver / ReachSkill / Survive chain, and a quest naming
+    /// unknown ids is rejected while its valid neighbours stay.
+    #[test]
+    fn shipped_quests_pass_the_validator_and_bad_ones_are_rejected() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let read = |rel: &str| std::fs::read(root.join(rel)).unwrap();
+        let mut data = DataStore::new();
+        data.insert("item_registry", crate::systems::inventory::ItemRegistry::from_csv(&read("items.csv")).unwrap());
+        data.insert("recipe_registry", crate::systems::crafting::RecipeRegistry::from_csv(&read("recipes.csv")).unwrap());
+        data.insert("plant_registry", crate::systems::farming::PlantRegistry::from_csv(&read("plants.csv")).unwrap());
+        data.insert(
+            "blueprint_registry",
+            crate::systems::construction::BlueprintRegistry::from_ron(&read("blueprints/basic.ron")).unwrap(),
+        );
+        data.insert("quest_destinations", DestinationList::from_ron(&read("entities/destinations.ron")).unwrap());
+        data.insert(
+            "creature_registry",
+            crate::systems::livestock::CreatureRegistry::from_csv(&read("creatures.csv")).unwrap(),
+        );
+        data.insert(
+            "skill_registry",
+            crate::systems::skills::SkillRegistry::from_csv(&read("skills/skills.csv")).unwrap(),
+        );
+        let mut npcs = std::collections::HashSet::new();
+        for rel in ["npcs.ron", "npc/crew.ron"] {
+            let text = String::from_utf8(read(rel)).unwrap();
+            npcs.extend(objectives::npc_keys_from_ron(&text).unwrap());
+        }
+        let factions = graph::ids_from_ron(&String::from_utf8(read("factions.ron")).unwrap()).unwrap();
+        let refs = QuestRefs::from_data(&data, Some(npcs), Some(factions));
+
+        let mut reg = QuestRegistry::from_ron_dir(&root.join("quests"));
+        assert_eq!(reg.validate(&refs), Vec::<(String, String)>::new());
+        assert!(reg.get("hs_provider").is_some(), "the homestead chain loads");
+
+        reg.quests.insert(
+            "q_typo".into(),
+            quest(
+                "q_typo",
+                QuestObjective::Deliver {
+                    item_id: "eg_0".into(),
+                    quantity: 1,
+                    npc_id: "crewmate_nia".into(),
+                },
+                vec![],
+                None,
+            ),
+        );
+        let before = reg.quests.len();
+        assert_eq!(reg.reject_invalid(&refs), vec!["q_typo".to_string()]);
+        assert_eq!(reg.quests.len(), before - 1, "only the bad quest is dropped");
+    }
+
+    fn one_quest_store(objective: QuestObjective) -> DataStore {
+        let mut reg = QuestRegistry::default();
+        reg.quests.insert("q".into(), quest("q", objective, vec![], None));
+        let mut data = DataStore::new();
+        data.insert("quest_registry", reg);
+        data.insert("quest_events", std::sync::Mutex::new(Vec::<QuestEvent>::new()));
+        data
+    }
+
+    fn accepted() -> QuestTracker {
+        let mut tracker = QuestTracker::default();
+        tracker.accept_quest("q");
+        tracker
+    }
+
+    #[test]
+    fn kill_objective_counts_killing_blows_per_species() {
+        let data = one_quest_store(QuestObjective::Kill { creature_id: "wolf".into(), quantity: 2 });
+        let mut world = hecs::World::new();
+        let player = world.spawn((accepted(),));
+        let mut sys = QuestSystem::new();
+        push_quest_event(&data, QuestEvent::Killed { creature_id: "wolf".into() });
+        push_quest_event(&data, QuestEvent::Killed { creature_id: "chicken".into() });
+        sys.tick(&mut world, 0.0, &data);
+        assert!(!world.get::<&QuestTracker>(player).unwrap().is_completed("q"), "1 of 2 wolves");
+        push_quest_event(&data, QuestEvent::Killed { creature_id: "wolf".into() });
+        sys.tick(&mut world, 0.0, &data);
+        assert!(world.get::<&QuestTracker>(player).unwrap().is_completed("q"));
+    }
+
+    #[test]
+    fn deliver_hands_items_over_when_talking_to_the_npc() {
+        let data = one_quest_store(QuestObjective::Deliver {
+            item_id: "egg_0".into(),
+            quantity: 3,
+            npc_id: "crewmate_nia".into(),
+        });
+        let mut world = hecs::World::new();
+        let mut inv = Inventory::new(8);
+        inv.add_item("egg_0", 2, 99);
+        let player = world.spawn((accepted(), inv));
+        let mut sys = QuestSystem::new();
+
+        // Short one egg: talking hands nothing over.
+        push_quest_event(&data, QuestEvent::Talked { npc_id: "crewmate_nia".into() });
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item("egg_0"), 2);
+        assert!(!world.get::<&QuestTracker>(player).unwrap().is_completed("q"));
+
+        // Carrying enough but talking to someone else: still nothing.
+        world.get::<&mut Inventory>(player).unwrap().add_item("egg_0", 2, 99);
+        push_quest_event(&data, QuestEvent::Talked { npc_id: "dr_kel".into() });
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item("egg_0"), 4);
+
+        push_quest_event(&data, QuestEvent::Talked { npc_id: "crewmate_nia".into() });
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item("egg_0"), 1, "3 eggs delivered");
+        assert!(world.get::<&QuestTracker>(player).unwrap().is_completed("q"));
+    }
+
+    #[test]
+    fn reach_skill_reads_live_levels() {
+        let data = one_quest_store(QuestObjective::ReachSkill { skill_id: "farming".into(), level: 2 });
+        let mut world = hecs::World::new();
+        let player = world.spawn((accepted(), PlayerSkills::new()));
+        let mut sys = QuestSystem::new();
+        sys.tick(&mut world, 0.0, &data);
+        assert!(!world.get::<&QuestTracker>(player).unwrap().is_completed("q"));
+        world.get::<&mut PlayerSkills>(player).unwrap().skills.insert(
+            "farming".into(),
+            crate::systems::skills::SkillProgress { level: 2, xp: 0 },
+        );
+        sys.tick(&mut world, 0.0, &data);
+        assert!(world.get::<&QuestTracker>(player).unwrap().is_completed("q"));
+    }
+
+    #[test]
+    fn survive_counts_days_from_the_step_and_restarts_on_death() {
+        let mut data = one_quest_store(QuestObjective::Survive { days: 2 });
+        let time = crate::systems::time::GameTime { day_count: 5, ..Default::default() };
+        data.insert("game_time", std::sync::Mutex::new(time));
+        let set_day = |data: &DataStore, day: u32| {
+            data.get::<std::sync::Mutex<crate::systems::time::GameTime>>("game_time")
+                .unwrap()
+                .lock()
+                .unwrap()
+                .day_count = day;
+        };
+        let mut world = hecs::World::new();
+        let player = world.spawn((accepted(),));
+        let mut sys = QuestSystem::new();
+        sys.tick(&mut world, 0.0, &data); // step starts on day 5
+
+        set_day(&data, 6);
+        world.insert_one(player, crate::ecs::components::Dead::default()).unwrap();
+        sys.tick(&mut world, 0.0, &data); // died: the count restarts
+        world.remove_one::<crate::ecs::components::Dead>(player).unwrap();
+        sys.tick(&mut world, 0.0, &data); // alive again on day 6
+
+        set_day(&data, 7);
+        sys.tick(&mut world, 0.0, &data);
+        assert!(
+            !world.get::<&QuestTracker>(player).unwrap().is_completed("q"),
+            "only one day since the restart"
+        );
+        set_day(&data, 8);
+        sys.tick(&mut world, 0.0, &data);
+        assert!(world.get::<&QuestTracker>(player).unwrap().is_completed("q"));
+    }
+
+    /// A fork chosen in dialogue: the chosen branch's steps run, its rewards
+    /// and standing land on top of the quest's, the branch is recorded, and
+    /// only the follow-up gated on THAT branch is auto-accepted.
+    #[test]
+    fn dialogue_choice_picks_a_branch_and_opens_only_its_follow_up() {
+        let mut fork = quest(
+            "q_fork",
+            QuestObjective::Talk { npc_id: "elder".into() },
+            vec![("stick_0".into(), 1)],
+            None,
+        );
+        fork.branches = vec![
+            QuestBranch {
+                id: "council".into(),
+                name: "Side with the council".into(),
+                steps: vec![QuestStep {
+                    description: String::new(),
+                    objective: QuestObjective::Talk { npc_id: "mayor".into() },
+                    time_limit_s: None,
+                }],
+                rewards: vec![("iron_ingot_0".into(), 2)],
+                xp_rewards: Vec::new(),
+                standing_rewards: vec![("united_earth_federation".into(), 5)],
+            },
+            QuestBranch {
+                id: "rebels".into(),
+                name: "Side with the rebels".into(),
+                steps: Vec::new(),
+                rewards: Vec::new(),
+                xp_rewards: Vec::new(),
+                standing_rewards: vec![("frontier_coalition".into(), 5)],
+            },
+        ];
+        let gated = |id: &str, branch: &str| {
+            let mut q = quest(id, QuestObjective::Talk { npc_id: "x".into() }, vec![], None);
+            q.requires = vec![QuestRequirement::BranchTaken {
+                quest_id: "q_fork".into(),
+                branch_id: branch.into(),
+            }];
+            q
+        };
+        let mut reg = QuestRegistry::default();
+        for q in [fork, gated("q_council_next", "council"), gated("q_rebel_next", "rebels")] {
+            reg.quests.insert(q.id.clone(), q);
+        }
+        let mut data = DataStore::new();
+        data.insert("quest_registry", reg);
+        data.insert("quest_events", std::sync::Mutex::new(Vec::<QuestEvent>::new()));
+
+        let mut world = hecs::World::new();
+        let mut tracker = QuestTracker::default();
+        tracker.accept_quest("q_fork");
+        let player = world.spawn((tracker, Inventory::new(16)));
+        let mut sys = QuestSystem::new();
+
+        push_quest_event(&data, QuestEvent::Talked { npc_id: "elder".into() });
+        sys.tick(&mut world, 0.0, &data);
+        sys.tick(&mut world, 0.0, &data);
+        {
+            let t = world.get::<&QuestTracker>(player).unwrap();
+            assert!(t.is_active("q_fork"), "trunk done, the quest waits at the fork");
+            let reg = data.get::<QuestRegistry>("quest_registry").unwrap();
+            let choices = pending_choices(reg, &t);
+            assert_eq!(choices.len(), 1);
+            assert_eq!(choices[0].options.len(), 2);
+        }
+
+        push_quest_event(&data, QuestEvent::Chose { quest_id: "q_fork".into(), branch_id: "council".into() });
+        sys.tick(&mut world, 0.0, &data);
+        // A second pick is ignored: the branches are mutually exclusive.
+        push_quest_event(&data, QuestEvent::Chose { quest_id: "q_fork".into(), branch_id: "rebels".into() });
+        push_quest_event(&data, QuestEvent::Talked { npc_id: "mayor".into() });
+        sys.tick(&mut world, 0.0, &data);
+
+        let t = world.get::<&QuestTracker>(player).unwrap();
+        assert!(t.is_completed("q_fork"));
+        assert_eq!(t.branch_taken("q_fork"), Some("council"));
+        assert_eq!(t.standing("united_earth_federation"), 5);
+        assert_eq!(t.standing("frontier_coalition"), 0);
+        assert!(t.is_active("q_council_next"));
+        assert!(!t.is_active("q_rebel_next"), "the road not taken stays shut");
+        let inv = world.get::<&Inventory>(player).unwrap();
+        assert_eq!(inv.count_item("stick_0"), 1, "the quest's own reward");
+        assert_eq!(inv.count_item("iron_ingot_0"), 2, "plus the branch's");
+    }
+
+    #[test]
+    fn timed_step_fails_the_quest_when_time_runs_out() {
+        let mut q = quest("q", QuestObjective::Talk { npc_id: "dr_kel".into() }, vec![], None);
+        q.steps[0].time_limit_s = Some(10.0);
+        let mut reg = QuestRegistry::default();
+        reg.quests.insert("q".into(), q);
+        let mut data = DataStore::new();
+        data.insert("quest_registry", reg);
+        data.insert("quest_events", std::sync::Mutex::new(Vec::<QuestEvent>::new()));
+        let mut world = hecs::World::new();
+        let player = world.spawn((accepted(),));
+        let mut sys = QuestSystem::new();
+
+        sys.tick(&mut world, 6.0, &data);
+        assert!(world.get::<&QuestTracker>(player).unwrap().is_active("q"), "4 s left");
+        sys.tick(&mut world, 6.0, &data);
+        let t = world.get::<&QuestTracker>(player).unwrap();
+        assert!(!t.is_active("q") && t.is_failed("q") && !t.is_completed("q"));
+        drop(t);
+
+        // A failed quest can be retried, and the failure clears.
+        let mut t = world.get::<&mut QuestTracker>(player).unwrap();
+        let reg = data.get::<QuestRegistry>("quest_registry").unwrap();
+        assert!(reg.accept(&mut t, "q", None, None));
+        assert!(!t.is_failed("q"));
+    }
+
+    #[test]
+    fn daily_quest_comes_back_the_next_day() {
+        let mut q = quest("q_daily", QuestObjective::Talk { npc_id: "dr_kel".into() }, vec![], None);
+        q.repeat = QuestRepeat::Daily;
+        let mut reg = QuestRegistry::default();
+        reg.quests.insert(q.id.clone(), q);
+        let mut data = DataStore::new();
+        data.insert("quest_events", std::sync::Mutex::new(Vec::<QuestEvent>::new()));
+        let time = crate::systems::time::GameTime { day_count: 3, ..Default::default() };
+        data.insert("game_time", std::sync::Mutex::new(time));
+
+        let mut tracker = QuestTracker::default();
+        assert!(reg.accept(&mut tracker, "q_daily", None, Some(3)));
+        data.insert("quest_registry", reg);
+        let mut world = hecs::World::new();
+        let player = world.spawn((tracker,));
+        let mut sys = QuestSystem::new();
+        push_quest_event(&data, QuestEvent::Talked { npc_id: "dr_kel".into() });
+        sys.tick(&mut world, 0.0, &data);
+
+        let mut t = world.get::<&mut QuestTracker>(player).unwrap();
+        let reg = data.get::<QuestRegistry>("quest_registry").unwrap();
+        assert!(t.is_completed("q_daily"));
+        assert!(reg.available(&t, None, Some(3)).is_empty(), "done for today");
+        assert!(!reg.accept(&mut t, "q_daily", None, Some(3)));
+        assert_eq!(reg.available(&t, None, Some(4)).len(), 1);
+        assert!(reg.accept(&mut t, "q_daily", None, Some(4)));
+    }
+
+    /// The tracker saved in `WorldSave::quests` keeps the branch (active and
+    /// taken), failures, daily stamps and standing through a JSON round-trip,
+    /// and a pre-graph save (none of those fields) still loads.
+    #[test]
+    fn tracker_round_trips_branch_state_and_loads_old_saves() {
+        let mut t = QuestTracker::default();
+        t.start_quest("q_fork");
+        t.active_quests[0].branch = Some("council".into());
+        t.active_quests[0].step_elapsed_s = 4.5;
+        t.completed_quests.push("q_old".into());
+        t.branches_taken.insert("q_old".into(), "rebels".into());
+        t.failed_quests.push("q_timed".into());
+        t.last_completed_day.insert("q_old".into(), 7);
+        t.faction_standing.insert("nova_corp".into(), -3);
+
+        let json = serde_json::to_string(&t).unwrap();
+        let back: QuestTracker = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.active_quests[0].branch.as_deref(), Some("council"));
+        assert_eq!(back.active_quests[0].step_elapsed_s, 4.5);
+        assert_eq!(back.branch_taken("q_old"), Some("rebels"));
+        assert!(back.is_failed("q_timed"));
+        assert_eq!(back.last_completed_day.get("q_old"), Some(&7));
+        assert_eq!(back.standing("nova_corp"), -3);
+
+        let old = r#"{"active_quests":[{"quest_id":"a","current_step":1,"progress":{}}],"completed_quests":["b"]}"#;
+        let old: QuestTracker = serde_json::from_str(old).unwrap();
+        assert_eq!(old.active_quests[0].branch, None);
+        assert!(old.is_completed("b") && old.branches_taken.is_empty());
+    }
 }
@@ -1,4 +1,7 @@
-//! Quest objective types — what the player needs to accomplish for each step.
+//! Quest objective types — what the player needs to accomplish for each step,
+//! and the typed progress events the action systems emit toward them.
+
+use std::collections::HashSet;
 
 use serde::{Deserialize, Serialize};
 
@@ -9,6 +12,10 @@ pub struct QuestStep {
     pub description: String,
     /// The objective that must be satisfied to complete this step.
     pub objective: QuestObjective,
+    /// Seconds of play allowed for this step once it begins; running out
+    /// fails the whole quest. None = untimed.
+    #[serde(default)]
+    pub time_limit_s: Option<f32>,
 }
 
 /// Specific objective types that the quest system can evaluate.
@@ -26,4 +33,202 @@ pub enum QuestObjective {
     Travel { destination: String },
     /// Talk to an NPC — tracked via progress counter from interaction system.
     Talk { npc_id: String },
+    /// Kill creatures of a creatures.csv species — counted from combat's
+    /// player-landed killing blows.
+    Kill { creature_id: String, quantity: u32 },
+    /// Hand items to an NPC: talking to them while carrying `quantity`
+    /// consumes the items and completes the step.
+    Deliver { item_id: String, quantity: u32, npc_id: String },
+    /// Reach a level in a skill — checked against the player's PlayerSkills.
+    ReachSkill { skill_id: String, level: u32 },
+    /// Stay alive for N in-game days from the moment this step begins
+    /// (dying restarts the count).
+    Survive { days: u32 },
+}
+
+impl QuestObjective {
+    /// The progress counter an event-driven objective reads, and how high
+    /// it must reach. None for objectives checked against live state
+    /// (inventory, skills, the calendar).
+    pub fn counter(&self) -> Option<(String, u32)> {
+        match self {
+            Self::Craft { recipe_id, quantity } => Some((format!("craft_{recipe_id}"), *quantity)),
+            Self::Harvest { crop_id, quantity } => Some((format!("harvest_{crop_id}"), *quantity)),
+            Self::Build { blueprint_id } => Some((format!("build_{blueprint_id}"), 1)),
+            Self::Travel { destination } => Some((format!("travel_{destination}"), 1)),
+            Self::Talk { npc_id } => Some((format!("talk_{npc_id}"), 1)),
+            Self::Kill { creature_id, quantity } => Some((format!("kill_{creature_id}"), *quantity)),
+            Self::Deliver { item_id, quantity, npc_id } => {
+                Some((format!("deliver_{item_id}_to_{npc_id}"), *quantity))
+            }
+            Self::Gather { .. } | Self::ReachSkill { .. } | Self::Survive { .. } => None,
+        }
+    }
+
+    /// Every id this objective points at that does not exist in `refs`.
+    /// Categories `refs` has no list for are not checked.
+    pub fn unknown_refs(&self, refs: &QuestRefs) -> Vec<String> {
+        let mut bad = Vec::new();
+        let mut check = |kind: &str, set: &Option<HashSet<String>>, id: &str| {
+            if set.as_ref().is_some_and(|s| !s.contains(id)) {
+                bad.push(format!("unknown {kind} '{id}'"));
+            }
+        };
+        match self {
+            Self::Gather { item_id, .. } => check("item", &refs.items, item_id),
+            Self::Craft { recipe_id, .. } => check("recipe", &refs.recipes, recipe_id),
+            Self::Harvest { crop_id, .. } => check("crop", &refs.crops, crop_id),
+            Self::Build { blueprint_id } => check("blueprint", &refs.blueprints, blueprint_id),
+            Self::Travel { destination } => check("destination", &refs.destinations, destination),
+            Self::Talk { npc_id } => check("NPC", &refs.npcs, npc_id),
+            Self::Kill { creature_id, .. } => check("creature", &refs.creatures, creature_id),
+            Self::Deliver { item_id, npc_id, .. } => {
+                check("item", &refs.items, item_id);
+                check("NPC", &refs.npcs, npc_id);
+            }
+            Self::ReachSkill { skill_id, .. } => check("skill", &refs.skills, skill_id),
+            Self::Survive { .. } => {}
+        }
+        bad
+    }
+}
+
+/// Typed quest progress. The action systems push these onto the
+/// `"quest_events"` channel (`quests::push_quest_event`); QuestSystem drains
+/// them each tick and bumps the matching objective counters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuestEvent {
+    /// CraftingSystem finished a recipe.
+    Crafted { recipe_id: String },
+    /// A crop (or a living animal's renewable product) was harvested.
+    Harvested { crop_id: String },
+    /// ConstructionSystem completed a structure.
+    Built { blueprint_id: String },
+    /// The player stepped into a destination radius.
+    Arrived { destination: String },
+    /// The player opened a dialogue (`npc_talk_key` slug).
+    Talked { npc_id: String },
+    /// The player landed the killing blow on a creature.
+    Killed { creature_id: String },
+    /// The player picked one of a quest's branches in dialogue.
+    Chose { quest_id: String, branch_id: String },
+}
+
+impl QuestEvent {
+    /// The progress counter this event bumps (matches `QuestObjective::counter`).
+    /// None for events that steer a quest rather than count toward it.
+    pub fn counter(&self) -> Option<String> {
+        match self {
+            Self::Crafted { recipe_id } => Some(format!("craft_{recipe_id}")),
+            Self::Harvested { crop_id } => Some(format!("harvest_{crop_id}")),
+            Self::Built { blueprint_id } => Some(format!("build_{blueprint_id}")),
+            Self::Arrived { destination } => Some(format!("travel_{destination}")),
+            Self::Talked { npc_id } => Some(format!("talk_{npc_id}")),
+            Self::Killed { creature_id } => Some(format!("kill_{creature_id}")),
+            Self::Chose { .. } => None,
+        }
+    }
+}
+
+/// The id sets quest files are validated against. A `None` category is not
+/// checked (its registry failed to load; the quest is kept rather than
+/// rejected for someone else's missing file).
+#[derive(Debug, Clone, Default)]
+pub struct QuestRefs {
+    pub items: Option<HashSet<String>>,
+    pub recipes: Option<HashSet<String>>,
+    pub crops: Option<HashSet<String>>,
+    pub blueprints: Option<HashSet<String>>,
+    pub destinations: Option<HashSet<String>>,
+    pub creatures: Option<HashSet<String>>,
+    pub skills: Option<HashSet<String>>,
+    /// `npc_talk_key` slugs of every known NPC and crew member.
+    pub npcs: Option<HashSet<String>>,
+    /// data/factions.ron ids (standing requirements and rewards).
+    pub factions: Option<HashSet<String>>,
+}
+
+/// Just the naming fields of an NPC definition (`data/npcs.ron` rows carry
+/// an id; `data/npc/crew.ron` rows only a name). Other fields are ignored.
+#[derive(Debug, Clone, Deserialize)]
+struct NpcName {
+    #[serde(default)]
+    id: String,
+    name: String,
+}
+
+/// Talk keys for a RON list of NPC definitions: each row's `id` (when it
+/// has one) and the slug of its display name, so a quest may reference
+/// either "survival_mentor" or "brynn_ashwood".
+pub fn npc_keys_from_ron(text: &str) -> Result<HashSet<String>, String> {
+    let rows: Vec<NpcName> = ron::from_str(text).map_err(|e| e.to_string())?;
+    Ok(rows
+        .into_iter()
+        .flat_map(|r| [r.id, super::npc_talk_key(&r.name)])
+        .filter(|k| !k.is_empty())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_bump_the_counters_their_objectives_read() {
+        let pairs = [
+            (
+                QuestObjective::Craft { recipe_id: "smelt_iron".into(), quantity: 2 },
+                QuestEvent::Crafted { recipe_id: "smelt_iron".into() },
+            ),
+            (
+                QuestObjective::Kill { creature_id: "wolf".into(), quantity: 3 },
+                QuestEvent::Killed { creature_id: "wolf".into() },
+            ),
+            (
+                QuestObjective::Talk { npc_id: "dr_kel".into() },
+                QuestEvent::Talked { npc_id: "dr_kel".into() },
+            ),
+        ];
+        for (objective, event) in pairs {
+            assert_eq!(objective.counter().map(|c| c.0), event.counter());
+        }
+        assert!(QuestObjective::Survive { days: 3 }.counter().is_none());
+        let choice = QuestEvent::Chose { quest_id: "q".into(), branch_id: "b".into() };
+        assert!(choice.counter().is_none());
+    }
+
+    #[test]
+    fn unknown_refs_checks_only_known_categories() {
+        let refs = QuestRefs {
+            items: Some(["bandage_0".to_string()].into()),
+            npcs: Some(["dr_kel".to_string()].into()),
+            ..Default::default()
+        };
+        let ok = QuestObjective::Deliver {
+            item_id: "bandage_0".into(),
+            quantity: 2,
+            npc_id: "dr_kel".into(),
+        };
+        assert!(ok.unknown_refs(&refs).is_empty());
+        let bad = QuestObjective::Deliver {
+            item_id: "unobtainium".into(),
+            quantity: 1,
+            npc_id: "nobody".into(),
+        };
+        assert_eq!(bad.unknown_refs(&refs).len(), 2);
+        // No recipe list loaded: nothing to check against.
+        let craft = QuestObjective::Craft { recipe_id: "anything".into(), quantity: 1 };
+        assert!(craft.unknown_refs(&refs).is_empty());
+    }
+
+    #[test]
+    fn npc_keys_cover_ids_and_name_slugs() {
+        let keys = npc_keys_from_ron(
+            r#"[(id: "survival_mentor", name: "Brynn Ashwood", essential: true), (name: "Dr. Kel")]"#,
+        )
+        .unwrap();
+        for k in ["survival_mentor", "brynn_ashwood", "dr_kel"] {
+            assert!(keys.contains(k), "{k}");
+        }
+    }
 }
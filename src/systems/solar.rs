@@ -24,6 +24,29 @@ pub fn sun_factor(hour: f32) -> f32 {
     }
 }
 
+/// Average of `sun_factor` over `span_hours` starting at `start_hour`, integrated
+/// exactly across day boundaries. Lets a coarse step (the offline catch-up) credit
+/// the same solar energy the per-frame sim would have, whatever the step length.
+pub fn mean_sun_factor(start_hour: f32, span_hours: f32) -> f32 {
+    if span_hours <= 0.0 {
+        return sun_factor(start_hour.rem_euclid(24.0));
+    }
+    // Integral of sin(pi (h - 6) / 12) from 6 to h, for h within daylight.
+    let arc = |h: f64| {
+        let h = h.clamp(6.0, 18.0);
+        12.0 / std::f64::consts::PI * (1.0 - ((h - 6.0) / 12.0 * std::f64::consts::PI).cos())
+    };
+    let (mut h, end) = (start_hour as f64, start_hour as f64 + span_hours as f64);
+    let mut total = 0.0;
+    while h < end {
+        let day_start = (h / 24.0).floor() * 24.0;
+        let seg_end = end.min(day_start + 24.0);
+        total += arc(seg_end - day_start) - arc(h - day_start);
+        h = seg_end;
+    }
+    (total / span_hours as f64) as f32
+}
+
 pub struct SolarSystem;
 
 impl SolarSystem {
@@ -65,4 +88,16 @@ mod tests {
         let nine = sun_factor(9.0);
         assert!(nine > 0.6 && nine < 0.8, "9am ~0.707, got {nine}");
     }
+
+    #[test]
+    fn mean_sun_factor_integrates_across_days() {
+        // A full day averages 2/pi over the 12 daylight hours, so 1/pi over 24.
+        let day = mean_sun_factor(0.0, 24.0);
+        assert!((day - std::f32::consts::FRAC_1_PI).abs() < 1e-4, "got {day}");
+        // Where the span starts does not matter for whole days, even across midnight.
+        assert!((mean_sun_factor(17.0, 72.0) - day).abs() < 1e-4);
+        assert_eq!(mean_sun_factor(19.0, 10.0), 0.0, "night only");
+        // A short span converges on the point value.
+        assert!((mean_sun_factor(9.0, 0.01) - sun_factor(9.0)).abs() < 1e-3);
+    }
 }
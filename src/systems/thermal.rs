@@ -0,0 +1,462 @@
+//! Thermal network -- heat moving between the home's rooms and out through its walls.
+//!
+//! `HvacSystem` used to drift every `RoomEnvironment` toward a fixed outdoor temperature on its own;
+//! this module makes the home a connected set of rooms. The rooms `HomeStructure::room_map` finds
+//! become nodes, each holding the heat capacity of its air, its floor slab and half of every wall
+//! around it. Links between them are real conductances:
+//!   - a wall conducts U·A, with U = 1 / (surface air films + the stack's R-value), the R-value coming
+//!     from each layer's thickness over its `wall_materials.ron` conductivity -- so lining a steel
+//!     wall with mineral wool is something the rooms on either side feel;
+//!   - the outer shell and the roof lose heat to the outside the same way;
+//!   - windows conduct as glass panes, closed doors as a panel of their wall's material, and an OPEN
+//!     door swaps air by buoyancy (warm air out the top, cool air in underneath), which dwarfs any
+//!     wall.
+//!
+//! Heat enters from the machines, the people in each room and the sun through a glass roof;
+//! `HvacSystem` gathers those, steps the network on the game clock and publishes a `ThermalStatus`
+//! (key `thermal_status`) that the home air and the player's body temperature read.
+
+use std::collections::BTreeMap;
+
+use glam::Vec3;
+use serde::Deserialize;
+
+use crate::ship::door_panels::PANEL_THICKNESS;
+use crate::ship::home_structure::{wall_material, HomeStructure, OpeningKind, ROOM_CELL};
+
+/// Volumetric heat capacity of air (J/m³·K): 1.2 kg/m³ × 1005 J/kg·K.
+pub const AIR_J_M3K: f32 = 1206.0;
+/// Surface film resistance on an indoor face (m²·K/W, ISO 6946 horizontal flow).
+const R_SURFACE_IN: f32 = 0.13;
+/// Surface film resistance on an outdoor face (m²·K/W).
+const R_SURFACE_OUT: f32 = 0.04;
+/// Indoor film under a ceiling (m²·K/W, upward flow).
+const R_CEILING_IN: f32 = 0.10;
+/// Pitch (metres) at which walls are walked to find the rooms on each side.
+const SAMPLE_M: f32 = 0.25;
+/// Window panes are tempered glass (`wall_materials.ron` id 4).
+const GLASS_ID: u32 = 4;
+const GRAVITY: f32 = 9.81;
+/// A live door panel is matched to a door link within this distance (metres).
+const DOOR_MATCH_M: f32 = 0.3;
+/// Upper bound on Euler sub-steps per tick.
+const MAX_SUBSTEPS: usize = 2000;
+
+/// A room node: the floor-plan region it covers and how much heat it stores. Rides on the room's
+/// `RoomEnvironment` entity, which carries the temperature.
+#[derive(Debug, Clone)]
+pub struct ThermalRoom {
+    /// `detect_rooms` id ("room_3", or "home" for an undivided box).
+    pub id: String,
+    /// World-space floor AABB (x, z): which machines and people are in the room.
+    pub min: (f32, f32),
+    pub max: (f32, f32),
+    pub floor_m2: f32,
+    pub volume_m3: f32,
+    /// Heat stored per kelvin (J/K): the air, the floor slab and half of each bounding wall.
+    pub capacity_j_k: f32,
+    /// Ceiling area that lets the sun in (m²): the whole floor under a glass roof, else 0.
+    pub glazed_roof_m2: f32,
+}
+
+/// A door in a wall between two rooms.
+#[derive(Debug, Clone)]
+pub struct ThermalDoor {
+    /// World (x, z) of the door's centre on the wall line -- where its door panel is anchored.
+    pub at: (f32, f32),
+    pub width: f32,
+    pub height: f32,
+}
+
+/// A heat path between a room and a neighbour or the outside. All walls, windows and roof between
+/// the same pair are merged into one link; each door is a link of its own.
+#[derive(Debug, Clone)]
+pub struct ThermalLink {
+    pub a: String,
+    /// The room on the far side, or None for the outside.
+    pub b: Option<String>,
+    /// Conductance (W/K) with every door shut.
+    pub ua_w_k: f32,
+    /// An open door adds buoyant air exchange on top of `ua_w_k`.
+    pub door: Option<ThermalDoor>,
+}
+
+/// The parts of the network that are not a wall, from the `thermal` block of `data/hvac.ron`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ThermalTuning {
+    /// Metabolic heat of one occupant (W).
+    pub occupant_w: f32,
+    /// Outside air leaking into every room (air changes per hour).
+    pub air_changes_per_hour: f32,
+    /// Sun on a horizontal roof at noon (W/m²).
+    pub solar_irradiance_w_m2: f32,
+    /// Share of that sunlight a glass roof turns into room heat.
+    pub solar_heat_gain: f32,
+    /// Energy in a litre of genset fuel (kWh).
+    pub fuel_kwh_per_l: f32,
+    /// Share of a genset's waste heat released into its room (the rest leaves with the exhaust).
+    pub genset_room_fraction: f32,
+    /// Discharge coefficient of a doorway in the buoyant exchange.
+    pub door_discharge: f32,
+    /// Outside air a "vent" unit moves per kW (m³/s).
+    pub vent_m3s_per_kw: f32,
+    /// Thermostat proportional band (°C): off above the set point by half of it, flat out below.
+    pub thermostat_band_c: f32,
+}
+
+impl Default for ThermalTuning {
+    fn default() -> Self {
+        Self {
+            occupant_w: 100.0,
+            air_changes_per_hour: 0.5,
+            solar_irradiance_w_m2: 1000.0,
+            solar_heat_gain: 0.25,
+            fuel_kwh_per_l: 10.0,
+            genset_room_fraction: 0.3,
+            door_discharge: 0.6,
+            vent_m3s_per_kw: 1.0,
+            thermostat_band_c: 1.0,
+        }
+    }
+}
+
+/// One room in the live readout.
+#[derive(Debug, Clone, Default)]
+pub struct RoomTemp {
+    pub id: String,
+    pub min: (f32, f32),
+    pub max: (f32, f32),
+    pub temp_c: f32,
+    /// Heat entering from machines, people and the sun (W); negative while a cooler runs.
+    pub gain_w: f32,
+}
+
+/// Live thermal readout, published each tick (key `thermal_status`).
+#[derive(Debug, Clone, Default)]
+pub struct ThermalStatus {
+    pub rooms: Vec<RoomTemp>,
+    pub outside_c: f32,
+    /// Volume-weighted mean indoor temperature.
+    pub mean_c: f32,
+}
+
+impl ThermalStatus {
+    /// Temperature of the room holding world point (x, z), if any.
+    pub fn temp_at(&self, x: f32, z: f32) -> Option<f32> {
+        room_index_at(self.rooms.iter().map(|r| (r.min, r.max)), x, z).map(|i| self.rooms[i].temp_c)
+    }
+}
+
+/// Index of the room whose floor AABB holds (x, z). Where the boxes of L-shaped rooms overlap, the
+/// smaller room wins -- it is the one that box actually describes.
+pub fn room_index_at(bounds: impl Iterator<Item = ((f32, f32), (f32, f32))>, x: f32, z: f32) -> Option<usize> {
+    bounds
+        .enumerate()
+        .filter(|(_, (mn, mx))| x >= mn.0 && x <= mx.0 && z >= mn.1 && z <= mx.1)
+        .min_by(|(_, a), (_, b)| {
+            let area = |(mn, mx): &((f32, f32), (f32, f32))| (mx.0 - mn.0) * (mx.1 - mn.1);
+            area(a).partial_cmp(&area(b)).unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+}
+
+/// Build a home's thermal network: one `ThermalRoom` per detected room and the links between them,
+/// in world space (`origin` is the zone's box min corner). Walls are walked every `SAMPLE_M` and
+/// probed on both faces to find the rooms they separate; the floor is taken as adiabatic (the deck
+/// below is another heated zone, or the ground's slow mass).
+pub fn build_network(hs: &HomeStructure, origin: Vec3) -> (Vec<ThermalRoom>, Vec<ThermalLink>) {
+    let map = hs.room_map();
+    let h = hs.height.max(1.0);
+    let (ox, oz) = (origin.x, origin.z);
+    let shell_t = hs.shell_resolved_thickness();
+    let shell_cap = wall_material(hs.shell_material).map_or(0.0, |m| m.heat_capacity_per_m2(shell_t));
+    let mut rooms: Vec<ThermalRoom> = map
+        .rooms
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            let floor = map.floor_area(i);
+            let half = r.dimensions * 0.5;
+            ThermalRoom {
+                id: r.id.clone(),
+                min: (ox + r.center.x - half.x, oz + r.center.z - half.z),
+                max: (ox + r.center.x + half.x, oz + r.center.z + half.z),
+                floor_m2: floor,
+                volume_m3: floor * h,
+                capacity_j_k: floor * (h * AIR_J_M3K + shell_cap),
+                glazed_roof_m2: if hs.roof_is_glass() { floor } else { 0.0 },
+            }
+        })
+        .collect();
+
+    // Merged conductances, keyed (lower room, other room | None = outside).
+    let mut ua: BTreeMap<(usize, Option<usize>), f32> = BTreeMap::new();
+    let mut add = |a: usize, b: Option<usize>, g: f32| {
+        let key = match b {
+            Some(b) if b < a => (b, Some(a)),
+            _ => (a, b),
+        };
+        *ua.entry(key).or_default() += g;
+    };
+    // Walk a segment (box-local) at SAMPLE_M and hand each sample's arc position, step and the rooms
+    // `off` metres out on either face to `f`.
+    let walk = |a: (f32, f32), b: (f32, f32), off: f32, f: &mut dyn FnMut(f32, f32, Option<usize>, Option<usize>)| {
+        let (dx, dz) = (b.0 - a.0, b.1 - a.1);
+        let len = (dx * dx + dz * dz).sqrt();
+        if len < 0.05 {
+            return;
+        }
+        let (ux, uz) = (dx / len, dz / len);
+        let n = (len / SAMPLE_M).ceil().max(1.0) as usize;
+        let ds = len / n as f32;
+        for k in 0..n {
+            let s = (k as f32 + 0.5) * ds;
+            let (px, pz) = (a.0 + ux * s, a.1 + uz * s);
+            let left = map.room_at(px - uz * off, pz + ux * off);
+            let right = map.room_at(px + uz * off, pz - ux * off);
+            f(s, ds, left, right);
+        }
+    };
+
+    // Roof: every room loses heat up through its ceiling.
+    let u_roof = 1.0 / (R_CEILING_IN + hs.roof_r_value() + R_SURFACE_OUT);
+    for (i, r) in rooms.iter().enumerate() {
+        add(i, None, r.floor_m2 * u_roof);
+    }
+
+    // Outer shell: probe inward past the shell and any wall cell it might share.
+    let u_shell = 1.0 / (R_SURFACE_IN + hs.shell_r_value() + R_SURFACE_OUT);
+    let (w, d) = (hs.width.max(1.0), hs.depth.max(1.0));
+    let probe = shell_t + ROOM_CELL * 1.5;
+    for (a, b) in [((0.0, 0.0), (w, 0.0)), ((w, 0.0), (w, d)), ((w, d), (0.0, d)), ((0.0, d), (0.0, 0.0))] {
+        walk(a, b, probe, &mut |_, ds, left, right| {
+            // Counter-clockwise in (x, z) puts the interior on the left; a probe off the box is None.
+            if let Some(r) = left.or(right) {
+                add(r, None, ds * h * u_shell);
+                rooms[r].capacity_j_k += ds * h * shell_cap * 0.5;
+            }
+        });
+    }
+
+    // Interior walls: wall area and window glass conduct between the rooms on either face.
+    let glass_r = wall_material(GLASS_ID).map_or(0.012, |g| g.r_value(g.default_thickness_m));
+    let u_window = 1.0 / (2.0 * R_SURFACE_IN + glass_r);
+    let mut links: Vec<ThermalLink> = Vec::new();
+    for wall in &hs.walls {
+        let hw = wall.height.min(h).max(0.0);
+        let u_wall = 1.0 / (2.0 * R_SURFACE_IN + wall.r_value());
+        let cap = wall.heat_capacity_per_m2();
+        let off = wall.resolved_thickness() * 0.5 + ROOM_CELL * 1.5;
+        walk(wall.a, wall.b, off, &mut |s, ds, left, right| {
+            let (Some(ra), Some(rb)) = (left, right) else { return };
+            if ra == rb {
+                return;
+            }
+            let mut solid = ds * hw;
+            let mut g = 0.0;
+            for op in wall.openings.iter().filter(|op| s >= op.at && s < op.at + op.width) {
+                let oh = op.height.min(hw - op.sill).max(0.0);
+                solid -= ds * oh;
+                if op.kind == OpeningKind::Window {
+                    g += ds * oh * u_window;
+                }
+            }
+            let solid = solid.max(0.0);
+            add(ra, Some(rb), g + solid * u_wall);
+            rooms[ra].capacity_j_k += solid * cap * 0.5;
+            rooms[rb].capacity_j_k += solid * cap * 0.5;
+        });
+        // Doors get links of their own so an open one can mix the air.
+        let (dx, dz) = (wall.b.0 - wall.a.0, wall.b.1 - wall.a.1);
+        let len = (dx * dx + dz * dz).sqrt();
+        if len < 0.05 {
+            continue;
+        }
+        let (ux, uz) = (dx / len, dz / len);
+        let panel_r = wall_material(wall.material).map_or(PANEL_THICKNESS, |m| m.r_value(PANEL_THICKNESS));
+        for op in wall.openings.iter().filter(|op| op.kind == OpeningKind::Door && op.width > 0.01) {
+            let s = (op.at + op.width * 0.5).clamp(0.0, len);
+            let (px, pz) = (wall.a.0 + ux * s, wall.a.1 + uz * s);
+            let left = map.room_at(px - uz * off, pz + ux * off);
+            let right = map.room_at(px + uz * off, pz - ux * off);
+            let (Some(ra), Some(rb)) = (left, right) else { continue };
+            if ra == rb {
+                continue;
+            }
+            let dh = op.height.min(hw);
+            links.push(ThermalLink {
+                a: rooms[ra].id.clone(),
+                b: Some(rooms[rb].id.clone()),
+                ua_w_k: op.width * dh / (2.0 * R_SURFACE_IN + panel_r),
+                door: Some(ThermalDoor { at: (ox + px, oz + pz), width: op.width, height: dh }),
+            });
+        }
+    }
+
+    let merged = ua.into_iter().map(|((a, b), g)| ThermalLink {
+        a: rooms[a].id.clone(),
+        b: b.map(|b| rooms[b].id.clone()),
+        ua_w_k: g,
+        door: None,
+    });
+    let mut out: Vec<ThermalLink> = merged.collect();
+    out.extend(links);
+    (rooms, out)
+}
+
+/// Conductance (W/K) of the buoyant two-way air swap through `door` opened to `open` (0..1) between
+/// air at `ta` and `tb` °C: the textbook doorway flow Q = Cd/3 · W · H · √(g·H·ΔT / T).
+pub fn door_exchange_w_k(door: &ThermalDoor, open: f32, ta: f32, tb: f32, discharge: f32) -> f32 {
+    let dt = (ta - tb).abs();
+    let t_mean = ((ta + tb) * 0.5 + 273.15).max(100.0);
+    let flow = discharge / 3.0 * door.width * open.clamp(0.0, 1.0) * door.height * (GRAVITY * door.height * dt / t_mean).sqrt();
+    flow * AIR_J_M3K
+}
+
+/// Look up a door's live open fraction among the published door panels ((x, z), open).
+pub fn door_open_fraction(door: &ThermalDoor, panels: &[((f32, f32), f32)]) -> f32 {
+    panels
+        .iter()
+        .find(|(p, _)| (p.0 - door.at.0).hypot(p.1 - door.at.1) < DOOR_MATCH_M)
+        .map_or(0.0, |(_, open)| *open)
+}
+
+/// Advance room temperatures `temps` (°C) by `seconds` of physical time: `gains` watts into each
+/// room, `links` as (room, other room or None for outside, W/K). Explicit Euler, sub-stepped under
+/// the fastest room's time constant so a small closet beside an open door cannot overshoot.
+pub fn step(
+    temps: &mut [f32],
+    caps: &[f32],
+    gains: &[f32],
+    links: &[(usize, Option<usize>, f32)],
+    outside_c: f32,
+    seconds: f32,
+) {
+    if seconds <= 0.0 || temps.is_empty() {
+        return;
+    }
+    let mut g_sum = vec![0.0f32; temps.len()];
+    for &(a, b, g) in links {
+        g_sum[a] += g;
+        if let Some(b) = b {
+            g_sum[b] += g;
+        }
+    }
+    let tau = caps
+        .iter()
+        .zip(&g_sum)
+        .filter(|(_, g)| **g > 0.0)
+        .map(|(c, g)| c / g)
+        .fold(f32::INFINITY, f32::min);
+    let n = if tau.is_finite() { (seconds / (tau * 0.5)).ceil().clamp(1.0, MAX_SUBSTEPS as f32) as usize } else { 1 };
+    let h = seconds / n as f32;
+    let mut flux = vec![0.0f32; temps.len()];
+    for _ in 0..n {
+        flux.copy_from_slice(gains);
+        for &(a, b, g) in links {
+            let q = g * (b.map_or(outside_c, |b| temps[b]) - temps[a]);
+            flux[a] += q;
+            if let Some(b) = b {
+                flux[b] -= q;
+            }
+        }
+        for ((t, q), c) in temps.iter_mut().zip(&flux).zip(caps) {
+            *t += q * h / c.max(1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ship::home_structure::{InteriorWall, Opening, SurfaceLayer};
+
+    /// A 10 x 6 m box split down the middle by a steel wall.
+    fn two_rooms() -> HomeStructure {
+        let mut hs: HomeStructure = ron::from_str("(width: 10.0, depth: 6.0, height: 3.0)").expect("box parses");
+        hs.walls.push(InteriorWall {
+            a: (5.0, 0.0),
+            b: (5.0, 6.0),
+            height: 3.0,
+            material: 1,
+            openings: Vec::new(),
+            thickness: None,
+            layers: Vec::new(),
+        });
+        hs
+    }
+
+    fn between(links: &[ThermalLink], a: &str, b: &str) -> f32 {
+        links
+            .iter()
+            .filter(|l| l.door.is_none())
+            .filter(|l| (l.a == a && l.b.as_deref() == Some(b)) || (l.a == b && l.b.as_deref() == Some(a)))
+            .map(|l| l.ua_w_k)
+            .sum()
+    }
+
+    #[test]
+    fn dividing_wall_conducts_by_its_materials() {
+        let bare = two_rooms();
+        let (rooms, links) = build_network(&bare, Vec3::ZERO);
+        assert_eq!(rooms.len(), 2);
+        let steel = between(&links, "room_1", "room_2");
+        // 6 m x 3 m of 6 cm steel is all surface film: U ~ 1 / 0.26.
+        assert!((steel - 18.0 / 0.26).abs() < 10.0, "bare steel wall UA {steel}");
+
+        let mut wool = two_rooms();
+        wool.walls[0].layers.push(SurfaceLayer { material: 9, thickness_m: 0.10 });
+        let (_, links) = build_network(&wool, Vec3::ZERO);
+        let insulated = between(&links, "room_1", "room_2");
+        assert!(insulated * 8.0 < steel, "10 cm of mineral wool cuts it ~11x: {insulated} vs {steel}");
+
+        // Both rooms lose heat outside through the shell and the glass roof.
+        for r in ["room_1", "room_2"] {
+            assert!(links.iter().any(|l| l.a == r && l.b.is_none() && l.ua_w_k > 0.0), "{r} touches the outside");
+        }
+    }
+
+    #[test]
+    fn an_open_door_mixes_far_more_than_the_wall() {
+        let mut hs = two_rooms();
+        hs.walls[0].material = 3;
+        let door: Opening = ron::from_str("(kind: Door, at: 2.0, width: 0.9, height: 2.1)").expect("door parses");
+        hs.walls[0].openings.push(door);
+        let (_, links) = build_network(&hs, Vec3::new(100.0, 0.0, 50.0));
+        let link = links.iter().find(|l| l.door.is_some()).expect("the door is a link of its own");
+        let d = link.door.as_ref().unwrap();
+        assert!((d.at.0 - 105.0).abs() < 1e-3 && (d.at.1 - 52.45).abs() < 1e-3, "door sits on the wall in world space");
+        assert!(link.ua_w_k < 5.0, "a shut oak door barely conducts: {}", link.ua_w_k);
+        let swap = door_exchange_w_k(d, 1.0, 25.0, 15.0, 0.6);
+        assert!(swap > 50.0 * link.ua_w_k, "open, the air swap dominates: {swap} W/K");
+        assert_eq!(door_exchange_w_k(d, 0.0, 25.0, 15.0, 0.6), 0.0, "a shut door swaps no air");
+        assert_eq!(door_open_fraction(d, &[((105.1, 52.4), 0.7)]), 0.7);
+        assert_eq!(door_open_fraction(d, &[((90.0, 52.4), 1.0)]), 0.0, "someone else's door");
+    }
+
+    #[test]
+    fn heat_flows_from_the_heated_room_through_the_wall() {
+        // Two 60 m³ rooms, 70 W/K between them, 20 W/K each to a 0 °C outside; 2 kW in room 0.
+        let mut temps = [10.0, 10.0];
+        let caps = [1.0e6, 1.0e6];
+        let links = [(0, Some(1), 70.0), (0, None, 20.0), (1, None, 20.0)];
+        step(&mut temps, &caps, &[2000.0, 0.0], &links, 0.0, 3.0 * 86_400.0);
+        // Steady state: t0 = (2000 + 70 t1) / 90, t1 = 70 t0 / 90.
+        let t0 = 2000.0 / (90.0 - 70.0 * 70.0 / 90.0);
+        assert!((temps[0] - t0).abs() < 0.5, "heated room settles at {t0}, got {}", temps[0]);
+        assert!(temps[1] > 0.0 && temps[1] < temps[0], "the neighbour warms but less: {temps:?}");
+        // One huge step stays stable (sub-stepped).
+        let mut t = [10.0, 10.0];
+        step(&mut t, &[1000.0, 1000.0], &[0.0, 0.0], &links, 0.0, 3600.0);
+        assert!(t.iter().all(|v| (0.0..=10.0).contains(v)), "no overshoot: {t:?}");
+    }
+
+    #[test]
+    fn smallest_room_box_wins_where_they_overlap() {
+        let bounds = [((0.0, 0.0), (10.0, 10.0)), ((4.0, 4.0), (6.0, 6.0))];
+        assert_eq!(room_index_at(bounds.iter().copied(), 5.0, 5.0), Some(1));
+        assert_eq!(room_index_at(bounds.iter().copied(), 1.0, 1.0), Some(0));
+        assert_eq!(room_index_at(bounds.iter().copied(), 11.0, 1.0), None);
+    }
+}
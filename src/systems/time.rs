@@ -57,6 +57,17 @@ impl Default for GameTime {
     }
 }
 
+impl GameTime {
+    /// Move the clock to `elapsed_seconds`, recomputing hour, day and season.
+    pub fn set_elapsed(&mut self, elapsed_seconds: f64) {
+        self.elapsed_seconds = elapsed_seconds.max(0.0);
+        let day_seconds = self.elapsed_seconds % SECONDS_PER_DAY;
+        self.hour = (day_seconds / SECONDS_PER_DAY * 24.0) as f32;
+        self.day_count = (self.elapsed_seconds / SECONDS_PER_DAY) as u32;
+        self.season = Season::from_day(self.day_count);
+    }
+}
+
 /// Seconds per in-game day (real-time at time_scale=1.0).
 /// 20 real minutes = 1 game day. Public so sibling systems pacing
 /// per-game-day mechanics (economy passive income, v0.747) share it.
@@ -165,18 +176,19 @@ impl System for TimeSystem {
                 }
             }
         }
+        // Absolute clock restore: the saved game time plus the offline
+        // catch-up (`systems::catch_up`) lands here on the first frame.
+        if let Some(req) = data.get::<std::sync::Mutex<Option<f64>>>("time_set_elapsed_request") {
+            if let Ok(mut r) = req.lock() {
+                if let Some(secs) = r.take() {
+                    self.game_time.elapsed_seconds = secs.max(0.0);
+                }
+            }
+        }
         let scaled_dt = dt as f64 * self.game_time.time_scale as f64;
-        self.game_time.elapsed_seconds += scaled_dt;
-
-        // Calculate current hour from total elapsed seconds
-        let day_seconds = self.game_time.elapsed_seconds % SECONDS_PER_DAY;
-        self.game_time.hour = (day_seconds / SECONDS_PER_DAY * 24.0) as f32;
-
-        // Calculate day count
-        self.game_time.day_count = (self.game_time.elapsed_seconds / SECONDS_PER_DAY) as u32;
-
-        // Determine season
-        self.game_time.season = Season::from_day(self.game_time.day_count);
+        let elapsed = self.game_time.elapsed_seconds + scaled_dt;
+        // Hour, day count and season all follow from total elapsed seconds.
+        self.game_time.set_elapsed(elapsed);
 
         self.initialized = true;
 
@@ -275,6 +287,23 @@ mod game_time_export_tests {
         sys.tick(&mut world, 1.0, &data);
         assert!(sys.game_time().elapsed_seconds > 0.0);
     }
+
+    #[test]
+    fn elapsed_request_restores_the_clock_with_day_and_season() {
+        let mut data = DataStore::new();
+        data.insert("game_time", std::sync::Mutex::new(GameTime::default()));
+        data.insert("time_set_elapsed_request", std::sync::Mutex::new(Some(31.5 * SECONDS_PER_DAY)));
+        let mut world = hecs::World::new();
+        let mut sys = TimeSystem::new();
+        sys.tick(&mut world, 0.0, &data);
+        let gt = sys.game_time();
+        assert_eq!(gt.day_count, 31);
+        assert!((gt.hour - 12.0).abs() < 1e-3, "half a day in = noon, got {}", gt.hour);
+        assert_eq!(gt.season, Season::Summer);
+        // One-shot: the next tick advances from there instead of re-applying.
+        sys.tick(&mut world, 1.0, &data);
+        assert!(sys.game_time().elapsed_seconds > 31.5 * SECONDS_PER_DAY);
+    }
 }
 
 #[cfg(test)]
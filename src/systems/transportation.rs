@@ -1,18 +1,34 @@
 //! Transportation system — advances `CargoVehicle.progress` toward 1.0
 //! at `speed_per_day` rate. When progress reaches 1.0, vehicle is marked
-//! `arrived` and a log line fires. Game code reads arrived vehicles to
-//! unload payload at the destination.
+//! `arrived` and a log line fires.
+//!
+//! Fleet logistics: the fleet's ships and the settlements' depots are
+//! `LogisticsNode` entities whose `Inventory` is the real hold, seeded from
+//! `data/logistics.ron` (and a ship's layout file, e.g. the Pioneer's Cargo
+//! Bay in `data/ships/starter_fleet.ron`). A shipment takes concrete stacks
+//! out of the origin's hold, must fit its route's capacity and burns fuel from
+//! the origin at departure; in transit it can be intercepted and lose part of
+//! every stack; on arrival it unloads into the destination as far as the hold
+//! has room. Standing orders top a destination up on a schedule. Requests come
+//! in on the `"logistics_request"` channel; the Home page reads the
+//! `"logistics_report"` slot.
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use serde::Deserialize;
 
-use crate::ecs::components::CargoVehicle;
+use crate::ecs::components::{CargoVehicle, LogisticsNode, StandingOrder};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
+use crate::ship::layout::{ShipDef, ShipHold};
+use crate::systems::economy::fleet::FleetResources;
+use crate::systems::inventory::{Inventory, ItemRegistry};
 
 /// 1 game day = 1200 real seconds.
 const REAL_SECONDS_PER_GAME_DAY: f32 = 1200.0;
+/// Lines kept in the report's event log.
+const LOG_LINES: usize = 8;
 
 /// Top-level RON schema for `data/transportation.ron`.
 #[derive(Debug, Deserialize)]
@@ -22,11 +38,112 @@ pub struct TransportationData {
     #[serde(default)] pub space_infrastructure: Vec<ron::Value>,
 }
 
+/// A fleet ship or settlement depot in `data/logistics.ron`. A ship with a
+/// `layout` takes its name and hold from that ship file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct NodeDef {
+    pub id: String,
+    #[serde(default)] pub name: String,
+    /// Ship layout file under the data dir (e.g. "ships/starter_fleet.ron").
+    #[serde(default)] pub layout: String,
+    #[serde(default)] pub hold: ShipHold,
+}
+
+/// A cargo route between two nodes (either direction).
+#[derive(Debug, Clone, Deserialize)]
+pub struct CargoRoute {
+    pub from: String,
+    pub to: String,
+    /// Game days end to end.
+    pub days: f32,
+    /// Most cargo one shipment carries.
+    pub capacity_kg: f32,
+    /// Units of the fuel item the origin burns per departure.
+    #[serde(default)] pub fuel: u32,
+    /// Chance per game day in transit that raiders hit the shipment.
+    #[serde(default)] pub interception_per_day: f32,
+}
+
+impl CargoRoute {
+    fn joins(&self, a: &str, b: &str) -> bool {
+        (self.from == a && self.to == b) || (self.from == b && self.to == a)
+    }
+}
+
+/// Top-level RON schema for `data/logistics.ron`.
+#[derive(Debug, Default, Deserialize)]
+pub struct LogisticsData {
+    #[serde(default)] pub fuel_item: String,
+    /// Share of each stack raiders take from an intercepted shipment.
+    #[serde(default)] pub interception_loss: f32,
+    #[serde(default)] pub ships: Vec<NodeDef>,
+    #[serde(default)] pub settlements: Vec<NodeDef>,
+    #[serde(default)] pub routes: Vec<CargoRoute>,
+    #[serde(default)] pub standing_orders: Vec<StandingOrder>,
+}
+
+/// A logistics intent from the GUI (`"logistics_request"` channel).
+#[derive(Debug, Clone)]
+pub enum LogisticsRequest {
+    /// Ship these stacks from one node to another now.
+    Ship { from: String, to: String, cargo: Vec<(String, u32)> },
+    /// Add (or replace, by id) a standing order.
+    AddOrder(StandingOrder),
+    /// Pause / resume a standing order.
+    ToggleOrder(String),
+    CancelOrder(String),
+}
+
+/// One node's hold, for the Home page.
+#[derive(Debug, Clone, Default)]
+pub struct NodeReport {
+    pub id: String,
+    pub name: String,
+    pub kind: String,
+    pub mass_kg: f32,
+    pub capacity_kg: f32,
+    /// (item id, count), largest first.
+    pub cargo: Vec<(String, u32)>,
+}
+
+/// One shipment in transit (or waiting to unload).
+#[derive(Debug, Clone, Default)]
+pub struct ShipmentReport {
+    pub from: String,
+    pub to: String,
+    pub progress: f32,
+    pub arrived: bool,
+    pub cargo: Vec<(String, u32)>,
+    pub order: Option<String>,
+}
+
+/// Live fleet logistics, published every tick under `"logistics_report"`.
+#[derive(Debug, Clone, Default)]
+pub struct LogisticsReport {
+    /// Every ship's hold totalled.
+    pub fleet: FleetResources,
+    pub nodes: Vec<NodeReport>,
+    pub shipments: Vec<ShipmentReport>,
+    pub orders: Vec<StandingOrder>,
+    /// (from, to) node id pairs that have a route, for the ship form.
+    pub routes: Vec<(String, String)>,
+    /// Recent departures, deliveries, interceptions and refusals, newest last.
+    pub log: Vec<String>,
+}
+
 /// Manages roads, rail networks, and space infrastructure.
 pub struct TransportationSystem {
     pub data: TransportationData,
+    pub logistics: LogisticsData,
     /// Total deliveries completed since startup.
     pub lifetime_deliveries: u64,
+    /// Node id -> name, from the data (names for log lines).
+    names: HashMap<String, String>,
+    /// The fleet was seeded this session (never re-seeded once the player owns it).
+    seeded: bool,
+    /// Ticks run, salting the interception rolls.
+    ticks: u64,
+    log: Vec<String>,
 }
 
 impl TransportationSystem {
@@ -41,35 +158,676 @@ impl TransportationSystem {
             TransportationData { roads: vec![], rail: vec![], space_infrastructure: vec![] }
         });
         log::info!("Loaded transportation data: {} roads, {} rail", data.roads.len(), data.rail.len());
-        Self { data, lifetime_deliveries: 0 }
+        let logistics = Self::load_logistics(data_dir);
+        let mut sys = Self::with_logistics(logistics);
+        sys.data = data;
+        sys
+    }
+
+    /// Read `logistics.ron` and fill in every ship that points at a layout file.
+    fn load_logistics(data_dir: &Path) -> LogisticsData {
+        let mut logistics: LogisticsData = match crate::embedded_data::read_data_or_embedded(data_dir, "logistics.ron") {
+            Some(text) => ron::from_str(&text).unwrap_or_else(|e| {
+                log::warn!("Failed to parse logistics.ron: {e}");
+                LogisticsData::default()
+            }),
+            None => {
+                log::warn!("logistics.ron not found (no embedded copy); no fleet logistics");
+                LogisticsData::default()
+            }
+        };
+        for ship in logistics.ships.iter_mut().filter(|s| !s.layout.is_empty()) {
+            match crate::embedded_data::read_data_or_embedded(data_dir, &ship.layout)
+                .ok_or_else(|| "not found".to_string())
+                .and_then(|t| ron::from_str::<ShipDef>(&t).map_err(|e| e.to_string()))
+            {
+                Ok(def) => {
+                    if ship.name.is_empty() {
+                        ship.name = def.name;
+                    }
+                    if ship.hold.capacity_kg <= 0.0 {
+                        ship.hold = def.hold;
+                    }
+                }
+                Err(e) => log::warn!("Ship '{}': failed to load {}: {e}", ship.id, ship.layout),
+            }
+        }
+        log::info!(
+            "Loaded fleet logistics: {} ships, {} settlements, {} routes",
+            logistics.ships.len(),
+            logistics.settlements.len(),
+            logistics.routes.len()
+        );
+        logistics
+    }
+
+    /// A system over already-parsed logistics (tests, and `new`).
+    pub fn with_logistics(logistics: LogisticsData) -> Self {
+        let names = logistics
+            .ships
+            .iter()
+            .chain(&logistics.settlements)
+            .map(|n| (n.id.clone(), if n.name.is_empty() { n.id.clone() } else { n.name.clone() }))
+            .collect();
+        Self {
+            data: TransportationData { roads: vec![], rail: vec![], space_infrastructure: vec![] },
+            logistics,
+            lifetime_deliveries: 0,
+            names,
+            seeded: false,
+            ticks: 0,
+            log: Vec::new(),
+        }
+    }
+
+    fn name<'a>(&'a self, id: &'a str) -> &'a str {
+        self.names.get(id).map_or(id, |n| n.as_str())
+    }
+
+    fn note(&mut self, line: String) {
+        log::debug!("Logistics: {line}");
+        self.log.push(line);
+        if self.log.len() > LOG_LINES {
+            self.log.remove(0);
+        }
+    }
+
+    /// Spawn the fleet's ships, depots and standing orders, unless the world
+    /// already has them (restored from a save).
+    fn seed(&mut self, world: &mut hecs::World, items: Option<&ItemRegistry>) {
+        self.seeded = true;
+        if world.query::<&LogisticsNode>().iter().next().is_some() {
+            return;
+        }
+        let nodes = self
+            .logistics
+            .ships
+            .iter()
+            .map(|n| (n, "ship"))
+            .chain(self.logistics.settlements.iter().map(|n| (n, "settlement")));
+        for (def, kind) in nodes {
+            let mut inv = Inventory::new(8);
+            for (item, qty) in &def.hold.cargo {
+                stow(&mut inv, items, item, *qty);
+            }
+            world.spawn((
+                LogisticsNode {
+                    id: def.id.clone(),
+                    name: self.name(&def.id).to_string(),
+                    kind: kind.to_string(),
+                    capacity_kg: def.hold.capacity_kg,
+                },
+                inv,
+            ));
+        }
+        for order in &self.logistics.standing_orders {
+            world.spawn((order.clone(),));
+        }
+    }
+
+    /// Load `cargo` out of `from` onto a new shipment to `to`. Refusals leave
+    /// every hold untouched.
+    fn dispatch(
+        &mut self,
+        world: &mut hecs::World,
+        items: Option<&ItemRegistry>,
+        from: &str,
+        to: &str,
+        cargo: &[(String, u32)],
+        order: Option<String>,
+    ) -> Result<String, String> {
+        let route = self
+            .logistics
+            .routes
+            .iter()
+            .find(|r| r.joins(from, to))
+            .cloned()
+            .ok_or_else(|| format!("No route from {} to {}", self.name(from), self.name(to)))?;
+        let cargo: Vec<(String, u32)> = cargo.iter().filter(|(_, q)| *q > 0).cloned().collect();
+        if cargo.is_empty() {
+            return Err("Nothing to ship".to_string());
+        }
+        let mass: f32 = cargo.iter().map(|(id, q)| unit_mass(items, id) * *q as f32).sum();
+        if mass > route.capacity_kg {
+            return Err(format!("Too heavy for the route: {mass:.0} / {:.0} kg", route.capacity_kg));
+        }
+        let origin = node_entity(world, from).ok_or_else(|| format!("Unknown node {from}"))?;
+        if node_entity(world, to).is_none() {
+            return Err(format!("Unknown node {to}"));
+        }
+        let fuel_item = self.logistics.fuel_item.clone();
+        {
+            let inv = world.get::<&Inventory>(origin).map_err(|_| "origin has no hold".to_string())?;
+            let mut need: HashMap<&str, u32> = HashMap::new();
+            for (id, q) in &cargo {
+                *need.entry(id.as_str()).or_default() += q;
+            }
+            if route.fuel > 0 {
+                *need.entry(fuel_item.as_str()).or_default() += route.fuel;
+            }
+            for (id, q) in need {
+                let have = inv.count_item(id);
+                if have < q {
+                    return Err(if id == fuel_item && have + route.fuel >= q {
+                        format!("Not enough fuel at {} ({} of {} {id})", self.name(from), have, route.fuel)
+                    } else {
+                        format!("{} only has {have}x {id}", self.name(from))
+                    });
+                }
+            }
+        }
+        {
+            let mut inv = world.get::<&mut Inventory>(origin).map_err(|_| "origin has no hold".to_string())?;
+            for (id, q) in &cargo {
+                inv.remove_item(id, *q);
+            }
+            if route.fuel > 0 {
+                inv.remove_item(&fuel_item, route.fuel);
+            }
+        }
+        world.spawn((CargoVehicle {
+            route_id: format!("{}-{}", route.from, route.to),
+            progress: 0.0,
+            speed_per_day: 1.0 / route.days.max(0.01),
+            payload: cargo.clone(),
+            arrived: false,
+            origin: from.to_string(),
+            destination: to.to_string(),
+            order,
+        },));
+        Ok(format!(
+            "{} → {}: {} ({mass:.0} kg, {:.1} days)",
+            self.name(from),
+            self.name(to),
+            cargo_line(&cargo),
+            route.days
+        ))
+    }
+
+    /// Run every standing order that is due.
+    fn run_orders(&mut self, world: &mut hecs::World, items: Option<&ItemRegistry>, days: f32) {
+        let mut due: Vec<StandingOrder> = Vec::new();
+        for (_e, order) in world.query_mut::<&mut StandingOrder>() {
+            if order.paused {
+                continue;
+            }
+            order.since_days += days;
+            if order.since_days >= order.every_days {
+                order.since_days = 0.0;
+                due.push(order.clone());
+            }
+        }
+        for order in due {
+            let at_dest = node_entity(world, &order.to)
+                .and_then(|e| world.get::<&Inventory>(e).ok().map(|inv| inv.count_item(&order.item)))
+                .unwrap_or(0);
+            let in_transit: u32 = world
+                .query::<&CargoVehicle>()
+                .iter()
+                .filter(|(_, v)| v.order.as_deref() == Some(order.id.as_str()))
+                .flat_map(|(_, v)| v.payload.iter().filter(|(id, _)| *id == order.item).map(|(_, q)| *q))
+                .sum();
+            let short = order.keep.saturating_sub(at_dest + in_transit);
+            if short == 0 {
+                continue;
+            }
+            let mut available = node_entity(world, &order.from)
+                .and_then(|e| world.get::<&Inventory>(e).ok().map(|inv| inv.count_item(&order.item)))
+                .unwrap_or(0);
+            if order.item == self.logistics.fuel_item {
+                let fuel = self.logistics.routes.iter().find(|r| r.joins(&order.from, &order.to)).map_or(0, |r| r.fuel);
+                available = available.saturating_sub(fuel);
+            }
+            let qty = short.min(order.lot).min(available);
+            let line = if qty == 0 {
+                Err(format!("{} is out of {}", self.name(&order.from), order.item))
+            } else {
+                self.dispatch(world, items, &order.from, &order.to, &[(order.item.clone(), qty)], Some(order.id.clone()))
+            };
+            match line {
+                Ok(l) => self.note(format!("Order {}: {l}", order.id)),
+                Err(e) => self.note(format!("Order {} stalled: {e}", order.id)),
+            }
+        }
+    }
+
+    /// Move shipments along, roll for raiders, and unload arrivals.
+    fn advance(&mut self, world: &mut hecs::World, items: Option<&ItemRegistry>, days: f32) {
+        let loss = self.logistics.interception_loss.clamp(0.0, 1.0);
+        let mut hits: Vec<String> = Vec::new();
+        for (e, v) in world.query_mut::<&mut CargoVehicle>() {
+            if v.arrived {
+                continue;
+            }
+            let risk = self
+                .logistics
+                .routes
+                .iter()
+                .find(|r| r.joins(&v.origin, &v.destination))
+                .map_or(0.0, |r| r.interception_per_day.clamp(0.0, 1.0));
+            let step = (v.speed_per_day * days).min(1.0 - v.progress);
+            let transit_days = step / v.speed_per_day.max(1e-6);
+            if risk > 0.0 && roll(e, self.ticks) < 1.0 - (1.0 - risk).powf(transit_days) {
+                let mut taken = Vec::new();
+                for (id, q) in v.payload.iter_mut() {
+                    let lost = ((*q as f32 * loss).ceil() as u32).min(*q);
+                    if lost > 0 {
+                        *q -= lost;
+                        taken.push((id.clone(), lost));
+                    }
+                }
+                v.payload.retain(|(_, q)| *q > 0);
+                if !taken.is_empty() {
+                    hits.push(format!(
+                        "{} → {} intercepted: lost {}",
+                        self.names.get(&v.origin).unwrap_or(&v.origin),
+                        self.names.get(&v.destination).unwrap_or(&v.destination),
+                        cargo_line(&taken)
+                    ));
+                }
+            }
+            v.progress += step;
+            if v.progress >= 1.0 - 1e-6 {
+                v.progress = 1.0;
+                v.arrived = true;
+                log::debug!("Transportation: cargo vehicle {:?} arrived on route '{}'", e, v.route_id);
+            }
+        }
+        for line in hits {
+            self.note(line);
+        }
+
+        let arrived: Vec<(hecs::Entity, CargoVehicle)> = world
+            .query::<&CargoVehicle>()
+            .iter()
+            .filter(|(_, v)| v.arrived)
+            .map(|(e, v)| (e, v.clone()))
+            .collect();
+        for (e, mut v) in arrived {
+            let dest = node_entity(world, &v.destination);
+            let Some(dest) = dest.filter(|d| world.get::<&Inventory>(*d).is_ok()) else {
+                // A node that no longer exists, or has no hold to unload into (a
+                // hand-edited save): the cargo goes back where it came from.
+                if dest.is_some() {
+                    self.note(format!("{} has no hold: cargo turned back", self.name(&v.destination)));
+                }
+                std::mem::swap(&mut v.origin, &mut v.destination);
+                v.progress = 0.0;
+                v.arrived = false;
+                let _ = world.insert_one(e, v);
+                continue;
+            };
+            let mut landed = Vec::new();
+            {
+                let capacity = world.get::<&LogisticsNode>(dest).map_or(0.0, |n| n.capacity_kg);
+                let Ok(mut inv) = world.get::<&mut Inventory>(dest) else { continue };
+                let mut free = capacity - hold_mass(&inv, items);
+                for (id, q) in v.payload.iter_mut() {
+                    let unit = unit_mass(items, id);
+                    let fits = if unit > 0.0 { ((free / unit).floor().max(0.0) as u32).min(*q) } else { *q };
+                    if fits > 0 {
+                        stow(&mut inv, items, id, fits);
+                        free -= unit * fits as f32;
+                        *q -= fits;
+                        landed.push((id.clone(), fits));
+                    }
+                }
+            }
+            v.payload.retain(|(_, q)| *q > 0);
+            let (from, to) = (self.name(&v.origin).to_string(), self.name(&v.destination).to_string());
+            if !landed.is_empty() {
+                self.note(format!("Delivered to {to} from {from}: {}", cargo_line(&landed)));
+            }
+            if v.payload.is_empty() {
+                let _ = world.despawn(e);
+                self.lifetime_deliveries += 1;
+            } else {
+                if !landed.is_empty() || !self.log.last().is_some_and(|l| l.starts_with(&format!("{to} hold full"))) {
+                    self.note(format!("{to} hold full: {} waiting aboard", cargo_line(&v.payload)));
+                }
+                let _ = world.insert_one(e, v);
+            }
+        }
+    }
+
+    /// Everything the Home page shows.
+    fn report(&self, world: &hecs::World, items: Option<&ItemRegistry>) -> LogisticsReport {
+        let mut nodes: Vec<NodeReport> = world
+            .query::<(&LogisticsNode, &Inventory)>()
+            .iter()
+            .map(|(_, (n, inv))| {
+                let mut cargo: Vec<(String, u32)> = inv.summary().into_iter().collect();
+                cargo.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+                NodeReport {
+                    id: n.id.clone(),
+                    name: n.name.clone(),
+                    kind: n.kind.clone(),
+                    mass_kg: hold_mass(inv, items),
+                    capacity_kg: n.capacity_kg,
+                    cargo,
+                }
+            })
+            .collect();
+        nodes.sort_by(|a, b| (b.kind == "ship").cmp(&(a.kind == "ship")).then(a.name.cmp(&b.name)));
+        let mut shipments: Vec<ShipmentReport> = world
+            .query::<&CargoVehicle>()
+            .iter()
+            .filter(|(_, v)| !v.destination.is_empty())
+            .map(|(_, v)| ShipmentReport {
+                from: self.name(&v.origin).to_string(),
+                to: self.name(&v.destination).to_string(),
+                progress: v.progress,
+                arrived: v.arrived,
+                cargo: v.payload.clone(),
+                order: v.order.clone(),
+            })
+            .collect();
+        shipments.sort_by(|a, b| b.progress.total_cmp(&a.progress));
+        let mut orders: Vec<StandingOrder> = world.query::<&StandingOrder>().iter().map(|(_, o)| o.clone()).collect();
+        orders.sort_by(|a, b| a.id.cmp(&b.id));
+        LogisticsReport {
+            fleet: FleetResources::from_world(world),
+            nodes,
+            shipments,
+            orders,
+            routes: self.logistics.routes.iter().map(|r| (r.from.clone(), r.to.clone())).collect(),
+            log: self.log.clone(),
+        }
     }
 }
 
+/// Add to a hold, growing its slots so nothing overflows (mass is the limit, not slots).
+fn stow(inv: &mut Inventory, items: Option<&ItemRegistry>, item: &str, qty: u32) {
+    let max_stack = items.map_or(99, |r| r.max_stack_for(item)).max(1);
+    inv.ensure_slots(inv.used_slots() + qty.div_ceil(max_stack) as usize + 1);
+    inv.add_item(item, qty, max_stack);
+}
+
+fn unit_mass(items: Option<&ItemRegistry>, item: &str) -> f32 {
+    items.map_or(0.0, |r| r.mass_for(item))
+}
+
+fn hold_mass(inv: &Inventory, items: Option<&ItemRegistry>) -> f32 {
+    inv.slots.iter().flatten().map(|s| unit_mass(items, &s.item_id) * s.quantity as f32).sum()
+}
+
+fn node_entity(world: &hecs::World, id: &str) -> Option<hecs::Entity> {
+    world.query::<&LogisticsNode>().iter().find(|(_, n)| n.id == id).map(|(e, _)| e)
+}
+
+/// "10x iron_ore_0, 2x coal_0".
+fn cargo_line(cargo: &[(String, u32)]) -> String {
+    cargo.iter().map(|(id, q)| format!("{q}x {id}")).collect::<Vec<_>>().join(", ")
+}
+
+/// Deterministic 0..1 roll per shipment and tick (splitmix64), like the
+/// factories' quality roll: no RNG state to carry or save.
+fn roll(entity: hecs::Entity, tick: u64) -> f32 {
+    let mut z = entity.to_bits().get() ^ tick.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
+}
+
 impl System for TransportationSystem {
     fn name(&self) -> &str { "TransportationSystem" }
 
-    fn tick(&mut self, world: &mut hecs::World, dt: f32, _data: &DataStore) {
-        let day_fraction = dt / REAL_SECONDS_PER_GAME_DAY;
-        if day_fraction <= 0.0 { return; }
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
+        let items = data.get::<ItemRegistry>("item_registry");
+        if !self.seeded {
+            self.seed(world, items);
+        }
+        self.ticks += 1;
+
+        let requests: Vec<LogisticsRequest> = data
+            .get::<std::sync::Mutex<Vec<LogisticsRequest>>>("logistics_request")
+            .and_then(|m| m.lock().ok().map(|mut q| std::mem::take(&mut *q)))
+            .unwrap_or_default();
+        for req in requests {
+            match req {
+                LogisticsRequest::Ship { from, to, cargo } => {
+                    let line = self.dispatch(world, items, &from, &to, &cargo, None);
+                    self.note(line.unwrap_or_else(|e| format!("Not shipped: {e}")));
+                }
+                LogisticsRequest::AddOrder(order) => {
+                    let old: Vec<hecs::Entity> = world
+                        .query::<&StandingOrder>()
+                        .iter()
+                        .filter(|(_, o)| o.id == order.id)
+                        .map(|(e, _)| e)
+                        .collect();
+                    for e in old {
+                        let _ = world.despawn(e);
+                    }
+                    self.note(format!(
+                        "Standing order {}: keep {}x {} at {}",
+                        order.id,
+                        order.keep,
+                        order.item,
+                        self.name(&order.to)
+                    ));
+                    world.spawn((order,));
+                }
+                LogisticsRequest::ToggleOrder(id) => {
+                    for (_e, o) in world.query_mut::<&mut StandingOrder>() {
+                        if o.id == id {
+                            o.paused = !o.paused;
+                        }
+                    }
+                }
+                LogisticsRequest::CancelOrder(id) => {
+                    let gone: Vec<hecs::Entity> = world
+                        .query::<&StandingOrder>()
+                        .iter()
+                        .filter(|(_, o)| o.id == id)
+                        .map(|(e, _)| e)
+                        .collect();
+                    for e in gone {
+                        let _ = world.despawn(e);
+                    }
+                }
+            }
+        }
+
+        let day_fraction = crate::systems::time::scaled_dt(dt, data) / REAL_SECONDS_PER_GAME_DAY;
+        if day_fraction > 0.0 {
+            self.run_orders(world, items, day_fraction);
+            self.advance(world, items, day_fraction);
+        }
+
+        if let Some(slot) = data.get::<std::sync::Mutex<LogisticsReport>>("logistics_report") {
+            if let Ok(mut r) = slot.lock() {
+                *r = self.report(world, items);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> ItemRegistry {
+        ItemRegistry::from_csv(include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/items.csv"))).unwrap()
+    }
+
+    fn shipped() -> (hecs::World, DataStore, TransportationSystem) {
+        let data_dir = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/data"));
+        let mut sys = TransportationSystem::new(data_dir);
+        sys.logistics.standing_orders.clear();
+        let mut data = DataStore::new();
+        data.insert("item_registry", items());
+        data.insert("logistics_request", std::sync::Mutex::new(Vec::<LogisticsRequest>::new()));
+        data.insert("logistics_report", std::sync::Mutex::new(LogisticsReport::default()));
+        let mut world = hecs::World::new();
+        sys.tick(&mut world, 0.0, &data);
+        (world, data, sys)
+    }
+
+    fn count(world: &hecs::World, node: &str, item: &str) -> u32 {
+        let e = node_entity(world, node).unwrap();
+        world.get::<&Inventory>(e).unwrap().count_item(item)
+    }
+
+    fn request(data: &DataStore, req: LogisticsRequest) {
+        data.get::<std::sync::Mutex<Vec<LogisticsRequest>>>("logistics_request").unwrap().lock().unwrap().push(req);
+    }
+
+    fn report(data: &DataStore) -> LogisticsReport {
+        data.get::<std::sync::Mutex<LogisticsReport>>("logistics_report").unwrap().lock().unwrap().clone()
+    }
+
+    #[test]
+    fn the_fleet_pool_is_the_ships_holds_and_a_shipment_moves_real_stacks() {
+        let (mut world, data, mut sys) = shipped();
+        sys.logistics.routes.iter_mut().for_each(|r| r.interception_per_day = 0.0);
+        let r = report(&data);
+        let pioneer = r.nodes.iter().find(|n| n.id == "pioneer").expect("the Pioneer from starter_fleet.ron");
+        assert_eq!(pioneer.name, "Pioneer");
+        assert_eq!(pioneer.capacity_kg, 6000.0);
+        assert_eq!(r.fleet.get("fuel_refined_0"), 100.0, "Pioneer 40 + Mule 60; depots are not the fleet");
+        assert_eq!(r.fleet.get("iron_ore_0"), 120.0);
 
-        let mut arrivals: Vec<(hecs::Entity, String)> = Vec::new();
+        request(&data, LogisticsRequest::Ship {
+            from: "mule".into(),
+            to: "home".into(),
+            cargo: vec![("iron_ore_0".into(), 30)],
+        });
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(count(&world, "mule", "iron_ore_0"), 90, "the ore left the hold");
+        assert_eq!(count(&world, "mule", "fuel_refined_0"), 56, "and the route burned 4 fuel");
+        let r = report(&data);
+        assert_eq!(r.fleet.get("iron_ore_0"), 90.0, "cargo in transit is out of the pool");
+        assert_eq!(r.shipments.len(), 1);
 
-        for (entity, vehicle) in world.query_mut::<&mut CargoVehicle>() {
-            if vehicle.arrived { continue; }
-            vehicle.progress += vehicle.speed_per_day * day_fraction;
-            if vehicle.progress >= 1.0 {
-                vehicle.progress = 1.0;
-                vehicle.arrived = true;
-                arrivals.push((entity, vehicle.route_id.clone()));
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 0.25, &data);
+        assert_eq!(report(&data).shipments[0].progress, 0.5, "half way on a half-day route");
+        assert_eq!(count(&world, "home", "iron_ore_0"), 0);
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 0.25, &data);
+        assert_eq!(count(&world, "home", "iron_ore_0"), 30);
+        assert!(report(&data).shipments.is_empty());
+        assert_eq!(sys.lifetime_deliveries, 1);
+    }
+
+    #[test]
+    fn capacity_and_fuel_limits_refuse_without_touching_the_hold() {
+        let (mut world, data, mut sys) = shipped();
+        // 30 ore is 135 kg.
+        sys.logistics.routes.iter_mut().for_each(|r| r.capacity_kg = 100.0);
+        request(&data, LogisticsRequest::Ship {
+            from: "mule".into(),
+            to: "home".into(),
+            cargo: vec![("iron_ore_0".into(), 30)],
+        });
+        sys.tick(&mut world, 0.0, &data);
+        let r = report(&data);
+        assert!(r.log.last().unwrap().contains("Too heavy"), "{:?}", r.log);
+        assert!(r.shipments.is_empty());
+        assert_eq!(count(&world, "mule", "iron_ore_0"), 120);
+        assert_eq!(count(&world, "mule", "fuel_refined_0"), 60);
+
+        // The homestead depot holds 10 fuel and the overland route burns 1 a trip:
+        // shipping 1 fuel each time gets five trips out.
+        for _ in 0..8 {
+            request(&data, LogisticsRequest::Ship {
+                from: "home".into(),
+                to: "commons".into(),
+                cargo: vec![("fuel_refined_0".into(), 1)],
+            });
+        }
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(count(&world, "home", "fuel_refined_0"), 0);
+        assert_eq!(report(&data).shipments.len(), 5);
+        assert!(report(&data).log.iter().any(|l| l.contains("only has 0x fuel_refined_0")));
+    }
+
+    #[test]
+    fn raiders_take_a_share_of_every_stack_and_a_full_hold_keeps_the_rest_aboard() {
+        let (mut world, data, mut sys) = shipped();
+        sys.logistics.routes.iter_mut().for_each(|r| r.interception_per_day = 1.0);
+        request(&data, LogisticsRequest::Ship {
+            from: "mule".into(),
+            to: "commons".into(),
+            cargo: vec![("iron_ore_0".into(), 9), ("coal_0".into(), 1)],
+        });
+        sys.tick(&mut world, 0.0, &data);
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 2.0, &data);
+        assert_eq!(count(&world, "commons", "iron_ore_0"), 4, "half of 9 rounded up is lost");
+        assert_eq!(count(&world, "commons", "coal_0"), 0, "a single unit is lost whole");
+        assert!(report(&data).log.iter().any(|l| l.contains("intercepted")));
+
+        // Shrink the homestead depot to its 10 fuel (8 kg) plus 8 kg of room:
+        // 16 of 20 rice land, the rest waits aboard.
+        sys.logistics.routes.iter_mut().for_each(|r| r.interception_per_day = 0.0);
+        for (_e, n) in world.query_mut::<&mut LogisticsNode>() {
+            if n.id == "home" {
+                n.capacity_kg = 16.0;
             }
         }
+        request(&data, LogisticsRequest::Ship {
+            from: "pioneer".into(),
+            to: "home".into(),
+            cargo: vec![("rice_0".into(), 20)],
+        });
+        sys.tick(&mut world, 0.0, &data);
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY, &data);
+        let waiting = report(&data).shipments;
+        assert_eq!(count(&world, "home", "rice_0"), 16);
+        assert_eq!(waiting.len(), 1);
+        assert!(waiting[0].arrived);
+        assert_eq!(waiting[0].cargo, vec![("rice_0".to_string(), 4)], "nothing is lost to a full hold");
+    }
 
-        for (entity, route) in arrivals {
-            log::debug!(
-                "Transportation: cargo vehicle {:?} arrived on route '{}'",
-                entity, route
-            );
-            self.lifetime_deliveries += 1;
+    #[test]
+    fn a_node_without_a_hold_turns_the_cargo_back() {
+        let (mut world, data, mut sys) = shipped();
+        sys.logistics.routes.iter_mut().for_each(|r| r.interception_per_day = 0.0);
+        request(&data, LogisticsRequest::Ship {
+            from: "mule".into(),
+            to: "home".into(),
+            cargo: vec![("iron_ore_0".into(), 30)],
+        });
+        sys.tick(&mut world, 0.0, &data);
+        let home = node_entity(&world, "home").unwrap();
+        world.remove_one::<Inventory>(home).unwrap();
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 0.5, &data);
+        assert!(report(&data).log.iter().any(|l| l.ends_with("has no hold: cargo turned back")));
+        sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 0.5, &data);
+        assert_eq!(count(&world, "mule", "iron_ore_0"), 120, "the ore is back aboard the Mule");
+        assert!(report(&data).shipments.is_empty());
+    }
+
+    #[test]
+    fn a_standing_order_tops_the_destination_up_counting_what_is_in_transit() {
+        let (mut world, data, mut sys) = shipped();
+        sys.logistics.routes.iter_mut().for_each(|r| r.interception_per_day = 0.0);
+        request(&data, LogisticsRequest::AddOrder(StandingOrder {
+            id: "ore".into(),
+            from: "mule".into(),
+            to: "home".into(),
+            item: "iron_ore_0".into(),
+            keep: 25,
+            lot: 10,
+            every_days: 0.1,
+            since_days: 0.0,
+            paused: false,
+        }));
+        sys.tick(&mut world, 0.0, &data);
+        // Every 0.1 day it ships a lot of 10 until 25 are home or on the way.
+        for _ in 0..4 {
+            sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 0.1, &data);
+        }
+        let in_flight: u32 = report(&data).shipments.iter().flat_map(|s| s.cargo.iter()).map(|(_, q)| q).sum();
+        assert_eq!(count(&world, "home", "iron_ore_0") + in_flight, 25, "10 + 10 + 5, never more");
+        for _ in 0..10 {
+            sys.tick(&mut world, REAL_SECONDS_PER_GAME_DAY * 0.1, &data);
         }
+        assert_eq!(count(&world, "home", "iron_ore_0"), 25);
+        assert_eq!(count(&world, "mule", "iron_ore_0"), 95);
+
+        request(&data, LogisticsRequest::CancelOrder("ore".into()));
+        sys.tick(&mut world, 0.0, &data);
+        assert!(report(&data).orders.is_empty());
     }
 }
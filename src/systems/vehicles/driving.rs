@@ -0,0 +1,624 @@
+//! Driven vehicle dynamics — suspension, throttle, fuel, buoyancy.
+//!
+//! rapier's `PhysicsWorld` stays dormant (the same call wall_collision.rs
+//! made): a vehicle is one body on four vertical probes at its corners. Each
+//! tick DrivingSystem publishes the probe points under "vehicle_probes"; the
+//! engine answers next frame under "vehicle_ground" with the chunked planet
+//! ground and, at sea, the ocean height sampled there (`ocean_waves` or its
+//! `ocean_fft` twin, the same float the walker stands on). Wheels are
+//! spring/damper struts against that ground; hulls take buoyancy from the
+//! water. No answer (the orbital home's deck) is flat ground at the vehicle's
+//! current height.
+//!
+//! Drive is longitudinal only: throttle and brakes act along the body's long
+//! axis, steering is the bicycle model for wheels and a rudder for hulls, and
+//! slopes pull along the heading. Vehicles in transit (`VehicleRoute`) are
+//! left to `VehicleSystem::tick_routes`; parked ones sit on the parking brake.
+
+use glam::{Quat, Vec3};
+
+use crate::ecs::components::{Controllable, Transform, Vehicle, VehicleDynamics, VehicleRoute};
+use crate::ecs::systems::System;
+use crate::hot_reload::data_store::DataStore;
+use crate::systems::vehicles::{VehicleKitDef, VehicleKitRegistry};
+
+const GRAVITY: f32 = 9.81;
+/// Strut compression holding the body up at rest (meters).
+const SAG_M: f32 = 0.08;
+/// Strut travel beyond the rest sag before the bump stop (meters).
+const TRAVEL_M: f32 = 0.25;
+/// Fraction of critical damping on struts and hulls.
+const DAMPING_RATIO: f32 = 0.6;
+/// Fraction of a hull's height below the waterline at rest.
+const BOAT_DRAFT_FRACTION: f32 = 0.35;
+/// Service / parking brake deceleration with full traction (m/s^2).
+const BRAKE_DECEL_MPS2: f32 = 7.0;
+/// Rolling resistance on the ground (m/s^2).
+const ROLLING_DECEL_MPS2: f32 = 0.3;
+/// Throttle share available in reverse.
+const REVERSE_THROTTLE: f32 = 0.45;
+/// Below this speed, the opposite pedal changes gear instead of braking.
+const GEAR_CHANGE_MPS: f32 = 0.5;
+/// Full-lock front wheel angle (radians).
+const MAX_STEER_RAD: f32 = 0.6;
+/// Rudder yaw rate at full helm and speed (rad/s).
+const BOAT_TURN_RATE: f32 = 0.5;
+/// Idle burn as a fraction of full-throttle burn.
+const IDLE_BURN: f32 = 0.1;
+/// Vehicles farther than this from the player are not simulated (meters).
+const SIM_RADIUS_M: f32 = 250.0;
+/// Longest integration step; a slow frame is split into several.
+const MAX_SUBSTEP_S: f32 = 1.0 / 120.0;
+/// Longest frame integrated at all (a hitch does not launch anything).
+const MAX_FRAME_S: f32 = 0.1;
+/// What fills a tank, and how many litres one unit holds.
+pub const FUEL_ITEM: &str = "fuel_refined_0";
+const LITRES_PER_FUEL_ITEM: f32 = 1.0;
+
+/// The driver's controls this frame, published under "drive_input" as
+/// `Option<DriveInput>` (None when nobody is at the wheel).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DriveInput {
+    /// The driven vehicle (entity bits).
+    pub vehicle: u64,
+    /// Forward pedal 0..1, backward pedal -1..0 (brakes while rolling forward).
+    pub throttle: f32,
+    /// -1 full left .. +1 full right.
+    pub steer: f32,
+    pub handbrake: bool,
+}
+
+/// What lies under one probe, as entity-space heights.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct GroundSample {
+    pub ground_y: f32,
+    /// Sea surface (waves included) where the probe is over the ocean.
+    pub water_y: Option<f32>,
+}
+
+/// Vehicle heading: the yaw of the body's long (+X) axis.
+fn heading(rotation: Quat) -> f32 {
+    let f = rotation * Vec3::X;
+    (-f.z).atan2(f.x)
+}
+
+/// Corner offsets of the four probes, front-left, front-right, rear-left,
+/// rear-right. Wheels sit one radius in from the body ends.
+fn corner_offsets(kit: &VehicleKitDef, heading: f32) -> [Vec3; 4] {
+    let fwd = Vec3::new(heading.cos(), 0.0, -heading.sin());
+    let right = Vec3::new(heading.sin(), 0.0, heading.cos());
+    let half_l = (kit.body_m.0 * 0.5 - kit.wheel_radius_m).max(0.25);
+    let half_w = kit.body_m.2 * 0.5;
+    [
+        fwd * half_l - right * half_w,
+        fwd * half_l + right * half_w,
+        -fwd * half_l - right * half_w,
+        -fwd * half_l + right * half_w,
+    ]
+}
+
+/// The four probe points under a vehicle standing at `tf`.
+pub fn probe_points(kit: &VehicleKitDef, tf: &Transform) -> [Vec3; 4] {
+    corner_offsets(kit, heading(tf.rotation)).map(|o| tf.position + o)
+}
+
+/// Advance one vehicle by `dt`. `ground` is None on flat ground of unknown
+/// height; `input` is the driver's controls, None for a parked vehicle.
+pub fn step(
+    kit: &VehicleKitDef,
+    tf: &mut Transform,
+    dynamics: &mut VehicleDynamics,
+    ground: Option<&[GroundSample; 4]>,
+    input: Option<&DriveInput>,
+    dt: f32,
+) {
+    let dt = dt.min(MAX_FRAME_S);
+    let n = (dt / MAX_SUBSTEP_S).ceil().max(1.0);
+    for _ in 0..n as u32 {
+        substep(kit, tf, dynamics, ground, input, dt / n);
+    }
+}
+
+fn substep(
+    kit: &VehicleKitDef,
+    tf: &mut Transform,
+    dynamics: &mut VehicleDynamics,
+    ground: Option<&[GroundSample; 4]>,
+    input: Option<&DriveInput>,
+    dt: f32,
+) {
+    let mut h = heading(tf.rotation);
+    let tilt = Quat::from_rotation_y(-h) * tf.rotation;
+    let half_l = (kit.body_m.0 * 0.5 - kit.wheel_radius_m).max(0.25);
+    let half_w = kit.body_m.2 * 0.5;
+    let mut y = tf.position.y;
+    // Height of each body corner above `y` in the current tilt, so a strut
+    // on a slope measures from its own corner.
+    let lift = [(half_l, -half_w), (half_l, half_w), (-half_l, -half_w), (-half_l, half_w)]
+        .map(|(x, z)| (tilt * Vec3::new(x, 0.0, z)).y);
+
+    // ── Vertical: struts against the ground, hulls on the water ──
+    let k = GRAVITY / (4.0 * SAG_M);
+    let c = 2.0 * DAMPING_RATIO * (GRAVITY / SAG_M).sqrt() / 4.0;
+    let draft = (kit.body_m.1 * BOAT_DRAFT_FRACTION).max(0.05);
+    let kb = GRAVITY / (4.0 * draft);
+    let cb = 2.0 * DAMPING_RATIO * (GRAVITY / draft).sqrt() / 4.0;
+    let mut down = 0u8;
+    let mut afloat = false;
+    let mut flooded = false;
+    // Surface each corner rests on, for slope and tilt.
+    let mut rest = [y; 4];
+    match ground {
+        None => {
+            dynamics.climb_mps = 0.0;
+            down = 4;
+        }
+        Some(s) => {
+            let mut a = -GRAVITY;
+            let mut water_sum = 0.0;
+            for (i, g) in s.iter().enumerate() {
+                let x = g.ground_y + SAG_M - y - lift[i];
+                if x > 0.0 {
+                    a += k * x.min(SAG_M + TRAVEL_M) - c * dynamics.climb_mps;
+                    down += 1;
+                }
+                rest[i] = g.ground_y;
+                if let Some(w) = g.water_y {
+                    water_sum += w;
+                    if kit.boat {
+                        let d = (w - y - lift[i]).clamp(0.0, kit.body_m.1);
+                        if d > 0.0 {
+                            a += kb * d - cb * dynamics.climb_mps;
+                            afloat = true;
+                        }
+                        rest[i] = rest[i].max(w - draft);
+                    }
+                }
+            }
+            dynamics.climb_mps += a * dt;
+            y += dynamics.climb_mps * dt;
+            // Bump stop: a strut never compresses past its travel.
+            let floor = s
+                .iter()
+                .zip(lift)
+                .map(|(g, l)| g.ground_y - l)
+                .fold(f32::MIN, f32::max)
+                - TRAVEL_M;
+            if y < floor {
+                y = floor;
+                dynamics.climb_mps = dynamics.climb_mps.max(0.0);
+            }
+            // Water over the engine bay drowns a wheeled vehicle.
+            flooded = !kit.boat
+                && s.iter().all(|g| g.water_y.is_some())
+                && water_sum / 4.0 > y + kit.wheel_radius_m + kit.body_m.1 * 0.5;
+        }
+    }
+    dynamics.wheels_down = down;
+    dynamics.afloat = afloat;
+
+    // ── Slope along and across the body, from what the corners rest on ──
+    let supported = down > 0 || afloat;
+    let pitch_slope = (rest[0] + rest[1] - rest[2] - rest[3]) / (4.0 * half_l);
+    let roll_slope = (rest[1] + rest[3] - rest[0] - rest[2]) / (4.0 * half_w);
+    let tilt = if ground.is_some() && supported {
+        Quat::from_rotation_z(pitch_slope.atan()) * Quat::from_rotation_x(-roll_slope.atan())
+    } else {
+        tilt
+    };
+
+    // ── Longitudinal: engine, brakes, drag, rolling, slope ──
+    let traction = if kit.boat {
+        if afloat { 1.0 } else { 0.0 }
+    } else {
+        down as f32 / 4.0
+    };
+    let has_fuel = kit.fuel_tank_l <= 0.0 || dynamics.fuel_l > 0.0;
+    let top = kit.speed_mps.max(0.5);
+    let v = dynamics.speed_mps;
+    let mut a = -kit.accel_mps2 / (top * top) * v * v.abs();
+    let mut braking = input.is_none();
+    if let Some(inp) = input {
+        let throttle = inp.throttle.clamp(-1.0, 1.0);
+        let engine = has_fuel && !flooded;
+        if throttle > 0.0 {
+            if v < -GEAR_CHANGE_MPS {
+                braking = true;
+            } else if engine {
+                a += kit.accel_mps2 * throttle * traction;
+            }
+        } else if throttle < 0.0 {
+            if v > GEAR_CHANGE_MPS {
+                braking = true;
+            } else if engine {
+                a += kit.accel_mps2 * REVERSE_THROTTLE * throttle * traction;
+            }
+        }
+        braking |= inp.handbrake;
+        if kit.fuel_tank_l > 0.0 && engine {
+            let burn = kit.fuel_burn_lph / 3600.0 * (IDLE_BURN + (1.0 - IDLE_BURN) * throttle.abs());
+            dynamics.fuel_l = (dynamics.fuel_l - burn * dt).max(0.0);
+        }
+    }
+    let on_ground = down > 0 && !afloat;
+    if on_ground && ground.is_some() {
+        a -= GRAVITY * pitch_slope / (1.0 + pitch_slope * pitch_slope).sqrt();
+    }
+    let mut v = v + a * dt;
+    let mut friction = 0.0;
+    if on_ground {
+        friction += ROLLING_DECEL_MPS2;
+    }
+    if braking {
+        friction += BRAKE_DECEL_MPS2 * traction;
+    }
+    v = if v.abs() <= friction * dt { 0.0 } else { v - friction * dt * v.signum() };
+    dynamics.speed_mps = v;
+
+    // ── Steering ──
+    if let Some(inp) = input {
+        let steer = inp.steer.clamp(-1.0, 1.0);
+        if kit.boat {
+            if afloat {
+                let way = if v < -0.1 { -1.0 } else { 1.0 };
+                h -= steer * BOAT_TURN_RATE * (v.abs() / top).clamp(0.25, 1.0) * way * dt;
+            }
+        } else if down > 0 {
+            h -= v * (steer * MAX_STEER_RAD).tan() / (2.0 * half_l) * dt;
+        }
+    }
+
+    let fwd = Vec3::new(h.cos(), 0.0, -h.sin());
+    tf.position = Vec3::new(tf.position.x, y, tf.position.z) + fwd * v * dt;
+    tf.rotation = Quat::from_rotation_y(h) * tilt;
+}
+
+/// Vehicle dynamics: drives the player's vehicle, settles parked ones onto
+/// the ground or the sea, and burns and refills fuel.
+pub struct DrivingSystem {
+    /// Vehicles whose probes were published last tick, in probe order.
+    probed: Vec<hecs::Entity>,
+}
+
+impl DrivingSystem {
+    pub fn new() -> Self {
+        Self { probed: Vec::new() }
+    }
+}
+
+impl Default for DrivingSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl System for DrivingSystem {
+    fn name(&self) -> &str {
+        "DrivingSystem"
+    }
+
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
+        let Some(kits) = data.get::<VehicleKitRegistry>("vehicle_kit_registry") else {
+            return;
+        };
+        Self::handle_refuel(world, data, kits);
+
+        // Last tick's probes, answered by the engine since (one frame late).
+        let answers = data
+            .get::<std::sync::Mutex<Vec<GroundSample>>>("vehicle_ground")
+            .and_then(|m| m.lock().ok().map(|mut a| std::mem::take(&mut *a)))
+            .unwrap_or_default();
+        let answered = answers.len() == self.probed.len() * 4;
+        let ground_of = |e: hecs::Entity| -> Option<[GroundSample; 4]> {
+            let i = self.probed.iter().position(|p| *p == e)?;
+            answered.then(|| [answers[i * 4], answers[i * 4 + 1], answers[i * 4 + 2], answers[i * 4 + 3]])
+        };
+
+        let input = data.get::<Option<DriveInput>>("drive_input").copied().flatten();
+        let near = data.get::<Vec3>("camera_position").copied();
+        let mut active: Vec<(hecs::Entity, VehicleKitDef)> = Vec::new();
+        for (e, (v, tf)) in world.query::<(&Vehicle, &Transform)>().without::<&VehicleRoute>().iter() {
+            let driven = input.is_some_and(|i| i.vehicle == e.to_bits().get());
+            if !driven && near.is_some_and(|p| p.distance(tf.position) > SIM_RADIUS_M) {
+                continue;
+            }
+            if let Some(kit) = kits.get_vehicle(&v.item_id) {
+                active.push((e, kit.clone()));
+            }
+        }
+
+        let mut probes = Vec::with_capacity(active.len() * 4);
+        let mut probed = Vec::with_capacity(active.len());
+        for (e, kit) in &active {
+            if world.get::<&VehicleDynamics>(*e).is_err() {
+                let full = VehicleDynamics { fuel_l: kit.fuel_tank_l, ..Default::default() };
+                let _ = world.insert_one(*e, full);
+            }
+            let driven = input.filter(|i| i.vehicle == e.to_bits().get());
+            let ground = ground_of(*e);
+            let Ok((tf, dynamics)) = world.query_one_mut::<(&mut Transform, &mut VehicleDynamics)>(*e)
+            else {
+                continue;
+            };
+            step(kit, tf, dynamics, ground.as_ref(), driven.as_ref(), dt);
+            probes.extend(probe_points(kit, tf));
+            probed.push(*e);
+        }
+        self.probed = probed;
+        if let Some(slot) = data.get::<std::sync::Mutex<Vec<Vec3>>>("vehicle_probes") {
+            if let Ok(mut s) = slot.lock() {
+                *s = probes;
+            }
+        }
+    }
+}
+
+impl DrivingSystem {
+    /// Top up a vehicle's tank from the player's backpack: drain the one-shot
+    /// "refuel_vehicle" channel (entity bits from the Vehicles section), one
+    /// `FUEL_ITEM` per litre. Creative fills the tank for free.
+    fn handle_refuel(world: &mut hecs::World, data: &DataStore, kits: &VehicleKitRegistry) {
+        let Some(bits) = data
+            .get::<std::sync::Mutex<Option<u64>>>("refuel_vehicle")
+            .and_then(|m| m.lock().ok().and_then(|mut s| s.take()))
+        else {
+            return;
+        };
+        let Some(entity) = hecs::Entity::from_bits(bits) else { return };
+        let Some(tank) = world
+            .get::<&Vehicle>(entity)
+            .ok()
+            .and_then(|v| kits.get_vehicle(&v.item_id).map(|k| k.fuel_tank_l))
+        else {
+            log::warn!("refuel_vehicle: entity {bits:#x} is not a known vehicle");
+            return;
+        };
+        let fuel = world.get::<&VehicleDynamics>(entity).map(|d| d.fuel_l).unwrap_or(tank);
+        let wanted = ((tank - fuel) / LITRES_PER_FUEL_ITEM).ceil().max(0.0) as u32;
+        if wanted == 0 {
+            return;
+        }
+        let creative = data
+            .get::<std::sync::Mutex<bool>>("creative_mode")
+            .and_then(|m| m.lock().ok().map(|g| *g))
+            .unwrap_or(false);
+        let mut units = wanted;
+        if !creative {
+            units = 0;
+            if let Some((_e, (inv, _ctrl))) = world
+                .query_mut::<(&mut crate::systems::inventory::Inventory, &Controllable)>()
+                .into_iter()
+                .next()
+            {
+                units = wanted.min(inv.count_item(FUEL_ITEM));
+                inv.remove_item(FUEL_ITEM, units);
+            }
+            if units == 0 {
+                log::warn!("refuel_vehicle: no {FUEL_ITEM} in the backpack");
+                return;
+            }
+        }
+        let topped = (fuel + units as f32 * LITRES_PER_FUEL_ITEM).min(tank);
+        if world.get::<&VehicleDynamics>(entity).is_err() {
+            let _ = world.insert_one(entity, VehicleDynamics::default());
+        }
+        if let Ok(mut d) = world.get::<&mut VehicleDynamics>(entity) {
+            d.fuel_l = topped;
+        }
+        log::info!("Refuelled vehicle {bits:#x} with {units} x {FUEL_ITEM} ({topped:.0}/{tank:.0} L)");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::inventory::Inventory;
+
+    const KITS_RON: &str = r#"[
+        (
+            kit_item: "car_nova_1975_kit_0",
+            vehicle_item: "car_nova_1975_0",
+            display_name: "1975 Chevy Nova",
+            body_m: (4.85, 0.75, 1.83),
+            cabin_m: (2.1, 0.6, 1.7),
+            cabin_offset_x: -0.2,
+            wheel_radius_m: 0.33,
+            speed_mps: 12.0,
+            fuel_tank_l: 79.0,
+            fuel_burn_lph: 3600.0,
+        ),
+        (
+            kit_item: "boat_motorboat_kit_0",
+            vehicle_item: "boat_motorboat_0",
+            display_name: "Motorboat",
+            body_m: (5.0, 0.9, 2.0),
+            cabin_m: (1.2, 0.6, 1.6),
+            cabin_offset_x: -0.8,
+            wheel_radius_m: 0.0,
+            speed_mps: 10.0,
+            boat: true,
+        ),
+    ]"#;
+
+    fn make_store() -> DataStore {
+        let mut data = DataStore::new();
+        data.insert(
+            "vehicle_kit_registry",
+            VehicleKitRegistry::from_ron(KITS_RON.as_bytes()).expect("kits ron parses"),
+        );
+        data.insert("drive_input", Option::<DriveInput>::None);
+        data.insert("vehicle_probes", std::sync::Mutex::new(Vec::<Vec3>::new()));
+        data.insert("vehicle_ground", std::sync::Mutex::new(Vec::<GroundSample>::new()));
+        data.insert("refuel_vehicle", std::sync::Mutex::new(Option::<u64>::None));
+        data.insert("creative_mode", std::sync::Mutex::new(false));
+        data
+    }
+
+    fn spawn(world: &mut hecs::World, item: &str, pos: Vec3, yaw: f32) -> hecs::Entity {
+        world.spawn((
+            Vehicle { item_id: item.to_string() },
+            Transform { position: pos, rotation: Quat::from_rotation_y(yaw), scale: Vec3::ONE },
+        ))
+    }
+
+    fn drive(data: &mut DataStore, e: hecs::Entity, throttle: f32, steer: f32) {
+        data.insert(
+            "drive_input",
+            Some(DriveInput { vehicle: e.to_bits().get(), throttle, steer, handbrake: false }),
+        );
+    }
+
+    /// Run `secs` of 60 Hz frames, answering every probe from `surface`
+    /// (ground height, water height) the way the engine does.
+    fn run(
+        sys: &mut DrivingSystem,
+        world: &mut hecs::World,
+        data: &DataStore,
+        secs: f32,
+        surface: impl Fn(Vec3) -> (f32, Option<f32>),
+    ) {
+        for _ in 0..(secs * 60.0) as u32 {
+            let probes = data.get::<std::sync::Mutex<Vec<Vec3>>>("vehicle_probes").unwrap().lock().unwrap().clone();
+            *data.get::<std::sync::Mutex<Vec<GroundSample>>>("vehicle_ground").unwrap().lock().unwrap() = probes
+                .iter()
+                .map(|&p| {
+                    let (ground_y, water_y) = surface(p);
+                    GroundSample { ground_y, water_y }
+                })
+                .collect();
+            sys.tick(world, 1.0 / 60.0, data);
+        }
+    }
+
+    /// A vehicle dropped over a hillside lands on its struts, comes to rest
+    /// at ground height, pitches with the slope and holds there on the
+    /// parking brake.
+    #[test]
+    fn dropped_vehicle_settles_on_its_suspension() {
+        let mut world = hecs::World::new();
+        let data = make_store();
+        let mut sys = DrivingSystem::new();
+        let nova = spawn(&mut world, "car_nova_1975_0", Vec3::new(0.0, 3.0, 0.0), 0.0);
+        let hill = |p: Vec3| (0.2 * p.x, None);
+        run(&mut sys, &mut world, &data, 4.0, hill);
+
+        let tf = world.get::<&Transform>(nova).unwrap().clone();
+        let d = *world.get::<&VehicleDynamics>(nova).unwrap();
+        assert!(tf.position.y.abs() < 0.05, "rests at ground height, got {}", tf.position.y);
+        assert!(tf.position.x.abs() < 0.05, "parking brake holds on a 20% grade");
+        assert!(d.climb_mps.abs() < 0.05 && d.wheels_down == 4, "{d:?}");
+        let nose = tf.rotation * Vec3::X;
+        assert!((nose.y / nose.x - 0.2).abs() < 0.02, "nose up the slope: {nose}");
+        assert_eq!(d.fuel_l, 79.0, "a parked engine burns nothing");
+    }
+
+    /// Throttle drives the car toward its top speed and burns fuel; a dry
+    /// tank leaves it coasting to a stop.
+    #[test]
+    fn throttle_drives_and_burns_fuel_until_the_tank_runs_dry() {
+        let mut world = hecs::World::new();
+        let mut data = make_store();
+        let mut sys = DrivingSystem::new();
+        let nova = spawn(&mut world, "car_nova_1975_0", Vec3::ZERO, 0.0);
+        let flat = |_: Vec3| (0.0, None);
+        run(&mut sys, &mut world, &data, 1.0, flat);
+        drive(&mut data, nova, 1.0, 0.0);
+        run(&mut sys, &mut world, &data, 10.0, flat);
+
+        let d = *world.get::<&VehicleDynamics>(nova).unwrap();
+        let x = world.get::<&Transform>(nova).unwrap().position.x;
+        assert!(d.speed_mps > 8.0 && d.speed_mps < 12.0, "near top speed: {}", d.speed_mps);
+        assert!(x > 40.0, "drove along +X: {x}");
+        // 3600 L/h burns a litre a second at full throttle.
+        assert!((d.fuel_l - 69.0).abs() < 0.5, "ten litres burned: {}", d.fuel_l);
+
+        world.get::<&mut VehicleDynamics>(nova).unwrap().fuel_l = 0.0;
+        run(&mut sys, &mut world, &data, 20.0, flat);
+        assert_eq!(world.get::<&VehicleDynamics>(nova).unwrap().speed_mps, 0.0, "coasted to a stop");
+
+        // Backward while rolling forward brakes; at a stop it reverses.
+        world.get::<&mut VehicleDynamics>(nova).unwrap().fuel_l = 79.0;
+        drive(&mut data, nova, -1.0, 0.0);
+        run(&mut sys, &mut world, &data, 2.0, flat);
+        assert!(world.get::<&VehicleDynamics>(nova).unwrap().speed_mps < -1.0, "reversing");
+    }
+
+    /// Steering right while driving north (-Z) bends the path east (+X).
+    #[test]
+    fn steering_turns_the_way_the_wheel_points() {
+        let mut world = hecs::World::new();
+        let mut data = make_store();
+        let mut sys = DrivingSystem::new();
+        let north = std::f32::consts::FRAC_PI_2;
+        let nova = spawn(&mut world, "car_nova_1975_0", Vec3::ZERO, north);
+        drive(&mut data, nova, 1.0, 0.5);
+        run(&mut sys, &mut world, &data, 3.0, |_| (0.0, None));
+
+        let tf = world.get::<&Transform>(nova).unwrap().clone();
+        assert!(tf.position.z < -3.0, "went forward: {}", tf.position);
+        assert!(tf.position.x > 1.0, "curved right: {}", tf.position);
+        assert!(heading(tf.rotation) < north - 0.3, "heading swung right");
+    }
+
+    /// A boat floats at its draft on the swell, drives only while afloat, and
+    /// a car on the same sea floor sits on the bottom with a drowned engine.
+    #[test]
+    fn boats_float_and_cars_sink() {
+        let mut world = hecs::World::new();
+        let mut data = make_store();
+        let mut sys = DrivingSystem::new();
+        let boat = spawn(&mut world, "boat_motorboat_0", Vec3::new(0.0, 1.0, 0.0), 0.0);
+        let nova = spawn(&mut world, "car_nova_1975_0", Vec3::new(0.0, 1.0, 20.0), 0.0);
+        let sea = |_: Vec3| (-3.0, Some(0.0));
+        run(&mut sys, &mut world, &data, 6.0, sea);
+
+        let draft = 0.9 * BOAT_DRAFT_FRACTION;
+        let by = world.get::<&Transform>(boat).unwrap().position.y;
+        assert!((by + draft).abs() < 0.05, "floats at its draft: {by}");
+        assert!(world.get::<&VehicleDynamics>(boat).unwrap().afloat);
+        let ny = world.get::<&Transform>(nova).unwrap().position.y;
+        assert!((ny + 3.0).abs() < 0.05, "car on the sea floor: {ny}");
+
+        drive(&mut data, boat, 1.0, 0.0);
+        run(&mut sys, &mut world, &data, 3.0, sea);
+        assert!(world.get::<&Transform>(boat).unwrap().position.x > 5.0, "motored ahead");
+        drive(&mut data, nova, 1.0, 0.0);
+        run(&mut sys, &mut world, &data, 3.0, sea);
+        assert!(world.get::<&Transform>(nova).unwrap().position.x.abs() < 0.01, "engine drowned");
+
+        // Beached, the hull has no drive.
+        let dune = spawn(&mut world, "boat_motorboat_0", Vec3::new(0.0, 0.0, 40.0), 0.0);
+        drive(&mut data, dune, 1.0, 0.0);
+        run(&mut sys, &mut world, &data, 2.0, |_| (0.0, None));
+        assert!(world.get::<&Transform>(dune).unwrap().position.x.abs() < 0.01);
+    }
+
+    /// Refuelling spends one fuel item per missing litre, never more than
+    /// the tank holds, and nothing when the pack has none.
+    #[test]
+    fn refuelling_spends_fuel_from_the_pack() {
+        let mut world = hecs::World::new();
+        let data = make_store();
+        let mut sys = DrivingSystem::new();
+        let mut inv = Inventory::new(36);
+        inv.add_item(FUEL_ITEM, 30, 10);
+        let player = world.spawn((Controllable, inv));
+        let nova = spawn(&mut world, "car_nova_1975_0", Vec3::ZERO, 0.0);
+        world.insert_one(nova, VehicleDynamics { fuel_l: 60.5, ..Default::default() }).unwrap();
+        let refuel = |e: hecs::Entity| {
+            *data.get::<std::sync::Mutex<Option<u64>>>("refuel_vehicle").unwrap().lock().unwrap() =
+                Some(e.to_bits().get());
+        };
+
+        refuel(nova);
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(world.get::<&VehicleDynamics>(nova).unwrap().fuel_l, 79.0);
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item(FUEL_ITEM), 11, "19 litres");
+
+        world.get::<&mut VehicleDynamics>(nova).unwrap().fuel_l = 0.0;
+        refuel(nova);
+        sys.tick(&mut world, 0.0, &data);
+        assert_eq!(world.get::<&VehicleDynamics>(nova).unwrap().fuel_l, 11.0, "the pack ran out");
+        assert_eq!(world.get::<&Inventory>(player).unwrap().count_item(FUEL_ITEM), 0);
+    }
+}
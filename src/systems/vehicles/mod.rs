@@ -2,8 +2,10 @@
 //! jump jets, heat management, and vehicle movement control.
 //!
 //! Vehicle definitions loaded from `data/vehicles.csv`.
-//! Ship-specific logic in `ships.rs`, propulsion physics in `propulsion.rs`.
+//! Ship-specific logic in `ships.rs`, propulsion physics in `propulsion.rs`,
+//! driven suspension / fuel / buoyancy dynamics in `driving.rs`.
 
+pub mod driving;
 pub mod ships;
 pub mod propulsion;
 
@@ -66,12 +68,33 @@ pub struct VehicleKitDef {
     /// save, no vehicles) so driving needs no factory chain on day one.
     #[serde(default)]
     pub starter: bool,
+    /// Full-throttle acceleration from rest, m/s^2. Drag is sized so
+    /// the engine tops out at `speed_mps`.
+    #[serde(default = "default_accel_mps2")]
+    pub accel_mps2: f32,
+    /// Tank size in litres; 0 = no tank (battery or muscle), never runs dry.
+    #[serde(default)]
+    pub fuel_tank_l: f32,
+    /// Litres per hour at full throttle; idling burns a tenth of it.
+    #[serde(default = "default_fuel_burn_lph")]
+    pub fuel_burn_lph: f32,
+    /// A hull, not wheels: floats on the ocean and only drives afloat.
+    #[serde(default)]
+    pub boat: bool,
 }
 
 fn default_speed_mps() -> f32 {
     6.0
 }
 
+fn default_accel_mps2() -> f32 {
+    3.0
+}
+
+fn default_fuel_burn_lph() -> f32 {
+    10.0
+}
+
 /// All deployable kits, keyed both ways: by kit item (deploy consumes) and by
 /// vehicle item (the renderer looks up proportions for a spawned Vehicle).
 #[derive(Debug, Clone, Default)]
@@ -362,7 +385,8 @@ impl VehicleSystem {
     /// Arrival (within arrive_radius) removes the route -- the vehicle parks.
     fn tick_routes(world: &mut hecs::World, dt: f32, data: &DataStore) {
         let sdt = crate::systems::time::scaled_dt(dt, data);
-        let mut arrived: Vec<hecs::Entity> = Vec::new();
+        // (vehicle, where it stopped, the velocity it braked from)
+        let mut arrived: Vec<(hecs::Entity, Vec3, Vec3)> = Vec::new();
         for (e, (tf, route)) in world
             .query_mut::<(&mut Transform, &crate::ecs::components::VehicleRoute)>()
         {
@@ -370,15 +394,17 @@ impl VehicleSystem {
             let dist = to.length();
             let step = route.speed_mps * sdt;
             if dist <= route.arrive_radius.max(step) {
-                arrived.push(e);
+                arrived.push((e, tf.position, to.normalize_or_zero() * route.speed_mps));
                 continue;
             }
             let dir = to / dist;
             tf.position += dir * step;
             tf.rotation = Quat::from_rotation_y((-dir.z).atan2(dir.x));
         }
-        for e in arrived {
+        for (e, pos, velocity) in arrived {
             let _ = world.remove_one::<crate::ecs::components::VehicleRoute>(e);
+            // The arrival cue carries the arrival speed, so it doppler-shifts.
+            crate::systems::push_moving_sfx_event(data, "sfx.vehicle_arrive", "audio/sfx/door_close.ogg", Some(pos), velocity);
             log::info!("Vehicle arrived and parked");
         }
     }
@@ -5,6 +5,10 @@
 //! `WasteAccumulator` within `MAX_DEPOSIT_DIST` meters. If no accumulator
 //! is in range, the waste is logged as "atmospheric" pollution (drops to
 //! the ecosystem — handled by EcologySystem if present).
+//!
+//! One-off waste (a factory's byproducts, a scrapped part) arrives through the
+//! `waste_emissions` DataStore channel (see [`emit_waste`]) and is routed the
+//! same way.
 
 use std::path::Path;
 
@@ -21,6 +25,28 @@ const MAX_DEPOSIT_DIST: f32 = 8.0;
 /// 1 game day = 1200 real seconds.
 const REAL_SECONDS_PER_GAME_DAY: f32 = 1200.0;
 
+/// A lump of waste dropped at a position, queued on the `waste_emissions` channel.
+#[derive(Debug, Clone)]
+pub struct WasteEmission {
+    pub position: glam::Vec3,
+    /// Category id (a `waste_management.ron` category or a `manufacturing.ron` waste product).
+    pub category: String,
+    pub kg: f32,
+}
+
+/// Queue waste for [`WasteSystem`] to deposit into the nearest accumulator next tick. No-op when
+/// the channel is absent (a headless world without the WasteSystem) or `kg` is not positive.
+pub fn emit_waste(data: &DataStore, position: glam::Vec3, category: &str, kg: f32) {
+    if kg <= 0.0 {
+        return;
+    }
+    if let Some(lock) = data.get::<std::sync::Mutex<Vec<WasteEmission>>>("waste_emissions") {
+        if let Ok(mut q) = lock.lock() {
+            q.push(WasteEmission { position, category: category.to_string(), kg });
+        }
+    }
+}
+
 /// Top-level RON schema for `data/waste_management.ron`.
 #[derive(Debug, Deserialize)]
 pub struct WasteData {
@@ -57,9 +83,13 @@ impl WasteSystem {
 impl System for WasteSystem {
     fn name(&self) -> &str { "WasteSystem" }
 
-    fn tick(&mut self, world: &mut hecs::World, dt: f32, _data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         let day_fraction = dt / REAL_SECONDS_PER_GAME_DAY;
         if day_fraction <= 0.0 { return; }
+        let emissions: Vec<WasteEmission> = data
+            .get::<std::sync::Mutex<Vec<WasteEmission>>>("waste_emissions")
+            .and_then(|m| m.lock().ok().map(|mut v| std::mem::take(&mut *v)))
+            .unwrap_or_default();
 
         // Snapshot accumulator positions so we can mutate accumulators below
         // without alias issues.
@@ -72,22 +102,27 @@ impl System for WasteSystem {
         // For each WasteSource, compute deposit and find nearest accumulator.
         // We collect (target_entity_or_none, category, kg) pairs first to
         // avoid borrow checker issues with concurrent mutation.
-        let mut deposits: Vec<(Option<hecs::Entity>, String, f32)> = Vec::new();
-        for (_e, (src, src_t)) in world.query::<(&WasteSource, &Transform)>().iter() {
-            let kg = src.rate_per_day * day_fraction;
-            if kg <= 0.0 { continue; }
-
+        let nearest = |pos: glam::Vec3| -> Option<hecs::Entity> {
             // Find nearest accumulator within MAX_DEPOSIT_DIST.
             let max_d2 = MAX_DEPOSIT_DIST * MAX_DEPOSIT_DIST;
             let mut best: Option<(hecs::Entity, f32)> = None;
             for (acc_entity, acc_pos) in &accumulator_positions {
-                let d2 = src_t.position.distance_squared(*acc_pos);
+                let d2 = pos.distance_squared(*acc_pos);
                 if d2 > max_d2 { continue; }
                 if best.map(|(_, b)| d2 < b).unwrap_or(true) {
                     best = Some((*acc_entity, d2));
                 }
             }
-            deposits.push((best.map(|(e, _)| e), src.category.clone(), kg));
+            best.map(|(e, _)| e)
+        };
+        let mut deposits: Vec<(Option<hecs::Entity>, String, f32)> = Vec::new();
+        for (_e, (src, src_t)) in world.query::<(&WasteSource, &Transform)>().iter() {
+            let kg = src.rate_per_day * day_fraction;
+            if kg <= 0.0 { continue; }
+            deposits.push((nearest(src_t.position), src.category.clone(), kg));
+        }
+        for em in emissions {
+            deposits.push((nearest(em.position), em.category, em.kg));
         }
 
         // Apply deposits.
@@ -3,16 +3,31 @@
 //!
 //! Stores `Weather` in the WeatherSystem struct. Other systems can read
 //! weather state to affect farming, visibility, combat, etc.
+//!
+//! Extreme events (data/weather/events.ron) are forecast before they
+//! start, and while they run they act on the world: a Vortex
+//! core drifts with the wind and hurts exposed players and creatures via
+//! `damage_events`, wind tears at structures and outdoor crops and drags
+//! parked vehicles, and the event's `disasters` go to DisasterSystem on
+//! `disaster_requests`.
 
 use glam::Vec3;
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
+use crate::ecs::components::{
+    Controllable, CropInstance, Dead, EnvironmentContext, Health, Transform, Vehicle, VehicleRoute,
+    VehicleSeat,
+};
 use crate::ecs::systems::System;
 use crate::hot_reload::data_store::DataStore;
 use crate::systems::body_environment::{self, BodyEnvironment};
+use crate::systems::combat::damage::{self, DamageEvent};
+use crate::systems::construction::Structure;
+use crate::systems::disasters::DisasterType;
 use crate::systems::time::{GameTime, Season};
+use crate::systems::weather_events::{self, WeatherEvent, WeatherEventRegistry, WindProfile};
 
 /// Weather condition types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -73,6 +88,32 @@ pub struct Weather {
     pub event_name: String,
     #[serde(default)]
     pub event_remaining_s: f32,
+    /// Where a running Vortex event's core is, in the world frame
+    /// Transforms use; None for other events.
+    #[serde(default)]
+    pub event_core: Option<Vec3>,
+    /// The player's hazard proximity bands for the running event: `near`
+    /// within 3x the hazard radius (HUD warning), `inside` within it.
+    #[serde(default)]
+    pub hazard_near: bool,
+    #[serde(default)]
+    pub hazard_inside: bool,
+    /// The next extreme event, rolled but not started yet.
+    #[serde(default)]
+    pub forecast: Option<WeatherForecast>,
+}
+
+/// An extreme event announced ahead of time: rolled
+/// `FORECAST_LEAD_S` before it starts so the player can get indoors,
+/// bring the herd in, or park the truck somewhere sheltered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeatherForecast {
+    pub event_id: String,
+    pub event_name: String,
+    /// Seconds until it starts.
+    pub starts_in_s: f32,
+    /// How long it will run once it does.
+    pub duration_s: f32,
 }
 
 impl Default for Weather {
@@ -92,6 +133,10 @@ impl Default for Weather {
             event_id: String::new(),
             event_name: String::new(),
             event_remaining_s: 0.0,
+            event_core: None,
+            hazard_near: false,
+            hazard_inside: false,
+            forecast: None,
         }
     }
 }
@@ -147,6 +192,39 @@ const EVENT_FIRE_CHANCE: f32 = 0.35;
 /// the wind-field rung).
 const EVENT_GUST_EXPORT: f32 = 0.6;
 
+/// How far ahead a rolled event is announced: five minutes on
+/// the same clock, time to get under a roof.
+const FORECAST_LEAD_S: f32 = 300.0;
+
+/// A Vortex core is placed this far from the player when it touches down
+/// (metres, min..max), then tracks with the wind at `CORE_DRIFT_MPS`.
+const CORE_PLACE_M: (f32, f32) = (200.0, 600.0);
+const CORE_DRIFT_MPS: f32 = 8.0;
+
+/// Hazard damage lands in one-second pulses, so a tornado pass reads as
+/// a handful of hits with a cause instead of a hit every frame.
+const HAZARD_PULSE_S: f32 = 1.0;
+
+/// Structures shrug off hazard damage less well than bodies duck it: a
+/// tornado core tears a wall apart several times faster than it hurts a
+/// person.
+const STRUCTURE_HAZARD_MULT: f32 = 5.0;
+
+/// Wind load on structures: damage per second for every m/s over the
+/// threshold (gale force and up).
+const STRUCTURE_WIND_MPS: f32 = 30.0;
+const STRUCTURE_WIND_DAMAGE: f32 = 0.5;
+
+/// Outdoor crops (not in a tower) lodge and shred in event wind: health
+/// (0-100) lost per second for every m/s over the threshold.
+const CROP_WIND_MPS: f32 = 18.0;
+const CROP_WIND_DAMAGE: f32 = 0.02;
+
+/// Parked vehicles start sliding above this wind, at this fraction of
+/// every m/s over it.
+const LOOSE_SLIDE_MPS: f32 = 30.0;
+const LOOSE_SLIDE_FRACTION: f32 = 0.5;
+
 /// Drives weather transitions based on season and random rolls.
 pub struct WeatherSystem {
     weather: Weather,
@@ -170,6 +248,8 @@ pub struct WeatherSystem {
     /// active; 0 otherwise. Kept OUT of the lerp targets so event wind
     /// vanishes cleanly the moment the event ends.
     active_gust_mps: f32,
+    /// Time banked toward the next hazard pulse.
+    hazard_pulse: f32,
     /// The body whose weather is being simulated (artificial-planet
     /// increment 4): published each frame by the main loop from the
     /// frame-locked body. Its default is the Earth home frame, so the
@@ -198,6 +278,7 @@ impl WeatherSystem {
             next_change_timer: 60.0, // First change after 1 minute
             event_roll_timer: EVENT_ROLL_INTERVAL_S,
             active_gust_mps: 0.0,
+            hazard_pulse: 0.0,
             env: BodyEnvironment::default(),
             rng: StdRng::from_os_rng(),
         }
@@ -369,6 +450,197 @@ impl WeatherSystem {
         let angle: f32 = self.rng.gen_range(0.0..std::f32::consts::TAU);
         self.weather.wind_direction = Vec3::new(angle.cos(), 0.0, angle.sin()).normalize();
     }
+
+    /// Start a (forecast) event: HUD name, Front gusts, and for a Vortex a
+    /// core touching down near the player.
+    fn start_event(&mut self, ev: &WeatherEvent, duration_s: f32, player: Option<Vec3>) {
+        self.weather.event_id = ev.id.clone();
+        self.weather.event_name = ev.name.clone();
+        self.weather.event_remaining_s = duration_s;
+        self.hazard_pulse = 0.0;
+        self.active_gust_mps = match ev.wind_profile {
+            WindProfile::Front { gust_mps, .. } => gust_mps,
+            WindProfile::Vortex { .. } | WindProfile::None => 0.0,
+        };
+        self.weather.event_core = match ev.wind_profile {
+            WindProfile::Vortex { .. } => {
+                let bearing = self.rng.gen_range(0.0..std::f32::consts::TAU);
+                let dist = self.rng.gen_range(CORE_PLACE_M.0..CORE_PLACE_M.1);
+                let core = weather_events::place_event_core(player.unwrap_or(Vec3::ZERO), bearing, dist);
+                log::info!("[WeatherEvent] vortex core touched down {dist:.0} m from player");
+                Some(core)
+            }
+            _ => None,
+        };
+        log::info!(
+            "[WeatherEvent] '{}' started ({:.0}s)",
+            self.weather.event_name,
+            self.weather.event_remaining_s
+        );
+    }
+
+    fn end_event(&mut self) {
+        self.weather.event_remaining_s = 0.0;
+        self.weather.event_id.clear();
+        self.weather.event_name.clear();
+        self.weather.event_core = None;
+        self.weather.hazard_near = false;
+        self.weather.hazard_inside = false;
+        self.active_gust_mps = 0.0;
+    }
+
+    /// The running event acting on the world: hazard pulses on
+    /// exposed bodies and structures near a Vortex core, wind on
+    /// structures, outdoor crops and parked vehicles, and the event's
+    /// disaster strikes. `player` is the controllable's position;
+    /// `sheltered` is the sealed-home flag from `environment_context`.
+    fn apply_event(
+        &mut self,
+        world: &mut hecs::World,
+        dt: f32,
+        data: &DataStore,
+        ev: &WeatherEvent,
+        player: Option<Vec3>,
+        sheltered: bool,
+    ) {
+        if let (Some(core), WindProfile::Vortex { .. }) = (self.weather.event_core.as_mut(), ev.wind_profile) {
+            *core += Vec3::new(self.weather.wind_direction.x, 0.0, self.weather.wind_direction.z) * CORE_DRIFT_MPS * dt;
+        }
+        let core = self.weather.event_core;
+        let wind_dir = self.weather.wind_direction;
+        let base_wind = self.weather.wind_speed + self.active_gust_mps * EVENT_GUST_EXPORT;
+        let wind_at = |pos: Vec3| -> Vec3 {
+            let mut w = wind_dir * base_wind;
+            if let (Some(c), WindProfile::Vortex { core_radius_m, peak_mps }) = (core, ev.wind_profile) {
+                w += weather_events::vortex_wind(pos - c, core_radius_m, peak_mps);
+            }
+            w
+        };
+
+        // Proximity bands for the HUD (edge-logged).
+        let (near, inside) = match (core, player) {
+            (Some(c), Some(p)) => weather_events::hazard_proximity(horizontal_dist(p, c) as f64, ev.hazard.radius_m),
+            _ => (false, false),
+        };
+        if near != self.weather.hazard_near {
+            log::info!("[WeatherEvent] hazard zone {}", if near { "ENTERED (3x radius)" } else { "left" });
+        }
+        self.weather.hazard_near = near;
+        self.weather.hazard_inside = inside;
+
+        // Hazard pulses: bodies via damage_events, structures directly.
+        self.hazard_pulse += dt;
+        let pulse = if self.hazard_pulse >= HAZARD_PULSE_S {
+            std::mem::take(&mut self.hazard_pulse)
+        } else {
+            0.0
+        };
+        if pulse > 0.0 {
+            if let Some(c) = core.filter(|_| ev.hazard.damage_per_s > 0.0) {
+                let mut hits: Vec<(u64, f32)> = Vec::new();
+                for (e, (t, _h, controllable)) in world
+                    .query::<(&Transform, &Health, Option<&Controllable>)>()
+                    .without::<&Dead>()
+                    .iter()
+                {
+                    if controllable.is_some() && sheltered {
+                        continue;
+                    }
+                    let dmg = weather_events::hazard_damage_per_s(&ev.hazard, horizontal_dist(t.position, c)) * pulse;
+                    if dmg > 0.0 {
+                        hits.push((e.to_bits().into(), dmg));
+                    }
+                }
+                // The event's id is its damage source (damage_types.csv).
+                let damage_type = damage::damage_type_for(data, &ev.id);
+                if let Some(mut q) = data
+                    .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+                    .and_then(|m| m.lock().ok())
+                {
+                    for (bits, amount) in hits {
+                        q.push((
+                            bits,
+                            DamageEvent {
+                                damage_type: damage_type.clone(),
+                                amount,
+                                source_name: Some(ev.name.clone()),
+                                source_is_player: false,
+                                skill: None,
+                            },
+                        ));
+                    }
+                }
+            }
+            for (_e, (t, s)) in world.query_mut::<(&Transform, &mut Structure)>() {
+                if s.health <= 0.0 {
+                    continue;
+                }
+                let mut dmg = weather_events::wind_damage_per_s(wind_at(t.position).length(), STRUCTURE_WIND_MPS, STRUCTURE_WIND_DAMAGE);
+                if let Some(c) = core {
+                    dmg += weather_events::hazard_damage_per_s(&ev.hazard, horizontal_dist(t.position, c)) * STRUCTURE_HAZARD_MULT;
+                }
+                s.health = (s.health - dmg * pulse).max(0.0);
+                if s.health == 0.0 {
+                    log::info!("[WeatherEvent] {} wrecked a {}", ev.name, s.blueprint_id);
+                }
+            }
+        }
+
+        // Outdoor crops: the farm has no position of its own, so it feels
+        // the wind where the player is (home, usually).
+        let crop_wind = wind_at(player.unwrap_or(Vec3::ZERO)).length();
+        let crop_loss = weather_events::wind_damage_per_s(crop_wind, CROP_WIND_MPS, CROP_WIND_DAMAGE) * dt;
+        if crop_loss > 0.0 {
+            for (_e, crop) in world.query_mut::<&mut CropInstance>() {
+                if crop.tower_id.is_none() && crop.growth_stage != crate::ecs::components::STAGE_DEAD {
+                    crop.health = (crop.health - crop_loss).max(0.0);
+                }
+            }
+        }
+
+        // Parked vehicles slide downwind (nobody aboard, not driving a route).
+        for (_e, (t, _v, seat)) in world
+            .query_mut::<(&mut Transform, &Vehicle, Option<&VehicleSeat>)>()
+            .without::<&VehicleRoute>()
+        {
+            if seat.is_some_and(|s| s.occupant_key.is_some()) {
+                continue;
+            }
+            let w = wind_at(t.position);
+            let speed = w.length();
+            if speed > LOOSE_SLIDE_MPS {
+                let flat = Vec3::new(w.x, 0.0, w.z).normalize_or_zero();
+                t.position += flat * (speed - LOOSE_SLIDE_MPS) * LOOSE_SLIDE_FRACTION * dt;
+            }
+        }
+
+        // Disaster strikes around the player.
+        if !ev.disasters.is_empty() {
+            let anchor = player.unwrap_or(Vec3::ZERO);
+            let mut strikes: Vec<(DisasterType, Vec3, f32)> = Vec::new();
+            for d in &ev.disasters {
+                if self.rng.gen::<f32>() < d.per_min / 60.0 * dt {
+                    let bearing = self.rng.gen_range(0.0..std::f32::consts::TAU);
+                    let dist = self.rng.gen_range(d.distance_m.min..=d.distance_m.max);
+                    let intensity = self.rng.gen_range(d.intensity.min..=d.intensity.max);
+                    strikes.push((d.kind, weather_events::place_event_core(anchor, bearing, dist), intensity));
+                }
+            }
+            if !strikes.is_empty() {
+                if let Some(mut q) = data
+                    .get::<std::sync::Mutex<Vec<(DisasterType, Vec3, f32)>>>("disaster_requests")
+                    .and_then(|m| m.lock().ok())
+                {
+                    q.extend(strikes);
+                }
+            }
+        }
+    }
+}
+
+/// Ground distance between two points (the XZ plane; height ignored).
+fn horizontal_dist(a: Vec3, b: Vec3) -> f32 {
+    Vec3::new(a.x - b.x, 0.0, a.z - b.z).length()
 }
 
 impl System for WeatherSystem {
@@ -376,7 +648,7 @@ impl System for WeatherSystem {
         "WeatherSystem"
     }
 
-    fn tick(&mut self, _world: &mut hecs::World, dt: f32, data: &DataStore) {
+    fn tick(&mut self, world: &mut hecs::World, dt: f32, data: &DataStore) {
         // Determine current season + hour from the GameTime that TimeSystem
         // exports into the DataStore (behind a Mutex); fall back to Spring
         // noon if absent. The hour feeds the day/night temperature swing on
@@ -408,20 +680,26 @@ impl System for WeatherSystem {
             // wind ramp over the normal 30 s instead of waiting.
             let cond = body_environment::sanitize_condition(self.weather.condition, &self.env);
             self.begin_transition(cond, season);
-            // A running extreme event does not follow you to a world that
-            // cannot host it (a thunderstorm has no business on the Moon).
-            if !(self.env.has_atmosphere && self.env.has_water)
-                && self.weather.event_remaining_s > 0.0
-            {
+            // A running or forecast extreme event does not follow you to a
+            // world that cannot host it (a thunderstorm has no business on
+            // the Moon). Without the registry, fall back to the Earth-like
+            // rule every event used before bodies were listed.
+            let hosts = |id: &str| match data.get::<WeatherEventRegistry>("weather_event_registry") {
+                Some(reg) => reg.events.iter().any(|e| e.id == id && e.can_occur_on(&self.env)),
+                None => self.env.has_atmosphere && self.env.has_water,
+            };
+            let drop_event = self.weather.event_remaining_s > 0.0 && !hosts(&self.weather.event_id);
+            let drop_forecast = self.weather.forecast.as_ref().is_some_and(|f| !hosts(&f.event_id));
+            if drop_event {
                 log::info!(
                     "[WeatherEvent] '{}' dropped: body change to {}",
                     self.weather.event_name,
                     self.env.body_id
                 );
-                self.weather.event_remaining_s = 0.0;
-                self.weather.event_id.clear();
-                self.weather.event_name.clear();
-                self.active_gust_mps = 0.0;
+                self.end_event();
+            }
+            if drop_forecast {
+                self.weather.forecast = None;
             }
         }
 
@@ -486,67 +764,74 @@ impl System for WeatherSystem {
         }
 
         // ── Extreme-weather events (v0.1035, data/weather/events.ron) ──
-        // A running event counts down; otherwise roll periodically for an
-        // eligible one. Selection is season/temp/wind-gated + rarity-
-        // weighted (systems::weather_events). This rung applies Front
-        // gusts to the exported wind and surfaces the event to the HUD +
-        // precipitation; Vortex spatial wind and hazard damage are the
-        // NEXT rung (logged on activation so playtests can spot them).
+        // A running event counts down and acts on the world; a forecast
+        // one counts down to its start; otherwise roll periodically for
+        // an eligible one. Selection is season/temp/wind/body-gated +
+        // rarity-weighted (systems::weather_events), and a fired roll is
+        // FORECAST first so the player sees it coming.
+        let registry = data.get::<WeatherEventRegistry>("weather_event_registry");
+        let player = world
+            .query::<(&Transform, &Controllable)>()
+            .iter()
+            .next()
+            .map(|(_e, (t, _c))| t.position);
         if self.weather.event_remaining_s > 0.0 {
             self.weather.event_remaining_s = (self.weather.event_remaining_s - dt).max(0.0);
             if self.weather.event_remaining_s == 0.0 {
                 log::info!("[WeatherEvent] '{}' ended", self.weather.event_name);
-                self.weather.event_id.clear();
-                self.weather.event_name.clear();
-                self.active_gust_mps = 0.0;
+                self.end_event();
+            } else if let Some(ev) = registry.and_then(|r| r.events.iter().find(|e| e.id == self.weather.event_id)) {
+                let sheltered = data
+                    .get::<EnvironmentContext>("environment_context")
+                    .map(|c| c.sealed)
+                    .unwrap_or(true);
+                self.apply_event(world, dt, data, ev, player, sheltered);
             }
-        } else if manual.is_none() && self.env.has_atmosphere && self.env.has_water {
-            // No random extreme events while the F11 panel is driving, and
-            // none at all on bodies that cannot host them (increment 4):
-            // the shipped registry is Earth-authored (thunderstorms,
-            // tornadoes); per-body event profiles (Mars dust fronts) are a
-            // later increment per docs/design/artificial-planet.md.
+        } else if manual.is_some() {
+            // No random extreme events while the F11 panel is driving; a
+            // pending forecast waits with it.
+        } else if let Some(mut f) = self.weather.forecast.take() {
+            f.starts_in_s -= dt;
+            if f.starts_in_s > 0.0 {
+                self.weather.forecast = Some(f);
+            } else if let Some(ev) = registry
+                .and_then(|r| r.events.iter().find(|e| e.id == f.event_id))
+                .filter(|e| e.can_occur_on(&self.env))
+            {
+                self.start_event(ev, f.duration_s, player);
+            }
+        } else {
             self.event_roll_timer -= dt;
             if self.event_roll_timer <= 0.0 {
                 self.event_roll_timer = EVENT_ROLL_INTERVAL_S;
                 if self.rng.gen::<f32>() < EVENT_FIRE_CHANCE {
-                    if let Some(reg) = data
-                        .get::<crate::systems::weather_events::WeatherEventRegistry>(
-                            "weather_event_registry",
-                        )
-                    {
+                    if let Some(reg) = registry {
                         let season_name = format!("{season:?}");
-                        let elig = crate::systems::weather_events::eligible(
+                        let mut elig = weather_events::eligible(
                             reg,
                             &season_name,
                             self.weather.temperature,
                             self.weather.wind_speed,
                         );
+                        // Per-body profiles: the Earth-authored
+                        // storms stay on worlds with air and water, a Mars
+                        // dust storm stays on Mars, meteors fall anywhere
+                        // listed.
+                        elig.retain(|e| e.can_occur_on(&self.env));
                         let roll: f32 = self.rng.gen();
-                        if let Some(ev) =
-                            crate::systems::weather_events::weighted_pick(&elig, roll)
-                        {
-                            self.weather.event_id = ev.id.clone();
-                            self.weather.event_name = ev.name.clone();
-                            self.weather.event_remaining_s = self
-                                .rng
-                                .gen_range(ev.duration_s.min..=ev.duration_s.max);
-                            use crate::systems::weather_events::WindProfile as WP;
-                            self.active_gust_mps = match ev.wind_profile {
-                                WP::Front { gust_mps, .. } => gust_mps,
-                                WP::Vortex { peak_mps, .. } => {
-                                    log::info!(
-                                        "[WeatherEvent] vortex wind ({peak_mps} m/s core) + hazard deferred to the next rung"
-                                    );
-                                    0.0
-                                }
-                                WP::None => 0.0,
-                            };
+                        if let Some(ev) = weather_events::weighted_pick(&elig, roll) {
+                            let duration_s = self.rng.gen_range(ev.duration_s.min..=ev.duration_s.max);
                             log::info!(
-                                "[WeatherEvent] '{}' started ({:.0}s)",
-                                self.weather.event_name,
-                                self.weather.event_remaining_s
+                                "[WeatherEvent] '{}' forecast in {:.0}s",
+                                ev.name,
+                                FORECAST_LEAD_S
                             );
+                            self.weather.forecast = Some(WeatherForecast {
+                                event_id: ev.id.clone(),
+                                event_name: ev.name.clone(),
+                                starts_in_s: FORECAST_LEAD_S,
+                                duration_s,
+                            });
                         }
                     }
                 }
@@ -662,8 +947,9 @@ mod tests {
     }
 
     /// v0.1035: with the shipped registry in the store and eligible
-    /// conditions, repeated rolls eventually start an event (P(miss) per
-    /// roll = 0.65; 300 rolls make a false failure astronomically rare).
+    /// conditions, repeated rolls eventually forecast an event (P(miss)
+    /// per roll = 0.65; 300 rolls make a false failure astronomically
+    /// rare), and it starts once the forecast lead runs out.
     #[test]
     fn events_eventually_fire_from_the_shipped_registry() {
         let bytes = std::fs::read(
@@ -687,12 +973,17 @@ mod tests {
             sys.weather.event_id.clear();
             sys.event_roll_timer = 0.0;
             sys.tick(&mut world, 0.1, &data);
-            if !sys.weather.event_id.is_empty() {
+            if sys.weather.forecast.is_some() {
                 fired = true;
                 break;
             }
         }
-        assert!(fired, "no event fired in 300 forced rolls");
+        assert!(fired, "no event forecast in 300 forced rolls");
+        let f = sys.weather.forecast.clone().unwrap();
+        assert!(sys.weather.event_id.is_empty(), "forecast, not started yet");
+        sys.tick(&mut world, f.starts_in_s + 0.1, &data);
+        assert_eq!(sys.weather.event_id, f.event_id, "starts when the lead runs out");
+        assert!(sys.weather.forecast.is_none());
     }
 
     #[test]
@@ -776,6 +1067,8 @@ mod tests {
                     emitters: vec!["rain".to_string()],
                     cloud: CloudOverride { coverage_boost: 0.0, tint: (1.0, 1.0, 1.0) },
                     hazard: Hazard { damage_per_s: 0.0, radius_m: 0.0 },
+                    bodies: vec![],
+                    disasters: vec![],
                 }],
             },
         );
@@ -793,6 +1086,7 @@ mod tests {
                 "roll {i} produced weather on an airless body"
             );
             assert!(sys.weather().event_id.is_empty(), "event fired on airless body");
+            assert!(sys.weather().forecast.is_none(), "event forecast on airless body");
         }
         // After the transition settles: no wind, no humidity, full visibility.
         for _ in 0..40 {
@@ -814,7 +1108,7 @@ mod tests {
         for _ in 0..200 {
             earth_sys.event_roll_timer = 0.0;
             earth_sys.tick(&mut world, 1.0, &data);
-            if !earth_sys.weather().event_id.is_empty() {
+            if earth_sys.weather().forecast.is_some() {
                 fired = true;
                 break;
             }
@@ -822,6 +1116,120 @@ mod tests {
         assert!(fired, "control arm: the always-eligible event never fired on Earth");
     }
 
+    fn shipped_registry() -> WeatherEventRegistry {
+        let bytes = std::fs::read(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/weather/events.ron"),
+        )
+        .unwrap();
+        WeatherEventRegistry::from_ron(&bytes).unwrap()
+    }
+
+    /// A tornado core on the player hurts the exposed player and a
+    /// creature through damage_events, tears at a structure, flattens the
+    /// outdoor crop (not the tower one) and drags a parked truck; once the
+    /// player is inside the sealed home only the creature is hit.
+    #[test]
+    fn tornado_hits_what_is_exposed() {
+        use crate::ecs::components::{Controllable, CropInstance, EnvironmentContext, Health, Transform, Vehicle};
+        let reg = shipped_registry();
+        let tornado = reg.events.iter().find(|e| e.id == "tornado").unwrap().clone();
+        let mut data = DataStore::new();
+        data.insert("weather", std::sync::Mutex::new(Weather::default()));
+        data.insert("weather_event_registry", reg);
+        data.insert("damage_events", std::sync::Mutex::new(Vec::<(u64, DamageEvent)>::new()));
+        data.insert("environment_context", EnvironmentContext { sealed: false, ..Default::default() });
+        let mut world = hecs::World::new();
+        let at = |x: f32| Transform { position: Vec3::new(x, 0.0, 0.0), ..Default::default() };
+        let player = world.spawn((at(0.0), Health::default(), Controllable));
+        let cow = world.spawn((at(10.0), Health::default()));
+        let shed = world.spawn((
+            at(30.0),
+            Structure { blueprint_id: "wood_wall".into(), health: 500.0, max_health: 500.0, provides: None },
+        ));
+        let truck = world.spawn((at(60.0), Vehicle { item_id: "truck_pickup_0".into() }));
+        let crop = |tower: Option<&str>| CropInstance {
+            crop_def_id: "wheat".into(),
+            growth_stage: "seedling".into(),
+            planted_at: 0.0,
+            water_level: 1.0,
+            health: 100.0,
+            tower_id: tower.map(str::to_string),
+            tower_slot: None,
+            genome: None,
+            cultivar: None,
+        };
+        let field = world.spawn((crop(None),));
+        let tower = world.spawn((crop(Some("tower_a")),));
+
+        let mut sys = WeatherSystem::new();
+        sys.start_event(&tornado, 600.0, Some(Vec3::ZERO));
+        assert!(sys.weather.event_core.is_some(), "a vortex places its core");
+        sys.weather.event_core = Some(Vec3::new(30.0, 0.0, 0.0));
+        sys.weather.wind_direction = Vec3::Z;
+        for _ in 0..3 {
+            sys.tick(&mut world, 1.0, &data);
+        }
+        assert!(sys.weather.hazard_inside && sys.weather.hazard_near);
+        let hits: Vec<(u64, DamageEvent)> = data
+            .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect();
+        let hit = |e: hecs::Entity| hits.iter().any(|(b, ev)| *b == u64::from(e.to_bits()) && ev.source_name.as_deref() == Some("Tornado"));
+        assert!(hit(player) && hit(cow));
+        assert!(world.get::<&Structure>(shed).unwrap().health < 500.0);
+        assert!(world.get::<&CropInstance>(field).unwrap().health < 100.0, "outdoor crop lodged");
+        assert_eq!(world.get::<&CropInstance>(tower).unwrap().health, 100.0, "tower crop is indoors");
+        assert!(world.get::<&Transform>(truck).unwrap().position.distance(Vec3::new(60.0, 0.0, 0.0)) > 0.1, "truck dragged");
+
+        data.insert("environment_context", EnvironmentContext::default());
+        for _ in 0..2 {
+            sys.tick(&mut world, 1.0, &data);
+        }
+        let hits: Vec<(u64, DamageEvent)> = data
+            .get::<std::sync::Mutex<Vec<(u64, DamageEvent)>>>("damage_events")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .drain(..)
+            .collect();
+        assert!(hits.iter().any(|(b, _)| *b == u64::from(cow.to_bits())));
+        assert!(hits.iter().all(|(b, _)| *b != u64::from(player.to_bits())), "sheltered at home");
+    }
+
+    /// A running thunderstorm throws lightning onto
+    /// disaster_requests around the player.
+    #[test]
+    fn thunderstorm_throws_lightning() {
+        let reg = shipped_registry();
+        let storm = reg.events.iter().find(|e| e.id == "thunderstorm").unwrap().clone();
+        let mut data = DataStore::new();
+        data.insert("weather_event_registry", reg);
+        data.insert(
+            "disaster_requests",
+            std::sync::Mutex::new(Vec::<(DisasterType, Vec3, f32)>::new()),
+        );
+        let mut world = hecs::World::new();
+        let mut sys = WeatherSystem::new();
+        sys.start_event(&storm, 1000.0, None);
+        // A minute-long tick makes the 1/min rate a certainty.
+        sys.tick(&mut world, 60.0, &data);
+        let strikes = data
+            .get::<std::sync::Mutex<Vec<(DisasterType, Vec3, f32)>>>("disaster_requests")
+            .unwrap()
+            .lock()
+            .unwrap()
+            .clone();
+        assert!(!strikes.is_empty());
+        for (kind, pos, intensity) in strikes {
+            assert_eq!(kind, DisasterType::Lightning);
+            assert!((30.0..=600.01).contains(&pos.length()), "strike {pos:?} outside the band");
+            assert!((0.3..=0.8).contains(&intensity));
+        }
+    }
+
     /// Increment 4: a body with air but no surface water (Mars) never
     /// rains, snows, or fogs; storms sanitize to dust storms.
     #[test]
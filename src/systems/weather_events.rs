@@ -1,17 +1,28 @@
 //! Extreme-weather EVENT registry (v0.1034, weather-water roadmap
 //! section 5): tornadoes, blizzards, meteor showers - and whatever a
 //! modder invents - as DATA entries in `data/weather/events.ron`, per
-//! the infinite-of-x rule. This increment is the SCHEMA + loader +
-//! validation only; WeatherSystem consumption (trigger rolls, wind
-//! application, hazard damage) is the next rung, so nothing here
-//! affects gameplay yet.
+//! the infinite-of-x rule. v0.1034 was the schema + loader + validation;
+//! v0.1035 the trigger rolls and Front gusts in WeatherSystem.
+//!
+//! The hazard rung makes events matter: `bodies` gates which
+//! worlds can host an event, a Vortex core sits in the world and its
+//! `hazard` hurts whatever is exposed inside the radius, event wind
+//! flattens outdoor crops and drags parked vehicles, and `disasters`
+//! lists the DisasterSystem strikes an event throws while it runs
+//! (lightning in a thunderstorm, impacts in a meteor shower). The
+//! wind/damage maths lives here as pure functions; WeatherSystem owns
+//! the state and the rolls.
 //!
 //! Posture mirrors the other registries: parse failures degrade to an
 //! empty registry with a log warning, and the shipped-data tests are
 //! the real gate against a bad edit reaching players silently.
 
+use glam::Vec3;
 use serde::Deserialize;
 
+use crate::systems::body_environment::BodyEnvironment;
+use crate::systems::disasters::DisasterType;
+
 /// Inclusive numeric range (RON: `(min: a, max: b)`).
 #[derive(Debug, Clone, Copy, Deserialize)]
 pub struct Range {
@@ -37,7 +48,9 @@ pub struct CloudOverride {
     pub tint: (f32, f32, f32),
 }
 
-/// Hazard placeholder: validated now, dealt by the future hazard rung.
+/// Damage to anything exposed within `radius_m` of the event core
+/// (Vortex events; the core is placed by WeatherSystem). Linear falloff
+/// to zero at the edge.
 #[derive(Debug, Clone, Copy, Deserialize)]
 pub struct Hazard {
     pub damage_per_s: f32,
@@ -61,6 +74,36 @@ pub struct WeatherEvent {
     pub emitters: Vec<String>,
     pub cloud: CloudOverride,
     pub hazard: Hazard,
+    /// Body ids ("earth", "mars", "moon") the event can occur on. Empty =
+    /// any body with an atmosphere and surface water (the Earth-authored
+    /// default).
+    #[serde(default)]
+    pub bodies: Vec<String>,
+    /// DisasterSystem strikes thrown while the event runs.
+    #[serde(default)]
+    pub disasters: Vec<DisasterTrigger>,
+}
+
+/// One kind of disaster an event throws: on average `per_min`
+/// strikes a minute, each at a rolled intensity and distance from the
+/// player. DisasterSystem then deals it and queues its chain reactions.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct DisasterTrigger {
+    pub kind: DisasterType,
+    pub per_min: f32,
+    pub intensity: Range,
+    pub distance_m: Range,
+}
+
+impl WeatherEvent {
+    /// Whether this event can happen on the given body.
+    pub fn can_occur_on(&self, env: &BodyEnvironment) -> bool {
+        if self.bodies.is_empty() {
+            env.has_atmosphere && env.has_water
+        } else {
+            self.bodies.contains(&env.body_id)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -116,6 +159,17 @@ impl WeatherEventRegistry {
             if e.hazard.damage_per_s > 0.0 && e.hazard.radius_m <= 0.0 {
                 return Err(format!("event '{id}': damaging hazard needs a positive radius"));
             }
+            for d in &e.disasters {
+                if d.per_min <= 0.0 {
+                    return Err(format!("event '{id}': {:?} per_min must be > 0", d.kind));
+                }
+                if d.intensity.min > d.intensity.max || d.intensity.min < 0.0 || d.intensity.max > 1.0 {
+                    return Err(format!("event '{id}': {:?} intensity must be a range within 0..1", d.kind));
+                }
+                if d.distance_m.min > d.distance_m.max || d.distance_m.min < 0.0 {
+                    return Err(format!("event '{id}': {:?} distance_m min > max", d.kind));
+                }
+            }
         }
         Ok(())
     }
@@ -169,31 +223,18 @@ pub fn eligible<'a>(
         .collect()
 }
 
-/// Place a Vortex event's core on the planet surface (v0.1038): from
-/// the player's PLANET-FRAME position, walk `dist_m` along the surface
-/// at `bearing_rad`, then re-project onto the player's radius so the
-/// core sits on the sphere (a tornado core is a ground feature). Pure
-/// and deterministic - the caller supplies the bearing roll.
-pub fn place_event_core(anchor: glam::DVec3, bearing_rad: f64, dist_m: f64) -> glam::DVec3 {
-    let r = anchor.length();
-    if r < 1.0 {
-        return anchor;
-    }
-    let dir = anchor / r;
-    // Tangent basis at the anchor (east-ish / north-ish; exact compass
-    // orientation is irrelevant for a random bearing).
-    let mut east = glam::DVec3::Y.cross(dir);
-    if east.length_squared() < 1.0e-9 {
-        east = glam::DVec3::X.cross(dir);
-    }
-    let east = east.normalize();
-    let north = dir.cross(east);
-    let offset = (east * bearing_rad.cos() + north * bearing_rad.sin()) * dist_m;
-    (anchor + offset).normalize() * r
+/// Place a Vortex event's core (in the world frame entities live
+/// in): `dist_m` from the player along `bearing_rad` in the horizontal
+/// (XZ) plane, at the player's height - a tornado core is a ground
+/// feature. v0.1038 placed it in planet-frame metres for the HUD only;
+/// hazard damage needs it where Transforms are. Pure and deterministic -
+/// the caller supplies the bearing roll.
+pub fn place_event_core(anchor: Vec3, bearing_rad: f32, dist_m: f32) -> Vec3 {
+    anchor + Vec3::new(bearing_rad.cos(), 0.0, bearing_rad.sin()) * dist_m
 }
 
 /// Hazard proximity bands: `near` inside 3x the hazard radius (the HUD
-/// warning), `inside` within the radius itself (the future damage rung).
+/// warning), `inside` within the radius itself (where damage lands).
 pub fn hazard_proximity(dist_m: f64, radius_m: f32) -> (bool, bool) {
     if radius_m <= 0.0 {
         return (false, false);
@@ -201,6 +242,42 @@ pub fn hazard_proximity(dist_m: f64, radius_m: f32) -> (bool, bool) {
     (dist_m < radius_m as f64 * 3.0, dist_m < radius_m as f64)
 }
 
+/// Wind (m/s, world vector) a Vortex adds at `offset` from its core:
+/// a Rankine vortex - solid-body rotation rising to `peak_mps`
+/// at the core radius, then falling off as 1/r - plus an inflow of a
+/// third of the swirl, so debris spirals in. Horizontal (XZ) only.
+pub fn vortex_wind(offset: Vec3, core_radius_m: f32, peak_mps: f32) -> Vec3 {
+    let flat = Vec3::new(offset.x, 0.0, offset.z);
+    let r = flat.length();
+    if r < 1.0e-3 || core_radius_m <= 0.0 {
+        return Vec3::ZERO;
+    }
+    let speed = if r <= core_radius_m {
+        peak_mps * r / core_radius_m
+    } else {
+        peak_mps * core_radius_m / r
+    };
+    let radial = flat / r;
+    let tangent = Vec3::new(-radial.z, 0.0, radial.x);
+    (tangent - radial / 3.0).normalize() * speed
+}
+
+/// Per-second damage from wind above `threshold_mps`: `per_mps` for
+/// every m/s over. Crops and structures share the rule with their own
+/// thresholds.
+pub fn wind_damage_per_s(wind_mps: f32, threshold_mps: f32, per_mps: f32) -> f32 {
+    (wind_mps - threshold_mps).max(0.0) * per_mps
+}
+
+/// Hazard damage per second at `dist_m` from the core: full at the
+/// core, falling linearly to zero at the radius.
+pub fn hazard_damage_per_s(hazard: &Hazard, dist_m: f32) -> f32 {
+    if hazard.radius_m <= 0.0 || dist_m >= hazard.radius_m {
+        return 0.0;
+    }
+    hazard.damage_per_s * (1.0 - dist_m / hazard.radius_m)
+}
+
 /// Deterministic weighted selection: `roll01` in [0,1) maps onto the
 /// cumulative rarity weights, so rare events (tornado 0.08) fire far
 /// less often than common ones (thunderstorm 1.0). Pure function of its
@@ -284,18 +361,15 @@ mod tests {
     }
 
     #[test]
-    fn core_placement_stays_on_sphere_in_the_distance_band() {
-        let anchor = glam::DVec3::new(3_000_000.0, 4_000_000.0, 2_500_000.0);
-        let r0 = anchor.length();
+    fn core_placement_stays_level_in_the_distance_band() {
+        let anchor = Vec3::new(120.0, 4.5, -60.0);
         for i in 0..24 {
-            let bearing = i as f64 * 0.26;
-            let dist = 200.0 + (i as f64 * 17.3) % 400.0;
+            let bearing = i as f32 * 0.26;
+            let dist = 200.0 + (i as f32 * 17.3) % 400.0;
             let core = place_event_core(anchor, bearing, dist);
-            assert!((core.length() - r0).abs() < 0.01, "core left the sphere");
+            assert!((core.y - anchor.y).abs() < 1e-4, "core left the ground plane");
             let d = (core - anchor).length();
-            // Chord vs arc at <= 600 m on a planet radius is sub-mm; the
-            // re-projection shortens the offset slightly, never grows it.
-            assert!(d > dist * 0.98 && d < dist * 1.001, "band miss: {d} vs {dist}");
+            assert!((d - dist).abs() < 0.01, "band miss: {d} vs {dist}");
         }
         // Proximity bands (tornado hazard radius 80 m).
         assert_eq!(hazard_proximity(500.0, 80.0), (false, false));
@@ -304,6 +378,38 @@ mod tests {
         assert_eq!(hazard_proximity(50.0, 0.0), (false, false), "no hazard, no warning");
     }
 
+    /// Events are body-gated, a vortex swirls fastest at its core
+    /// radius and spirals inward, and wind/hazard damage scale as documented.
+    #[test]
+    fn body_gates_and_wind_maths() {
+        use crate::systems::body_environment::BodyEnvironment;
+        let bytes = std::fs::read(data_dir().join("weather/events.ron")).unwrap();
+        let reg = WeatherEventRegistry::from_ron(&bytes).unwrap();
+        let find = |id: &str| reg.events.iter().find(|e| e.id == id).unwrap();
+        let earth = BodyEnvironment::default();
+        let moon = BodyEnvironment::airless("moon", 220.0);
+        let mars = BodyEnvironment::dry_atmosphere("mars", 210.0);
+        assert!(find("tornado").can_occur_on(&earth));
+        assert!(!find("tornado").can_occur_on(&mars), "no water, no tornado");
+        assert!(find("meteor_shower").can_occur_on(&moon));
+        assert!(find("dust_storm").can_occur_on(&mars) && !find("dust_storm").can_occur_on(&earth));
+        assert!(find("thunderstorm").disasters.iter().any(|d| d.kind == DisasterType::Lightning));
+
+        let at_core = vortex_wind(Vec3::new(60.0, 0.0, 0.0), 60.0, 70.0);
+        assert!((at_core.length() - 70.0).abs() < 0.01, "peak at the core radius");
+        let far = vortex_wind(Vec3::new(600.0, 5.0, 0.0), 60.0, 70.0);
+        assert!((far.length() - 7.0).abs() < 0.01, "1/r outside: {}", far.length());
+        assert!(far.y == 0.0 && far.x < 0.0, "spirals inward, stays level");
+        assert_eq!(vortex_wind(Vec3::ZERO, 60.0, 70.0), Vec3::ZERO);
+
+        assert_eq!(wind_damage_per_s(10.0, 18.0, 0.5), 0.0);
+        assert!((wind_damage_per_s(28.0, 18.0, 0.5) - 5.0).abs() < 1e-6);
+        let h = Hazard { damage_per_s: 4.0, radius_m: 80.0 };
+        assert!((hazard_damage_per_s(&h, 0.0) - 4.0).abs() < 1e-6);
+        assert!((hazard_damage_per_s(&h, 40.0) - 2.0).abs() < 1e-6);
+        assert_eq!(hazard_damage_per_s(&h, 90.0), 0.0);
+    }
+
     #[test]
     fn validation_rejects_bad_entries() {
         let base = std::fs::read_to_string(data_dir().join("weather/events.ron")).unwrap();
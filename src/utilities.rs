@@ -275,6 +275,56 @@ pub fn cheapest_cable_for(load_watts: f32, volts: f32, length_m: f32) -> Option<
         .min_by(|a, b| a.cost_per_m.partial_cmp(&b.cost_per_m).unwrap_or(std::cmp::Ordering::Equal))
 }
 
+/// Nominal voltage of a home circuit. The buildability sizing and the live grid solve both use it;
+/// per-circuit voltage (240 V appliance runs) is a later increment.
+pub const HOME_VOLTS: f32 = 120.0;
+
+// ── Overcurrent protection ──────────────────────────────────────────────────────────────────────
+// Every power run is guarded by a breaker or fuse rated at the cable's ampacity. The live grid solve
+// (`systems::power_grid`) heats each device on its thermal curve and opens the run when it trips.
+
+/// Breaker (resettable) or fuse (one-shot).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProtectionKind {
+    Breaker,
+    Fuse,
+}
+
+/// An overcurrent device TYPE from `data/utilities/protection.ron`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProtectionType {
+    pub id: String,
+    pub label: String,
+    pub kind: ProtectionKind,
+    /// Trips at once at this multiple of its rating (a short circuit).
+    pub instant_multiple: f32,
+    /// Thermal curve: overloaded k times its rating it trips after about `trip_seconds / (k^2 - 1)`.
+    pub trip_seconds: f32,
+    /// Seconds for a fully heated device to cool back to cold once the overload ends.
+    pub cool_seconds: f32,
+    pub note: String,
+}
+
+/// The protection registry, parsed once + embedded (same pattern as `conduit_types`).
+pub fn protection_types() -> &'static [ProtectionType] {
+    static REG: std::sync::OnceLock<Vec<ProtectionType>> = std::sync::OnceLock::new();
+    REG.get_or_init(|| {
+        const SRC: &str = include_str!("../data/utilities/protection.ron");
+        match ron::from_str::<Vec<ProtectionType>>(SRC) {
+            Ok(v) => v,
+            Err(e) => {
+                log::error!("protection.ron parse error: {e}");
+                Vec::new()
+            }
+        }
+    })
+}
+
+/// Look up a protection device by id.
+pub fn protection_type(id: &str) -> Option<&'static ProtectionType> {
+    protection_types().iter().find(|p| p.id == id)
+}
+
 // ── DATA / telecom links (v0.619) ───────────────────────────────────────────────────────────────
 // Real media (copper ethernet, fibre, WiFi, ...) with real tradeoffs: bandwidth, range, latency, cost,
 // and RF emission. Mirrors the electrical cable physics. See docs/design/telecom.md.
@@ -435,6 +485,18 @@ mod tests {
         assert_eq!(check_cable(sc, 10000.0, 240.0, 100.0).verdict, CableVerdict::Pass, "the room-temp superconductor carries it");
     }
 
+    #[test]
+    fn protection_registry_has_a_breaker_and_a_faster_fuse() {
+        let breaker = protection_type("breaker").expect("breaker");
+        let fuse = protection_type("fuse").expect("fuse");
+        assert_eq!(breaker.kind, ProtectionKind::Breaker);
+        assert_eq!(fuse.kind, ProtectionKind::Fuse);
+        assert!(fuse.trip_seconds < breaker.trip_seconds, "a fuse blows faster than a breaker trips");
+        // An extension cord is a real (thin) cable: a furnace-sized load fails it outright.
+        let cord = conduit_type("cu_awg16_cord").expect("extension cord");
+        assert_eq!(check_cable(cord, 3000.0, HOME_VOLTS, 10.0).verdict, CableVerdict::Fail);
+    }
+
     #[test]
     fn a_pipe_is_not_an_electrical_cable() {
         // (Guards the utility-mismatch path even before pipes are wired.)